@@ -55,6 +55,27 @@ mod m20260618_000049_subscriptions_provider_sub_id_unique;
 mod m20260620_000050_add_totp_last_used_counter;
 mod m20260620_000051_payout_account_metadata_encryption_runbook;
 mod m20260627_000052_alter_user_add_session_auth_secret_and_encrypt_fields;
+mod m20260701_000053_create_media_uploads_table;
+mod m20260705_000054_alter_media_add_descriptive_fields;
+mod m20260708_000055_alter_media_add_blurhash;
+mod m20260712_000056_create_media_optimization_jobs_table;
+mod m20260716_000057_alter_media_add_playback_details;
+mod m20260720_000058_create_analytics_events_table;
+mod m20260724_000059_alter_analytics_events_add_engagement;
+mod m20260728_000060_alter_subscriptions_add_canceled_at;
+mod m20260801_000061_create_analytics_rollups;
+mod m20260805_000062_create_redirects_table;
+mod m20260809_000063_create_seo_overrides_table;
+mod m20260812_000064_alter_posts_add_translations;
+mod m20260816_000065_create_post_authors_and_author_profiles;
+mod m20260820_000066_alter_post_comments_add_moderation;
+mod m20260824_000067_create_blocklist_entries_and_shadow_bans;
+mod m20260828_000068_create_comment_likes_and_reactions;
+mod m20260901_000069_create_notification_preferences_and_digests;
+mod m20260905_000070_create_notifications;
+mod m20260910_000071_create_webhooks;
+mod m20260914_000072_alter_media_optimization_jobs_add_next_attempt_at;
+mod m20260918_000073_alter_posts_add_search_text;
 
 pub struct Migrator;
 
@@ -119,6 +140,29 @@ impl MigratorTrait for Migrator {
             Box::new(
                 m20260627_000052_alter_user_add_session_auth_secret_and_encrypt_fields::Migration,
             ),
+            Box::new(m20260701_000053_create_media_uploads_table::Migration),
+            Box::new(m20260705_000054_alter_media_add_descriptive_fields::Migration),
+            Box::new(m20260708_000055_alter_media_add_blurhash::Migration),
+            Box::new(m20260712_000056_create_media_optimization_jobs_table::Migration),
+            Box::new(m20260716_000057_alter_media_add_playback_details::Migration),
+            Box::new(m20260720_000058_create_analytics_events_table::Migration),
+            Box::new(m20260724_000059_alter_analytics_events_add_engagement::Migration),
+            Box::new(m20260728_000060_alter_subscriptions_add_canceled_at::Migration),
+            Box::new(m20260801_000061_create_analytics_rollups::Migration),
+            Box::new(m20260805_000062_create_redirects_table::Migration),
+            Box::new(m20260809_000063_create_seo_overrides_table::Migration),
+            Box::new(m20260812_000064_alter_posts_add_translations::Migration),
+            Box::new(m20260816_000065_create_post_authors_and_author_profiles::Migration),
+            Box::new(m20260820_000066_alter_post_comments_add_moderation::Migration),
+            Box::new(m20260824_000067_create_blocklist_entries_and_shadow_bans::Migration),
+            Box::new(m20260828_000068_create_comment_likes_and_reactions::Migration),
+            Box::new(m20260901_000069_create_notification_preferences_and_digests::Migration),
+            Box::new(m20260905_000070_create_notifications::Migration),
+            Box::new(m20260910_000071_create_webhooks::Migration),
+            Box::new(
+                m20260914_000072_alter_media_optimization_jobs_add_next_attempt_at::Migration,
+            ),
+            Box::new(m20260918_000073_alter_posts_add_search_text::Migration),
         ]
     }
 }
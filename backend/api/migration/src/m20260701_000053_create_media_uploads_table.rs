@@ -0,0 +1,220 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+/// `media_uploads` tracks direct-to-storage multipart uploads. The client
+/// pushes parts straight to the object store through presigned URLs; the row
+/// keeps the staging key and provider upload id so the session can be resumed,
+/// finalized into a `media` row, or aborted (by the client or by media GC once
+/// `expires_at` has passed).
+///
+/// Creates PostgreSQL enum `media_upload_status` and table `media_uploads`:
+/// - uploader_id -> users.id (cascade)
+/// - media_id -> media.id (set null), filled in on completion
+///
+/// Indexes:
+/// - idx_media_uploads_uploader_id (uploader_id)
+/// - idx_media_uploads_status_expires_at (status, expires_at)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(MediaUploadStatus::Table)
+                    .values(vec![
+                        MediaUploadStatus::Pending,
+                        MediaUploadStatus::Completed,
+                        MediaUploadStatus::Aborted,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaUploads::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaUploads::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploads::UploaderId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaUploads::ObjectKey).text().not_null())
+                    .col(
+                        ColumnDef::new(MediaUploads::ProviderUploadId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaUploads::Filename).text())
+                    .col(ColumnDef::new(MediaUploads::MimeType).text().not_null())
+                    .col(ColumnDef::new(MediaUploads::Extension).text().not_null())
+                    .col(ColumnDef::new(MediaUploads::Size).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(MediaUploads::PartSize)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaUploads::PartCount).integer().not_null())
+                    // Hash declared by the client at init; the authoritative
+                    // hash is computed from the assembled bytes on completion.
+                    .col(ColumnDef::new(MediaUploads::ContentHash).text())
+                    .col(ColumnDef::new(MediaUploads::ReferenceType).enumeration(
+                        MediaReferenceType::Table,
+                        [
+                            MediaReferenceType::Category,
+                            MediaReferenceType::User,
+                            MediaReferenceType::Post,
+                        ],
+                    ))
+                    .col(ColumnDef::new(MediaUploads::Width).integer())
+                    .col(ColumnDef::new(MediaUploads::Height).integer())
+                    .col(
+                        ColumnDef::new(MediaUploads::Status)
+                            .enumeration(
+                                MediaUploadStatus::Table,
+                                [
+                                    MediaUploadStatus::Pending,
+                                    MediaUploadStatus::Completed,
+                                    MediaUploadStatus::Aborted,
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaUploads::MediaId).integer())
+                    .col(
+                        ColumnDef::new(MediaUploads::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploads::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(MediaUploads::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_uploads_uploader_id")
+                            .from(MediaUploads::Table, MediaUploads::UploaderId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_uploads_media_id")
+                            .from(MediaUploads::Table, MediaUploads::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_uploads_uploader_id")
+                    .table(MediaUploads::Table)
+                    .col(MediaUploads::UploaderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Lets media GC find expired pending sessions without a scan.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_uploads_status_expires_at")
+                    .table(MediaUploads::Table)
+                    .col(MediaUploads::Status)
+                    .col(MediaUploads::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaUploads::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(MediaUploadStatus::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MediaUploads {
+    Table,
+    Id,
+    UploaderId,
+    ObjectKey,
+    ProviderUploadId,
+    Filename,
+    MimeType,
+    Extension,
+    Size,
+    PartSize,
+    PartCount,
+    ContentHash,
+    ReferenceType,
+    Width,
+    Height,
+    Status,
+    MediaId,
+    ExpiresAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum MediaUploadStatus {
+    Table,
+    #[iden = "pending"]
+    Pending,
+    #[iden = "completed"]
+    Completed,
+    #[iden = "aborted"]
+    Aborted,
+}
+
+#[derive(Iden)]
+enum MediaReferenceType {
+    Table,
+    #[iden = "category"]
+    Category,
+    #[iden = "user"]
+    User,
+    #[iden = "post"]
+    Post,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    Id,
+}
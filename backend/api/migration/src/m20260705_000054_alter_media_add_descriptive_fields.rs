@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds descriptive metadata to `media`:
+/// - alt_text, caption, credit, license (text, nullable)
+/// - focal_x, focal_y (double, nullable) — normalized 0..1 coordinates the
+///   optimizer crops square variants around; both are set or neither is.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .add_column(ColumnDef::new(Media::AltText).text())
+                    .add_column(ColumnDef::new(Media::Caption).text())
+                    .add_column(ColumnDef::new(Media::Credit).text())
+                    .add_column(ColumnDef::new(Media::License).text())
+                    .add_column(ColumnDef::new(Media::FocalX).double())
+                    .add_column(ColumnDef::new(Media::FocalY).double())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE media ADD CONSTRAINT chk_media_focal_point CHECK (\
+                 (focal_x IS NULL AND focal_y IS NULL) OR \
+                 (focal_x BETWEEN 0 AND 1 AND focal_y BETWEEN 0 AND 1))",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE media DROP CONSTRAINT IF EXISTS chk_media_focal_point")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .drop_column(Media::AltText)
+                    .drop_column(Media::Caption)
+                    .drop_column(Media::Credit)
+                    .drop_column(Media::License)
+                    .drop_column(Media::FocalX)
+                    .drop_column(Media::FocalY)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    AltText,
+    Caption,
+    Credit,
+    License,
+    FocalX,
+    FocalY,
+}
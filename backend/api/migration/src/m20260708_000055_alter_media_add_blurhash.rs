@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `media.blurhash` (text, nullable): a compact blurred placeholder the
+/// optimizer computes at upload so clients can paint something before the
+/// image (or its responsive variants) load.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .add_column(ColumnDef::new(Media::Blurhash).text())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .drop_column(Media::Blurhash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    Blurhash,
+}
@@ -0,0 +1,208 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_query::extension::postgres::Type;
+
+/// `media_optimization_jobs` is the work queue for background image
+/// (re)optimization. Admins enqueue a batch of media (`batch_id` groups the
+/// rows for progress reporting) and deferred uploads enqueue themselves; the
+/// worker claims `pending` rows oldest first and regenerates the variants.
+///
+/// Creates PostgreSQL enum `media_job_status` and table
+/// `media_optimization_jobs`:
+/// - media_id -> media.id (cascade)
+/// - requested_by -> users.id (set null)
+///
+/// Indexes:
+/// - idx_media_optimization_jobs_status_id (status, id) for claiming
+/// - idx_media_optimization_jobs_batch_id (batch_id)
+/// - idx_media_optimization_jobs_media_id (media_id)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(MediaJobStatus::Table)
+                    .values(vec![
+                        MediaJobStatus::Pending,
+                        MediaJobStatus::Running,
+                        MediaJobStatus::Completed,
+                        MediaJobStatus::Skipped,
+                        MediaJobStatus::Failed,
+                    ])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaOptimizationJobs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::MediaId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::BatchId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaOptimizationJobs::RequestedBy).integer())
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::Status)
+                            .enumeration(
+                                MediaJobStatus::Table,
+                                [
+                                    MediaJobStatus::Pending,
+                                    MediaJobStatus::Running,
+                                    MediaJobStatus::Completed,
+                                    MediaJobStatus::Skipped,
+                                    MediaJobStatus::Failed,
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    // Skip reason or last error, for the progress report.
+                    .col(ColumnDef::new(MediaOptimizationJobs::Message).text())
+                    .col(ColumnDef::new(MediaOptimizationJobs::VariantCount).integer())
+                    .col(ColumnDef::new(MediaOptimizationJobs::StartedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::FinishedAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(MediaOptimizationJobs::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_optimization_jobs_media_id")
+                            .from(MediaOptimizationJobs::Table, MediaOptimizationJobs::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_optimization_jobs_requested_by")
+                            .from(
+                                MediaOptimizationJobs::Table,
+                                MediaOptimizationJobs::RequestedBy,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_optimization_jobs_status_id")
+                    .table(MediaOptimizationJobs::Table)
+                    .col(MediaOptimizationJobs::Status)
+                    .col(MediaOptimizationJobs::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_optimization_jobs_batch_id")
+                    .table(MediaOptimizationJobs::Table)
+                    .col(MediaOptimizationJobs::BatchId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_optimization_jobs_media_id")
+                    .table(MediaOptimizationJobs::Table)
+                    .col(MediaOptimizationJobs::MediaId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaOptimizationJobs::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(MediaJobStatus::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MediaOptimizationJobs {
+    Table,
+    Id,
+    MediaId,
+    BatchId,
+    RequestedBy,
+    Status,
+    Attempts,
+    Message,
+    VariantCount,
+    StartedAt,
+    FinishedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum MediaJobStatus {
+    Table,
+    #[iden = "pending"]
+    Pending,
+    #[iden = "running"]
+    Running,
+    #[iden = "completed"]
+    Completed,
+    #[iden = "skipped"]
+    Skipped,
+    #[iden = "failed"]
+    Failed,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    Id,
+}
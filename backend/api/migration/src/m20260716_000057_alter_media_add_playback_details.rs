@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `media.duration_ms` (bigint, nullable) and `media.page_count`
+/// (integer, nullable): what the media probe reads from video/audio containers
+/// and PDFs at upload. Both stay null for images and for files the probe
+/// could not parse.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .add_column(ColumnDef::new(Media::DurationMs).big_integer())
+                    .add_column(ColumnDef::new(Media::PageCount).integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Media::Table)
+                    .drop_column(Media::DurationMs)
+                    .drop_column(Media::PageCount)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    DurationMs,
+    PageCount,
+}
@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+/// `analytics_events` holds first-party traffic events sent by the consumer
+/// site. Rows carry no IP address or user id: visitors are identified by a
+/// keyed hash that changes every day, and the country is resolved at ingest.
+///
+/// `post_id` deliberately has no foreign key: events outlive the posts they
+/// point at, and ingestion must never fail on a deleted post.
+///
+/// Indexes:
+/// - idx_analytics_events_created_at (created_at) for range scans
+/// - idx_analytics_events_post_id_created_at (post_id, created_at)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnalyticsEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AnalyticsEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AnalyticsEvents::Kind).text().not_null())
+                    .col(ColumnDef::new(AnalyticsEvents::Path).text().not_null())
+                    .col(ColumnDef::new(AnalyticsEvents::PostId).integer())
+                    .col(ColumnDef::new(AnalyticsEvents::ReferrerHost).text())
+                    .col(ColumnDef::new(AnalyticsEvents::UtmSource).text())
+                    .col(ColumnDef::new(AnalyticsEvents::UtmMedium).text())
+                    .col(ColumnDef::new(AnalyticsEvents::UtmCampaign).text())
+                    .col(ColumnDef::new(AnalyticsEvents::UtmTerm).text())
+                    .col(ColumnDef::new(AnalyticsEvents::UtmContent).text())
+                    .col(
+                        ColumnDef::new(AnalyticsEvents::DeviceClass)
+                            .text()
+                            .not_null(),
+                    )
+                    // ISO 3166-1 alpha-2, when the GeoLite2 database knows the IP.
+                    .col(ColumnDef::new(AnalyticsEvents::CountryCode).string_len(2))
+                    .col(
+                        ColumnDef::new(AnalyticsEvents::VisitorId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnalyticsEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_analytics_events_created_at")
+                    .table(AnalyticsEvents::Table)
+                    .col(AnalyticsEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_analytics_events_post_id_created_at")
+                    .table(AnalyticsEvents::Table)
+                    .col(AnalyticsEvents::PostId)
+                    .col(AnalyticsEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AnalyticsEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum AnalyticsEvents {
+    Table,
+    Id,
+    Kind,
+    Path,
+    PostId,
+    ReferrerHost,
+    UtmSource,
+    UtmMedium,
+    UtmCampaign,
+    UtmTerm,
+    UtmContent,
+    DeviceClass,
+    CountryCode,
+    VisitorId,
+    CreatedAt,
+}
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `analytics_events.scroll_depth` (smallint, 0-100) and
+/// `analytics_events.engaged_ms` (integer) for `engagement` heartbeats sent
+/// while a visitor reads a post. Both stay null for pageviews.
+///
+/// Indexes:
+/// - idx_analytics_events_post_id_kind_visitor (post_id, kind, visitor_id)
+///   for per-reader aggregation
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AnalyticsEvents::Table)
+                    .add_column(ColumnDef::new(AnalyticsEvents::ScrollDepth).small_integer())
+                    .add_column(ColumnDef::new(AnalyticsEvents::EngagedMs).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_analytics_events_post_id_kind_visitor")
+                    .table(AnalyticsEvents::Table)
+                    .col(AnalyticsEvents::PostId)
+                    .col(AnalyticsEvents::Kind)
+                    .col(AnalyticsEvents::VisitorId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_analytics_events_post_id_kind_visitor")
+                    .table(AnalyticsEvents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AnalyticsEvents::Table)
+                    .drop_column(AnalyticsEvents::ScrollDepth)
+                    .drop_column(AnalyticsEvents::EngagedMs)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum AnalyticsEvents {
+    Table,
+    PostId,
+    Kind,
+    VisitorId,
+    ScrollDepth,
+    EngagedMs,
+}
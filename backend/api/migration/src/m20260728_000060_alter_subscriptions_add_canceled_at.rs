@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `subscriptions.canceled_at` (timestamptz), stamped when a
+/// subscription becomes canceled or expired and cleared on reactivation, so
+/// churn can be dated. Existing canceled/expired rows are backfilled from
+/// `updated_at`, the closest record of when they ended.
+///
+/// Indexes:
+/// - idx_subscriptions_canceled_at (canceled_at) for churn windows
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .add_column(ColumnDef::new(Subscriptions::CanceledAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"UPDATE "subscriptions" SET "canceled_at" = "updated_at" WHERE "status" IN ('canceled', 'expired');"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_subscriptions_canceled_at")
+                    .table(Subscriptions::Table)
+                    .col(Subscriptions::CanceledAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_subscriptions_canceled_at")
+                    .table(Subscriptions::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .drop_column(Subscriptions::CanceledAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Subscriptions {
+    Table,
+    CanceledAt,
+}
@@ -0,0 +1,233 @@
+use sea_orm_migration::prelude::*;
+
+/// Daily rollups the analytics endpoints read instead of scanning raw rows,
+/// maintained by the background rollup job (`services::analytics_rollup`).
+/// Days are UTC calendar days.
+///
+/// - `analytics_daily_post_views` (day, post_id): views and unique viewers
+/// - `analytics_daily_comments` (day, post_id): comments
+/// - `analytics_daily_signups` (day): new users
+/// - `analytics_rollup_state`: the last day rolled up and the raw-view
+///   retention floor, per rollup name
+/// - `post_views_archive`: raw `post_views` rows moved out by the retention
+///   policy when archiving is on
+///
+/// Rollup and archive rows carry no foreign keys: history outlives the posts
+/// and users it counts.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnalyticsDailyPostViews::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AnalyticsDailyPostViews::Day).date().not_null())
+                    .col(
+                        ColumnDef::new(AnalyticsDailyPostViews::PostId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnalyticsDailyPostViews::Views)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AnalyticsDailyPostViews::UniqueVisitors)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(AnalyticsDailyPostViews::Day)
+                            .col(AnalyticsDailyPostViews::PostId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_analytics_daily_post_views_post_id_day")
+                    .table(AnalyticsDailyPostViews::Table)
+                    .col(AnalyticsDailyPostViews::PostId)
+                    .col(AnalyticsDailyPostViews::Day)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnalyticsDailyComments::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AnalyticsDailyComments::Day).date().not_null())
+                    .col(
+                        ColumnDef::new(AnalyticsDailyComments::PostId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AnalyticsDailyComments::Comments)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(AnalyticsDailyComments::Day)
+                            .col(AnalyticsDailyComments::PostId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnalyticsDailySignups::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AnalyticsDailySignups::Day)
+                            .date()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AnalyticsDailySignups::NewUsers)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AnalyticsRollupState::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AnalyticsRollupState::Name)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AnalyticsRollupState::RolledUpThrough).date())
+                    // Raw views before this day were pruned; rebuilds stop here.
+                    .col(ColumnDef::new(AnalyticsRollupState::RawPrunedBefore).date())
+                    .col(
+                        ColumnDef::new(AnalyticsRollupState::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostViewsArchive::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostViewsArchive::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostViewsArchive::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostViewsArchive::UserId).integer())
+                    .col(ColumnDef::new(PostViewsArchive::IpAddress).string())
+                    .col(ColumnDef::new(PostViewsArchive::UserAgent).string())
+                    .col(
+                        ColumnDef::new(PostViewsArchive::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_views_archive_created_at")
+                    .table(PostViewsArchive::Table)
+                    .col(PostViewsArchive::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostViewsArchive::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AnalyticsRollupState::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AnalyticsDailySignups::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AnalyticsDailyComments::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AnalyticsDailyPostViews::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum AnalyticsDailyPostViews {
+    Table,
+    Day,
+    PostId,
+    Views,
+    UniqueVisitors,
+}
+
+#[derive(Iden)]
+enum AnalyticsDailyComments {
+    Table,
+    Day,
+    PostId,
+    Comments,
+}
+
+#[derive(Iden)]
+enum AnalyticsDailySignups {
+    Table,
+    Day,
+    NewUsers,
+}
+
+#[derive(Iden)]
+enum AnalyticsRollupState {
+    Table,
+    Name,
+    RolledUpThrough,
+    RawPrunedBefore,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum PostViewsArchive {
+    Table,
+    Id,
+    PostId,
+    UserId,
+    IpAddress,
+    UserAgent,
+    CreatedAt,
+}
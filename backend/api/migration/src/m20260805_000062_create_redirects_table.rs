@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+/// `redirects` maps old consumer-site paths to their new location, or marks
+/// them gone. Rows are written automatically when a post or category slug
+/// changes or the content is deleted, and by admins for manual rules.
+///
+/// - source_path: the path matched, unique per rule
+/// - target_path: where to send the visitor; null for `gone`
+/// - match_type: `exact` or `prefix`
+/// - status: `permanent` (301), `temporary` (302) or `gone` (410)
+///
+/// Indexes:
+/// - idx_redirects_source_path_unique (source_path)
+/// - idx_redirects_target_path (target_path), for collapsing chains
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Redirects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Redirects::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Redirects::SourcePath).text().not_null())
+                    .col(ColumnDef::new(Redirects::TargetPath).text())
+                    .col(
+                        ColumnDef::new(Redirects::MatchType)
+                            .string_len(10)
+                            .not_null()
+                            .default("exact"),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::Status)
+                            .string_len(10)
+                            .not_null()
+                            .default("permanent"),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::IsAutomatic)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(Redirects::Note).text())
+                    .col(
+                        ColumnDef::new(Redirects::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Redirects::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_redirects_source_path_unique")
+                    .table(Redirects::Table)
+                    .col(Redirects::SourcePath)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_redirects_target_path")
+                    .table(Redirects::Table)
+                    .col(Redirects::TargetPath)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Redirects::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Redirects {
+    Table,
+    Id,
+    SourcePath,
+    TargetPath,
+    MatchType,
+    Status,
+    IsAutomatic,
+    Note,
+    CreatedAt,
+    UpdatedAt,
+}
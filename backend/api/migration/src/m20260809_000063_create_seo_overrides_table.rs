@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+/// `seo_overrides` holds hand-set SEO metadata for a post, category or tag.
+/// Anything left null falls back to what the consumer site derives from the
+/// content itself.
+///
+/// - entity_type / entity_id: the item the overrides belong to
+/// - meta_title / meta_description: replace the derived title and summary
+/// - canonical_url: absolute URL, for content first published elsewhere
+/// - noindex: ask crawlers to skip the page (also drops it from sitemaps)
+/// - og_image_id: custom social card; without it a card is generated
+///
+/// Indexes:
+/// - idx_seo_overrides_entity_unique (entity_type, entity_id)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeoOverrides::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SeoOverrides::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SeoOverrides::EntityType)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SeoOverrides::EntityId).integer().not_null())
+                    .col(ColumnDef::new(SeoOverrides::MetaTitle).string_len(200))
+                    .col(ColumnDef::new(SeoOverrides::MetaDescription).text())
+                    .col(ColumnDef::new(SeoOverrides::CanonicalUrl).text())
+                    .col(
+                        ColumnDef::new(SeoOverrides::Noindex)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(SeoOverrides::OgImageId).integer())
+                    .col(
+                        ColumnDef::new(SeoOverrides::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SeoOverrides::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_seo_overrides_og_image_id")
+                            .from(SeoOverrides::Table, SeoOverrides::OgImageId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_seo_overrides_entity_unique")
+                    .table(SeoOverrides::Table)
+                    .col(SeoOverrides::EntityType)
+                    .col(SeoOverrides::EntityId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeoOverrides::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum SeoOverrides {
+    Table,
+    Id,
+    EntityType,
+    EntityId,
+    MetaTitle,
+    MetaDescription,
+    CanonicalUrl,
+    Noindex,
+    OgImageId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Media {
+    Table,
+    Id,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `posts.locale` (BCP 47 tag such as `en` or `pt-BR`, defaulting to
+/// `en` for existing rows) and `posts.translation_group_id`, a uuid shared
+/// by the localized versions of one post. Each version is its own row with
+/// its own title, slug, excerpt and content; posts that were never
+/// translated keep a null group.
+///
+/// Indexes:
+/// - idx_posts_locale (locale) for locale-filtered listings and feeds
+/// - idx_posts_translation_group_locale_unique (translation_group_id, locale)
+///   so a group holds at most one version per locale
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::Locale)
+                            .string_len(16)
+                            .not_null()
+                            .default("en"),
+                    )
+                    .add_column(ColumnDef::new(Posts::TranslationGroupId).uuid())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_locale")
+                    .table(Posts::Table)
+                    .col(Posts::Locale)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_posts_translation_group_locale_unique")
+                    .table(Posts::Table)
+                    .col(Posts::TranslationGroupId)
+                    .col(Posts::Locale)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_translation_group_locale_unique")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_posts_locale")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::Locale)
+                    .drop_column(Posts::TranslationGroupId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Locale,
+    TranslationGroupId,
+}
@@ -0,0 +1,172 @@
+use sea_orm_migration::prelude::*;
+
+/// Public author profiles and co-author credits.
+///
+/// `users` gains the fields shown on `/author/{slug}` pages:
+/// - bio: free-form text
+/// - website_url: personal site
+/// - social_links: jsonb array of `{ "label", "url" }` objects
+/// - profile_slug: URL handle, unique when set
+///
+/// `post_authors` credits additional authors on a post. `posts.author_id`
+/// stays the owner (and the only one allowed to edit as an author); the rows
+/// here are listed after the owner, ordered by `position`.
+///
+/// Indexes:
+/// - idx_users_profile_slug_unique (profile_slug)
+/// - idx_post_authors_post_user_unique (post_id, user_id)
+/// - idx_post_authors_user_id (user_id) for author pages and feeds
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(ColumnDef::new(Users::Bio).text())
+                    .add_column(ColumnDef::new(Users::WebsiteUrl).text())
+                    .add_column(
+                        ColumnDef::new(Users::SocialLinks)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .add_column(ColumnDef::new(Users::ProfileSlug).string_len(64))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_profile_slug_unique")
+                    .table(Users::Table)
+                    .col(Users::ProfileSlug)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostAuthors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostAuthors::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostAuthors::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostAuthors::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(PostAuthors::Position)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PostAuthors::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_authors_post_id")
+                            .from(PostAuthors::Table, PostAuthors::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_post_authors_user_id")
+                            .from(PostAuthors::Table, PostAuthors::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_authors_post_user_unique")
+                    .table(PostAuthors::Table)
+                    .col(PostAuthors::PostId)
+                    .col(PostAuthors::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_authors_user_id")
+                    .table(PostAuthors::Table)
+                    .col(PostAuthors::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostAuthors::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_users_profile_slug_unique")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Bio)
+                    .drop_column(Users::WebsiteUrl)
+                    .drop_column(Users::SocialLinks)
+                    .drop_column(Users::ProfileSlug)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+    Bio,
+    WebsiteUrl,
+    SocialLinks,
+    ProfileSlug,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum PostAuthors {
+    Table,
+    Id,
+    PostId,
+    UserId,
+    Position,
+    CreatedAt,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::prelude::*;
+
+/// Comment moderation queue.
+///
+/// `post_comments` gains:
+/// - status: `pending`, `approved` or `rejected`; existing rows are approved
+/// - spam_score: score from the local spam scorer when the comment was
+///   created or last edited
+/// - spam_reasons: jsonb array of the signals that contributed to the score
+/// - moderated_by / moderated_at: the admin who last approved or rejected it
+///
+/// Indexes:
+/// - idx_post_comments_status_created_at (status, created_at) for the queue
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostComments::Table)
+                    .add_column(
+                        ColumnDef::new(PostComments::Status)
+                            .string_len(10)
+                            .not_null()
+                            .default("approved"),
+                    )
+                    .add_column(
+                        ColumnDef::new(PostComments::SpamScore)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(PostComments::SpamReasons)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .add_column(ColumnDef::new(PostComments::ModeratedBy).integer())
+                    .add_column(
+                        ColumnDef::new(PostComments::ModeratedAt).timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_post_comments_moderated_by")
+                    .from(PostComments::Table, PostComments::ModeratedBy)
+                    .to(Users::Table, Users::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_comments_status_created_at")
+                    .table(PostComments::Table)
+                    .col(PostComments::Status)
+                    .col(PostComments::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_comments_status_created_at")
+                    .table(PostComments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_post_comments_moderated_by")
+                    .table(PostComments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostComments::Table)
+                    .drop_column(PostComments::Status)
+                    .drop_column(PostComments::SpamScore)
+                    .drop_column(PostComments::SpamReasons)
+                    .drop_column(PostComments::ModeratedBy)
+                    .drop_column(PostComments::ModeratedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum PostComments {
+    Table,
+    Status,
+    SpamScore,
+    SpamReasons,
+    ModeratedBy,
+    ModeratedAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
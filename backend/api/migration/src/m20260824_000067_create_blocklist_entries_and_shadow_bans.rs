@@ -0,0 +1,155 @@
+use sea_orm_migration::prelude::*;
+
+/// Admin-managed blocklists and shadow bans.
+///
+/// `blocklist_entries` holds one pattern per row:
+/// - kind: `word`, `regex`, `domain` or `email_domain`
+/// - pattern: the word/phrase, regex source or bare domain
+/// - note: why it was added
+///
+/// Words and regexes are checked against comment content and user names,
+/// domains against links in comments, email domains against registrations.
+///
+/// `user_bans.kind` separates full bans (`full`, the existing behaviour:
+/// no login) from shadow bans (`shadow`: the user keeps using the site but
+/// their comments are only visible to themselves).
+///
+/// Indexes:
+/// - idx_blocklist_entries_kind_pattern_unique (kind, pattern)
+/// - idx_user_bans_user_id_kind (user_id, kind)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlocklistEntries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BlocklistEntries::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BlocklistEntries::Kind)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BlocklistEntries::Pattern).text().not_null())
+                    .col(ColumnDef::new(BlocklistEntries::Note).text())
+                    .col(ColumnDef::new(BlocklistEntries::CreatedBy).integer())
+                    .col(
+                        ColumnDef::new(BlocklistEntries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(BlocklistEntries::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_blocklist_entries_created_by")
+                            .from(BlocklistEntries::Table, BlocklistEntries::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_blocklist_entries_kind_pattern_unique")
+                    .table(BlocklistEntries::Table)
+                    .col(BlocklistEntries::Kind)
+                    .col(BlocklistEntries::Pattern)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserBans::Table)
+                    .add_column(
+                        ColumnDef::new(UserBans::Kind)
+                            .string_len(10)
+                            .not_null()
+                            .default("full"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_bans_user_id_kind")
+                    .table(UserBans::Table)
+                    .col(UserBans::UserId)
+                    .col(UserBans::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_user_bans_user_id_kind")
+                    .table(UserBans::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserBans::Table)
+                    .drop_column(UserBans::Kind)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BlocklistEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum BlocklistEntries {
+    Table,
+    Id,
+    Kind,
+    Pattern,
+    Note,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum UserBans {
+    Table,
+    UserId,
+    Kind,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
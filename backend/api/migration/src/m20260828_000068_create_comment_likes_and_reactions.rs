@@ -0,0 +1,199 @@
+use sea_orm_migration::prelude::*;
+
+/// Comment likes and emoji reactions.
+///
+/// `comment_likes` mirrors `post_likes`: one row per (comment, user), with
+/// `post_comments.likes_count` kept in step by the like/unlike actions.
+///
+/// `comment_reactions` holds one row per (comment, user, reaction); a user
+/// may leave several different reactions on the same comment but each only
+/// once. reaction is `love`, `laugh`, `wow`, `sad` or `celebrate`.
+///
+/// Indexes:
+/// - idx_comment_likes_comment_user_unique (comment_id, user_id)
+/// - idx_comment_likes_user_id (user_id)
+/// - idx_comment_reactions_comment_user_reaction_unique
+///   (comment_id, user_id, reaction)
+/// - idx_comment_reactions_user_id (user_id)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentLikes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentLikes::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentLikes::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentLikes::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentLikes::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_likes_comment")
+                            .from(CommentLikes::Table, CommentLikes::CommentId)
+                            .to(PostComments::Table, PostComments::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_likes_user")
+                            .from(CommentLikes::Table, CommentLikes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_likes_comment_user_unique")
+                    .table(CommentLikes::Table)
+                    .col(CommentLikes::CommentId)
+                    .col(CommentLikes::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_likes_user_id")
+                    .table(CommentLikes::Table)
+                    .col(CommentLikes::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentReactions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentReactions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentReactions::CommentId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CommentReactions::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CommentReactions::Reaction)
+                            .string_len(16)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommentReactions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_reactions_comment")
+                            .from(CommentReactions::Table, CommentReactions::CommentId)
+                            .to(PostComments::Table, PostComments::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_reactions_user")
+                            .from(CommentReactions::Table, CommentReactions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_reactions_comment_user_reaction_unique")
+                    .table(CommentReactions::Table)
+                    .col(CommentReactions::CommentId)
+                    .col(CommentReactions::UserId)
+                    .col(CommentReactions::Reaction)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_reactions_user_id")
+                    .table(CommentReactions::Table)
+                    .col(CommentReactions::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentReactions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CommentLikes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CommentLikes {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum CommentReactions {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    Reaction,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum PostComments {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,298 @@
+use sea_orm_migration::prelude::*;
+
+/// Email notifications.
+///
+/// `notification_preferences` holds one row per user, created the first time
+/// the user reads their settings or is sent a notification:
+/// - one flag per notification kind (comment_on_post, comment_reply,
+///   comment_mention, new_user, payment_received, subscription_canceled,
+///   newsletter_subscriber, contact_form)
+/// - email_enabled / in_app_enabled: channel switches
+/// - email_delivery: `immediate` or `digest`
+/// - unsubscribe_token: secret for the one-click unsubscribe link
+///
+/// `notification_digest_items` queues notifications for users on digest
+/// delivery until the digest worker sends them (sent_at).
+///
+/// `post_comments` gains parent_id so replies can notify the parent's author;
+/// deleting a parent keeps its replies.
+///
+/// Indexes:
+/// - idx_notification_preferences_unsubscribe_token_unique (unsubscribe_token)
+/// - idx_notification_digest_items_user_sent (user_id, sent_at)
+/// - idx_post_comments_parent_id (parent_id)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreferences::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UserId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(flag(NotificationPreferences::CommentOnPost, true))
+                    .col(flag(NotificationPreferences::CommentReply, true))
+                    .col(flag(NotificationPreferences::CommentMention, true))
+                    .col(flag(NotificationPreferences::NewUser, true))
+                    .col(flag(NotificationPreferences::PaymentReceived, true))
+                    .col(flag(NotificationPreferences::SubscriptionCanceled, true))
+                    .col(flag(NotificationPreferences::NewsletterSubscriber, false))
+                    .col(flag(NotificationPreferences::ContactForm, true))
+                    .col(flag(NotificationPreferences::EmailEnabled, true))
+                    .col(flag(NotificationPreferences::InAppEnabled, true))
+                    .col(
+                        ColumnDef::new(NotificationPreferences::EmailDelivery)
+                            .string_len(16)
+                            .not_null()
+                            .default("immediate"),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UnsubscribeToken)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preferences_user")
+                            .from(
+                                NotificationPreferences::Table,
+                                NotificationPreferences::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_preferences_unsubscribe_token_unique")
+                    .table(NotificationPreferences::Table)
+                    .col(NotificationPreferences::UnsubscribeToken)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationDigestItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Kind)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Subject)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::Body)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NotificationDigestItems::Link).text())
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDigestItems::SentAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_digest_items_user")
+                            .from(
+                                NotificationDigestItems::Table,
+                                NotificationDigestItems::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_digest_items_user_sent")
+                    .table(NotificationDigestItems::Table)
+                    .col(NotificationDigestItems::UserId)
+                    .col(NotificationDigestItems::SentAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostComments::Table)
+                    .add_column(ColumnDef::new(PostComments::ParentId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_post_comments_parent")
+                    .from(PostComments::Table, PostComments::ParentId)
+                    .to(PostComments::Table, PostComments::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_comments_parent_id")
+                    .table(PostComments::Table)
+                    .col(PostComments::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_comments_parent_id")
+                    .table(PostComments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_post_comments_parent")
+                    .table(PostComments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PostComments::Table)
+                    .drop_column(PostComments::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationDigestItems::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationPreferences::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+fn flag(column: NotificationPreferences, default: bool) -> ColumnDef {
+    ColumnDef::new(column)
+        .boolean()
+        .not_null()
+        .default(default)
+        .to_owned()
+}
+
+#[derive(Iden)]
+enum NotificationPreferences {
+    Table,
+    UserId,
+    CommentOnPost,
+    CommentReply,
+    CommentMention,
+    NewUser,
+    PaymentReceived,
+    SubscriptionCanceled,
+    NewsletterSubscriber,
+    ContactForm,
+    EmailEnabled,
+    InAppEnabled,
+    EmailDelivery,
+    UnsubscribeToken,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum NotificationDigestItems {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    Subject,
+    Body,
+    Link,
+    CreatedAt,
+    SentAt,
+}
+
+#[derive(Iden)]
+enum PostComments {
+    Table,
+    Id,
+    ParentId,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,106 @@
+use sea_orm_migration::prelude::*;
+
+/// In-app notifications.
+///
+/// One row per notification shown in a user's notification center:
+/// - kind: notification kind (same values as notification_digest_items.kind)
+/// - subject / body / link: what the bell dropdown shows and where it goes
+/// - read_at: set when the user opens or dismisses it
+///
+/// Indexes:
+/// - idx_notifications_user_created (user_id, created_at)
+/// - idx_notifications_user_read (user_id, read_at)
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notifications::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Notifications::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Notifications::Kind)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Notifications::Subject).text().not_null())
+                    .col(ColumnDef::new(Notifications::Body).text().not_null())
+                    .col(ColumnDef::new(Notifications::Link).text())
+                    .col(ColumnDef::new(Notifications::ReadAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(Notifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_user")
+                            .from(Notifications::Table, Notifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_created")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .col(Notifications::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_read")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .col(Notifications::ReadAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Notifications::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Notifications {
+    Table,
+    Id,
+    UserId,
+    Kind,
+    Subject,
+    Body,
+    Link,
+    ReadAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
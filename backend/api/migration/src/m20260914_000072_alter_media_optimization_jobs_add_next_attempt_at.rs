@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+/// Adds `media_optimization_jobs.next_attempt_at` (timestamptz, nullable):
+/// a failed job goes back to `pending` with this set to a backoff deadline,
+/// and the worker leaves it alone until then. NULL means due now.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaOptimizationJobs::Table)
+                    .add_column(
+                        ColumnDef::new(MediaOptimizationJobs::NextAttemptAt)
+                            .timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaOptimizationJobs::Table)
+                    .drop_column(MediaOptimizationJobs::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum MediaOptimizationJobs {
+    Table,
+    NextAttemptAt,
+}
@@ -0,0 +1,135 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{FromQueryResult, JsonValue, Statement};
+
+/// Adds `posts.search_text` (text, nullable): the post body as plain text,
+/// rendered from the EditorJS `content` by `ruxlog_types::editorjs`. The
+/// backend keeps it in step on every save; here existing posts are
+/// backfilled.
+///
+/// `posts_search_vector_update()` now also indexes it (weight D) and the
+/// trigger fires on changes to it, so search covers post bodies rather than
+/// only title, excerpt and slug.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(FromQueryResult)]
+struct PostContent {
+    id: i32,
+    content: JsonValue,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+
+        db.execute(Statement::from_string(
+            backend,
+            "ALTER TABLE posts ADD COLUMN IF NOT EXISTS search_text TEXT".to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            r#"
+            CREATE OR REPLACE FUNCTION posts_search_vector_update() RETURNS trigger AS $$
+            BEGIN
+                NEW.search_vector :=
+                    setweight(to_tsvector('english', COALESCE(NEW.title, '')), 'A') ||
+                    setweight(to_tsvector('english', COALESCE(NEW.excerpt, '')), 'B') ||
+                    setweight(to_tsvector('english', COALESCE(NEW.slug, '')), 'C') ||
+                    setweight(to_tsvector('english', COALESCE(NEW.search_text, '')), 'D');
+                RETURN NEW;
+            END
+            $$ LANGUAGE plpgsql
+            "#
+            .to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            "DROP TRIGGER IF EXISTS posts_search_vector_trigger ON posts".to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            "CREATE TRIGGER posts_search_vector_trigger \
+                BEFORE INSERT OR UPDATE OF title, excerpt, slug, search_text ON posts \
+                FOR EACH ROW \
+                EXECUTE FUNCTION posts_search_vector_update()"
+                .to_string(),
+        ))
+        .await?;
+
+        // Backfill through the same renderer the backend uses; the trigger
+        // refreshes each row's search_vector.
+        let posts = PostContent::find_by_statement(Statement::from_string(
+            backend,
+            "SELECT id, content FROM posts WHERE search_text IS NULL".to_string(),
+        ))
+        .all(db)
+        .await?;
+        for post in posts {
+            db.execute(Statement::from_sql_and_values(
+                backend,
+                "UPDATE posts SET search_text = $1 WHERE id = $2",
+                [
+                    ruxlog_types::editorjs::to_plain_text(&post.content).into(),
+                    post.id.into(),
+                ],
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+
+        db.execute(Statement::from_string(
+            backend,
+            r#"
+            CREATE OR REPLACE FUNCTION posts_search_vector_update() RETURNS trigger AS $$
+            BEGIN
+                NEW.search_vector :=
+                    setweight(to_tsvector('english', COALESCE(NEW.title, '')), 'A') ||
+                    setweight(to_tsvector('english', COALESCE(NEW.excerpt, '')), 'B') ||
+                    setweight(to_tsvector('english', COALESCE(NEW.slug, '')), 'C');
+                RETURN NEW;
+            END
+            $$ LANGUAGE plpgsql
+            "#
+            .to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            "DROP TRIGGER IF EXISTS posts_search_vector_trigger ON posts".to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            "CREATE TRIGGER posts_search_vector_trigger \
+                BEFORE INSERT OR UPDATE OF title, excerpt, slug ON posts \
+                FOR EACH ROW \
+                EXECUTE FUNCTION posts_search_vector_update()"
+                .to_string(),
+        ))
+        .await?;
+
+        db.execute(Statement::from_string(
+            backend,
+            "ALTER TABLE posts DROP COLUMN IF EXISTS search_text".to_string(),
+        ))
+        .await?;
+
+        Ok(())
+    }
+}
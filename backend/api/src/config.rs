@@ -2,4 +2,15 @@ pub mod body_limits {
     pub const DEFAULT: usize = 64 * 1024; // 64 KiB
     pub const POST: usize = 256 * 1024; // 256 KiB
     pub const MEDIA: usize = 2 * 1024 * 1024; // 2 MiB
+    /// Largest file of each kind accepted through the direct (multipart)
+    /// upload flow.
+    pub const MEDIA_IMAGE: usize = 64 * 1024 * 1024; // 64 MiB
+    pub const MEDIA_VIDEO: usize = 256 * 1024 * 1024; // 256 MiB
+    pub const MEDIA_AUDIO: usize = 64 * 1024 * 1024; // 64 MiB
+    pub const MEDIA_DOCUMENT: usize = 32 * 1024 * 1024; // 32 MiB
+    /// Largest finished direct upload read into memory to be probed and
+    /// optimized. Bigger ones are hashed as a stream and stored as uploaded.
+    pub const MEDIA_BUFFERED: usize = 32 * 1024 * 1024; // 32 MiB
+    /// Size of each direct-upload part; S3 rejects non-final parts below 5 MiB.
+    pub const MEDIA_UPLOAD_PART: usize = 5 * 1024 * 1024; // 5 MiB
 }
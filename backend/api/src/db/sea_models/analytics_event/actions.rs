@@ -0,0 +1,34 @@
+use sea_orm::{entity::prelude::*, Set};
+
+use crate::error::{DbResult, ErrorResponse};
+
+use super::{
+    model::{ActiveModel, Entity},
+    Model, NewAnalyticsEvent,
+};
+
+impl Entity {
+    pub async fn create(conn: &DbConn, event: NewAnalyticsEvent) -> DbResult<Model> {
+        ActiveModel {
+            kind: Set(event.kind),
+            path: Set(event.path),
+            post_id: Set(event.post_id),
+            referrer_host: Set(event.referrer_host),
+            utm_source: Set(event.utm.source),
+            utm_medium: Set(event.utm.medium),
+            utm_campaign: Set(event.utm.campaign),
+            utm_term: Set(event.utm.term),
+            utm_content: Set(event.utm.content),
+            device_class: Set(event.device_class),
+            country_code: Set(event.country_code),
+            visitor_id: Set(event.visitor_id),
+            scroll_depth: Set(event.scroll_depth),
+            engaged_ms: Set(event.engaged_ms),
+            created_at: Set(chrono::Utc::now().fixed_offset()),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await
+        .map_err(ErrorResponse::from)
+    }
+}
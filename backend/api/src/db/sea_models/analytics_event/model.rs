@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::{AnalyticsEventKind, DeviceClass};
+
+/// One first-party traffic event. No IP or user id is stored; `visitor_id`
+/// is a keyed hash that rotates daily, so visitors can be counted within a
+/// day but not followed across days.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "analytics_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub kind: AnalyticsEventKind,
+    pub path: String,
+    pub post_id: Option<i32>,
+    /// External referrer, lowercased and without `www.`; `None` is direct.
+    pub referrer_host: Option<String>,
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub utm_term: Option<String>,
+    pub utm_content: Option<String>,
+    pub device_class: DeviceClass,
+    pub country_code: Option<String>,
+    pub visitor_id: String,
+    /// Deepest scroll position reached, in percent (engagement events only).
+    pub scroll_depth: Option<i16>,
+    /// Active reading time since the previous heartbeat (engagement events
+    /// only).
+    pub engaged_ms: Option<i32>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,25 @@
+use super::{AnalyticsEventKind, DeviceClass};
+
+/// Campaign parameters read from the landing URL's query string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UtmParams {
+    pub source: Option<String>,
+    pub medium: Option<String>,
+    pub campaign: Option<String>,
+    pub term: Option<String>,
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAnalyticsEvent {
+    pub kind: AnalyticsEventKind,
+    pub path: String,
+    pub post_id: Option<i32>,
+    pub referrer_host: Option<String>,
+    pub utm: UtmParams,
+    pub device_class: DeviceClass,
+    pub country_code: Option<String>,
+    pub visitor_id: String,
+    pub scroll_depth: Option<i16>,
+    pub engaged_ms: Option<i32>,
+}
@@ -0,0 +1,100 @@
+use sea_orm::{entity::prelude::*, QueryOrder, Set};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+impl Entity {
+    pub const PER_PAGE: u64 = 20;
+
+    pub async fn create(conn: &DbConn, new_entry: NewBlocklistEntry) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let entry = ActiveModel {
+            kind: Set(new_entry.kind),
+            pattern: Set(new_entry.pattern),
+            note: Set(new_entry.note),
+            created_by: Set(new_entry.created_by),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        entry.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    pub async fn update(
+        conn: &DbConn,
+        entry_id: i32,
+        update: UpdateBlocklistEntry,
+    ) -> DbResult<Option<Model>> {
+        let Some(existing) = Self::find_by_id(entry_id).one(conn).await? else {
+            return Ok(None);
+        };
+        let mut active: ActiveModel = existing.into();
+        if let Some(pattern) = update.pattern {
+            active.pattern = Set(pattern);
+        }
+        if let Some(note) = update.note {
+            active.note = Set(Some(note));
+        }
+        active.updated_at = Set(update.updated_at);
+
+        active
+            .update(conn)
+            .await
+            .map(Some)
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn delete(conn: &DbConn, entry_id: i32) -> DbResult<u64> {
+        let result = Self::delete_by_id(entry_id).exec(conn).await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Every entry, for compiling the blocklist that content is checked
+    /// against.
+    pub async fn find_all(conn: &DbConn) -> DbResult<Vec<Model>> {
+        Ok(Self::find().order_by_asc(Column::Id).all(conn).await?)
+    }
+
+    pub async fn find_with_query(
+        conn: &DbConn,
+        query: BlocklistQuery,
+    ) -> DbResult<(Vec<Model>, u64)> {
+        let mut entry_query = Self::find();
+
+        if let Some(search_term) = query.search {
+            let search_pattern = format!("%{}%", search_term.to_lowercase());
+            entry_query = entry_query.filter(Column::Pattern.like(&search_pattern));
+        }
+        if let Some(kind) = query.kind {
+            entry_query = entry_query.filter(Column::Kind.eq(kind));
+        }
+
+        match query.sorts {
+            Some(sorts) => {
+                for sort in sorts {
+                    let column = match sort.field.as_str() {
+                        "id" => Some(Column::Id),
+                        "kind" => Some(Column::Kind),
+                        "pattern" => Some(Column::Pattern),
+                        "created_at" => Some(Column::CreatedAt),
+                        "updated_at" => Some(Column::UpdatedAt),
+                        _ => None,
+                    };
+                    if let Some(col) = column {
+                        entry_query = entry_query.order_by(col, sort.order);
+                    }
+                }
+            }
+            None => entry_query = entry_query.order_by_desc(Column::UpdatedAt),
+        }
+
+        let page = match query.page {
+            Some(p) if p > 0 => p,
+            _ => 1,
+        };
+        let paginator = entry_query.paginate(conn, Self::PER_PAGE);
+        let total = paginator.num_items().await?;
+        let results = paginator.fetch_page(page - 1).await?;
+        Ok((results, total))
+    }
+}
@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::BlocklistKind;
+
+/// One admin-managed blocklist pattern. See `services::blocklist` for how
+/// each kind is matched.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "blocklist_entries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub kind: BlocklistKind,
+    pub pattern: String,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::CreatedBy",
+        to = "super::super::user::Column::Id",
+        on_delete = "SetNull"
+    )]
+    CreatedByUser,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,28 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::{Deserialize, Serialize};
+
+use super::BlocklistKind;
+use crate::utils::SortParam;
+
+#[derive(Deserialize, Debug)]
+pub struct NewBlocklistEntry {
+    pub kind: BlocklistKind,
+    pub pattern: String,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateBlocklistEntry {
+    pub pattern: Option<String>,
+    pub note: Option<String>,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct BlocklistQuery {
+    pub page: Option<u64>,
+    pub search: Option<String>,
+    pub kind: Option<BlocklistKind>,
+    pub sorts: Option<Vec<SortParam>>,
+}
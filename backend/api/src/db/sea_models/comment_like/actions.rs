@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use crate::error::DbResult;
+use sea_orm::{entity::prelude::*, sea_query::OnConflict, QuerySelect, Set, TransactionTrait};
+use tracing::{info, instrument, warn};
+
+use super::super::{comment_reaction, post_comment};
+use super::*;
+
+impl Entity {
+    /// Like a comment, keeping `post_comments.likes_count` in step.
+    /// Returns (changed, likes_count); liking twice is a no-op.
+    #[instrument(skip(conn), fields(comment_id, user_id))]
+    pub async fn like_comment(
+        conn: &DbConn,
+        comment_id: i32,
+        user_id: i32,
+    ) -> DbResult<(bool, i32)> {
+        let transaction = conn.begin().await?;
+
+        let like = ActiveModel {
+            comment_id: Set(comment_id),
+            user_id: Set(user_id),
+            created_at: Set(chrono::Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+        let inserted = Self::insert(like)
+            .on_conflict(
+                OnConflict::columns([Column::CommentId, Column::UserId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&transaction)
+            .await?;
+
+        if inserted > 0 {
+            post_comment::Entity::update_many()
+                .col_expr(
+                    post_comment::Column::LikesCount,
+                    Expr::col(post_comment::Column::LikesCount).add(1),
+                )
+                .filter(post_comment::Column::Id.eq(comment_id))
+                .exec(&transaction)
+                .await?;
+        } else {
+            warn!(comment_id, user_id, "User already liked this comment");
+        }
+
+        let likes_count = Self::current_likes_count(&transaction, comment_id).await?;
+        transaction.commit().await?;
+        if inserted > 0 {
+            info!(comment_id, user_id, likes_count, "Comment liked");
+        }
+        Ok((inserted > 0, likes_count))
+    }
+
+    /// Remove a like, never taking `likes_count` below zero.
+    /// Returns (changed, likes_count).
+    #[instrument(skip(conn), fields(comment_id, user_id))]
+    pub async fn unlike_comment(
+        conn: &DbConn,
+        comment_id: i32,
+        user_id: i32,
+    ) -> DbResult<(bool, i32)> {
+        let transaction = conn.begin().await?;
+
+        let deleted = Self::delete_many()
+            .filter(Column::CommentId.eq(comment_id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(&transaction)
+            .await?
+            .rows_affected;
+
+        if deleted > 0 {
+            post_comment::Entity::update_many()
+                .col_expr(
+                    post_comment::Column::LikesCount,
+                    Expr::cust("GREATEST(likes_count - 1, 0)"),
+                )
+                .filter(post_comment::Column::Id.eq(comment_id))
+                .exec(&transaction)
+                .await?;
+        } else {
+            warn!(comment_id, user_id, "User hasn't liked this comment");
+        }
+
+        let likes_count = Self::current_likes_count(&transaction, comment_id).await?;
+        transaction.commit().await?;
+        if deleted > 0 {
+            info!(comment_id, user_id, likes_count, "Comment unliked");
+        }
+        Ok((deleted > 0, likes_count))
+    }
+
+    async fn current_likes_count<C: ConnectionTrait>(conn: &C, comment_id: i32) -> DbResult<i32> {
+        let count: Option<i32> = post_comment::Entity::find_by_id(comment_id)
+            .select_only()
+            .column(post_comment::Column::LikesCount)
+            .into_tuple()
+            .one(conn)
+            .await?;
+        Ok(count.unwrap_or(0))
+    }
+
+    /// Like status and the viewer's reactions for several comments. Unknown
+    /// comment ids are left out.
+    #[instrument(skip(conn), fields(user_id, comment_count = comment_ids.len()))]
+    pub async fn get_like_status_batch(
+        conn: &DbConn,
+        comment_ids: &[i32],
+        user_id: i32,
+    ) -> DbResult<Vec<CommentLikeStatus>> {
+        let liked: HashSet<i32> = Self::find()
+            .select_only()
+            .column(Column::CommentId)
+            .filter(Column::CommentId.is_in(comment_ids.to_vec()))
+            .filter(Column::UserId.eq(user_id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut reactions =
+            comment_reaction::Entity::user_reactions(conn, comment_ids, user_id).await?;
+
+        let comments: Vec<(i32, i32)> = post_comment::Entity::find()
+            .select_only()
+            .column(post_comment::Column::Id)
+            .column(post_comment::Column::LikesCount)
+            .filter(post_comment::Column::Id.is_in(comment_ids.to_vec()))
+            .into_tuple()
+            .all(conn)
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(|(comment_id, likes_count)| CommentLikeStatus {
+                comment_id,
+                is_liked: liked.contains(&comment_id),
+                likes_count,
+                reactions: reactions.remove(&comment_id).unwrap_or_default(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use super::super::comment_reaction::CommentReaction;
+
+/// The viewer's like and reactions on one comment
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentLikeStatus {
+    pub comment_id: i32,
+    pub is_liked: bool,
+    pub likes_count: i32,
+    pub reactions: Vec<CommentReaction>,
+}
+
+/// Response for like/unlike action
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentLikeActionResponse {
+    pub comment_id: i32,
+    pub is_liked: bool,
+    pub likes_count: i32,
+    pub message: String,
+}
+
+/// Request to check like status for multiple comments
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommentLikeStatusBatchRequest {
+    pub comment_ids: Vec<i32>,
+}
+
+impl Validate for CommentLikeStatusBatchRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        if self.comment_ids.is_empty() {
+            errors.add(
+                "comment_ids",
+                ValidationError::new("length").with_message("comment_ids must not be empty".into()),
+            );
+        }
+
+        // Matches the public comment list cap in `find_all_by_post`.
+        if self.comment_ids.len() > 500 {
+            errors.add(
+                "comment_ids",
+                ValidationError::new("length")
+                    .with_message("comment_ids must not exceed 500 items".into()),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Response with like status for multiple comments
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommentLikeStatusBatchResponse {
+    pub statuses: Vec<CommentLikeStatus>,
+}
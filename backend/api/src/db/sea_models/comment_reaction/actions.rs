@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::error::DbResult;
+use sea_orm::{entity::prelude::*, sea_query::OnConflict, QueryOrder, QuerySelect, Set};
+use tracing::instrument;
+
+use super::*;
+
+impl Entity {
+    /// Add a reaction; returns false when the user already left it.
+    #[instrument(skip(conn), fields(comment_id, user_id))]
+    pub async fn add(
+        conn: &DbConn,
+        comment_id: i32,
+        user_id: i32,
+        reaction: CommentReaction,
+    ) -> DbResult<bool> {
+        let row = ActiveModel {
+            comment_id: Set(comment_id),
+            user_id: Set(user_id),
+            reaction: Set(reaction),
+            created_at: Set(chrono::Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+        let inserted = Self::insert(row)
+            .on_conflict(
+                OnConflict::columns([Column::CommentId, Column::UserId, Column::Reaction])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(conn)
+            .await?;
+        Ok(inserted > 0)
+    }
+
+    /// Remove a reaction; returns false when there was none.
+    #[instrument(skip(conn), fields(comment_id, user_id))]
+    pub async fn remove(
+        conn: &DbConn,
+        comment_id: i32,
+        user_id: i32,
+        reaction: CommentReaction,
+    ) -> DbResult<bool> {
+        let result = Self::delete_many()
+            .filter(Column::CommentId.eq(comment_id))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Reaction.eq(reaction))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    pub async fn count(conn: &DbConn, comment_id: i32, reaction: CommentReaction) -> DbResult<i64> {
+        let count = Self::find()
+            .filter(Column::CommentId.eq(comment_id))
+            .filter(Column::Reaction.eq(reaction))
+            .count(conn)
+            .await?;
+        Ok(count as i64)
+    }
+
+    /// Reaction totals per comment, in reaction order.
+    pub async fn counts_by_comment(
+        conn: &DbConn,
+        comment_ids: &[i32],
+    ) -> DbResult<HashMap<i32, Vec<ReactionCount>>> {
+        if comment_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let rows: Vec<(i32, CommentReaction, i64)> = Self::find()
+            .select_only()
+            .column(Column::CommentId)
+            .column(Column::Reaction)
+            .column_as(Column::Id.count(), "count")
+            .filter(Column::CommentId.is_in(comment_ids.to_vec()))
+            .group_by(Column::CommentId)
+            .group_by(Column::Reaction)
+            .order_by_asc(Column::CommentId)
+            .into_tuple()
+            .all(conn)
+            .await?;
+
+        let mut counts: HashMap<i32, Vec<ReactionCount>> = HashMap::new();
+        for (comment_id, reaction, count) in rows {
+            counts
+                .entry(comment_id)
+                .or_default()
+                .push(ReactionCount { reaction, count });
+        }
+        for list in counts.values_mut() {
+            list.sort_by_key(|c| c.reaction);
+        }
+        Ok(counts)
+    }
+
+    /// The reactions `user_id` left on each of `comment_ids`.
+    pub async fn user_reactions(
+        conn: &DbConn,
+        comment_ids: &[i32],
+        user_id: i32,
+    ) -> DbResult<HashMap<i32, Vec<CommentReaction>>> {
+        let rows: Vec<(i32, CommentReaction)> = Self::find()
+            .select_only()
+            .column(Column::CommentId)
+            .column(Column::Reaction)
+            .filter(Column::CommentId.is_in(comment_ids.to_vec()))
+            .filter(Column::UserId.eq(user_id))
+            .into_tuple()
+            .all(conn)
+            .await?;
+
+        let mut reactions: HashMap<i32, Vec<CommentReaction>> = HashMap::new();
+        for (comment_id, reaction) in rows {
+            reactions.entry(comment_id).or_default().push(reaction);
+        }
+        for list in reactions.values_mut() {
+            list.sort();
+        }
+        Ok(reactions)
+    }
+}
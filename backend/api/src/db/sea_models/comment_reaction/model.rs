@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::CommentReaction;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "comment_reactions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub comment_id: i32,
+    pub user_id: i32,
+    pub reaction: CommentReaction,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::post_comment::Entity",
+        from = "Column::CommentId",
+        to = "super::super::post_comment::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Comment,
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::UserId",
+        to = "super::super::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::super::post_comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::CommentReaction;
+
+/// How many users left one reaction on a comment
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReactionCount {
+    pub reaction: CommentReaction,
+    pub count: i64,
+}
+
+/// Response for react/unreact action
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentReactionResponse {
+    pub comment_id: i32,
+    pub reaction: CommentReaction,
+    pub reacted: bool,
+    pub count: i64,
+}
@@ -1,13 +1,24 @@
+use std::collections::HashSet;
+
 use crate::error::{DbResult, ErrorResponse};
-use sea_orm::{entity::prelude::*, Condition, Order, QueryOrder, QuerySelect, Set};
+use sea_orm::{
+    entity::prelude::*, Condition, DatabaseBackend, FromQueryResult, Order, QueryOrder,
+    QuerySelect, Set, Statement,
+};
 use tracing::{error, info, instrument, warn};
 
-use super::super::media_usage;
+use super::super::{category, media_usage, media_variant, post, seo_override, user};
 use super::{
     model::{ActiveModel, Column, Entity},
-    slice::MediaWithUsage,
-    MediaQuery, MediaReference, Model, NewMedia,
+    slice::{MediaReferenceInfo, MediaWithUsage},
+    MediaOptimizationFilter, MediaQuery, MediaReference, Model, NewMedia, UpdateMediaDetails,
 };
+use media_usage::EntityType;
+
+#[derive(Debug, FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
 
 impl Entity {
     pub const PER_PAGE: u64 = 20;
@@ -28,6 +39,15 @@ impl Entity {
             content_hash: Set(payload.content_hash),
             is_optimized: Set(payload.is_optimized),
             optimized_at: Set(payload.optimized_at),
+            alt_text: Set(payload.alt_text),
+            caption: Set(payload.caption),
+            credit: Set(payload.credit),
+            license: Set(payload.license),
+            focal_x: Set(payload.focal_x),
+            focal_y: Set(payload.focal_y),
+            blurhash: Set(payload.blurhash),
+            duration_ms: Set(payload.duration_ms),
+            page_count: Set(payload.page_count),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -62,6 +82,56 @@ impl Entity {
             .map_err(ErrorResponse::from)
     }
 
+    #[instrument(skip(conn, media, update), fields(media_id = media.id))]
+    pub async fn update_details(
+        conn: &DbConn,
+        media: Model,
+        update: UpdateMediaDetails,
+    ) -> DbResult<Model> {
+        let mut active: ActiveModel = media.into();
+
+        if let Some(alt_text) = update.alt_text {
+            active.alt_text = Set(alt_text);
+        }
+        if let Some(caption) = update.caption {
+            active.caption = Set(caption);
+        }
+        if let Some(credit) = update.credit {
+            active.credit = Set(credit);
+        }
+        if let Some(license) = update.license {
+            active.license = Set(license);
+        }
+        if let Some(focal_point) = update.focal_point {
+            active.focal_x = Set(focal_point.map(|(x, _)| x));
+            active.focal_y = Set(focal_point.map(|(_, y)| y));
+        }
+        active.updated_at = Set(chrono::Utc::now().fixed_offset());
+
+        match active.update(conn).await {
+            Ok(model) => {
+                info!(media_id = model.id, "Media details updated");
+                Ok(model)
+            }
+            Err(err) => {
+                error!("Failed to update media details: {}", err);
+                Err(err.into())
+            }
+        }
+    }
+
+    #[instrument(skip(conn, ids), fields(count = ids.len()))]
+    pub async fn find_by_ids(conn: &DbConn, ids: &[i32]) -> DbResult<Vec<Model>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        <Self as EntityTrait>::find()
+            .filter(Column::Id.is_in(ids.iter().copied()))
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
     #[instrument(skip(conn), fields(hash, media_id))]
     pub async fn find_by_hash(conn: &DbConn, hash: &str) -> DbResult<Option<Model>> {
         <Self as EntityTrait>::find()
@@ -270,4 +340,252 @@ impl Entity {
             None => Ok(None),
         }
     }
+
+    /// Every place that still points at `media_id`.
+    ///
+    /// `media_usage` is the primary source, but post featured images and SEO
+    /// override OG images are not tracked there and EditorJS image blocks
+    /// embed the file URL directly in `posts.content`, so those are checked
+    /// as well.
+    #[instrument(skip(conn), fields(media_id))]
+    pub async fn find_references(
+        conn: &DbConn,
+        media_id: i32,
+    ) -> DbResult<Vec<MediaReferenceInfo>> {
+        let media = match Self::find_by_id(conn, media_id).await? {
+            Some(media) => media,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut references: Vec<MediaReferenceInfo> =
+            media_usage::Entity::find_by_media_id(conn, media_id)
+                .await?
+                .into_iter()
+                .map(|usage| {
+                    MediaReferenceInfo::new(usage.entity_type, usage.entity_id, usage.field_name)
+                })
+                .collect();
+
+        let featured_posts = post::Entity::find()
+            .select_only()
+            .column(post::Column::Id)
+            .filter(post::Column::FeaturedImageId.eq(media_id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        references.extend(
+            featured_posts
+                .into_iter()
+                .map(|id| MediaReferenceInfo::new(EntityType::Post, id, "featured_image_id")),
+        );
+
+        let avatar_users = user::Entity::find()
+            .select_only()
+            .column(user::Column::Id)
+            .filter(user::Column::AvatarId.eq(media_id))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        references.extend(
+            avatar_users
+                .into_iter()
+                .map(|id| MediaReferenceInfo::new(EntityType::User, id, "avatar_id")),
+        );
+
+        let categories = category::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(category::Column::CoverId.eq(media_id))
+                    .add(category::Column::LogoId.eq(media_id)),
+            )
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        for category in categories {
+            if category.cover_id == Some(media_id) {
+                references.push(MediaReferenceInfo::new(
+                    EntityType::Category,
+                    category.id,
+                    "cover_id",
+                ));
+            }
+            if category.logo_id == Some(media_id) {
+                references.push(MediaReferenceInfo::new(
+                    EntityType::Category,
+                    category.id,
+                    "logo_id",
+                ));
+            }
+        }
+
+        let seo_overrides = seo_override::Entity::find()
+            .filter(seo_override::Column::OgImageId.eq(media_id))
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        references.extend(
+            seo_overrides
+                .into_iter()
+                .map(|seo| MediaReferenceInfo::seo_og_image(seo.entity_type, seo.entity_id)),
+        );
+
+        let content_posts = IdRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "SELECT id FROM posts WHERE strpos(content::text, $1) > 0",
+            vec![media.object_key.clone().into()],
+        ))
+        .all(conn)
+        .await
+        .map_err(ErrorResponse::from)?;
+        references.extend(
+            content_posts
+                .into_iter()
+                .map(|row| MediaReferenceInfo::new(EntityType::Post, row.id, "content")),
+        );
+
+        Ok(MediaReferenceInfo::dedup(references))
+    }
+
+    /// Media rows older than `created_before` that nothing references, using
+    /// the same sources as [`Entity::find_references`]. Oldest first.
+    #[instrument(skip(conn))]
+    pub async fn find_unreferenced(
+        conn: &DbConn,
+        created_before: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> DbResult<Vec<Model>> {
+        let sql = r#"
+            SELECT m.*
+            FROM media m
+            WHERE m.created_at < $1
+              AND NOT EXISTS (SELECT 1 FROM media_usage mu WHERE mu.media_id = m.id)
+              AND NOT EXISTS (SELECT 1 FROM posts p WHERE p.featured_image_id = m.id)
+              AND NOT EXISTS (SELECT 1 FROM users u WHERE u.avatar_id = m.id)
+              AND NOT EXISTS (SELECT 1 FROM seo_overrides so WHERE so.og_image_id = m.id)
+              AND NOT EXISTS (
+                  SELECT 1 FROM categories c WHERE c.cover_id = m.id OR c.logo_id = m.id
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM posts p WHERE strpos(p.content::text, m.object_key) > 0
+              )
+            ORDER BY m.created_at ASC
+            LIMIT $2
+        "#;
+
+        Model::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            sql,
+            vec![created_before.into(), (limit as i64).into()],
+        ))
+        .all(conn)
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    /// All object keys the database knows about, originals and variants.
+    #[instrument(skip(conn))]
+    pub async fn all_object_keys(conn: &DbConn) -> DbResult<HashSet<String>> {
+        let mut keys: HashSet<String> = Self::find()
+            .select_only()
+            .column(Column::ObjectKey)
+            .into_tuple::<String>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?
+            .into_iter()
+            .collect();
+
+        let variant_keys = media_variant::Entity::find()
+            .select_only()
+            .column(media_variant::Column::ObjectKey)
+            .into_tuple::<String>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        keys.extend(variant_keys);
+
+        Ok(keys)
+    }
+
+    /// Ids of image media matching `filter`, oldest first, capped at `limit`.
+    #[instrument(skip(conn, filter))]
+    pub async fn find_ids_for_optimization(
+        conn: &DbConn,
+        filter: MediaOptimizationFilter,
+        limit: u64,
+    ) -> DbResult<Vec<i32>> {
+        let mut query = Self::find()
+            .select_only()
+            .column(Column::Id)
+            .filter(Column::MimeType.starts_with("image/"));
+
+        if let Some(ids) = filter.media_ids {
+            query = query.filter(Column::Id.is_in(ids));
+        }
+        if let Some(reference) = filter.reference_type {
+            query = query.filter(Column::ReferenceType.eq(reference));
+        }
+        if let Some(mime) = filter.mime_type {
+            query = query.filter(Column::MimeType.eq(mime.to_lowercase()));
+        }
+        if filter.only_unoptimized {
+            query = query.filter(Column::IsOptimized.eq(false));
+        }
+        if let Some(ts) = filter.optimized_before {
+            query = query.filter(
+                Condition::any()
+                    .add(Column::OptimizedAt.is_null())
+                    .add(Column::OptimizedAt.lt(ts)),
+            );
+        }
+        if let Some(ts) = filter.created_at_gt {
+            query = query.filter(Column::CreatedAt.gt(ts));
+        }
+        if let Some(ts) = filter.created_at_lt {
+            query = query.filter(Column::CreatedAt.lt(ts));
+        }
+
+        query
+            .order_by_asc(Column::Id)
+            .limit(limit)
+            .into_tuple::<i32>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Record a finished (re)optimization: dimensions of the stored original,
+    /// the placeholder hash and the optimization timestamp.
+    #[instrument(skip(conn, media, blurhash), fields(media_id = media.id))]
+    pub async fn mark_optimized(
+        conn: &DbConn,
+        media: Model,
+        width: Option<i32>,
+        height: Option<i32>,
+        blurhash: Option<String>,
+    ) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let mut active: ActiveModel = media.into();
+        if width.is_some() && height.is_some() {
+            active.width = Set(width);
+            active.height = Set(height);
+        }
+        if blurhash.is_some() {
+            active.blurhash = Set(blurhash);
+        }
+        active.is_optimized = Set(true);
+        active.optimized_at = Set(Some(now));
+        active.updated_at = Set(now);
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Replace the placeholder hash, e.g. after a new poster was uploaded.
+    pub async fn set_blurhash(conn: &DbConn, media: Model, blurhash: String) -> DbResult<Model> {
+        let mut active: ActiveModel = media.into();
+        active.blurhash = Set(Some(blurhash));
+        active.updated_at = Set(chrono::Utc::now().fixed_offset());
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
 }
@@ -1,5 +1,6 @@
 mod actions;
 pub mod model;
+pub mod responsive;
 pub mod slice;
 pub mod url;
 
@@ -1,9 +1,9 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-pub use ruxlog_types::enums::MediaReference;
+pub use ruxlog_types::enums::{MediaKind, MediaReference};
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "media")]
 pub struct Model {
     #[sea_orm(primary_key)]
@@ -20,6 +20,19 @@ pub struct Model {
     pub content_hash: Option<String>,
     pub is_optimized: bool,
     pub optimized_at: Option<DateTimeWithTimeZone>,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub license: Option<String>,
+    /// Normalized (0..1) horizontal focal point used when cropping variants.
+    pub focal_x: Option<f64>,
+    /// Normalized (0..1) vertical focal point used when cropping variants.
+    pub focal_y: Option<f64>,
+    pub blurhash: Option<String>,
+    /// Playback length of video and audio, from the media probe.
+    pub duration_ms: Option<i64>,
+    /// Number of pages of a PDF.
+    pub page_count: Option<i32>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -50,6 +63,13 @@ impl Model {
         }
     }
 
+    pub fn focal_point(&self) -> Option<(f64, f64)> {
+        match (self.focal_x, self.focal_y) {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None,
+        }
+    }
+
     pub fn with_usage(&self, usage_count: i64) -> super::slice::MediaWithUsage {
         super::slice::MediaWithUsage {
             media: self.clone(),
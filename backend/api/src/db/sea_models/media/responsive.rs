@@ -0,0 +1,248 @@
+//! Responsive image descriptors assembled from a media row and its optimizer
+//! variants. Clients render these as `<picture>`: one `<source>` per modern
+//! format (AVIF, WebP) plus an `<img>` fallback in the original format.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{model::Model, url::build_public_file_url};
+use crate::db::sea_models::media_variant;
+
+/// `sizes` used when the caller does not supply one: full viewport width on
+/// small screens, capped at the article column on larger ones.
+pub const DEFAULT_SIZES: &str = "(max-width: 768px) 100vw, 768px";
+
+/// Preferred `<source>` order; browsers take the first type they support.
+const MODERN_FORMATS: [&str; 2] = ["image/avif", "image/webp"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponsiveSource {
+    pub mime_type: String,
+    pub srcset: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResponsiveImage {
+    pub media_id: i32,
+    /// Fallback `<img src>`: the original upload.
+    pub src: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    /// Width candidates in the original's format, for the `<img>` itself.
+    pub srcset: Option<String>,
+    pub sizes: String,
+    /// Modern-format `<source>` elements, most efficient first.
+    pub sources: Vec<ResponsiveSource>,
+    pub blurhash: Option<String>,
+    /// URL of the tiny low-quality preview variant, when one was generated.
+    pub placeholder: Option<String>,
+}
+
+/// Build the responsive descriptor for `media` from its stored `variants`.
+/// Variants belonging to other media rows are ignored, so callers may pass a
+/// batch-loaded slice.
+pub fn build(
+    public_url: &str,
+    media: &Model,
+    variants: &[media_variant::Model],
+    sizes: Option<&str>,
+) -> ResponsiveImage {
+    let url_for = |key: &str| build_public_file_url(public_url, media.bucket.as_deref(), key);
+    let original_mime = media.mime_type.to_ascii_lowercase();
+
+    let mut by_mime: BTreeMap<String, Vec<(i32, String)>> = BTreeMap::new();
+    let mut placeholder = None;
+    for variant in variants.iter().filter(|v| v.media_id == media.id) {
+        if variant.variant_type == "lqip" {
+            placeholder = Some(url_for(&variant.object_key));
+            continue;
+        }
+        let Some(width) = variant_width(&variant.variant_type) else {
+            continue;
+        };
+        by_mime
+            .entry(variant.mime_type.to_ascii_lowercase())
+            .or_default()
+            .push((width, url_for(&variant.object_key)));
+    }
+
+    let sources = MODERN_FORMATS
+        .iter()
+        .filter(|mime| **mime != original_mime)
+        .filter_map(|mime| {
+            by_mime.get(*mime).map(|candidates| ResponsiveSource {
+                mime_type: (*mime).to_string(),
+                srcset: format_srcset(candidates.clone()),
+            })
+        })
+        .collect();
+
+    let src = url_for(&media.object_key);
+    let mut fallback = by_mime.remove(&original_mime).unwrap_or_default();
+    let srcset = if fallback.is_empty() {
+        None
+    } else {
+        if let Some(width) = media.width.filter(|w| *w > 0) {
+            fallback.push((width, src.clone()));
+        }
+        Some(format_srcset(fallback))
+    };
+
+    ResponsiveImage {
+        media_id: media.id,
+        src,
+        width: media.width,
+        height: media.height,
+        srcset,
+        sizes: sizes
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(DEFAULT_SIZES)
+            .to_string(),
+        sources,
+        blurhash: media.blurhash.clone(),
+        placeholder,
+    }
+}
+
+/// Parse the `"{width}w"` variant type written by the media ingest.
+fn variant_width(variant_type: &str) -> Option<i32> {
+    variant_type.strip_suffix('w')?.parse().ok()
+}
+
+fn format_srcset(mut candidates: Vec<(i32, String)>) -> String {
+    candidates.sort_by_key(|(width, _)| *width);
+    candidates.dedup_by_key(|(width, _)| *width);
+    candidates
+        .into_iter()
+        .map(|(width, url)| format!("{url} {width}w"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone};
+
+    fn now() -> sea_orm::prelude::DateTimeWithTimeZone {
+        FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
+            .unwrap()
+    }
+
+    fn media(mime_type: &str, width: Option<i32>) -> Model {
+        Model {
+            id: 7,
+            object_key: "posts/a.jpg".into(),
+            bucket: Some("media".into()),
+            mime_type: mime_type.into(),
+            width,
+            height: width.map(|w| w / 2),
+            size: 1,
+            extension: None,
+            uploader_id: None,
+            reference_type: None,
+            content_hash: None,
+            is_optimized: true,
+            optimized_at: None,
+            created_at: now(),
+            updated_at: now(),
+            alt_text: None,
+            caption: None,
+            credit: None,
+            license: None,
+            focal_x: None,
+            focal_y: None,
+            blurhash: Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj".into()),
+            duration_ms: None,
+            page_count: None,
+        }
+    }
+
+    fn variant(media_id: i32, mime: &str, variant_type: &str) -> media_variant::Model {
+        media_variant::Model {
+            id: 0,
+            media_id,
+            object_key: format!(
+                "posts/a@{variant_type}.{}",
+                mime.trim_start_matches("image/")
+            ),
+            mime_type: mime.into(),
+            width: None,
+            height: None,
+            size: 1,
+            extension: None,
+            quality: None,
+            variant_type: variant_type.into(),
+            created_at: now(),
+            updated_at: now(),
+        }
+    }
+
+    #[test]
+    fn groups_variants_into_sources_and_fallback() {
+        let variants = vec![
+            variant(7, "image/webp", "960w"),
+            variant(7, "image/webp", "480w"),
+            variant(7, "image/avif", "480w"),
+            variant(7, "image/jpeg", "480w"),
+            variant(7, "image/webp", "lqip"),
+            variant(8, "image/avif", "960w"),
+        ];
+        let image = build(
+            "https://cdn.test",
+            &media("image/jpeg", Some(1600)),
+            &variants,
+            None,
+        );
+
+        assert_eq!(image.src, "https://cdn.test/media/posts/a.jpg");
+        assert_eq!(image.sizes, DEFAULT_SIZES);
+        assert_eq!(image.sources.len(), 2);
+        assert_eq!(image.sources[0].mime_type, "image/avif");
+        assert_eq!(
+            image.sources[0].srcset,
+            "https://cdn.test/media/posts/a@480w.avif 480w"
+        );
+        assert_eq!(
+            image.sources[1].srcset,
+            "https://cdn.test/media/posts/a@480w.webp 480w, https://cdn.test/media/posts/a@960w.webp 960w"
+        );
+        assert_eq!(
+            image.srcset.as_deref(),
+            Some("https://cdn.test/media/posts/a@480w.jpeg 480w, https://cdn.test/media/posts/a.jpg 1600w")
+        );
+        assert_eq!(
+            image.placeholder.as_deref(),
+            Some("https://cdn.test/media/posts/a@lqip.webp")
+        );
+        assert!(image.blurhash.is_some());
+    }
+
+    #[test]
+    fn webp_original_is_not_repeated_as_a_source() {
+        let variants = vec![variant(7, "image/webp", "480w")];
+        let image = build(
+            "https://cdn.test",
+            &media("image/webp", Some(800)),
+            &variants,
+            Some("50vw"),
+        );
+
+        assert!(image.sources.is_empty());
+        assert_eq!(image.sizes, "50vw");
+        assert!(image.srcset.unwrap().ends_with("a.jpg 800w"));
+    }
+
+    #[test]
+    fn unoptimized_media_has_only_a_src() {
+        let image = build("https://cdn.test", &media("image/gif", None), &[], None);
+
+        assert!(image.sources.is_empty());
+        assert!(image.srcset.is_none());
+        assert!(image.placeholder.is_none());
+    }
+}
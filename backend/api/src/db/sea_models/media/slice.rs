@@ -3,6 +3,8 @@ use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 
 use super::MediaReference;
+use crate::db::sea_models::media_usage::EntityType;
+use crate::db::sea_models::seo_override::SeoEntityType;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct NewMedia {
@@ -18,6 +20,26 @@ pub struct NewMedia {
     pub content_hash: Option<String>,
     pub is_optimized: bool,
     pub optimized_at: Option<DateTimeWithTimeZone>,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub license: Option<String>,
+    pub focal_x: Option<f64>,
+    pub focal_y: Option<f64>,
+    pub blurhash: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub page_count: Option<i32>,
+}
+
+/// Editable descriptive fields. `None` leaves a field untouched; an empty
+/// string (or a cleared focal point) is normalized to NULL by the caller.
+#[derive(Debug, Default)]
+pub struct UpdateMediaDetails {
+    pub alt_text: Option<Option<String>>,
+    pub caption: Option<Option<String>>,
+    pub credit: Option<Option<String>>,
+    pub license: Option<Option<String>>,
+    pub focal_point: Option<Option<(f64, f64)>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -40,6 +62,22 @@ pub struct MediaQuery {
     pub updated_at_lt: Option<DateTimeWithTimeZone>,
 }
 
+/// Which media an optimization run should pick up. Only `image/*` rows are
+/// ever selected.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaOptimizationFilter {
+    pub media_ids: Option<Vec<i32>>,
+    pub reference_type: Option<MediaReference>,
+    pub mime_type: Option<String>,
+    /// Only rows never optimized (`is_optimized = false`).
+    pub only_unoptimized: bool,
+    /// Rows optimized before this time, plus never-optimized ones; used to
+    /// re-run after an `OptimizerConfig` change.
+    pub optimized_before: Option<DateTimeWithTimeZone>,
+    pub created_at_gt: Option<DateTimeWithTimeZone>,
+    pub created_at_lt: Option<DateTimeWithTimeZone>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct MediaWithUsage {
     #[serde(flatten)]
@@ -52,6 +90,9 @@ pub struct MediaPublic {
     #[serde(flatten)]
     pub media: super::model::Model,
     pub file_url: String,
+    /// Still image for video, audio and PDF media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -60,4 +101,53 @@ pub struct MediaWithUsagePublic {
     pub media: super::model::Model,
     pub usage_count: i64,
     pub file_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
+}
+
+/// A live reference to a media row, either recorded in `media_usage` or held
+/// directly by a foreign key / embedded in post content. `entity_type` is a
+/// [`EntityType`] or, for SEO overrides, a [`SeoEntityType`] name.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Hash)]
+pub struct MediaReferenceInfo {
+    pub entity_type: &'static str,
+    pub entity_id: i32,
+    pub field_name: String,
+}
+
+impl MediaReferenceInfo {
+    pub fn new(entity_type: EntityType, entity_id: i32, field_name: impl Into<String>) -> Self {
+        Self {
+            entity_type: entity_type.as_str(),
+            entity_id,
+            field_name: field_name.into(),
+        }
+    }
+
+    /// The OG image of an SEO override on a post, category or tag.
+    pub fn seo_og_image(entity_type: SeoEntityType, entity_id: i32) -> Self {
+        Self {
+            entity_type: entity_type.as_str(),
+            entity_id,
+            field_name: "og_image_id".to_string(),
+        }
+    }
+
+    /// Collapse duplicates (a category cover shows up both in `media_usage`
+    /// and in `categories.cover_id`) and return a stable ordering.
+    pub fn dedup(references: Vec<Self>) -> Vec<Self> {
+        let mut seen = std::collections::HashSet::new();
+        let mut unique: Vec<Self> = references
+            .into_iter()
+            .filter(|reference| seen.insert(reference.clone()))
+            .collect();
+        unique.sort_by(|a, b| {
+            (a.entity_type, a.entity_id, a.field_name.as_str()).cmp(&(
+                b.entity_type,
+                b.entity_id,
+                b.field_name.as_str(),
+            ))
+        });
+        unique
+    }
 }
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use sea_orm::{
+    entity::prelude::*, sea_query::Expr, Condition, FromQueryResult, QueryOrder, QuerySelect, Set,
+};
+use tracing::{info, instrument};
+
+use crate::error::{DbResult, ErrorResponse};
+
+use super::{
+    model::{ActiveModel, Column, Entity},
+    JobResult, MediaJobStatus, MediaOptimizationProgress, Model,
+};
+
+#[derive(Debug, FromQueryResult)]
+struct StatusCount {
+    status: MediaJobStatus,
+    count: i64,
+}
+
+impl Entity {
+    /// Queue one job per media id under `batch_id`. Media that already have a
+    /// pending or running job are left alone so repeated runs don't pile up
+    /// duplicate work. Returns the number of jobs created.
+    #[instrument(skip(conn, media_ids), fields(count = media_ids.len()))]
+    pub async fn enqueue_many(
+        conn: &DbConn,
+        batch_id: &str,
+        media_ids: &[i32],
+        requested_by: Option<i32>,
+    ) -> DbResult<u64> {
+        if media_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let active: HashSet<i32> = Self::find()
+            .select_only()
+            .column(Column::MediaId)
+            .filter(Column::MediaId.is_in(media_ids.iter().copied()))
+            .filter(Column::Status.is_in([MediaJobStatus::Pending, MediaJobStatus::Running]))
+            .into_tuple::<i32>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?
+            .into_iter()
+            .collect();
+
+        let now = chrono::Utc::now().fixed_offset();
+        let mut seen = HashSet::new();
+        let rows: Vec<ActiveModel> = media_ids
+            .iter()
+            .copied()
+            .filter(|id| !active.contains(id) && seen.insert(*id))
+            .map(|media_id| ActiveModel {
+                media_id: Set(media_id),
+                batch_id: Set(batch_id.to_string()),
+                requested_by: Set(requested_by),
+                status: Set(MediaJobStatus::Pending),
+                attempts: Set(0),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .collect();
+
+        let queued = rows.len() as u64;
+        if queued > 0 {
+            Self::insert_many(rows)
+                .exec(conn)
+                .await
+                .map_err(ErrorResponse::from)?;
+        }
+        info!(batch_id, queued, "Media optimization jobs queued");
+        Ok(queued)
+    }
+
+    /// Claim up to `limit` due pending jobs, oldest first. Each row is
+    /// flipped to `running` with a conditional update, so two workers racing
+    /// for the same row can't both win it.
+    #[instrument(skip(conn))]
+    pub async fn claim_pending(conn: &DbConn, limit: u64) -> DbResult<Vec<Model>> {
+        let now = chrono::Utc::now().fixed_offset();
+        let candidates = Self::find()
+            .filter(Column::Status.eq(MediaJobStatus::Pending))
+            .filter(
+                Condition::any()
+                    .add(Column::NextAttemptAt.is_null())
+                    .add(Column::NextAttemptAt.lte(now)),
+            )
+            .order_by_asc(Column::Id)
+            .limit(limit)
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let mut claimed = Vec::with_capacity(candidates.len());
+        for job in candidates {
+            let result = Self::update_many()
+                .col_expr(Column::Status, Expr::value(MediaJobStatus::Running))
+                .col_expr(Column::Attempts, Expr::col(Column::Attempts).add(1))
+                .col_expr(Column::StartedAt, Expr::value(now))
+                .col_expr(Column::UpdatedAt, Expr::value(now))
+                .filter(Column::Id.eq(job.id))
+                .filter(Column::Status.eq(MediaJobStatus::Pending))
+                .exec(conn)
+                .await
+                .map_err(ErrorResponse::from)?;
+            if result.rows_affected == 1 {
+                claimed.push(Model {
+                    status: MediaJobStatus::Running,
+                    attempts: job.attempts + 1,
+                    started_at: Some(now),
+                    updated_at: now,
+                    ..job
+                });
+            }
+        }
+        Ok(claimed)
+    }
+
+    /// Record the outcome of a claimed job.
+    #[instrument(skip(conn, job, result), fields(job_id = job.id, status = %result.status))]
+    pub async fn finish(conn: &DbConn, job: Model, result: JobResult) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let terminal = !result.status.is_active();
+        let mut active: ActiveModel = job.into();
+        active.status = Set(result.status);
+        active.message = Set(result.message);
+        active.variant_count = Set(result.variant_count);
+        active.next_attempt_at = Set(result.next_attempt_at);
+        active.finished_at = Set(terminal.then_some(now));
+        active.updated_at = Set(now);
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Recover jobs whose worker died mid-job: anything `running` since
+    /// before `stale_before` goes back to `pending`, or to `failed` once it
+    /// has used up `max_attempts`. Jobs still within their lease belong to a
+    /// live worker (possibly on another replica) and are left alone.
+    #[instrument(skip(conn))]
+    pub async fn requeue_stale(
+        conn: &DbConn,
+        stale_before: DateTimeWithTimeZone,
+        max_attempts: i32,
+    ) -> DbResult<u64> {
+        let now = chrono::Utc::now().fixed_offset();
+        let stale = || {
+            Self::update_many()
+                .col_expr(Column::UpdatedAt, Expr::value(now))
+                .filter(Column::Status.eq(MediaJobStatus::Running))
+                .filter(Column::StartedAt.lt(stale_before))
+        };
+
+        let failed = stale()
+            .col_expr(Column::Status, Expr::value(MediaJobStatus::Failed))
+            .col_expr(
+                Column::Message,
+                Expr::value("worker stopped before finishing"),
+            )
+            .col_expr(Column::FinishedAt, Expr::value(now))
+            .filter(Column::Attempts.gte(max_attempts))
+            .exec(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        let requeued = stale()
+            .col_expr(Column::Status, Expr::value(MediaJobStatus::Pending))
+            .col_expr(Column::NextAttemptAt, Expr::value(now))
+            .exec(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        Ok(failed.rows_affected + requeued.rows_affected)
+    }
+
+    /// Status counts for `batch_id`, or for every job when `None`.
+    #[instrument(skip(conn))]
+    pub async fn progress(
+        conn: &DbConn,
+        batch_id: Option<&str>,
+    ) -> DbResult<MediaOptimizationProgress> {
+        let mut counts = Self::find()
+            .select_only()
+            .column(Column::Status)
+            .column_as(Column::Id.count(), "count")
+            .group_by(Column::Status);
+        let mut failures = Self::find()
+            .filter(Column::Status.eq(MediaJobStatus::Failed))
+            .order_by_desc(Column::UpdatedAt)
+            .limit(20);
+        if let Some(batch_id) = batch_id {
+            counts = counts.filter(Column::BatchId.eq(batch_id));
+            failures = failures.filter(Column::BatchId.eq(batch_id));
+        }
+
+        let mut progress = MediaOptimizationProgress {
+            batch_id: batch_id.map(str::to_string),
+            ..Default::default()
+        };
+        for row in counts
+            .into_model::<StatusCount>()
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?
+        {
+            progress.record(row.status, row.count.max(0) as u64);
+        }
+        progress.recent_failures = failures.all(conn).await.map_err(ErrorResponse::from)?;
+        Ok(progress)
+    }
+}
@@ -0,0 +1,55 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::MediaJobStatus;
+
+/// One queued (re)optimization of a media item. Rows are grouped by
+/// `batch_id` so an admin run can report progress as a whole.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "media_optimization_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub media_id: i32,
+    pub batch_id: String,
+    pub requested_by: Option<i32>,
+    pub status: MediaJobStatus,
+    pub attempts: i32,
+    /// Skip reason or last error.
+    pub message: Option<String>,
+    pub variant_count: Option<i32>,
+    /// Earliest time a retried job may be claimed again; `None` means now.
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+    pub started_at: Option<DateTimeWithTimeZone>,
+    pub finished_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::media::Entity",
+        from = "Column::MediaId",
+        to = "super::super::media::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Media,
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::RequestedBy",
+        to = "super::super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    RequestedBy,
+}
+
+impl Related<super::super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Media.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
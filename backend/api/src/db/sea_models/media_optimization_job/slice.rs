@@ -0,0 +1,77 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::{Deserialize, Serialize};
+
+use super::{MediaJobStatus, Model};
+
+/// How a finished job ended, recorded by the worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobResult {
+    pub status: MediaJobStatus,
+    pub message: Option<String>,
+    pub variant_count: Option<i32>,
+    /// Backoff deadline for a job sent back to `pending`.
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+}
+
+/// Per-status job counts for one batch (or the whole queue).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MediaOptimizationProgress {
+    pub batch_id: Option<String>,
+    pub total: u64,
+    pub pending: u64,
+    pub running: u64,
+    pub completed: u64,
+    pub skipped: u64,
+    pub failed: u64,
+    /// Most recent failures, newest first.
+    pub recent_failures: Vec<Model>,
+}
+
+impl MediaOptimizationProgress {
+    pub fn record(&mut self, status: MediaJobStatus, count: u64) {
+        match status {
+            MediaJobStatus::Pending => self.pending += count,
+            MediaJobStatus::Running => self.running += count,
+            MediaJobStatus::Completed => self.completed += count,
+            MediaJobStatus::Skipped => self.skipped += count,
+            MediaJobStatus::Failed => self.failed += count,
+        }
+        self.total += count;
+    }
+
+    /// Nothing left for the worker to do.
+    pub fn is_finished(&self) -> bool {
+        self.pending == 0 && self.running == 0
+    }
+
+    /// Share of jobs that reached a terminal state, 0..=100.
+    pub fn percent_done(&self) -> u8 {
+        if self.total == 0 {
+            return 100;
+        }
+        let done = self.completed + self.skipped + self.failed;
+        ((done * 100) / self.total) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_counts_terminal_states_as_done() {
+        let mut progress = MediaOptimizationProgress::default();
+        progress.record(MediaJobStatus::Completed, 5);
+        progress.record(MediaJobStatus::Skipped, 2);
+        progress.record(MediaJobStatus::Failed, 1);
+        progress.record(MediaJobStatus::Pending, 2);
+
+        assert_eq!(progress.total, 10);
+        assert_eq!(progress.percent_done(), 80);
+        assert!(!progress.is_finished());
+
+        let empty = MediaOptimizationProgress::default();
+        assert_eq!(empty.percent_done(), 100);
+        assert!(empty.is_finished());
+    }
+}
@@ -0,0 +1,89 @@
+use sea_orm::{entity::prelude::*, QueryOrder, QuerySelect, Set};
+use tracing::{info, instrument};
+
+use crate::error::{DbResult, ErrorResponse};
+
+use super::{
+    model::{ActiveModel, Column, Entity},
+    MediaUploadStatus, Model, NewMediaUpload,
+};
+
+impl Entity {
+    #[instrument(skip(conn, payload), fields(upload_id, uploader_id = payload.uploader_id))]
+    pub async fn create(conn: &DbConn, payload: NewMediaUpload) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let upload = ActiveModel {
+            uploader_id: Set(payload.uploader_id),
+            object_key: Set(payload.object_key),
+            provider_upload_id: Set(payload.provider_upload_id),
+            filename: Set(payload.filename),
+            mime_type: Set(payload.mime_type),
+            extension: Set(payload.extension),
+            size: Set(payload.size),
+            part_size: Set(payload.part_size),
+            part_count: Set(payload.part_count),
+            content_hash: Set(payload.content_hash),
+            reference_type: Set(payload.reference_type),
+            width: Set(payload.width),
+            height: Set(payload.height),
+            status: Set(MediaUploadStatus::Pending),
+            media_id: Set(None),
+            expires_at: Set(payload.expires_at),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let model = upload.insert(conn).await.map_err(ErrorResponse::from)?;
+        tracing::Span::current().record("upload_id", model.id);
+        info!(
+            upload_id = model.id,
+            uploader_id = model.uploader_id,
+            size = model.size,
+            part_count = model.part_count,
+            "Media upload session created"
+        );
+        Ok(model)
+    }
+
+    #[instrument(skip(conn), fields(upload_id = id))]
+    pub async fn find_by_id(conn: &DbConn, id: i32) -> DbResult<Option<Model>> {
+        <Self as EntityTrait>::find_by_id(id)
+            .one(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Move a session out of `Pending`, recording the resulting media row on
+    /// completion.
+    #[instrument(skip(conn, upload), fields(upload_id = upload.id))]
+    pub async fn finish(
+        conn: &DbConn,
+        upload: Model,
+        status: MediaUploadStatus,
+        media_id: Option<i32>,
+    ) -> DbResult<Model> {
+        let mut active: ActiveModel = upload.into();
+        active.status = Set(status);
+        active.media_id = Set(media_id);
+        active.updated_at = Set(chrono::Utc::now().fixed_offset());
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Pending sessions whose `expires_at` is before `now`, oldest first.
+    #[instrument(skip(conn))]
+    pub async fn find_expired_pending(
+        conn: &DbConn,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> DbResult<Vec<Model>> {
+        <Self as EntityTrait>::find()
+            .filter(Column::Status.eq(MediaUploadStatus::Pending))
+            .filter(Column::ExpiresAt.lt(now))
+            .order_by_asc(Column::ExpiresAt)
+            .limit(limit)
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+}
@@ -0,0 +1,6 @@
+mod actions;
+pub mod model;
+pub mod slice;
+
+pub use model::*;
+pub use slice::*;
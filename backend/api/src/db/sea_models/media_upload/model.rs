@@ -0,0 +1,76 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::{MediaReference, MediaUploadStatus};
+
+/// A direct-to-storage multipart upload session. Parts are written straight
+/// to `object_key` in the object store; on completion the assembled object is
+/// ingested into a `media` row (`media_id`) and the staging object removed.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "media_uploads")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub uploader_id: i32,
+    /// Staging key under `uploads/`; never the final media key.
+    #[serde(skip_serializing)]
+    pub object_key: String,
+    /// Multipart upload id issued by the storage backend.
+    #[serde(skip_serializing)]
+    pub provider_upload_id: String,
+    pub filename: Option<String>,
+    pub mime_type: String,
+    pub extension: String,
+    pub size: i64,
+    pub part_size: i64,
+    pub part_count: i32,
+    pub content_hash: Option<String>,
+    pub reference_type: Option<MediaReference>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub status: MediaUploadStatus,
+    pub media_id: Option<i32>,
+    pub expires_at: DateTimeWithTimeZone,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::UploaderId",
+        to = "super::super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Uploader,
+    #[sea_orm(
+        belongs_to = "super::super::media::Entity",
+        from = "Column::MediaId",
+        to = "super::super::media::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Media,
+}
+
+impl Related<super::super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Uploader.def()
+    }
+}
+
+impl Related<super::super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Media.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn is_expired(&self, now: DateTimeWithTimeZone) -> bool {
+        self.expires_at <= now
+    }
+}
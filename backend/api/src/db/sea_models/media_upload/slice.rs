@@ -0,0 +1,22 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::{Deserialize, Serialize};
+
+use super::MediaReference;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NewMediaUpload {
+    pub uploader_id: i32,
+    pub object_key: String,
+    pub provider_upload_id: String,
+    pub filename: Option<String>,
+    pub mime_type: String,
+    pub extension: String,
+    pub size: i64,
+    pub part_size: i64,
+    pub part_count: i32,
+    pub content_hash: Option<String>,
+    pub reference_type: Option<MediaReference>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub expires_at: DateTimeWithTimeZone,
+}
@@ -1,8 +1,13 @@
-use sea_orm::{ActiveModelTrait, Set};
+use std::collections::HashMap;
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set, TransactionTrait};
 
 use crate::error::{DbResult, ErrorResponse};
 
-use super::{model::ActiveModel, Entity, Model, NewMediaVariant};
+use super::{
+    model::{ActiveModel, Column},
+    Entity, Model, NewMediaVariant, POSTER_VARIANT,
+};
 
 impl Entity {
     pub async fn create_many(
@@ -36,4 +41,128 @@ impl Entity {
 
         Ok(inserted)
     }
+
+    pub async fn find_by_media_id<C>(conn: &C, media_id: i32) -> DbResult<Vec<Model>>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        Self::find()
+            .filter(Column::MediaId.eq(media_id))
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Batch variant lookup for building responsive descriptors of many media
+    /// rows at once.
+    pub async fn find_by_media_ids<C>(conn: &C, media_ids: &[i32]) -> DbResult<Vec<Model>>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        if media_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        Self::find()
+            .filter(Column::MediaId.is_in(media_ids.to_vec()))
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Swap a media item's variant rows for `variants` in one transaction and
+    /// return the rows that were removed, so the caller can clean up any
+    /// objects the new set no longer uses.
+    pub async fn replace_for_media(
+        conn: &sea_orm::DatabaseConnection,
+        media_id: i32,
+        variants: Vec<NewMediaVariant>,
+    ) -> DbResult<Vec<Model>> {
+        let txn = conn.begin().await.map_err(ErrorResponse::from)?;
+
+        let previous = Self::find_by_media_id(&txn, media_id).await?;
+        Self::delete_many()
+            .filter(Column::MediaId.eq(media_id))
+            .exec(&txn)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        for variant in variants {
+            ActiveModel {
+                media_id: Set(media_id),
+                object_key: Set(variant.object_key),
+                mime_type: Set(variant.mime_type),
+                width: Set(variant.width),
+                height: Set(variant.height),
+                size: Set(variant.size),
+                extension: Set(variant.extension),
+                quality: Set(variant.quality),
+                variant_type: Set(variant.variant_type),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        }
+
+        txn.commit().await.map_err(ErrorResponse::from)?;
+        Ok(previous)
+    }
+
+    /// Poster variant of each media id that has one.
+    pub async fn find_posters<C>(conn: &C, media_ids: &[i32]) -> DbResult<HashMap<i32, Model>>
+    where
+        C: sea_orm::ConnectionTrait,
+    {
+        if media_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let rows = Self::find()
+            .filter(Column::MediaId.is_in(media_ids.to_vec()))
+            .filter(Column::VariantType.eq(POSTER_VARIANT))
+            .all(conn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        Ok(rows.into_iter().map(|row| (row.media_id, row)).collect())
+    }
+
+    /// Set `poster` as its media's only poster variant, leaving other
+    /// variants alone. Returns the inserted row and the rows it replaced.
+    pub async fn replace_poster(
+        conn: &sea_orm::DatabaseConnection,
+        poster: NewMediaVariant,
+    ) -> DbResult<(Model, Vec<Model>)> {
+        let txn = conn.begin().await.map_err(ErrorResponse::from)?;
+
+        let previous = Self::find()
+            .filter(Column::MediaId.eq(poster.media_id))
+            .filter(Column::VariantType.eq(POSTER_VARIANT))
+            .all(&txn)
+            .await
+            .map_err(ErrorResponse::from)?;
+        Self::delete_many()
+            .filter(Column::MediaId.eq(poster.media_id))
+            .filter(Column::VariantType.eq(POSTER_VARIANT))
+            .exec(&txn)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let inserted = ActiveModel {
+            media_id: Set(poster.media_id),
+            object_key: Set(poster.object_key),
+            mime_type: Set(poster.mime_type),
+            width: Set(poster.width),
+            height: Set(poster.height),
+            size: Set(poster.size),
+            extension: Set(poster.extension),
+            quality: Set(poster.quality),
+            variant_type: Set(POSTER_VARIANT.to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        txn.commit().await.map_err(ErrorResponse::from)?;
+        Ok((inserted, previous))
+    }
 }
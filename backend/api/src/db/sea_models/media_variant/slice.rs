@@ -12,3 +12,7 @@ pub struct NewMediaVariant {
     pub quality: Option<i32>,
     pub variant_type: String,
 }
+
+/// `variant_type` of the still image shown for video, audio and PDF media:
+/// a captured frame, embedded cover art or a rendered first page.
+pub const POSTER_VARIANT: &str = "poster";
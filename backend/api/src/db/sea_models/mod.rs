@@ -1,14 +1,23 @@
 pub mod category;
 pub mod comment_flag;
+pub mod comment_like;
+pub mod comment_reaction;
 pub mod discount_code;
 pub mod email_verification;
 pub mod forgot_password;
 pub mod invoice;
 pub mod newsletter_subscriber;
+pub mod notification;
+pub mod notification_digest_item;
+pub mod notification_preference;
 
+pub mod analytics_event;
 pub mod app_constant;
 pub mod audit_log;
+pub mod blocklist_entry;
 pub mod media;
+pub mod media_optimization_job;
+pub mod media_upload;
 pub mod media_usage;
 pub mod media_variant;
 pub mod pagination;
@@ -18,6 +27,7 @@ pub mod payout_ledger;
 pub mod plan;
 pub mod post;
 pub mod post_access;
+pub mod post_author;
 pub mod post_comment;
 pub mod post_like;
 pub mod post_purchase;
@@ -25,13 +35,17 @@ pub mod post_revision;
 pub mod post_series;
 pub mod post_series_post;
 pub mod post_view;
+pub mod redirect;
 pub mod route_status;
 pub mod scheduled_post;
 pub mod seed_run;
+pub mod seo_override;
 pub mod subscription;
 pub mod tag;
 pub mod user;
 pub mod user_ban;
 pub mod user_session;
+pub mod webhook_delivery;
+pub mod webhook_endpoint;
 
 pub use crate::utils::color as color_utils;
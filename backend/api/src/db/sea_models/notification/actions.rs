@@ -0,0 +1,86 @@
+use sea_orm::{entity::prelude::*, QueryOrder, Set};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+impl Entity {
+    pub const PER_PAGE: u64 = 20;
+
+    pub async fn create(conn: &DbConn, new_notification: NewNotification) -> DbResult<Model> {
+        let row = ActiveModel {
+            user_id: Set(new_notification.user_id),
+            kind: Set(new_notification.kind),
+            subject: Set(new_notification.subject),
+            body: Set(new_notification.body),
+            link: Set(new_notification.link),
+            read_at: Set(None),
+            created_at: Set(chrono::Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+        row.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// A user's notifications, newest first.
+    pub async fn find_with_query(
+        conn: &DbConn,
+        user_id: i32,
+        query: NotificationQuery,
+    ) -> DbResult<(Vec<Model>, u64)> {
+        let mut notification_query = Self::find().filter(Column::UserId.eq(user_id));
+        if query.unread_only {
+            notification_query = notification_query.filter(Column::ReadAt.is_null());
+        }
+
+        let page = match query.page {
+            Some(p) if p > 0 => p,
+            _ => 1,
+        };
+        let paginator = notification_query
+            .order_by_desc(Column::CreatedAt)
+            .order_by_desc(Column::Id)
+            .paginate(conn, Self::PER_PAGE);
+        let total = paginator.num_items().await?;
+        let results = paginator.fetch_page(page - 1).await?;
+        Ok((results, total))
+    }
+
+    pub async fn unread_count(conn: &DbConn, user_id: i32) -> DbResult<u64> {
+        Ok(Self::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ReadAt.is_null())
+            .count(conn)
+            .await?)
+    }
+
+    /// Mark the given notifications read. Ids that belong to another user or
+    /// are already read are skipped.
+    pub async fn mark_read(conn: &DbConn, user_id: i32, ids: &[i32]) -> DbResult<u64> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let result = Self::update_many()
+            .col_expr(
+                Column::ReadAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Id.is_in(ids.iter().copied()))
+            .filter(Column::ReadAt.is_null())
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn mark_all_read(conn: &DbConn, user_id: i32) -> DbResult<u64> {
+        let result = Self::update_many()
+            .col_expr(
+                Column::ReadAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ReadAt.is_null())
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::NotificationKind;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewNotification {
+    pub user_id: i32,
+    pub kind: NotificationKind,
+    pub subject: String,
+    pub body: String,
+    pub link: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NotificationQuery {
+    pub page: Option<u64>,
+    pub unread_only: bool,
+}
@@ -0,0 +1,66 @@
+use sea_orm::{entity::prelude::*, QueryOrder, QuerySelect, Set};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+impl Entity {
+    pub async fn enqueue(conn: &DbConn, item: NewDigestItem) -> DbResult<Model> {
+        let row = ActiveModel {
+            user_id: Set(item.user_id),
+            kind: Set(item.kind),
+            subject: Set(item.subject),
+            body: Set(item.body),
+            link: Set(item.link),
+            created_at: Set(chrono::Utc::now().fixed_offset()),
+            sent_at: Set(None),
+            ..Default::default()
+        };
+        row.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Users with an unsent item queued at or before `cutoff`, at most
+    /// `limit` of them per pass.
+    pub async fn pending_user_ids(
+        conn: &DbConn,
+        cutoff: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> DbResult<Vec<i32>> {
+        Ok(Self::find()
+            .select_only()
+            .column(Column::UserId)
+            .filter(Column::SentAt.is_null())
+            .filter(Column::CreatedAt.lte(cutoff))
+            .group_by(Column::UserId)
+            .order_by_asc(Column::UserId)
+            .limit(limit)
+            .into_tuple::<i32>()
+            .all(conn)
+            .await?)
+    }
+
+    /// A user's unsent items, oldest first.
+    pub async fn pending_for_user(conn: &DbConn, user_id: i32) -> DbResult<Vec<Model>> {
+        Ok(Self::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::SentAt.is_null())
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Id)
+            .all(conn)
+            .await?)
+    }
+
+    pub async fn mark_sent(conn: &DbConn, item_ids: &[i32]) -> DbResult<u64> {
+        if item_ids.is_empty() {
+            return Ok(0);
+        }
+        let result = Self::update_many()
+            .col_expr(
+                Column::SentAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::Id.is_in(item_ids.iter().copied()))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
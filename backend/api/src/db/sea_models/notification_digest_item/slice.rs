@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+use super::NotificationKind;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewDigestItem {
+    pub user_id: i32,
+    pub kind: NotificationKind,
+    pub subject: String,
+    pub body: String,
+    pub link: Option<String>,
+}
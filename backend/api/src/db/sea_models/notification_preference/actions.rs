@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use sea_orm::{entity::prelude::*, sea_query::OnConflict, Set};
+use tracing::info;
+
+use super::*;
+use crate::error::{DbResult, ErrorCode, ErrorResponse};
+
+impl Entity {
+    fn new_unsubscribe_token() -> String {
+        let mut bytes = [0u8; 32];
+        rand::rng().fill(&mut bytes);
+        hex::encode(bytes)
+    }
+
+    /// Insert default settings for any of `user_ids` without a row.
+    async fn ensure_rows(conn: &DbConn, user_ids: &[i32]) -> DbResult<()> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+        let now = chrono::Utc::now().fixed_offset();
+        let rows = user_ids.iter().map(|&user_id| ActiveModel {
+            user_id: Set(user_id),
+            unsubscribe_token: Set(Self::new_unsubscribe_token()),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        });
+        Self::insert_many(rows)
+            .on_conflict(OnConflict::column(Column::UserId).do_nothing().to_owned())
+            .exec_without_returning(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn find_or_create(conn: &DbConn, user_id: i32) -> DbResult<Model> {
+        Self::ensure_rows(conn, &[user_id]).await?;
+        Self::find_by_id(user_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| ErrorResponse::new(ErrorCode::RecordNotFound))
+    }
+
+    /// Settings for each of `user_ids`, keyed by user id.
+    pub async fn find_or_create_many(
+        conn: &DbConn,
+        user_ids: &[i32],
+    ) -> DbResult<HashMap<i32, Model>> {
+        Self::ensure_rows(conn, user_ids).await?;
+        let rows = Self::find()
+            .filter(Column::UserId.is_in(user_ids.iter().copied()))
+            .all(conn)
+            .await?;
+        Ok(rows.into_iter().map(|row| (row.user_id, row)).collect())
+    }
+
+    pub async fn update(
+        conn: &DbConn,
+        user_id: i32,
+        update: UpdateNotificationPreference,
+    ) -> DbResult<Model> {
+        let existing = Self::find_or_create(conn, user_id).await?;
+        let mut active: ActiveModel = existing.into();
+
+        let flags = [
+            (&mut active.comment_on_post, update.comment_on_post),
+            (&mut active.comment_reply, update.comment_reply),
+            (&mut active.comment_mention, update.comment_mention),
+            (&mut active.new_user, update.new_user),
+            (&mut active.payment_received, update.payment_received),
+            (
+                &mut active.subscription_canceled,
+                update.subscription_canceled,
+            ),
+            (
+                &mut active.newsletter_subscriber,
+                update.newsletter_subscriber,
+            ),
+            (&mut active.contact_form, update.contact_form),
+            (&mut active.email_enabled, update.email_enabled),
+            (&mut active.in_app_enabled, update.in_app_enabled),
+        ];
+        for (field, value) in flags {
+            if let Some(value) = value {
+                *field = Set(value);
+            }
+        }
+        if let Some(email_delivery) = update.email_delivery {
+            active.email_delivery = Set(email_delivery);
+        }
+        active.updated_at = Set(chrono::Utc::now().fixed_offset());
+
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
+
+    pub async fn find_by_unsubscribe_token(conn: &DbConn, token: &str) -> DbResult<Option<Model>> {
+        Ok(Self::find()
+            .filter(Column::UnsubscribeToken.eq(token))
+            .one(conn)
+            .await?)
+    }
+
+    /// Apply an unsubscribe link: stop all notification email, or only
+    /// `kind` when the link was scoped to it. `None` for an unknown token.
+    pub async fn unsubscribe(
+        conn: &DbConn,
+        token: &str,
+        kind: Option<NotificationKind>,
+    ) -> DbResult<Option<Model>> {
+        let Some(existing) = Self::find_by_unsubscribe_token(conn, token).await? else {
+            return Ok(None);
+        };
+        let update = match kind {
+            Some(kind) => UpdateNotificationPreference::disable(kind),
+            None => UpdateNotificationPreference {
+                email_enabled: Some(false),
+                ..Default::default()
+            },
+        };
+        let updated = Self::update(conn, existing.user_id, update).await?;
+        info!(user_id = updated.user_id, kind = ?kind, "Unsubscribed from notification email");
+        Ok(Some(updated))
+    }
+}
@@ -0,0 +1,72 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::{EmailDelivery, NotificationKind};
+
+/// A user's notification settings. Created with defaults the first time the
+/// user reads them or is sent a notification.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "notification_preferences")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: i32,
+    pub comment_on_post: bool,
+    pub comment_reply: bool,
+    pub comment_mention: bool,
+    pub new_user: bool,
+    pub payment_received: bool,
+    pub subscription_canceled: bool,
+    pub newsletter_subscriber: bool,
+    pub contact_form: bool,
+    pub email_enabled: bool,
+    pub in_app_enabled: bool,
+    pub email_delivery: EmailDelivery,
+    // Only ever sent inside unsubscribe links.
+    #[serde(skip_serializing)]
+    pub unsubscribe_token: String,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+impl Model {
+    /// Whether the user wants to hear about `kind` at all.
+    pub fn wants(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::CommentOnPost => self.comment_on_post,
+            NotificationKind::CommentReply => self.comment_reply,
+            NotificationKind::CommentMention => self.comment_mention,
+            NotificationKind::NewUser => self.new_user,
+            NotificationKind::PaymentReceived => self.payment_received,
+            NotificationKind::SubscriptionCanceled => self.subscription_canceled,
+            NotificationKind::NewsletterSubscriber => self.newsletter_subscriber,
+            NotificationKind::ContactForm => self.contact_form,
+        }
+    }
+
+    pub fn wants_email(&self, kind: NotificationKind) -> bool {
+        self.email_enabled && self.wants(kind)
+    }
+
+    pub fn wants_in_app(&self, kind: NotificationKind) -> bool {
+        self.in_app_enabled && self.wants(kind)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::UserId",
+        to = "super::super::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::{EmailDelivery, NotificationKind};
+
+/// Partial update of a user's notification settings; `None` keeps the
+/// current value.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateNotificationPreference {
+    pub comment_on_post: Option<bool>,
+    pub comment_reply: Option<bool>,
+    pub comment_mention: Option<bool>,
+    pub new_user: Option<bool>,
+    pub payment_received: Option<bool>,
+    pub subscription_canceled: Option<bool>,
+    pub newsletter_subscriber: Option<bool>,
+    pub contact_form: Option<bool>,
+    pub email_enabled: Option<bool>,
+    pub in_app_enabled: Option<bool>,
+    pub email_delivery: Option<EmailDelivery>,
+}
+
+impl UpdateNotificationPreference {
+    /// Turn a single kind off, as an unsubscribe link scoped to one kind does.
+    pub fn disable(kind: NotificationKind) -> Self {
+        let mut update = Self::default();
+        let flag = match kind {
+            NotificationKind::CommentOnPost => &mut update.comment_on_post,
+            NotificationKind::CommentReply => &mut update.comment_reply,
+            NotificationKind::CommentMention => &mut update.comment_mention,
+            NotificationKind::NewUser => &mut update.new_user,
+            NotificationKind::PaymentReceived => &mut update.payment_received,
+            NotificationKind::SubscriptionCanceled => &mut update.subscription_canceled,
+            NotificationKind::NewsletterSubscriber => &mut update.newsletter_subscriber,
+            NotificationKind::ContactForm => &mut update.contact_form,
+        };
+        *flag = Some(false);
+        update
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 
-use crate::{db::sea_models::tag, error::DbResult};
+use crate::{
+    db::sea_models::tag,
+    error::{DbResult, ErrorCode, ErrorResponse},
+};
 use sea_orm::{
     entity::prelude::*, prelude::Expr, sea_query::Alias, Condition, JoinType, Order, QueryOrder,
     QuerySelect, Set, TransactionTrait,
@@ -21,7 +24,7 @@ impl Entity {
             .column_as(Column::FeaturedImageId, "featured_image_id")
             .column_as(UserColumn::Id, "author_id")
             .column_as(UserColumn::Name, "author_name")
-            .column_as(UserColumn::Email, "author_email")
+            .column_as(UserColumn::ProfileSlug, "author_profile_slug")
             .column_as(UserColumn::AvatarId, "author_avatar_id")
             .column_as(CategoryColumn::Id, "category_id")
             .column_as(CategoryColumn::Name, "category_name")
@@ -30,7 +33,10 @@ impl Entity {
             .column_as(CategoryColumn::CoverId, "category_cover_id")
             .column_as(CategoryColumn::LogoId, "category_logo_id")
             .expr_as(
-                Expr::cust("COALESCE((SELECT COUNT(*) FROM post_comments WHERE post_comments.post_id = posts.id), 0)"),
+                Expr::cust(format!(
+                    "COALESCE((SELECT COUNT(*) FROM post_comments WHERE post_comments.post_id = posts.id AND post_comments.status = 'approved' AND NOT {}), 0)",
+                    super::super::user_ban::actions::active_shadow_ban_exists_sql("post_comments.user_id")
+                )),
                 "comment_count",
             )
             .join(JoinType::InnerJoin, Relation::User.def())
@@ -211,6 +217,77 @@ impl Entity {
                 )),
                 "featured_image_size",
             )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::AltText,
+                )),
+                "featured_image_alt_text",
+            )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::Caption,
+                )),
+                "featured_image_caption",
+            )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::Credit,
+                )),
+                "featured_image_credit",
+            )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::License,
+                )),
+                "featured_image_license",
+            )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::FocalX,
+                )),
+                "featured_image_focal_x",
+            )
+            .expr_as(
+                Expr::col((
+                    Alias::new("featured_image_media"),
+                    super::super::media::Column::FocalY,
+                )),
+                "featured_image_focal_y",
+            )
+    }
+
+    /// Posts `user_id` owns or is credited on as a co-author.
+    pub fn credited_to(user_id: i32) -> Condition {
+        use super::super::post_author;
+
+        Condition::any().add(Column::AuthorId.eq(user_id)).add(
+            Column::Id.in_subquery(
+                sea_orm::sea_query::Query::select()
+                    .column(post_author::Column::PostId)
+                    .from(post_author::Entity)
+                    .and_where(post_author::Column::UserId.eq(user_id))
+                    .to_owned(),
+            ),
+        )
+    }
+
+    async fn attach_co_authors(
+        conn: &DbConn,
+        public_url: &str,
+        posts: &mut [PostWithRelations],
+    ) -> DbResult<()> {
+        let post_ids: Vec<i32> = posts.iter().map(|p| p.id).collect();
+        let mut credits =
+            super::super::post_author::Entity::find_for_posts(conn, public_url, &post_ids).await?;
+        for post in posts {
+            post.co_authors = credits.remove(&post.id).unwrap_or_default();
+        }
+        Ok(())
     }
 
     async fn sanitized_tag_ids(conn: &DbConn, tag_ids: Vec<i32>) -> DbResult<Vec<i32>> {
@@ -226,6 +303,93 @@ impl Entity {
         Ok(sanitized_ids)
     }
 
+    /// Translation group a post in `locale` joins when it translates
+    /// `source_id`. A source that was never translated starts a new group.
+    /// Rejects sources in the same locale and locales the group already has.
+    async fn join_translation_group(
+        conn: &DbConn,
+        source_id: i32,
+        locale: &str,
+        post_id: Option<i32>,
+    ) -> DbResult<Uuid> {
+        let Some(source) = Self::find_by_id(source_id).one(conn).await? else {
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Translation source post not found"));
+        };
+        if Some(source.id) == post_id {
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("A post cannot be a translation of itself"));
+        }
+        if source.locale == locale {
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Translation must use a different locale than its source"));
+        }
+
+        let group = match source.translation_group_id {
+            Some(group) => group,
+            None => {
+                let group = Uuid::new_v4();
+                Self::update_many()
+                    .col_expr(Column::TranslationGroupId, Expr::value(group))
+                    .filter(Column::Id.eq(source.id))
+                    .exec(conn)
+                    .await?;
+                group
+            }
+        };
+        Self::ensure_locale_free(conn, group, locale, post_id).await?;
+        Ok(group)
+    }
+
+    async fn ensure_locale_free(
+        conn: &DbConn,
+        group: Uuid,
+        locale: &str,
+        post_id: Option<i32>,
+    ) -> DbResult<()> {
+        let mut taken = Self::find()
+            .filter(Column::TranslationGroupId.eq(group))
+            .filter(Column::Locale.eq(locale));
+        if let Some(post_id) = post_id {
+            taken = taken.filter(Column::Id.ne(post_id));
+        }
+        if taken.count(conn).await? > 0 {
+            return Err(
+                ErrorResponse::new(ErrorCode::DuplicateEntry).with_message(format!(
+                    "A {locale} translation of this post already exists"
+                )),
+            );
+        }
+        Ok(())
+    }
+
+    /// Other locales' versions of `post`, optionally published ones only.
+    pub async fn find_translations(
+        conn: &DbConn,
+        post: &PostWithRelations,
+        published_only: bool,
+    ) -> DbResult<Vec<PostTranslation>> {
+        let Some(group) = post.translation_group_id else {
+            return Ok(Vec::new());
+        };
+        let mut query = Self::find()
+            .select_only()
+            .columns([
+                Column::Id,
+                Column::Locale,
+                Column::Slug,
+                Column::Title,
+                Column::Status,
+            ])
+            .filter(Column::TranslationGroupId.eq(group))
+            .filter(Column::Id.ne(post.id))
+            .order_by_asc(Column::Locale);
+        if published_only {
+            query = query.filter(Column::Status.eq(PostStatus::Published));
+        }
+        Ok(query.into_model::<PostTranslation>().all(conn).await?)
+    }
+
     #[instrument(skip(conn, new_post), fields(post_id, author_id = new_post.author_id, slug = %new_post.slug))]
     pub async fn create(
         conn: &DbConn,
@@ -235,6 +399,12 @@ impl Entity {
         let now = chrono::Utc::now().fixed_offset();
 
         let sanitized_tag_ids = Self::sanitized_tag_ids(conn, new_post.tag_ids).await?;
+        let translation_group_id = match new_post.translation_of {
+            Some(source_id) => {
+                Some(Self::join_translation_group(conn, source_id, &new_post.locale, None).await?)
+            }
+            None => None,
+        };
 
         let post = ActiveModel {
             title: Set(new_post.title),
@@ -249,6 +419,8 @@ impl Entity {
             view_count: Set(new_post.view_count),
             likes_count: Set(new_post.likes_count),
             tag_ids: Set(sanitized_tag_ids),
+            locale: Set(new_post.locale),
+            translation_group_id: Set(translation_group_id),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -257,6 +429,15 @@ impl Entity {
         match post.insert(conn).await {
             Ok(model) => {
                 tracing::Span::current().record("post_id", model.id);
+                if !new_post.co_author_ids.is_empty() {
+                    super::super::post_author::Entity::set_for_post(
+                        conn,
+                        model.id,
+                        model.author_id,
+                        new_post.co_author_ids,
+                    )
+                    .await?;
+                }
                 info!(
                     post_id = model.id,
                     author_id = model.author_id,
@@ -288,7 +469,26 @@ impl Entity {
         let post: Option<Model> = Self::find_by_id(post_id).one(conn).await?;
 
         if let Some(post_model) = post {
+            let locale = update_post
+                .locale
+                .clone()
+                .unwrap_or_else(|| post_model.locale.clone());
+            let translation_group_id = match update_post.translation_of {
+                Some(source_id) => Some(
+                    Self::join_translation_group(conn, source_id, &locale, Some(post_id)).await?,
+                ),
+                None => {
+                    if let Some(group) = post_model.translation_group_id {
+                        Self::ensure_locale_free(conn, group, &locale, Some(post_id)).await?;
+                    }
+                    post_model.translation_group_id
+                }
+            };
+
+            let owner_id = post_model.author_id;
             let mut post_active: ActiveModel = post_model.into();
+            post_active.locale = Set(locale);
+            post_active.translation_group_id = Set(translation_group_id);
 
             if let Some(title) = update_post.title {
                 post_active.title = Set(title);
@@ -339,6 +539,15 @@ impl Entity {
 
             match post_active.update(conn).await {
                 Ok(updated_post) => {
+                    if let Some(co_author_ids) = update_post.co_author_ids {
+                        super::super::post_author::Entity::set_for_post(
+                            conn,
+                            post_id,
+                            owner_id,
+                            co_author_ids,
+                        )
+                        .await?;
+                    }
                     info!(post_id, "Post updated");
                     Self::find_by_id_or_slug(conn, public_url, Some(updated_post.id), None).await
                 }
@@ -406,7 +615,9 @@ impl Entity {
                 }
             }
 
-            return Ok(Some(post_data.into_relation(tags)));
+            let mut post = post_data.into_relation(tags);
+            Self::attach_co_authors(conn, public_url, std::slice::from_mut(&mut post)).await?;
+            return Ok(Some(post));
         }
 
         Ok(None)
@@ -430,7 +641,7 @@ impl Entity {
         }
 
         if let Some(author_id_filter) = query.author_id {
-            post_query = post_query.filter(Column::AuthorId.eq(author_id_filter));
+            post_query = post_query.filter(Self::credited_to(author_id_filter));
         }
 
         // Date range filters
@@ -453,6 +664,10 @@ impl Entity {
             post_query = post_query.filter(Column::PublishedAt.lt(ts));
         }
 
+        if let Some(locale) = &query.locale {
+            post_query = post_query.filter(Column::Locale.eq(locale.as_str()));
+        }
+
         if let Some(category_id_filter) = query.category_id {
             post_query = post_query.filter(Column::CategoryId.eq(category_id_filter));
         }
@@ -511,7 +726,46 @@ impl Entity {
         let total = paginated.num_items().await?;
 
         let posts_joined = paginated.fetch_page(page - 1).await?;
+        let posts_with_relations = Self::hydrate(conn, public_url, posts_joined).await?;
+
+        Ok((posts_with_relations, total))
+    }
+
+    /// Published posts of a series in reading order, unpaginated.
+    pub async fn find_published_in_series(
+        conn: &DbConn,
+        public_url: &str,
+        series_id: i32,
+    ) -> DbResult<Vec<PostWithRelations>> {
+        let post_ids: Vec<i32> = super::super::post_series_post::Entity::find()
+            .filter(super::super::post_series_post::Column::SeriesId.eq(series_id))
+            .order_by_asc(super::super::post_series_post::Column::SortOrder)
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|row| row.post_id)
+            .collect();
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let posts_joined = Self::build_post_query_with_relations(public_url)
+            .filter(Column::Status.eq(PostStatus::Published))
+            .filter(Column::Id.is_in(post_ids.clone()))
+            .into_model::<PostWithJoinedData>()
+            .all(conn)
+            .await?;
+        let mut posts = Self::hydrate(conn, public_url, posts_joined).await?;
+        posts.sort_by_key(|post| post_ids.iter().position(|id| *id == post.id));
+        Ok(posts)
+    }
 
+    /// Resolve tags and co-authors for joined post rows, one query each.
+    async fn hydrate(
+        conn: &DbConn,
+        public_url: &str,
+        posts_joined: Vec<PostWithJoinedData>,
+    ) -> DbResult<Vec<PostWithRelations>> {
         // Collect all tag IDs from each post into a set
         let all_tag_ids: HashSet<i32> = posts_joined
             .iter()
@@ -545,7 +799,7 @@ impl Entity {
         };
 
         // Map joined data to PostWithRelations
-        let posts_with_relations: Vec<PostWithRelations> = posts_joined
+        let mut posts_with_relations: Vec<PostWithRelations> = posts_joined
             .into_iter()
             .map(|joined_data| {
                 let post_tags = joined_data
@@ -558,8 +812,9 @@ impl Entity {
                 joined_data.into_relation(post_tags)
             })
             .collect();
+        Self::attach_co_authors(conn, public_url, &mut posts_with_relations).await?;
 
-        Ok((posts_with_relations, total))
+        Ok(posts_with_relations)
     }
 
     pub async fn find_published_paginated(
@@ -579,6 +834,7 @@ impl Entity {
             category_id: query.category_id,
             search: None,
             tag_ids: query.tag_ids,
+            locale: query.locale,
             created_at_gt: None,
             created_at_lt: None,
             updated_at_gt: None,
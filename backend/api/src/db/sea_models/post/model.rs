@@ -22,6 +22,11 @@ pub struct Model {
     pub view_count: i32,
     pub likes_count: i32,
     pub tag_ids: Vec<i32>,
+    pub locale: String,
+    pub translation_group_id: Option<Uuid>,
+    /// Plain-text rendering of `content`, feeding the full-text index.
+    #[serde(skip)]
+    pub search_text: Option<String>,
 
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
@@ -85,4 +90,18 @@ impl Related<super::super::media::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+// Any save that sets `content` re-renders `search_text` through the shared
+// EditorJS renderer, so the search index always tracks the body callers wrote.
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, _insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if let sea_orm::ActiveValue::Set(content) = &self.content {
+            self.search_text =
+                sea_orm::ActiveValue::Set(Some(ruxlog_types::editorjs::to_plain_text(content)));
+        }
+        Ok(self)
+    }
+}
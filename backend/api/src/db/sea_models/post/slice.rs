@@ -1,6 +1,7 @@
 use super::PostStatus;
+use crate::db::sea_models::media::responsive::ResponsiveImage;
 use chrono::{DateTime, FixedOffset};
-use sea_orm::prelude::{DateTimeWithTimeZone, Json};
+use sea_orm::prelude::{DateTimeWithTimeZone, Json, Uuid};
 use sea_orm::FromQueryResult;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,11 @@ pub struct NewPost {
     pub view_count: i32,
     pub likes_count: i32,
     pub tag_ids: Vec<i32>,
+    pub locale: String,
+    /// Existing post this one translates; joins (or starts) its translation group.
+    pub translation_of: Option<i32>,
+    /// Users credited after `author_id`, in order.
+    pub co_author_ids: Vec<i32>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -58,6 +64,9 @@ pub struct UpdatePost {
     pub view_count: Option<i32>,
     pub likes_count: Option<i32>,
     pub tag_ids: Option<Vec<i32>>,
+    pub locale: Option<String>,
+    pub translation_of: Option<i32>,
+    pub co_author_ids: Option<Vec<i32>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -70,6 +79,7 @@ pub struct PostQuery {
     pub category_id: Option<i32>,
     pub search: Option<String>,
     pub tag_ids: Option<Vec<i32>>,
+    pub locale: Option<String>,
     // Date range filters
     pub created_at_gt: Option<DateTimeWithTimeZone>,
     pub created_at_lt: Option<DateTimeWithTimeZone>,
@@ -103,11 +113,23 @@ pub struct PostTag {
 pub struct PostAuthor {
     pub id: i32,
     pub name: String,
-    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub profile_slug: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<AuthorMedia>,
 }
 
+/// Another locale's version of the same post, for language switchers and
+/// hreflang alternates.
+#[derive(Clone, Debug, Serialize, Deserialize, FromQueryResult)]
+pub struct PostTranslation {
+    pub id: i32,
+    pub locale: String,
+    pub slug: String,
+    pub title: String,
+    pub status: PostStatus,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PostFeaturedImage {
     pub id: i32,
@@ -117,6 +139,15 @@ pub struct PostFeaturedImage {
     pub width: Option<i32>,
     pub height: Option<i32>,
     pub size: i64,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub license: Option<String>,
+    pub focal_x: Option<f64>,
+    pub focal_y: Option<f64>,
+    /// `<picture>` sources; filled in by the read handlers, not the query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responsive: Option<ResponsiveImage>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -144,9 +175,20 @@ pub struct PostWithRelations {
     #[serde(skip_serializing_if = "Vec::is_empty", default = "Vec::new")]
     pub tags: Vec<PostTag>,
     pub author: PostAuthor,
+    /// Further credited authors after `author`, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub co_authors: Vec<PostAuthor>,
 
     pub comment_count: i64,
 
+    pub locale: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub translation_group_id: Option<Uuid>,
+    /// Other versions in the translation group; filled in by the single-post
+    /// read handler, empty in listings.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub translations: Vec<PostTranslation>,
+
     // ── Paywall (plan Phase 4c) ──────────────────────────────────────────
     /// Access policy for this post. Defaults to `Free`; the read-path controllers
     /// overwrite it from `post_access` and clear `content` when `has_access` is
@@ -198,10 +240,12 @@ pub struct PostWithJoinedData {
     pub likes_count: i32,
     pub tag_ids: Vec<i32>,
     pub category_id: i32,
+    pub locale: String,
+    pub translation_group_id: Option<Uuid>,
 
     // Author fields from join
     pub author_name: String,
-    pub author_email: String,
+    pub author_profile_slug: Option<String>,
     pub author_avatar_id: Option<i32>,
 
     // Author avatar media fields from join
@@ -242,6 +286,12 @@ pub struct PostWithJoinedData {
     pub featured_image_width: Option<i32>,
     pub featured_image_height: Option<i32>,
     pub featured_image_size: Option<i64>,
+    pub featured_image_alt_text: Option<String>,
+    pub featured_image_caption: Option<String>,
+    pub featured_image_credit: Option<String>,
+    pub featured_image_license: Option<String>,
+    pub featured_image_focal_x: Option<f64>,
+    pub featured_image_focal_y: Option<f64>,
 
     pub comment_count: i64,
 }
@@ -323,6 +373,13 @@ impl PostWithJoinedData {
                 width: self.featured_image_width,
                 height: self.featured_image_height,
                 size,
+                alt_text: self.featured_image_alt_text.clone(),
+                caption: self.featured_image_caption.clone(),
+                credit: self.featured_image_credit.clone(),
+                license: self.featured_image_license.clone(),
+                focal_x: self.featured_image_focal_x,
+                focal_y: self.featured_image_focal_y,
+                responsive: None,
             })
         } else {
             None
@@ -353,10 +410,14 @@ impl PostWithJoinedData {
             author: PostAuthor {
                 id: self.author_id,
                 name: self.author_name.clone(),
-                email: self.author_email.clone(),
+                profile_slug: self.author_profile_slug.clone(),
                 avatar,
             },
+            co_authors: Vec::new(),
             comment_count: self.comment_count,
+            locale: self.locale.clone(),
+            translation_group_id: self.translation_group_id,
+            translations: Vec::new(),
             // Default to free + full access; public read-path controllers
             // overwrite these after consulting the paywall (`services/paywall`).
             access_type: PostAccessType::Free,
@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{entity::prelude::*, ConnectionTrait, QueryOrder, Set};
+
+use super::*;
+use crate::db::sea_models::{
+    post::{AuthorMedia, PostAuthor},
+    user,
+};
+use crate::error::DbResult;
+
+impl Entity {
+    /// Replace a post's co-authors with `user_ids`, in that order. The owner
+    /// and unknown users are dropped, as are repeats.
+    pub async fn set_for_post<C>(
+        conn: &C,
+        post_id: i32,
+        owner_id: i32,
+        user_ids: Vec<i32>,
+    ) -> DbResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        let existing: HashSet<i32> = user::Entity::find()
+            .filter(user::Column::Id.is_in(user_ids.clone()))
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|u| u.id)
+            .collect();
+        let mut seen = HashSet::new();
+        let credited: Vec<i32> = user_ids
+            .into_iter()
+            .filter(|id| *id != owner_id && existing.contains(id) && seen.insert(*id))
+            .collect();
+
+        Self::delete_many()
+            .filter(Column::PostId.eq(post_id))
+            .exec(conn)
+            .await?;
+        if credited.is_empty() {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().fixed_offset();
+        let rows = credited
+            .into_iter()
+            .enumerate()
+            .map(|(position, user_id)| ActiveModel {
+                post_id: Set(post_id),
+                user_id: Set(user_id),
+                position: Set(position as i32),
+                created_at: Set(now),
+                ..Default::default()
+            });
+        Self::insert_many(rows).exec(conn).await?;
+        Ok(())
+    }
+
+    /// Co-authors of each post in `post_ids`, keyed by post and in credit
+    /// order. Posts without co-authors are absent from the map.
+    pub async fn find_for_posts(
+        conn: &DbConn,
+        public_url: &str,
+        post_ids: &[i32],
+    ) -> DbResult<HashMap<i32, Vec<PostAuthor>>> {
+        if post_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let credits = Self::find()
+            .filter(Column::PostId.is_in(post_ids.to_vec()))
+            .order_by_asc(Column::PostId)
+            .order_by_asc(Column::Position)
+            .all(conn)
+            .await?;
+        if credits.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let user_ids: HashSet<i32> = credits.iter().map(|c| c.user_id).collect();
+        let users = user::Entity::find()
+            .filter(user::Column::Id.is_in(user_ids))
+            .all(conn)
+            .await?;
+        let authors: HashMap<i32, PostAuthor> =
+            user::Entity::load_media_for_users(conn, public_url, users)
+                .await?
+                .into_iter()
+                .map(|(user, avatar)| {
+                    (
+                        user.id,
+                        PostAuthor {
+                            id: user.id,
+                            name: user.name,
+                            profile_slug: user.profile_slug,
+                            avatar: avatar.map(|m| AuthorMedia {
+                                id: m.id,
+                                object_key: m.object_key,
+                                file_url: m.file_url,
+                                mime_type: m.mime_type,
+                                width: m.width,
+                                height: m.height,
+                                size: m.size,
+                            }),
+                        },
+                    )
+                })
+                .collect();
+
+        let mut by_post: HashMap<i32, Vec<PostAuthor>> = HashMap::new();
+        for credit in credits {
+            if let Some(author) = authors.get(&credit.user_id) {
+                by_post
+                    .entry(credit.post_id)
+                    .or_default()
+                    .push(author.clone());
+            }
+        }
+        Ok(by_post)
+    }
+}
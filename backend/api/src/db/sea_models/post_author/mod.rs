@@ -0,0 +1,4 @@
+mod actions;
+mod model;
+
+pub use model::*;
@@ -4,6 +4,13 @@ use tracing::{error, info, instrument, warn};
 
 use super::*;
 
+/// True when the commenter is the post's author or one of its co-authors.
+const POST_AUTHOR_SQL: &str =
+    "(EXISTS (SELECT 1 FROM posts WHERE posts.id = post_comments.post_id \
+     AND posts.author_id = post_comments.user_id) \
+     OR EXISTS (SELECT 1 FROM post_authors WHERE post_authors.post_id = post_comments.post_id \
+     AND post_authors.user_id = post_comments.user_id))";
+
 impl Entity {
     pub const PER_PAGE: u64 = 20;
 
@@ -13,8 +20,12 @@ impl Entity {
         let comment = ActiveModel {
             post_id: Set(new_comment.post_id),
             user_id: Set(new_comment.user_id),
+            parent_id: Set(new_comment.parent_id),
             content: Set(new_comment.content),
             likes_count: Set(new_comment.likes_count.unwrap_or(0)),
+            status: Set(new_comment.status),
+            spam_score: Set(new_comment.spam_score),
+            spam_reasons: Set(serde_json::json!(new_comment.spam_reasons)),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -27,6 +38,8 @@ impl Entity {
                     comment_id = model.id,
                     post_id = model.post_id,
                     user_id = model.user_id,
+                    status = ?model.status,
+                    spam_score = model.spam_score,
                     "Comment created"
                 );
                 Ok(model)
@@ -61,6 +74,15 @@ impl Entity {
             if let Some(content) = update_comment.content {
                 comment_active.content = Set(content);
             }
+            if let Some(status) = update_comment.status {
+                comment_active.status = Set(status);
+            }
+            if let Some(score) = update_comment.spam_score {
+                comment_active.spam_score = Set(score);
+            }
+            if let Some(reasons) = update_comment.spam_reasons {
+                comment_active.spam_reasons = Set(serde_json::json!(reasons));
+            }
 
             comment_active.updated_at = Set(update_comment.updated_at);
 
@@ -104,31 +126,64 @@ impl Entity {
     }
 
     /// Find all comments by post ID (public use)
+    ///
+    /// Comments from shadow-banned authors are left out unless `viewer_id`
+    /// is that author, so they keep seeing their own comments. Each comment
+    /// carries its author's badges and reaction totals.
     #[instrument(skip(conn), fields(post_id))]
     pub async fn find_all_by_post(
         conn: &DbConn,
         public_url: &str,
         post_id: i32,
+        viewer_id: Option<i32>,
+        sort: CommentSort,
     ) -> DbResult<Vec<CommentWithUser>> {
         use super::super::media::url::public_file_url_expr;
         use super::super::user::Column as UserColumn;
+        use super::super::user_ban::actions::active_shadow_ban_exists_sql;
         use sea_orm::prelude::Expr;
         use sea_orm::sea_query::Alias;
-        use sea_orm::{JoinType, QuerySelect};
+        use sea_orm::{Condition, JoinType, QuerySelect};
+
+        let shadow_banned_sql = active_shadow_ban_exists_sql("post_comments.user_id");
+        let mut visible_authors =
+            Condition::any().add(Expr::cust(format!("NOT {}", shadow_banned_sql)));
+        if let Some(viewer_id) = viewer_id {
+            visible_authors = visible_authors.add(Column::UserId.eq(viewer_id));
+        }
+
+        let ordered = match sort {
+            CommentSort::Oldest => Self::find().order_by(Column::CreatedAt, Order::Asc),
+            CommentSort::Newest => Self::find().order_by(Column::CreatedAt, Order::Desc),
+            CommentSort::Top => Self::find()
+                .order_by(Column::LikesCount, Order::Desc)
+                .order_by(Column::CreatedAt, Order::Asc),
+        };
 
-        let comments_joined = Self::find()
+        let comments_joined = ordered
             .select_only()
             .column(Column::Id)
             .column(Column::PostId)
             .column(Column::UserId)
+            .column(Column::ParentId)
             .column(Column::Content)
             .column(Column::LikesCount)
             .column(Column::Hidden)
             .column(Column::FlagsCount)
+            .column(Column::Status)
+            .column(Column::SpamScore)
+            .column(Column::SpamReasons)
+            .column(Column::ModeratedAt)
             .column(Column::CreatedAt)
             .column(Column::UpdatedAt)
             .column_as(UserColumn::Name, "user_name")
             .column_as(UserColumn::AvatarId, "user_avatar_id")
+            .column_as(UserColumn::Role, "user_role")
+            .expr_as(Expr::cust(POST_AUTHOR_SQL), "is_post_author")
+            .expr_as(
+                Expr::cust(shadow_banned_sql.clone()),
+                "author_shadow_banned",
+            )
             .join(JoinType::InnerJoin, Relation::User.def())
             .join_as(
                 JoinType::LeftJoin,
@@ -176,7 +231,8 @@ impl Entity {
             )
             .filter(Column::PostId.eq(post_id))
             .filter(Column::Hidden.eq(false))
-            .order_by(Column::CreatedAt, Order::Asc)
+            .filter(Column::Status.eq(CommentStatus::Approved))
+            .filter(visible_authors)
             // DOS-COMMENTLIST-1: cap the result set so a heavily-commented post
             // cannot force an unbounded SELECT + 3-table join + serialization on
             // every public request. 500 is a generous ceiling; true pagination
@@ -186,9 +242,17 @@ impl Entity {
             .all(conn)
             .await?;
 
+        let comment_ids: Vec<i32> = comments_joined.iter().map(|c| c.id).collect();
+        let mut reactions =
+            super::super::comment_reaction::Entity::counts_by_comment(conn, &comment_ids).await?;
+
         let comments = comments_joined
             .into_iter()
-            .map(|c| c.into_comment_with_user())
+            .map(|c| {
+                let mut comment = c.into_comment_with_user().without_moderation();
+                comment.reactions = reactions.remove(&comment.id).unwrap_or_default();
+                comment
+            })
             .collect();
 
         Ok(comments)
@@ -202,23 +266,37 @@ impl Entity {
     ) -> DbResult<(Vec<CommentWithUser>, u64)> {
         use super::super::media::url::public_file_url_expr;
         use super::super::user::Column as UserColumn;
+        use super::super::user_ban::actions::active_shadow_ban_exists_sql;
         use sea_orm::prelude::Expr;
         use sea_orm::sea_query::Alias;
         use sea_orm::{JoinType, QuerySelect};
 
+        let shadow_banned_sql = active_shadow_ban_exists_sql("post_comments.user_id");
+
         let mut comment_query = Self::find()
             .select_only()
             .column(Column::Id)
             .column(Column::PostId)
             .column(Column::UserId)
+            .column(Column::ParentId)
             .column(Column::Content)
             .column(Column::LikesCount)
             .column(Column::Hidden)
             .column(Column::FlagsCount)
+            .column(Column::Status)
+            .column(Column::SpamScore)
+            .column(Column::SpamReasons)
+            .column(Column::ModeratedAt)
             .column(Column::CreatedAt)
             .column(Column::UpdatedAt)
             .column_as(UserColumn::Name, "user_name")
             .column_as(UserColumn::AvatarId, "user_avatar_id")
+            .column_as(UserColumn::Role, "user_role")
+            .expr_as(Expr::cust(POST_AUTHOR_SQL), "is_post_author")
+            .expr_as(
+                Expr::cust(shadow_banned_sql.clone()),
+                "author_shadow_banned",
+            )
             .join(JoinType::InnerJoin, Relation::User.def())
             .join_as(
                 JoinType::LeftJoin,
@@ -277,6 +355,21 @@ impl Entity {
             comment_query = comment_query.filter(Column::Content.contains(search_term));
         }
 
+        if let Some(status) = query.status {
+            comment_query = comment_query.filter(Column::Status.eq(status));
+        }
+
+        match query.shadow_banned {
+            Some(true) => {
+                comment_query = comment_query.filter(Expr::cust(shadow_banned_sql.clone()));
+            }
+            Some(false) => {
+                comment_query =
+                    comment_query.filter(Expr::cust(format!("NOT {}", shadow_banned_sql)));
+            }
+            None => {}
+        }
+
         match query.hidden_filter.unwrap_or(HiddenFilter::Visible) {
             HiddenFilter::All => {}
             HiddenFilter::Hidden => {
@@ -388,6 +481,55 @@ impl Entity {
         }
     }
 
+    /// Approve or reject a comment. Approving also lifts a flag auto-hide,
+    /// since a moderator has now reviewed it.
+    pub async fn admin_moderate(
+        conn: &DbConn,
+        comment_id: i32,
+        moderator_id: i32,
+        status: CommentStatus,
+    ) -> DbResult<Option<Model>> {
+        let existing = Self::find_by_id(comment_id).one(conn).await?;
+        if let Some(model) = existing {
+            let now = chrono::Utc::now().fixed_offset();
+            let mut active: ActiveModel = model.into();
+            active.status = Set(status);
+            if status == CommentStatus::Approved {
+                active.hidden = Set(false);
+            }
+            active.moderated_by = Set(Some(moderator_id));
+            active.moderated_at = Set(Some(now));
+            active.updated_at = Set(now);
+            let updated = active.update(conn).await?;
+            info!(comment_id, moderator_id, status = ?status, "Comment moderated");
+            Ok(Some(updated))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Hide a comment once its flag count reaches `threshold`. Returns whether
+    /// this call hid it; already-hidden comments are left alone.
+    pub async fn hide_if_flagged(conn: &DbConn, comment_id: i32, threshold: i32) -> DbResult<bool> {
+        use sea_orm::prelude::Expr;
+
+        if threshold <= 0 {
+            return Ok(false);
+        }
+        let res = Self::update_many()
+            .col_expr(Column::Hidden, Expr::value(true))
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::Id.eq(comment_id))
+            .filter(Column::Hidden.eq(false))
+            .filter(Column::FlagsCount.gte(threshold))
+            .exec(conn)
+            .await?;
+        Ok(res.rows_affected > 0)
+    }
+
     pub async fn admin_delete(conn: &DbConn, comment_id: i32) -> DbResult<u64> {
         let res = Self::delete_by_id(comment_id).exec(conn).await?;
         Ok(res.rows_affected)
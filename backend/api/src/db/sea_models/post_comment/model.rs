@@ -1,6 +1,8 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub use ruxlog_types::enums::CommentStatus;
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "post_comments")]
 pub struct Model {
@@ -8,14 +10,31 @@ pub struct Model {
     pub id: i32,
     pub post_id: i32,
     pub user_id: i32,
+    /// The comment this one replies to, if any.
+    pub parent_id: Option<i32>,
     pub content: String,
     pub likes_count: i32,
     pub hidden: bool,
     pub flags_count: i32,
+    pub status: CommentStatus,
+    // Spam scorer output; only surfaced to moderators through the admin list.
+    #[serde(skip_serializing)]
+    pub spam_score: i32,
+    #[serde(skip_serializing)]
+    pub spam_reasons: Json,
+    pub moderated_by: Option<i32>,
+    pub moderated_at: Option<DateTimeWithTimeZone>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
 
+impl Model {
+    /// Approved and not hidden: what readers of the post can see.
+    pub fn is_published(&self) -> bool {
+        self.status == CommentStatus::Approved && !self.hidden
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
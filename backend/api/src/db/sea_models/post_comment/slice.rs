@@ -1,6 +1,50 @@
-use sea_orm::{prelude::DateTimeWithTimeZone, FromQueryResult};
+use sea_orm::{
+    prelude::{DateTimeWithTimeZone, Json},
+    FromQueryResult,
+};
 use serde::{Deserialize, Serialize};
 
+use super::super::comment_reaction::ReactionCount;
+use super::super::user::UserRole;
+use super::CommentStatus;
+
+/// Order of the public comment list.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentSort {
+    /// Oldest first, the reading order of a conversation.
+    #[default]
+    Oldest,
+    Newest,
+    /// Most liked first, oldest first among ties.
+    Top,
+}
+
+/// Role badge shown next to a commenter's name.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentBadge {
+    /// The commenter wrote (or co-wrote) the post.
+    PostAuthor,
+    Moderator,
+    Admin,
+}
+
+impl CommentBadge {
+    pub fn for_author(role: UserRole, is_post_author: bool) -> Vec<Self> {
+        let mut badges = Vec::new();
+        if is_post_author {
+            badges.push(CommentBadge::PostAuthor);
+        }
+        match role {
+            UserRole::SuperAdmin | UserRole::Admin => badges.push(CommentBadge::Admin),
+            UserRole::Moderator => badges.push(CommentBadge::Moderator),
+            UserRole::Author | UserRole::User => {}
+        }
+        badges
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HiddenFilter {
@@ -46,14 +90,22 @@ pub struct CommentUserMedia {
 pub struct NewComment {
     pub post_id: i32,
     pub user_id: i32,
+    pub parent_id: Option<i32>,
     pub content: String,
     pub likes_count: Option<i32>,
+    pub status: CommentStatus,
+    pub spam_score: i32,
+    pub spam_reasons: Vec<String>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct UpdateComment {
     pub content: Option<String>,
     pub updated_at: DateTimeWithTimeZone,
+    /// Re-assessed moderation outcome when the content changed.
+    pub status: Option<CommentStatus>,
+    pub spam_score: Option<i32>,
+    pub spam_reasons: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
@@ -62,6 +114,8 @@ pub struct CommentQuery {
     pub post_id: Option<i32>,
     pub user_id: Option<i32>,
     pub search_term: Option<String>,
+    pub status: Option<CommentStatus>,
+    pub shadow_banned: Option<bool>,
     pub hidden_filter: Option<HiddenFilter>,
     pub flag_filter: Option<FlagFilter>,
     pub min_flags: Option<i32>,
@@ -78,14 +132,22 @@ pub struct CommentWithUserJoined {
     pub id: i32,
     pub post_id: i32,
     pub user_id: i32,
+    pub parent_id: Option<i32>,
     pub content: String,
     pub likes_count: i32,
     pub hidden: bool,
     pub flags_count: i32,
+    pub status: CommentStatus,
+    pub spam_score: i32,
+    pub spam_reasons: Json,
+    pub moderated_at: Option<DateTimeWithTimeZone>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
     pub user_name: String,
     pub user_avatar_id: Option<i32>,
+    pub user_role: UserRole,
+    pub is_post_author: bool,
+    pub author_shadow_banned: bool,
 
     // User avatar media fields from join
     pub user_avatar_object_key: Option<String>,
@@ -122,14 +184,22 @@ impl CommentWithUserJoined {
             id: self.id,
             post_id: self.post_id,
             user_id: self.user_id,
+            parent_id: self.parent_id,
             content: self.content,
             likes_count: self.likes_count,
             hidden: self.hidden,
             flags_count: self.flags_count,
+            status: self.status,
+            spam_score: Some(self.spam_score),
+            spam_reasons: serde_json::from_value(self.spam_reasons).unwrap_or_default(),
+            moderated_at: self.moderated_at,
+            author_shadow_banned: self.author_shadow_banned,
             created_at: self.created_at,
             updated_at: self.updated_at,
             user_name: self.user_name,
             user_avatar: avatar,
+            badges: CommentBadge::for_author(self.user_role, self.is_post_author),
+            reactions: Vec::new(),
         }
     }
 }
@@ -139,15 +209,43 @@ pub struct CommentWithUser {
     pub id: i32,
     pub post_id: i32,
     pub user_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i32>,
     pub content: String,
     pub likes_count: i32,
     pub hidden: bool,
     pub flags_count: i32,
+    pub status: CommentStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spam_score: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spam_reasons: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderated_at: Option<DateTimeWithTimeZone>,
+    /// Whether the author has an active shadow ban (dashboard only).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub author_shadow_banned: bool,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
     pub user_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_avatar: Option<CommentUserMedia>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub badges: Vec<CommentBadge>,
+    /// Emoji reaction totals (public list only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<ReactionCount>,
+}
+
+impl CommentWithUser {
+    /// Drop the moderation details before a comment goes to the public site.
+    pub fn without_moderation(mut self) -> Self {
+        self.spam_score = None;
+        self.spam_reasons.clear();
+        self.moderated_at = None;
+        self.author_shadow_banned = false;
+        self
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -155,3 +253,28 @@ pub struct CommentTree {
     pub comment: CommentWithUser,
     pub replies: Vec<CommentWithUser>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badges_combine_post_authorship_and_staff_role() {
+        assert_eq!(
+            CommentBadge::for_author(UserRole::User, false),
+            Vec::<CommentBadge>::new()
+        );
+        assert_eq!(
+            CommentBadge::for_author(UserRole::Author, true),
+            vec![CommentBadge::PostAuthor]
+        );
+        assert_eq!(
+            CommentBadge::for_author(UserRole::Moderator, false),
+            vec![CommentBadge::Moderator]
+        );
+        assert_eq!(
+            CommentBadge::for_author(UserRole::SuperAdmin, true),
+            vec![CommentBadge::PostAuthor, CommentBadge::Admin]
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use sea_orm::{
+    entity::prelude::*,
+    sea_query::{Expr, Func, OnConflict},
+    Condition, QueryOrder, Set,
+};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+impl Entity {
+    pub const PER_PAGE: u64 = 20;
+
+    pub async fn create(conn: &DbConn, new_redirect: NewRedirect) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let redirect = ActiveModel {
+            source_path: Set(new_redirect.source_path),
+            target_path: Set(new_redirect.target_path),
+            match_type: Set(new_redirect.match_type),
+            status: Set(new_redirect.status),
+            is_automatic: Set(false),
+            note: Set(new_redirect.note),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        redirect.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Apply an admin edit. The rule stops counting as automatic once a
+    /// person has touched it.
+    pub async fn update(
+        conn: &DbConn,
+        redirect_id: i32,
+        update: UpdateRedirect,
+    ) -> DbResult<Option<Model>> {
+        let Some(existing) = Self::find_by_id(redirect_id).one(conn).await? else {
+            return Ok(None);
+        };
+        let mut active: ActiveModel = existing.into();
+        if let Some(source_path) = update.source_path {
+            active.source_path = Set(source_path);
+        }
+        if let Some(target_path) = update.target_path {
+            active.target_path = Set(Some(target_path));
+        }
+        if let Some(match_type) = update.match_type {
+            active.match_type = Set(match_type);
+        }
+        if let Some(status) = update.status {
+            active.status = Set(status);
+            if status == RedirectStatus::Gone {
+                active.target_path = Set(None);
+            }
+        }
+        if let Some(note) = update.note {
+            active.note = Set(Some(note));
+        }
+        active.is_automatic = Set(false);
+        active.updated_at = Set(update.updated_at);
+
+        active
+            .update(conn)
+            .await
+            .map(Some)
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn delete(conn: &DbConn, redirect_id: i32) -> DbResult<u64> {
+        let result = Self::delete_by_id(redirect_id).exec(conn).await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn find_with_query(
+        conn: &DbConn,
+        query: RedirectQuery,
+    ) -> DbResult<(Vec<Model>, u64)> {
+        let mut redirect_query = Self::find();
+
+        if let Some(search_term) = query.search {
+            let search_pattern = format!("%{}%", search_term.to_lowercase());
+            redirect_query = redirect_query.filter(
+                Condition::any()
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(Column::SourcePath)))
+                            .like(&search_pattern),
+                    )
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(Column::TargetPath)))
+                            .like(&search_pattern),
+                    ),
+            );
+        }
+        if let Some(match_type) = query.match_type {
+            redirect_query = redirect_query.filter(Column::MatchType.eq(match_type));
+        }
+        if let Some(status) = query.status {
+            redirect_query = redirect_query.filter(Column::Status.eq(status));
+        }
+        if let Some(is_automatic) = query.is_automatic {
+            redirect_query = redirect_query.filter(Column::IsAutomatic.eq(is_automatic));
+        }
+
+        match query.sorts {
+            Some(sorts) => {
+                for sort in sorts {
+                    let column = match sort.field.as_str() {
+                        "id" => Some(Column::Id),
+                        "source_path" => Some(Column::SourcePath),
+                        "target_path" => Some(Column::TargetPath),
+                        "status" => Some(Column::Status),
+                        "created_at" => Some(Column::CreatedAt),
+                        "updated_at" => Some(Column::UpdatedAt),
+                        _ => None,
+                    };
+                    if let Some(col) = column {
+                        redirect_query = redirect_query.order_by(col, sort.order);
+                    }
+                }
+            }
+            None => redirect_query = redirect_query.order_by_desc(Column::UpdatedAt),
+        }
+
+        let page = match query.page {
+            Some(p) if p > 0 => p,
+            _ => 1,
+        };
+        let paginator = redirect_query.paginate(conn, Self::PER_PAGE);
+        let total = paginator.num_items().await?;
+        let results = paginator.fetch_page(page - 1).await?;
+        Ok((results, total))
+    }
+
+    /// The rule for a requested path: an exact rule for the path itself
+    /// wins, otherwise the longest matching prefix rule.
+    pub async fn resolve(conn: &DbConn, path: &str) -> DbResult<Option<ResolvedRedirect>> {
+        let Some(path) = normalize_path(path) else {
+            return Ok(None);
+        };
+        let rules = Self::find()
+            .filter(Column::SourcePath.is_in(candidate_prefixes(&path)))
+            .all(conn)
+            .await?;
+
+        let rule = rules
+            .iter()
+            .find(|r| r.source_path == path && r.match_type == RedirectMatchType::Exact)
+            .or_else(|| {
+                rules
+                    .iter()
+                    .filter(|r| r.match_type == RedirectMatchType::Prefix)
+                    .max_by_key(|r| r.source_path.len())
+            });
+        Ok(rule.map(|rule| apply_rule(rule, &path)))
+    }
+
+    /// Record that content moved from `old_path` to `new_path`: a permanent
+    /// redirect from the old path, and any rule pointing at the old path
+    /// repointed so visitors never follow a chain.
+    pub async fn record_move(conn: &DbConn, old_path: &str, new_path: &str) -> DbResult<()> {
+        if old_path == new_path {
+            return Ok(());
+        }
+        Self::release(conn, new_path).await?;
+        Self::update_many()
+            .col_expr(Column::TargetPath, Expr::value(new_path))
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::TargetPath.eq(old_path))
+            .exec(conn)
+            .await?;
+        Self::upsert_automatic(conn, old_path, Some(new_path), RedirectStatus::Permanent).await
+    }
+
+    /// Record that the content at `path` was deleted. Rules that redirected
+    /// to it now answer 410 as well.
+    pub async fn record_gone(conn: &DbConn, path: &str) -> DbResult<()> {
+        Self::update_many()
+            .col_expr(Column::TargetPath, Expr::value(Option::<String>::None))
+            .col_expr(Column::Status, Expr::value(RedirectStatus::Gone))
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(Column::TargetPath.eq(path))
+            .exec(conn)
+            .await?;
+        Self::upsert_automatic(conn, path, None, RedirectStatus::Gone).await
+    }
+
+    /// Drop any rule for `path` now that live content answers there again.
+    pub async fn release(conn: &DbConn, path: &str) -> DbResult<()> {
+        Self::delete_many()
+            .filter(Column::SourcePath.eq(path))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn upsert_automatic(
+        conn: &DbConn,
+        source_path: &str,
+        target_path: Option<&str>,
+        status: RedirectStatus,
+    ) -> DbResult<()> {
+        let now = chrono::Utc::now().fixed_offset();
+        let redirect = ActiveModel {
+            source_path: Set(source_path.to_string()),
+            target_path: Set(target_path.map(str::to_string)),
+            match_type: Set(RedirectMatchType::Exact),
+            status: Set(status),
+            is_automatic: Set(true),
+            note: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        Self::insert(redirect)
+            .on_conflict(
+                OnConflict::column(Column::SourcePath)
+                    .update_columns([
+                        Column::TargetPath,
+                        Column::MatchType,
+                        Column::Status,
+                        Column::IsAutomatic,
+                        Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+mod actions;
+mod model;
+mod slice;
+
+pub use model::*;
+pub use slice::*;
+
+/// Consumer-site path of a post, under its locale's prefix.
+pub fn post_path(locale: &str, slug: &str) -> String {
+    crate::utils::locale::localized_path(locale, &format!("/posts/{slug}"))
+}
+
+/// Consumer-site path of a category.
+pub fn category_path(slug: &str) -> String {
+    format!("/categories/{slug}")
+}
+
+/// Canonical form of a site path for storing and matching: the query and
+/// fragment dropped, no trailing slash. `None` for anything that isn't a
+/// site-relative path, including protocol-relative `//host` paths and the
+/// home page itself.
+pub fn normalize_path(path: &str) -> Option<String> {
+    let path = path.trim();
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if !path.starts_with('/') || path.starts_with("//") {
+        return None;
+    }
+    if path.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return None;
+    }
+    let path = path.trim_end_matches('/');
+    if path.is_empty() {
+        return None;
+    }
+    Some(path.to_string())
+}
+
+/// Source paths a prefix rule could have to match `path`: the path itself
+/// and each parent at a segment boundary. `/a/b` yields `/a/b` and `/a`, so
+/// a rule for `/a` covers `/a/b` but not `/ab`.
+pub fn candidate_prefixes(path: &str) -> Vec<String> {
+    let mut out = vec![path.to_string()];
+    let mut rest = path;
+    while let Some(idx) = rest.rfind('/') {
+        rest = &rest[..idx];
+        if rest.is_empty() {
+            break;
+        }
+        out.push(rest.to_string());
+    }
+    out
+}
+
+/// Where `rule` sends the already-normalized `path`. Prefix rules carry the
+/// rest of the path over to the target.
+pub fn apply_rule(rule: &Model, path: &str) -> ResolvedRedirect {
+    let location = match (rule.status, &rule.target_path) {
+        (RedirectStatus::Gone, _) | (_, None) => None,
+        (_, Some(target)) => Some(match rule.match_type {
+            RedirectMatchType::Exact => target.clone(),
+            RedirectMatchType::Prefix => {
+                let rest = path.strip_prefix(&rule.source_path).unwrap_or_default();
+                match format!("{}{rest}", target.trim_end_matches('/')) {
+                    joined if joined.is_empty() => "/".to_string(),
+                    joined => joined,
+                }
+            }
+        }),
+    };
+    let status = if location.is_none() {
+        RedirectStatus::Gone
+    } else {
+        rule.status
+    };
+    ResolvedRedirect {
+        redirect_id: rule.id,
+        status,
+        status_code: status.status_code(),
+        location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(
+        source: &str,
+        target: Option<&str>,
+        match_type: RedirectMatchType,
+        status: RedirectStatus,
+    ) -> Model {
+        let now = chrono::Utc::now().fixed_offset();
+        Model {
+            id: 1,
+            source_path: source.to_string(),
+            target_path: target.map(str::to_string),
+            match_type,
+            status,
+            is_automatic: false,
+            note: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn normalizes_site_paths() {
+        assert_eq!(normalize_path("/posts/a/"), Some("/posts/a".to_string()));
+        assert_eq!(
+            normalize_path(" /posts/a?utm=x#top "),
+            Some("/posts/a".to_string())
+        );
+        assert_eq!(normalize_path("/"), None);
+        assert_eq!(normalize_path("posts/a"), None);
+        assert_eq!(normalize_path("//evil.example/x"), None);
+        assert_eq!(normalize_path("https://example.com/a"), None);
+        assert_eq!(normalize_path("/a b"), None);
+    }
+
+    #[test]
+    fn prefixes_stop_at_segment_boundaries() {
+        assert_eq!(
+            candidate_prefixes("/blog/2020/post"),
+            vec!["/blog/2020/post", "/blog/2020", "/blog"]
+        );
+        assert_eq!(candidate_prefixes("/blog"), vec!["/blog"]);
+    }
+
+    #[test]
+    fn prefix_rules_carry_the_rest_of_the_path() {
+        let r = rule(
+            "/blog",
+            Some("/posts/"),
+            RedirectMatchType::Prefix,
+            RedirectStatus::Temporary,
+        );
+        let resolved = apply_rule(&r, "/blog/hello");
+        assert_eq!(resolved.location.as_deref(), Some("/posts/hello"));
+        assert_eq!(resolved.status_code, 302);
+        assert_eq!(apply_rule(&r, "/blog").location.as_deref(), Some("/posts"));
+    }
+
+    #[test]
+    fn exact_and_gone_rules() {
+        let r = rule(
+            "/posts/old",
+            Some("https://example.com/new"),
+            RedirectMatchType::Exact,
+            RedirectStatus::Permanent,
+        );
+        let resolved = apply_rule(&r, "/posts/old");
+        assert_eq!(
+            resolved.location.as_deref(),
+            Some("https://example.com/new")
+        );
+        assert_eq!(resolved.status_code, 301);
+
+        let r = rule(
+            "/posts/removed",
+            None,
+            RedirectMatchType::Exact,
+            RedirectStatus::Gone,
+        );
+        let resolved = apply_rule(&r, "/posts/removed");
+        assert_eq!(resolved.location, None);
+        assert_eq!(resolved.status_code, 410);
+    }
+}
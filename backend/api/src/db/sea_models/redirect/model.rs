@@ -0,0 +1,28 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::{RedirectMatchType, RedirectStatus};
+
+/// A redirect rule for a consumer-site path. `target_path` is a site path
+/// or an absolute URL, and is `None` only for `Gone`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "redirects")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub source_path: String,
+    pub target_path: Option<String>,
+    pub match_type: RedirectMatchType,
+    pub status: RedirectStatus,
+    /// Written by a slug change or deletion rather than by an admin.
+    pub is_automatic: bool,
+    pub note: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::{Deserialize, Serialize};
+
+use super::{RedirectMatchType, RedirectStatus};
+use crate::utils::SortParam;
+
+#[derive(Deserialize, Debug)]
+pub struct NewRedirect {
+    pub source_path: String,
+    pub target_path: Option<String>,
+    pub match_type: RedirectMatchType,
+    pub status: RedirectStatus,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateRedirect {
+    pub source_path: Option<String>,
+    pub target_path: Option<String>,
+    pub match_type: Option<RedirectMatchType>,
+    pub status: Option<RedirectStatus>,
+    pub note: Option<String>,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct RedirectQuery {
+    pub page: Option<u64>,
+    pub search: Option<String>,
+    pub sorts: Option<Vec<SortParam>>,
+    pub match_type: Option<RedirectMatchType>,
+    pub status: Option<RedirectStatus>,
+    pub is_automatic: Option<bool>,
+}
+
+/// Where a requested path should go, after prefix expansion.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ResolvedRedirect {
+    pub redirect_id: i32,
+    pub status: RedirectStatus,
+    pub status_code: u16,
+    /// `None` when the content is gone.
+    pub location: Option<String>,
+}
@@ -0,0 +1,114 @@
+use sea_orm::{entity::prelude::*, sea_query::OnConflict, ConnectionTrait, Set};
+
+use super::*;
+use crate::db::sea_models::media::{self, url::build_public_file_url};
+use crate::error::DbResult;
+
+impl Entity {
+    pub async fn find_for<C>(
+        conn: &C,
+        entity_type: SeoEntityType,
+        entity_id: i32,
+    ) -> DbResult<Option<Model>>
+    where
+        C: ConnectionTrait,
+    {
+        Ok(Self::find()
+            .filter(Column::EntityType.eq(entity_type))
+            .filter(Column::EntityId.eq(entity_id))
+            .one(conn)
+            .await?)
+    }
+
+    /// Overrides for an item in their served shape, with the custom social
+    /// image resolved to a public URL.
+    pub async fn find_meta(
+        conn: &DbConn,
+        public_url: &str,
+        entity_type: SeoEntityType,
+        entity_id: i32,
+    ) -> DbResult<Option<SeoMeta>> {
+        let Some(row) = Self::find_for(conn, entity_type, entity_id).await? else {
+            return Ok(None);
+        };
+        let og_image = match row.og_image_id {
+            Some(id) => media::Entity::find_by_id(conn, id).await?,
+            None => None,
+        };
+        Ok(Some(SeoMeta {
+            meta_title: row.meta_title,
+            meta_description: row.meta_description,
+            canonical_url: row.canonical_url,
+            noindex: row.noindex,
+            og_image: og_image.map(|m| SeoOgImage {
+                id: m.id,
+                file_url: build_public_file_url(public_url, m.bucket.as_deref(), &m.object_key),
+                width: m.width,
+                height: m.height,
+            }),
+        }))
+    }
+
+    /// Replace an item's overrides. An empty set deletes the row instead.
+    pub async fn set<C>(
+        conn: &C,
+        entity_type: SeoEntityType,
+        entity_id: i32,
+        fields: SeoFields,
+    ) -> DbResult<()>
+    where
+        C: ConnectionTrait,
+    {
+        if fields.is_empty() {
+            Self::delete_for(conn, entity_type, entity_id).await?;
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().fixed_offset();
+        let row = ActiveModel {
+            entity_type: Set(entity_type),
+            entity_id: Set(entity_id),
+            meta_title: Set(fields.meta_title),
+            meta_description: Set(fields.meta_description),
+            canonical_url: Set(fields.canonical_url),
+            noindex: Set(fields.noindex),
+            og_image_id: Set(fields.og_image_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        Self::insert(row)
+            .on_conflict(
+                OnConflict::columns([Column::EntityType, Column::EntityId])
+                    .update_columns([
+                        Column::MetaTitle,
+                        Column::MetaDescription,
+                        Column::CanonicalUrl,
+                        Column::Noindex,
+                        Column::OgImageId,
+                        Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Drop an item's overrides; called when the item itself is deleted.
+    pub async fn delete_for<C>(
+        conn: &C,
+        entity_type: SeoEntityType,
+        entity_id: i32,
+    ) -> DbResult<u64>
+    where
+        C: ConnectionTrait,
+    {
+        let result = Self::delete_many()
+            .filter(Column::EntityType.eq(entity_type))
+            .filter(Column::EntityId.eq(entity_id))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::SeoEntityType;
+
+/// Hand-set SEO metadata for one post, category or tag. Unset fields fall
+/// back to values derived from the content.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "seo_overrides")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub entity_type: SeoEntityType,
+    pub entity_id: i32,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
+    pub og_image_id: Option<i32>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::media::Entity",
+        from = "Column::OgImageId",
+        to = "super::super::media::Column::Id",
+        on_delete = "SetNull"
+    )]
+    OgImage,
+}
+
+impl Related<super::super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OgImage.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
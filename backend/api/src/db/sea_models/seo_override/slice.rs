@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The editable override set. Saving replaces all of it; a set with nothing
+/// in it removes the row.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct SeoFields {
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
+    pub og_image_id: Option<i32>,
+}
+
+impl SeoFields {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SeoOgImage {
+    pub id: i32,
+    pub file_url: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+/// Overrides as served alongside a post, category or tag.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SeoMeta {
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub og_image: Option<SeoOgImage>,
+}
@@ -18,6 +18,9 @@ pub struct Model {
     pub current_period_end: Option<DateTimeWithTimeZone>,
     pub cancel_at_period_end: bool,
     pub trial_ends_at: Option<DateTimeWithTimeZone>,
+    /// When the subscription became canceled or expired; cleared if it is
+    /// reactivated.
+    pub canceled_at: Option<DateTimeWithTimeZone>,
     pub metadata: Option<Json>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
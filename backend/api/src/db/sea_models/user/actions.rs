@@ -15,8 +15,7 @@ use crate::db::sea_models::media_usage::EntityType;
 impl Entity {
     pub const PER_PAGE: u64 = 20;
 
-    #[allow(dead_code)]
-    async fn load_media_for_users(
+    pub(crate) async fn load_media_for_users(
         conn: &DbConn,
         public_url: &str,
         users: Vec<Model>,
@@ -121,6 +120,21 @@ impl Entity {
         }
     }
 
+    fn apply_profile(user_active: &mut ActiveModel, profile: AuthorProfileFields) {
+        if let Some(bio) = profile.bio {
+            user_active.bio = Set(bio);
+        }
+        if let Some(website_url) = profile.website_url {
+            user_active.website_url = Set(website_url);
+        }
+        if let Some(social_links) = profile.social_links {
+            user_active.social_links = Set(serde_json::json!(social_links));
+        }
+        if let Some(profile_slug) = profile.profile_slug {
+            user_active.profile_slug = Set(profile_slug);
+        }
+    }
+
     #[instrument(skip(conn, update_user), fields(user_id))]
     pub async fn update(
         conn: &DbConn,
@@ -138,6 +152,7 @@ impl Entity {
             // detected and the stale trust state reset.
             let prev_email = user_model.email.clone();
             let prev_verified = user_model.is_verified;
+            let prev_avatar_id = user_model.avatar_id;
             let mut user_active: ActiveModel = user_model.into();
 
             if let Some(name) = update_user.name {
@@ -176,18 +191,37 @@ impl Entity {
                 }
             }
 
+            if update_user.avatar_id.is_some() {
+                user_active.avatar_id = Set(update_user.avatar_id);
+            }
+
+            Self::apply_profile(&mut user_active, update_user.profile);
+
             user_active.updated_at = Set(update_user.updated_at);
 
-            match user_active.update(conn).await {
-                Ok(updated_user) => {
-                    info!(user_id, "User updated");
-                    Ok(Some(updated_user))
-                }
+            let txn = conn.begin().await?;
+            let updated_user = match user_active.update(&txn).await {
+                Ok(updated_user) => updated_user,
                 Err(err) => {
                     error!(user_id, "Failed to update user: {}", err);
-                    Err(err.into())
+                    return Err(err.into());
                 }
+            };
+            if update_user.avatar_id.is_some() && update_user.avatar_id != prev_avatar_id {
+                super::super::media_usage::Entity::update_usage(
+                    &txn,
+                    prev_avatar_id,
+                    update_user.avatar_id,
+                    EntityType::User,
+                    user_id,
+                    "avatar_id",
+                )
+                .await?;
             }
+            txn.commit().await?;
+
+            info!(user_id, "User updated");
+            Ok(Some(updated_user))
         } else {
             warn!(user_id, "User not found for update");
             Ok(None)
@@ -483,6 +517,8 @@ impl Entity {
                 user_active.is_verified = Set(is_verified);
             }
 
+            Self::apply_profile(&mut user_active, update_user.profile);
+
             user_active.updated_at = Set(update_user.updated_at);
 
             let _updated_user = user_active.update(&txn).await?;
@@ -522,20 +558,20 @@ impl Entity {
         Ok(result.rows_affected)
     }
 
-    pub async fn find_by_id_with_relations(
-        conn: &DbConn,
-        public_url: &str,
-        user_id: i32,
-    ) -> DbResult<UserWithRelations> {
+    fn build_user_query_with_relations(public_url: &str) -> Select<Entity> {
         use super::super::media::url::public_file_url_expr;
 
-        let user_query = Self::find()
+        Self::find()
             .select_only()
             .columns(vec![
                 Column::Id,
                 Column::Name,
                 Column::Email,
                 Column::AvatarId,
+                Column::Bio,
+                Column::WebsiteUrl,
+                Column::SocialLinks,
+                Column::ProfileSlug,
                 Column::IsVerified,
                 Column::Role,
                 Column::TwoFaEnabled,
@@ -586,9 +622,15 @@ impl Entity {
                 )),
                 "avatar_size",
             )
-            .filter(Column::Id.eq(user_id));
+    }
 
-        let row = user_query
+    pub async fn find_by_id_with_relations(
+        conn: &DbConn,
+        public_url: &str,
+        user_id: i32,
+    ) -> DbResult<UserWithRelations> {
+        let row = Self::build_user_query_with_relations(public_url)
+            .filter(Column::Id.eq(user_id))
             .into_model::<UserWithJoinedData>()
             .one(conn)
             .await?;
@@ -600,70 +642,31 @@ impl Entity {
         }
     }
 
+    /// Looks up an author page handle: the user's `profile_slug`, or their
+    /// numeric id while they have not picked one.
+    pub async fn find_by_profile_slug(
+        conn: &DbConn,
+        public_url: &str,
+        slug: &str,
+    ) -> DbResult<Option<UserWithRelations>> {
+        let condition = match slug.parse::<i32>() {
+            Ok(id) => Column::Id.eq(id).and(Column::ProfileSlug.is_null()),
+            Err(_) => Column::ProfileSlug.eq(slug),
+        };
+        let row = Self::build_user_query_with_relations(public_url)
+            .filter(condition)
+            .into_model::<UserWithJoinedData>()
+            .one(conn)
+            .await?;
+        Ok(row.map(UserWithJoinedData::into_relation))
+    }
+
     pub async fn admin_list(
         conn: &DbConn,
         public_url: &str,
         query: AdminUserQuery,
     ) -> DbResult<(Vec<UserWithRelations>, u64)> {
-        use super::super::media::url::public_file_url_expr;
-
-        let mut user_query = Self::find()
-            .select_only()
-            .columns(vec![
-                Column::Id,
-                Column::Name,
-                Column::Email,
-                Column::AvatarId,
-                Column::IsVerified,
-                Column::Role,
-                Column::TwoFaEnabled,
-                Column::CreatedAt,
-                Column::UpdatedAt,
-            ])
-            .join_as(
-                JoinType::LeftJoin,
-                Relation::Media.def(),
-                Alias::new("avatar_media"),
-            )
-            .expr_as(
-                Expr::col((
-                    Alias::new("avatar_media"),
-                    super::super::media::Column::ObjectKey,
-                )),
-                "avatar_object_key",
-            )
-            .expr_as(
-                public_file_url_expr(public_url, "avatar_media"),
-                "avatar_file_url",
-            )
-            .expr_as(
-                Expr::col((
-                    Alias::new("avatar_media"),
-                    super::super::media::Column::MimeType,
-                )),
-                "avatar_mime_type",
-            )
-            .expr_as(
-                Expr::col((
-                    Alias::new("avatar_media"),
-                    super::super::media::Column::Width,
-                )),
-                "avatar_width",
-            )
-            .expr_as(
-                Expr::col((
-                    Alias::new("avatar_media"),
-                    super::super::media::Column::Height,
-                )),
-                "avatar_height",
-            )
-            .expr_as(
-                Expr::col((
-                    Alias::new("avatar_media"),
-                    super::super::media::Column::Size,
-                )),
-                "avatar_size",
-            );
+        let mut user_query = Self::build_user_query_with_relations(public_url);
 
         if let Some(email_filter) = query.email {
             let email_pattern = format!("%{}%", email_filter);
@@ -15,6 +15,11 @@ pub struct Model {
     #[serde(skip_serializing)]
     pub password: Option<String>,
     pub avatar_id: Option<i32>,
+    // Public author profile, shown on `/author/{profile_slug}`.
+    pub bio: Option<String>,
+    pub website_url: Option<String>,
+    pub social_links: Json,
+    pub profile_slug: Option<String>,
     pub is_verified: bool,
     pub role: UserRole,
     pub two_fa_enabled: bool,
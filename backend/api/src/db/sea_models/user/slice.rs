@@ -1,6 +1,6 @@
 use super::UserRole;
 use chrono::{DateTime, FixedOffset};
-use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::prelude::{DateTimeWithTimeZone, Json};
 use sea_orm::FromQueryResult;
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +27,10 @@ pub struct UserWithRelations {
     pub updated_at: DateTimeWithTimeZone,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<UserMedia>,
+    pub bio: Option<String>,
+    pub website_url: Option<String>,
+    pub social_links: Vec<SocialLink>,
+    pub profile_slug: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, FromQueryResult)]
@@ -36,6 +40,10 @@ pub struct UserWithJoinedData {
     pub name: String,
     pub email: String,
     pub avatar_id: Option<i32>,
+    pub bio: Option<String>,
+    pub website_url: Option<String>,
+    pub social_links: Json,
+    pub profile_slug: Option<String>,
     pub is_verified: bool,
     pub role: UserRole,
     pub two_fa_enabled: bool,
@@ -83,6 +91,57 @@ impl UserWithJoinedData {
             created_at: self.created_at,
             updated_at: self.updated_at,
             avatar,
+            bio: self.bio,
+            website_url: self.website_url,
+            social_links: serde_json::from_value(self.social_links).unwrap_or_default(),
+            profile_slug: self.profile_slug,
+        }
+    }
+}
+
+/// A labelled link on a public author profile.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SocialLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Changes to the public author profile. `None` leaves a field as it is;
+/// `Some(None)` clears it.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AuthorProfileFields {
+    pub bio: Option<Option<String>>,
+    pub website_url: Option<Option<String>>,
+    pub social_links: Option<Vec<SocialLink>>,
+    pub profile_slug: Option<Option<String>>,
+}
+
+/// What `/author/{slug}` shows about a user: no email, role or account state.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PublicAuthorProfile {
+    pub id: i32,
+    pub name: String,
+    /// Handle for the author page; the id when no slug is set.
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<UserMedia>,
+    pub bio: Option<String>,
+    pub website_url: Option<String>,
+    pub social_links: Vec<SocialLink>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<UserWithRelations> for PublicAuthorProfile {
+    fn from(user: UserWithRelations) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+            slug: user.profile_slug.unwrap_or_else(|| user.id.to_string()),
+            avatar: user.avatar,
+            bio: user.bio,
+            website_url: user.website_url,
+            social_links: user.social_links,
+            created_at: user.created_at,
         }
     }
 }
@@ -99,6 +158,8 @@ pub struct NewUser {
 pub struct UpdateUser {
     pub name: Option<String>,
     pub email: Option<String>,
+    pub avatar_id: Option<i32>,
+    pub profile: AuthorProfileFields,
     pub updated_at: DateTimeWithTimeZone,
 }
 
@@ -146,5 +207,6 @@ pub struct AdminUpdateUser {
     pub role: Option<UserRole>,
     pub avatar_id: Option<i32>,
     pub is_verified: Option<bool>,
+    pub profile: AuthorProfileFields,
     pub updated_at: DateTimeWithTimeZone,
 }
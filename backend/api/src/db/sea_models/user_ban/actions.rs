@@ -4,6 +4,17 @@ use crate::error::DbResult;
 
 use super::*;
 
+/// SQL condition that is true when the user in `user_id_column` has an
+/// active shadow ban. Used to hide shadow-banned users' comments in joined
+/// queries without a round trip per author.
+pub fn active_shadow_ban_exists_sql(user_id_column: &str) -> String {
+    format!(
+        "EXISTS (SELECT 1 FROM user_bans WHERE user_bans.user_id = {user_id_column} \
+         AND user_bans.kind = 'shadow' AND user_bans.revoked_at IS NULL \
+         AND (user_bans.expires_at IS NULL OR user_bans.expires_at > NOW()))"
+    )
+}
+
 /// Actions for the `user_bans` entity
 impl Entity {
     pub const PER_PAGE: u64 = 20;
@@ -14,6 +25,7 @@ impl Entity {
 
         let ban = ActiveModel {
             user_id: Set(new_ban.user_id),
+            kind: Set(new_ban.kind),
             reason: Set(new_ban.reason),
             banned_by: Set(new_ban.banned_by),
             expires_at: Set(new_ban.expires_at),
@@ -55,10 +67,24 @@ impl Entity {
         }
     }
 
-    /// Check if a user has an active ban
+    /// Check if a user has an active (full) ban
     ///
-    /// Returns the active ban if one exists, None otherwise.
+    /// Returns the active ban if one exists, None otherwise. Shadow bans are
+    /// not included; they never block login.
     pub async fn get_active_ban(conn: &DbConn, user_id: i32) -> DbResult<Option<Model>> {
+        Self::get_active_ban_of_kind(conn, user_id, UserBanKind::Full).await
+    }
+
+    /// Check if a user has an active shadow ban
+    pub async fn get_active_shadow_ban(conn: &DbConn, user_id: i32) -> DbResult<Option<Model>> {
+        Self::get_active_ban_of_kind(conn, user_id, UserBanKind::Shadow).await
+    }
+
+    async fn get_active_ban_of_kind(
+        conn: &DbConn,
+        user_id: i32,
+        kind: UserBanKind,
+    ) -> DbResult<Option<Model>> {
         let now = chrono::Utc::now().fixed_offset();
 
         // Find bans that are:
@@ -66,6 +92,7 @@ impl Entity {
         // 2. Either no expiry (expires_at IS NULL) OR expires_at > now
         let ban = Self::find()
             .filter(Column::UserId.eq(user_id))
+            .filter(Column::Kind.eq(kind))
             .filter(Column::RevokedAt.is_null())
             .filter(Column::ExpiresAt.is_null().or(Column::ExpiresAt.gt(now)))
             .order_by(Column::CreatedAt, Order::Desc)
@@ -83,6 +110,38 @@ impl Entity {
         Ok(Self::get_active_ban(conn, user_id).await?.is_some())
     }
 
+    /// Check if a user is shadow banned
+    pub async fn is_shadow_banned(conn: &DbConn, user_id: i32) -> DbResult<bool> {
+        Ok(Self::get_active_shadow_ban(conn, user_id).await?.is_some())
+    }
+
+    /// Revoke every active ban of `kind` for a user
+    ///
+    /// Returns the number of bans revoked.
+    pub async fn revoke_active(
+        conn: &DbConn,
+        user_id: i32,
+        kind: UserBanKind,
+        revoked_by: Option<i32>,
+    ) -> DbResult<u64> {
+        let now = chrono::Utc::now().fixed_offset();
+
+        let result = Self::update_many()
+            .col_expr(Column::RevokedAt, Expr::value(now))
+            .col_expr(Column::RevokedBy, Expr::value(revoked_by))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Kind.eq(kind))
+            .filter(Column::RevokedAt.is_null())
+            .filter(Column::ExpiresAt.is_null().or(Column::ExpiresAt.gt(now)))
+            .exec(conn)
+            .await;
+
+        match result {
+            Ok(res) => Ok(res.rows_affected),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// List all bans for a user (paginated)
     pub async fn list_by_user(
         conn: &DbConn,
@@ -117,6 +176,10 @@ impl Entity {
             q = q.filter(Column::UserId.eq(user_id));
         }
 
+        if let Some(kind) = query.kind {
+            q = q.filter(Column::Kind.eq(kind));
+        }
+
         if let Some(active_only) = query.active_only {
             if active_only {
                 let now = chrono::Utc::now().fixed_offset();
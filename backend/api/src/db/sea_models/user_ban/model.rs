@@ -1,12 +1,16 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub use ruxlog_types::enums::UserBanKind;
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "user_bans")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub user_id: i32,
+    /// Full ban (no login) or shadow ban (comments visible only to the user)
+    pub kind: UserBanKind,
     /// Reason for the ban
     pub reason: Option<String>,
     /// Admin who created the ban
@@ -1,10 +1,13 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::UserBanKind;
+
 /// New ban record to be created
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewUserBan {
     pub user_id: i32,
+    pub kind: UserBanKind,
     pub reason: Option<String>,
     pub banned_by: Option<i32>,
     pub expires_at: Option<DateTimeWithTimeZone>,
@@ -14,12 +17,20 @@ impl NewUserBan {
     pub fn new(user_id: i32) -> Self {
         Self {
             user_id,
+            kind: UserBanKind::Full,
             reason: None,
             banned_by: None,
             expires_at: None,
         }
     }
 
+    /// Turn this into a shadow ban: the user can still log in, but their
+    /// comments are only shown to themselves.
+    pub fn shadow(mut self) -> Self {
+        self.kind = UserBanKind::Shadow;
+        self
+    }
+
     pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
         self.reason = Some(reason.into());
         self
@@ -48,6 +59,7 @@ impl NewUserBan {
 pub struct UserBanQuery {
     pub page_no: Option<i64>,
     pub user_id: Option<i32>,
+    pub kind: Option<UserBanKind>,
     pub active_only: Option<bool>,
     pub sort_by: Option<String>,
     pub sort_order: Option<String>,
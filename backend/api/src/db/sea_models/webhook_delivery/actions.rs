@@ -0,0 +1,94 @@
+use sea_orm::{entity::prelude::*, QueryOrder, QuerySelect, Set};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+impl Entity {
+    pub const PER_PAGE: u64 = 20;
+
+    /// Queue a delivery for its first attempt right away.
+    pub async fn create(conn: &DbConn, new_delivery: NewWebhookDelivery) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let delivery = ActiveModel {
+            endpoint_id: Set(new_delivery.endpoint_id),
+            message_id: Set(new_delivery.message_id),
+            event: Set(new_delivery.event),
+            payload: Set(new_delivery.payload),
+            status: Set(WebhookDeliveryStatus::Pending),
+            attempts: Set(0),
+            next_attempt_at: Set(Some(now)),
+            last_attempt_at: Set(None),
+            response_status: Set(None),
+            response_body: Set(None),
+            error: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        delivery.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// Pending deliveries whose next attempt is due, oldest first.
+    pub async fn find_due(
+        conn: &DbConn,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> DbResult<Vec<Model>> {
+        Ok(Self::find()
+            .filter(Column::Status.eq(WebhookDeliveryStatus::Pending))
+            .filter(Column::NextAttemptAt.lte(now))
+            .order_by_asc(Column::NextAttemptAt)
+            .limit(limit)
+            .all(conn)
+            .await?)
+    }
+
+    /// Store the outcome of an attempt and count it.
+    pub async fn record_attempt(
+        conn: &DbConn,
+        delivery: Model,
+        attempt: WebhookAttempt,
+    ) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let attempts = delivery.attempts + 1;
+        let mut active: ActiveModel = delivery.into();
+        active.status = Set(attempt.status);
+        active.attempts = Set(attempts);
+        active.next_attempt_at = Set(attempt.next_attempt_at);
+        active.last_attempt_at = Set(Some(now));
+        active.response_status = Set(attempt.response_status);
+        active.response_body = Set(attempt.response_body);
+        active.error = Set(attempt.error);
+        active.updated_at = Set(now);
+        active.update(conn).await.map_err(ErrorResponse::from)
+    }
+
+    /// The delivery log, newest first.
+    pub async fn find_with_query(
+        conn: &DbConn,
+        query: WebhookDeliveryQuery,
+    ) -> DbResult<(Vec<Model>, u64)> {
+        let mut delivery_query = Self::find();
+        if let Some(endpoint_id) = query.endpoint_id {
+            delivery_query = delivery_query.filter(Column::EndpointId.eq(endpoint_id));
+        }
+        if let Some(event) = query.event {
+            delivery_query = delivery_query.filter(Column::Event.eq(event));
+        }
+        if let Some(status) = query.status {
+            delivery_query = delivery_query.filter(Column::Status.eq(status));
+        }
+
+        let page = match query.page {
+            Some(p) if p > 0 => p,
+            _ => 1,
+        };
+        let paginator = delivery_query
+            .order_by_desc(Column::CreatedAt)
+            .order_by_desc(Column::Id)
+            .paginate(conn, Self::PER_PAGE);
+        let total = paginator.num_items().await?;
+        let results = paginator.fetch_page(page - 1).await?;
+        Ok((results, total))
+    }
+}
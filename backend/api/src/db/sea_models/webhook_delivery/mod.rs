@@ -0,0 +1,6 @@
+mod actions;
+mod model;
+mod slice;
+
+pub use model::*;
+pub use slice::*;
@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::{WebhookDeliveryStatus, WebhookEvent};
+
+/// One event sent (or still to be sent) to one webhook endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub endpoint_id: i32,
+    /// Sent as `webhook-id`; the same across retries and redeliveries so
+    /// receivers can drop duplicates.
+    pub message_id: String,
+    pub event: WebhookEvent,
+    /// The exact JSON body that is signed and sent.
+    pub payload: Json,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+    pub last_attempt_at: Option<DateTimeWithTimeZone>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::super::webhook_endpoint::Entity",
+        from = "Column::EndpointId",
+        to = "super::super::webhook_endpoint::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Endpoint,
+}
+
+impl Related<super::super::webhook_endpoint::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Endpoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
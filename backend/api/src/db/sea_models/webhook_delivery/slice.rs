@@ -0,0 +1,31 @@
+use sea_orm::prelude::{DateTimeWithTimeZone, Json};
+use serde::Deserialize;
+
+use super::{WebhookDeliveryStatus, WebhookEvent};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewWebhookDelivery {
+    pub endpoint_id: i32,
+    pub message_id: String,
+    pub event: WebhookEvent,
+    pub payload: Json,
+}
+
+/// What one delivery attempt produced.
+#[derive(Debug, Clone)]
+pub struct WebhookAttempt {
+    pub status: WebhookDeliveryStatus,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    /// When to try again; `None` once the delivery succeeded or gave up.
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WebhookDeliveryQuery {
+    pub page: Option<u64>,
+    pub endpoint_id: Option<i32>,
+    pub event: Option<WebhookEvent>,
+    pub status: Option<WebhookDeliveryStatus>,
+}
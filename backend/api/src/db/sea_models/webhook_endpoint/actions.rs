@@ -0,0 +1,111 @@
+use sea_orm::{entity::prelude::*, QueryOrder, Set};
+
+use super::*;
+use crate::error::{DbResult, ErrorResponse};
+
+fn events_json(events: &[WebhookEvent]) -> Json {
+    serde_json::to_value(events).unwrap_or_else(|_| Json::Array(Vec::new()))
+}
+
+impl Entity {
+    pub const PER_PAGE: u64 = 20;
+
+    pub async fn create(conn: &DbConn, new_endpoint: NewWebhookEndpoint) -> DbResult<Model> {
+        let now = chrono::Utc::now().fixed_offset();
+        let endpoint = ActiveModel {
+            url: Set(new_endpoint.url),
+            description: Set(new_endpoint.description),
+            secret: Set(new_endpoint.secret),
+            events: Set(events_json(&new_endpoint.events)),
+            is_active: Set(new_endpoint.is_active),
+            created_by: Set(new_endpoint.created_by),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        endpoint.insert(conn).await.map_err(ErrorResponse::from)
+    }
+
+    pub async fn update(
+        conn: &DbConn,
+        endpoint_id: i32,
+        update: UpdateWebhookEndpoint,
+    ) -> DbResult<Option<Model>> {
+        let Some(existing) = Self::find_by_id(endpoint_id).one(conn).await? else {
+            return Ok(None);
+        };
+        let mut active: ActiveModel = existing.into();
+        if let Some(url) = update.url {
+            active.url = Set(url);
+        }
+        if let Some(description) = update.description {
+            active.description = Set(Some(description));
+        }
+        if let Some(events) = update.events {
+            active.events = Set(events_json(&events));
+        }
+        if let Some(is_active) = update.is_active {
+            active.is_active = Set(is_active);
+        }
+        active.updated_at = Set(update.updated_at);
+
+        active
+            .update(conn)
+            .await
+            .map(Some)
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn rotate_secret(
+        conn: &DbConn,
+        endpoint_id: i32,
+        secret: String,
+    ) -> DbResult<Option<Model>> {
+        let Some(existing) = Self::find_by_id(endpoint_id).one(conn).await? else {
+            return Ok(None);
+        };
+        let mut active: ActiveModel = existing.into();
+        active.secret = Set(secret);
+        active.updated_at = Set(chrono::Utc::now().fixed_offset());
+        active
+            .update(conn)
+            .await
+            .map(Some)
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn delete(conn: &DbConn, endpoint_id: i32) -> DbResult<u64> {
+        let result = Self::delete_by_id(endpoint_id).exec(conn).await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Active endpoints subscribed to `event`.
+    pub async fn find_subscribed(conn: &DbConn, event: WebhookEvent) -> DbResult<Vec<Model>> {
+        let endpoints = Self::find()
+            .filter(Column::IsActive.eq(true))
+            .order_by_asc(Column::Id)
+            .all(conn)
+            .await?;
+        Ok(endpoints
+            .into_iter()
+            .filter(|endpoint| endpoint.is_subscribed(event))
+            .collect())
+    }
+
+    pub async fn find_with_query(
+        conn: &DbConn,
+        query: WebhookEndpointQuery,
+    ) -> DbResult<(Vec<Model>, u64)> {
+        let page = match query.page {
+            Some(p) if p > 0 => p,
+            _ => 1,
+        };
+        let paginator = Self::find()
+            .order_by_desc(Column::CreatedAt)
+            .order_by_desc(Column::Id)
+            .paginate(conn, Self::PER_PAGE);
+        let total = paginator.num_items().await?;
+        let results = paginator.fetch_page(page - 1).await?;
+        Ok((results, total))
+    }
+}
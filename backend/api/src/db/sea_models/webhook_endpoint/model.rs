@@ -0,0 +1,64 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub use ruxlog_types::enums::WebhookEvent;
+
+/// An admin-registered receiver for outgoing webhooks. See
+/// `services::webhooks` for how deliveries are signed and retried.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_endpoints")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub url: String,
+    pub description: Option<String>,
+    /// Only returned when the endpoint is created or its secret rotated.
+    #[serde(skip_serializing)]
+    pub secret: String,
+    /// Subscribed event types, as a JSON array of their names.
+    pub events: Json,
+    pub is_active: bool,
+    pub created_by: Option<i32>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::super::webhook_delivery::Entity")]
+    Deliveries,
+    #[sea_orm(
+        belongs_to = "super::super::user::Entity",
+        from = "Column::CreatedBy",
+        to = "super::super::user::Column::Id",
+        on_delete = "SetNull"
+    )]
+    CreatedByUser,
+}
+
+impl Related<super::super::webhook_delivery::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Deliveries.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// Subscribed events; unknown names are ignored.
+    pub fn subscribed_events(&self) -> Vec<WebhookEvent> {
+        self.events
+            .as_array()
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|name| serde_json::from_value(name.clone()).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn is_subscribed(&self, event: WebhookEvent) -> bool {
+        self.is_active && self.subscribed_events().contains(&event)
+    }
+}
@@ -0,0 +1,28 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Deserialize;
+
+use super::WebhookEvent;
+
+#[derive(Deserialize, Debug)]
+pub struct NewWebhookEndpoint {
+    pub url: String,
+    pub description: Option<String>,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub is_active: bool,
+    pub created_by: Option<i32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateWebhookEndpoint {
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub is_active: Option<bool>,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WebhookEndpointQuery {
+    pub page: Option<u64>,
+}
@@ -8,6 +8,7 @@ pub mod codes;
 pub mod database;
 pub mod middleware;
 pub mod response;
+pub mod storage;
 pub mod validation;
 
 pub use codes::ErrorCode;
@@ -15,3 +16,4 @@ pub use database::{DbResult, DbResultExt};
 pub use middleware::{CorsError, CsrfError, RouteBlockerError};
 pub use response::ErrorResponse;
 pub use response::IntoErrorResponse;
+pub use storage::StorageError;
@@ -0,0 +1,43 @@
+use axum::response::IntoResponse;
+
+use crate::error::{ErrorCode, ErrorResponse, IntoErrorResponse};
+
+/// Errors raised by an [`crate::services::storage::ObjectStore`] driver.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("invalid object key: {0}")]
+    InvalidKey(String),
+    #[error("object not found: {0}")]
+    NotFound(String),
+    #[error("storage io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("storage backend error: {0}")]
+    Backend(String),
+}
+
+impl IntoErrorResponse for StorageError {
+    fn into_error_response(self) -> ErrorResponse {
+        match self {
+            Self::InvalidKey(key) => ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Invalid object key")
+                .with_details(key),
+            Self::NotFound(key) => ErrorResponse::new(ErrorCode::FileNotFound).with_details(key),
+            Self::Io(err) => {
+                ErrorResponse::new(ErrorCode::StorageError).with_details(err.to_string())
+            }
+            Self::Backend(err) => ErrorResponse::new(ErrorCode::StorageError).with_details(err),
+        }
+    }
+}
+
+impl From<StorageError> for ErrorResponse {
+    fn from(err: StorageError) -> Self {
+        err.into_error_response()
+    }
+}
+
+impl IntoResponse for StorageError {
+    fn into_response(self) -> axum::response::Response {
+        ErrorResponse::from(self).into_response()
+    }
+}
@@ -3,7 +3,7 @@ use axum::extract::State;
 use axum::{http::HeaderName, middleware, Extension};
 use axum_client_ip::ClientIpSource;
 use axum_extra::extract::cookie::SameSite;
-use std::{env, net::SocketAddr, time::Duration};
+use std::{env, net::SocketAddr, sync::Arc, time::Duration};
 use tower_http::{
     compression::CompressionLayer,
     cors::{AllowOrigin, CorsLayer},
@@ -14,7 +14,11 @@ use tower_sessions_redis_store::RedisStore;
 use ruxlog::utils::cors::get_allowed_origins;
 use ruxlog::{
     db, middlewares, router,
-    services::{self, redis::init_redis_store},
+    services::{
+        self,
+        redis::init_redis_store,
+        storage::{LocalStore, ObjectStore, S3Store, StorageBackend},
+    },
     state::{validate_cookie_key, AppState, ObjectStorageConfig},
     utils::telemetry,
 };
@@ -48,7 +52,6 @@ fn env_bool(key: &str, default: bool) -> bool {
         .unwrap_or(default)
 }
 
-#[cfg_attr(not(feature = "full"), allow(dead_code))]
 fn env_u64(key: &str, default: u64) -> u64 {
     env::var(key)
         .ok()
@@ -77,30 +80,48 @@ fn env_with_fallback(keys: &[&str], default: Option<&str>) -> Option<String> {
     default.map(|value| value.to_string())
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    dotenvy::dotenv().ok();
-
-    let _telemetry_guard = telemetry::init();
-
-    telemetry::init_pool_metrics();
+/// Pick the media object store from `STORAGE_BACKEND` (`s3` by default).
+///
+/// The local driver needs no credentials; its files are served by the
+/// `/media/{bucket}/{key}` route, signed with a key derived from `COOKIE_KEY`.
+async fn build_object_storage(secret: &[u8]) -> (ObjectStorageConfig, Arc<dyn ObjectStore>) {
+    let backend = match env::var("STORAGE_BACKEND") {
+        Ok(value) if !value.trim().is_empty() => StorageBackend::from_env_value(&value)
+            .unwrap_or_else(|| panic!("Unsupported STORAGE_BACKEND value: {value}")),
+        _ => StorageBackend::S3,
+    };
 
-    let cookie_key_str = env::var("COOKIE_KEY").expect("COOKIE_KEY must be set");
-    // V-CRIT-1: refuse the known committed placeholder, empty/whitespace, and
-    // sub-32-byte keys BEFORE Key::derive_from. The previous length-only guard
-    // passed for the placeholder because it is >32 bytes, so production could
-    // boot on a publicly-known key. Panicking here is intentional — booting on
-    // a weak/known cookie key is worse than failing to boot. See
-    // CRYPTO_AUDIT.md V-CRIT-1 / V-HIGH-3.
-    if let Err(reason) = validate_cookie_key(&cookie_key_str) {
-        panic!("{}", reason);
+    if backend == StorageBackend::Local {
+        let root = env::var("LOCAL_STORAGE_ROOT").unwrap_or_else(|_| "./storage".to_string());
+        let bucket = env::var("LOCAL_STORAGE_BUCKET").unwrap_or_else(|_| "local".to_string());
+        let public_url =
+            env_with_fallback(&["LOCAL_STORAGE_PUBLIC_URL"], None).unwrap_or_else(|| {
+                let port = env::var("PORT").unwrap_or_else(|_| "8888".to_string());
+                format!("http://localhost:{port}/media")
+            });
+        let signing_key =
+            ruxlog::utils::code_hash::hash_code(secret, "local-storage-url-signing").into_bytes();
+
+        tracing::info!(
+            root = %root,
+            bucket = %bucket,
+            public_url = %public_url,
+            "Local object storage configured"
+        );
+
+        let object_storage = ObjectStorageConfig {
+            region: "local".to_string(),
+            account_id: "local".to_string(),
+            bucket: bucket.clone(),
+            access_key: String::new(),
+            secret_key: String::new(),
+            public_url: public_url.clone(),
+            endpoint: String::new(),
+        };
+        let store = LocalStore::new(root, bucket, public_url, signing_key);
+        return (object_storage, Arc::new(store));
     }
 
-    let sea_db = db::sea_connect::get_sea_connection().await;
-
-    let (redis_pool, redis_connection) = init_redis_store().await?;
-    let mailer = services::mail::smtp::create_connection().await;
-
     let bucket = env_with_fallback(&["S3_BUCKET", "AWS_S3_BUCKET"], None)
         .expect("S3_BUCKET or AWS_S3_BUCKET must be set");
     let access_key = env_with_fallback(&["S3_ACCESS_KEY", "AWS_ACCESS_KEY_ID"], None)
@@ -161,6 +182,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await;
 
     let s3_client = aws_sdk_s3::Client::new(&s3_config);
+    let store = S3Store::new(s3_client, object_storage.bucket.clone());
+
+    (object_storage, Arc::new(store))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+
+    let _telemetry_guard = telemetry::init();
+
+    telemetry::init_pool_metrics();
+
+    let cookie_key_str = env::var("COOKIE_KEY").expect("COOKIE_KEY must be set");
+    // V-CRIT-1: refuse the known committed placeholder, empty/whitespace, and
+    // sub-32-byte keys BEFORE Key::derive_from. The previous length-only guard
+    // passed for the placeholder because it is >32 bytes, so production could
+    // boot on a publicly-known key. Panicking here is intentional — booting on
+    // a weak/known cookie key is worse than failing to boot. See
+    // CRYPTO_AUDIT.md V-CRIT-1 / V-HIGH-3.
+    if let Err(reason) = validate_cookie_key(&cookie_key_str) {
+        panic!("{}", reason);
+    }
+
+    let sea_db = db::sea_connect::get_sea_connection().await;
+
+    let (redis_pool, redis_connection) = init_redis_store().await?;
+    let mailer = services::mail::smtp::create_connection().await;
+
+    let (object_storage, storage) = build_object_storage(cookie_key_str.as_bytes()).await;
 
     // V-LOW-PRINTLN: the previous boot-time `println!("Buckets:")` loop dumped
     // every bucket name + creation date to stdout. That is unnecessary startup
@@ -177,6 +228,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_pixels: env_u64("OPTIMIZER_MAX_PIXELS", 12_000_000),
         keep_original: env_bool("OPTIMIZER_KEEP_ORIGINAL", true),
         default_webp_quality: env_u8("OPTIMIZER_WEBP_QUALITY_DEFAULT", 80),
+        avif_enabled: env_bool("OPTIMIZER_AVIF", true),
+        avif_quality: env_u8("OPTIMIZER_AVIF_QUALITY", 55),
+        defer_uploads: env_bool("OPTIMIZER_DEFER_UPLOADS", false),
     };
 
     // V-MED-10: ONE shared, timeout-configured reqwest::Client for all outbound
@@ -185,6 +239,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // cheaply (it is internally an `Arc`) into each provider and the Google
     // userinfo/JWKS fetch. See `state::build_http_client`.
     let http_client = ruxlog::state::build_http_client();
+    let geoip = std::sync::Arc::new(ruxlog::services::geoip::GeoIp::from_env());
 
     #[cfg(feature = "billing")]
     let billing_router: std::sync::Arc<BillingRouter> = {
@@ -381,23 +436,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         tracing::info!(providers = ?names, "Billing providers available");
 
         let geo_config = GeoRulesConfig::from_env();
-        let geo_router = GeoRouter::new(geo_config);
+        let geo_router = GeoRouter::new(geo_config, geoip.clone());
 
         std::sync::Arc::new(BillingRouter::new(providers, geo_router))
     };
 
+    let notification_hub = std::sync::Arc::new(services::notification_hub::NotificationHub::new());
+    services::notification_hub::start_notification_relay(
+        redis_pool.clone(),
+        notification_hub.clone(),
+    );
+
     let state = AppState {
         sea_db,
         redis_pool: redis_pool.clone(),
         mailer,
         object_storage,
-        s3_client,
+        storage,
         secret_key: cookie_key_str.as_bytes().to_vec(),
         field_enc_key: ruxlog::state::load_field_enc_key(),
         #[cfg(feature = "image-optimization")]
         optimizer,
         meter: telemetry::global_meter(),
         http_client,
+        geoip,
+        exchange_rates: std::sync::Arc::new(ruxlog::services::fx::ExchangeRates::from_env()),
+        comment_moderator: std::sync::Arc::new(
+            ruxlog::services::comment_moderation::CommentModerator::from_env(),
+        ),
+        notification_hub: notification_hub.clone(),
         #[cfg(feature = "billing")]
         billing_router,
     };
@@ -489,6 +556,73 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[cfg(feature = "scheduler")]
     services::scheduler::start_scheduler(state.clone());
 
+    services::media_gc::start_upload_sweep(
+        state.clone(),
+        services::media_gc::UploadSweepConfig {
+            enabled: env_bool("MEDIA_UPLOAD_SWEEP_ENABLED", true),
+            interval_secs: env_u64("MEDIA_UPLOAD_SWEEP_INTERVAL_SECS", 60 * 60),
+            batch_limit: env_u64("MEDIA_UPLOAD_SWEEP_BATCH_LIMIT", 500),
+        },
+    );
+
+    services::media_gc::start_media_gc(
+        state.clone(),
+        services::media_gc::MediaGcConfig {
+            enabled: env_bool("MEDIA_GC_ENABLED", false),
+            interval_secs: env_u64("MEDIA_GC_INTERVAL_SECS", 60 * 60 * 24),
+            min_age_hours: env_u64("MEDIA_GC_MIN_AGE_HOURS", 72),
+            delete: env_bool("MEDIA_GC_DELETE", false),
+            batch_limit: env_u64("MEDIA_GC_BATCH_LIMIT", 500),
+        },
+    );
+
+    #[cfg(feature = "image-optimization")]
+    services::media_reoptimizer::start_media_reoptimizer(
+        state.clone(),
+        services::media_reoptimizer::MediaReoptimizerConfig {
+            enabled: env_bool("MEDIA_REOPTIMIZE_ENABLED", true),
+            concurrency: env_u64("MEDIA_REOPTIMIZE_CONCURRENCY", 2) as usize,
+            poll_interval_secs: env_u64("MEDIA_REOPTIMIZE_POLL_SECS", 30),
+            max_attempts: env_u64("MEDIA_REOPTIMIZE_MAX_ATTEMPTS", 3) as i32,
+            retry_base_secs: env_u64("MEDIA_REOPTIMIZE_RETRY_BASE_SECS", 60),
+            lease_secs: env_u64("MEDIA_REOPTIMIZE_LEASE_SECS", 15 * 60),
+        },
+    );
+
+    #[cfg(feature = "analytics")]
+    services::analytics_rollup::start_analytics_rollup(
+        state.clone(),
+        services::analytics_rollup::AnalyticsRollupConfig {
+            enabled: env_bool("ANALYTICS_ROLLUP_ENABLED", true),
+            interval_secs: env_u64("ANALYTICS_ROLLUP_INTERVAL_SECS", 60 * 60),
+            max_days_per_pass: env_u64("ANALYTICS_ROLLUP_MAX_DAYS", 31),
+            raw_retention_days: env_u64("ANALYTICS_RAW_RETENTION_DAYS", 0),
+            archive: env_bool("ANALYTICS_RAW_ARCHIVE", true),
+            prune_batch: env_u64("ANALYTICS_PRUNE_BATCH", 5000),
+        },
+    );
+
+    services::notifications::start_notification_digest(
+        state.clone(),
+        services::notifications::NotificationDigestConfig {
+            enabled: env_bool("NOTIFICATION_DIGEST_ENABLED", true),
+            interval_secs: env_u64("NOTIFICATION_DIGEST_INTERVAL_SECS", 60 * 60),
+            min_age_hours: env_u64("NOTIFICATION_DIGEST_MIN_AGE_HOURS", 24),
+            batch_limit: env_u64("NOTIFICATION_DIGEST_BATCH_LIMIT", 200),
+        },
+    );
+
+    services::webhooks::start_webhook_delivery(
+        state.clone(),
+        services::webhooks::WebhookDeliveryConfig {
+            enabled: env_bool("WEBHOOK_DELIVERY_ENABLED", true),
+            interval_secs: env_u64("WEBHOOK_DELIVERY_INTERVAL_SECS", 10),
+            batch_limit: env_u64("WEBHOOK_DELIVERY_BATCH_LIMIT", 50),
+            max_attempts: env_u64("WEBHOOK_MAX_ATTEMPTS", 8),
+            retry_base_secs: env_u64("WEBHOOK_RETRY_BASE_SECS", 60),
+        },
+    );
+
     tracing::info!("Redis successfully established.");
     let session_store = RedisStore::new(redis_pool);
     // Derive the cookie signing+encryption key via HKDF-SHA256 rather than the
@@ -106,6 +106,12 @@ fn is_csrf_exempt(path: &str) -> bool {
     }
     // Webhook receivers: exactly /billing/v1/webhook/{provider} (5 segments
     // when split on '/': ["", "billing", "v1", "webhook", "<provider>"]).
+    // Local-storage part uploads: exactly /media/_parts/{upload_id}/{part}.
+    // Those are authorized by the presigned `sig` query instead and are sent
+    // without credentials, just like a PUT to an S3 presigned URL.
+    // Notification unsubscribes: exactly /notification/v1/unsubscribe/{token}.
+    // Mail clients POST these one-click (RFC 8058) with no session; the
+    // token in the path is the authorization.
     let mut segs = path.split('/');
     let _leading = segs.next();
     matches!(
@@ -117,6 +123,14 @@ fn is_csrf_exempt(path: &str) -> bool {
             segs.next(),
         ),
         (Some("billing"), Some("v1"), Some("webhook"), Some(_), None)
+            | (Some("media"), Some("_parts"), Some(_), Some(_), None)
+            | (
+                Some("notification"),
+                Some("v1"),
+                Some("unsubscribe"),
+                Some(_),
+                None
+            )
     )
 }
 
@@ -234,6 +248,18 @@ mod tests {
         // … but cousins are not.
         assert!(!is_csrf_exempt("/auth/google/v1/callback-evil"));
         assert!(!is_csrf_exempt("/csrf/v1/generateX"));
+
+        // Presigned local part uploads are exempt, nothing else under /media.
+        assert!(is_csrf_exempt("/media/_parts/abc/1"));
+        assert!(!is_csrf_exempt("/media/_parts/abc"));
+        assert!(!is_csrf_exempt("/media/_parts/abc/1/x"));
+        assert!(!is_csrf_exempt("/media/v1/create"));
+
+        // One-click unsubscribe links, but not the preference endpoints.
+        assert!(is_csrf_exempt("/notification/v1/unsubscribe/abc"));
+        assert!(!is_csrf_exempt("/notification/v1/unsubscribe"));
+        assert!(!is_csrf_exempt("/notification/v1/unsubscribe/abc/x"));
+        assert!(!is_csrf_exempt("/notification/v1/preferences/update"));
     }
 }
 
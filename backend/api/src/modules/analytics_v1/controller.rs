@@ -1,8 +1,14 @@
 use std::collections::{BTreeMap, HashMap};
 
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use axum_client_ip::ClientIp;
 use axum_macros::debug_handler;
-use chrono::{Duration as ChronoDuration, NaiveDate, Utc};
+use chrono::{Duration as ChronoDuration, Utc};
 use sea_orm::prelude::DateTimeWithTimeZone;
 use sea_orm::{
     sea_query::{ArrayType, Value},
@@ -12,21 +18,31 @@ use serde_json::{json, Map as JsonMap, Value as JsonValue};
 use tracing::instrument;
 
 use crate::{
-    db::sea_models::post::PostStatus,
+    db::sea_models::{
+        analytics_event::{
+            AnalyticsEventKind, DeviceClass, Entity as AnalyticsEvent, NewAnalyticsEvent,
+        },
+        post::PostStatus,
+    },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
-    services::auth::AuthSession,
+    services::{analytics_rollup, auth::AuthSession, fx::ExchangeRates, traffic},
     AppState,
 };
 
 use super::validator::{
-    AnalyticsEnvelope, AnalyticsEnvelopeResponse, AnalyticsMeta, CommentRatePoint,
-    CommentRateRequest, CommentRateSort, DashboardSummaryData, DashboardSummaryEngagement,
-    DashboardSummaryMedia, DashboardSummaryPosts, DashboardSummaryRequest, DashboardSummaryUsers,
-    MediaUploadPoint, MediaUploadRequest, NewsletterGrowthPoint, NewsletterGrowthRequest,
-    PageViewPoint, PageViewsRequest, PublishingTrendPoint, PublishingTrendsRequest,
-    RegistrationTrendPoint, RegistrationTrendsRequest, VerificationRatePoint,
-    VerificationRatesRequest,
+    AnalyticsEnvelope, AnalyticsEnvelopeResponse, AnalyticsInterval, AnalyticsMeta,
+    AuthorEngagementRequest, CampaignPoint, CollectEventPayload, CommentRatePoint,
+    CommentRateRequest, CommentRateSort, CountryPoint, DashboardSummaryData,
+    DashboardSummaryEngagement, DashboardSummaryMedia, DashboardSummaryPosts,
+    DashboardSummaryRequest, DashboardSummaryRevenue, DashboardSummaryUsers, DevicePoint,
+    MediaUploadPoint, MediaUploadRequest, MrrPoint, NewsletterGrowthPoint, NewsletterGrowthRequest,
+    PageViewPoint, PageViewsRequest, PostEngagementData, PostEngagementPoint,
+    PostEngagementRequest, PostRevenuePoint, PostTrafficPoint, ProviderRevenuePoint,
+    PublishingTrendPoint, PublishingTrendsRequest, ReferrerPoint, RegistrationTrendPoint,
+    RegistrationTrendsRequest, ResolvedAnalyticsEnvelope, RevenueBreakdownRequest,
+    RevenueTrendRequest, RollupRunPayload, SubscriberFlowPoint, TrafficRequest,
+    TrialConversionPoint, VerificationRatePoint, VerificationRatesRequest, COMPLETION_SCROLL_DEPTH,
 };
 
 #[derive(Debug, FromQueryResult)]
@@ -103,6 +119,50 @@ struct DashboardSummaryRow {
     media_uploads: i64,
 }
 
+/// The rollup watermark as a nullable `DATE` bind for the `*_days_cte`
+/// helpers in [`analytics_rollup`]. Rollups are keyed by UTC day, so for
+/// any other timezone this is `NULL` and the helpers read raw rows only.
+async fn rollup_watermark(
+    state: &AppState,
+    resolved: &ResolvedAnalyticsEnvelope,
+) -> Result<Value, ErrorResponse> {
+    if !resolved.uses_utc_days() {
+        return Ok(Value::ChronoDate(None));
+    }
+    let through = analytics_rollup::rolled_up_through(&state.sea_db).await?;
+    Ok(Value::ChronoDate(through.map(Box::new)))
+}
+
+/// [`rollup_watermark`] for reports over `post_views`. Without the rollup
+/// those read raw rows only, so a range reaching back past the retention
+/// prune is rejected instead of silently undercounting.
+async fn view_rollup_watermark(
+    state: &AppState,
+    resolved: &ResolvedAnalyticsEnvelope,
+) -> Result<Value, ErrorResponse> {
+    if !resolved.uses_utc_days() {
+        require_raw_views(state, resolved).await?;
+    }
+    rollup_watermark(state, resolved).await
+}
+
+/// Fail when `resolved` starts before the oldest raw view still kept.
+async fn require_raw_views(
+    state: &AppState,
+    resolved: &ResolvedAnalyticsEnvelope,
+) -> Result<(), ErrorResponse> {
+    let pruned_before = analytics_rollup::raw_pruned_before(&state.sea_db).await?;
+    if analytics_rollup::raw_views_cover(resolved.date_from, pruned_before) {
+        return Ok(());
+    }
+    let before = pruned_before.unwrap_or_default();
+    Err(ErrorResponse::new(ErrorCode::InvalidInput)
+        .with_message(format!(
+            "Raw page views before {before} have been pruned; use UTC daily or coarser buckets for earlier ranges"
+        ))
+        .with_context(json!({ "raw_pruned_before": before })))
+}
+
 #[debug_handler]
 #[instrument(skip(state, _auth, payload))]
 pub async fn registration_trends(
@@ -116,7 +176,6 @@ pub async fn registration_trends(
     let offset = resolved.offset() as i64;
 
     let interval = request.filters.group_by;
-    let bucket_expr = interval.to_bucket_expr("users.created_at");
     let sort_field = match resolved.sort_by.as_deref() {
         Some("new_users") => "new_users",
         Some("bucket") => "bucket",
@@ -134,22 +193,49 @@ pub async fn registration_trends(
         format!("ORDER BY bucket {}", resolved.sort_order.as_sql())
     };
 
+    // Hourly and non-UTC buckets need raw timestamps; the rest read the
+    // daily rollup.
+    let use_rollup = interval != AnalyticsInterval::Hour && resolved.uses_utc_days();
+    let bucketed = if !use_rollup {
+        format!(
+            r#"
+            bucketed AS (
+                SELECT
+                    {bucket_expr} AS bucket,
+                    COUNT(*)::BIGINT AS new_users
+                FROM users
+                WHERE created_at >= $1 AND created_at <= $2
+                GROUP BY 1
+            )
+            "#,
+            bucket_expr = interval.to_bucket_expr(&resolved.local("users.created_at")),
+        )
+    } else {
+        format!(
+            r#"
+            {signup_days},
+            bucketed AS (
+                SELECT
+                    {bucket_expr} AS bucket,
+                    SUM(new_users)::BIGINT AS new_users
+                FROM signup_days
+                GROUP BY 1
+            )
+            "#,
+            signup_days = analytics_rollup::signup_days_cte("$1", "$2", "$5"),
+            bucket_expr = interval.to_bucket_expr("day::TIMESTAMP"),
+        )
+    };
+
     let sql = format!(
         r#"
-        WITH bucketed AS (
-            SELECT
-                {bucket_expr} AS bucket,
-                COUNT(*)::BIGINT AS new_users
-            FROM users
-            WHERE created_at >= $1 AND created_at <= $2
-            GROUP BY 1
-        )
+        WITH {bucketed}
         SELECT bucket, new_users, COUNT(*) OVER () AS total
         FROM bucketed
         {order_clause}
         LIMIT $3 OFFSET $4
         "#,
-        bucket_expr = bucket_expr,
+        bucketed = bucketed,
         order_clause = order_clause,
     );
 
@@ -161,6 +247,7 @@ pub async fn registration_trends(
             Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
             Value::BigInt(Some(limit)),
             Value::BigInt(Some(offset)),
+            rollup_watermark(&state, &resolved).await?,
         ],
     );
 
@@ -204,7 +291,7 @@ pub async fn verification_rates(
     let offset = resolved.offset() as i64;
 
     let interval = request.filters.group_by;
-    let bucket_expr = interval.to_bucket_expr("email_verifications.created_at");
+    let bucket_expr = interval.to_bucket_expr(&resolved.local("email_verifications.created_at"));
     let order_target = match resolved.sort_by.as_deref() {
         Some("requested") => "requested",
         Some("verified") => "verified",
@@ -223,7 +310,7 @@ pub async fn verification_rates(
         )
     };
 
-    let user_bucket_expr = interval.to_bucket_expr("users.updated_at");
+    let user_bucket_expr = interval.to_bucket_expr(&resolved.local("users.updated_at"));
 
     let sql = format!(
         r#"
@@ -324,7 +411,7 @@ pub async fn publishing_trends(
     let offset = resolved.offset() as i64;
 
     let interval = request.filters.group_by;
-    let bucket_expr = interval.to_bucket_expr("posts.created_at");
+    let bucket_expr = interval.to_bucket_expr(&resolved.local("posts.created_at"));
     let bucket_order = format!("ORDER BY bucket {}", resolved.sort_order.as_sql());
 
     let status_filter = parse_status_filters(request.filters.status.as_ref())?;
@@ -476,37 +563,71 @@ pub async fn page_views(
     let post_id_filter = filters.post_id;
     let author_id_filter = filters.author_id;
     let only_unique = filters.only_unique;
-    let bucket_expr = interval.to_bucket_expr("pv.created_at");
     let order_clause = format!("ORDER BY bucket {}", resolved.sort_order.as_sql());
 
+    // Hourly and non-UTC buckets need raw timestamps; the rest read the
+    // daily rollup, where unique visitors are counted per post and day and
+    // then summed.
+    let use_rollup = interval != AnalyticsInterval::Hour && resolved.uses_utc_days();
+    if !use_rollup {
+        require_raw_views(&state, &resolved).await?;
+    }
+    let bucketed = if !use_rollup {
+        format!(
+            r#"
+            filtered AS (
+                SELECT
+                    pv.post_id,
+                    pv.user_id,
+                    pv.ip_address,
+                    {bucket_expr} AS bucket
+                FROM post_views pv
+                LEFT JOIN posts p ON pv.post_id = p.id
+                WHERE pv.created_at >= $1
+                  AND pv.created_at <= $2
+                  AND ($3 IS NULL OR pv.post_id = $3)
+                  AND ($4 IS NULL OR p.author_id = $4)
+            ),
+            bucketed AS (
+                SELECT
+                    bucket,
+                    COUNT(*)::BIGINT AS views,
+                    COUNT(
+                        DISTINCT COALESCE(
+                            filtered.user_id::text,
+                            CONCAT('ip:', COALESCE(filtered.ip_address, ''))
+                        )
+                    )::BIGINT AS unique_visitors
+                FROM filtered
+                GROUP BY bucket
+            )
+            "#,
+            bucket_expr = interval.to_bucket_expr(&resolved.local("pv.created_at")),
+        )
+    } else {
+        format!(
+            r#"
+            {view_days},
+            bucketed AS (
+                SELECT
+                    {bucket_expr} AS bucket,
+                    SUM(vd.views)::BIGINT AS views,
+                    SUM(vd.unique_visitors)::BIGINT AS unique_visitors
+                FROM view_days vd
+                LEFT JOIN posts p ON vd.post_id = p.id
+                WHERE ($3 IS NULL OR vd.post_id = $3)
+                  AND ($4 IS NULL OR p.author_id = $4)
+                GROUP BY 1
+            )
+            "#,
+            view_days = analytics_rollup::post_view_days_cte("$1", "$2", "$8"),
+            bucket_expr = interval.to_bucket_expr("vd.day::TIMESTAMP"),
+        )
+    };
+
     let sql = format!(
         r#"
-        WITH filtered AS (
-            SELECT
-                pv.post_id,
-                pv.user_id,
-                pv.ip_address,
-                {bucket_expr} AS bucket
-            FROM post_views pv
-            LEFT JOIN posts p ON pv.post_id = p.id
-            WHERE pv.created_at >= $1
-              AND pv.created_at <= $2
-              AND ($3 IS NULL OR pv.post_id = $3)
-              AND ($4 IS NULL OR p.author_id = $4)
-        ),
-        bucketed AS (
-            SELECT
-                bucket,
-                COUNT(*)::BIGINT AS views,
-                COUNT(
-                    DISTINCT COALESCE(
-                        filtered.user_id::text,
-                        CONCAT('ip:', COALESCE(filtered.ip_address, ''))
-                    )
-                )::BIGINT AS unique_visitors
-            FROM filtered
-            GROUP BY bucket
-        )
+        WITH {bucketed}
         SELECT
             bucket,
             CASE WHEN $5 THEN unique_visitors ELSE views END AS views,
@@ -516,7 +637,7 @@ pub async fn page_views(
         {order_clause}
         LIMIT $6 OFFSET $7
         "#,
-        bucket_expr = bucket_expr,
+        bucketed = bucketed,
         order_clause = order_clause,
     );
 
@@ -531,6 +652,7 @@ pub async fn page_views(
             Value::Bool(Some(only_unique)),
             Value::BigInt(Some(limit)),
             Value::BigInt(Some(offset)),
+            rollup_watermark(&state, &resolved).await?,
         ],
     );
 
@@ -566,9 +688,14 @@ pub async fn page_views(
         filters_obj.insert("only_unique".into(), json!(true));
     }
 
-    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+    let mut meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
         .with_interval(interval.as_str().to_string())
         .with_filters(JsonValue::Object(filters_obj));
+    if use_rollup {
+        meta = meta.with_notes(
+            "unique_visitors is counted per post and day, then summed over the bucket.",
+        );
+    }
 
     Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
 }
@@ -593,20 +720,16 @@ pub async fn comment_rate(
 
     let sql = format!(
         r#"
-        WITH view_counts AS (
-            SELECT
-                post_id,
-                COUNT(*)::BIGINT AS views
-            FROM post_views
-            WHERE created_at >= $1 AND created_at <= $2
+        WITH {view_days},
+        {comment_days},
+        view_counts AS (
+            SELECT post_id, SUM(views)::BIGINT AS views
+            FROM view_days
             GROUP BY post_id
         ),
         comment_counts AS (
-            SELECT
-                post_id,
-                COUNT(*)::BIGINT AS comments
-            FROM post_comments
-            WHERE created_at >= $1 AND created_at <= $2
+            SELECT post_id, SUM(comments)::BIGINT AS comments
+            FROM comment_days
             GROUP BY post_id
         ),
         combined AS (
@@ -638,6 +761,8 @@ pub async fn comment_rate(
         {sort_order}
         LIMIT $4 OFFSET $5
         "#,
+        view_days = analytics_rollup::post_view_days_cte("$1", "$2", "$6"),
+        comment_days = analytics_rollup::comment_days_cte("$1", "$2", "$6"),
         sort_order = sort_order,
     );
 
@@ -650,6 +775,7 @@ pub async fn comment_rate(
             Value::BigInt(Some(min_views)),
             Value::BigInt(Some(limit)),
             Value::BigInt(Some(offset)),
+            view_rollup_watermark(&state, &resolved).await?,
         ],
     );
 
@@ -704,8 +830,8 @@ pub async fn newsletter_growth(
     let offset = resolved.offset() as i64;
 
     let interval = request.filters.group_by;
-    let created_bucket_expr = interval.to_bucket_expr("created_at");
-    let updated_bucket_expr = interval.to_bucket_expr("updated_at");
+    let created_bucket_expr = interval.to_bucket_expr(&resolved.local("created_at"));
+    let updated_bucket_expr = interval.to_bucket_expr(&resolved.local("updated_at"));
     let order_clause = format!("ORDER BY bucket {}", resolved.sort_order.as_sql());
 
     let sql = format!(
@@ -815,7 +941,7 @@ pub async fn media_upload_trends(
     let offset = resolved.offset() as i64;
 
     let interval = request.filters.group_by;
-    let bucket_expr = interval.to_bucket_expr("created_at");
+    let bucket_expr = interval.to_bucket_expr(&resolved.local("created_at"));
     let order_clause = format!("ORDER BY bucket {}", resolved.sort_order.as_sql());
 
     let sql = format!(
@@ -894,26 +1020,34 @@ pub async fn dashboard_summary(
 ) -> Result<impl IntoResponse, ErrorResponse> {
     let ValidatedJson(request) = payload;
 
-    let summary_range = resolve_dashboard_range(&request);
-    let (date_from, date_to, page, per_page) = summary_range;
+    let resolved = resolve_dashboard_range(&request);
+    let (date_from, date_to) = (resolved.date_from, resolved.date_to);
 
-    let stmt = Statement::from_sql_and_values(
-        DatabaseBackend::Postgres,
+    let sql = format!(
         r#"
+        WITH {view_days},
+        {comment_days}
         SELECT
             (SELECT COUNT(*)::BIGINT FROM users) AS users_total,
             (SELECT COUNT(*)::BIGINT FROM users WHERE created_at >= $1 AND created_at <= $2) AS users_new,
             (SELECT COUNT(*)::BIGINT FROM posts WHERE status = 'published') AS posts_published,
             (SELECT COUNT(*)::BIGINT FROM posts WHERE status = 'draft') AS posts_drafts,
-            (SELECT COUNT(*)::BIGINT FROM post_views WHERE created_at >= $1 AND created_at <= $2) AS views_in_period,
-            (SELECT COUNT(*)::BIGINT FROM post_comments WHERE created_at >= $1 AND created_at <= $2) AS comments_in_period,
+            (SELECT COALESCE(SUM(views), 0)::BIGINT FROM view_days) AS views_in_period,
+            (SELECT COALESCE(SUM(comments), 0)::BIGINT FROM comment_days) AS comments_in_period,
             (SELECT COUNT(*)::BIGINT FROM newsletter_subscribers WHERE status = 'confirmed' AND updated_at >= $1 AND updated_at <= $2) AS newsletter_confirmed,
             (SELECT COUNT(*)::BIGINT FROM media) AS media_total,
             (SELECT COUNT(*)::BIGINT FROM media WHERE created_at >= $1 AND created_at <= $2) AS media_uploads
         "#,
+        view_days = analytics_rollup::post_view_days_cte("$1", "$2", "$3"),
+        comment_days = analytics_rollup::comment_days_cte("$1", "$2", "$3"),
+    );
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
         vec![
             Value::ChronoDateTimeWithTimeZone(Some(Box::new(date_from))),
             Value::ChronoDateTimeWithTimeZone(Some(Box::new(date_to))),
+            view_rollup_watermark(&state, &resolved).await?,
         ],
     );
 
@@ -951,13 +1085,14 @@ pub async fn dashboard_summary(
             total_files: row.media_total,
             uploads_in_period: row.media_uploads,
         },
+        revenue: revenue_summary(&state, date_from, date_to).await?,
     };
 
     let filters_obj = json!({
         "period": request.filters.period.as_str()
     });
 
-    let meta = AnalyticsMeta::new(1, page, per_page)
+    let meta = AnalyticsMeta::new(1, resolved.page, resolved.per_page)
         .with_filters(filters_obj)
         .with_interval(format!(
             "{}-{}",
@@ -1035,41 +1170,1386 @@ fn parse_status_filters(
     }
 }
 
-fn resolve_dashboard_range(
-    request: &DashboardSummaryRequest,
-) -> (DateTimeWithTimeZone, DateTimeWithTimeZone, u64, u64) {
-    if let Some(envelope) = &request.envelope {
-        let resolved = envelope.resolve();
-        return (
-            resolved.date_from,
-            resolved.date_to,
-            resolved.page,
-            resolved.per_page,
-        );
+/// The envelope's own dates when given, otherwise `filters.period` back from
+/// today in the envelope's timezone.
+fn resolve_dashboard_range(request: &DashboardSummaryRequest) -> ResolvedAnalyticsEnvelope {
+    let envelope = request.envelope.clone().unwrap_or(AnalyticsEnvelope {
+        page: Some(1),
+        per_page: Some(1),
+        ..Default::default()
+    });
+    if envelope.date_from.is_some() || envelope.date_to.is_some() {
+        return envelope.resolve();
     }
 
-    let period = request.filters.period;
-    let duration: ChronoDuration = period.as_duration();
-    let today: NaiveDate = Utc::now().date_naive();
-    let date_to = today;
+    let duration: ChronoDuration = request.filters.period.as_duration();
+    let today = envelope.today();
     let date_from = today
         .checked_sub_signed(duration)
         .unwrap_or_else(|| today - ChronoDuration::days(30));
 
-    let temp_envelope = AnalyticsEnvelope {
+    AnalyticsEnvelope {
         date_from: Some(date_from),
-        date_to: Some(date_to),
-        page: Some(1),
-        per_page: Some(1),
-        sort_by: None,
-        sort_order: None,
+        date_to: Some(today),
+        ..envelope
+    }
+    .resolve()
+}
+
+/// Ingest one first-party traffic event from the consumer site. Bots are
+/// dropped silently; the client IP and User-Agent are only used to derive the
+/// country, device class and daily visitor hash, and are not stored.
+#[debug_handler]
+#[instrument(skip(state, client_ip, headers, payload))]
+pub async fn collect(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    headers: HeaderMap,
+    payload: ValidatedJson<CollectEventPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(event) = payload;
+    // Heartbeats only mean something on a post page.
+    if event.kind == AnalyticsEventKind::Engagement && event.post_id.is_none() {
+        return Ok(StatusCode::ACCEPTED);
+    }
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if traffic::is_bot(user_agent) {
+        return Ok(StatusCode::ACCEPTED);
+    }
+
+    // The consumer site posts cross-origin, so `Origin` is its own host;
+    // referrers from that host are internal navigation, not traffic sources.
+    let own_host = headers
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|origin| traffic::referrer_host(origin, None));
+    let referrer_host = event
+        .referrer
+        .as_deref()
+        .and_then(|referrer| traffic::referrer_host(referrer, own_host.as_deref()));
+    let utm = event
+        .query
+        .as_deref()
+        .map(traffic::parse_utm)
+        .unwrap_or_default();
+
+    let is_engagement = event.kind == AnalyticsEventKind::Engagement;
+    let ip = client_ip.to_string();
+    let visitor_id =
+        traffic::visitor_id(&state.secret_key, Utc::now().date_naive(), &ip, user_agent);
+
+    AnalyticsEvent::create(
+        &state.sea_db,
+        NewAnalyticsEvent {
+            kind: event.kind,
+            path: event.path,
+            post_id: event.post_id,
+            referrer_host,
+            utm,
+            device_class: traffic::device_class(Some(user_agent)),
+            country_code: state.geoip.country_code(client_ip),
+            visitor_id,
+            scroll_depth: event.scroll_depth.filter(|_| is_engagement),
+            engaged_ms: event.engaged_ms.filter(|_| is_engagement),
+        },
+    )
+    .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[derive(Debug, FromQueryResult)]
+struct ReferrerRow {
+    referrer_host: Option<String>,
+    visitors: i64,
+    pageviews: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CampaignRow {
+    utm_source: Option<String>,
+    utm_medium: Option<String>,
+    utm_campaign: Option<String>,
+    visitors: i64,
+    pageviews: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CountryRow {
+    country_code: Option<String>,
+    visitors: i64,
+    pageviews: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct DeviceRow {
+    device_class: DeviceClass,
+    visitors: i64,
+    pageviews: i64,
+    total: Option<i64>,
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn traffic_referrers(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<TrafficRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let (rows, meta) =
+        traffic_breakdown::<ReferrerRow>(&state, &request, "referrer_host", |r| r.total).await?;
+    let data: Vec<ReferrerPoint> = rows
+        .into_iter()
+        .map(|row| ReferrerPoint {
+            referrer_host: row.referrer_host,
+            visitors: row.visitors,
+            pageviews: row.pageviews,
+        })
+        .collect();
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn traffic_campaigns(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<TrafficRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let (rows, meta) = traffic_breakdown::<CampaignRow>(
+        &state,
+        &request,
+        "utm_source, utm_medium, utm_campaign",
+        |r| r.total,
+    )
+    .await?;
+    let data: Vec<CampaignPoint> = rows
+        .into_iter()
+        .map(|row| CampaignPoint {
+            utm_source: row.utm_source,
+            utm_medium: row.utm_medium,
+            utm_campaign: row.utm_campaign,
+            visitors: row.visitors,
+            pageviews: row.pageviews,
+        })
+        .collect();
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn traffic_countries(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<TrafficRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let (rows, meta) =
+        traffic_breakdown::<CountryRow>(&state, &request, "country_code", |r| r.total).await?;
+    let data: Vec<CountryPoint> = rows
+        .into_iter()
+        .map(|row| CountryPoint {
+            country_code: row.country_code,
+            visitors: row.visitors,
+            pageviews: row.pageviews,
+        })
+        .collect();
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn traffic_devices(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<TrafficRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let (rows, meta) =
+        traffic_breakdown::<DeviceRow>(&state, &request, "device_class", |r| r.total).await?;
+    let data: Vec<DevicePoint> = rows
+        .into_iter()
+        .map(|row| DevicePoint {
+            device_class: row.device_class,
+            visitors: row.visitors,
+            pageviews: row.pageviews,
+        })
+        .collect();
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+/// Group pageviews in the requested window by `dimensions` (a fixed column
+/// list chosen by the caller, never user input). Visitors are distinct daily
+/// visitor ids, so across a multi-day range a returning visitor counts once
+/// per day.
+async fn traffic_breakdown<R: FromQueryResult>(
+    state: &AppState,
+    request: &TrafficRequest,
+    dimensions: &str,
+    total_of: impl Fn(&R) -> Option<i64>,
+) -> Result<(Vec<R>, AnalyticsMeta), ErrorResponse> {
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let filters = &request.filters;
+
+    let (sort_field, tie_breaker) = match resolved.sort_by.as_deref() {
+        Some("pageviews") => ("pageviews", "visitors"),
+        _ => ("visitors", "pageviews"),
     };
 
-    let resolved = temp_envelope.resolve();
-    (
-        resolved.date_from,
-        resolved.date_to,
-        resolved.page,
-        resolved.per_page,
+    let sql = format!(
+        r#"
+        SELECT
+            {dimensions},
+            COUNT(DISTINCT visitor_id)::BIGINT AS visitors,
+            COUNT(*)::BIGINT AS pageviews,
+            COUNT(*) OVER () AS total
+        FROM analytics_events
+        WHERE kind = $1
+          AND created_at >= $2
+          AND created_at <= $3
+          AND ($4 IS NULL OR post_id = $4)
+          AND ($5 IS NULL OR path = $5)
+        GROUP BY {dimensions}
+        ORDER BY {sort_field} {order}, {tie_breaker} DESC
+        LIMIT $6 OFFSET $7
+        "#,
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::String(Some(Box::new(
+                AnalyticsEventKind::Pageview.as_str().to_string(),
+            ))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            Value::Int(filters.post_id),
+            Value::String(filters.path.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+
+    let rows = R::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows.first().and_then(&total_of).unwrap_or_default().max(0) as u64;
+
+    let mut filters_obj = JsonMap::new();
+    if let Some(post_id) = filters.post_id {
+        filters_obj.insert("post_id".into(), json!(post_id));
+    }
+    if let Some(path) = &filters.path {
+        filters_obj.insert("path".into(), json!(path));
+    }
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_sorted_by(sort_field)
+        .with_filters(JsonValue::Object(filters_obj));
+
+    Ok((rows, meta))
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PostEngagementRow {
+    post_id: i32,
+    title: String,
+    views: i64,
+    visitors: i64,
+    avg_read_time_secs: f64,
+    completion_rate: f64,
+    likes: i64,
+    comments: i64,
+    likes_per_view: f64,
+    comments_per_view: f64,
+    total: Option<i64>,
+}
+
+impl From<PostEngagementRow> for PostEngagementPoint {
+    fn from(row: PostEngagementRow) -> Self {
+        Self {
+            post_id: row.post_id,
+            title: row.title,
+            views: row.views,
+            visitors: row.visitors,
+            avg_read_time_secs: row.avg_read_time_secs,
+            completion_rate: row.completion_rate,
+            likes: row.likes,
+            comments: row.comments,
+            likes_per_view: row.likes_per_view,
+            comments_per_view: row.comments_per_view,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PostTrafficRow {
+    bucket: String,
+    pageviews: i64,
+    visitors: i64,
+}
+
+/// Per-post engagement over `[$1, $2]` for the posts matched by `scope` (a
+/// fixed predicate on `posts` bound to `$3`), with `$4` as the completion
+/// scroll depth. Views and comments come from the daily rollups, with
+/// `through` the placeholder bound to [`rollup_watermark`]. `tail` carries any
+/// ORDER BY / LIMIT clause.
+fn post_engagement_sql(scope: &str, through: &str, tail: &str) -> String {
+    format!(
+        r#"
+        WITH scoped_posts AS (
+            SELECT id, title FROM posts WHERE {scope}
+        ),
+        {view_days},
+        {comment_days},
+        views AS (
+            SELECT post_id, SUM(views)::BIGINT AS views
+            FROM view_days
+            WHERE post_id IN (SELECT id FROM scoped_posts)
+            GROUP BY post_id
+        ),
+        visits AS (
+            SELECT post_id, COUNT(DISTINCT visitor_id)::BIGINT AS visitors
+            FROM analytics_events
+            WHERE kind = 'pageview'
+              AND created_at >= $1 AND created_at <= $2
+              AND post_id IN (SELECT id FROM scoped_posts)
+            GROUP BY post_id
+        ),
+        readers AS (
+            SELECT
+                post_id,
+                visitor_id,
+                SUM(COALESCE(engaged_ms, 0))::BIGINT AS engaged_ms,
+                MAX(COALESCE(scroll_depth, 0)) AS max_depth
+            FROM analytics_events
+            WHERE kind = 'engagement'
+              AND created_at >= $1 AND created_at <= $2
+              AND post_id IN (SELECT id FROM scoped_posts)
+            GROUP BY post_id, visitor_id
+        ),
+        reading AS (
+            SELECT
+                post_id,
+                AVG(engaged_ms)::FLOAT8 / 1000 AS avg_read_time_secs,
+                COUNT(*) FILTER (WHERE max_depth >= $4)::BIGINT AS completed
+            FROM readers
+            GROUP BY post_id
+        ),
+        like_counts AS (
+            SELECT post_id, COUNT(*)::BIGINT AS likes
+            FROM post_likes
+            WHERE created_at >= $1 AND created_at <= $2
+              AND post_id IN (SELECT id FROM scoped_posts)
+            GROUP BY post_id
+        ),
+        comment_counts AS (
+            SELECT post_id, SUM(comments)::BIGINT AS comments
+            FROM comment_days
+            WHERE post_id IN (SELECT id FROM scoped_posts)
+            GROUP BY post_id
+        ),
+        combined AS (
+            SELECT
+                p.id AS post_id,
+                p.title,
+                COALESCE(v.views, 0) AS views,
+                COALESCE(vi.visitors, 0) AS visitors,
+                ROUND(COALESCE(r.avg_read_time_secs, 0)::NUMERIC, 1)::FLOAT8
+                    AS avg_read_time_secs,
+                CASE
+                    WHEN COALESCE(vi.visitors, 0) = 0 THEN 0::FLOAT8
+                    ELSE ROUND(
+                        LEAST(COALESCE(r.completed, 0)::NUMERIC / vi.visitors::NUMERIC, 1),
+                        4
+                    )::FLOAT8
+                END AS completion_rate,
+                COALESCE(l.likes, 0) AS likes,
+                COALESCE(c.comments, 0) AS comments,
+                CASE
+                    WHEN COALESCE(v.views, 0) = 0 THEN 0::FLOAT8
+                    ELSE ROUND(COALESCE(l.likes, 0)::NUMERIC / v.views::NUMERIC, 4)::FLOAT8
+                END AS likes_per_view,
+                CASE
+                    WHEN COALESCE(v.views, 0) = 0 THEN 0::FLOAT8
+                    ELSE ROUND(COALESCE(c.comments, 0)::NUMERIC / v.views::NUMERIC, 4)::FLOAT8
+                END AS comments_per_view
+            FROM scoped_posts p
+            LEFT JOIN views v ON v.post_id = p.id
+            LEFT JOIN visits vi ON vi.post_id = p.id
+            LEFT JOIN reading r ON r.post_id = p.id
+            LEFT JOIN like_counts l ON l.post_id = p.id
+            LEFT JOIN comment_counts c ON c.post_id = p.id
+        )
+        SELECT
+            *,
+            COUNT(*) OVER () AS total
+        FROM combined
+        {tail}
+        "#,
+        view_days = analytics_rollup::post_view_days_cte("$1", "$2", through),
+        comment_days = analytics_rollup::comment_days_cte("$1", "$2", through),
     )
 }
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn post_engagement(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<PostEngagementRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let post_id = request.filters.post_id;
+    let interval = request.filters.group_by;
+
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        post_engagement_sql("id = $3", "$5", ""),
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            Value::Int(Some(post_id)),
+            Value::SmallInt(Some(COMPLETION_SCROLL_DEPTH)),
+            view_rollup_watermark(&state, &resolved).await?,
+        ],
+    );
+    let summary = PostEngagementRow::find_by_statement(stmt)
+        .one(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Post not found")
+        })?;
+
+    let bucket_expr = interval.to_bucket_expr(&resolved.local("created_at"));
+    let sql = format!(
+        r#"
+        SELECT
+            {bucket_expr} AS bucket,
+            COUNT(*)::BIGINT AS pageviews,
+            COUNT(DISTINCT visitor_id)::BIGINT AS visitors
+        FROM analytics_events
+        WHERE kind = 'pageview'
+          AND post_id = $1
+          AND created_at >= $2
+          AND created_at <= $3
+        GROUP BY bucket
+        ORDER BY bucket {order}
+        "#,
+        order = resolved.sort_order.as_sql(),
+    );
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::Int(Some(post_id)),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+        ],
+    );
+    let traffic: Vec<PostTrafficPoint> = PostTrafficRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?
+        .into_iter()
+        .map(|row| PostTrafficPoint {
+            bucket: row.bucket,
+            pageviews: row.pageviews,
+            visitors: row.visitors,
+        })
+        .collect();
+
+    let buckets = traffic.len() as u64;
+    let meta = AnalyticsMeta::new(buckets, 1, buckets)
+        .with_interval(interval.as_str())
+        .with_filters(json!({ "post_id": post_id, "group_by": interval.as_str() }));
+    let data = PostEngagementData {
+        summary: summary.into(),
+        traffic,
+    };
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn author_engagement(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<AuthorEngagementRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let author_id = request.filters.author_id;
+
+    let sort_field = match resolved.sort_by.as_deref() {
+        Some("visitors") => "visitors",
+        Some("avg_read_time") => "avg_read_time_secs",
+        Some("completion_rate") => "completion_rate",
+        Some("likes_per_view") => "likes_per_view",
+        Some("comments_per_view") => "comments_per_view",
+        _ => "views",
+    };
+    let tail = format!(
+        "ORDER BY {sort_field} {}, post_id DESC LIMIT $5 OFFSET $6",
+        resolved.sort_order.as_sql()
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        post_engagement_sql("author_id = $3", "$7", &tail),
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            Value::Int(Some(author_id)),
+            Value::SmallInt(Some(COMPLETION_SCROLL_DEPTH)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+            view_rollup_watermark(&state, &resolved).await?,
+        ],
+    );
+    let rows = PostEngagementRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<PostEngagementPoint> = rows.into_iter().map(Into::into).collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_sorted_by(sort_field)
+        .with_filters(json!({ "author_id": author_id }));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+/// `fx(currency, rate)` from the configured exchange rates, bound to `$3`
+/// (codes) and `$4` (rates) by [`fx_values`].
+const FX_CTE: &str = "fx AS (SELECT * FROM unnest($3::TEXT[], $4::FLOAT8[]) AS fx(currency, rate))";
+
+fn fx_values(fx: &ExchangeRates) -> [Value; 2] {
+    // Stored amounts are minor units, so the rates applied to them must be too.
+    let minor_rates = fx.minor_unit_rates();
+    let codes = minor_rates
+        .keys()
+        .map(|code| Value::String(Some(Box::new(code.clone()))))
+        .collect::<Vec<_>>();
+    let rates = minor_rates
+        .values()
+        .map(|rate| Value::Double(Some(*rate)))
+        .collect::<Vec<_>>();
+    [
+        Value::Array(ArrayType::String, Some(Box::new(codes))),
+        Value::Array(ArrayType::Double, Some(Box::new(rates))),
+    ]
+}
+
+fn revenue_filters_json(fx: &ExchangeRates, extra: JsonValue) -> JsonValue {
+    let mut filters = match extra {
+        JsonValue::Object(map) => map,
+        _ => JsonMap::new(),
+    };
+    filters.insert("currency".into(), json!(fx.reporting_currency()));
+    filters.insert("fx_rates".into(), json!(fx.rates()));
+    JsonValue::Object(filters)
+}
+
+/// Subscriptions with their plan price normalized to one month and converted
+/// into the reporting currency (`NULL` without a rate). Needs [`FX_CTE`];
+/// `$5` optionally restricts the provider.
+const SUBSCRIPTION_VALUES_CTE: &str = r#"
+    subs AS (
+        SELECT
+            s.status,
+            s.created_at,
+            s.canceled_at,
+            s.trial_ends_at,
+            (CASE WHEN p."interval" = 'yearly'
+                THEN p.price_cents::FLOAT8 / 12
+                ELSE p.price_cents::FLOAT8
+            END) * fx.rate AS monthly_cents
+        FROM subscriptions s
+        JOIN plans p ON p.id = s.plan_id
+        LEFT JOIN fx ON fx.currency = LOWER(p.currency)
+        WHERE ($5::TEXT IS NULL OR s.provider = $5)
+    )
+"#;
+
+/// A subscription in [`SUBSCRIPTION_VALUES_CTE`] is paying at `{at}` when it
+/// had started, had not churned and was past any trial.
+fn paying_at(alias: &str, at: &str) -> String {
+    format!(
+        "{alias}.created_at <= {at} \
+         AND ({alias}.canceled_at IS NULL OR {alias}.canceled_at > {at}) \
+         AND ({alias}.trial_ends_at IS NULL OR {alias}.trial_ends_at <= {at}) \
+         AND {alias}.status <> 'trialing'"
+    )
+}
+
+#[derive(Debug, FromQueryResult)]
+struct MrrRow {
+    bucket: String,
+    mrr_cents: i64,
+    paying_subscriptions: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SubscriberFlowRow {
+    bucket: String,
+    new_subscriptions: i64,
+    churned: i64,
+    net_change: i64,
+    active_at_start: i64,
+    churn_rate: f64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TrialConversionRow {
+    bucket: String,
+    trials_started: i64,
+    converted: i64,
+    in_trial: i64,
+    conversion_rate: f64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PostRevenueRow {
+    post_id: i32,
+    title: String,
+    purchases: i64,
+    refunded: i64,
+    revenue_cents: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct ProviderRevenueRow {
+    provider: String,
+    payments: i64,
+    failed_payments: i64,
+    revenue_cents: i64,
+    refunded_cents: i64,
+    post_purchases: i64,
+    active_subscriptions: i64,
+    total: Option<i64>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct RevenueSummaryRow {
+    mrr_cents: i64,
+    paying_subscriptions: i64,
+    trialing: i64,
+    new_subscriptions: i64,
+    churned: i64,
+    active_at_start: i64,
+    revenue_in_period_cents: i64,
+    refunded_cents: i64,
+    unconverted_payments: i64,
+}
+
+/// Generated buckets covering `[$1, $2]` with their start and end. Buckets
+/// are stepped in local time so days and months follow the requested
+/// timezone across DST changes.
+fn bucket_series(interval: AnalyticsInterval, resolved: &ResolvedAnalyticsEnvelope) -> String {
+    let unit = interval.as_str();
+    let tz = resolved.timezone.name();
+    format!(
+        r#"
+        buckets AS (
+            SELECT
+                local_start AT TIME ZONE '{tz}' AS bucket_start,
+                (local_start + INTERVAL '1 {unit}') AT TIME ZONE '{tz}' AS bucket_end
+            FROM generate_series(
+                date_trunc('{unit}', {from}),
+                {to},
+                INTERVAL '1 {unit}'
+            ) AS local_start
+        )
+        "#,
+        from = resolved.local("$1::TIMESTAMPTZ"),
+        to = resolved.local("$2::TIMESTAMPTZ"),
+    )
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn revenue_mrr(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<RevenueTrendRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let interval = request.filters.group_by;
+    let provider = request.filters.provider;
+    let fx = state.exchange_rates.clone();
+
+    // MRR is measured at the end of each bucket, or at `date_to` for the
+    // last, partial one.
+    let sql = format!(
+        r#"
+        WITH {FX_CTE},
+        {SUBSCRIPTION_VALUES_CTE},
+        {buckets},
+        points AS (
+            SELECT bucket_start, LEAST(bucket_end, $2::TIMESTAMPTZ) AS point_at
+            FROM buckets
+        )
+        SELECT
+            {bucket_expr} AS bucket,
+            COALESCE(ROUND(SUM(s.monthly_cents)), 0)::BIGINT AS mrr_cents,
+            COUNT(s.created_at)::BIGINT AS paying_subscriptions,
+            COUNT(*) OVER () AS total
+        FROM points b
+        LEFT JOIN subs s ON {paying}
+        GROUP BY b.bucket_start
+        ORDER BY b.bucket_start {order}
+        LIMIT $6 OFFSET $7
+        "#,
+        buckets = bucket_series(interval, &resolved),
+        bucket_expr = interval.to_bucket_expr(&resolved.local("b.bucket_start")),
+        paying = paying_at("s", "b.point_at"),
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let [codes, rates] = fx_values(&fx);
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            codes,
+            rates,
+            Value::String(provider.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+    let rows = MrrRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<MrrPoint> = rows
+        .into_iter()
+        .map(|row| MrrPoint {
+            bucket: row.bucket,
+            mrr_cents: row.mrr_cents,
+            arr_cents: row.mrr_cents * 12,
+            paying_subscriptions: row.paying_subscriptions,
+        })
+        .collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_interval(interval.as_str())
+        .with_filters(revenue_filters_json(
+            &fx,
+            json!({ "group_by": interval.as_str(), "provider": provider }),
+        ));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn revenue_subscribers(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<RevenueTrendRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let interval = request.filters.group_by;
+    let provider = request.filters.provider;
+
+    let sql = format!(
+        r#"
+        WITH {buckets},
+        subs AS (
+            SELECT created_at, canceled_at
+            FROM subscriptions
+            WHERE ($3::TEXT IS NULL OR provider = $3)
+        ),
+        flow AS (
+            SELECT
+                b.bucket_start,
+                COUNT(*) FILTER (
+                    WHERE s.created_at >= b.bucket_start AND s.created_at < b.bucket_end
+                )::BIGINT AS new_subscriptions,
+                COUNT(*) FILTER (
+                    WHERE s.canceled_at >= b.bucket_start AND s.canceled_at < b.bucket_end
+                )::BIGINT AS churned,
+                COUNT(*) FILTER (
+                    WHERE s.created_at < b.bucket_start
+                      AND (s.canceled_at IS NULL OR s.canceled_at >= b.bucket_start)
+                )::BIGINT AS active_at_start
+            FROM buckets b
+            LEFT JOIN subs s ON s.created_at < b.bucket_end
+            GROUP BY b.bucket_start
+        )
+        SELECT
+            {bucket_expr} AS bucket,
+            new_subscriptions,
+            churned,
+            new_subscriptions - churned AS net_change,
+            active_at_start,
+            CASE
+                WHEN active_at_start = 0 THEN 0::FLOAT8
+                ELSE ROUND(churned::NUMERIC / active_at_start::NUMERIC, 4)::FLOAT8
+            END AS churn_rate,
+            COUNT(*) OVER () AS total
+        FROM flow
+        ORDER BY bucket_start {order}
+        LIMIT $4 OFFSET $5
+        "#,
+        buckets = bucket_series(interval, &resolved),
+        bucket_expr = interval.to_bucket_expr(&resolved.local("bucket_start")),
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            Value::String(provider.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+    let rows = SubscriberFlowRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<SubscriberFlowPoint> = rows
+        .into_iter()
+        .map(|row| SubscriberFlowPoint {
+            bucket: row.bucket,
+            new_subscriptions: row.new_subscriptions,
+            churned: row.churned,
+            net_change: row.net_change,
+            active_at_start: row.active_at_start,
+            churn_rate: row.churn_rate,
+        })
+        .collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_interval(interval.as_str())
+        .with_filters(json!({ "group_by": interval.as_str(), "provider": provider }));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn revenue_trials(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<RevenueTrendRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let interval = request.filters.group_by;
+    let provider = request.filters.provider;
+
+    // A trial converts once a real payment lands on its subscription; the
+    // rate only counts trials that have had the chance to.
+    let sql = format!(
+        r#"
+        WITH trials AS (
+            SELECT
+                s.created_at,
+                s.trial_ends_at,
+                EXISTS (
+                    SELECT 1 FROM payments p
+                    WHERE p.subscription_id = s.id
+                      AND p.status = 'completed'
+                      AND p.amount_cents > 0
+                ) AS converted
+            FROM subscriptions s
+            WHERE s.trial_ends_at IS NOT NULL
+              AND s.created_at >= $1 AND s.created_at <= $2
+              AND ($3::TEXT IS NULL OR s.provider = $3)
+        ),
+        grouped AS (
+            SELECT
+                {bucket_expr} AS bucket,
+                COUNT(*)::BIGINT AS trials_started,
+                COUNT(*) FILTER (WHERE converted)::BIGINT AS converted,
+                COUNT(*) FILTER (WHERE NOT converted AND trial_ends_at > NOW())::BIGINT
+                    AS in_trial
+            FROM trials
+            GROUP BY 1
+        )
+        SELECT
+            *,
+            CASE
+                WHEN trials_started - in_trial = 0 THEN 0::FLOAT8
+                ELSE ROUND(
+                    LEAST(converted::NUMERIC / (trials_started - in_trial)::NUMERIC, 1),
+                    4
+                )::FLOAT8
+            END AS conversion_rate,
+            COUNT(*) OVER () AS total
+        FROM grouped
+        ORDER BY bucket {order}
+        LIMIT $4 OFFSET $5
+        "#,
+        bucket_expr = interval.to_bucket_expr(&resolved.local("created_at")),
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            Value::String(provider.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+    let rows = TrialConversionRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<TrialConversionPoint> = rows
+        .into_iter()
+        .map(|row| TrialConversionPoint {
+            bucket: row.bucket,
+            trials_started: row.trials_started,
+            converted: row.converted,
+            in_trial: row.in_trial,
+            conversion_rate: row.conversion_rate,
+        })
+        .collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_interval(interval.as_str())
+        .with_filters(json!({ "group_by": interval.as_str(), "provider": provider }));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn revenue_posts(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<RevenueBreakdownRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let provider = request.filters.provider;
+    let fx = state.exchange_rates.clone();
+
+    let sort_field = match resolved.sort_by.as_deref() {
+        Some("purchases") => "purchases",
+        Some("refunded") => "refunded",
+        _ => "revenue_cents",
+    };
+
+    let sql = format!(
+        r#"
+        WITH {FX_CTE},
+        purchases AS (
+            SELECT
+                pp.post_id,
+                COUNT(*)::BIGINT AS purchases,
+                COUNT(*) FILTER (WHERE pay.status = 'refunded')::BIGINT AS refunded,
+                COALESCE(
+                    ROUND(SUM(pp.amount_cents * fx.rate) FILTER (
+                        WHERE pay.status IS DISTINCT FROM 'refunded'
+                    )),
+                    0
+                )::BIGINT AS revenue_cents
+            FROM post_purchases pp
+            LEFT JOIN payments pay ON pay.id = pp.payment_id
+            LEFT JOIN fx ON fx.currency = LOWER(pp.currency)
+            WHERE pp.created_at >= $1 AND pp.created_at <= $2
+              AND ($5::TEXT IS NULL OR pp.provider = $5)
+            GROUP BY pp.post_id
+        )
+        SELECT
+            p.id AS post_id,
+            p.title,
+            pu.purchases,
+            pu.refunded,
+            pu.revenue_cents,
+            COUNT(*) OVER () AS total
+        FROM purchases pu
+        JOIN posts p ON p.id = pu.post_id
+        ORDER BY {sort_field} {order}, post_id DESC
+        LIMIT $6 OFFSET $7
+        "#,
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let [codes, rates] = fx_values(&fx);
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            codes,
+            rates,
+            Value::String(provider.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+    let rows = PostRevenueRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<PostRevenuePoint> = rows
+        .into_iter()
+        .map(|row| PostRevenuePoint {
+            post_id: row.post_id,
+            title: row.title,
+            purchases: row.purchases,
+            refunded: row.refunded,
+            revenue_cents: row.revenue_cents,
+        })
+        .collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_sorted_by(sort_field)
+        .with_filters(revenue_filters_json(&fx, json!({ "provider": provider })));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn revenue_providers(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<RevenueBreakdownRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let ValidatedJson(request) = payload;
+    let resolved = request.envelope.resolve();
+    let limit = resolved.per_page as i64;
+    let offset = resolved.offset() as i64;
+    let provider = request.filters.provider;
+    let fx = state.exchange_rates.clone();
+
+    let sort_field = match resolved.sort_by.as_deref() {
+        Some("payments") => "payments",
+        Some("active_subscriptions") => "active_subscriptions",
+        Some("post_purchases") => "post_purchases",
+        _ => "revenue_cents",
+    };
+
+    let sql = format!(
+        r#"
+        WITH {FX_CTE},
+        payment_stats AS (
+            SELECT
+                p.provider,
+                COUNT(*) FILTER (WHERE p.status = 'completed')::BIGINT AS payments,
+                COUNT(*) FILTER (WHERE p.status = 'failed')::BIGINT AS failed_payments,
+                COALESCE(
+                    ROUND(SUM(p.amount_cents * fx.rate) FILTER (WHERE p.status = 'completed')),
+                    0
+                )::BIGINT AS revenue_cents,
+                COALESCE(
+                    ROUND(SUM(p.amount_cents * fx.rate) FILTER (WHERE p.status = 'refunded')),
+                    0
+                )::BIGINT AS refunded_cents
+            FROM payments p
+            LEFT JOIN fx ON fx.currency = LOWER(p.currency)
+            WHERE p.created_at >= $1 AND p.created_at <= $2
+            GROUP BY p.provider
+        ),
+        purchase_stats AS (
+            SELECT provider, COUNT(*)::BIGINT AS post_purchases
+            FROM post_purchases
+            WHERE created_at >= $1 AND created_at <= $2
+            GROUP BY provider
+        ),
+        subscription_stats AS (
+            SELECT provider, COUNT(*)::BIGINT AS active_subscriptions
+            FROM subscriptions
+            WHERE status IN ('active', 'past_due', 'trialing')
+            GROUP BY provider
+        ),
+        providers AS (
+            SELECT provider FROM payment_stats
+            UNION SELECT provider FROM purchase_stats
+            UNION SELECT provider FROM subscription_stats
+        )
+        SELECT
+            pr.provider,
+            COALESCE(ps.payments, 0) AS payments,
+            COALESCE(ps.failed_payments, 0) AS failed_payments,
+            COALESCE(ps.revenue_cents, 0) AS revenue_cents,
+            COALESCE(ps.refunded_cents, 0) AS refunded_cents,
+            COALESCE(pu.post_purchases, 0) AS post_purchases,
+            COALESCE(ss.active_subscriptions, 0) AS active_subscriptions,
+            COUNT(*) OVER () AS total
+        FROM providers pr
+        LEFT JOIN payment_stats ps ON ps.provider = pr.provider
+        LEFT JOIN purchase_stats pu ON pu.provider = pr.provider
+        LEFT JOIN subscription_stats ss ON ss.provider = pr.provider
+        WHERE ($5::TEXT IS NULL OR pr.provider = $5)
+        ORDER BY {sort_field} {order}, pr.provider ASC
+        LIMIT $6 OFFSET $7
+        "#,
+        order = resolved.sort_order.as_sql(),
+    );
+
+    let [codes, rates] = fx_values(&fx);
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(resolved.date_to))),
+            codes,
+            rates,
+            Value::String(provider.clone().map(Box::new)),
+            Value::BigInt(Some(limit)),
+            Value::BigInt(Some(offset)),
+        ],
+    );
+    let rows = ProviderRevenueRow::find_by_statement(stmt)
+        .all(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let total = rows
+        .first()
+        .and_then(|row| row.total)
+        .unwrap_or_default()
+        .max(0) as u64;
+    let data: Vec<ProviderRevenuePoint> = rows
+        .into_iter()
+        .map(|row| ProviderRevenuePoint {
+            provider: row.provider,
+            payments: row.payments,
+            failed_payments: row.failed_payments,
+            revenue_cents: row.revenue_cents,
+            refunded_cents: row.refunded_cents,
+            post_purchases: row.post_purchases,
+            active_subscriptions: row.active_subscriptions,
+        })
+        .collect();
+
+    let meta = AnalyticsMeta::new(total, resolved.page, resolved.per_page)
+        .with_sorted_by(sort_field)
+        .with_filters(revenue_filters_json(&fx, json!({ "provider": provider })));
+
+    Ok(Json(AnalyticsEnvelopeResponse { data, meta }))
+}
+
+/// Revenue headline for the dashboard: current MRR and trial count, plus
+/// subscriber flow and payments within `[date_from, date_to]`.
+async fn revenue_summary(
+    state: &AppState,
+    date_from: DateTimeWithTimeZone,
+    date_to: DateTimeWithTimeZone,
+) -> Result<DashboardSummaryRevenue, ErrorResponse> {
+    let fx = state.exchange_rates.clone();
+    let sql = format!(
+        r#"
+        WITH {FX_CTE},
+        {SUBSCRIPTION_VALUES_CTE},
+        subscription_stats AS (
+            SELECT
+                COALESCE(ROUND(SUM(s.monthly_cents) FILTER (WHERE {paying})), 0)::BIGINT
+                    AS mrr_cents,
+                COUNT(*) FILTER (WHERE {paying})::BIGINT AS paying_subscriptions,
+                COUNT(*) FILTER (
+                    WHERE s.status = 'trialing' AND s.canceled_at IS NULL
+                )::BIGINT AS trialing,
+                COUNT(*) FILTER (
+                    WHERE s.created_at >= $1 AND s.created_at <= $2
+                )::BIGINT AS new_subscriptions,
+                COUNT(*) FILTER (
+                    WHERE s.canceled_at >= $1 AND s.canceled_at <= $2
+                )::BIGINT AS churned,
+                COUNT(*) FILTER (
+                    WHERE s.created_at < $1 AND (s.canceled_at IS NULL OR s.canceled_at >= $1)
+                )::BIGINT AS active_at_start
+            FROM subs s
+        ),
+        payment_stats AS (
+            SELECT
+                COALESCE(
+                    ROUND(SUM(p.amount_cents * fx.rate) FILTER (WHERE p.status = 'completed')),
+                    0
+                )::BIGINT AS revenue_in_period_cents,
+                COALESCE(
+                    ROUND(SUM(p.amount_cents * fx.rate) FILTER (WHERE p.status = 'refunded')),
+                    0
+                )::BIGINT AS refunded_cents,
+                COUNT(*) FILTER (
+                    WHERE p.status = 'completed' AND fx.rate IS NULL
+                )::BIGINT AS unconverted_payments
+            FROM payments p
+            LEFT JOIN fx ON fx.currency = LOWER(p.currency)
+            WHERE p.created_at >= $1 AND p.created_at <= $2
+              AND ($5::TEXT IS NULL OR p.provider = $5)
+        )
+        SELECT * FROM subscription_stats, payment_stats
+        "#,
+        paying = paying_at("s", "NOW()"),
+    );
+
+    let [codes, rates] = fx_values(&fx);
+    let stmt = Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        sql,
+        vec![
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(date_from))),
+            Value::ChronoDateTimeWithTimeZone(Some(Box::new(date_to))),
+            codes,
+            rates,
+            Value::String(None),
+        ],
+    );
+    let row = RevenueSummaryRow::find_by_statement(stmt)
+        .one(&state.sea_db)
+        .await
+        .map_err(ErrorResponse::from)?
+        .unwrap_or(RevenueSummaryRow {
+            mrr_cents: 0,
+            paying_subscriptions: 0,
+            trialing: 0,
+            new_subscriptions: 0,
+            churned: 0,
+            active_at_start: 0,
+            revenue_in_period_cents: 0,
+            refunded_cents: 0,
+            unconverted_payments: 0,
+        });
+
+    let churn_rate = if row.active_at_start > 0 {
+        row.churned as f64 / row.active_at_start as f64
+    } else {
+        0.0
+    };
+    let arpu_cents = if row.paying_subscriptions > 0 {
+        row.mrr_cents / row.paying_subscriptions
+    } else {
+        0
+    };
+    let period_days = (date_to - date_from).num_days().max(1);
+
+    Ok(DashboardSummaryRevenue {
+        currency: fx.reporting_currency().to_string(),
+        mrr_cents: row.mrr_cents,
+        arr_cents: row.mrr_cents * 12,
+        paying_subscriptions: row.paying_subscriptions,
+        trialing: row.trialing,
+        new_subscriptions: row.new_subscriptions,
+        churned: row.churned,
+        churn_rate: (churn_rate * 10_000.0).round() / 10_000.0,
+        revenue_in_period_cents: row.revenue_in_period_cents,
+        refunded_cents: row.refunded_cents,
+        arpu_cents,
+        ltv_cents: estimate_ltv_cents(arpu_cents, churn_rate, period_days),
+        unconverted_payments: row.unconverted_payments,
+    })
+}
+
+/// Lifetime value as ARPU over monthly churn, with the churn observed over
+/// `period_days` compounded to a 30-day rate.
+fn estimate_ltv_cents(arpu_cents: i64, period_churn: f64, period_days: i64) -> Option<i64> {
+    if arpu_cents <= 0 || period_churn <= 0.0 {
+        return None;
+    }
+    let monthly_churn = if period_churn >= 1.0 {
+        1.0
+    } else {
+        1.0 - (1.0 - period_churn).powf(30.0 / period_days as f64)
+    };
+    (monthly_churn > 0.0).then(|| (arpu_cents as f64 / monthly_churn).round() as i64)
+}
+
+/// Run a rollup pass on demand, e.g. to backfill after deploying or to
+/// rebuild days after correcting raw data.
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn run_rollups(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    ValidatedJson(payload): ValidatedJson<RollupRunPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let defaults = analytics_rollup::AnalyticsRollupConfig::default();
+    let options = analytics_rollup::AnalyticsRollupOptions {
+        rebuild_from: payload.rebuild_from,
+        max_days: payload.max_days.unwrap_or(defaults.max_days_per_pass),
+        raw_retention_days: 0,
+        archive: defaults.archive,
+        prune_batch: defaults.prune_batch,
+    };
+
+    let report = analytics_rollup::run_once(&state, options).await?;
+    Ok((StatusCode::OK, Json(report)))
+}
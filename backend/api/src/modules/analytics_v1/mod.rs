@@ -7,7 +7,11 @@ use crate::{middlewares::auth_guard, AppState};
 
 /// Routes for the analytics v1 module.
 pub fn routes() -> Router<AppState> {
-    Router::<AppState>::new()
+    // Event ingestion from the consumer site is public; every report is
+    // admin-only.
+    let public = Router::<AppState>::new().route("/collect", post(controller::collect));
+
+    let admin = Router::<AppState>::new()
         .route(
             "/user/registration-trends",
             post(controller::registration_trends),
@@ -21,6 +25,8 @@ pub fn routes() -> Router<AppState> {
             post(controller::publishing_trends),
         )
         .route("/engagement/page-views", post(controller::page_views))
+        .route("/engagement/post", post(controller::post_engagement))
+        .route("/engagement/author", post(controller::author_engagement))
         .route("/engagement/comment-rate", post(controller::comment_rate))
         .route(
             "/engagement/newsletter-growth",
@@ -31,7 +37,22 @@ pub fn routes() -> Router<AppState> {
             post(controller::media_upload_trends),
         )
         .route("/dashboard/summary", post(controller::dashboard_summary))
+        .route("/traffic/referrers", post(controller::traffic_referrers))
+        .route("/traffic/campaigns", post(controller::traffic_campaigns))
+        .route("/traffic/countries", post(controller::traffic_countries))
+        .route("/traffic/devices", post(controller::traffic_devices))
+        .route("/revenue/mrr", post(controller::revenue_mrr))
+        .route(
+            "/revenue/subscribers",
+            post(controller::revenue_subscribers),
+        )
+        .route("/revenue/trials", post(controller::revenue_trials))
+        .route("/revenue/posts", post(controller::revenue_posts))
+        .route("/revenue/providers", post(controller::revenue_providers))
+        .route("/rollups/run", post(controller::run_rollups))
         .route_layer(middleware::from_fn(
             auth_guard::verified_with_role::<{ auth_guard::ROLE_ADMIN }>,
-        ))
+        ));
+
+    public.merge(admin)
 }
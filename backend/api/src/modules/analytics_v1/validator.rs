@@ -1,11 +1,14 @@
 use std::{collections::BTreeMap, ops::Bound};
 
-use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use validator::{Validate, ValidationError, ValidationErrors};
 
+use crate::db::sea_models::analytics_event::{AnalyticsEventKind, DeviceClass};
+
 pub const DEFAULT_PER_PAGE: u64 = 30;
 pub const MAX_PER_PAGE: u64 = 200;
 
@@ -32,11 +35,29 @@ pub struct AnalyticsEnvelope {
     pub sort_by: Option<String>,
     #[serde(default)]
     pub sort_order: Option<String>,
+    /// IANA timezone (e.g. `America/Los_Angeles`) that dates, "today" and
+    /// buckets are interpreted in. Defaults to UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 impl AnalyticsEnvelope {
+    /// The requested timezone, UTC when unset or unknown.
+    pub fn tz(&self) -> Tz {
+        self.timezone
+            .as_deref()
+            .and_then(parse_timezone)
+            .unwrap_or(Tz::UTC)
+    }
+
+    /// The current calendar date in the requested timezone.
+    pub fn today(&self) -> NaiveDate {
+        Utc::now().with_timezone(&self.tz()).date_naive()
+    }
+
     pub fn resolve(&self) -> ResolvedAnalyticsEnvelope {
-        let now = Utc::now().date_naive();
+        let timezone = self.tz();
+        let now = self.today();
 
         let upper_bound = self.date_to.unwrap_or(now);
         let lower_bound = self.date_from.unwrap_or_else(|| {
@@ -54,8 +75,9 @@ impl AnalyticsEnvelope {
         let sort_order = SortOrder::from_option(self.sort_order.as_deref());
 
         ResolvedAnalyticsEnvelope {
-            date_from: start_of_day(lower_bound),
-            date_to: end_of_day(upper_bound),
+            date_from: start_of_day(lower_bound, timezone),
+            date_to: end_of_day(upper_bound, timezone),
+            timezone,
             page,
             per_page,
             sort_by: self
@@ -103,6 +125,17 @@ impl Validate for AnalyticsEnvelope {
             }
         }
 
+        if let Some(timezone) = &self.timezone {
+            if parse_timezone(timezone).is_none() {
+                errors.add(
+                    "timezone",
+                    ValidationError::new("timezone").with_message(
+                        "timezone must be an IANA name such as 'Europe/Berlin'".into(),
+                    ),
+                );
+            }
+        }
+
         if let (Some(from), Some(to)) = (self.date_from, self.date_to) {
             if from > to {
                 errors.add(
@@ -125,6 +158,7 @@ impl Validate for AnalyticsEnvelope {
 pub struct ResolvedAnalyticsEnvelope {
     pub date_from: DateTimeWithTimeZone,
     pub date_to: DateTimeWithTimeZone,
+    pub timezone: Tz,
     pub page: u64,
     pub per_page: u64,
     pub sort_by: Option<String>,
@@ -142,6 +176,19 @@ impl ResolvedAnalyticsEnvelope {
             Bound::Included(self.date_to),
         )
     }
+
+    /// A `timestamptz` column as wall-clock time in the requested timezone,
+    /// for [`AnalyticsInterval::to_bucket_expr`] and `date_trunc`.
+    pub fn local(&self, column: &str) -> String {
+        // Tz names are drawn from the tz database and never contain quotes.
+        format!("({column} AT TIME ZONE '{}')", self.timezone.name())
+    }
+
+    /// Whether local days coincide with the UTC days the daily rollups are
+    /// keyed by.
+    pub fn uses_utc_days(&self) -> bool {
+        matches!(self.timezone, Tz::UTC | Tz::Etc__UTC)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -580,6 +627,340 @@ pub struct DashboardSummaryData {
     pub posts: DashboardSummaryPosts,
     pub engagement: DashboardSummaryEngagement,
     pub media: DashboardSummaryMedia,
+    pub revenue: DashboardSummaryRevenue,
+}
+
+pub const MAX_EVENT_PATH_LEN: u64 = 512;
+pub const MAX_EVENT_URL_LEN: u64 = 2048;
+/// Longest active-reading span one heartbeat may report. The consumer sends
+/// one every 15 seconds; anything far above that is a stale or forged event.
+pub const MAX_HEARTBEAT_MS: i32 = 60_000;
+/// Scroll depth (percent) at which a reader counts as having finished a post.
+pub const COMPLETION_SCROLL_DEPTH: i16 = 90;
+
+fn validate_event_path(path: &str) -> Result<(), ValidationError> {
+    if path.starts_with('/') && !path.chars().any(char::is_control) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("path")
+            .with_message("path must be a site-relative path starting with '/'".into()))
+    }
+}
+
+/// Event posted by the consumer site. Only the path and the raw referrer and
+/// query string are sent; everything else is derived server-side.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CollectEventPayload {
+    #[serde(default)]
+    pub kind: AnalyticsEventKind,
+    #[validate(
+        length(min = 1, max = MAX_EVENT_PATH_LEN),
+        custom(function = "validate_event_path")
+    )]
+    pub path: String,
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub post_id: Option<i32>,
+    /// `document.referrer` as seen by the browser.
+    #[serde(default)]
+    #[validate(length(max = MAX_EVENT_URL_LEN))]
+    pub referrer: Option<String>,
+    /// `location.search` of the landing page; only `utm_*` keys are kept.
+    #[serde(default)]
+    #[validate(length(max = MAX_EVENT_URL_LEN))]
+    pub query: Option<String>,
+    /// Engagement events: deepest scroll position so far, in percent.
+    #[serde(default)]
+    #[validate(range(min = 0, max = 100))]
+    pub scroll_depth: Option<i16>,
+    /// Engagement events: active reading time since the previous heartbeat.
+    #[serde(default)]
+    #[validate(range(min = 0, max = MAX_HEARTBEAT_MS))]
+    pub engaged_ms: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct TrafficFilters {
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub post_id: Option<i32>,
+    #[serde(default)]
+    #[validate(length(min = 1, max = MAX_EVENT_PATH_LEN))]
+    pub path: Option<String>,
+}
+
+/// Shared request for the `/traffic/*` breakdowns. `sort_by` accepts
+/// `visitors` (default) or `pageviews`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    #[serde(default)]
+    pub filters: TrafficFilters,
+}
+
+impl Validate for TrafficRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.envelope.validate()?;
+        self.filters.validate()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReferrerPoint {
+    /// `None` groups direct traffic and self-referrals.
+    pub referrer_host: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignPoint {
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CountryPoint {
+    /// `None` when the IP was not in the GeoLite2 database (or none is loaded).
+    pub country_code: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DevicePoint {
+    pub device_class: DeviceClass,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct PostEngagementFilters {
+    #[validate(range(min = 1))]
+    pub post_id: i32,
+    #[serde(default)]
+    pub group_by: AnalyticsInterval,
+}
+
+/// Engagement summary and traffic over time for one post.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostEngagementRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: PostEngagementFilters,
+}
+
+impl Validate for PostEngagementRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.envelope.validate()?;
+        self.filters.validate()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct AuthorEngagementFilters {
+    #[validate(range(min = 1))]
+    pub author_id: i32,
+}
+
+/// Per-post engagement for every post by one author. `sort_by` accepts
+/// `views` (default), `visitors`, `avg_read_time`, `completion_rate`,
+/// `likes_per_view` or `comments_per_view`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorEngagementRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: AuthorEngagementFilters,
+}
+
+impl Validate for AuthorEngagementRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.envelope.validate()?;
+        self.filters.validate()
+    }
+}
+
+/// Engagement metrics for one post over the requested window. `views` comes
+/// from `post_views` (the counter behind `view_count`); reading metrics come
+/// from first-party events, per daily visitor.
+#[derive(Debug, Clone, Serialize)]
+pub struct PostEngagementPoint {
+    pub post_id: i32,
+    pub title: String,
+    pub views: i64,
+    pub visitors: i64,
+    /// Mean active reading time of visitors who sent any heartbeat.
+    pub avg_read_time_secs: f64,
+    /// Share of visitors (0-1) who scrolled past `COMPLETION_SCROLL_DEPTH`.
+    pub completion_rate: f64,
+    pub likes: i64,
+    pub comments: i64,
+    pub likes_per_view: f64,
+    pub comments_per_view: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PostTrafficPoint {
+    pub bucket: String,
+    pub pageviews: i64,
+    pub visitors: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PostEngagementData {
+    pub summary: PostEngagementPoint,
+    pub traffic: Vec<PostTrafficPoint>,
+}
+
+pub const MAX_PROVIDER_LEN: u64 = 32;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct RevenueTrendFilters {
+    #[serde(default)]
+    pub group_by: AnalyticsInterval,
+    /// Restrict to one billing provider (e.g. `stripe`).
+    #[serde(default)]
+    #[validate(length(min = 1, max = MAX_PROVIDER_LEN))]
+    pub provider: Option<String>,
+}
+
+/// Shared request for the bucketed `/revenue/*` trends (MRR, subscriber flow,
+/// trial conversion).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueTrendRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    #[serde(default)]
+    pub filters: RevenueTrendFilters,
+}
+
+impl Validate for RevenueTrendRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.envelope.validate()?;
+        self.filters.validate()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct RevenueBreakdownFilters {
+    #[serde(default)]
+    #[validate(length(min = 1, max = MAX_PROVIDER_LEN))]
+    pub provider: Option<String>,
+}
+
+/// Shared request for the per-post and per-provider revenue breakdowns.
+/// `sort_by` accepts `revenue` (default) or the breakdown's count column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueBreakdownRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    #[serde(default)]
+    pub filters: RevenueBreakdownFilters,
+}
+
+impl Validate for RevenueBreakdownRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.envelope.validate()?;
+        self.filters.validate()
+    }
+}
+
+/// Recurring revenue at the end of each bucket, in minor units of the
+/// reporting currency. Yearly plans count as a twelfth of their price.
+#[derive(Debug, Clone, Serialize)]
+pub struct MrrPoint {
+    pub bucket: String,
+    pub mrr_cents: i64,
+    pub arr_cents: i64,
+    pub paying_subscriptions: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscriberFlowPoint {
+    pub bucket: String,
+    pub new_subscriptions: i64,
+    pub churned: i64,
+    pub net_change: i64,
+    /// Subscriptions live at the start of the bucket.
+    pub active_at_start: i64,
+    /// `churned / active_at_start`, 0 when nothing was live.
+    pub churn_rate: f64,
+}
+
+/// Trials grouped by the bucket they started in.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrialConversionPoint {
+    pub bucket: String,
+    pub trials_started: i64,
+    /// Trials followed by a completed payment on the subscription.
+    pub converted: i64,
+    /// Trials still running without a payment yet.
+    pub in_trial: i64,
+    /// `converted` over trials that have ended.
+    pub conversion_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PostRevenuePoint {
+    pub post_id: i32,
+    pub title: String,
+    pub purchases: i64,
+    pub refunded: i64,
+    /// Net of refunds, in the reporting currency.
+    pub revenue_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderRevenuePoint {
+    pub provider: String,
+    pub payments: i64,
+    pub failed_payments: i64,
+    pub revenue_cents: i64,
+    pub refunded_cents: i64,
+    pub post_purchases: i64,
+    pub active_subscriptions: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardSummaryRevenue {
+    /// Reporting currency every `*_cents` value is converted into.
+    pub currency: String,
+    pub mrr_cents: i64,
+    pub arr_cents: i64,
+    pub paying_subscriptions: i64,
+    pub trialing: i64,
+    pub new_subscriptions: i64,
+    pub churned: i64,
+    pub churn_rate: f64,
+    /// Completed payments in the period; refunded payments are reported in
+    /// `refunded_cents` instead.
+    pub revenue_in_period_cents: i64,
+    pub refunded_cents: i64,
+    /// MRR per paying subscription.
+    pub arpu_cents: i64,
+    /// `arpu / monthly churn`; `None` until there is churn to estimate from.
+    pub ltv_cents: Option<i64>,
+    /// Completed payments in a currency with no configured rate, left out of
+    /// the converted totals.
+    pub unconverted_payments: i64,
+}
+
+/// Longest stretch one `/rollups/run` call may fold.
+pub const MAX_ROLLUP_DAYS: u64 = 366;
+
+/// On-demand rollup pass. An empty body continues after the watermark like
+/// the background job; `rebuild_from` recomputes days already rolled up.
+/// Raw-view retention only runs in the background job.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct RollupRunPayload {
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub rebuild_from: Option<NaiveDate>,
+    #[validate(range(min = 1, max = MAX_ROLLUP_DAYS))]
+    pub max_days: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -624,6 +1005,11 @@ impl AnalyticsMeta {
         self.filters_applied = Some(filters);
         self
     }
+
+    pub fn with_notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -632,20 +1018,26 @@ pub struct AnalyticsEnvelopeResponse<T> {
     pub meta: AnalyticsMeta,
 }
 
-fn start_of_day(date: NaiveDate) -> DateTimeWithTimeZone {
-    let offset = FixedOffset::east_opt(0).expect("UTC offset available");
-    offset
-        .with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0)
-        .single()
-        .expect("valid start-of-day datetime")
+fn parse_timezone(name: &str) -> Option<Tz> {
+    name.trim().parse().ok()
+}
+
+/// The first instant of `date` in `timezone`. Where midnight falls in a DST
+/// gap the day starts at the first valid local time after it.
+fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTimeWithTimeZone {
+    let mut local = date.and_time(NaiveTime::MIN);
+    loop {
+        match timezone.from_local_datetime(&local) {
+            LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => return at.fixed_offset(),
+            LocalResult::None => local += Duration::minutes(15),
+        }
+    }
 }
 
-fn end_of_day(date: NaiveDate) -> DateTimeWithTimeZone {
-    let offset = FixedOffset::east_opt(0).expect("UTC offset available");
-    offset
-        .with_ymd_and_hms(date.year(), date.month(), date.day(), 23, 59, 59)
-        .single()
-        .expect("valid end-of-day datetime")
+/// The last second of `date` in `timezone`.
+fn end_of_day(date: NaiveDate, timezone: Tz) -> DateTimeWithTimeZone {
+    let next = date.succ_opt().unwrap_or(date);
+    start_of_day(next, timezone) - Duration::seconds(1)
 }
 
 fn deserialize_optional_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
@@ -685,6 +1077,7 @@ mod tests {
             per_page: Some(50),
             sort_by: Some("created_at".into()),
             sort_order: Some("asc".into()),
+            timezone: None,
         };
         assert!(env.validate().is_ok());
     }
@@ -698,6 +1091,7 @@ mod tests {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: None,
         };
         assert!(env.validate().is_ok());
     }
@@ -825,6 +1219,7 @@ mod tests {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: None,
         };
         let resolved = env.resolve();
 
@@ -882,6 +1277,7 @@ mod tests {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: None,
         };
         let resolved = env.resolve();
         assert_eq!(resolved.date_from.date_naive(), from);
@@ -898,6 +1294,7 @@ mod tests {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: None,
         };
         let resolved = env.resolve();
         let expected_from = to - Duration::days(30);
@@ -915,6 +1312,7 @@ mod tests {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: None,
         };
         let resolved = env.resolve();
 
@@ -929,6 +1327,66 @@ mod tests {
         assert_eq!(resolved.date_to.time().second(), 59);
     }
 
+    #[test]
+    fn resolve_uses_local_day_bounds_for_timezone() {
+        let d = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let env = AnalyticsEnvelope {
+            date_from: Some(d),
+            date_to: Some(d),
+            timezone: Some("America/Los_Angeles".into()),
+            ..Default::default()
+        };
+        let resolved = env.resolve();
+
+        assert_eq!(resolved.timezone, Tz::America__Los_Angeles);
+        assert!(!resolved.uses_utc_days());
+        assert_eq!(resolved.date_from.to_rfc3339(), "2025-01-15T00:00:00-08:00");
+        assert_eq!(resolved.date_to.to_rfc3339(), "2025-01-15T23:59:59-08:00");
+        assert_eq!(
+            resolved.local("created_at"),
+            "(created_at AT TIME ZONE 'America/Los_Angeles')"
+        );
+    }
+
+    #[test]
+    fn resolve_handles_dst_transition_days() {
+        // Spring forward: 2025-03-09 is 23 hours long in Los Angeles.
+        let d = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let env = AnalyticsEnvelope {
+            date_from: Some(d),
+            date_to: Some(d),
+            timezone: Some("America/Los_Angeles".into()),
+            ..Default::default()
+        };
+        let resolved = env.resolve();
+        assert_eq!(resolved.date_from.to_rfc3339(), "2025-03-09T00:00:00-08:00");
+        assert_eq!(resolved.date_to.to_rfc3339(), "2025-03-09T23:59:59-07:00");
+
+        // Midnight does not exist on 2025-09-07 in Santiago; the day starts at 01:00.
+        let d = NaiveDate::from_ymd_opt(2025, 9, 7).unwrap();
+        assert_eq!(
+            start_of_day(d, Tz::America__Santiago).to_rfc3339(),
+            "2025-09-07T01:00:00-03:00"
+        );
+    }
+
+    #[test]
+    fn unknown_timezone_fails_validation() {
+        let env = AnalyticsEnvelope {
+            timezone: Some("Mars/Olympus_Mons".into()),
+            ..Default::default()
+        };
+        let err = env.validate().unwrap_err();
+        assert!(err.field_errors().contains_key("timezone"));
+
+        let env = AnalyticsEnvelope {
+            timezone: Some("Asia/Kolkata".into()),
+            ..Default::default()
+        };
+        assert!(env.validate().is_ok());
+        assert!(AnalyticsEnvelope::default().resolve().uses_utc_days());
+    }
+
     #[test]
     fn resolve_normalizes_sort_by() {
         let env = AnalyticsEnvelope {
@@ -1266,6 +1724,7 @@ mod tests {
                 per_page: Some(10),
                 sort_by: None,
                 sort_order: None,
+                timezone: None,
             },
             filters: Default::default(),
         };
@@ -1335,6 +1794,149 @@ mod tests {
         assert_eq!(date, NaiveDate::from_ymd_opt(2025, 6, 15).unwrap());
     }
 
+    // ── Traffic ──────────────────────────────────────────────────────────
+
+    fn collect_payload(path: &str) -> CollectEventPayload {
+        serde_json::from_value(serde_json::json!({ "path": path })).unwrap()
+    }
+
+    #[test]
+    fn collect_payload_defaults_to_pageview() {
+        let payload = collect_payload("/blog/hello");
+        assert_eq!(payload.kind, AnalyticsEventKind::Pageview);
+        assert!(payload.post_id.is_none());
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn collect_payload_requires_site_relative_path() {
+        assert!(collect_payload("https://evil.test/").validate().is_err());
+        assert!(collect_payload("blog").validate().is_err());
+        assert!(collect_payload("").validate().is_err());
+        assert!(collect_payload("/a\nb").validate().is_err());
+        let long = format!("/{}", "a".repeat(MAX_EVENT_PATH_LEN as usize));
+        assert!(collect_payload(&long).validate().is_err());
+    }
+
+    #[test]
+    fn collect_payload_rejects_oversized_referrer() {
+        let mut payload = collect_payload("/");
+        payload.referrer = Some(format!("https://a.test/{}", "x".repeat(2048)));
+        assert!(payload.validate().is_err());
+    }
+
+    #[test]
+    fn traffic_request_validates_filters_and_envelope() {
+        let request: TrafficRequest = serde_json::from_value(serde_json::json!({
+            "per_page": 10,
+            "sort_by": "pageviews",
+            "filters": { "post_id": 3, "path": "/blog/hello" }
+        }))
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let request: TrafficRequest =
+            serde_json::from_value(serde_json::json!({ "filters": { "post_id": 0 } })).unwrap();
+        assert!(request.validate().is_err());
+
+        let request: TrafficRequest =
+            serde_json::from_value(serde_json::json!({ "page": 0 })).unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn collect_payload_bounds_engagement_fields() {
+        let mut payload = collect_payload("/posts/hello");
+        payload.kind = AnalyticsEventKind::Engagement;
+        payload.scroll_depth = Some(100);
+        payload.engaged_ms = Some(MAX_HEARTBEAT_MS);
+        assert!(payload.validate().is_ok());
+
+        payload.scroll_depth = Some(101);
+        assert!(payload.validate().is_err());
+
+        payload.scroll_depth = Some(50);
+        payload.engaged_ms = Some(MAX_HEARTBEAT_MS + 1);
+        assert!(payload.validate().is_err());
+
+        payload.engaged_ms = Some(-1);
+        assert!(payload.validate().is_err());
+    }
+
+    #[test]
+    fn post_engagement_request_requires_post_id() {
+        let missing: Result<PostEngagementRequest, _> =
+            serde_json::from_value(serde_json::json!({ "filters": {} }));
+        assert!(missing.is_err());
+
+        let request: PostEngagementRequest = serde_json::from_value(serde_json::json!({
+            "filters": { "post_id": 7, "group_by": "week" }
+        }))
+        .unwrap();
+        assert_eq!(request.filters.group_by, AnalyticsInterval::Week);
+        assert!(request.validate().is_ok());
+
+        let request: PostEngagementRequest =
+            serde_json::from_value(serde_json::json!({ "filters": { "post_id": 0 } })).unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn author_engagement_request_validates_author_id() {
+        let request: AuthorEngagementRequest =
+            serde_json::from_value(serde_json::json!({ "filters": { "author_id": 3 } })).unwrap();
+        assert!(request.validate().is_ok());
+
+        let request: AuthorEngagementRequest =
+            serde_json::from_value(serde_json::json!({ "filters": { "author_id": -1 } })).unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn revenue_trend_request_defaults_and_bounds_provider() {
+        let request: RevenueTrendRequest = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(request.validate().is_ok());
+        assert_eq!(request.filters.group_by, AnalyticsInterval::Day);
+        assert!(request.filters.provider.is_none());
+
+        let request: RevenueTrendRequest = serde_json::from_value(serde_json::json!({
+            "filters": { "group_by": "month", "provider": "stripe" }
+        }))
+        .unwrap();
+        assert!(request.validate().is_ok());
+        assert_eq!(request.filters.group_by, AnalyticsInterval::Month);
+
+        let request: RevenueTrendRequest = serde_json::from_value(serde_json::json!({
+            "filters": { "provider": "" }
+        }))
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn revenue_breakdown_request_rejects_long_provider() {
+        let request: RevenueBreakdownRequest = serde_json::from_value(serde_json::json!({
+            "filters": { "provider": "p".repeat(MAX_PROVIDER_LEN as usize + 1) }
+        }))
+        .unwrap();
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn rollup_run_payload_accepts_empty_body_and_bounds_max_days() {
+        let empty: RollupRunPayload = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(empty.rebuild_from.is_none());
+        assert!(empty.validate().is_ok());
+
+        let rebuild: RollupRunPayload =
+            serde_json::from_value(serde_json::json!({ "rebuild_from": "2024-03-01" })).unwrap();
+        assert_eq!(rebuild.rebuild_from, NaiveDate::from_ymd_opt(2024, 3, 1));
+
+        let too_long: RollupRunPayload =
+            serde_json::from_value(serde_json::json!({ "max_days": MAX_ROLLUP_DAYS + 1 })).unwrap();
+        assert!(too_long.validate().is_err());
+    }
+
     // ── Constants ────────────────────────────────────────────────────────
 
     #[test]
@@ -23,7 +23,13 @@ use crate::{
         V1LoginPayload, V1LoginTotpPayload, V1RegisterPayload, V1TwoFADisablePayload,
         V1TwoFAVerifyPayload,
     },
-    services::{abuse_limiter, auth::AuthSession, mail::send_email_verification_code},
+    services::{
+        abuse_limiter,
+        auth::AuthSession,
+        blocklist::Blocklist,
+        mail::send_email_verification_code,
+        notifications::{self, SiteEvent},
+    },
     utils::twofa,
     AppState,
 };
@@ -415,6 +421,21 @@ pub async fn register(
 
     info!("User registration attempt");
 
+    let blocklist = Blocklist::load(&state.sea_db).await?;
+    if let Some(found) = blocklist.check_email(&payload.email) {
+        warn!(pattern = %found.pattern, "Registration blocked by email domain");
+        tracing::Span::current().record("result", "blocked");
+        return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("Registrations from this email domain are not allowed"));
+    }
+    if let Some(found) = blocklist.check_name(&payload.name) {
+        warn!(pattern = %found.pattern, "Registration blocked by name");
+        tracing::Span::current().record("result", "blocked");
+        return Err(
+            ErrorResponse::new(ErrorCode::InvalidInput).with_message("This name is not allowed")
+        );
+    }
+
     let email = payload.email.clone();
 
     // Generate the verification code now: store only its keyed hash alongside
@@ -449,6 +470,8 @@ pub async fn register(
                 }
             });
 
+            notifications::dispatch(&state, SiteEvent::UserRegistered { user_id: user.id });
+
             Ok((StatusCode::CREATED, Json(json!(user))))
         }
         Err(err) => {
@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use axum_macros::debug_handler;
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter};
+use serde_json::json;
+use tracing::instrument;
+
+use crate::{
+    db::sea_models::{
+        post::{self, PostStatus},
+        user::{self, PublicAuthorProfile},
+    },
+    error::{ErrorCode, ErrorResponse},
+    extractors::ValidatedQuery,
+    modules::post_v1::controller::{apply_paywall_list, attach_image_details},
+    services::auth::AuthSession,
+    AppState,
+};
+
+use super::validator::V1AuthorPostsQuery;
+
+/// Public author page: profile plus a page of the posts they own or are
+/// credited on. Only users with at least one published post have a page.
+#[debug_handler]
+#[instrument(skip(state, auth, query))]
+pub async fn view(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(slug): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<V1AuthorPostsQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let not_found =
+        || ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Author not found");
+    let public_url = &state.object_storage.public_url;
+
+    let author = user::Entity::find_by_profile_slug(&state.sea_db, public_url, &slug)
+        .await?
+        .ok_or_else(not_found)?;
+    let published = post::Entity::find()
+        .filter(post::Column::Status.eq(PostStatus::Published))
+        .filter(post::Entity::credited_to(author.id))
+        .count(&state.sea_db)
+        .await?;
+    if published == 0 {
+        return Err(not_found());
+    }
+
+    let page = query.page.unwrap_or(1);
+    let (mut posts, total) = post::Entity::find_published_paginated(
+        &state.sea_db,
+        public_url,
+        query.into_post_query(author.id),
+    )
+    .await?;
+    apply_paywall_list(&state, &mut posts, auth.user.as_ref()).await?;
+    attach_image_details(&state, &mut posts, false).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "author": PublicAuthorProfile::from(author),
+            "data": posts,
+            "total": total,
+            "per_page": post::Entity::PER_PAGE,
+            "page": page,
+        })),
+    ))
+}
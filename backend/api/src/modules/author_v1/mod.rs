@@ -0,0 +1,10 @@
+pub mod controller;
+pub mod validator;
+
+use axum::{routing::get, Router};
+
+use crate::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::<AppState>::new().route("/{slug}", get(controller::view))
+}
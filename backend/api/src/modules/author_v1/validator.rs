@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::db::sea_models::post::{PostQuery, PostStatus};
+use crate::utils::locale::{normalize_locale, validate_locale};
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1AuthorPostsQuery {
+    pub page: Option<u64>,
+    #[validate(custom(function = "validate_locale"))]
+    pub locale: Option<String>,
+}
+
+impl V1AuthorPostsQuery {
+    pub fn into_post_query(self, author_id: i32) -> PostQuery {
+        PostQuery {
+            // Same clamp as the public post listing.
+            page_no: self.page.map(|p| p.clamp(1, 500)),
+            title: None,
+            status: Some(PostStatus::Published),
+            author_id: Some(author_id),
+            sorts: None,
+            category_id: None,
+            search: None,
+            tag_ids: None,
+            locale: self.locale.as_deref().and_then(normalize_locale),
+            created_at_gt: None,
+            created_at_lt: None,
+            updated_at_gt: None,
+            updated_at_lt: None,
+            published_at_gt: None,
+            published_at_lt: None,
+        }
+    }
+}
@@ -27,7 +27,9 @@ use crate::db::sea_models::subscription;
 use crate::error::codes::ErrorCode;
 use crate::error::response::ErrorResponse;
 use crate::services::auth::AuthSession;
+use crate::services::notifications::{self, SiteEvent};
 use crate::services::paywall;
+use crate::services::sitemap;
 use crate::AppState;
 
 #[cfg(feature = "billing")]
@@ -324,10 +326,13 @@ pub async fn admin_cancel_subscription(
     }
 
     // Update status in DB
+    let now = chrono::Utc::now().fixed_offset();
+    let canceled_at = sub.canceled_at.unwrap_or(now);
     let mut active: subscription::ActiveModel = sub.into();
     active.status = Set(subscription::model::SubscriptionStatus::Canceled);
     active.cancel_at_period_end = Set(false);
-    active.updated_at = Set(chrono::Utc::now().fixed_offset());
+    active.canceled_at = Set(Some(canceled_at));
+    active.updated_at = Set(now);
     active
         .update(&state.sea_db)
         .await
@@ -860,7 +865,7 @@ async fn process_webhook_event(
                     payment_id: Set(None),
                     provider: Set(provider_name.to_string()),
                     amount_cents: Set(amount_cents),
-                    currency: Set(currency),
+                    currency: Set(currency.clone()),
                     ..Default::default()
                 };
                 match active_model.insert(&state.sea_db).await {
@@ -870,6 +875,14 @@ async fn process_webhook_event(
                             post_id,
                             "Post purchase granted from verified webhook"
                         );
+                        notifications::dispatch(
+                            state,
+                            SiteEvent::PaymentReceived {
+                                user_id,
+                                amount_cents,
+                                currency,
+                            },
+                        );
                     }
                     Err(e) => {
                         let s = e.to_string();
@@ -1001,6 +1014,9 @@ async fn process_webhook_event(
                     // into an ActiveModel, so the forward-only guard below can
                     // compare against it (V-MED-2).
                     let existing_period_end = existing.current_period_end;
+                    let existing_canceled_at = existing.canceled_at;
+                    let existing_status = existing.status;
+                    let existing_user_id = existing.user_id;
                     let mut active: subscription::ActiveModel = existing.into();
 
                     // Status from the provider-normalized canonical value (audit
@@ -1043,6 +1059,15 @@ async fn process_webhook_event(
                     };
                     if status_changed {
                         active.status = Set(new_status);
+                        // Date the churn once; a reactivation clears it.
+                        active.canceled_at = Set(match new_status {
+                            subscription::model::SubscriptionStatus::Canceled
+                            | subscription::model::SubscriptionStatus::Expired => Some(
+                                existing_canceled_at
+                                    .unwrap_or_else(|| chrono::Utc::now().fixed_offset()),
+                            ),
+                            _ => None,
+                        });
                     }
                     // Refresh the period end from the verified webhook (audit
                     // F#11): renewals send a fresh `current_period_end`, and the
@@ -1072,6 +1097,18 @@ async fn process_webhook_event(
                         .await
                         .map_err(|_| ErrorResponse::new(ErrorCode::QueryError))?;
 
+                    if status_changed
+                        && new_status == subscription::model::SubscriptionStatus::Canceled
+                        && existing_status != subscription::model::SubscriptionStatus::Canceled
+                    {
+                        notifications::dispatch(
+                            state,
+                            SiteEvent::SubscriptionCanceled {
+                                user_id: existing_user_id,
+                            },
+                        );
+                    }
+
                     tracing::info!(
                         subscription_id = %provider_sub_id,
                         status_changed,
@@ -1132,7 +1169,7 @@ async fn process_webhook_event(
                 provider: Set(provider_name.to_string()),
                 provider_payment_id: Set(event.payment_id.clone()),
                 amount_cents: Set(amount),
-                currency: Set(currency),
+                currency: Set(currency.clone()),
                 status: Set(payment::model::PaymentStatus::Completed),
                 description: Set(Some(format!("Invoice payment: {}", event.event_type))),
                 metadata: Set(Some(event.data.clone())),
@@ -1189,6 +1226,17 @@ async fn process_webhook_event(
                 }
             }
 
+            if user_id != 0 {
+                notifications::dispatch(
+                    state,
+                    SiteEvent::PaymentReceived {
+                        user_id,
+                        amount_cents: amount,
+                        currency,
+                    },
+                );
+            }
+
             tracing::info!(user_id, amount, "Payment recorded from invoice webhook");
         }
         "payment.confirmed" | "payment.pending" => {
@@ -1324,6 +1372,17 @@ async fn process_webhook_event(
                 status = %event.event_type,
                 "Payment recorded from webhook (server-bound intent)"
             );
+
+            if status == payment::model::PaymentStatus::Completed {
+                notifications::dispatch(
+                    state,
+                    SiteEvent::PaymentReceived {
+                        user_id,
+                        amount_cents,
+                        currency,
+                    },
+                );
+            }
         }
         _ => {
             tracing::info!(event_type = %event.event_type, "Unhandled billing webhook event");
@@ -1387,6 +1446,9 @@ pub async fn admin_set_post_access(
         .await
         .map_err(|_| ErrorResponse::new(ErrorCode::QueryError))?;
 
+    // A post moving behind or out from the paywall joins or leaves the sitemap.
+    sitemap::invalidate(&state.redis_pool).await;
+
     Ok(Json(json!({ "message": "Post access updated" })))
 }
 
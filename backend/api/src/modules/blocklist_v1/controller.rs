@@ -0,0 +1,142 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use axum_macros::debug_handler;
+use sea_orm::EntityTrait;
+use serde_json::json;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    db::sea_models::blocklist_entry::Entity as BlocklistEntry,
+    error::{ErrorCode, ErrorResponse},
+    extractors::ValidatedJson,
+    services::auth::AuthSession,
+    AppState,
+};
+
+use super::validator::{
+    check_pattern, V1BlocklistQueryParams, V1CreateBlocklistEntryPayload,
+    V1UpdateBlocklistEntryPayload,
+};
+
+/// Add a blocklist entry
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(entry_id))]
+pub async fn create(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1CreateBlocklistEntryPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let new_entry = payload.0.into_new_entry(user.id)?;
+
+    match BlocklistEntry::create(&state.sea_db, new_entry).await {
+        Ok(result) => {
+            tracing::Span::current().record("entry_id", result.id);
+            info!(entry_id = result.id, kind = ?result.kind, "Blocklist entry created");
+            Ok((StatusCode::CREATED, Json(json!(result))))
+        }
+        Err(err) => {
+            error!("Failed to create blocklist entry: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Update a blocklist entry. A new pattern is checked against the entry's
+/// existing kind.
+#[debug_handler]
+#[instrument(skip(state, _auth, payload), fields(entry_id))]
+pub async fn update(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(entry_id): Path<i32>,
+    payload: ValidatedJson<V1UpdateBlocklistEntryPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let existing = BlocklistEntry::find_by_id(entry_id)
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Blocklist entry does not exist")
+        })?;
+
+    let mut update = payload.0.into_update_entry();
+    if let Some(pattern) = update.pattern.as_deref() {
+        update.pattern = Some(check_pattern(existing.kind, pattern)?);
+    }
+
+    match BlocklistEntry::update(&state.sea_db, entry_id, update).await {
+        Ok(Some(entry)) => {
+            info!(entry_id, "Blocklist entry updated");
+            Ok((StatusCode::OK, Json(json!(entry))))
+        }
+        Ok(None) => {
+            warn!(entry_id, "Blocklist entry not found for update");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Blocklist entry does not exist"))
+        }
+        Err(err) => {
+            error!(entry_id, "Failed to update blocklist entry: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Delete a blocklist entry
+#[debug_handler]
+#[instrument(skip(state, _auth), fields(entry_id))]
+pub async fn delete(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(entry_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match BlocklistEntry::delete(&state.sea_db, entry_id).await {
+        Ok(0) => {
+            warn!(entry_id, "Blocklist entry not found for delete");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Blocklist entry does not exist"))
+        }
+        Ok(_) => {
+            info!(entry_id, "Blocklist entry deleted");
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Blocklist entry deleted successfully" })),
+            ))
+        }
+        Err(err) => {
+            error!(entry_id, "Failed to delete blocklist entry: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// List blocklist entries with filters and paging
+#[debug_handler]
+#[instrument(skip(state, payload))]
+pub async fn find_with_query(
+    State(state): State<AppState>,
+    payload: ValidatedJson<V1BlocklistQueryParams>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let query = payload.0.into_query();
+    let page = query.page.unwrap_or(1);
+
+    match BlocklistEntry::find_with_query(&state.sea_db, query).await {
+        Ok((entries, total)) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "data": entries,
+                "total": total,
+                "per_page": BlocklistEntry::PER_PAGE,
+                "page": page,
+            })),
+        )),
+        Err(err) => {
+            error!("Failed to query blocklist entries: {}", err);
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub mod controller;
+pub mod validator;
+
+use axum::{middleware, routing::post, Router};
+
+use crate::{middlewares::auth_guard, AppState};
+
+pub fn routes() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route("/create", post(controller::create))
+        .route("/update/{entry_id}", post(controller::update))
+        .route("/delete/{entry_id}", post(controller::delete))
+        .route("/list/query", post(controller::find_with_query))
+        .route_layer(middleware::from_fn(
+            auth_guard::verified_with_role::<{ auth_guard::ROLE_ADMIN }>,
+        ))
+}
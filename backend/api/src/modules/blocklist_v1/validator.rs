@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::db::sea_models::blocklist_entry::{
+    BlocklistKind, BlocklistQuery, NewBlocklistEntry, UpdateBlocklistEntry,
+};
+use crate::error::{ErrorCode, ErrorResponse};
+use crate::services::blocklist::{compile_regex, normalize_domain};
+use crate::utils::SortParam;
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1CreateBlocklistEntryPayload {
+    pub kind: BlocklistKind,
+    #[validate(length(min = 1, max = 500))]
+    pub pattern: String,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+impl V1CreateBlocklistEntryPayload {
+    #[allow(clippy::result_large_err)]
+    pub fn into_new_entry(self, created_by: i32) -> Result<NewBlocklistEntry, ErrorResponse> {
+        Ok(NewBlocklistEntry {
+            pattern: check_pattern(self.kind, &self.pattern)?,
+            kind: self.kind,
+            note: self.note,
+            created_by: Some(created_by),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1UpdateBlocklistEntryPayload {
+    #[validate(length(min = 1, max = 500))]
+    pub pattern: Option<String>,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+impl V1UpdateBlocklistEntryPayload {
+    pub fn into_update_entry(self) -> UpdateBlocklistEntry {
+        UpdateBlocklistEntry {
+            pattern: self.pattern,
+            note: self.note,
+            updated_at: chrono::Utc::now().fixed_offset(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1BlocklistQueryParams {
+    pub page: Option<u64>,
+    pub search: Option<String>,
+    pub kind: Option<BlocklistKind>,
+    pub sorts: Option<Vec<SortParam>>,
+}
+
+impl V1BlocklistQueryParams {
+    pub fn into_query(self) -> BlocklistQuery {
+        BlocklistQuery {
+            page: self.page,
+            search: self.search,
+            kind: self.kind,
+            sorts: self.sorts,
+        }
+    }
+}
+
+fn invalid(message: &str) -> ErrorResponse {
+    ErrorResponse::new(ErrorCode::InvalidInput).with_message(message)
+}
+
+/// Normalize a pattern for its kind: words are trimmed and lowercased,
+/// regexes must compile within the size limit, and domains are reduced to a
+/// bare host.
+#[allow(clippy::result_large_err)]
+pub fn check_pattern(kind: BlocklistKind, pattern: &str) -> Result<String, ErrorResponse> {
+    match kind {
+        BlocklistKind::Word => {
+            let word = pattern.trim().to_lowercase();
+            if word.is_empty() {
+                return Err(invalid("pattern cannot be blank"));
+            }
+            Ok(word)
+        }
+        BlocklistKind::Regex => compile_regex(pattern)
+            .map(|_| pattern.to_string())
+            .map_err(|err| invalid(&format!("pattern is not a valid regex: {}", err))),
+        BlocklistKind::Domain | BlocklistKind::EmailDomain => normalize_domain(pattern)
+            .ok_or_else(|| invalid("pattern must be a domain such as spam.example")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_patterns_per_kind() {
+        assert_eq!(
+            check_pattern(BlocklistKind::Word, "  Casino ").unwrap(),
+            "casino"
+        );
+        assert_eq!(
+            check_pattern(BlocklistKind::Domain, "https://www.Spam.example/x").unwrap(),
+            "spam.example"
+        );
+        assert_eq!(
+            check_pattern(BlocklistKind::EmailDomain, "@mailinator.com").unwrap(),
+            "mailinator.com"
+        );
+        assert_eq!(
+            check_pattern(BlocklistKind::Regex, r"buy\s+followers").unwrap(),
+            r"buy\s+followers"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_patterns() {
+        assert!(check_pattern(BlocklistKind::Word, "   ").is_err());
+        assert!(check_pattern(BlocklistKind::Regex, "(unclosed").is_err());
+        assert!(check_pattern(BlocklistKind::Domain, "not a domain").is_err());
+    }
+}
@@ -5,14 +5,19 @@ use axum::{
     Json,
 };
 use axum_macros::debug_handler;
+use sea_orm::EntityTrait;
 use serde_json::json;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
-    db::sea_models::category::Entity as Category,
+    db::sea_models::{
+        category::Entity as Category,
+        redirect,
+        seo_override::{self, SeoEntityType},
+    },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
-    services::auth::AuthSession,
+    services::{auth::AuthSession, redirects, seo, sitemap},
     AppState,
 };
 
@@ -26,7 +31,10 @@ pub async fn create(
     _auth: AuthSession,
     payload: ValidatedJson<V1CreateCategoryPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let new_category = payload.0.into_new_category();
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let new_category = payload.into_new_category();
 
     match Category::create(
         &state.sea_db,
@@ -38,7 +46,11 @@ pub async fn create(
         Ok(result) => {
             tracing::Span::current().record("category_id", result.id);
             info!(category_id = result.id, "Category created");
-            Ok((StatusCode::CREATED, Json(json!(result))))
+            redirects::release(&state.sea_db, &redirect::category_path(&result.slug)).await;
+            seo::save(&state, SeoEntityType::Category, result.id, seo_fields).await?;
+            let body =
+                seo::attach(&state, SeoEntityType::Category, result.id, json!(result)).await?;
+            Ok((StatusCode::CREATED, Json(body)))
         }
         Err(err) => {
             error!("Failed to create category: {}", err);
@@ -56,7 +68,14 @@ pub async fn update(
     Path(category_id): Path<i32>,
     payload: ValidatedJson<V1UpdateCategoryPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let update_category = payload.0.into_update_category();
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let update_category = payload.into_update_category();
+    let previous_slug = Category::find_by_id(category_id)
+        .one(&state.sea_db)
+        .await?
+        .map(|c| c.slug);
 
     match Category::update(
         &state.sea_db,
@@ -68,7 +87,24 @@ pub async fn update(
     {
         Ok(Some(category)) => {
             info!(category_id, "Category updated");
-            Ok((StatusCode::OK, Json(json!(category))))
+            if let Some(old_slug) = previous_slug {
+                redirects::record_move(
+                    &state.sea_db,
+                    &redirect::category_path(&old_slug),
+                    &redirect::category_path(&category.slug),
+                )
+                .await;
+            }
+            seo::save(&state, SeoEntityType::Category, category_id, seo_fields).await?;
+            sitemap::invalidate(&state.redis_pool).await;
+            let body = seo::attach(
+                &state,
+                SeoEntityType::Category,
+                category_id,
+                json!(category),
+            )
+            .await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!(category_id, "Category not found for update");
@@ -90,14 +126,9 @@ pub async fn delete(
     _auth: AuthSession,
     Path(category_id): Path<i32>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
+    let existing = Category::find_by_id(category_id).one(&state.sea_db).await?;
+
     match Category::delete(&state.sea_db, category_id).await {
-        Ok(1) => {
-            info!(category_id, "Category deleted");
-            Ok((
-                StatusCode::OK,
-                Json(json!({ "message": "Category deleted successfully" })),
-            ))
-        }
         Ok(0) => {
             warn!(category_id, "Category not found for delete");
             Err(ErrorResponse::new(ErrorCode::RecordNotFound)
@@ -105,6 +136,13 @@ pub async fn delete(
         }
         Ok(_) => {
             info!(category_id, "Category deleted");
+            if let Some(category) = existing {
+                redirects::record_gone(&state.sea_db, &redirect::category_path(&category.slug))
+                    .await;
+            }
+            seo_override::Entity::delete_for(&state.sea_db, SeoEntityType::Category, category_id)
+                .await?;
+            sitemap::invalidate(&state.redis_pool).await;
             Ok((
                 StatusCode::OK,
                 Json(json!({ "message": "Category deleted successfully" })),
@@ -145,7 +183,14 @@ pub async fn find_by_id_or_slug(
                 category_id = category.id,
                 "Category retrieved by id or slug"
             );
-            Ok((StatusCode::OK, Json(json!(category))))
+            let body = seo::attach(
+                &state,
+                SeoEntityType::Category,
+                category.id,
+                json!(category),
+            )
+            .await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!("Category not found");
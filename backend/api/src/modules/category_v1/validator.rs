@@ -3,6 +3,7 @@ use validator::Validate;
 
 use crate::{
     db::sea_models::category::{CategoryQuery, NewCategory, UpdateCategory},
+    modules::seo_v1::validator::V1SeoPayload,
     utils::SortParam,
 };
 
@@ -22,6 +23,8 @@ pub struct V1CreateCategoryPayload {
     #[validate(custom(function = "validate_hex_color"), skip)]
     pub text_color: Option<String>,
     pub is_active: Option<bool>,
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1CreateCategoryPayload {
@@ -56,6 +59,9 @@ pub struct V1UpdateCategoryPayload {
     #[validate(custom(function = "validate_hex_color"), skip)]
     pub text_color: Option<String>,
     pub is_active: Option<bool>,
+    /// SEO overrides; omitted leaves them as they are.
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1UpdateCategoryPayload {
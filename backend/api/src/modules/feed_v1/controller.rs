@@ -0,0 +1,875 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_macros::debug_handler;
+use chrono::{DateTime, FixedOffset, Utc};
+use ruxlog_types::editorjs;
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select,
+};
+use sha2::{Digest, Sha256};
+
+use super::{
+    render::{self, Feed, FeedImage, FeedItem},
+    validator::{FeedFormat, FeedQuery},
+};
+use crate::{
+    db::sea_models::{
+        category, media,
+        post::{self, Column as PostColumn, Entity as PostEntity, PostStatus},
+        post_series, post_series_post, tag,
+        user::{self, PublicAuthorProfile},
+    },
+    error::{ErrorCode, ErrorResponse},
+    services::paywall::{load_post_access_map, PostAccessPolicy, PostAccessType},
+    utils::locale::{localized_path, normalize_locale},
+    AppState,
+};
+
+/// Which posts a feed covers. Scoped variants carry the row resolved from
+/// the path so its name can title the feed.
+enum FeedScope {
+    All,
+    Category(category::Model),
+    Tag(tag::Model),
+    Author(PublicAuthorProfile),
+    Series(post_series::Model),
+}
+
+impl FeedScope {
+    /// Path under `/feed/v1` without the format segment.
+    fn path(&self) -> String {
+        match self {
+            Self::All => String::new(),
+            Self::Category(c) => format!("/category/{}", c.slug),
+            Self::Tag(t) => format!("/tag/{}", t.slug),
+            Self::Author(a) => format!("/author/{}", a.slug),
+            Self::Series(s) => format!("/series/{}", s.slug),
+        }
+    }
+
+    /// Stable feed identity for Atom `<id>`. The global feed keeps the id it
+    /// has always published so existing subscriptions don't see a new feed.
+    fn key(&self) -> String {
+        match self {
+            Self::All => "feed:atom".to_string(),
+            Self::Category(c) => format!("feed:category:{}", c.slug),
+            Self::Tag(t) => format!("feed:tag:{}", t.slug),
+            Self::Author(a) => format!("feed:author:{}", a.id),
+            Self::Series(s) => format!("feed:series:{}", s.slug),
+        }
+    }
+
+    fn describe(&self, site_name: &str) -> (String, String) {
+        match self {
+            Self::All => (
+                site_name.to_string(),
+                format!("Latest posts from {}", site_name),
+            ),
+            Self::Category(c) => (
+                format!("{} — {}", site_name, c.name),
+                format!("Latest posts in {} from {}", c.name, site_name),
+            ),
+            Self::Tag(t) => (
+                format!("{} — #{}", site_name, t.name),
+                format!("Latest posts tagged {} from {}", t.name, site_name),
+            ),
+            Self::Author(a) => (
+                format!("{} — {}", site_name, a.name),
+                format!("Latest posts by {} on {}", a.name, site_name),
+            ),
+            Self::Series(s) => (
+                format!("{} — {}", site_name, s.name),
+                format!("Posts in the {} series on {}", s.name, site_name),
+            ),
+        }
+    }
+}
+
+/// Summary text shown for a gated post in the public feed. The feed is
+/// unauthenticated, so the body of a `Paid`/`SubscriberOnly` post must NEVER
+/// be derived from `content` — the previous fallback (`content_to_summary`)
+/// leaked up to 500 chars of the real body to anonymous feed readers
+/// (audit F#11 round-2, paywall bypass via the feed). Show only a policy
+/// hint; readers must visit the site to read, where the server-side paywall
+/// gates them properly.
+fn gated_summary(policy: &PostAccessPolicy) -> String {
+    match policy.access_type {
+        PostAccessType::SubscriberOnly => {
+            "This post is for subscribers only — visit the site to read it.".to_string()
+        }
+        PostAccessType::Paid => match policy.price_cents {
+            Some(price) => format!(
+                "This post is available for purchase ({} {}). Visit the site to read it.",
+                (price as f64) / 100.0,
+                policy.currency.as_deref().unwrap_or("USD")
+            ),
+            None => "This post is available for purchase — visit the site to read it.".to_string(),
+        },
+        // Unreachable for open posts (the caller only invokes this when the
+        // policy is gated), but kept exhaustive.
+        PostAccessType::Free => String::new(),
+    }
+}
+
+/// Short plain-text summary of Editor.js-style JSON, via the shared
+/// renderer. Content with no text falls back to the raw JSON, trimmed.
+fn content_to_summary(value: &serde_json::Value, max_len: usize) -> String {
+    let out = editorjs::summary(value, max_len);
+    if !out.is_empty() {
+        return out;
+    }
+    value.to_string().chars().take(max_len).collect()
+}
+
+/// Weak validator over the rendered body: any change to a post, its policy,
+/// or the feed's scope changes the document and therefore the tag.
+fn etag_for(body: &str) -> String {
+    let digest = Sha256::digest(body.as_bytes());
+    format!("W/\"{}\"", hex::encode(&digest[..16]))
+}
+
+fn http_date(at: DateTime<FixedOffset>) -> String {
+    at.with_timezone(&Utc)
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string()
+}
+
+/// RFC 9110 §13.1: `If-None-Match` decides when present (weak comparison);
+/// `If-Modified-Since` is only consulted without it. HTTP dates have
+/// second precision, so sub-second edits compare as unmodified.
+fn is_not_modified(
+    headers: &HeaderMap,
+    etag: &str,
+    last_modified: Option<DateTime<FixedOffset>>,
+) -> bool {
+    if let Some(value) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        let ours = etag.trim_start_matches("W/");
+        return value
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == ours);
+    }
+
+    let (Some(last_modified), Some(since)) = (
+        last_modified,
+        headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok()),
+    ) else {
+        return false;
+    };
+    last_modified.timestamp() <= since.timestamp()
+}
+
+#[allow(clippy::result_large_err)]
+fn build_feed_response(
+    format: FeedFormat,
+    body: String,
+    last_modified: Option<DateTime<FixedOffset>>,
+    headers: &HeaderMap,
+) -> Result<Response, ErrorResponse> {
+    let etag = etag_for(&body);
+    let not_modified = is_not_modified(headers, &etag, last_modified);
+
+    let mut builder = axum::http::Response::builder()
+        .status(if not_modified {
+            StatusCode::NOT_MODIFIED
+        } else {
+            StatusCode::OK
+        })
+        .header(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=300, s-maxage=300"),
+        )
+        .header(header::ETAG, etag);
+    if let Some(at) = last_modified {
+        builder = builder.header(header::LAST_MODIFIED, http_date(at));
+    }
+
+    let response = if not_modified {
+        builder.body(axum::body::Body::empty())
+    } else {
+        builder
+            .header(header::CONTENT_TYPE, format.content_type())
+            .body(axum::body::Body::from(body))
+    };
+
+    match response {
+        Ok(resp) => Ok(resp),
+        Err(_) => Err(ErrorResponse::new(ErrorCode::InternalServerError)),
+    }
+}
+
+fn scoped_query(
+    scope: &FeedScope,
+    series_post_ids: &[i32],
+    locale: Option<&str>,
+) -> Select<PostEntity> {
+    let mut query = PostEntity::find().filter(PostColumn::Status.eq(PostStatus::Published));
+    if let Some(locale) = locale {
+        query = query.filter(PostColumn::Locale.eq(locale));
+    }
+    match scope {
+        FeedScope::All => query,
+        FeedScope::Category(c) => query.filter(PostColumn::CategoryId.eq(c.id)),
+        FeedScope::Tag(t) => query.filter(Expr::cust(format!(
+            "posts.tag_ids && ARRAY[{}]::int[]",
+            t.id
+        ))),
+        FeedScope::Author(a) => query.filter(post::Entity::credited_to(a.id)),
+        FeedScope::Series(_) => query.filter(PostColumn::Id.is_in(series_post_ids.to_vec())),
+    }
+}
+
+async fn fetch_latest_posts(
+    state: &AppState,
+    scope: &FeedScope,
+    locale: Option<&str>,
+    limit: u64,
+) -> Result<Vec<post::Model>, ErrorResponse> {
+    let series_post_ids: Vec<i32> = match scope {
+        FeedScope::Series(s) => post_series_post::Entity::find()
+            .filter(post_series_post::Column::SeriesId.eq(s.id))
+            .all(&state.sea_db)
+            .await?
+            .into_iter()
+            .map(|row| row.post_id)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let posts = scoped_query(scope, &series_post_ids, locale)
+        .order_by_desc(PostColumn::PublishedAt)
+        .order_by_desc(PostColumn::UpdatedAt)
+        .limit(limit)
+        .all(&state.sea_db)
+        .await;
+
+    match posts {
+        Ok(list) => Ok(list),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Names and featured images for a page of posts, loaded in one query per
+/// table rather than per post.
+struct FeedLookups {
+    authors: HashMap<i32, String>,
+    categories: HashMap<i32, String>,
+    tags: HashMap<i32, String>,
+    images: HashMap<i32, FeedImage>,
+}
+
+async fn load_lookups(
+    state: &AppState,
+    posts: &[post::Model],
+) -> Result<FeedLookups, ErrorResponse> {
+    let author_ids: HashSet<i32> = posts.iter().map(|p| p.author_id).collect();
+    let category_ids: HashSet<i32> = posts.iter().map(|p| p.category_id).collect();
+    let tag_ids: HashSet<i32> = posts
+        .iter()
+        .flat_map(|p| p.tag_ids.iter().copied())
+        .collect();
+    let media_ids: HashSet<i32> = posts.iter().filter_map(|p| p.featured_image_id).collect();
+
+    let authors = user::Entity::find()
+        .filter(user::Column::Id.is_in(author_ids))
+        .all(&state.sea_db)
+        .await?
+        .into_iter()
+        .map(|u| (u.id, u.name))
+        .collect();
+    let categories = category::Entity::find()
+        .filter(category::Column::Id.is_in(category_ids))
+        .all(&state.sea_db)
+        .await?
+        .into_iter()
+        .map(|c| (c.id, c.name))
+        .collect();
+    let tags = tag::Entity::find()
+        .filter(tag::Column::Id.is_in(tag_ids))
+        .filter(tag::Column::IsActive.eq(true))
+        .all(&state.sea_db)
+        .await?
+        .into_iter()
+        .map(|t| (t.id, t.name))
+        .collect();
+    let images = media::Entity::find()
+        .filter(media::Column::Id.is_in(media_ids))
+        .all(&state.sea_db)
+        .await?
+        .into_iter()
+        .map(|m| {
+            let url = media::url::build_public_file_url(
+                &state.object_storage.public_url,
+                m.bucket.as_deref(),
+                &m.object_key,
+            );
+            (
+                m.id,
+                FeedImage {
+                    url,
+                    mime_type: m.mime_type,
+                    size: m.size,
+                },
+            )
+        })
+        .collect();
+
+    Ok(FeedLookups {
+        authors,
+        categories,
+        tags,
+        images,
+    })
+}
+
+async fn serve_feed(
+    state: &AppState,
+    scope: FeedScope,
+    format: FeedFormat,
+    params: FeedQuery,
+    headers: &HeaderMap,
+) -> Result<Response, ErrorResponse> {
+    let site_url =
+        std::env::var("SITE_URL").unwrap_or_else(|_| "http://localhost:8888".to_string());
+    let site_name = std::env::var("SITE_NAME").unwrap_or_else(|_| "Ruxlog".to_string());
+    let base = site_url.trim_end_matches('/');
+
+    let locale = match params.locale.as_deref() {
+        Some(code) => Some(normalize_locale(code).ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::InvalidInput).with_message("Invalid locale")
+        })?),
+        None => None,
+    };
+
+    let limit = params.limit.unwrap_or(20).min(100);
+    let posts = fetch_latest_posts(state, &scope, locale.as_deref(), limit).await?;
+
+    // Batch-load the access policy for every post so gated (Paid/
+    // SubscriberOnly) entries never leak their body in the public feed
+    // (audit F#11 round-2). Defaults to Free for posts with no policy row.
+    let post_ids: Vec<i32> = posts.iter().map(|p| p.id).collect();
+    let policies = load_post_access_map(&state.sea_db, &post_ids).await?;
+    let lookups = load_lookups(state, &posts).await?;
+
+    let last_modified = posts.iter().map(|p| p.updated_at).max();
+
+    let items = posts
+        .iter()
+        .map(|p| {
+            let policy = policies
+                .get(&p.id)
+                .cloned()
+                .unwrap_or_else(PostAccessPolicy::free);
+            // The feed is public/unauthenticated: a gated post leaks nothing
+            // of its body — only a policy hint, and never full content. Open
+            // (Free) posts keep the excerpt or a body-derived summary (audit
+            // F#11 round-2).
+            let (summary, content_html) = if policy.is_open() {
+                let summary = match &p.excerpt {
+                    Some(ex) => ex.clone(),
+                    None => content_to_summary(&p.content, 500),
+                };
+                let content_html = params.full.then(|| editorjs::to_html(&p.content));
+                (summary, content_html)
+            } else {
+                (gated_summary(&policy), None)
+            };
+
+            let mut categories: Vec<String> = lookups
+                .categories
+                .get(&p.category_id)
+                .cloned()
+                .into_iter()
+                .collect();
+            categories.extend(
+                p.tag_ids
+                    .iter()
+                    .filter_map(|id| lookups.tags.get(id).cloned()),
+            );
+
+            FeedItem {
+                url: format!(
+                    "{}{}",
+                    base,
+                    localized_path(&p.locale, &format!("/posts/{}", p.slug))
+                ),
+                title: p.title.clone(),
+                published: p.published_at.unwrap_or(p.updated_at),
+                updated: p.updated_at,
+                summary,
+                content_html,
+                author: lookups.authors.get(&p.author_id).cloned(),
+                categories,
+                image: p
+                    .featured_image_id
+                    .and_then(|id| lookups.images.get(&id).cloned()),
+            }
+        })
+        .collect();
+
+    let (title, description) = scope.describe(&site_name);
+    let mut id = format!("tag:{},{}", site_url, scope.key());
+    let mut self_url = format!("{}/feed/v1{}/{}", base, scope.path(), format.segment());
+    if let Some(locale) = &locale {
+        id.push_str(&format!(":{}", locale));
+        self_url.push_str(&format!("?locale={}", locale));
+    }
+    let feed = Feed {
+        title,
+        description,
+        id,
+        home_url: match &locale {
+            Some(locale) => format!("{}{}", base, localized_path(locale, "/")),
+            None => format!("{}/", base),
+        },
+        self_url,
+        updated: last_modified.unwrap_or_else(|| Utc::now().fixed_offset()),
+        language: locale,
+        items,
+    };
+
+    let body = match format {
+        FeedFormat::Rss => render::render_rss(&feed),
+        FeedFormat::Atom => render::render_atom(&feed),
+        FeedFormat::Json => render::render_json(&feed),
+    };
+
+    build_feed_response(format, body, last_modified, headers)
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_format(segment: &str) -> Result<FeedFormat, ErrorResponse> {
+    FeedFormat::from_segment(segment).ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::RecordNotFound)
+            .with_message("Unknown feed format; use rss, atom or json")
+    })
+}
+
+fn scope_not_found(what: &str) -> ErrorResponse {
+    ErrorResponse::new(ErrorCode::RecordNotFound).with_message(format!("{} not found", what))
+}
+
+#[debug_handler]
+pub async fn rss(
+    State(state): State<AppState>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    serve_feed(&state, FeedScope::All, FeedFormat::Rss, params, &headers).await
+}
+
+#[debug_handler]
+pub async fn atom(
+    State(state): State<AppState>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    serve_feed(&state, FeedScope::All, FeedFormat::Atom, params, &headers).await
+}
+
+#[debug_handler]
+pub async fn json(
+    State(state): State<AppState>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    serve_feed(&state, FeedScope::All, FeedFormat::Json, params, &headers).await
+}
+
+#[debug_handler]
+pub async fn category_feed(
+    State(state): State<AppState>,
+    Path((slug, format)): Path<(String, String)>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = parse_format(&format)?;
+    let category = category::Entity::find()
+        .filter(category::Column::Slug.eq(slug))
+        .filter(category::Column::IsActive.eq(true))
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| scope_not_found("Category"))?;
+    serve_feed(
+        &state,
+        FeedScope::Category(category),
+        format,
+        params,
+        &headers,
+    )
+    .await
+}
+
+#[debug_handler]
+pub async fn tag_feed(
+    State(state): State<AppState>,
+    Path((slug, format)): Path<(String, String)>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = parse_format(&format)?;
+    let tag = tag::Entity::find()
+        .filter(tag::Column::Slug.eq(slug))
+        .filter(tag::Column::IsActive.eq(true))
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| scope_not_found("Tag"))?;
+    serve_feed(&state, FeedScope::Tag(tag), format, params, &headers).await
+}
+
+/// Keyed like the author page: `profile_slug`, or the id for users without
+/// one. Users with no published or credited posts 404, so the route can't be
+/// used to look up account names.
+#[debug_handler]
+pub async fn author_feed(
+    State(state): State<AppState>,
+    Path((slug, format)): Path<(String, String)>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = parse_format(&format)?;
+    let author =
+        user::Entity::find_by_profile_slug(&state.sea_db, &state.object_storage.public_url, &slug)
+            .await?
+            .ok_or_else(|| scope_not_found("Author"))?;
+    let published = post::Entity::find()
+        .filter(post::Column::Status.eq(PostStatus::Published))
+        .filter(post::Entity::credited_to(author.id))
+        .count(&state.sea_db)
+        .await?;
+    if published == 0 {
+        return Err(scope_not_found("Author"));
+    }
+    serve_feed(
+        &state,
+        FeedScope::Author(PublicAuthorProfile::from(author)),
+        format,
+        params,
+        &headers,
+    )
+    .await
+}
+
+#[debug_handler]
+pub async fn series_feed(
+    State(state): State<AppState>,
+    Path((slug, format)): Path<(String, String)>,
+    Query(params): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let format = parse_format(&format)?;
+    let series = post_series::Entity::find()
+        .filter(post_series::Column::Slug.eq(slug))
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| scope_not_found("Series"))?;
+    serve_feed(&state, FeedScope::Series(series), format, params, &headers).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── content_to_summary ─────────────────────────────────────────
+
+    #[test]
+    fn content_to_summary_paragraph() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "paragraph", "data": { "text": "Hello world" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "Hello world");
+    }
+
+    #[test]
+    fn content_to_summary_header() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "header", "data": { "text": "Title" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "Title");
+    }
+
+    #[test]
+    fn content_to_summary_quote() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "quote", "data": { "text": "To be or not to be" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "To be or not to be");
+    }
+
+    #[test]
+    fn content_to_summary_alert() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "alert", "data": { "message": "Warning!" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "Warning!");
+    }
+
+    #[test]
+    fn content_to_summary_checklist() {
+        let json = serde_json::json!({
+            "blocks": [
+                {
+                    "type": "checklist",
+                    "data": {
+                        "items": [
+                            { "text": "Buy milk" },
+                            { "text": "Walk dog" }
+                        ]
+                    }
+                }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "Buy milk, Walk dog");
+    }
+
+    #[test]
+    fn content_to_summary_code() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "code", "data": { "code": "fn main() {}" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "fn main() {}");
+    }
+
+    #[test]
+    fn content_to_summary_unknown_type_skipped() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "image", "data": { "url": "http://example.com/img.png" } },
+                { "type": "paragraph", "data": { "text": "Visible" } }
+            ]
+        });
+        let result = content_to_summary(&json, 100);
+        assert_eq!(result, "Visible");
+    }
+
+    #[test]
+    fn content_to_summary_multiple_blocks_joined() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "paragraph", "data": { "text": "First" } },
+                { "type": "paragraph", "data": { "text": "Second" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 100), "First Second");
+    }
+
+    #[test]
+    fn content_to_summary_respects_max_len() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "paragraph", "data": { "text": "A very long text that exceeds the limit" } }
+            ]
+        });
+        let result = content_to_summary(&json, 10);
+        // The function breaks once out.len() >= max_len (byte-based), then
+        // truncates by chars().take(max_len). "A very lon" is 10 bytes and
+        // 10 chars, so the 'g' never gets appended.
+        assert_eq!(result, "A very lon");
+    }
+
+    #[test]
+    fn content_to_summary_empty_blocks_falls_back_to_json_string() {
+        let json = serde_json::json!({ "not": "blocks" });
+        let result = content_to_summary(&json, 200);
+        // Falls back to the entire JSON as a string
+        assert!(result.contains("not"));
+        assert!(result.contains("blocks"));
+    }
+
+    #[test]
+    fn content_to_summary_empty_value() {
+        let json = serde_json::json!({});
+        let result = content_to_summary(&json, 100);
+        // Fallback: the JSON representation of {}
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn content_to_summary_max_len_zero() {
+        let json = serde_json::json!({
+            "blocks": [
+                { "type": "paragraph", "data": { "text": "Hello" } }
+            ]
+        });
+        assert_eq!(content_to_summary(&json, 0), "");
+    }
+
+    #[test]
+    fn content_to_summary_truncates_fallback_json() {
+        let json = serde_json::json!({
+            "some_key": "some relatively long value here"
+        });
+        let result = content_to_summary(&json, 5);
+        // The fallback JSON string is also truncated
+        assert_eq!(result.len(), 5);
+    }
+
+    // ── gated_summary (audit F#11 round-2, feed paywall) ──────────
+
+    fn access(access_type: PostAccessType, price: Option<i32>) -> PostAccessPolicy {
+        PostAccessPolicy {
+            access_type,
+            price_cents: price,
+            currency: price.map(|_| "USD".to_string()),
+        }
+    }
+
+    #[test]
+    fn gated_summary_subscriber_only_is_a_hint_not_the_body() {
+        let s = gated_summary(&access(PostAccessType::SubscriberOnly, None));
+        assert!(s.to_lowercase().contains("subscriber"));
+        // Never echoes body content — it is a fixed policy hint.
+        assert!(s.contains("visit the site"));
+    }
+
+    #[test]
+    fn gated_summary_paid_includes_price() {
+        let s = gated_summary(&access(PostAccessType::Paid, Some(499)));
+        assert!(s.to_lowercase().contains("purchase"));
+        // 499 cents → 4.99
+        assert!(s.contains("4.99"));
+    }
+
+    #[test]
+    fn gated_summary_paid_without_price_is_a_generic_hint() {
+        let s = gated_summary(&access(PostAccessType::Paid, None));
+        assert!(s.to_lowercase().contains("purchase"));
+        assert!(!s.contains("USD"));
+    }
+
+    #[test]
+    fn gated_summary_free_is_empty() {
+        // Open posts never reach gated_summary; the value is unused.
+        assert_eq!(gated_summary(&access(PostAccessType::Free, None)), "");
+    }
+
+    // ── conditional requests ───────────────────────────────────────
+
+    fn headers(pairs: &[(header::HeaderName, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(name.clone(), HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    fn at(s: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn etag_is_weak_and_tracks_the_body() {
+        let a = etag_for("<rss/>");
+        assert!(a.starts_with("W/\""));
+        assert_eq!(a, etag_for("<rss/>"));
+        assert_ne!(a, etag_for("<rss></rss>"));
+    }
+
+    #[test]
+    fn http_date_is_imf_fixdate_in_gmt() {
+        assert_eq!(
+            http_date(at("2025-05-01T14:30:05+02:00")),
+            "Thu, 01 May 2025 12:30:05 GMT"
+        );
+    }
+
+    #[test]
+    fn if_none_match_matches_weakly_and_in_lists() {
+        let etag = etag_for("body");
+        let strong = etag.trim_start_matches("W/").to_string();
+        for value in [etag.as_str(), strong.as_str(), "*"] {
+            assert!(is_not_modified(
+                &headers(&[(header::IF_NONE_MATCH, value)]),
+                &etag,
+                None
+            ));
+        }
+        let list = format!("\"other\", {}", etag);
+        assert!(is_not_modified(
+            &headers(&[(header::IF_NONE_MATCH, &list)]),
+            &etag,
+            None
+        ));
+        assert!(!is_not_modified(
+            &headers(&[(header::IF_NONE_MATCH, "\"other\"")]),
+            &etag,
+            None
+        ));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let etag = etag_for("body");
+        let map = headers(&[
+            (header::IF_NONE_MATCH, "\"stale\""),
+            (header::IF_MODIFIED_SINCE, "Thu, 01 May 2025 12:30:05 GMT"),
+        ]);
+        assert!(!is_not_modified(
+            &map,
+            &etag,
+            Some(at("2025-05-01T12:00:00Z"))
+        ));
+    }
+
+    #[test]
+    fn if_modified_since_compares_at_second_precision() {
+        let etag = etag_for("body");
+        let map = headers(&[(header::IF_MODIFIED_SINCE, "Thu, 01 May 2025 12:30:05 GMT")]);
+        assert!(is_not_modified(
+            &map,
+            &etag,
+            Some(at("2025-05-01T12:30:05.750Z"))
+        ));
+        assert!(!is_not_modified(
+            &map,
+            &etag,
+            Some(at("2025-05-01T12:30:06Z"))
+        ));
+        // No posts means no Last-Modified to compare against.
+        assert!(!is_not_modified(&map, &etag, None));
+    }
+
+    #[test]
+    fn unparseable_if_modified_since_is_ignored() {
+        let map = headers(&[(header::IF_MODIFIED_SINCE, "yesterday")]);
+        assert!(!is_not_modified(
+            &map,
+            &etag_for("body"),
+            Some(at("2025-05-01T12:00:00Z"))
+        ));
+    }
+
+    #[test]
+    fn feed_format_round_trips_path_segments() {
+        for format in [FeedFormat::Rss, FeedFormat::Atom, FeedFormat::Json] {
+            assert_eq!(FeedFormat::from_segment(format.segment()), Some(format));
+        }
+        assert_eq!(FeedFormat::from_segment("xml"), None);
+        assert_eq!(
+            FeedFormat::Json.content_type(),
+            "application/feed+json; charset=utf-8"
+        );
+    }
+}
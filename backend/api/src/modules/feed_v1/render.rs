@@ -0,0 +1,373 @@
+//! Feed documents (RSS 2.0, Atom 1.0, JSON Feed 1.1) built from one
+//! format-neutral [`Feed`].
+
+use chrono::{DateTime, FixedOffset};
+use serde_json::{json, Value};
+
+/// Channel-level metadata shared by every output format.
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    /// Atom `<id>`; stays stable across formats and URL changes.
+    pub id: String,
+    pub home_url: String,
+    /// Canonical URL of this document, used for `rel="self"` / `feed_url`.
+    pub self_url: String,
+    pub updated: DateTime<FixedOffset>,
+    /// BCP 47 tag when the feed is limited to one locale.
+    pub language: Option<String>,
+    pub items: Vec<FeedItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedItem {
+    pub url: String,
+    pub title: String,
+    pub published: DateTime<FixedOffset>,
+    pub updated: DateTime<FixedOffset>,
+    pub summary: String,
+    /// Sanitized HTML of the full post, only for open posts when requested.
+    pub content_html: Option<String>,
+    pub author: Option<String>,
+    /// Category first, then tags.
+    pub categories: Vec<String>,
+    pub image: Option<FeedImage>,
+}
+
+/// Featured image, emitted as an enclosure.
+#[derive(Debug, Clone)]
+pub struct FeedImage {
+    pub url: String,
+    pub mime_type: String,
+    pub size: i64,
+}
+
+pub fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+pub fn render_rss(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(concat!(
+        r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom""#,
+        r#" xmlns:content="http://purl.org/rss/1.0/modules/content/""#,
+        r#" xmlns:dc="http://purl.org/dc/elements/1.1/"><channel>"#
+    ));
+    xml.push_str(&format!("<title>{}</title>", xml_escape(&feed.title)));
+    xml.push_str(&format!("<link>{}</link>", xml_escape(&feed.home_url)));
+    xml.push_str(&format!(
+        "<description>{}</description>",
+        xml_escape(&feed.description)
+    ));
+    xml.push_str(&format!(
+        r#"<atom:link href="{}" rel="self" type="application/rss+xml" />"#,
+        xml_escape(&feed.self_url)
+    ));
+    xml.push_str(&format!(
+        "<lastBuildDate>{}</lastBuildDate>",
+        feed.updated.to_rfc2822()
+    ));
+    if let Some(language) = &feed.language {
+        xml.push_str(&format!("<language>{}</language>", xml_escape(language)));
+    }
+    xml.push_str("<generator>ruxlog</generator>");
+
+    for item in &feed.items {
+        xml.push_str("<item>");
+        xml.push_str(&format!("<title>{}</title>", xml_escape(&item.title)));
+        xml.push_str(&format!("<link>{}</link>", xml_escape(&item.url)));
+        xml.push_str(&format!(
+            "<guid isPermaLink=\"true\">{}</guid>",
+            xml_escape(&item.url)
+        ));
+        xml.push_str(&format!(
+            "<pubDate>{}</pubDate>",
+            item.published.to_rfc2822()
+        ));
+        if let Some(author) = &item.author {
+            xml.push_str(&format!("<dc:creator>{}</dc:creator>", xml_escape(author)));
+        }
+        for category in &item.categories {
+            xml.push_str(&format!("<category>{}</category>", xml_escape(category)));
+        }
+        xml.push_str(&format!(
+            "<description>{}</description>",
+            xml_escape(&item.summary)
+        ));
+        if let Some(html) = &item.content_html {
+            xml.push_str(&format!(
+                "<content:encoded>{}</content:encoded>",
+                xml_escape(html)
+            ));
+        }
+        if let Some(image) = &item.image {
+            xml.push_str(&format!(
+                r#"<enclosure url="{}" length="{}" type="{}" />"#,
+                xml_escape(&image.url),
+                image.size.max(0),
+                xml_escape(&image.mime_type)
+            ));
+        }
+        xml.push_str("</item>");
+    }
+
+    xml.push_str("</channel></rss>");
+    xml
+}
+
+pub fn render_atom(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    match &feed.language {
+        Some(language) => xml.push_str(&format!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="{}">"#,
+            xml_escape(language)
+        )),
+        None => xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#),
+    }
+    xml.push_str(&format!("<title>{}</title>", xml_escape(&feed.title)));
+    xml.push_str(&format!(
+        "<subtitle>{}</subtitle>",
+        xml_escape(&feed.description)
+    ));
+    xml.push_str(&format!("<id>{}</id>", xml_escape(&feed.id)));
+    xml.push_str(&format!("<updated>{}</updated>", feed.updated.to_rfc3339()));
+    xml.push_str(&format!(
+        r#"<link rel="self" href="{}" />"#,
+        xml_escape(&feed.self_url)
+    ));
+    xml.push_str(&format!(
+        r#"<link href="{}" />"#,
+        xml_escape(&feed.home_url)
+    ));
+    xml.push_str("<generator>ruxlog</generator>");
+
+    for item in &feed.items {
+        xml.push_str("<entry>");
+        xml.push_str(&format!("<title>{}</title>", xml_escape(&item.title)));
+        xml.push_str(&format!("<id>{}</id>", xml_escape(&item.url)));
+        xml.push_str(&format!(
+            r#"<link rel="alternate" href="{}" />"#,
+            xml_escape(&item.url)
+        ));
+        xml.push_str(&format!(
+            "<published>{}</published>",
+            item.published.to_rfc3339()
+        ));
+        xml.push_str(&format!("<updated>{}</updated>", item.updated.to_rfc3339()));
+        if let Some(author) = &item.author {
+            xml.push_str(&format!(
+                "<author><name>{}</name></author>",
+                xml_escape(author)
+            ));
+        }
+        for category in &item.categories {
+            xml.push_str(&format!(r#"<category term="{}" />"#, xml_escape(category)));
+        }
+        xml.push_str(&format!("<summary>{}</summary>", xml_escape(&item.summary)));
+        if let Some(html) = &item.content_html {
+            xml.push_str(&format!(
+                r#"<content type="html">{}</content>"#,
+                xml_escape(html)
+            ));
+        }
+        if let Some(image) = &item.image {
+            xml.push_str(&format!(
+                r#"<link rel="enclosure" href="{}" type="{}" length="{}" />"#,
+                xml_escape(&image.url),
+                xml_escape(&image.mime_type),
+                image.size.max(0)
+            ));
+        }
+        xml.push_str("</entry>");
+    }
+
+    xml.push_str("</feed>");
+    xml
+}
+
+/// JSON Feed 1.1 (<https://jsonfeed.org/version/1.1>).
+pub fn render_json(feed: &Feed) -> String {
+    let items: Vec<Value> = feed
+        .items
+        .iter()
+        .map(|item| {
+            let mut entry = json!({
+                "id": item.url,
+                "url": item.url,
+                "title": item.title,
+                "summary": item.summary,
+                "date_published": item.published.to_rfc3339(),
+                "date_modified": item.updated.to_rfc3339(),
+                "tags": item.categories,
+            });
+            // `content_text` or `content_html` is required; the summary
+            // stands in for gated posts and excerpt-only feeds.
+            match &item.content_html {
+                Some(html) => entry["content_html"] = json!(html),
+                None => entry["content_text"] = json!(item.summary),
+            }
+            if let Some(author) = &item.author {
+                entry["authors"] = json!([{ "name": author }]);
+            }
+            if let Some(image) = &item.image {
+                entry["image"] = json!(image.url);
+                entry["attachments"] = json!([{
+                    "url": image.url,
+                    "mime_type": image.mime_type,
+                    "size_in_bytes": image.size.max(0),
+                }]);
+            }
+            entry
+        })
+        .collect();
+
+    let mut doc = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": feed.title,
+        "description": feed.description,
+        "home_page_url": feed.home_url,
+        "feed_url": feed.self_url,
+        "items": items,
+    });
+    if let Some(language) = &feed.language {
+        doc["language"] = json!(language);
+    }
+    doc.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_feed(content_html: Option<String>) -> Feed {
+        let at = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2025, 5, 1, 12, 0, 0)
+            .unwrap();
+        Feed {
+            title: "Ruxlog — Rust & <Web>".into(),
+            description: "Posts in Rust".into(),
+            id: "tag:https://example.com,feed:category:rust".into(),
+            home_url: "https://example.com/".into(),
+            self_url: "https://example.com/feed/v1/category/rust/rss".into(),
+            updated: at,
+            language: Some("pt-BR".into()),
+            items: vec![FeedItem {
+                url: "https://example.com/posts/hello".into(),
+                title: "Hello".into(),
+                published: at,
+                updated: at,
+                summary: "Short".into(),
+                content_html,
+                author: Some("Ada".into()),
+                categories: vec!["Rust".into(), "async".into()],
+                image: Some(FeedImage {
+                    url: "https://cdn.example.com/a.webp".into(),
+                    mime_type: "image/webp".into(),
+                    size: 1234,
+                }),
+            }],
+        }
+    }
+
+    // ── xml_escape ─────────────────────────────────────────────────
+
+    #[test]
+    fn xml_escape_ampersand() {
+        assert_eq!(xml_escape("a&b"), "a&amp;b");
+    }
+
+    #[test]
+    fn xml_escape_angle_brackets() {
+        assert_eq!(xml_escape("<div>"), "&lt;div&gt;");
+    }
+
+    #[test]
+    fn xml_escape_quotes() {
+        assert_eq!(
+            xml_escape(r#"say "hi" and it's fine"#),
+            "say &quot;hi&quot; and it&apos;s fine"
+        );
+    }
+
+    #[test]
+    fn xml_escape_all_entities() {
+        let input = "<tag attr='val'&more>";
+        let escaped = xml_escape(input);
+        assert!(escaped.contains("&lt;"));
+        assert!(escaped.contains("&gt;"));
+        assert!(escaped.contains("&apos;"));
+        assert!(escaped.contains("&amp;"));
+    }
+
+    #[test]
+    fn xml_escape_plain_string_unchanged() {
+        assert_eq!(xml_escape("hello world"), "hello world");
+    }
+
+    #[test]
+    fn xml_escape_empty() {
+        assert_eq!(xml_escape(""), "");
+    }
+
+    // ── documents ──────────────────────────────────────────────────
+
+    #[test]
+    fn rss_has_enclosure_content_and_escaped_channel() {
+        let xml = render_rss(&sample_feed(Some("<p>Hi</p>".into())));
+        assert!(xml.contains("<title>Ruxlog — Rust &amp; &lt;Web&gt;</title>"));
+        assert!(xml.contains(
+            r#"<enclosure url="https://cdn.example.com/a.webp" length="1234" type="image/webp" />"#
+        ));
+        assert!(xml.contains("<content:encoded>&lt;p&gt;Hi&lt;/p&gt;</content:encoded>"));
+        assert!(xml.contains("<dc:creator>Ada</dc:creator>"));
+        assert!(xml.contains("<category>async</category>"));
+        assert!(xml.contains("<language>pt-BR</language>"));
+    }
+
+    #[test]
+    fn atom_has_enclosure_link_and_html_content() {
+        let xml = render_atom(&sample_feed(Some("<p>Hi</p>".into())));
+        assert!(xml.contains(r#"<link rel="enclosure" href="https://cdn.example.com/a.webp""#));
+        assert!(xml.contains(r#"<content type="html">&lt;p&gt;Hi&lt;/p&gt;</content>"#));
+        assert!(xml.contains("<author><name>Ada</name></author>"));
+        assert!(xml.contains("<id>tag:https://example.com,feed:category:rust</id>"));
+        assert!(xml.contains(r#"xml:lang="pt-BR">"#));
+
+        let untagged = render_atom(&Feed {
+            language: None,
+            ..sample_feed(None)
+        });
+        assert!(!untagged.contains("xml:lang"));
+    }
+
+    #[test]
+    fn json_feed_uses_content_text_without_full_content() {
+        let doc: Value = serde_json::from_str(&render_json(&sample_feed(None))).unwrap();
+        assert_eq!(doc["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(
+            doc["feed_url"],
+            "https://example.com/feed/v1/category/rust/rss"
+        );
+        let item = &doc["items"][0];
+        assert_eq!(item["content_text"], "Short");
+        assert!(item.get("content_html").is_none());
+        assert_eq!(item["image"], "https://cdn.example.com/a.webp");
+        assert_eq!(item["attachments"][0]["size_in_bytes"], 1234);
+        assert_eq!(item["authors"][0]["name"], "Ada");
+        assert_eq!(doc["language"], "pt-BR");
+
+        let doc: Value =
+            serde_json::from_str(&render_json(&sample_feed(Some("<p>Hi</p>".into())))).unwrap();
+        assert_eq!(doc["items"][0]["content_html"], "<p>Hi</p>");
+        assert!(doc["items"][0].get("content_text").is_none());
+    }
+}
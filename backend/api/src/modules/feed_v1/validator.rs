@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct FeedQuery {
+    pub limit: Option<u64>,
+    /// Render the full post body (open posts only) instead of the excerpt.
+    #[serde(default)]
+    pub full: bool,
+    /// Only posts in this locale (BCP 47 tag).
+    pub locale: Option<String>,
+}
+
+/// Output format, taken from the last path segment of a scoped feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    pub fn from_segment(segment: &str) -> Option<Self> {
+        match segment {
+            "rss" => Some(Self::Rss),
+            "atom" => Some(Self::Atom),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn segment(self) -> &'static str {
+        match self {
+            Self::Rss => "rss",
+            Self::Atom => "atom",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Rss => "application/rss+xml; charset=utf-8",
+            Self::Atom => "application/atom+xml; charset=utf-8",
+            Self::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
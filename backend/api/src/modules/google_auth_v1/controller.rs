@@ -20,7 +20,11 @@ use crate::{
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
     extractors::ValidatedQuery,
-    services::auth::AuthSession,
+    services::{
+        auth::AuthSession,
+        blocklist::Blocklist,
+        notifications::{self, SiteEvent},
+    },
     AppState,
 };
 
@@ -596,12 +600,34 @@ async fn find_or_create_user(
             .with_message("Google has not verified this email address"));
     }
 
+    // Same blocklist gate as password registration: a Google sign-in must not
+    // be a way around it.
+    let blocklist = Blocklist::load(&state.sea_db).await?;
+    if let Some(found) = blocklist.check_email(&user_info.email) {
+        warn!(pattern = %found.pattern, "Google registration blocked by email domain");
+        return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("Registrations from this email domain are not allowed"));
+    }
+    if let Some(found) = blocklist.check_name(&user_info.name) {
+        warn!(pattern = %found.pattern, "Google registration blocked by name");
+        return Err(
+            ErrorResponse::new(ErrorCode::InvalidInput).with_message("This name is not allowed")
+        );
+    }
+
     info!("Creating new user from Google account");
-    user::Entity::create_from_google(
+    let new_user = user::Entity::create_from_google(
         &state.sea_db,
         user_info.id.clone(),
         user_info.email.clone(),
         user_info.name.clone(),
     )
-    .await
+    .await?;
+    notifications::dispatch(
+        state,
+        SiteEvent::UserRegistered {
+            user_id: new_user.id,
+        },
+    );
+    Ok(new_user)
 }
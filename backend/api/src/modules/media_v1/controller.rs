@@ -1,15 +1,15 @@
 use std::collections::{BTreeSet, HashMap};
 
-use aws_sdk_s3::primitives::ByteStream;
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::IntoResponse,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use axum_macros::debug_handler;
 use bytes::Bytes;
 use chrono::{Datelike, Utc};
+use futures_util::TryStreamExt;
 use sea_orm::{prelude::DateTimeWithTimeZone, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
 use serde::Serialize;
 use serde_json::json;
@@ -20,30 +20,47 @@ use crate::{
     config,
     db::sea_models::{
         category::{self, Model as CategoryModel},
-        media::{self, Entity as Media, NewMedia},
+        media::{self, responsive, slice::MediaPublic, Entity as Media, MediaKind, NewMedia},
+        media_upload::{self, Entity as MediaUpload, MediaUploadStatus, NewMediaUpload},
         media_usage,
+        media_variant::{self, Entity as MediaVariant, NewMediaVariant},
         post::{self, Model as PostModel},
         user::{self, Model as UserModel},
     },
     error::{ErrorCode, ErrorResponse},
     extractors::{ValidatedJson, ValidatedMultipart},
-    services::auth::AuthSession,
+    services::{
+        auth::AuthSession,
+        media_gc,
+        media_probe::{self, MediaProbe, Poster},
+        storage::{ObjectStore, SignedQuery},
+    },
     AppState,
 };
 
 #[cfg(feature = "image-optimization")]
-use crate::db::sea_models::media_variant::{Entity as MediaVariant, NewMediaVariant};
+use crate::db::sea_models::media_optimization_job::Entity as MediaOptimizationJob;
 #[cfg(feature = "image-optimization")]
-use crate::services::image_optimizer;
+use crate::services::{image_optimizer, media_reoptimizer};
 use tracing::{debug, error, info, instrument, warn};
 
+use super::validator::{
+    allowlisted_extension, max_upload_size, missing_parts, part_count_for, validate_upload,
+    MediaUploadMetadata, V1MediaDeleteParams, V1MediaGcPayload, V1MediaListQuery,
+    V1MediaResponsivePayload, V1MediaUpdatePayload, V1MediaUploadInitPayload,
+    V1MediaUploadPartsPayload, V1MediaUsageQuery,
+};
 #[cfg(feature = "image-optimization")]
-use super::validator::is_allowed_mime;
 use super::validator::{
-    allowlisted_extension, validate_upload, MediaUploadMetadata, V1MediaListQuery,
-    V1MediaUsageQuery,
+    is_allowed_mime, V1MediaOptimizePayload, V1MediaOptimizeStatusPayload, DEFAULT_OPTIMIZE_LIMIT,
 };
 
+/// How long an unfinished direct upload may be resumed before media GC
+/// aborts it.
+const UPLOAD_SESSION_TTL_HOURS: i64 = 24;
+/// Lifetime of a presigned part URL.
+const PART_URL_TTL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
 #[derive(Debug, Serialize)]
 struct PostUsage {
     usage_id: i32,
@@ -150,18 +167,7 @@ struct MediaUsageGroup {
 }
 
 #[debug_handler]
-#[instrument(
-    skip(state, auth, multipart),
-    fields(
-        user_id,
-        file_size,
-        content_hash,
-        is_duplicate,
-        is_optimized,
-        variant_count,
-        result
-    )
-)]
+#[instrument(skip(state, auth, multipart), fields(user_id, file_size))]
 pub async fn create(
     State(state): State<AppState>,
     auth: AuthSession,
@@ -248,19 +254,83 @@ pub async fn create(
             ErrorResponse::new(ErrorCode::InvalidFileType).with_message(&msg)
         })?;
 
-    let mut hasher = Sha256::new();
-    hasher.update(&file_bytes);
-    let content_hash = format!("{:x}", hasher.finalize());
+    ingest(
+        &state,
+        &uploader,
+        file_bytes,
+        declared_mime,
+        declared_extension,
+        mime_type,
+        metadata,
+    )
+    .await
+    .map(Ingested::into_response)
+}
 
-    debug!(content_hash = %content_hash, "File hash calculated");
-    tracing::Span::current().record("content_hash", &content_hash);
+/// Result of running an upload through [`ingest`].
+enum Ingested {
+    /// The caller already owns byte-identical media; nothing was stored.
+    Duplicate(MediaPublic),
+    Created(MediaPublic),
+}
+
+impl Ingested {
+    fn media_id(&self) -> i32 {
+        match self {
+            Self::Duplicate(existing) => existing.media.id,
+            Self::Created(created) => created.media.id,
+        }
+    }
+
+    fn into_response(self) -> (StatusCode, Json<serde_json::Value>) {
+        match self {
+            Self::Duplicate(existing) => (StatusCode::OK, Json(json!(existing))),
+            Self::Created(created) => (StatusCode::CREATED, Json(json!(created))),
+        }
+    }
+}
+
+fn media_public(state: &AppState, media: media::Model) -> MediaPublic {
+    let file_url = crate::db::sea_models::media::url::build_public_file_url(
+        &state.object_storage.public_url,
+        media.bucket.as_deref(),
+        &media.object_key,
+    );
+    MediaPublic {
+        media,
+        file_url,
+        poster_url: None,
+    }
+}
+
+/// [`media_public`] with the poster URL filled in, for single-item responses.
+async fn media_public_with_poster(
+    state: &AppState,
+    media: media::Model,
+) -> Result<MediaPublic, ErrorResponse> {
+    let poster = MediaVariant::find_posters(&state.sea_db, &[media.id])
+        .await?
+        .remove(&media.id);
+    let poster_url = poster.map(|variant| variant_url(state, &media, &variant));
+    Ok(MediaPublic {
+        poster_url,
+        ..media_public(state, media)
+    })
+}
 
-    if let Some(existing) = Media::find_by_hash(&state.sea_db, &content_hash).await? {
+/// Media the uploader already owns with the same content hash. Records the
+/// outcome on the current span.
+async fn find_duplicate(
+    state: &AppState,
+    uploader: &user::Model,
+    content_hash: &str,
+) -> Result<Option<MediaPublic>, ErrorResponse> {
+    if let Some(existing) = Media::find_by_hash(&state.sea_db, content_hash).await? {
         // M-5: only return the existing record to its owner or staff. A non-owner
         // otherwise learns another user's object key / bucket / public URL by
         // uploading a byte-identical file. For everyone else, fall through and
         // create a fresh record rather than leaking the existing one.
-        if can_view_media(&uploader, existing.uploader_id) {
+        if can_view_media(uploader, existing.uploader_id) {
             info!(
                 media_id = existing.id,
                 content_hash = %content_hash,
@@ -268,7 +338,7 @@ pub async fn create(
             );
             tracing::Span::current().record("is_duplicate", true);
             tracing::Span::current().record("result", "duplicate");
-            return Ok((StatusCode::OK, Json(json!(existing))));
+            return Ok(Some(media_public_with_poster(state, existing).await?));
         } else {
             warn!(
                 media_id = existing.id,
@@ -278,9 +348,42 @@ pub async fn create(
     }
 
     tracing::Span::current().record("is_duplicate", false);
+    Ok(None)
+}
+
+/// Shared tail of the multipart `create` handler and direct-upload
+/// completion: hash + dedup, optimize, store the original and its variants
+/// under a fresh object key and insert the media row. `declared_mime` /
+/// `declared_extension` must already have passed `validate_upload`.
+#[instrument(
+    skip(state, uploader, file_bytes, original_mime, metadata),
+    fields(user_id = uploader.id, content_hash, is_duplicate, result)
+)]
+#[cfg_attr(not(feature = "image-optimization"), allow(unused_variables))]
+async fn ingest(
+    state: &AppState,
+    uploader: &user::Model,
+    file_bytes: Bytes,
+    declared_mime: String,
+    declared_extension: String,
+    original_mime: Option<String>,
+    mut metadata: MediaUploadMetadata,
+) -> Result<Ingested, ErrorResponse> {
+    let mut hasher = Sha256::new();
+    hasher.update(&file_bytes);
+    let content_hash = format!("{:x}", hasher.finalize());
+
+    debug!(content_hash = %content_hash, "File hash calculated");
+    tracing::Span::current().record("content_hash", &content_hash);
+
+    if let Some(existing) = find_duplicate(state, uploader, &content_hash).await? {
+        return Ok(Ingested::Duplicate(existing));
+    }
+
+    let kind = MediaKind::from_mime(&declared_mime).unwrap_or(MediaKind::Image);
 
     // Derive useful metadata if it was not supplied
-    if metadata.width.is_none() || metadata.height.is_none() {
+    if kind == MediaKind::Image && (metadata.width.is_none() || metadata.height.is_none()) {
         if let Ok(dimensions) = imagesize::blob_size(&file_bytes) {
             debug!(
                 width = dimensions.width,
@@ -296,6 +399,14 @@ pub async fn create(
         }
     }
 
+    let probe = if kind == MediaKind::Image {
+        MediaProbe::default()
+    } else {
+        probe_upload(state, &declared_mime, &file_bytes).await?
+    };
+    metadata.width = metadata.width.or(probe.width);
+    metadata.height = metadata.height.or(probe.height);
+
     #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
     let mut extension = Some(declared_extension.clone());
     #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
@@ -306,6 +417,11 @@ pub async fn create(
     let mut is_optimized = false;
     #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
     let mut optimized_at = None;
+    #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
+    let mut blurhash = probe
+        .poster
+        .as_ref()
+        .and_then(|poster| poster.blurhash.clone());
 
     #[cfg(feature = "image-optimization")]
     struct PreparedVariant {
@@ -326,7 +442,10 @@ pub async fn create(
     let mut variants_to_upload: Vec<image_optimizer::OptimizedImage> = Vec::new();
 
     #[cfg(feature = "image-optimization")]
-    if content_type.starts_with("image/") {
+    let defer_optimization = state.optimizer.defer_uploads && content_type.starts_with("image/");
+
+    #[cfg(feature = "image-optimization")]
+    if content_type.starts_with("image/") && !defer_optimization {
         // DOS-MEDIA-OPTIMIZER: the `image` crate work (full RGBA decode +
         // Lanczos3 resize/re-encode for up to 6 variants + a second validation
         // decode per variant) is CPU/memory-heavy. Run it on a blocking thread
@@ -336,7 +455,7 @@ pub async fn create(
         let req_bytes = file_bytes.clone();
         let req_metadata = metadata.clone();
         let req_reference = metadata.reference_type;
-        let req_mime = mime_type.clone();
+        let req_mime = original_mime.clone();
         let req_ext = extension.clone();
         let optimizer_cfg = state.optimizer.clone();
         let optimization_outcome = match tokio::task::spawn_blocking(move || {
@@ -346,12 +465,25 @@ pub async fn create(
                 reference: req_reference,
                 original_mime: req_mime.as_deref(),
                 original_extension: req_ext.as_deref(),
+                regenerate: false,
             };
-            image_optimizer::optimize(&optimizer_cfg, optimization_request)
+            let outcome = image_optimizer::optimize(&optimizer_cfg, optimization_request);
+            // Well-compressed uploads skip re-encoding but still get a
+            // placeholder hash.
+            let fallback_hash = match &outcome {
+                Ok(image_optimizer::OptimizationOutcome::Skipped(
+                    image_optimizer::SkipReason::AlreadyOptimized,
+                )) => image_optimizer::blurhash_for_bytes(&optimizer_cfg, &req_bytes),
+                _ => None,
+            };
+            outcome.map(|outcome| (outcome, fallback_hash))
         })
         .await
         {
-            Ok(Ok(outcome)) => outcome,
+            Ok(Ok((outcome, fallback_hash))) => {
+                blurhash = fallback_hash;
+                outcome
+            }
             Ok(Err(err)) => {
                 warn!("image optimizer error: {}", err);
                 image_optimizer::OptimizationOutcome::Skipped(
@@ -388,6 +520,7 @@ pub async fn create(
             }
 
             variants_to_upload = result.variants;
+            blurhash = result.blurhash;
             is_optimized = true;
             optimized_at = Some(Utc::now().fixed_offset());
         }
@@ -399,22 +532,10 @@ pub async fn create(
     })?;
 
     let object_key = build_object_key(extension.as_deref());
-    #[cfg(feature = "image-optimization")]
-    let base_object_key = object_key
-        .rsplit_once('.')
-        .map(|(prefix, _)| prefix.to_string())
-        .unwrap_or_else(|| object_key.clone());
-
-    let byte_stream = ByteStream::from(final_bytes.clone().to_vec());
 
     state
-        .s3_client
-        .put_object()
-        .bucket(&state.object_storage.bucket)
-        .key(&object_key)
-        .body(byte_stream)
-        .content_type(&content_type)
-        .send()
+        .storage
+        .put(&object_key, final_bytes.clone(), &content_type)
         .await
         .map_err(|err| {
             ErrorResponse::new(ErrorCode::StorageError)
@@ -424,23 +545,11 @@ pub async fn create(
 
     #[cfg(feature = "image-optimization")]
     for variant in variants_to_upload {
-        let suffix = match variant.label {
-            image_optimizer::VariantLabel::Width(width) => format!("@{}w", width),
-            image_optimizer::VariantLabel::Lqip => "@lqip".to_string(),
-            image_optimizer::VariantLabel::Original => continue,
+        let Some(variant_key) = image_optimizer::variant_object_key(&object_key, &variant, None)
+        else {
+            continue;
         };
 
-        let variant_key = format!(
-            "{}{}{}",
-            base_object_key,
-            suffix,
-            if variant.extension.is_empty() {
-                String::new()
-            } else {
-                format!(".{}", variant.extension)
-            }
-        );
-
         let size_bytes = i64::try_from(variant.bytes.len()).map_err(|_| {
             ErrorResponse::new(ErrorCode::InvalidValue)
                 .with_message("Variant size exceeds supported range")
@@ -458,17 +567,12 @@ pub async fn create(
                 Some(variant.extension.clone())
             },
             quality: variant.quality.map(i32::from),
-            variant_type: label_to_variant_type(&variant.label),
+            variant_type: variant.label.variant_type(),
         });
 
         if let Err(err) = state
-            .s3_client
-            .put_object()
-            .bucket(&state.object_storage.bucket)
-            .key(&variant_key)
-            .body(ByteStream::from(variant.bytes.to_vec()))
-            .content_type(&variant.mime_type)
-            .send()
+            .storage
+            .put(&variant_key, variant.bytes.clone(), &variant.mime_type)
             .await
         {
             warn!(
@@ -479,7 +583,7 @@ pub async fn create(
     }
 
     let new_media = NewMedia {
-        bucket: state.object_storage.bucket.clone(),
+        bucket: state.storage.bucket().to_string(),
         object_key,
         mime_type: content_type,
         width: metadata.width,
@@ -491,6 +595,15 @@ pub async fn create(
         content_hash: Some(content_hash),
         is_optimized,
         optimized_at,
+        alt_text: metadata.alt_text.clone(),
+        caption: metadata.caption.clone(),
+        credit: metadata.credit.clone(),
+        license: metadata.license.clone(),
+        focal_x: metadata.focal_point().map(|(x, _)| x),
+        focal_y: metadata.focal_point().map(|(_, y)| y),
+        blurhash,
+        duration_ms: probe.duration_ms,
+        page_count: probe.page_count,
     };
 
     let stored = Media::create(&state.sea_db, new_media).await?;
@@ -500,6 +613,18 @@ pub async fn create(
         &stored.object_key,
     );
 
+    // A missing poster only costs the preview; the client can upload one
+    // later through `set_poster`.
+    let mut poster_url = None;
+    if let Some(poster) = probe.poster {
+        match store_poster(state, &stored, poster).await {
+            Ok(variant) => poster_url = Some(variant_url(state, &stored, &variant)),
+            Err(err) => {
+                warn!(media_id = stored.id, error = %err.message, "Failed to store media poster")
+            }
+        }
+    }
+
     #[cfg(feature = "image-optimization")]
     if !prepared_variants.is_empty() {
         let records = prepared_variants
@@ -520,13 +645,320 @@ pub async fn create(
         MediaVariant::create_many(&state.sea_db, records).await?;
     }
 
-    Ok((
-        StatusCode::CREATED,
-        Json(json!(crate::db::sea_models::media::slice::MediaPublic {
-            media: stored,
-            file_url,
-        })),
-    ))
+    #[cfg(feature = "image-optimization")]
+    if defer_optimization {
+        // The row is already usable with the original; variants follow once
+        // the queue gets to it. A failed enqueue only costs the variants, which
+        // an admin re-run picks up.
+        let batch_id = format!("upload-{}", stored.id);
+        match MediaOptimizationJob::enqueue_many(
+            &state.sea_db,
+            &batch_id,
+            &[stored.id],
+            Some(uploader.id),
+        )
+        .await
+        {
+            Ok(_) => media_reoptimizer::wake(),
+            Err(err) => {
+                warn!(media_id = stored.id, error = %err, "Failed to queue deferred optimization")
+            }
+        }
+    }
+
+    Ok(Ingested::Created(MediaPublic {
+        media: stored,
+        file_url,
+        poster_url,
+    }))
+}
+
+/// Signature check and metadata probe for video, audio and PDF uploads. These
+/// never reach the `image` crate, so this is their M-7 content check.
+async fn probe_upload(
+    state: &AppState,
+    mime_type: &str,
+    bytes: &Bytes,
+) -> Result<MediaProbe, ErrorResponse> {
+    if !media_probe::matches_signature(mime_type, bytes) {
+        warn!(
+            mime_type,
+            "Rejected upload: contents do not match declared type"
+        );
+        return Err(ErrorResponse::new(ErrorCode::InvalidFileType)
+            .with_message("File contents do not match the declared type"));
+    }
+
+    let mime_type = mime_type.to_string();
+    let bytes = bytes.clone();
+    Ok(run_probe(state, move || media_probe::probe(&mime_type, &bytes)).await)
+}
+
+/// Run a probe on a blocking thread (container parsing is cheap, but PDF
+/// rendering is not) and give its poster a blurhash.
+#[cfg_attr(not(feature = "image-optimization"), allow(unused_variables))]
+async fn run_probe(
+    state: &AppState,
+    probe: impl FnOnce() -> MediaProbe + Send + 'static,
+) -> MediaProbe {
+    #[cfg(feature = "image-optimization")]
+    let optimizer_cfg = state.optimizer.clone();
+    let probed = tokio::task::spawn_blocking(move || {
+        #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
+        let mut probe = probe();
+        #[cfg(feature = "image-optimization")]
+        if let Some(poster) = probe.poster.as_mut() {
+            if poster.blurhash.is_none() {
+                poster.blurhash =
+                    image_optimizer::blurhash_for_bytes(&optimizer_cfg, &poster.bytes);
+            }
+        }
+        probe
+    })
+    .await;
+
+    probed.unwrap_or_else(|err| {
+        warn!(error = %err, "media probe blocking task failed");
+        MediaProbe::default()
+    })
+}
+
+/// Whether `head` starts like an image of type `mime` whose size can be read.
+/// Stands in for the optimizer's decode when an image is too large to buffer.
+fn image_matches_declared(mime: &str, head: &[u8]) -> bool {
+    use imagesize::ImageType;
+
+    let matches = match imagesize::image_type(head) {
+        Ok(ImageType::Jpeg) => matches!(mime, "image/jpeg" | "image/jpg"),
+        Ok(ImageType::Png) => mime == "image/png",
+        Ok(ImageType::Webp) => mime == "image/webp",
+        Ok(ImageType::Gif) => mime == "image/gif",
+        Ok(ImageType::Tiff) => mime == "image/tiff",
+        _ => false,
+    };
+    matches && imagesize::blob_size(head).is_ok()
+}
+
+/// Bytes `range` of a stored upload, which must still be there.
+async fn read_upload_range(
+    state: &AppState,
+    key: &str,
+    range: std::ops::Range<u64>,
+) -> Result<Bytes, ErrorResponse> {
+    state.storage.get_range(key, range).await?.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::FileNotFound)
+            .with_message("Assembled upload is missing from storage")
+    })
+}
+
+/// [`ingest`] for a finished direct upload too large to buffer. The staging
+/// object is hashed as a stream and copied into place within the store;
+/// validation and probing only read its first and last
+/// [`media_probe::SPARSE_WINDOW`] bytes. Images are checked against their
+/// declared type here and left to the optimization queue for variants.
+#[instrument(
+    skip(state, uploader, upload, metadata),
+    fields(user_id = uploader.id, content_hash, is_duplicate, result)
+)]
+async fn ingest_stored(
+    state: &AppState,
+    uploader: &user::Model,
+    upload: &media_upload::Model,
+    mut metadata: MediaUploadMetadata,
+) -> Result<Ingested, ErrorResponse> {
+    let len = u64::try_from(upload.size).unwrap_or(0);
+    let window = media_probe::SPARSE_WINDOW;
+    let head = read_upload_range(state, &upload.object_key, 0..window.min(len)).await?;
+
+    let kind = MediaKind::from_mime(&upload.mime_type).unwrap_or(MediaKind::Image);
+    let content_matches = if kind == MediaKind::Image {
+        image_matches_declared(&upload.mime_type, &head)
+    } else {
+        media_probe::matches_signature(&upload.mime_type, &head)
+    };
+    if !content_matches {
+        warn!(
+            mime_type = %upload.mime_type,
+            "Rejected upload: contents do not match declared type"
+        );
+        return Err(ErrorResponse::new(ErrorCode::InvalidFileType)
+            .with_message("File contents do not match the declared type"));
+    }
+
+    let mut stream = state
+        .storage
+        .get_stream(&upload.object_key)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::FileNotFound)
+                .with_message("Assembled upload is missing from storage")
+        })?;
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = stream.try_next().await? {
+        hasher.update(&chunk);
+    }
+    let content_hash = format!("{:x}", hasher.finalize());
+    tracing::Span::current().record("content_hash", &content_hash);
+
+    if let Some(existing) = find_duplicate(state, uploader, &content_hash).await? {
+        return Ok(Ingested::Duplicate(existing));
+    }
+
+    let probe = if kind == MediaKind::Image {
+        if let Ok(dimensions) = imagesize::blob_size(&head) {
+            metadata.width = metadata
+                .width
+                .or_else(|| i32::try_from(dimensions.width).ok());
+            metadata.height = metadata
+                .height
+                .or_else(|| i32::try_from(dimensions.height).ok());
+        }
+        MediaProbe::default()
+    } else {
+        let tail =
+            read_upload_range(state, &upload.object_key, len.saturating_sub(window)..len).await?;
+        let mime_type = upload.mime_type.clone();
+        run_probe(state, move || {
+            media_probe::probe_sparse(&mime_type, &head, &tail, len)
+        })
+        .await
+    };
+    metadata.width = metadata.width.or(probe.width);
+    metadata.height = metadata.height.or(probe.height);
+
+    let object_key = build_object_key(Some(&upload.extension));
+    state
+        .storage
+        .copy(&upload.object_key, &object_key, &upload.mime_type)
+        .await
+        .map_err(|err| {
+            ErrorResponse::new(ErrorCode::StorageError)
+                .with_message("Failed to persist media to storage")
+                .with_details(err.to_string())
+        })?;
+
+    let new_media = NewMedia {
+        bucket: state.storage.bucket().to_string(),
+        object_key,
+        mime_type: upload.mime_type.clone(),
+        width: metadata.width,
+        height: metadata.height,
+        size: upload.size,
+        extension: Some(upload.extension.clone()),
+        uploader_id: Some(uploader.id),
+        reference_type: metadata.reference_type,
+        content_hash: Some(content_hash),
+        is_optimized: false,
+        optimized_at: None,
+        alt_text: metadata.alt_text.clone(),
+        caption: metadata.caption.clone(),
+        credit: metadata.credit.clone(),
+        license: metadata.license.clone(),
+        focal_x: metadata.focal_point().map(|(x, _)| x),
+        focal_y: metadata.focal_point().map(|(_, y)| y),
+        blurhash: probe
+            .poster
+            .as_ref()
+            .and_then(|poster| poster.blurhash.clone()),
+        duration_ms: probe.duration_ms,
+        page_count: probe.page_count,
+    };
+
+    let stored = Media::create(&state.sea_db, new_media).await?;
+    info!(
+        media_id = stored.id,
+        size = upload.size,
+        "Large upload stored"
+    );
+
+    let mut public = media_public(state, stored);
+    if let Some(poster) = probe.poster {
+        match store_poster(state, &public.media, poster).await {
+            Ok(variant) => public.poster_url = Some(variant_url(state, &public.media, &variant)),
+            Err(err) => {
+                warn!(media_id = public.media.id, error = %err.message, "Failed to store media poster")
+            }
+        }
+    }
+
+    // Variants come from the optimization queue, which reads the original
+    // back from storage off the request path.
+    #[cfg(feature = "image-optimization")]
+    if kind == MediaKind::Image {
+        let batch_id = format!("upload-{}", public.media.id);
+        match MediaOptimizationJob::enqueue_many(
+            &state.sea_db,
+            &batch_id,
+            &[public.media.id],
+            Some(uploader.id),
+        )
+        .await
+        {
+            Ok(_) => media_reoptimizer::wake(),
+            Err(err) => {
+                warn!(media_id = public.media.id, error = %err, "Failed to queue optimization")
+            }
+        }
+    }
+
+    tracing::Span::current().record("result", "created");
+    Ok(Ingested::Created(public))
+}
+
+/// Store `poster` next to `media`'s original and make it the media's only
+/// poster variant. Objects of replaced posters are deleted.
+async fn store_poster(
+    state: &AppState,
+    media: &media::Model,
+    poster: Poster,
+) -> Result<media_variant::Model, ErrorResponse> {
+    let object_key = poster_object_key(&media.object_key, &poster.extension);
+    let size = i64::try_from(poster.bytes.len()).map_err(|_| {
+        ErrorResponse::new(ErrorCode::InvalidValue)
+            .with_message("Poster size exceeds supported range")
+    })?;
+
+    state
+        .storage
+        .put(&object_key, Bytes::from(poster.bytes), &poster.mime_type)
+        .await
+        .map_err(|err| {
+            ErrorResponse::new(ErrorCode::StorageError)
+                .with_message("Failed to persist poster to storage")
+                .with_details(err.to_string())
+        })?;
+
+    let (inserted, previous) = MediaVariant::replace_poster(
+        &state.sea_db,
+        NewMediaVariant {
+            media_id: media.id,
+            object_key,
+            mime_type: poster.mime_type,
+            width: poster.width,
+            height: poster.height,
+            size,
+            extension: Some(poster.extension),
+            quality: None,
+            variant_type: media_variant::POSTER_VARIANT.to_string(),
+        },
+    )
+    .await?;
+
+    for old in previous {
+        if let Err(err) = state.storage.delete(&old.object_key).await {
+            warn!(variant_id = old.id, key = %old.object_key, error = %err, "Failed to delete replaced poster");
+        }
+    }
+
+    Ok(inserted)
+}
+
+fn variant_url(state: &AppState, media: &media::Model, variant: &media_variant::Model) -> String {
+    crate::db::sea_models::media::url::build_public_file_url(
+        &state.object_storage.public_url,
+        media.bucket.as_deref(),
+        &variant.object_key,
+    )
 }
 
 // ── M-5 / M-6 ownership decision helpers ───────────────────────────────────
@@ -581,6 +1013,11 @@ pub async fn view(
                 &media.media.object_key,
             );
 
+            let poster_url = MediaVariant::find_posters(&state.sea_db, &[media.media.id])
+                .await?
+                .remove(&media.media.id)
+                .map(|variant| variant_url(&state, &media.media, &variant));
+
             Ok((
                 StatusCode::OK,
                 Json(json!(
@@ -588,6 +1025,7 @@ pub async fn view(
                         media: media.media,
                         usage_count: media.usage_count,
                         file_url,
+                        poster_url,
                     }
                 )),
             ))
@@ -596,6 +1034,158 @@ pub async fn view(
     }
 }
 
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(media_id))]
+pub async fn update(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(media_id): Path<i32>,
+    payload: ValidatedJson<V1MediaUpdatePayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let editor = auth.user.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::Unauthorized)
+            .with_message("Authentication required to edit media")
+    })?;
+    tracing::Span::current().record("media_id", media_id);
+
+    let update = payload
+        .0
+        .into_update()
+        .map_err(|msg| ErrorResponse::new(ErrorCode::InvalidValue).with_message(&msg))?;
+
+    let media = Media::find_by_id(&state.sea_db, media_id)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::FileNotFound).with_message("Media record not found")
+        })?;
+
+    // Same owner-or-staff rule as delete: system media is staff-only.
+    if !can_delete_media(&editor, media.uploader_id) {
+        return Err(ErrorResponse::new(ErrorCode::OperationNotAllowed)
+            .with_message("You can only edit media you uploaded"));
+    }
+
+    #[cfg(feature = "image-optimization")]
+    let focal_changed = update
+        .focal_point
+        .is_some_and(|focal| focal != media.focal_x.zip(media.focal_y));
+
+    let updated = Media::update_details(&state.sea_db, media, update).await?;
+
+    // Crops are cut around the focal point, so moving it means regenerating
+    // the variants. A failed enqueue leaves the old crops until an admin
+    // re-run.
+    #[cfg(feature = "image-optimization")]
+    if focal_changed && updated.mime_type.starts_with("image/") {
+        match MediaOptimizationJob::enqueue_many(
+            &state.sea_db,
+            &format!("focal-{}", updated.id),
+            &[updated.id],
+            Some(editor.id),
+        )
+        .await
+        {
+            Ok(_) => media_reoptimizer::wake(),
+            Err(err) => {
+                warn!(media_id = updated.id, error = %err, "Failed to queue re-crop after focal point change")
+            }
+        }
+    }
+
+    let body = media_public_with_poster(&state, updated).await?;
+    Ok((StatusCode::OK, Json(json!(body))))
+}
+
+/// Replace the poster of a video, audio or PDF item with an uploaded image.
+/// Video posters arrive this way: the server has no video decoder, so the
+/// client captures a frame and sends it here.
+#[debug_handler]
+#[instrument(skip(state, auth, multipart), fields(media_id))]
+pub async fn set_poster(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(media_id): Path<i32>,
+    mut multipart: ValidatedMultipart,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let editor = auth.user.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::Unauthorized)
+            .with_message("Authentication required to edit media")
+    })?;
+    tracing::Span::current().record("media_id", media_id);
+
+    let media = Media::find_by_id(&state.sea_db, media_id)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::FileNotFound).with_message("Media record not found")
+        })?;
+
+    if !can_delete_media(&editor, media.uploader_id) {
+        return Err(ErrorResponse::new(ErrorCode::OperationNotAllowed)
+            .with_message("You can only edit media you uploaded"));
+    }
+    if matches!(
+        MediaKind::from_mime(&media.mime_type),
+        None | Some(MediaKind::Image)
+    ) {
+        return Err(ErrorResponse::new(ErrorCode::BusinessRuleViolation)
+            .with_message("Only video, audio and PDF media have posters"));
+    }
+
+    let mut upload = None;
+    while let Some(field) = multipart.next_field().await.map_err(|err| {
+        ErrorResponse::new(ErrorCode::ValidationError).with_details(err.to_string())
+    })? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let mime_type = field.content_type().map(|ty| ty.to_string());
+        let file_name = field.file_name().map(|name| name.to_string());
+        let bytes = field.bytes().await.map_err(|err| {
+            ErrorResponse::new(ErrorCode::FileUploadError)
+                .with_message("Failed to read uploaded file")
+                .with_details(err.to_string())
+        })?;
+        upload = Some((mime_type, file_name, bytes));
+    }
+    let (mime_type, file_name, bytes) = upload.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::MissingRequiredField).with_message("Missing file field")
+    })?;
+
+    let (declared_mime, _) = validate_upload(mime_type.as_deref(), file_name.as_deref())
+        .map_err(|msg| ErrorResponse::new(ErrorCode::InvalidFileType).with_message(&msg))?;
+    if MediaKind::from_mime(&declared_mime) != Some(MediaKind::Image) {
+        return Err(
+            ErrorResponse::new(ErrorCode::InvalidFileType).with_message("Poster must be an image")
+        );
+    }
+    #[cfg_attr(not(feature = "image-optimization"), allow(unused_mut))]
+    let mut poster = Poster::from_image_bytes(bytes.to_vec()).ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::InvalidFileType)
+            .with_message("Poster must be a JPEG, PNG or WebP image")
+    })?;
+
+    #[cfg(feature = "image-optimization")]
+    {
+        poster.blurhash = image_optimizer::blurhash_for_bytes(&state.optimizer, &poster.bytes);
+    }
+    let blurhash = poster.blurhash.clone();
+
+    let variant = store_poster(&state, &media, poster).await?;
+    let poster_url = variant_url(&state, &media, &variant);
+    let media = match blurhash {
+        Some(blurhash) => Media::set_blurhash(&state.sea_db, media, blurhash).await?,
+        None => media,
+    };
+
+    Ok((
+        StatusCode::OK,
+        Json(json!(MediaPublic {
+            poster_url: Some(poster_url),
+            ..media_public(&state, media)
+        })),
+    ))
+}
+
 #[debug_handler]
 pub async fn find_with_query(
     State(state): State<AppState>,
@@ -615,6 +1205,8 @@ pub async fn find_with_query(
     let page = query.page.unwrap_or(1);
 
     let (items, total) = Media::find_with_query(&state.sea_db, query).await?;
+    let ids = items.iter().map(|item| item.media.id).collect::<Vec<_>>();
+    let mut posters = MediaVariant::find_posters(&state.sea_db, &ids).await?;
     let data = items
         .into_iter()
         .map(|item| {
@@ -623,10 +1215,14 @@ pub async fn find_with_query(
                 item.media.bucket.as_deref(),
                 &item.media.object_key,
             );
+            let poster_url = posters
+                .remove(&item.media.id)
+                .map(|variant| variant_url(&state, &item.media, &variant));
             crate::db::sea_models::media::slice::MediaWithUsagePublic {
                 media: item.media,
                 usage_count: item.usage_count,
                 file_url,
+                poster_url,
             }
         })
         .collect::<Vec<_>>();
@@ -642,6 +1238,43 @@ pub async fn find_with_query(
     ))
 }
 
+/// Ready-to-render `<picture>` data (srcset per format, sizes, blurhash) for a
+/// batch of media ids. Ids the caller may not view are silently omitted.
+#[debug_handler]
+pub async fn responsive(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1MediaResponsivePayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let caller = auth.user.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::Unauthorized)
+            .with_message("Authentication required to view media")
+    })?;
+
+    let payload = payload.0;
+    let media_records = Media::find_by_ids(&state.sea_db, &payload.media_ids)
+        .await?
+        .into_iter()
+        .filter(|media| can_view_media(&caller, media.uploader_id))
+        .collect::<Vec<_>>();
+    let ids = media_records.iter().map(|m| m.id).collect::<Vec<_>>();
+    let variants = MediaVariant::find_by_media_ids(&state.sea_db, &ids).await?;
+
+    let data = media_records
+        .iter()
+        .map(|media| {
+            responsive::build(
+                &state.object_storage.public_url,
+                media,
+                &variants,
+                payload.sizes.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(json!({ "data": data }))))
+}
+
 #[debug_handler]
 pub async fn list_usage_details(
     State(state): State<AppState>,
@@ -822,6 +1455,7 @@ pub async fn delete(
     State(state): State<AppState>,
     auth: AuthSession,
     Path(media_id): Path<i32>,
+    Query(params): Query<V1MediaDeleteParams>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
     let uploader = auth.user.ok_or_else(|| {
         ErrorResponse::new(ErrorCode::Unauthorized)
@@ -851,23 +1485,21 @@ pub async fn delete(
         );
     }
 
-    state
-        .s3_client
-        .delete_object()
-        .bucket(
-            media
-                .bucket
-                .as_deref()
-                .unwrap_or(state.object_storage.bucket.as_str()),
-        )
-        .key(&media.object_key)
-        .send()
-        .await
-        .map_err(|err| {
-            ErrorResponse::new(ErrorCode::FileDeletionError)
-                .with_message("Failed to delete media from storage")
-                .with_details(err.to_string())
-        })?;
+    // Refuse to pull an image out from under a post, avatar or category
+    // unless the caller explicitly opts into cascading. The FKs on those
+    // columns are `ON DELETE SET NULL` and `media_usage` cascades, so removing
+    // the row is enough to detach them.
+    let references = Media::find_references(&state.sea_db, media_id).await?;
+    if !references.is_empty() && !params.cascade {
+        return Err(ErrorResponse::new(ErrorCode::DependencyExists)
+            .with_message("Media is still in use; pass cascade=true to detach and delete")
+            .with_context(json!({
+                "media_id": media.id,
+                "references": references,
+            })));
+    }
+
+    media_gc::delete_stored_objects(&state, &media).await?;
 
     Media::delete_by_id(&state.sea_db, media_id).await?;
 
@@ -876,10 +1508,586 @@ pub async fn delete(
         Json(json!({
             "message": "Media deleted",
             "media_id": media.id,
+            "detached_references": references.len(),
         })),
     ))
 }
 
+/// Serve an object written by the local storage driver at
+/// `/media/{bucket}/{key}`. Returns 404 when another driver is configured.
+/// A single `Range` is honoured so video and audio can seek.
+#[debug_handler]
+pub async fn serve_file(
+    State(state): State<AppState>,
+    Path(path): Path<String>,
+    Query(query): Query<SignedQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ErrorResponse> {
+    let not_found = || ErrorResponse::new(ErrorCode::FileNotFound);
+    let store = state.storage.as_local().ok_or_else(not_found)?;
+    let (bucket, key) = path.split_once('/').ok_or_else(not_found)?;
+
+    if !store.authorize(bucket, key, &query, Utc::now()) {
+        return Err(ErrorResponse::new(ErrorCode::InvalidToken)
+            .with_message("Missing or invalid media signature"));
+    }
+
+    let blob = store.get(key).await?.ok_or_else(not_found)?;
+    // Object keys are content-unique (uuid per upload), so unsigned URLs can
+    // be cached forever; signed ones must not outlive their expiry.
+    let cache_control = if query.sig.is_some() {
+        "private, max-age=300"
+    } else {
+        "public, max-age=31536000, immutable"
+    };
+
+    let common = [
+        (header::CONTENT_TYPE, blob.content_type),
+        (header::CACHE_CONTROL, cache_control.to_string()),
+        (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        (header::ACCEPT_RANGES, "bytes".to_string()),
+    ];
+    // SVG can carry script; opened directly it would run on the API origin
+    // with its cookies. `<img>` embeds ignore both headers.
+    let sandbox = scriptable_content_type(&common[0].1).then_some([
+        (header::CONTENT_SECURITY_POLICY, "sandbox"),
+        (header::CONTENT_DISPOSITION, "attachment"),
+    ]);
+    let total = blob.bytes.len();
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_byte_range(value, total));
+
+    Ok(match range {
+        None => (common, sandbox, blob.bytes).into_response(),
+        Some(Ok((start, end))) => (
+            StatusCode::PARTIAL_CONTENT,
+            common,
+            sandbox,
+            [(
+                header::CONTENT_RANGE,
+                format!("bytes {}-{}/{}", start, end, total),
+            )],
+            blob.bytes.slice(start..=end),
+        )
+            .into_response(),
+        Some(Err(())) => (
+            StatusCode::RANGE_NOT_SATISFIABLE,
+            [(header::CONTENT_RANGE, format!("bytes */{}", total))],
+        )
+            .into_response(),
+    })
+}
+
+/// Content types a browser would execute script from if opened directly.
+fn scriptable_content_type(content_type: &str) -> bool {
+    let essence = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    matches!(
+        essence.as_str(),
+        "image/svg+xml" | "text/html" | "application/xhtml+xml" | "text/xml" | "application/xml"
+    )
+}
+
+/// Inclusive byte span requested by a `Range` header over a `len`-byte body.
+/// `None` means serve the whole body: no usable range, or several ranges
+/// (rare, and allowed to be ignored). `Some(Err(()))` is unsatisfiable.
+fn parse_byte_range(value: &str, len: usize) -> Option<Result<(usize, usize), ()>> {
+    let spec = value.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    let span = if start.is_empty() {
+        // Suffix range: the last `end` bytes.
+        let suffix: usize = end.parse().ok()?;
+        if suffix == 0 || len == 0 {
+            return Some(Err(()));
+        }
+        (len.saturating_sub(suffix), len - 1)
+    } else {
+        let start: usize = start.parse().ok()?;
+        let end = if end.is_empty() {
+            len.saturating_sub(1)
+        } else {
+            let end: usize = end.parse().ok()?;
+            if end < start {
+                return None;
+            }
+            end.min(len.saturating_sub(1))
+        };
+        if start >= len {
+            return Some(Err(()));
+        }
+        (start, end)
+    };
+    Some(Ok(span))
+}
+
+/// Run a media garbage-collection pass on demand. Defaults to a dry run that
+/// only reports orphaned storage keys and unreferenced media.
+#[debug_handler]
+#[instrument(skip(state, payload))]
+pub async fn run_gc(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<V1MediaGcPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let defaults = media_gc::MediaGcConfig::default();
+    let options = media_gc::MediaGcOptions {
+        dry_run: payload.dry_run.unwrap_or(true),
+        min_age_hours: payload.min_age_hours.unwrap_or(defaults.min_age_hours),
+        limit: payload.limit.unwrap_or(defaults.batch_limit),
+    };
+
+    let report = media_gc::run_once(&state, options).await?;
+    info!(
+        dry_run = report.dry_run,
+        orphaned = report.orphaned_keys.len(),
+        unreferenced = report.unreferenced_media_ids.len(),
+        expired_uploads = report.expired_upload_ids.len(),
+        deleted_media = report.deleted_media,
+        "Manual media GC run"
+    );
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+/// Queue background (re)optimization for every image matching the filter,
+/// e.g. after changing optimizer quality or widths. Returns the batch id to
+/// poll with `/optimize/status`.
+#[cfg(feature = "image-optimization")]
+#[debug_handler]
+#[instrument(skip(state, auth, payload))]
+pub async fn run_optimize(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    ValidatedJson(payload): ValidatedJson<V1MediaOptimizePayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let limit = payload.limit.unwrap_or(DEFAULT_OPTIMIZE_LIMIT);
+    let media_ids =
+        Media::find_ids_for_optimization(&state.sea_db, payload.into_filter(), limit).await?;
+
+    let batch_id = Uuid::new_v4().to_string();
+    let requested_by = auth.user.as_ref().map(|u| u.id);
+    let queued =
+        MediaOptimizationJob::enqueue_many(&state.sea_db, &batch_id, &media_ids, requested_by)
+            .await?;
+    if queued > 0 {
+        media_reoptimizer::wake();
+    }
+    info!(batch_id = %batch_id, matched = media_ids.len(), queued, "Media optimization batch queued");
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(json!({
+            "batch_id": batch_id,
+            "matched": media_ids.len(),
+            "queued": queued,
+        })),
+    ))
+}
+
+/// Progress of one optimization batch, or of the whole queue when no
+/// `batch_id` is given.
+#[cfg(feature = "image-optimization")]
+#[debug_handler]
+pub async fn optimize_status(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<V1MediaOptimizeStatusPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let progress =
+        MediaOptimizationJob::progress(&state.sea_db, payload.batch_id.as_deref()).await?;
+    let percent_done = progress.percent_done();
+    let finished = progress.is_finished();
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "progress": progress,
+            "percent_done": percent_done,
+            "finished": finished,
+        })),
+    ))
+}
+
+// ── Direct-to-storage uploads ──────────────────────────────────────────────
+//
+// Files above the `/create` body limit are pushed by the client straight to
+// the object store in `MEDIA_UPLOAD_PART`-sized parts through presigned URLs.
+// The session row keeps the staging key and provider upload id so an
+// interrupted upload can ask which parts landed and carry on. `complete`
+// assembles the parts and runs the assembled object through the same
+// `ingest` pipeline as `/create`, so dedup, optimization and the final key
+// layout are identical for both paths.
+
+/// Load an upload session owned by `uploader`.
+async fn find_owned_upload(
+    state: &AppState,
+    uploader: &user::Model,
+    upload_id: i32,
+) -> Result<media_upload::Model, ErrorResponse> {
+    let upload = MediaUpload::find_by_id(&state.sea_db, upload_id)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Upload session not found")
+        })?;
+
+    if upload.uploader_id != uploader.id {
+        return Err(ErrorResponse::new(ErrorCode::OperationNotAllowed)
+            .with_message("You can only manage your own uploads"));
+    }
+    Ok(upload)
+}
+
+/// Like [`find_owned_upload`], but the session must still accept parts.
+async fn find_pending_upload(
+    state: &AppState,
+    uploader: &user::Model,
+    upload_id: i32,
+) -> Result<media_upload::Model, ErrorResponse> {
+    let upload = find_owned_upload(state, uploader, upload_id).await?;
+    if upload.status != MediaUploadStatus::Pending {
+        return Err(ErrorResponse::new(ErrorCode::ResourceConflict)
+            .with_message(format!("Upload is already {}", upload.status)));
+    }
+    if upload.is_expired(Utc::now().fixed_offset()) {
+        return Err(ErrorResponse::new(ErrorCode::SessionExpired)
+            .with_message("Upload session has expired; start a new upload"));
+    }
+    Ok(upload)
+}
+
+#[allow(clippy::result_large_err)]
+fn require_uploader(auth: AuthSession) -> Result<user::Model, ErrorResponse> {
+    auth.user.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::Unauthorized)
+            .with_message("Authentication required to upload media")
+    })
+}
+
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id, size = payload.size))]
+pub async fn upload_init(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    ValidatedJson(payload): ValidatedJson<V1MediaUploadInitPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let uploader = require_uploader(auth)?;
+    tracing::Span::current().record("user_id", uploader.id);
+
+    let (mime_type, extension) =
+        validate_upload(payload.mime_type.as_deref(), Some(&payload.filename)).map_err(|msg| {
+            warn!(error = %msg, "Rejected direct upload: file type not on allowlist");
+            ErrorResponse::new(ErrorCode::InvalidFileType).with_message(&msg)
+        })?;
+
+    // Every allowlisted MIME maps to a kind; fall back to the image cap if
+    // that ever stops holding.
+    let kind = MediaKind::from_mime(&mime_type).unwrap_or(MediaKind::Image);
+    let max_size = max_upload_size(kind);
+    if payload.size > max_size as i64 {
+        return Err(
+            ErrorResponse::new(ErrorCode::FileTooLarge).with_message(format!(
+                "File size exceeds the {}MiB limit for {} uploads",
+                max_size / 1024 / 1024,
+                kind
+            )),
+        );
+    }
+
+    // M-5: as in `create`, only an owner or staff gets the existing record
+    // back. The final dedup check runs again on the real bytes at completion.
+    if let Some(hash) = payload.content_hash.as_deref() {
+        if let Some(existing) = Media::find_by_hash(&state.sea_db, hash).await? {
+            if can_view_media(&uploader, existing.uploader_id) {
+                info!(
+                    media_id = existing.id,
+                    "Direct upload matches existing media"
+                );
+                return Ok((
+                    StatusCode::OK,
+                    Json(json!({
+                        "duplicate": true,
+                        "media": media_public_with_poster(&state, existing).await?,
+                    })),
+                ));
+            }
+        }
+    }
+
+    let object_key = build_staging_key(&extension);
+    let provider_upload_id = state
+        .storage
+        .create_multipart(&object_key, &mime_type)
+        .await?;
+
+    let part_size = config::body_limits::MEDIA_UPLOAD_PART as i64;
+    let upload = MediaUpload::create(
+        &state.sea_db,
+        NewMediaUpload {
+            uploader_id: uploader.id,
+            object_key,
+            provider_upload_id,
+            filename: Some(payload.filename),
+            mime_type,
+            extension,
+            size: payload.size,
+            part_size,
+            part_count: part_count_for(payload.size, part_size),
+            content_hash: payload.content_hash.map(|h| h.to_ascii_lowercase()),
+            reference_type: payload.reference_type,
+            width: payload.width,
+            height: payload.height,
+            expires_at: (Utc::now() + chrono::Duration::hours(UPLOAD_SESSION_TTL_HOURS))
+                .fixed_offset(),
+        },
+    )
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(json!({ "duplicate": false, "upload": upload })),
+    ))
+}
+
+/// Presign URLs for the requested part numbers of a pending upload.
+#[debug_handler]
+pub async fn upload_parts(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(upload_id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<V1MediaUploadPartsPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let uploader = require_uploader(auth)?;
+    let upload = find_pending_upload(&state, &uploader, upload_id).await?;
+
+    let part_numbers: BTreeSet<i32> = payload.part_numbers.into_iter().collect();
+    if let Some(invalid) = part_numbers
+        .iter()
+        .find(|n| !(1..=upload.part_count).contains(*n))
+    {
+        return Err(
+            ErrorResponse::new(ErrorCode::InvalidValue).with_message(format!(
+                "Part {} is outside 1..={}",
+                invalid, upload.part_count
+            )),
+        );
+    }
+
+    let mut parts = Vec::with_capacity(part_numbers.len());
+    for part_number in part_numbers {
+        let url = state
+            .storage
+            .presign_part(
+                &upload.object_key,
+                &upload.provider_upload_id,
+                part_number,
+                PART_URL_TTL,
+            )
+            .await?;
+        parts.push(json!({ "part_number": part_number, "url": url }));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "parts": parts,
+            "expires_in": PART_URL_TTL.as_secs(),
+        })),
+    ))
+}
+
+/// Report which parts have landed so an interrupted client can resume.
+#[debug_handler]
+pub async fn upload_status(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(upload_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let uploader = require_uploader(auth)?;
+    let upload = find_owned_upload(&state, &uploader, upload_id).await?;
+
+    let uploaded = if upload.status == MediaUploadStatus::Pending {
+        state
+            .storage
+            .list_parts(&upload.object_key, &upload.provider_upload_id)
+            .await?
+    } else {
+        Vec::new()
+    };
+    let missing = if upload.status == MediaUploadStatus::Pending {
+        missing_parts(upload.part_count, &uploaded)
+    } else {
+        Vec::new()
+    };
+    let uploaded: Vec<_> = uploaded
+        .iter()
+        .map(|part| json!({ "part_number": part.part_number, "size": part.size }))
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "upload": upload,
+            "uploaded_parts": uploaded,
+            "missing_parts": missing,
+        })),
+    ))
+}
+
+/// Assemble the uploaded parts and turn them into a media row.
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id))]
+pub async fn upload_complete(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(upload_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let uploader = require_uploader(auth)?;
+    tracing::Span::current().record("user_id", uploader.id);
+    let upload = find_pending_upload(&state, &uploader, upload_id).await?;
+
+    // The part list comes from the store, not the client, so ETags can't be
+    // forged and every declared part must actually be there.
+    let parts = state
+        .storage
+        .list_parts(&upload.object_key, &upload.provider_upload_id)
+        .await?;
+    let missing = missing_parts(upload.part_count, &parts);
+    if !missing.is_empty() {
+        return Err(ErrorResponse::new(ErrorCode::ValidationError)
+            .with_message("Upload is missing parts")
+            .with_context(json!({ "missing_parts": missing })));
+    }
+    let parts: Vec<_> = parts
+        .into_iter()
+        .filter(|part| part.part_number <= upload.part_count)
+        .collect();
+
+    let total: i64 = parts.iter().map(|part| part.size).sum();
+    if total != upload.size {
+        return Err(ErrorResponse::new(ErrorCode::ValidationError)
+            .with_message("Uploaded size does not match the declared size")
+            .with_context(json!({ "declared": upload.size, "received": total })));
+    }
+
+    state
+        .storage
+        .complete_multipart(&upload.object_key, &upload.provider_upload_id, &parts)
+        .await?;
+
+    let metadata = MediaUploadMetadata {
+        reference_type: upload.reference_type,
+        width: upload.width,
+        height: upload.height,
+        ..Default::default()
+    };
+    let buffered =
+        usize::try_from(upload.size).is_ok_and(|size| size <= config::body_limits::MEDIA_BUFFERED);
+    let result = if buffered {
+        match state.storage.get(&upload.object_key).await {
+            Ok(Some(blob)) => {
+                ingest(
+                    &state,
+                    &uploader,
+                    blob.bytes,
+                    upload.mime_type.clone(),
+                    upload.extension.clone(),
+                    Some(upload.mime_type.clone()),
+                    metadata,
+                )
+                .await
+            }
+            Ok(None) => Err(ErrorResponse::new(ErrorCode::FileNotFound)
+                .with_message("Assembled upload is missing from storage")),
+            Err(err) => Err(err.into()),
+        }
+    } else {
+        ingest_stored(&state, &uploader, &upload, metadata).await
+    };
+
+    // Both ingest paths write the media under its own key, so the staging object is
+    // dropped either way. The multipart upload no longer exists after
+    // `complete_multipart`, so a failed ingest can't be retried on this
+    // session.
+    if let Err(err) = state.storage.delete(&upload.object_key).await {
+        warn!(upload_id, key = %upload.object_key, error = %err, "Failed to delete staging object");
+    }
+
+    match result {
+        Ok(ingested) => {
+            MediaUpload::finish(
+                &state.sea_db,
+                upload,
+                MediaUploadStatus::Completed,
+                Some(ingested.media_id()),
+            )
+            .await?;
+            Ok(ingested.into_response())
+        }
+        Err(err) => {
+            MediaUpload::finish(&state.sea_db, upload, MediaUploadStatus::Aborted, None).await?;
+            Err(err)
+        }
+    }
+}
+
+#[debug_handler]
+pub async fn upload_abort(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(upload_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let uploader = require_uploader(auth)?;
+    let upload = find_owned_upload(&state, &uploader, upload_id).await?;
+    if upload.status != MediaUploadStatus::Pending {
+        return Err(ErrorResponse::new(ErrorCode::ResourceConflict)
+            .with_message(format!("Upload is already {}", upload.status)));
+    }
+
+    state
+        .storage
+        .abort_multipart(&upload.object_key, &upload.provider_upload_id)
+        .await?;
+    MediaUpload::finish(&state.sea_db, upload, MediaUploadStatus::Aborted, None).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "message": "Upload aborted", "upload_id": upload_id })),
+    ))
+}
+
+/// Receive one part of a direct upload at `/media/_parts/{upload_id}/{part}`.
+/// Only the local driver needs this; under S3 the client PUTs to the
+/// presigned S3 URL and this route 404s.
+#[debug_handler]
+pub async fn put_part(
+    State(state): State<AppState>,
+    Path((upload_id, part_number)): Path<(String, i32)>,
+    Query(query): Query<SignedQuery>,
+    body: Bytes,
+) -> Result<Response, ErrorResponse> {
+    let store = state
+        .storage
+        .as_local()
+        .ok_or_else(|| ErrorResponse::new(ErrorCode::FileNotFound))?;
+
+    if !store.authorize_part(&upload_id, part_number, &query, Utc::now()) {
+        return Err(ErrorResponse::new(ErrorCode::InvalidToken)
+            .with_message("Missing or invalid upload signature"));
+    }
+
+    let etag = store.write_part(&upload_id, part_number, body).await?;
+    Ok(([(header::ETAG, etag)], StatusCode::OK).into_response())
+}
+
 /// Derive the stored object-key extension from a filename/MIME hint, applying
 /// the M-7 allowlist. Any client-supplied extension that is not on the list is
 /// stripped; if the filename yields nothing usable we fall back to the MIME
@@ -915,13 +2123,30 @@ fn build_object_key(extension: Option<&str>) -> String {
     }
 }
 
-#[cfg(feature = "image-optimization")]
-fn label_to_variant_type(label: &image_optimizer::VariantLabel) -> String {
-    match label {
-        image_optimizer::VariantLabel::Width(width) => format!("{}w", width),
-        image_optimizer::VariantLabel::Lqip => "lqip".to_string(),
-        image_optimizer::VariantLabel::Original => "original".to_string(),
-    }
+/// Key for a poster of the object at `object_key`: `{base}@poster-{id}.{ext}`.
+/// Each poster gets a fresh key so a replacement is never served from a stale
+/// cache.
+fn poster_object_key(object_key: &str, extension: &str) -> String {
+    let base = object_key
+        .rsplit_once('.')
+        .map(|(prefix, _)| prefix)
+        .unwrap_or(object_key);
+    let id = Uuid::new_v4().simple().to_string();
+    format!("{}@poster-{}.{}", base, &id[..8], extension)
+}
+
+/// Staging key for a direct upload. Kept outside `media/` so media GC's
+/// orphan scan never races an in-flight upload; expired sessions are cleaned
+/// up through their `media_uploads` row instead.
+fn build_staging_key(extension: &str) -> String {
+    let now = Utc::now();
+    format!(
+        "uploads/{}/{:02}/{}.{}",
+        now.year(),
+        now.month(),
+        Uuid::new_v4(),
+        extension
+    )
 }
 
 #[cfg(test)]
@@ -944,6 +2169,10 @@ mod tests {
             email: format!("user-{id}@example.com"),
             password: None,
             avatar_id: None,
+            bio: None,
+            website_url: None,
+            social_links: serde_json::json!([]),
+            profile_slug: None,
             is_verified: true,
             role,
             two_fa_enabled: false,
@@ -1020,6 +2249,60 @@ mod tests {
         assert!(can_delete_media(&admin, Some(7)));
     }
 
+    // ── file serving and stored uploads ───────────────────────────────────
+
+    #[test]
+    fn parse_byte_range_handles_open_suffix_and_clamped_ranges() {
+        assert_eq!(parse_byte_range("bytes=0-99", 1000), Some(Ok((0, 99))));
+        assert_eq!(parse_byte_range("bytes=900-", 1000), Some(Ok((900, 999))));
+        assert_eq!(parse_byte_range("bytes=-100", 1000), Some(Ok((900, 999))));
+        assert_eq!(parse_byte_range("bytes=-5000", 1000), Some(Ok((0, 999))));
+        assert_eq!(
+            parse_byte_range("bytes=500-5000", 1000),
+            Some(Ok((500, 999)))
+        );
+    }
+
+    #[test]
+    fn parse_byte_range_rejects_or_ignores_unusable_ranges() {
+        assert_eq!(parse_byte_range("bytes=1000-", 1000), Some(Err(())));
+        assert_eq!(parse_byte_range("bytes=-0", 1000), Some(Err(())));
+        // Malformed, inverted, multi-range and non-byte units serve the body.
+        assert_eq!(parse_byte_range("bytes=abc", 1000), None);
+        assert_eq!(parse_byte_range("bytes=50-10", 1000), None);
+        assert_eq!(parse_byte_range("bytes=0-1,5-9", 1000), None);
+        assert_eq!(parse_byte_range("items=0-1", 1000), None);
+    }
+
+    #[test]
+    fn stored_images_must_parse_as_their_declared_type() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        png.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        assert!(image_matches_declared("image/png", &png));
+        assert!(!image_matches_declared("image/jpeg", &png));
+        assert!(!image_matches_declared("image/png", &png[..12]));
+        assert!(!image_matches_declared("image/png", b"<html><script>"));
+    }
+
+    #[test]
+    fn svg_and_markup_are_served_sandboxed() {
+        assert!(scriptable_content_type("image/svg+xml"));
+        assert!(scriptable_content_type("Text/HTML; charset=utf-8"));
+        assert!(!scriptable_content_type("image/png"));
+        assert!(!scriptable_content_type("application/octet-stream"));
+    }
+
+    #[test]
+    fn poster_object_key_sits_beside_the_original() {
+        let key = poster_object_key("media/2026/07/abc.mp4", "jpg");
+        assert!(key.starts_with("media/2026/07/abc@poster-"));
+        assert!(key.ends_with(".jpg"));
+        assert_ne!(key, poster_object_key("media/2026/07/abc.mp4", "jpg"));
+    }
+
     // ── M-7: extension inference is allowlist-backed ──────────────────────
 
     #[test]
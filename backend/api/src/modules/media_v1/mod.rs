@@ -8,15 +8,39 @@ use crate::{config, middlewares::auth_guard, AppState};
 pub fn routes() -> Router<AppState> {
     let media_limited = Router::<AppState>::new()
         .route("/create", post(controller::create))
+        .route("/poster/{media_id}", post(controller::set_poster))
         .layer(DefaultBodyLimit::max(config::body_limits::MEDIA));
 
+    let admin = Router::<AppState>::new().route("/gc/run", post(controller::run_gc));
+    #[cfg(feature = "image-optimization")]
+    let admin = admin
+        .route("/optimize/run", post(controller::run_optimize))
+        .route("/optimize/status", post(controller::optimize_status));
+    let admin = admin.route_layer(middleware::from_fn(
+        auth_guard::verified_with_role::<{ auth_guard::ROLE_ADMIN }>,
+    ));
+
     Router::<AppState>::new()
         .route("/view/{media_id}", post(controller::view))
         .route("/list/query", post(controller::find_with_query))
         .route("/usage/details", post(controller::list_usage_details))
+        .route("/responsive", post(controller::responsive))
+        .route("/update/{media_id}", post(controller::update))
         .route("/delete/{media_id}", post(controller::delete))
+        .route("/upload/init", post(controller::upload_init))
+        .route("/upload/{upload_id}/parts", post(controller::upload_parts))
+        .route(
+            "/upload/{upload_id}/status",
+            post(controller::upload_status),
+        )
+        .route(
+            "/upload/{upload_id}/complete",
+            post(controller::upload_complete),
+        )
+        .route("/upload/{upload_id}/abort", post(controller::upload_abort))
         .merge(media_limited)
         .route_layer(middleware::from_fn(
             auth_guard::verified_with_role::<{ auth_guard::ROLE_AUTHOR }>,
         ))
+        .merge(admin)
 }
@@ -1,8 +1,12 @@
 use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-use crate::db::sea_models::media::{MediaQuery, MediaReference};
+use crate::config;
+use crate::db::sea_models::media::{
+    MediaKind, MediaOptimizationFilter, MediaQuery, MediaReference, UpdateMediaDetails,
+};
+use crate::services::storage::UploadedPart;
 use crate::utils::SortParam;
 
 // ── M-7: MIME + extension allowlist ────────────────────────────────────────
@@ -13,11 +17,16 @@ use crate::utils::SortParam;
 // compiled with only jpeg/png/webp/tiff decoders, so an svg is never decoded
 // and its bytes pass straight through.
 //
-// These helpers define the single source of truth for accepted image types.
+// These helpers define the single source of truth for accepted file types.
 // `validate_upload` is called from the controller before any bytes are hashed
 // or persisted; `allowlisted_extension` is used by `infer_extension` so a
 // client-supplied extension that is not on the list is dropped rather than
 // baked into the stored object key.
+//
+// Video, audio and PDF uploads are stored verbatim as well, so ingest also
+// checks their leading bytes against the declared container
+// (`media_probe::matches_signature`); an HTML page renamed to `.mp4` is
+// rejected instead of being served back under a media type.
 
 /// Accepted MIME types (lowercase, without parameters). Anything else — most
 /// importantly `image/svg+xml` — is rejected at upload time.
@@ -28,11 +37,18 @@ pub const ALLOWED_MIME_TYPES: &[&str] = &[
     "image/webp",
     "image/gif",
     "image/tiff",
+    "video/mp4",
+    "video/webm",
+    "audio/mpeg",
+    "audio/ogg",
+    "application/pdf",
 ];
 
 /// Accepted file extensions (lowercase, no leading dot). A client-supplied
 /// extension that is not in this set is stripped from the stored key.
-pub const ALLOWED_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "gif", "tiff"];
+pub const ALLOWED_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "webp", "gif", "tiff", "mp4", "webm", "mp3", "ogg", "pdf",
+];
 
 /// Returns true if `mime` (case-insensitive, parameters stripped) is on the
 /// allowlist.
@@ -105,13 +121,16 @@ pub fn validate_upload(
         }
     };
 
-    // Extension: prefer an allowlisted filename extension; else derive from MIME.
+    // Extension: prefer an allowlisted filename extension of the same kind as
+    // the MIME (a `.mp4` name on an `image/png` body is not kept); else derive
+    // from MIME.
+    let kind = MediaKind::from_mime(&normalized_mime);
     let extension = filename
         .and_then(|n| n.rsplit_once('.'))
         .map(|(_, e)| e.trim().to_ascii_lowercase())
         .filter(|e| !e.is_empty())
         .and_then(|e| {
-            if ALLOWED_EXTENSIONS.iter().any(|a| *a == e) {
+            if ALLOWED_EXTENSIONS.iter().any(|a| *a == e) && MediaKind::from_extension(&e) == kind {
                 Some(e)
             } else {
                 None
@@ -129,6 +148,11 @@ fn mime_for_extension(ext: &str) -> &'static str {
         "webp" => "image/webp",
         "gif" => "image/gif",
         "tiff" => "image/tiff",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        "pdf" => "application/pdf",
         _ => "application/octet-stream",
     }
 }
@@ -140,15 +164,42 @@ fn extension_for_mime(mime: &str) -> &'static str {
         "image/webp" => "webp",
         "image/gif" => "gif",
         "image/tiff" => "tiff",
+        "video/mp4" => "mp4",
+        "video/webm" => "webm",
+        "audio/mpeg" => "mp3",
+        "audio/ogg" => "ogg",
+        "application/pdf" => "pdf",
         _ => "bin",
     }
 }
 
+/// Largest accepted upload of `kind` through the direct upload flow. The
+/// multipart `create` route is additionally capped by its body limit.
+pub fn max_upload_size(kind: MediaKind) -> usize {
+    match kind {
+        MediaKind::Image => config::body_limits::MEDIA_IMAGE,
+        MediaKind::Video => config::body_limits::MEDIA_VIDEO,
+        MediaKind::Audio => config::body_limits::MEDIA_AUDIO,
+        MediaKind::Document => config::body_limits::MEDIA_DOCUMENT,
+    }
+}
+
+pub const MAX_ALT_TEXT_LEN: u64 = 500;
+pub const MAX_CAPTION_LEN: u64 = 2000;
+pub const MAX_CREDIT_LEN: u64 = 255;
+pub const MAX_LICENSE_LEN: u64 = 255;
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize, Validate)]
 pub struct MediaUploadMetadata {
     pub reference_type: Option<MediaReference>,
     pub width: Option<i32>,
     pub height: Option<i32>,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub license: Option<String>,
+    pub focal_x: Option<f64>,
+    pub focal_y: Option<f64>,
 }
 
 impl MediaUploadMetadata {
@@ -185,11 +236,52 @@ impl MediaUploadMetadata {
                     );
                 }
             }
+            "alt_text" => self.alt_text = descriptive_text(name, value, MAX_ALT_TEXT_LEN)?,
+            "caption" => self.caption = descriptive_text(name, value, MAX_CAPTION_LEN)?,
+            "credit" => self.credit = descriptive_text(name, value, MAX_CREDIT_LEN)?,
+            "license" => self.license = descriptive_text(name, value, MAX_LICENSE_LEN)?,
+            "focal_x" => self.focal_x = focal_coordinate(name, value)?,
+            "focal_y" => self.focal_y = focal_coordinate(name, value)?,
             _ => {}
         }
 
         Ok(())
     }
+
+    /// The focal point, only when both coordinates were supplied.
+    pub fn focal_point(&self) -> Option<(f64, f64)> {
+        match (self.focal_x, self.focal_y) {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None,
+        }
+    }
+}
+
+/// Trimmed descriptive text; blank clears the field.
+fn descriptive_text(name: &str, value: &str, max: u64) -> Result<Option<String>, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    if trimmed.chars().count() as u64 > max {
+        return Err(format!("{} must be at most {} characters", name, max));
+    }
+    Ok(Some(trimmed.to_string()))
+}
+
+/// A normalized focal coordinate in `0..=1`; blank clears it.
+fn focal_coordinate(name: &str, value: &str) -> Result<Option<f64>, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    match trimmed.parse::<f64>() {
+        Ok(parsed) if (0.0..=1.0).contains(&parsed) => Ok(Some(parsed)),
+        _ => Err(format!(
+            "Invalid {}: expected a number between 0 and 1",
+            name
+        )),
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
@@ -245,6 +337,179 @@ pub struct V1MediaUsageQuery {
     pub media_ids: Vec<i32>,
 }
 
+/// Maximum media ids accepted by a single `/responsive` lookup.
+pub const MAX_RESPONSIVE_IDS: u64 = 50;
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1MediaResponsivePayload {
+    #[validate(length(
+        min = 1,
+        max = MAX_RESPONSIVE_IDS,
+        message = "media_ids must contain between 1 and 50 ids"
+    ))]
+    pub media_ids: Vec<i32>,
+    /// Optional `sizes` attribute; defaults to the article column layout.
+    #[validate(length(max = 255))]
+    pub sizes: Option<String>,
+}
+
+/// Query string for `/delete/{media_id}`. Without `cascade=true` the delete is
+/// refused while anything still references the media.
+#[derive(Debug, Default, Deserialize)]
+pub struct V1MediaDeleteParams {
+    #[serde(default)]
+    pub cascade: bool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1MediaGcPayload {
+    pub dry_run: Option<bool>,
+    #[validate(range(min = 1, max = 8760, message = "min_age_hours must be 1..=8760"))]
+    pub min_age_hours: Option<u64>,
+    #[validate(range(min = 1, max = 5000, message = "limit must be 1..=5000"))]
+    pub limit: Option<u64>,
+}
+
+/// Media picked up by one `/optimize/run` call when no `limit` is given.
+pub const DEFAULT_OPTIMIZE_LIMIT: u64 = 1000;
+
+/// Filter for a background (re)optimization run. All conditions are ANDed;
+/// an empty payload selects every image.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1MediaOptimizePayload {
+    #[validate(length(min = 1, max = 5000, message = "media_ids must contain 1..=5000 ids"))]
+    pub media_ids: Option<Vec<i32>>,
+    pub reference_type: Option<MediaReference>,
+    #[validate(length(max = 100))]
+    pub mime_type: Option<String>,
+    #[serde(default)]
+    pub only_unoptimized: bool,
+    pub optimized_before: Option<DateTimeWithTimeZone>,
+    pub created_at_gt: Option<DateTimeWithTimeZone>,
+    pub created_at_lt: Option<DateTimeWithTimeZone>,
+    #[validate(range(min = 1, max = 10000, message = "limit must be 1..=10000"))]
+    pub limit: Option<u64>,
+}
+
+impl V1MediaOptimizePayload {
+    pub fn into_filter(self) -> MediaOptimizationFilter {
+        MediaOptimizationFilter {
+            media_ids: self.media_ids,
+            reference_type: self.reference_type,
+            mime_type: self
+                .mime_type
+                .map(|m| m.trim().to_string())
+                .filter(|m| !m.is_empty()),
+            only_unoptimized: self.only_unoptimized,
+            optimized_before: self.optimized_before,
+            created_at_gt: self.created_at_gt,
+            created_at_lt: self.created_at_lt,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1MediaOptimizeStatusPayload {
+    #[validate(length(min = 1, max = 64))]
+    pub batch_id: Option<String>,
+}
+
+/// Edit the descriptive fields of a media item. Omitted fields are left
+/// untouched; an empty string clears one. `focal_x` / `focal_y` must be sent
+/// together, and `clear_focal_point` drops a previously stored point.
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1MediaUpdatePayload {
+    #[validate(length(max = MAX_ALT_TEXT_LEN, message = "alt_text is too long"))]
+    pub alt_text: Option<String>,
+    #[validate(length(max = MAX_CAPTION_LEN, message = "caption is too long"))]
+    pub caption: Option<String>,
+    #[validate(length(max = MAX_CREDIT_LEN, message = "credit is too long"))]
+    pub credit: Option<String>,
+    #[validate(length(max = MAX_LICENSE_LEN, message = "license is too long"))]
+    pub license: Option<String>,
+    #[validate(range(min = 0.0, max = 1.0, message = "focal_x must be between 0 and 1"))]
+    pub focal_x: Option<f64>,
+    #[validate(range(min = 0.0, max = 1.0, message = "focal_y must be between 0 and 1"))]
+    pub focal_y: Option<f64>,
+    #[serde(default)]
+    pub clear_focal_point: bool,
+}
+
+impl V1MediaUpdatePayload {
+    pub fn into_update(self) -> Result<UpdateMediaDetails, String> {
+        let text = |value: Option<String>| {
+            value.map(|v| {
+                let trimmed = v.trim();
+                (!trimmed.is_empty()).then(|| trimmed.to_string())
+            })
+        };
+
+        let focal_point = match (self.focal_x, self.focal_y, self.clear_focal_point) {
+            (None, None, false) => None,
+            (None, None, true) => Some(None),
+            (Some(x), Some(y), false) => Some(Some((x, y))),
+            (_, _, true) => {
+                return Err("clear_focal_point cannot be combined with focal_x/focal_y".into())
+            }
+            _ => return Err("focal_x and focal_y must be provided together".into()),
+        };
+
+        Ok(UpdateMediaDetails {
+            alt_text: text(self.alt_text),
+            caption: text(self.caption),
+            credit: text(self.credit),
+            license: text(self.license),
+            focal_point,
+        })
+    }
+}
+
+/// Start a direct-to-storage upload. `mime_type` / `filename` go through the
+/// same allowlist as `/create`; `content_hash` (hex SHA-256) is only used to
+/// short-circuit re-uploads of media the caller already has.
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1MediaUploadInitPayload {
+    #[validate(length(min = 1, max = 255, message = "filename must be 1-255 characters"))]
+    pub filename: String,
+    pub mime_type: Option<String>,
+    #[validate(range(min = 1, message = "size must be positive"))]
+    pub size: i64,
+    #[validate(custom(function = "validate_sha256_hex"))]
+    pub content_hash: Option<String>,
+    pub reference_type: Option<MediaReference>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1MediaUploadPartsPayload {
+    #[validate(length(min = 1, max = 100, message = "request 1-100 parts at a time"))]
+    pub part_numbers: Vec<i32>,
+}
+
+fn validate_sha256_hex(value: &str) -> Result<(), ValidationError> {
+    if value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("sha256_hex"))
+    }
+}
+
+/// Number of `part_size` parts needed for `size` bytes.
+pub fn part_count_for(size: i64, part_size: i64) -> i32 {
+    if size <= 0 || part_size <= 0 {
+        return 0;
+    }
+    i32::try_from((size + part_size - 1) / part_size).unwrap_or(i32::MAX)
+}
+
+/// Part numbers in `1..=part_count` that have not been uploaded yet.
+pub fn missing_parts(part_count: i32, uploaded: &[UploadedPart]) -> Vec<i32> {
+    (1..=part_count)
+        .filter(|n| !uploaded.iter().any(|part| part.part_number == *n))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +637,96 @@ mod tests {
         assert_eq!(meta.reference_type, Some(MediaReference::Category));
     }
 
+    #[test]
+    fn apply_field_descriptive_text_trims_and_clears() {
+        let mut meta = MediaUploadMetadata::default();
+
+        meta.apply_field("alt_text", "  A red kite over a ridge  ")
+            .unwrap();
+        assert_eq!(meta.alt_text.as_deref(), Some("A red kite over a ridge"));
+
+        meta.apply_field("alt_text", "   ").unwrap();
+        assert!(meta.alt_text.is_none());
+
+        let too_long = "x".repeat(MAX_CREDIT_LEN as usize + 1);
+        assert!(meta.apply_field("credit", &too_long).is_err());
+    }
+
+    #[test]
+    fn apply_field_focal_point_range() {
+        let mut meta = MediaUploadMetadata::default();
+
+        meta.apply_field("focal_x", "0.25").unwrap();
+        assert!(meta.focal_point().is_none());
+        meta.apply_field("focal_y", "1").unwrap();
+        assert_eq!(meta.focal_point(), Some((0.25, 1.0)));
+
+        assert!(meta.apply_field("focal_x", "1.5").is_err());
+        assert!(meta.apply_field("focal_y", "-0.1").is_err());
+        assert!(meta.apply_field("focal_y", "NaN").is_err());
+    }
+
+    // ── V1MediaUpdatePayload ──────────────────────────────────────────────
+
+    #[test]
+    fn update_payload_normalizes_text_fields() {
+        let update = V1MediaUpdatePayload {
+            alt_text: Some("  Portrait  ".into()),
+            caption: Some("".into()),
+            ..Default::default()
+        }
+        .into_update()
+        .unwrap();
+
+        assert_eq!(update.alt_text, Some(Some("Portrait".to_string())));
+        assert_eq!(update.caption, Some(None));
+        assert_eq!(update.credit, None);
+        assert_eq!(update.focal_point, None);
+    }
+
+    #[test]
+    fn update_payload_focal_point_rules() {
+        let set = V1MediaUpdatePayload {
+            focal_x: Some(0.3),
+            focal_y: Some(0.7),
+            ..Default::default()
+        };
+        assert_eq!(
+            set.into_update().unwrap().focal_point,
+            Some(Some((0.3, 0.7)))
+        );
+
+        let clear = V1MediaUpdatePayload {
+            clear_focal_point: true,
+            ..Default::default()
+        };
+        assert_eq!(clear.into_update().unwrap().focal_point, Some(None));
+
+        let partial = V1MediaUpdatePayload {
+            focal_x: Some(0.3),
+            ..Default::default()
+        };
+        assert!(partial.into_update().is_err());
+
+        let conflicting = V1MediaUpdatePayload {
+            focal_x: Some(0.3),
+            focal_y: Some(0.3),
+            clear_focal_point: true,
+            ..Default::default()
+        };
+        assert!(conflicting.into_update().is_err());
+    }
+
+    #[test]
+    fn update_payload_rejects_out_of_range_focal_point() {
+        let payload = V1MediaUpdatePayload {
+            focal_x: Some(1.2),
+            focal_y: Some(0.5),
+            ..Default::default()
+        };
+        assert!(payload.validate().is_err());
+    }
+
     // ── M-7: MIME / extension allowlist ───────────────────────────────────
 
     #[test]
@@ -465,6 +820,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn validate_upload_accepts_video_audio_and_pdf() {
+        assert_eq!(
+            validate_upload(Some("video/webm"), Some("clip.webm")).unwrap(),
+            ("video/webm".to_string(), "webm".to_string())
+        );
+        assert_eq!(
+            validate_upload(None, Some("episode.MP3")).unwrap(),
+            ("audio/mpeg".to_string(), "mp3".to_string())
+        );
+        assert_eq!(
+            validate_upload(Some("application/pdf"), None).unwrap(),
+            ("application/pdf".to_string(), "pdf".to_string())
+        );
+        assert!(validate_upload(Some("video/quicktime"), Some("clip.mov")).is_err());
+    }
+
+    #[test]
+    fn validate_upload_drops_extension_of_another_kind() {
+        let (mime, ext) = validate_upload(Some("image/png"), Some("clip.mp4")).unwrap();
+        assert_eq!(mime, "image/png");
+        assert_eq!(ext, "png");
+    }
+
+    #[test]
+    fn max_upload_size_is_per_kind() {
+        assert_eq!(
+            max_upload_size(MediaKind::Video),
+            config::body_limits::MEDIA_VIDEO
+        );
+        assert!(max_upload_size(MediaKind::Document) < max_upload_size(MediaKind::Video));
+    }
+
     // ── M-5: uploader_id scoping ──────────────────────────────────────────
 
     #[test]
@@ -529,4 +917,71 @@ mod tests {
         let media_query = query.into_query(7, true);
         assert_eq!(media_query.uploader_id, None);
     }
+
+    // ── direct uploads ─────────────────────────────────────────────────────
+
+    fn part(part_number: i32) -> UploadedPart {
+        UploadedPart {
+            part_number,
+            etag: format!("\"etag-{part_number}\""),
+            size: 1,
+        }
+    }
+
+    #[test]
+    fn part_count_rounds_up() {
+        assert_eq!(part_count_for(1, 5), 1);
+        assert_eq!(part_count_for(5, 5), 1);
+        assert_eq!(part_count_for(6, 5), 2);
+        assert_eq!(part_count_for(0, 5), 0);
+    }
+
+    #[test]
+    fn missing_parts_lists_gaps() {
+        assert_eq!(missing_parts(4, &[part(1), part(3)]), vec![2, 4]);
+        assert!(missing_parts(2, &[part(2), part(1)]).is_empty());
+        // Parts beyond the declared count don't fill gaps.
+        assert_eq!(missing_parts(2, &[part(1), part(3)]), vec![2]);
+    }
+
+    #[test]
+    fn init_payload_rejects_malformed_hash() {
+        let mut payload = V1MediaUploadInitPayload {
+            filename: "big.png".to_string(),
+            mime_type: Some("image/png".to_string()),
+            size: 10,
+            content_hash: Some("abc".to_string()),
+            reference_type: None,
+            width: None,
+            height: None,
+        };
+        assert!(payload.validate().is_err());
+        payload.content_hash = Some("a".repeat(64));
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn optimize_payload_builds_filter_and_bounds_limit() {
+        let payload = V1MediaOptimizePayload {
+            mime_type: Some("  ".to_string()),
+            only_unoptimized: true,
+            limit: Some(50),
+            ..Default::default()
+        };
+        assert!(payload.validate().is_ok());
+        let filter = payload.into_filter();
+        assert!(filter.mime_type.is_none());
+        assert!(filter.only_unoptimized);
+
+        let too_many = V1MediaOptimizePayload {
+            limit: Some(50_000),
+            ..Default::default()
+        };
+        assert!(too_many.validate().is_err());
+        let empty_ids = V1MediaOptimizePayload {
+            media_ids: Some(Vec::new()),
+            ..Default::default()
+        };
+        assert!(empty_ids.validate().is_err());
+    }
 }
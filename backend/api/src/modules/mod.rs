@@ -1,12 +1,18 @@
 // Always enabled (core)
 pub mod auth_v1;
+pub mod author_v1;
+pub mod blocklist_v1;
 pub mod category_v1;
 pub mod csrf_v1;
 pub mod feed_v1;
 pub mod media_v1;
+pub mod notification_v1;
 pub mod post_v1;
+pub mod redirect_v1;
+pub mod seo_v1;
 pub mod tag_v1;
 pub mod user_v1; // Base profile routes always available; admin routes gated internally
+pub mod webhook_v1;
 
 // Feature-gated
 #[cfg(feature = "analytics")]
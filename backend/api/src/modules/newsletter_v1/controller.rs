@@ -2,20 +2,26 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use axum_client_ip::ClientIp;
 use axum_macros::debug_handler;
 use lettre::{message::header::ContentType, AsyncTransport, Message};
+use ruxlog_types::editorjs;
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use serde_json::json;
 use tracing::{error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
-    db::sea_models::newsletter_subscriber::{
-        Column as SubscriberColumn, Entity as SubscriberEntity, NewSubscriber, SubscriberStatus,
+    db::sea_models::{
+        newsletter_subscriber::{
+            Column as SubscriberColumn, Entity as SubscriberEntity, NewSubscriber, SubscriberStatus,
+        },
+        post::{self, PostStatus},
     },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
     services::{
         abuse_limiter::{limiter, AbuseLimiterConfig},
         auth::AuthSession,
+        notifications::{self, SiteEvent},
+        paywall::load_post_access_policy,
     },
     AppState,
 };
@@ -178,6 +184,55 @@ pub async fn unsubscribe(
     }
 }
 
+/// Intro plus a post rendered from its blocks, as `(text, html)`. Only
+/// published free posts go out in full: subscribers aren't necessarily
+/// entitled to gated ones.
+async fn compose_with_post(
+    state: &AppState,
+    post_id: i32,
+    intro_text: &str,
+    intro_html: Option<&str>,
+) -> Result<(String, String), ErrorResponse> {
+    let post = post::Entity::find_by_id(post_id)
+        .one(&state.sea_db)
+        .await?
+        .filter(|p| p.status == PostStatus::Published)
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Published post does not exist")
+        })?;
+    if !load_post_access_policy(&state.sea_db, post_id)
+        .await?
+        .is_open()
+    {
+        return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("Only free posts can be sent in a newsletter"));
+    }
+
+    let site_url =
+        std::env::var("SITE_URL").unwrap_or_else(|_| "http://localhost:8888".to_string());
+    let post_url = format!("{}/posts/{}", site_url.trim_end_matches('/'), post.slug);
+
+    let text = format!(
+        "{}\n\n{}\n\n{}\n\nRead it on the site: {}",
+        intro_text,
+        post.title,
+        editorjs::to_plain_text(&post.content),
+        post_url
+    );
+    let intro_html = intro_html
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("<p>{}</p>", editorjs::escape_html(intro_text)));
+    let html = format!(
+        "{}<h1>{}</h1>{}<p><a href=\"{}\">Read it on the site</a></p>",
+        intro_html,
+        editorjs::escape_html(&post.title),
+        editorjs::to_html(&post.content),
+        editorjs::escape_html(&post_url)
+    );
+    Ok((text, html))
+}
+
 #[debug_handler]
 #[instrument(skip(state, _auth, payload), fields(subject = %payload.subject))]
 pub async fn send(
@@ -185,10 +240,17 @@ pub async fn send(
     _auth: AuthSession,
     payload: ValidatedJson<V1SendNewsletterPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
+    let (text, html) = match payload.post_id {
+        Some(post_id) => {
+            let (text, html) =
+                compose_with_post(&state, post_id, &payload.text, payload.html.as_deref()).await?;
+            (text, Some(html))
+        }
+        None => (payload.text.clone(), payload.html.clone()),
+    };
+
     // Offload to background task
     let subject = payload.subject.clone();
-    let text = payload.text.clone();
-    let html = payload.html.clone();
     let state_cloned = state.clone();
 
     tokio::spawn(
@@ -284,6 +346,7 @@ pub async fn confirm(
     match SubscriberEntity::confirm(&state.sea_db, &email, &token).await {
         Ok(Some(_)) => {
             info!(email = %email, "Newsletter subscription confirmed");
+            notifications::dispatch(&state, SiteEvent::NewsletterSubscribed { email });
             Ok(Json(json!({ "message": "Subscription confirmed" })))
         }
         Ok(None) => {
@@ -30,6 +30,9 @@ pub struct V1SendNewsletterPayload {
     #[validate(length(min = 1))]
     pub text: String,
     pub html: Option<String>,
+    /// Append this published, free post (rendered from its blocks) below the
+    /// intro in `text`/`html`.
+    pub post_id: Option<i32>,
 }
 
 /// List subscribers (admin) with optional pagination and search
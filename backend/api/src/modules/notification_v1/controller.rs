@@ -0,0 +1,291 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse,
+    },
+    Json,
+};
+use axum_macros::debug_handler;
+use futures_util::{stream, Stream};
+use serde_json::json;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    db::sea_models::{
+        notification::Entity as Notification,
+        notification_preference::Entity as NotificationPreference,
+    },
+    error::ErrorResponse,
+    extractors::ValidatedJson,
+    services::auth::AuthSession,
+    AppState,
+};
+
+use super::validator::{
+    V1MarkNotificationsReadPayload, V1NotificationListQuery, V1UnsubscribeQuery,
+    V1UpdateNotificationPreferencesPayload,
+};
+
+/// How long one stream stays open. The browser reconnects on its own, and
+/// every reconnect goes back through the auth guard, so a signed-out or
+/// revoked session stops receiving notifications within this window.
+const STREAM_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+/// The signed-in user's notification settings
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn get_preferences(
+    State(state): State<AppState>,
+    auth: AuthSession,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+
+    match NotificationPreference::find_or_create(&state.sea_db, user.id).await {
+        Ok(preferences) => Ok((StatusCode::OK, Json(json!(preferences)))),
+        Err(err) => {
+            error!(
+                user_id = user.id,
+                "Failed to load notification preferences: {}", err
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Save the signed-in user's notification settings
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn update_preferences(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1UpdateNotificationPreferencesPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+
+    match NotificationPreference::update(&state.sea_db, user.id, payload.0.into_update()).await {
+        Ok(preferences) => {
+            info!(user_id = user.id, "Notification preferences updated");
+            Ok((StatusCode::OK, Json(json!(preferences))))
+        }
+        Err(err) => {
+            error!(
+                user_id = user.id,
+                "Failed to update notification preferences: {}", err
+            );
+            Err(err)
+        }
+    }
+}
+
+/// The signed-in user's notifications, newest first
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn list(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1NotificationListQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let query = payload.0.into_query();
+    let page = query.page.unwrap_or(1);
+
+    let (notifications, total) = Notification::find_with_query(&state.sea_db, user.id, query)
+        .await
+        .inspect_err(|err| error!(user_id = user.id, "Failed to list notifications: {}", err))?;
+    let unread = Notification::unread_count(&state.sea_db, user.id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "data": notifications,
+            "total": total,
+            "per_page": Notification::PER_PAGE,
+            "page": page,
+            "unread": unread,
+        })),
+    ))
+}
+
+/// Number of unread notifications, for the bell badge
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn unread_count(
+    State(state): State<AppState>,
+    auth: AuthSession,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let unread = Notification::unread_count(&state.sea_db, user.id).await?;
+    Ok((StatusCode::OK, Json(json!({ "unread": unread }))))
+}
+
+/// Mark some of the signed-in user's notifications read
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn mark_read(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1MarkNotificationsReadPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let updated = Notification::mark_read(&state.sea_db, user.id, &payload.ids)
+        .await
+        .inspect_err(|err| {
+            error!(
+                user_id = user.id,
+                "Failed to mark notifications read: {}", err
+            )
+        })?;
+    let unread = Notification::unread_count(&state.sea_db, user.id).await?;
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "updated": updated, "unread": unread })),
+    ))
+}
+
+/// Mark all of the signed-in user's notifications read
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn mark_all_read(
+    State(state): State<AppState>,
+    auth: AuthSession,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let updated = Notification::mark_all_read(&state.sea_db, user.id)
+        .await
+        .inspect_err(|err| {
+            error!(
+                user_id = user.id,
+                "Failed to mark all notifications read: {}", err
+            )
+        })?;
+    info!(user_id = user.id, updated, "Notifications marked read");
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "updated": updated, "unread": 0 })),
+    ))
+}
+
+/// Server-Sent Events stream of the signed-in user's new notifications.
+/// Each `notification` event carries one notification as JSON.
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn stream(
+    State(state): State<AppState>,
+    auth: AuthSession,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let user_id = auth.user.unwrap().id;
+    let receiver = state.notification_hub.subscribe();
+    let deadline = tokio::time::Instant::now() + STREAM_LIFETIME;
+
+    let events = stream::unfold(receiver, move |mut receiver| async move {
+        loop {
+            let next = tokio::time::timeout_at(deadline, receiver.recv()).await;
+            match next {
+                Ok(Ok(item)) if item.user_id == user_id => {
+                    let event = Event::default()
+                        .event("notification")
+                        .json_data(&item)
+                        .unwrap_or_else(|_| Event::default().event("notification"));
+                    return Some((Ok(event), receiver));
+                }
+                Ok(Ok(_)) => continue,
+                // Missed some; tell the client to refetch instead.
+                Ok(Err(RecvError::Lagged(_))) => {
+                    return Some((Ok(Event::default().event("resync").data("{}")), receiver));
+                }
+                Ok(Err(RecvError::Closed)) | Err(_) => return None,
+            }
+        }
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+fn unsubscribe_html(
+    status: StatusCode,
+    title: &str,
+    message: &str,
+    confirm: bool,
+) -> impl IntoResponse {
+    let form = if confirm {
+        r#"<form method="post"><button type="submit">Unsubscribe</button></form>"#
+    } else {
+        ""
+    };
+    (
+        status,
+        Html(format!(
+            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"UTF-8\" /><meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\" /><title>{title}</title></head><body><h1>{title}</h1><p>{message}</p>{form}</body></html>"
+        )),
+    )
+}
+
+fn scope_message(query: &V1UnsubscribeQuery) -> &'static str {
+    if query.kind.is_some() {
+        "emails like the one you received"
+    } else {
+        "all notification emails"
+    }
+}
+
+/// Confirmation page behind the unsubscribe link in an email
+#[debug_handler]
+#[instrument(skip(state, token, query))]
+pub async fn unsubscribe_page(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<V1UnsubscribeQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    if NotificationPreference::find_by_unsubscribe_token(&state.sea_db, &token)
+        .await?
+        .is_none()
+    {
+        return Ok(unsubscribe_html(
+            StatusCode::NOT_FOUND,
+            "Link expired",
+            "This unsubscribe link is no longer valid.",
+            false,
+        ));
+    }
+    Ok(unsubscribe_html(
+        StatusCode::OK,
+        "Unsubscribe",
+        &format!("Stop receiving {}?", scope_message(&query)),
+        true,
+    ))
+}
+
+/// One-click unsubscribe (RFC 8058), also used by the confirmation page
+#[debug_handler]
+#[instrument(skip(state, token, query))]
+pub async fn unsubscribe(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<V1UnsubscribeQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match NotificationPreference::unsubscribe(&state.sea_db, &token, query.kind).await? {
+        Some(_) => Ok(unsubscribe_html(
+            StatusCode::OK,
+            "Unsubscribed",
+            &format!(
+                "You will no longer receive {}. You can change this any time in your notification settings.",
+                scope_message(&query)
+            ),
+            false,
+        )),
+        None => {
+            warn!("Unknown notification unsubscribe token");
+            Ok(unsubscribe_html(
+                StatusCode::NOT_FOUND,
+                "Link expired",
+                "This unsubscribe link is no longer valid.",
+                false,
+            ))
+        }
+    }
+}
@@ -0,0 +1,31 @@
+pub mod controller;
+pub mod validator;
+
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+use crate::{middlewares::auth_guard, AppState};
+
+pub fn routes() -> Router<AppState> {
+    let inbox = Router::<AppState>::new()
+        .route("/preferences", get(controller::get_preferences))
+        .route("/preferences/update", post(controller::update_preferences))
+        .route("/list/query", post(controller::list))
+        .route("/unread_count", get(controller::unread_count))
+        .route("/mark_read", post(controller::mark_read))
+        .route("/mark_all_read", post(controller::mark_all_read))
+        .route("/stream", get(controller::stream))
+        .route_layer(middleware::from_fn(auth_guard::authenticated));
+
+    // Authorized by the token in the link; GET only shows a confirmation
+    // form so link scanners cannot unsubscribe anyone.
+    let unsubscribe = Router::<AppState>::new().route(
+        "/unsubscribe/{token}",
+        get(controller::unsubscribe_page).post(controller::unsubscribe),
+    );
+
+    inbox.merge(unsubscribe)
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::db::sea_models::{
+    notification::NotificationQuery,
+    notification_preference::{EmailDelivery, NotificationKind, UpdateNotificationPreference},
+};
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1UpdateNotificationPreferencesPayload {
+    pub comment_on_post: Option<bool>,
+    pub comment_reply: Option<bool>,
+    pub comment_mention: Option<bool>,
+    pub new_user: Option<bool>,
+    pub payment_received: Option<bool>,
+    pub subscription_canceled: Option<bool>,
+    pub newsletter_subscriber: Option<bool>,
+    pub contact_form: Option<bool>,
+    pub email_enabled: Option<bool>,
+    pub in_app_enabled: Option<bool>,
+    /// `immediate` or `digest`.
+    pub email_delivery: Option<EmailDelivery>,
+}
+
+impl V1UpdateNotificationPreferencesPayload {
+    pub fn into_update(self) -> UpdateNotificationPreference {
+        UpdateNotificationPreference {
+            comment_on_post: self.comment_on_post,
+            comment_reply: self.comment_reply,
+            comment_mention: self.comment_mention,
+            new_user: self.new_user,
+            payment_received: self.payment_received,
+            subscription_canceled: self.subscription_canceled,
+            newsletter_subscriber: self.newsletter_subscriber,
+            contact_form: self.contact_form,
+            email_enabled: self.email_enabled,
+            in_app_enabled: self.in_app_enabled,
+            email_delivery: self.email_delivery,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct V1UnsubscribeQuery {
+    /// Only turn this kind off; omit to stop all notification email.
+    pub kind: Option<NotificationKind>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, Default)]
+pub struct V1NotificationListQuery {
+    pub page: Option<u64>,
+    /// Only notifications that have not been read yet.
+    pub unread_only: Option<bool>,
+}
+
+impl V1NotificationListQuery {
+    pub fn into_query(self) -> NotificationQuery {
+        NotificationQuery {
+            page: self.page,
+            unread_only: self.unread_only.unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1MarkNotificationsReadPayload {
+    #[validate(length(min = 1, max = 100))]
+    pub ids: Vec<i32>,
+}
@@ -5,14 +5,21 @@ use axum::{
     Json,
 };
 use axum_macros::debug_handler;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use serde_json::json;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
-    db::sea_models::{comment_flag, post_comment},
+    db::sea_models::{comment_flag, comment_like, comment_reaction, post_comment, user, user_ban},
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
-    services::{abuse_limiter, auth::AuthSession},
+    middlewares::auth_guard::ROLE_MODERATOR,
+    services::{
+        abuse_limiter,
+        auth::AuthSession,
+        blocklist::Blocklist,
+        notifications::{self, SiteEvent},
+    },
     AppState,
 };
 
@@ -29,10 +36,32 @@ const COMMENT_ABUSE_CONFIG: abuse_limiter::AbuseLimiterConfig = abuse_limiter::A
 };
 
 use super::validator::{
-    V1AdminCommentFlagListQuery, V1AdminPostCommentListQuery, V1CreatePostCommentPayload,
-    V1FlagCommentPayload, V1UpdatePostCommentPayload,
+    V1AdminCommentFlagListQuery, V1AdminPostCommentListQuery, V1CommentReactionPayload,
+    V1CreatePostCommentPayload, V1FlagCommentPayload, V1PostCommentListQuery, V1ShadowBanPayload,
+    V1UpdatePostCommentPayload,
 };
 
+/// Blocklisted words, patterns and domains reject a comment outright.
+/// Moderators and admins are exempt, as they are from spam scoring.
+async fn ensure_not_blocklisted(
+    state: &AppState,
+    user: &user::Model,
+    content: &str,
+) -> Result<(), ErrorResponse> {
+    if user.role.to_i32() >= ROLE_MODERATOR {
+        return Ok(());
+    }
+    let blocklist = Blocklist::load(&state.sea_db).await?;
+    match blocklist.check_comment(content) {
+        Some(found) => {
+            warn!(user_id = user.id, kind = ?found.kind, pattern = %found.pattern, "Comment blocked by blocklist");
+            Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Comment contains blocked content"))
+        }
+        None => Ok(()),
+    }
+}
+
 #[debug_handler]
 #[instrument(skip(state, auth, payload), fields(user_id = auth.user.as_ref().map(|u| u.id), post_id, comment_id))]
 pub async fn create(
@@ -51,7 +80,23 @@ pub async fn create(
     )
     .await?;
 
-    let new_comment = payload.0.into_new_post_comment(user.id);
+    ensure_not_blocklisted(&state, &user, &payload.content).await?;
+
+    if let Some(parent_id) = payload.parent_id {
+        let parent = post_comment::Entity::find_by_id(parent_id)
+            .one(&state.sea_db)
+            .await?;
+        if parent.is_none_or(|parent| parent.post_id != payload.post_id) {
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Parent comment does not belong to this post"));
+        }
+    }
+
+    let assessment = state
+        .comment_moderator
+        .assess_new(&state.sea_db, &user, &payload.content)
+        .await?;
+    let new_comment = payload.0.into_new_post_comment(user.id, assessment);
     tracing::Span::current().record("post_id", new_comment.post_id);
 
     match post_comment::Entity::create(&state.sea_db, new_comment).await {
@@ -63,6 +108,14 @@ pub async fn create(
                 comment_id = comment.id,
                 "Comment created"
             );
+            if comment.is_published() {
+                notifications::dispatch(
+                    &state,
+                    SiteEvent::CommentPublished {
+                        comment_id: comment.id,
+                    },
+                );
+            }
             Ok((StatusCode::CREATED, Json(json!(comment))))
         }
         Err(err) => {
@@ -81,7 +134,34 @@ pub async fn update(
     payload: ValidatedJson<V1UpdatePostCommentPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
     let user = auth.user.unwrap();
-    let update_comment = payload.0.into_update_post_comment();
+
+    // Edited content goes back through the spam scorer so an approved
+    // comment cannot be turned into spam after the fact.
+    let assessment = match payload.content.as_deref() {
+        Some(content) => {
+            ensure_not_blocklisted(&state, &user, content).await?;
+            let existing = post_comment::Entity::find_by_id(comment_id)
+                .filter(post_comment::Column::UserId.eq(user.id))
+                .one(&state.sea_db)
+                .await?;
+            let Some(existing) = existing else {
+                warn!(
+                    user_id = user.id,
+                    comment_id, "Comment not found for update"
+                );
+                return Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                    .with_message("Comment does not exist"));
+            };
+            Some(
+                state
+                    .comment_moderator
+                    .assess_edit(&state.sea_db, &user, &existing, content)
+                    .await?,
+            )
+        }
+        None => None,
+    };
+    let update_comment = payload.0.into_update_post_comment(assessment);
 
     match post_comment::Entity::update(&state.sea_db, comment_id, user.id, update_comment).await {
         Ok(Some(comment)) => {
@@ -147,17 +227,22 @@ pub async fn delete(
     }
 }
 
-/// Find comments by post ID (public use)
+/// Find comments by post ID (public use). A shadow-banned viewer still
+/// sees their own comments.
 #[debug_handler]
-#[instrument(skip(state), fields(post_id))]
+#[instrument(skip(state, auth, payload), fields(post_id))]
 pub async fn find_all_by_post(
     State(state): State<AppState>,
+    auth: AuthSession,
     Path(post_id): Path<i32>,
+    payload: ValidatedJson<V1PostCommentListQuery>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
     match post_comment::Entity::find_all_by_post(
         &state.sea_db,
         &state.object_storage.public_url,
         post_id,
+        auth.user.as_ref().map(|u| u.id),
+        payload.0.sort.unwrap_or_default(),
     )
     .await
     {
@@ -176,6 +261,184 @@ pub async fn find_all_by_post(
     }
 }
 
+/// Only comments visible on the public site can be liked or reacted to.
+async fn ensure_public_comment(state: &AppState, comment_id: i32) -> Result<(), ErrorResponse> {
+    let visible = post_comment::Entity::find_by_id(comment_id)
+        .filter(post_comment::Column::Hidden.eq(false))
+        .filter(post_comment::Column::Status.eq(post_comment::CommentStatus::Approved))
+        .one(&state.sea_db)
+        .await?
+        .is_some();
+    if visible {
+        Ok(())
+    } else {
+        warn!(comment_id, "Comment not found for like/reaction");
+        Err(ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Comment does not exist"))
+    }
+}
+
+/// Like a comment
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id, comment_id))]
+pub async fn like_comment(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    tracing::Span::current().record("user_id", user.id);
+    ensure_public_comment(&state, comment_id).await?;
+
+    match comment_like::Entity::like_comment(&state.sea_db, comment_id, user.id).await {
+        Ok((changed, likes_count)) => Ok((
+            StatusCode::OK,
+            Json(json!(comment_like::CommentLikeActionResponse {
+                comment_id,
+                is_liked: true,
+                likes_count,
+                message: if changed {
+                    "Comment liked successfully".to_string()
+                } else {
+                    "Comment was already liked".to_string()
+                },
+            })),
+        )),
+        Err(err) => {
+            error!(
+                user_id = user.id,
+                comment_id, "Failed to like comment: {}", err
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Unlike a comment
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id, comment_id))]
+pub async fn unlike_comment(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    tracing::Span::current().record("user_id", user.id);
+
+    match comment_like::Entity::unlike_comment(&state.sea_db, comment_id, user.id).await {
+        Ok((changed, likes_count)) => Ok((
+            StatusCode::OK,
+            Json(json!(comment_like::CommentLikeActionResponse {
+                comment_id,
+                is_liked: false,
+                likes_count,
+                message: if changed {
+                    "Comment unliked successfully".to_string()
+                } else {
+                    "Comment was not liked".to_string()
+                },
+            })),
+        )),
+        Err(err) => {
+            error!(
+                user_id = user.id,
+                comment_id, "Failed to unlike comment: {}", err
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Get the viewer's likes and reactions for several comments
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id, comment_count))]
+pub async fn like_status_batch(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<comment_like::CommentLikeStatusBatchRequest>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    tracing::Span::current().record("user_id", user.id);
+    tracing::Span::current().record("comment_count", payload.comment_ids.len());
+
+    match comment_like::Entity::get_like_status_batch(&state.sea_db, &payload.comment_ids, user.id)
+        .await
+    {
+        Ok(statuses) => Ok((
+            StatusCode::OK,
+            Json(json!(comment_like::CommentLikeStatusBatchResponse {
+                statuses
+            })),
+        )),
+        Err(err) => {
+            error!(
+                user_id = user.id,
+                "Failed to get batch comment like status: {}", err
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Add an emoji reaction to a comment
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id, comment_id))]
+pub async fn react(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+    payload: ValidatedJson<V1CommentReactionPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    tracing::Span::current().record("user_id", user.id);
+    ensure_public_comment(&state, comment_id).await?;
+    let reaction = payload.0.reaction;
+
+    let added = comment_reaction::Entity::add(&state.sea_db, comment_id, user.id, reaction).await?;
+    let count = comment_reaction::Entity::count(&state.sea_db, comment_id, reaction).await?;
+    if added {
+        info!(user_id = user.id, comment_id, reaction = ?reaction, "Comment reaction added");
+    }
+    Ok((
+        StatusCode::OK,
+        Json(json!(comment_reaction::CommentReactionResponse {
+            comment_id,
+            reaction,
+            reacted: true,
+            count,
+        })),
+    ))
+}
+
+/// Remove an emoji reaction from a comment
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id, comment_id))]
+pub async fn unreact(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+    payload: ValidatedJson<V1CommentReactionPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    tracing::Span::current().record("user_id", user.id);
+    let reaction = payload.0.reaction;
+
+    let removed =
+        comment_reaction::Entity::remove(&state.sea_db, comment_id, user.id, reaction).await?;
+    let count = comment_reaction::Entity::count(&state.sea_db, comment_id, reaction).await?;
+    if removed {
+        info!(user_id = user.id, comment_id, reaction = ?reaction, "Comment reaction removed");
+    }
+    Ok((
+        StatusCode::OK,
+        Json(json!(comment_reaction::CommentReactionResponse {
+            comment_id,
+            reaction,
+            reacted: false,
+            count,
+        })),
+    ))
+}
+
 /// Find comments with query (dashboard use)
 #[debug_handler]
 #[instrument(skip(state, payload))]
@@ -212,6 +475,155 @@ pub async fn find_with_query(
     }
 }
 
+#[debug_handler]
+#[instrument(skip(state, auth), fields(comment_id, admin_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn admin_approve(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    admin_moderate(
+        state,
+        auth,
+        comment_id,
+        post_comment::CommentStatus::Approved,
+    )
+    .await
+}
+
+#[debug_handler]
+#[instrument(skip(state, auth), fields(comment_id, admin_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn admin_reject(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(comment_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    admin_moderate(
+        state,
+        auth,
+        comment_id,
+        post_comment::CommentStatus::Rejected,
+    )
+    .await
+}
+
+async fn admin_moderate(
+    state: AppState,
+    auth: AuthSession,
+    comment_id: i32,
+    status: post_comment::CommentStatus,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let was_published = post_comment::Entity::find_by_id(comment_id)
+        .one(&state.sea_db)
+        .await?
+        .is_some_and(|comment| comment.is_published());
+
+    match post_comment::Entity::admin_moderate(&state.sea_db, comment_id, user.id, status).await {
+        Ok(Some(comment)) => {
+            info!(comment_id, status = ?status, "Admin moderated comment");
+            if !was_published && comment.is_published() {
+                notifications::dispatch(&state, SiteEvent::CommentPublished { comment_id });
+            }
+            Ok((StatusCode::OK, Json(json!(comment))))
+        }
+        Ok(None) => {
+            warn!(comment_id, "Comment not found for moderation");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound))
+        }
+        Err(err) => {
+            error!(comment_id, "Failed to moderate comment: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Shadow-ban a commenter: they can keep posting, but their comments are
+/// only shown to themselves. Re-banning an already shadow-banned user
+/// returns the existing ban.
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(user_id, admin_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn admin_shadow_ban(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(user_id): Path<i32>,
+    payload: ValidatedJson<V1ShadowBanPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let admin = auth.user.unwrap();
+
+    if user_id == admin.id {
+        return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("You cannot shadow-ban yourself"));
+    }
+    let Some(target) = user::Entity::find_by_id(user_id).one(&state.sea_db).await? else {
+        warn!(user_id, "User not found for shadow ban");
+        return Err(
+            ErrorResponse::new(ErrorCode::RecordNotFound).with_message("User does not exist")
+        );
+    };
+    if target.role.to_i32() >= ROLE_MODERATOR {
+        return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("Moderators and admins cannot be shadow-banned"));
+    }
+
+    if let Some(existing) = user_ban::Entity::get_active_shadow_ban(&state.sea_db, user_id).await? {
+        info!(user_id, ban_id = existing.id, "User already shadow-banned");
+        return Ok((StatusCode::OK, Json(json!(existing))));
+    }
+
+    let new_ban = payload
+        .0
+        .into_new_user_ban(user_id)
+        .with_banned_by(admin.id);
+    match user_ban::Entity::create(&state.sea_db, new_ban).await {
+        Ok(ban) => {
+            info!(user_id, ban_id = ban.id, "User shadow-banned");
+            Ok((StatusCode::CREATED, Json(json!(ban))))
+        }
+        Err(err) => {
+            error!(user_id, "Failed to shadow-ban user: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Lift every active shadow ban on a user.
+#[debug_handler]
+#[instrument(skip(state, auth), fields(user_id, admin_id = auth.user.as_ref().map(|u| u.id)))]
+pub async fn admin_shadow_ban_lift(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(user_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let admin = auth.user.unwrap();
+
+    match user_ban::Entity::revoke_active(
+        &state.sea_db,
+        user_id,
+        user_ban::UserBanKind::Shadow,
+        Some(admin.id),
+    )
+    .await
+    {
+        Ok(0) => {
+            warn!(user_id, "No active shadow ban to lift");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("User is not shadow-banned"))
+        }
+        Ok(revoked) => {
+            info!(user_id, revoked, "Shadow ban lifted");
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Shadow ban lifted", "revoked": revoked })),
+            ))
+        }
+        Err(err) => {
+            error!(user_id, "Failed to lift shadow ban: {}", err);
+            Err(err)
+        }
+    }
+}
+
 #[debug_handler]
 #[instrument(skip(state, auth), fields(comment_id, admin_id = auth.user.as_ref().map(|u| u.id)))]
 pub async fn admin_hide(
@@ -342,6 +754,17 @@ pub async fn flag(
             let count = comment_flag::Entity::sync_flags_count(&state.sea_db, comment_id)
                 .await
                 .unwrap_or(0);
+            let threshold = state.comment_moderator.config.flag_hide_threshold;
+            match post_comment::Entity::hide_if_flagged(&state.sea_db, comment_id, threshold).await
+            {
+                Ok(true) => info!(
+                    comment_id,
+                    flags_count = count,
+                    "Comment auto-hidden after reaching the flag threshold"
+                ),
+                Ok(false) => {}
+                Err(err) => error!(comment_id, "Failed to auto-hide flagged comment: {}", err),
+            }
             info!(
                 user_id = user.id,
                 comment_id,
@@ -17,9 +17,25 @@ pub fn routes() -> Router<AppState> {
         // Public route for listing comments by post
         .route("/{post_id}", post(controller::find_all_by_post));
 
+    // Likes and reactions need a login, like post likes
+    let reactions = Router::<AppState>::new()
+        .route("/like/{comment_id}", post(controller::like_comment))
+        .route("/unlike/{comment_id}", post(controller::unlike_comment))
+        .route("/like/status/batch", post(controller::like_status_batch))
+        .route("/react/{comment_id}", post(controller::react))
+        .route("/unreact/{comment_id}", post(controller::unreact))
+        .route_layer(middleware::from_fn(auth_guard::authenticated));
+
     // Admin moderation routes nested under /admin
     let admin = Router::<AppState>::new()
         .route("/list", post(controller::find_with_query))
+        .route("/approve/{comment_id}", post(controller::admin_approve))
+        .route("/reject/{comment_id}", post(controller::admin_reject))
+        .route("/shadow_ban/{user_id}", post(controller::admin_shadow_ban))
+        .route(
+            "/shadow_ban/lift/{user_id}",
+            post(controller::admin_shadow_ban_lift),
+        )
         .route("/hide/{comment_id}", post(controller::admin_hide))
         .route("/unhide/{comment_id}", post(controller::admin_unhide))
         .route("/delete/{comment_id}", post(controller::admin_delete))
@@ -40,5 +56,5 @@ pub fn routes() -> Router<AppState> {
             auth_guard::verified_with_role::<{ auth_guard::ROLE_ADMIN }>,
         ));
 
-    base.nest("/admin", admin)
+    base.merge(reactions).nest("/admin", admin)
 }
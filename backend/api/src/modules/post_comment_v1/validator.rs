@@ -2,25 +2,34 @@ use sea_orm::prelude::DateTimeWithTimeZone;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::db::sea_models::comment_reaction::CommentReaction;
 use crate::db::sea_models::post_comment::{
-    CommentQuery, FlagFilter, HiddenFilter, NewComment, UpdateComment,
+    CommentQuery, CommentSort, CommentStatus, FlagFilter, HiddenFilter, NewComment, UpdateComment,
 };
+use crate::db::sea_models::user_ban::NewUserBan;
+use crate::services::comment_moderation::Assessment;
 use crate::utils::SortParam;
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct V1CreatePostCommentPayload {
     pub post_id: i32,
+    /// The comment being replied to; must be on the same post.
+    pub parent_id: Option<i32>,
     #[validate(length(min = 1, max = 1000))]
     pub content: String,
 }
 
 impl V1CreatePostCommentPayload {
-    pub fn into_new_post_comment(self, user_id: i32) -> NewComment {
+    pub fn into_new_post_comment(self, user_id: i32, assessment: Assessment) -> NewComment {
         NewComment {
             post_id: self.post_id,
             user_id,
+            parent_id: self.parent_id,
             content: self.content,
             likes_count: Some(0),
+            status: assessment.status,
+            spam_score: assessment.spam_score,
+            spam_reasons: assessment.spam_reasons,
         }
     }
 }
@@ -32,20 +41,38 @@ pub struct V1UpdatePostCommentPayload {
 }
 
 impl V1UpdatePostCommentPayload {
-    pub fn into_update_post_comment(self) -> UpdateComment {
+    pub fn into_update_post_comment(self, assessment: Option<Assessment>) -> UpdateComment {
         UpdateComment {
             content: self.content,
             updated_at: chrono::Utc::now().fixed_offset(),
+            status: assessment.as_ref().map(|a| a.status),
+            spam_score: assessment.as_ref().map(|a| a.spam_score),
+            spam_reasons: assessment.map(|a| a.spam_reasons),
         }
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Validate, Default)]
+pub struct V1PostCommentListQuery {
+    /// `oldest` (default), `newest` or `top`.
+    pub sort: Option<CommentSort>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1CommentReactionPayload {
+    pub reaction: CommentReaction,
+}
+
 #[derive(Debug, Deserialize, Serialize, Validate, Clone)]
 pub struct V1AdminPostCommentListQuery {
     pub page: Option<u64>,
     pub user_id: Option<i32>,
     pub post_id: Option<i32>,
     pub search: Option<String>,
+    /// `pending` lists the moderation queue.
+    pub status: Option<CommentStatus>,
+    /// Only comments whose author is (or is not) shadow-banned.
+    pub shadow_banned: Option<bool>,
     pub hidden_filter: Option<HiddenFilter>,
     pub flag_filter: Option<FlagFilter>,
     pub min_flags: Option<i32>,
@@ -67,6 +94,8 @@ impl V1AdminPostCommentListQuery {
             user_id: self.user_id,
             post_id: self.post_id,
             search_term: self.search,
+            status: self.status,
+            shadow_banned: self.shadow_banned,
             hidden_filter: Some(hidden_filter),
             flag_filter: Some(flag_filter),
             min_flags: self.min_flags,
@@ -84,6 +113,27 @@ pub struct V1AdminModerationPayload {
     pub reason: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1ShadowBanPayload {
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+    /// Omit for a shadow ban that lasts until lifted.
+    pub expires_at: Option<DateTimeWithTimeZone>,
+}
+
+impl V1ShadowBanPayload {
+    pub fn into_new_user_ban(self, user_id: i32) -> NewUserBan {
+        let mut ban = NewUserBan::new(user_id).shadow();
+        if let Some(reason) = self.reason {
+            ban = ban.with_reason(reason);
+        }
+        if let Some(expires_at) = self.expires_at {
+            ban = ban.with_expiry(expires_at);
+        }
+        ban
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct V1FlagCommentPayload {
     #[validate(length(min = 1, max = 500))]
@@ -1,6 +1,6 @@
 use axum::{
     extract::{Path, State},
-    http::{HeaderMap, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
@@ -9,27 +9,34 @@ use axum_client_ip::ClientIp;
 use crate::db::sea_models::post::UpdatePost;
 use crate::db::sea_models::{post_revision, post_series, post_series_post, scheduled_post};
 use axum_macros::debug_handler;
+use ruxlog_types::editorjs;
 use sea_orm::EntityTrait;
 use serde_json::json;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use tracing::{error, info, instrument, warn};
 
 use crate::db::sea_models::user::{self, UserRole};
 use crate::{
-    db::sea_models::post,
+    db::sea_models::{
+        media::{self, responsive::ResponsiveImage},
+        media_variant, post, redirect,
+        seo_override::{self, SeoEntityType},
+    },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
     modules::post_v1::validator::V1UpdatePostPayload,
     services::{
         auth::AuthSession,
+        notifications::{self, SiteEvent},
         paywall::{self, PostAccessPolicy},
+        redirects, seo, sitemap,
     },
     AppState,
 };
 
 use super::validator::{
-    V1AutosavePayload, V1CreatePostPayload, V1PostQueryParams, V1SchedulePayload,
-    V1SeriesCreatePayload, V1SeriesListQuery, V1SeriesUpdatePayload,
+    V1AutosavePayload, V1CreatePostPayload, V1ExportFormat, V1ExportPostPayload, V1PostQueryParams,
+    V1SchedulePayload, V1SeriesCreatePayload, V1SeriesListQuery, V1SeriesUpdatePayload,
 };
 
 // ── Paywall helpers (plan Phase 4c) ─────────────────────────────────────
@@ -132,10 +139,179 @@ async fn apply_paywall_single(
     Ok(())
 }
 
+// ── Image block details ─────────────────────────────────────────────────
+//
+// EditorJS image blocks only carry a `media_id` + URL. Alt text, credit and
+// focal point live on the media row so edits in the media library show up on
+// every post using the image; they are merged into `data.file` on read, along
+// with the responsive `<picture>` sources built from the optimizer variants.
+// Attaches blocks get the probed playback details and poster the same way.
+
+fn is_media_block(block: &serde_json::Value) -> bool {
+    matches!(
+        block.get("type").and_then(|v| v.as_str()),
+        Some("image" | "attaches")
+    )
+}
+
+/// Media ids referenced by image and attaches blocks, in first-seen order.
+fn image_block_media_ids(content: &serde_json::Value) -> Vec<i32> {
+    let mut ids = Vec::new();
+    let Some(blocks) = content.get("blocks").and_then(|v| v.as_array()) else {
+        return ids;
+    };
+    for block in blocks {
+        if !is_media_block(block) {
+            continue;
+        }
+        let id = block
+            .get("data")
+            .and_then(|data| {
+                data.get("file")
+                    .and_then(|f| f.get("media_id"))
+                    .or_else(|| data.get("media_id"))
+            })
+            .and_then(|v| v.as_i64())
+            .and_then(|v| i32::try_from(v).ok());
+        if let Some(id) = id {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+/// Copy descriptive media fields and responsive sources into each image
+/// block's `data.file`. An empty block caption falls back to the media caption.
+/// Attaches blocks get the MIME type, duration, page count and poster URL.
+fn apply_image_details(
+    content: &mut serde_json::Value,
+    details: &HashMap<i32, media::Model>,
+    responsive: &HashMap<i32, ResponsiveImage>,
+    posters: &HashMap<i32, String>,
+) {
+    let Some(blocks) = content.get_mut("blocks").and_then(|v| v.as_array_mut()) else {
+        return;
+    };
+    for block in blocks.iter_mut() {
+        if !is_media_block(block) {
+            continue;
+        }
+        let is_attachment = block.get("type").and_then(|v| v.as_str()) == Some("attaches");
+        let Some(data) = block.get_mut("data").and_then(|v| v.as_object_mut()) else {
+            continue;
+        };
+        let media_id = data
+            .get("file")
+            .and_then(|f| f.get("media_id"))
+            .or_else(|| data.get("media_id"))
+            .and_then(|v| v.as_i64())
+            .and_then(|v| i32::try_from(v).ok());
+        let Some(media) = media_id.and_then(|id| details.get(&id)) else {
+            continue;
+        };
+
+        if is_attachment {
+            if let Some(file) = data.get_mut("file").and_then(|v| v.as_object_mut()) {
+                file.insert("mime_type".into(), json!(media.mime_type));
+                file.insert("duration_ms".into(), json!(media.duration_ms));
+                file.insert("page_count".into(), json!(media.page_count));
+                file.insert("poster".into(), json!(posters.get(&media.id)));
+            }
+            continue;
+        }
+
+        let caption_empty = data
+            .get("caption")
+            .and_then(|v| v.as_str())
+            .is_none_or(|c| c.trim().is_empty());
+        if caption_empty {
+            if let Some(caption) = &media.caption {
+                data.insert("caption".into(), json!(caption));
+            }
+        }
+
+        if let Some(file) = data.get_mut("file").and_then(|v| v.as_object_mut()) {
+            file.insert("alt".into(), json!(media.alt_text));
+            file.insert("credit".into(), json!(media.credit));
+            file.insert("license".into(), json!(media.license));
+            file.insert("focal_x".into(), json!(media.focal_x));
+            file.insert("focal_y".into(), json!(media.focal_y));
+            if let Some(image) = responsive.get(&media.id) {
+                file.insert("responsive".into(), json!(image));
+            }
+        }
+    }
+}
+
+/// Merge media details into the featured image of every post and, when
+/// `with_blocks` is set, into their EditorJS image blocks. Two queries total
+/// (media rows + variants) regardless of how many posts are passed.
+pub(crate) async fn attach_image_details(
+    state: &AppState,
+    posts: &mut [post::PostWithRelations],
+    with_blocks: bool,
+) -> Result<(), ErrorResponse> {
+    let mut ids: Vec<i32> = posts
+        .iter()
+        .filter_map(|p| p.featured_image.as_ref().map(|f| f.id))
+        .collect();
+    if with_blocks {
+        for post in posts.iter() {
+            ids.extend(image_block_media_ids(&post.content));
+        }
+    }
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let details: HashMap<i32, media::Model> = media::Entity::find_by_ids(&state.sea_db, &ids)
+        .await?
+        .into_iter()
+        .map(|m| (m.id, m))
+        .collect();
+    let variants = media_variant::Entity::find_by_media_ids(&state.sea_db, &ids).await?;
+    let responsive: HashMap<i32, ResponsiveImage> = details
+        .values()
+        .map(|m| {
+            let image =
+                media::responsive::build(&state.object_storage.public_url, m, &variants, None);
+            (m.id, image)
+        })
+        .collect();
+
+    let posters: HashMap<i32, String> = variants
+        .iter()
+        .filter(|variant| variant.variant_type == media_variant::POSTER_VARIANT)
+        .filter_map(|variant| {
+            let media = details.get(&variant.media_id)?;
+            let url = media::url::build_public_file_url(
+                &state.object_storage.public_url,
+                media.bucket.as_deref(),
+                &variant.object_key,
+            );
+            Some((media.id, url))
+        })
+        .collect();
+
+    for post in posts.iter_mut() {
+        if let Some(featured) = post.featured_image.as_mut() {
+            featured.responsive = responsive.get(&featured.id).cloned();
+        }
+        if with_blocks {
+            apply_image_details(&mut post.content, &details, &responsive, &posters);
+        }
+    }
+    Ok(())
+}
+
 /// Batch-stamp policies and strip `content` for every gated post the viewer
 /// can't read. Costs three queries total regardless of page size (policies,
 /// purchases, subscription).
-async fn apply_paywall_list(
+pub(crate) async fn apply_paywall_list(
     state: &AppState,
     posts: &mut [post::PostWithRelations],
     viewer: Option<&user::Model>,
@@ -190,7 +366,10 @@ pub async fn create(
 
     info!(user_id = user.id, "Creating post");
 
-    let new_post = payload.0.into_new_post(user.id);
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let new_post = payload.into_new_post(user.id);
 
     match post::Entity::create(&state.sea_db, &state.object_storage.public_url, new_post).await {
         Ok(post) => {
@@ -198,7 +377,18 @@ pub async fn create(
             tracing::Span::current().record("post_id", post.id);
             tracing::Span::current().record("slug", &post.slug);
             tracing::Span::current().record("result", "success");
-            Ok((StatusCode::CREATED, Json(json!(post))))
+            redirects::release(
+                &state.sea_db,
+                &redirect::post_path(&post.locale, &post.slug),
+            )
+            .await;
+            seo::save(&state, SeoEntityType::Post, post.id, seo_fields).await?;
+            sitemap::invalidate(&state.redis_pool).await;
+            if post.status == post::PostStatus::Published {
+                notifications::dispatch(&state, SiteEvent::PostPublished { post_id: post.id });
+            }
+            let body = seo::attach(&state, SeoEntityType::Post, post.id, json!(post)).await?;
+            Ok((StatusCode::CREATED, Json(body)))
         }
         Err(err) => {
             error!(error = ?err, user_id = user.id, "Failed to create post");
@@ -262,7 +452,11 @@ pub async fn find_by_id_or_slug(
             // Enforce the server-side paywall: strip `content` for unentitled
             // viewers of paid / subscriber-only posts.
             apply_paywall_single(&state, &mut post, auth.user.as_ref()).await?;
-            Ok((StatusCode::OK, Json(json!(post))))
+            attach_image_details(&state, std::slice::from_mut(&mut post), true).await?;
+            post.translations =
+                post::Entity::find_translations(&state.sea_db, &post, !bypass).await?;
+            let body = seo::attach(&state, SeoEntityType::Post, post.id, json!(post)).await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!("Post not found");
@@ -293,7 +487,15 @@ pub async fn update(
     })?;
     require_post_ownership(&state, post_id, &user).await?;
 
-    let update_post = payload.0.into_update_post();
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let update_post = payload.into_update_post();
+    let previous = post::Entity::find_by_id(post_id).one(&state.sea_db).await?;
+    let previous_path = previous
+        .as_ref()
+        .map(|p| redirect::post_path(&p.locale, &p.slug));
+    let was_published = previous.is_some_and(|p| p.status == post::PostStatus::Published);
 
     match post::Entity::update(
         &state.sea_db,
@@ -306,7 +508,21 @@ pub async fn update(
         Ok(Some(post)) => {
             info!(post_id, slug = %post.slug, "Post updated successfully");
             tracing::Span::current().record("result", "success");
-            Ok((StatusCode::OK, Json(json!(post))))
+            if let Some(old_path) = previous_path {
+                redirects::record_move(
+                    &state.sea_db,
+                    &old_path,
+                    &redirect::post_path(&post.locale, &post.slug),
+                )
+                .await;
+            }
+            seo::save(&state, SeoEntityType::Post, post_id, seo_fields).await?;
+            sitemap::invalidate(&state.redis_pool).await;
+            if !was_published && post.status == post::PostStatus::Published {
+                notifications::dispatch(&state, SiteEvent::PostPublished { post_id });
+            }
+            let body = seo::attach(&state, SeoEntityType::Post, post_id, json!(post)).await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!(post_id, "Post not found for update");
@@ -332,12 +548,24 @@ pub async fn delete(
         ErrorResponse::new(ErrorCode::Unauthorized).with_message("Not authenticated")
     })?;
     require_post_ownership(&state, post_id, &user).await?;
+    let existing = post::Entity::find_by_id(post_id).one(&state.sea_db).await?;
 
     match post::Entity::delete(&state.sea_db, post_id).await {
-        Ok(1) => Ok((
-            StatusCode::OK,
-            Json(json!({ "message": "Post deleted successfully" })),
-        )),
+        Ok(1) => {
+            if let Some(post) = existing {
+                redirects::record_gone(
+                    &state.sea_db,
+                    &redirect::post_path(&post.locale, &post.slug),
+                )
+                .await;
+            }
+            seo_override::Entity::delete_for(&state.sea_db, SeoEntityType::Post, post_id).await?;
+            sitemap::invalidate(&state.redis_pool).await;
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Post deleted successfully" })),
+            ))
+        }
         Ok(0) => {
             Err(ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Post does not exist"))
         }
@@ -365,6 +593,7 @@ pub async fn find_published_posts(
             // Strip gated content the viewer isn't entitled to (lists never need
             // full bodies of paid posts anyway).
             apply_paywall_list(&state, &mut posts, auth.user.as_ref()).await?;
+            attach_image_details(&state, &mut posts, false).await?;
             Ok((
                 StatusCode::OK,
                 Json(json!({
@@ -533,6 +762,9 @@ pub async fn autosave(
                 view_count: None,
                 likes_count: None,
                 tag_ids: None,
+                locale: None,
+                translation_of: None,
+                co_author_ids: None,
             };
 
             match post::Entity::update(
@@ -551,6 +783,144 @@ pub async fn autosave(
     }
 }
 
+/// Download a post's body rendered to HTML, Markdown or plain text, titled
+/// and named after its slug. Drafts included, so ownership is enforced like
+/// the revision endpoints.
+#[debug_handler]
+pub async fn export(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(post_id): Path<i32>,
+    payload: ValidatedJson<V1ExportPostPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.ok_or_else(|| {
+        ErrorResponse::new(ErrorCode::Unauthorized).with_message("Not authenticated")
+    })?;
+    require_post_ownership(&state, post_id, &user).await?;
+
+    let post = post::Entity::find_by_id(post_id)
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Post does not exist")
+        })?;
+
+    let (body, content_type, extension) = match payload.format {
+        V1ExportFormat::Html => (
+            format!(
+                "<h1>{}</h1>\n{}\n",
+                editorjs::escape_html(&post.title),
+                editorjs::to_html(&post.content)
+            ),
+            "text/html; charset=utf-8",
+            "html",
+        ),
+        V1ExportFormat::Markdown => (
+            format!(
+                "# {}\n\n{}",
+                editorjs::escape_markdown(&post.title),
+                editorjs::to_markdown(&post.content)
+            ),
+            "text/markdown; charset=utf-8",
+            "md",
+        ),
+        V1ExportFormat::Text => (
+            format!(
+                "{}\n\n{}\n",
+                post.title,
+                editorjs::to_plain_text(&post.content)
+            ),
+            "text/plain; charset=utf-8",
+            "txt",
+        ),
+    };
+
+    // Slugs are only length-validated; keep the header value plain ASCII.
+    let filename: String = post
+        .slug
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    let filename = if filename.is_empty() {
+        format!("post-{}", post.id)
+    } else {
+        filename
+    };
+
+    info!(post_id, user_id = user.id, format = ?payload.format, "Post exported");
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.{}\"", filename, extension),
+            ),
+        ],
+        body,
+    ))
+}
+
+/// Boilerplate CSS every AMP document must carry verbatim.
+const AMP_BOILERPLATE: &str = "<style amp-boilerplate>body{-webkit-animation:-amp-start 8s steps(1,end) 0s 1 normal both;-moz-animation:-amp-start 8s steps(1,end) 0s 1 normal both;-ms-animation:-amp-start 8s steps(1,end) 0s 1 normal both;animation:-amp-start 8s steps(1,end) 0s 1 normal both}@-webkit-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-moz-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-ms-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@-o-keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}@keyframes -amp-start{from{visibility:hidden}to{visibility:visible}}</style><noscript><style amp-boilerplate>body{-webkit-animation:none;-moz-animation:none;-ms-animation:none;animation:none}</style></noscript>";
+
+/// A complete AMP page around an already-sanitized `body`.
+fn amp_document(title: &str, locale: &str, canonical: &str, body: &str) -> String {
+    format!(
+        concat!(
+            "<!doctype html>\n<html \u{26a1} lang=\"{lang}\">\n<head>\n",
+            "<meta charset=\"utf-8\">\n",
+            "<script async src=\"https://cdn.ampproject.org/v0.js\"></script>\n",
+            "<title>{title}</title>\n",
+            "<link rel=\"canonical\" href=\"{canonical}\">\n",
+            "<meta name=\"viewport\" content=\"width=device-width\">\n",
+            "{boilerplate}\n",
+            "</head>\n<body>\n<article>\n<h1>{title}</h1>\n{body}\n</article>\n</body>\n</html>\n"
+        ),
+        lang = editorjs::escape_html(locale),
+        title = editorjs::escape_html(title),
+        canonical = editorjs::escape_html(canonical),
+        boilerplate = AMP_BOILERPLATE,
+        body = body,
+    )
+}
+
+/// Public AMP version of a published post, pointing back at its canonical
+/// page. Served anonymously like any AMP cache would fetch it, so gated
+/// posts show only their excerpt and a link to the site.
+#[debug_handler]
+pub async fn amp(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let mut post = post::Entity::find_by_id_or_slug(
+        &state.sea_db,
+        &state.object_storage.public_url,
+        None,
+        Some(slug),
+    )
+    .await?
+    .filter(|post| post.status == post::PostStatus::Published)
+    .ok_or_else(|| ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Post not found"))?;
+
+    apply_paywall_single(&state, &mut post, None).await?;
+    let canonical = sitemap::post_url(&sitemap::base_url(), &post.locale, &post.slug);
+    let body = if post.has_access {
+        editorjs::to_amp_html(&post.content)
+    } else {
+        format!(
+            "<p>{}</p>\n<p><a href=\"{}\">Read the full post</a></p>",
+            editorjs::escape_html(post.excerpt.as_deref().unwrap_or("")),
+            editorjs::escape_html(&canonical)
+        )
+    };
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+        amp_document(&post.title, &post.locale, &canonical, &body),
+    ))
+}
+
 #[debug_handler]
 pub async fn revisions_list(
     State(state): State<AppState>,
@@ -627,6 +997,9 @@ pub async fn revisions_restore(
         view_count: None,
         likes_count: None,
         tag_ids: None,
+        locale: None,
+        translation_of: None,
+        co_author_ids: None,
     };
 
     match post::Entity::update(
@@ -638,6 +1011,7 @@ pub async fn revisions_restore(
     .await
     {
         Ok(_) => {
+            sitemap::invalidate(&state.redis_pool).await;
             let meta = serde_json::json!({ "restored_from_revision_id": revision_id });
             match post_revision::Entity::create(
                 &state.sea_db,
@@ -725,7 +1099,10 @@ pub async fn series_update(
     )
     .await
     {
-        Ok(Some(series)) => Ok((StatusCode::OK, Json(json!(series)))),
+        Ok(Some(series)) => {
+            sitemap::invalidate(&state.redis_pool).await;
+            Ok((StatusCode::OK, Json(json!(series))))
+        }
         Ok(None) => {
             Err(ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Series not found"))
         }
@@ -749,10 +1126,13 @@ pub async fn series_delete(
     }
 
     match post_series::Entity::delete(&state.sea_db, series_id).await {
-        Ok(1) => Ok((
-            StatusCode::OK,
-            Json(json!({ "message": "Deleted successfully" })),
-        )),
+        Ok(1) => {
+            sitemap::invalidate(&state.redis_pool).await;
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Deleted successfully" })),
+            ))
+        }
         Ok(0) => {
             Err(ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Series not found"))
         }
@@ -762,6 +1142,35 @@ pub async fn series_delete(
     }
 }
 
+/// Public series page: the series and its published posts in reading
+/// order. Series without a published post are not found.
+#[debug_handler]
+pub async fn series_view(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let not_found =
+        || ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Series not found");
+    let public_url = &state.object_storage.public_url;
+
+    let series = post_series::Entity::find_by_slug(&state.sea_db, slug)
+        .await?
+        .ok_or_else(not_found)?;
+    let mut posts =
+        post::Entity::find_published_in_series(&state.sea_db, public_url, series.id).await?;
+    if posts.is_empty() {
+        return Err(not_found());
+    }
+    apply_paywall_list(&state, &mut posts, auth.user.as_ref()).await?;
+    attach_image_details(&state, &mut posts, false).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "series": series, "data": posts })),
+    ))
+}
+
 #[debug_handler]
 pub async fn series_list(
     State(state): State<AppState>,
@@ -820,7 +1229,10 @@ pub async fn series_add(
     };
 
     match post_series_post::Entity::add(&state.sea_db, payload).await {
-        Ok(model) => Ok((StatusCode::CREATED, Json(json!(model)))),
+        Ok(model) => {
+            sitemap::invalidate(&state.redis_pool).await;
+            Ok((StatusCode::CREATED, Json(json!(model))))
+        }
         Err(err) => Err(err),
     }
 }
@@ -842,10 +1254,13 @@ pub async fn series_remove(
     let payload = post_series_post::RemovePostSeriesPost { series_id, post_id };
 
     match post_series_post::Entity::remove(&state.sea_db, payload).await {
-        Ok(affected) if affected > 0 => Ok((
-            StatusCode::OK,
-            Json(json!({ "message": "Removed successfully" })),
-        )),
+        Ok(affected) if affected > 0 => {
+            sitemap::invalidate(&state.redis_pool).await;
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Removed successfully" })),
+            ))
+        }
         Ok(_) => {
             Err(ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Mapping not found"))
         }
@@ -1015,6 +1430,23 @@ mod tests {
     use super::*;
     use chrono::TimeZone;
 
+    #[test]
+    fn amp_document_carries_the_required_markup() {
+        let html = amp_document(
+            "A <b> title",
+            "en",
+            "https://example.com/posts/a?x=1&y=2",
+            "<p>Body</p>",
+        );
+        assert!(html.starts_with("<!doctype html>\n<html \u{26a1} lang=\"en\">"));
+        assert!(html.contains(r#"<script async src="https://cdn.ampproject.org/v0.js"></script>"#));
+        assert!(html
+            .contains(r#"<link rel="canonical" href="https://example.com/posts/a?x=1&amp;y=2">"#));
+        assert!(html.contains("<style amp-boilerplate>"));
+        assert!(html.contains("<title>A &lt;b&gt; title</title>"));
+        assert!(html.contains("<p>Body</p>"));
+    }
+
     /// Build a `user::Model` with the given id and role. Other fields are
     /// filled with benign defaults — only `id` and `role` drive the decision.
     fn make_user(id: i32, role: UserRole) -> user::Model {
@@ -1028,6 +1460,10 @@ mod tests {
             email: format!("user-{id}@example.com"),
             password: None,
             avatar_id: None,
+            bio: None,
+            website_url: None,
+            social_links: serde_json::json!([]),
+            profile_slug: None,
             is_verified: true,
             role,
             two_fa_enabled: false,
@@ -1086,4 +1522,108 @@ mod tests {
         let mod_user = make_user(200, UserRole::Moderator);
         assert!(!can_mutate_post(&mod_user, 7));
     }
+
+    // ── Image block details ──────────────────────────────────────────────
+
+    fn make_media(id: i32) -> media::Model {
+        let now = chrono::Utc
+            .with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
+            .unwrap()
+            .fixed_offset();
+        media::Model {
+            id,
+            bucket: None,
+            object_key: format!("uploads/{id}.jpg"),
+            mime_type: "image/jpeg".into(),
+            width: None,
+            height: None,
+            size: 1,
+            extension: Some("jpg".into()),
+            uploader_id: None,
+            reference_type: None,
+            content_hash: None,
+            is_optimized: false,
+            optimized_at: None,
+            alt_text: Some(format!("alt {id}")),
+            caption: Some(format!("caption {id}")),
+            credit: Some("Jane Doe".into()),
+            license: None,
+            focal_x: Some(0.25),
+            focal_y: Some(0.75),
+            blurhash: None,
+            duration_ms: None,
+            page_count: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn image_block_media_ids_dedupes_and_skips_other_blocks() {
+        let content = json!({"blocks": [
+            {"type": "paragraph", "data": {"text": "hi", "media_id": 9}},
+            {"type": "image", "data": {"file": {"url": "a", "media_id": 2}}},
+            {"type": "image", "data": {"url": "b", "media_id": 3}},
+            {"type": "image", "data": {"file": {"url": "a", "media_id": 2}}},
+        ]});
+        assert_eq!(image_block_media_ids(&content), vec![2, 3]);
+        assert!(image_block_media_ids(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn apply_image_details_merges_media_fields() {
+        let mut content = json!({"blocks": [
+            {"type": "image", "data": {"file": {"url": "a", "media_id": 2}, "caption": ""}},
+            {"type": "image", "data": {"file": {"url": "b", "media_id": 2}, "caption": "Own"}},
+            {"type": "image", "data": {"file": {"url": "c", "media_id": 5}}},
+        ]});
+        let details = HashMap::from([(2, make_media(2))]);
+        let responsive = HashMap::from([(
+            2,
+            media::responsive::build("https://cdn.test", &make_media(2), &[], None),
+        )]);
+
+        apply_image_details(&mut content, &details, &responsive, &HashMap::new());
+
+        let first = &content["blocks"][0]["data"];
+        assert_eq!(first["caption"], "caption 2");
+        assert_eq!(first["file"]["alt"], "alt 2");
+        assert_eq!(first["file"]["credit"], "Jane Doe");
+        assert!(first["file"]["license"].is_null());
+        assert_eq!(first["file"]["focal_x"], 0.25);
+        assert!(first["file"]["responsive"]["src"]
+            .as_str()
+            .is_some_and(|src| src.starts_with("https://cdn.test/")));
+
+        // A caption written in the editor wins over the library caption.
+        assert_eq!(content["blocks"][1]["data"]["caption"], "Own");
+        // Unknown media is left untouched.
+        assert!(content["blocks"][2]["data"]["file"].get("alt").is_none());
+    }
+
+    #[test]
+    fn attaches_blocks_get_playback_details_and_poster() {
+        let mut content = json!({"blocks": [
+            {"type": "attaches", "data": {"file": {"url": "v", "media_id": 7}, "title": "Clip"}},
+        ]});
+        assert_eq!(image_block_media_ids(&content), vec![7]);
+
+        let mut video = make_media(7);
+        video.mime_type = "video/mp4".into();
+        video.duration_ms = Some(90_500);
+        let details = HashMap::from([(7, video)]);
+        let posters = HashMap::from([(7, "https://cdn.test/v@poster.jpg".to_string())]);
+
+        apply_image_details(&mut content, &details, &HashMap::new(), &posters);
+
+        let data = &content["blocks"][0]["data"];
+        assert_eq!(data["title"], "Clip");
+        assert_eq!(data["file"]["mime_type"], "video/mp4");
+        assert_eq!(data["file"]["duration_ms"], 90_500);
+        assert!(data["file"]["page_count"].is_null());
+        assert_eq!(data["file"]["poster"], "https://cdn.test/v@poster.jpg");
+        // Image-only fields stay off attachments.
+        assert!(data["file"].get("alt").is_none());
+        assert!(data.get("caption").is_none());
+    }
 }
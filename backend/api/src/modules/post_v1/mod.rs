@@ -1,7 +1,12 @@
 pub mod controller;
 pub mod validator;
 
-use axum::{extract::DefaultBodyLimit, middleware, routing::post, Router};
+use axum::{
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{get, post},
+    Router,
+};
 
 use crate::{config, middlewares::auth_guard, AppState};
 
@@ -15,6 +20,7 @@ pub fn routes() -> Router<AppState> {
     let protected = Router::<AppState>::new()
         .route("/query", post(controller::query))
         .route("/delete/{post_id}", post(controller::delete))
+        .route("/export/{post_id}", post(controller::export))
         .route(
             "/revisions/{post_id}/list",
             post(controller::revisions_list),
@@ -59,6 +65,8 @@ pub fn routes() -> Router<AppState> {
         .route("/view/{id_or_slug}", post(controller::find_by_id_or_slug))
         .route("/list/published", post(controller::find_published_posts))
         .route("/sitemap", post(controller::sitemap))
+        .route("/amp/{slug}", get(controller::amp))
+        .route("/series/view/{slug}", get(controller::series_view))
         .route("/track_view/{post_id}", post(controller::track_view));
 
     protected.merge(authenticated).merge(public)
@@ -4,6 +4,8 @@ use serde_json::Value;
 use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::db::sea_models::post::{NewPost, PostQuery, PostStatus, UpdatePost};
+use crate::modules::seo_v1::validator::V1SeoPayload;
+use crate::utils::locale::{default_locale, normalize_locale, validate_locale};
 use crate::utils::SortParam;
 
 // Validated Editor.js document types
@@ -275,6 +277,18 @@ pub struct V1CreatePostPayload {
     pub category_id: i32,
     #[serde(default = "Vec::new")]
     pub tag_ids: Vec<i32>,
+    /// BCP 47 tag; defaults to `DEFAULT_LOCALE`.
+    #[validate(custom(function = "validate_locale"))]
+    pub locale: Option<String>,
+    /// Post this one is a translation of.
+    pub translation_of: Option<i32>,
+    /// Users credited after the creator, in order; guest contributors need
+    /// no author role.
+    #[serde(default = "Vec::new")]
+    #[validate(length(max = 20))]
+    pub co_author_ids: Vec<i32>,
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1CreatePostPayload {
@@ -296,6 +310,13 @@ impl V1CreatePostPayload {
             view_count: 0,
             likes_count: 0,
             tag_ids: self.tag_ids,
+            locale: self
+                .locale
+                .as_deref()
+                .and_then(normalize_locale)
+                .unwrap_or_else(default_locale),
+            translation_of: self.translation_of,
+            co_author_ids: self.co_author_ids,
         }
     }
 }
@@ -315,6 +336,15 @@ pub struct V1UpdatePostPayload {
     pub featured_image_id: Option<Option<i32>>,
     pub category_id: Option<i32>,
     pub tag_ids: Option<Vec<i32>>,
+    #[validate(custom(function = "validate_locale"))]
+    pub locale: Option<String>,
+    pub translation_of: Option<i32>,
+    /// Replaces the co-author list; omitted leaves it as it is.
+    #[validate(length(max = 20))]
+    pub co_author_ids: Option<Vec<i32>>,
+    /// SEO overrides; omitted leaves them as they are.
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1UpdatePostPayload {
@@ -333,6 +363,9 @@ impl V1UpdatePostPayload {
             view_count: None,
             likes_count: None,
             tag_ids: self.tag_ids,
+            locale: self.locale.as_deref().and_then(normalize_locale),
+            translation_of: self.translation_of,
+            co_author_ids: self.co_author_ids,
         }
     }
 }
@@ -347,6 +380,8 @@ pub struct V1PostQueryParams {
     pub sorts: Option<Vec<SortParam>>,
     pub tag_ids: Option<Vec<i32>>,
     pub title: Option<String>,
+    #[validate(custom(function = "validate_locale"))]
+    pub locale: Option<String>,
     // Date range filters
     pub created_at_gt: Option<DateTimeWithTimeZone>,
     pub created_at_lt: Option<DateTimeWithTimeZone>,
@@ -371,6 +406,7 @@ impl V1PostQueryParams {
             sorts: self.sorts,
             tag_ids: self.tag_ids,
             title: self.title,
+            locale: self.locale.as_deref().and_then(normalize_locale),
             created_at_gt: self.created_at_gt,
             created_at_lt: self.created_at_lt,
             updated_at_gt: self.updated_at_gt,
@@ -395,6 +431,20 @@ pub struct V1SchedulePayload {
     pub publish_at: DateTimeWithTimeZone,
 }
 
+/// Output format for `/export/{post_id}`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum V1ExportFormat {
+    Html,
+    Markdown,
+    Text,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1ExportPostPayload {
+    pub format: V1ExportFormat,
+}
+
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct V1SeriesCreatePayload {
     #[validate(length(min = 3, max = 255))]
@@ -0,0 +1,169 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use axum_macros::debug_handler;
+use sea_orm::EntityTrait;
+use serde_json::json;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    db::sea_models::redirect::Entity as Redirect,
+    error::{ErrorCode, ErrorResponse},
+    extractors::ValidatedJson,
+    services::auth::AuthSession,
+    AppState,
+};
+
+use super::validator::{
+    check_rule, V1CreateRedirectPayload, V1RedirectQueryParams, V1ResolveRedirectQuery,
+    V1UpdateRedirectPayload,
+};
+
+/// Create a manual redirect rule
+#[debug_handler]
+#[instrument(skip(state, _auth, payload), fields(redirect_id))]
+pub async fn create(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    payload: ValidatedJson<V1CreateRedirectPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let new_redirect = payload.0.into_new_redirect()?;
+
+    match Redirect::create(&state.sea_db, new_redirect).await {
+        Ok(result) => {
+            tracing::Span::current().record("redirect_id", result.id);
+            info!(redirect_id = result.id, "Redirect created");
+            Ok((StatusCode::CREATED, Json(json!(result))))
+        }
+        Err(err) => {
+            error!("Failed to create redirect: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Update a redirect rule. The merged rule is re-checked as a whole, so a
+/// partial edit cannot leave it without a target or pointing at itself.
+#[debug_handler]
+#[instrument(skip(state, _auth, payload), fields(redirect_id))]
+pub async fn update(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(redirect_id): Path<i32>,
+    payload: ValidatedJson<V1UpdateRedirectPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let existing = Redirect::find_by_id(redirect_id)
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Redirect does not exist")
+        })?;
+
+    let mut update = payload.0.into_update_redirect();
+    let status = update.status.unwrap_or(existing.status);
+    let (source_path, target_path) = check_rule(
+        update
+            .source_path
+            .as_deref()
+            .unwrap_or(&existing.source_path),
+        update
+            .target_path
+            .as_deref()
+            .or(existing.target_path.as_deref()),
+        status,
+    )?;
+    update.source_path = Some(source_path);
+    update.target_path = target_path;
+
+    match Redirect::update(&state.sea_db, redirect_id, update).await {
+        Ok(Some(redirect)) => {
+            info!(redirect_id, "Redirect updated");
+            Ok((StatusCode::OK, Json(json!(redirect))))
+        }
+        Ok(None) => {
+            warn!(redirect_id, "Redirect not found for update");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Redirect does not exist"))
+        }
+        Err(err) => {
+            error!(redirect_id, "Failed to update redirect: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Delete a redirect rule
+#[debug_handler]
+#[instrument(skip(state, _auth), fields(redirect_id))]
+pub async fn delete(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(redirect_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match Redirect::delete(&state.sea_db, redirect_id).await {
+        Ok(0) => {
+            warn!(redirect_id, "Redirect not found for delete");
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Redirect does not exist"))
+        }
+        Ok(_) => {
+            info!(redirect_id, "Redirect deleted");
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Redirect deleted successfully" })),
+            ))
+        }
+        Err(err) => {
+            error!(redirect_id, "Failed to delete redirect: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// List redirect rules with filters and paging
+#[debug_handler]
+#[instrument(skip(state, payload))]
+pub async fn find_with_query(
+    State(state): State<AppState>,
+    payload: ValidatedJson<V1RedirectQueryParams>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let query = payload.0.into_query();
+    let page = query.page.unwrap_or(1);
+
+    match Redirect::find_with_query(&state.sea_db, query).await {
+        Ok((redirects, total)) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "data": redirects,
+                "total": total,
+                "per_page": Redirect::PER_PAGE,
+                "page": page,
+            })),
+        )),
+        Err(err) => {
+            error!("Failed to query redirects: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Look up a path the consumer site could not serve. 404 when no rule
+/// matches; otherwise the status to answer with and where to send the
+/// visitor (`location` is null for 410 Gone).
+#[debug_handler]
+#[instrument(skip(state), fields(path = %params.path))]
+pub async fn resolve(
+    State(state): State<AppState>,
+    Query(params): Query<V1ResolveRedirectQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match Redirect::resolve(&state.sea_db, &params.path).await? {
+        Some(resolved) => Ok((StatusCode::OK, Json(json!(resolved)))),
+        None => {
+            Err(ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("No redirect for this path"))
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::db::sea_models::redirect::{
+    normalize_path, NewRedirect, RedirectMatchType, RedirectQuery, RedirectStatus, UpdateRedirect,
+};
+use crate::error::{ErrorCode, ErrorResponse};
+use crate::utils::SortParam;
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1CreateRedirectPayload {
+    #[validate(length(min = 2, max = 2048))]
+    pub source_path: String,
+    /// Site path or absolute http(s) URL. Omitted for `gone`.
+    #[validate(length(min = 1, max = 2048))]
+    pub target_path: Option<String>,
+    pub match_type: Option<RedirectMatchType>,
+    pub status: Option<RedirectStatus>,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+impl V1CreateRedirectPayload {
+    #[allow(clippy::result_large_err)]
+    pub fn into_new_redirect(self) -> Result<NewRedirect, ErrorResponse> {
+        let status = self.status.unwrap_or(RedirectStatus::Permanent);
+        let (source_path, target_path) =
+            check_rule(&self.source_path, self.target_path.as_deref(), status)?;
+        Ok(NewRedirect {
+            source_path,
+            target_path,
+            match_type: self.match_type.unwrap_or(RedirectMatchType::Exact),
+            status,
+            note: self.note,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1UpdateRedirectPayload {
+    #[validate(length(min = 2, max = 2048))]
+    pub source_path: Option<String>,
+    #[validate(length(min = 1, max = 2048))]
+    pub target_path: Option<String>,
+    pub match_type: Option<RedirectMatchType>,
+    pub status: Option<RedirectStatus>,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+impl V1UpdateRedirectPayload {
+    pub fn into_update_redirect(self) -> UpdateRedirect {
+        UpdateRedirect {
+            source_path: self.source_path,
+            target_path: self.target_path,
+            match_type: self.match_type,
+            status: self.status,
+            note: self.note,
+            updated_at: chrono::Utc::now().fixed_offset(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1RedirectQueryParams {
+    pub page: Option<u64>,
+    pub search: Option<String>,
+    pub sorts: Option<Vec<SortParam>>,
+    pub match_type: Option<RedirectMatchType>,
+    pub status: Option<RedirectStatus>,
+    pub is_automatic: Option<bool>,
+}
+
+impl V1RedirectQueryParams {
+    pub fn into_query(self) -> RedirectQuery {
+        RedirectQuery {
+            page: self.page,
+            search: self.search,
+            sorts: self.sorts,
+            match_type: self.match_type,
+            status: self.status,
+            is_automatic: self.is_automatic,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct V1ResolveRedirectQuery {
+    pub path: String,
+}
+
+fn invalid(message: &str) -> ErrorResponse {
+    ErrorResponse::new(ErrorCode::InvalidInput).with_message(message)
+}
+
+/// Normalize and cross-check a rule: the source must be a site path, the
+/// target a site path or absolute http(s) URL (absent only for `gone`), and
+/// a rule may not point at itself.
+#[allow(clippy::result_large_err)]
+pub fn check_rule(
+    source_path: &str,
+    target_path: Option<&str>,
+    status: RedirectStatus,
+) -> Result<(String, Option<String>), ErrorResponse> {
+    let source = normalize_path(source_path)
+        .ok_or_else(|| invalid("source_path must be a site path such as /posts/old-slug"))?;
+
+    if status == RedirectStatus::Gone {
+        return Ok((source, None));
+    }
+
+    let target = target_path
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| invalid("target_path is required unless the status is gone"))?;
+    let target = if target.starts_with("https://") || target.starts_with("http://") || target == "/"
+    {
+        target.to_string()
+    } else {
+        normalize_path(target)
+            .ok_or_else(|| invalid("target_path must be a site path or an http(s) URL"))?
+    };
+
+    if target == source {
+        return Err(invalid("A redirect cannot point at its own source_path"));
+    }
+    Ok((source, Some(target)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_both_ends_of_a_rule() {
+        let (source, target) =
+            check_rule("/old/", Some("/posts/new?x=1"), RedirectStatus::Permanent).unwrap();
+        assert_eq!(source, "/old");
+        assert_eq!(target.as_deref(), Some("/posts/new"));
+
+        let (_, target) = check_rule(
+            "/old",
+            Some("https://example.com/x"),
+            RedirectStatus::Temporary,
+        )
+        .unwrap();
+        assert_eq!(target.as_deref(), Some("https://example.com/x"));
+    }
+
+    #[test]
+    fn gone_rules_drop_the_target() {
+        let (source, target) =
+            check_rule("/posts/x", Some("/ignored"), RedirectStatus::Gone).unwrap();
+        assert_eq!(source, "/posts/x");
+        assert_eq!(target, None);
+    }
+
+    #[test]
+    fn rejects_bad_rules() {
+        assert!(check_rule("posts/x", Some("/a"), RedirectStatus::Permanent).is_err());
+        assert!(check_rule("/posts/x", None, RedirectStatus::Permanent).is_err());
+        assert!(check_rule(
+            "/posts/x",
+            Some("//evil.example"),
+            RedirectStatus::Permanent
+        )
+        .is_err());
+        assert!(check_rule(
+            "/posts/x",
+            Some("javascript:alert(1)"),
+            RedirectStatus::Permanent
+        )
+        .is_err());
+        assert!(check_rule("/posts/x", Some("/posts/x/"), RedirectStatus::Permanent).is_err());
+    }
+}
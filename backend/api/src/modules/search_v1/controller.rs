@@ -1,23 +1,43 @@
 //! Search controller — full-text search across published posts.
 
 use axum::{extract::State, Json};
-use sea_orm::{ColumnTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    ColumnTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect,
+};
 use serde::Deserialize;
 use validator::Validate;
 
 use crate::db::sea_models::post;
 use crate::error::codes::ErrorCode;
 use crate::error::response::ErrorResponse;
+use crate::utils::locale::normalize_locale;
 use crate::AppState;
 
 use super::validator::{SearchMeta, SearchQuery, SearchResponse, SearchResult};
 
+/// Body text only matches posts without a paid or subscriber-only rule;
+/// gated posts stay findable by title, excerpt and slug.
+const FREE_POST_SQL: &str = "NOT EXISTS (SELECT 1 FROM post_access pa \
+     WHERE pa.post_id = posts.id AND pa.access_type::text <> 'free')";
+
+fn matches_query(q: &str) -> SimpleExpr {
+    post::Column::Title
+        .contains(q)
+        .or(post::Column::Excerpt.contains(q))
+        .or(post::Column::Slug.contains(q))
+        .or(post::Column::SearchText
+            .contains(q)
+            .and(Expr::cust(FREE_POST_SQL)))
+}
+
 #[derive(Debug, FromQueryResult, Deserialize)]
 struct SearchRow {
     id: i32,
     title: String,
     slug: String,
     excerpt: Option<String>,
+    locale: String,
     status: String,
     published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     created_at: chrono::DateTime<chrono::FixedOffset>,
@@ -32,15 +52,20 @@ pub async fn search(
         return Err(ErrorResponse::new(ErrorCode::InvalidInput)
             .with_message("Search query must be 1-200 characters"));
     }
+    let locale = match query.locale.as_deref() {
+        Some(code) => Some(normalize_locale(code).ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::InvalidInput).with_message("Invalid locale")
+        })?),
+        None => None,
+    };
+
+    let mut select = post::Entity::find().filter(post::Column::Status.eq("published"));
+    if let Some(locale) = locale {
+        select = select.filter(post::Column::Locale.eq(locale));
+    }
 
-    let rows: Vec<SearchRow> = post::Entity::find()
-        .filter(post::Column::Status.eq("published"))
-        .filter(
-            post::Column::Title
-                .contains(&query.q)
-                .or(post::Column::Excerpt.contains(&query.q))
-                .or(post::Column::Slug.contains(&query.q)),
-        )
+    let rows: Vec<SearchRow> = select
+        .filter(matches_query(&query.q))
         .order_by_desc(post::Column::CreatedAt)
         .offset(query.offset())
         .limit(query.per_page())
@@ -58,6 +83,7 @@ pub async fn search(
             title: r.title,
             slug: r.slug,
             excerpt: r.excerpt,
+            locale: r.locale,
             status: r.status,
             published_at: r.published_at,
             created_at: r.created_at,
@@ -75,3 +101,23 @@ pub async fn search(
         },
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn body_matches_are_limited_to_free_posts() {
+        let sql = post::Entity::find()
+            .filter(matches_query("secret"))
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(
+            sql.contains(&format!(
+                r#"("posts"."search_text" LIKE '%secret%' AND ({FREE_POST_SQL}))"#
+            )),
+            "{sql}"
+        );
+    }
+}
@@ -11,6 +11,9 @@ pub struct SearchQuery {
     pub page: Option<u64>,
     #[serde(default)]
     pub per_page: Option<u64>,
+    /// Restrict results to one locale (BCP 47 tag).
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 impl SearchQuery {
@@ -36,6 +39,7 @@ pub struct SearchResult {
     pub title: String,
     pub slug: String,
     pub excerpt: Option<String>,
+    pub locale: String,
     pub status: String,
     pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
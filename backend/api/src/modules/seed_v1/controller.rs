@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use axum_macros::debug_handler;
+use bytes::Bytes;
 use fake::faker::internet::en::*;
 use fake::faker::lorem::raw as l;
 use fake::locales::EN;
@@ -26,6 +27,15 @@ use fake::Fake;
 use rand::Rng;
 use sea_orm::{ActiveModelTrait, EntityTrait, Set};
 
+/// 1x1 transparent PNG written for seeded image media.
+const PLACEHOLDER_PNG: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0A, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0x00, 0x01, 0x00, 0x00,
+    0x05, 0x00, 0x01, 0x0D, 0x0A, 0x2D, 0xB4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE,
+    0x42, 0x60, 0x82,
+];
+
 #[debug_handler(state = AppState)]
 pub async fn seed_tags(State(state): State<AppState>, _auth: AuthSession) -> impl IntoResponse {
     let mut tags: Vec<tag::Model> = vec![];
@@ -241,6 +251,9 @@ pub async fn seed_posts(State(state): State<AppState>, _auth: AuthSession) -> im
                 view_count: 0,
                 likes_count: 0,
                 tag_ids,
+                locale: crate::utils::locale::default_locale(),
+                translation_of: None,
+                co_author_ids: Vec::new(),
             };
 
             if let Err(err) =
@@ -351,9 +364,12 @@ pub async fn seed_post_comments(
             let new_comment = post_comment::NewComment {
                 post_id,
                 user_id: user.id,
-                // parent_id: None,
+                parent_id: None,
                 content,
                 likes_count: Some(0),
+                status: post_comment::CommentStatus::Approved,
+                spam_score: 0,
+                spam_reasons: Vec::new(),
             };
             if let Err(err) = post_comment::Entity::create(&state.sea_db, new_comment).await {
                 println!("Error creating comment: {:?}", err);
@@ -848,7 +864,7 @@ pub async fn seed_media(State(state): State<AppState>, _auth: AuthSession) -> im
     for (i, (filename, mime_type, width, height, size)) in fake_files.iter().enumerate() {
         let new_media = media::Model {
             id: 0, // Auto-increment
-            bucket: Some(state.object_storage.bucket.clone()),
+            bucket: Some(state.storage.bucket().to_string()),
             object_key: format!("seed/{}", filename),
             mime_type: mime_type.to_string(),
             width: *width,
@@ -872,6 +888,15 @@ pub async fn seed_media(State(state): State<AppState>, _auth: AuthSession) -> im
             } else {
                 None
             },
+            alt_text: None,
+            caption: None,
+            credit: None,
+            license: None,
+            focal_x: None,
+            focal_y: None,
+            blurhash: None,
+            duration_ms: None,
+            page_count: None,
             created_at: chrono::Utc::now().fixed_offset(),
             updated_at: chrono::Utc::now().fixed_offset(),
         };
@@ -890,12 +915,41 @@ pub async fn seed_media(State(state): State<AppState>, _auth: AuthSession) -> im
             content_hash: Set(new_media.content_hash),
             is_optimized: Set(new_media.is_optimized),
             optimized_at: Set(new_media.optimized_at),
+            alt_text: Set(new_media.alt_text),
+            caption: Set(new_media.caption),
+            credit: Set(new_media.credit),
+            license: Set(new_media.license),
+            focal_x: Set(new_media.focal_x),
+            focal_y: Set(new_media.focal_y),
+            blurhash: Set(new_media.blurhash),
+            duration_ms: Set(new_media.duration_ms),
+            page_count: Set(new_media.page_count),
             created_at: Set(new_media.created_at),
             updated_at: Set(new_media.updated_at),
         };
 
         match active_model.insert(&state.sea_db).await {
-            Ok(media) => media_list.push(media),
+            Ok(media) => {
+                // Give seeded images a real object behind them so their URLs
+                // resolve against whichever storage driver is configured.
+                if media.mime_type.starts_with("image/") {
+                    if let Err(err) = state
+                        .storage
+                        .put(
+                            &media.object_key,
+                            Bytes::from_static(PLACEHOLDER_PNG),
+                            "image/png",
+                        )
+                        .await
+                    {
+                        println!(
+                            "Error writing placeholder for {}: {}",
+                            media.object_key, err
+                        );
+                    }
+                }
+                media_list.push(media)
+            }
             Err(err) => println!("Error creating media: {:?}", err),
         }
     }
@@ -0,0 +1,183 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use sha2::{Digest, Sha256};
+use tracing::{error, instrument};
+
+use crate::{
+    db::sea_models::{
+        category, post,
+        seo_override::{self, SeoEntityType},
+        tag,
+    },
+    error::{ErrorCode, ErrorResponse},
+    services::og_image::{self, OgCard},
+    AppState,
+};
+
+/// Cards only change with the content they show, and the ETag tracks that.
+const CARD_CACHE_CONTROL: &str = "public, max-age=86400";
+
+fn card_not_found() -> ErrorResponse {
+    ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Nothing to render a card for")
+}
+
+/// The item a card is for, and what goes on it. Only content the public can
+/// see gets a card.
+async fn load_card(
+    state: &AppState,
+    entity_type: SeoEntityType,
+    slug: &str,
+    site_name: String,
+) -> Result<Option<(i32, OgCard)>, ErrorResponse> {
+    let found = match entity_type {
+        SeoEntityType::Post => post::Entity::find_by_id_or_slug(
+            &state.sea_db,
+            &state.object_storage.public_url,
+            None,
+            Some(slug.to_string()),
+        )
+        .await?
+        .filter(|p| p.status == post::PostStatus::Published)
+        .map(|p| {
+            let card = OgCard {
+                title: p.title,
+                label: Some(p.category.name),
+                byline: Some(p.author.name),
+                color: p.category.color,
+                site_name,
+            };
+            (p.id, card)
+        }),
+        SeoEntityType::Category => category::Entity::find()
+            .filter(category::Column::Slug.eq(slug))
+            .filter(category::Column::IsActive.eq(true))
+            .one(&state.sea_db)
+            .await?
+            .map(|c| {
+                let card = OgCard {
+                    title: c.name,
+                    label: Some("Category".to_string()),
+                    byline: c.description,
+                    color: c.color,
+                    site_name,
+                };
+                (c.id, card)
+            }),
+        SeoEntityType::Tag => tag::Entity::find()
+            .filter(tag::Column::Slug.eq(slug))
+            .filter(tag::Column::IsActive.eq(true))
+            .one(&state.sea_db)
+            .await?
+            .map(|t| {
+                let card = OgCard {
+                    title: format!("#{}", t.name),
+                    label: Some("Tag".to_string()),
+                    byline: t.description,
+                    color: t.color,
+                    site_name,
+                };
+                (t.id, card)
+            }),
+    };
+    Ok(found)
+}
+
+fn card_etag(card: &OgCard) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        card.title.as_str(),
+        card.label.as_deref().unwrap_or_default(),
+        card.byline.as_deref().unwrap_or_default(),
+        card.color.as_str(),
+        card.site_name.as_str(),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    format!("W/\"{}\"", hex::encode(&hasher.finalize()[..16]))
+}
+
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    let ours = etag.trim_start_matches("W/");
+    headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == ours)
+        })
+}
+
+/// Social card for a post, category or tag: `/og/{post|category|tag}/{slug}.png`.
+/// Redirects to the custom image when one is set, otherwise renders the
+/// branded card (using the override title, if any).
+#[instrument(skip(state, headers))]
+pub async fn og_image(
+    State(state): State<AppState>,
+    Path((entity_type, file)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Response, ErrorResponse> {
+    let entity_type = SeoEntityType::from_str(&entity_type).map_err(|_| card_not_found())?;
+    let slug = file.strip_suffix(".png").ok_or_else(card_not_found)?;
+    let site_name = std::env::var("SITE_NAME").unwrap_or_else(|_| "Ruxlog".to_string());
+
+    let (entity_id, mut card) = load_card(&state, entity_type, slug, site_name)
+        .await?
+        .ok_or_else(card_not_found)?;
+
+    let seo = seo_override::Entity::find_meta(
+        &state.sea_db,
+        &state.object_storage.public_url,
+        entity_type,
+        entity_id,
+    )
+    .await?;
+    if let Some(seo) = seo {
+        if let Some(image) = seo.og_image {
+            return Ok(Redirect::temporary(&image.file_url).into_response());
+        }
+        if let Some(title) = seo.meta_title {
+            card.title = title;
+        }
+    }
+
+    let etag = card_etag(&card);
+    if etag_matches(&headers, &etag) {
+        return Ok((
+            StatusCode::NOT_MODIFIED,
+            [
+                (header::ETAG, etag),
+                (header::CACHE_CONTROL, CARD_CACHE_CONTROL.to_string()),
+            ],
+        )
+            .into_response());
+    }
+
+    let png = tokio::task::spawn_blocking(move || og_image::render(&card))
+        .await
+        .map_err(|err| {
+            error!(error = ?err, "Open Graph card task failed");
+            ErrorResponse::new(ErrorCode::InternalServerError)
+        })?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::ServiceUnavailable)
+                .with_message("Social card rendering is unavailable")
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "image/png".to_string()),
+            (header::ETAG, etag),
+            (header::CACHE_CONTROL, CARD_CACHE_CONTROL.to_string()),
+        ],
+        png,
+    )
+        .into_response())
+}
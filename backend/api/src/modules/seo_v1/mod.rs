@@ -0,0 +1,17 @@
+#[cfg(feature = "og-image")]
+pub mod controller;
+pub mod validator;
+
+#[cfg(feature = "og-image")]
+use axum::routing::get;
+use axum::Router;
+
+use crate::AppState;
+
+pub fn routes() -> Router<AppState> {
+    let public = Router::<AppState>::new();
+    #[cfg(feature = "og-image")]
+    let public = public.route("/og/{entity_type}/{file}", get(controller::og_image));
+
+    public
+}
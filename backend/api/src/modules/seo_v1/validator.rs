@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::db::sea_models::seo_override::SeoFields;
+
+fn validate_canonical_url(url: &str) -> Result<(), ValidationError> {
+    let url = url.trim();
+    if url.is_empty() || url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("canonical_url")
+            .with_message("canonical_url must be an absolute http(s) URL".into()))
+    }
+}
+
+/// SEO overrides sent with a post, category or tag. Saving replaces the whole
+/// set; blank strings count as unset.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate)]
+pub struct V1SeoPayload {
+    #[validate(length(max = 200))]
+    pub meta_title: Option<String>,
+    #[validate(length(max = 500))]
+    pub meta_description: Option<String>,
+    #[validate(length(max = 2048), custom(function = "validate_canonical_url"))]
+    pub canonical_url: Option<String>,
+    #[serde(default)]
+    pub noindex: bool,
+    pub og_image_id: Option<i32>,
+}
+
+impl V1SeoPayload {
+    pub fn into_fields(self) -> SeoFields {
+        let clean = |value: Option<String>| {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        SeoFields {
+            meta_title: clean(self.meta_title),
+            meta_description: clean(self.meta_description),
+            canonical_url: clean(self.canonical_url),
+            noindex: self.noindex,
+            og_image_id: self.og_image_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blank_overrides_collapse_to_empty() {
+        let payload = V1SeoPayload {
+            meta_title: Some("  ".into()),
+            canonical_url: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(payload.validate().is_ok());
+        assert!(payload.into_fields().is_empty());
+    }
+
+    #[test]
+    fn canonical_url_must_be_http() {
+        let payload = V1SeoPayload {
+            canonical_url: Some("javascript:alert(1)".into()),
+            ..Default::default()
+        };
+        assert!(payload.validate().is_err());
+
+        let payload = V1SeoPayload {
+            canonical_url: Some("https://example.com/original".into()),
+            ..Default::default()
+        };
+        assert!(payload.validate().is_ok());
+    }
+}
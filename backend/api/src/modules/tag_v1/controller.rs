@@ -9,10 +9,13 @@ use serde_json::json;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
-    db::sea_models::tag::Entity as Tag,
+    db::sea_models::{
+        seo_override::{self, SeoEntityType},
+        tag::Entity as Tag,
+    },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
-    services::auth::AuthSession,
+    services::{auth::AuthSession, seo, sitemap},
     AppState,
 };
 
@@ -26,13 +29,18 @@ pub async fn create(
     _auth: AuthSession, // Assuming tag creation requires authentication
     payload: ValidatedJson<V1CreateTagPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let new_tag = payload.0.into_new_tag();
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let new_tag = payload.into_new_tag();
 
     match Tag::create(&state.sea_db, new_tag).await {
         Ok(result) => {
             tracing::Span::current().record("tag_id", result.id);
             info!(tag_id = result.id, "Tag created");
-            Ok((StatusCode::CREATED, Json(json!(result))))
+            seo::save(&state, SeoEntityType::Tag, result.id, seo_fields).await?;
+            let body = seo::attach(&state, SeoEntityType::Tag, result.id, json!(result)).await?;
+            Ok((StatusCode::CREATED, Json(body)))
         }
         Err(err) => {
             error!("Failed to create tag: {}", err);
@@ -50,12 +58,18 @@ pub async fn update(
     Path(tag_id): Path<i32>,
     payload: ValidatedJson<V1UpdateTagPayload>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let update_tag = payload.0.into_update_tag();
+    let mut payload = payload.0;
+    let seo_fields = payload.seo.take().map(|seo| seo.into_fields());
+    seo::check(&state.sea_db, seo_fields.as_ref()).await?;
+    let update_tag = payload.into_update_tag();
 
     match Tag::update(&state.sea_db, tag_id, update_tag).await {
         Ok(Some(tag)) => {
             info!(tag_id, "Tag updated");
-            Ok((StatusCode::OK, Json(json!(tag))))
+            seo::save(&state, SeoEntityType::Tag, tag_id, seo_fields).await?;
+            sitemap::invalidate(&state.redis_pool).await;
+            let body = seo::attach(&state, SeoEntityType::Tag, tag_id, json!(tag)).await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!(tag_id, "Tag not found for update");
@@ -79,6 +93,8 @@ pub async fn delete(
     match Tag::delete(&state.sea_db, tag_id).await {
         Ok(1) => {
             info!(tag_id, "Tag deleted");
+            seo_override::Entity::delete_for(&state.sea_db, SeoEntityType::Tag, tag_id).await?;
+            sitemap::invalidate(&state.redis_pool).await;
             Ok((
                 StatusCode::OK,
                 Json(json!({ "message": "Tag deleted successfully" })),
@@ -90,6 +106,8 @@ pub async fn delete(
         }
         Ok(_) => {
             info!(tag_id, "Tag deleted");
+            seo_override::Entity::delete_for(&state.sea_db, SeoEntityType::Tag, tag_id).await?;
+            sitemap::invalidate(&state.redis_pool).await;
             Ok((
                 StatusCode::OK,
                 Json(json!({ "message": "Tag deleted successfully" })),
@@ -112,7 +130,8 @@ pub async fn find_by_id(
     match Tag::find_by_id_with_404(&state.sea_db, tag_id).await {
         Ok(tag) => {
             info!(tag_id, "Tag retrieved");
-            Ok((StatusCode::OK, Json(json!(tag))))
+            let body = seo::attach(&state, SeoEntityType::Tag, tag_id, json!(tag)).await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Err(err) => {
             warn!(tag_id, "Tag not found");
@@ -144,7 +163,8 @@ pub async fn find_by_id_or_slug(
         Ok(Some(tag)) => {
             tracing::Span::current().record("tag_id", tag.id);
             info!(tag_id = tag.id, "Tag retrieved by id or slug");
-            Ok((StatusCode::OK, Json(json!(tag))))
+            let body = seo::attach(&state, SeoEntityType::Tag, tag.id, json!(tag)).await?;
+            Ok((StatusCode::OK, Json(body)))
         }
         Ok(None) => {
             warn!("Tag not found");
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 
 use crate::db::sea_models::tag::{NewTag, TagQuery, UpdateTag};
+use crate::modules::seo_v1::validator::V1SeoPayload;
 use crate::utils::SortParam;
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
@@ -18,6 +19,8 @@ pub struct V1CreateTagPayload {
     #[validate(custom(function = "validate_hex_color"), skip)]
     pub text_color: Option<String>,
     pub is_active: Option<bool>,
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1CreateTagPayload {
@@ -46,6 +49,9 @@ pub struct V1UpdateTagPayload {
     #[validate(custom(function = "validate_hex_color"), skip)]
     pub text_color: Option<String>,
     pub is_active: Option<bool>,
+    /// SEO overrides; omitted leaves them as they are.
+    #[validate(nested)]
+    pub seo: Option<V1SeoPayload>,
 }
 
 impl V1UpdateTagPayload {
@@ -12,10 +12,14 @@ use tracing::{error, info, instrument, warn};
 use super::validator::*;
 #[cfg_attr(not(feature = "full"), allow(unused_imports))]
 use crate::{
-    db::sea_models::user::{Entity as User, UserRole},
+    db::sea_models::{
+        media,
+        user::{Entity as User, UserRole},
+    },
     error::{ErrorCode, ErrorResponse},
     extractors::ValidatedJson,
-    services::auth::AuthSession,
+    modules::media_v1::controller::can_view_media,
+    services::{auth::AuthSession, blocklist::Blocklist},
     AppState,
 };
 
@@ -47,6 +51,38 @@ pub async fn update_profile(
             .with_message("You must be logged in to access this resource")
     })?;
 
+    if let Some(avatar_id) = payload.avatar_id {
+        let owned = media::Entity::find_by_id(&state.sea_db, avatar_id)
+            .await?
+            .is_some_and(|m| can_view_media(&user, m.uploader_id));
+        if !owned {
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Avatar must be one of your own uploads"));
+        }
+    }
+
+    if payload.name.is_some() || payload.email.is_some() {
+        let blocklist = Blocklist::load(&state.sea_db).await?;
+        if let Some(found) = payload
+            .name
+            .as_deref()
+            .and_then(|n| blocklist.check_name(n))
+        {
+            warn!(user_id = user.id, pattern = %found.pattern, "Profile name blocked");
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("This name is not allowed"));
+        }
+        if let Some(found) = payload
+            .email
+            .as_deref()
+            .and_then(|e| blocklist.check_email(e))
+        {
+            warn!(user_id = user.id, pattern = %found.pattern, "Profile email blocked");
+            return Err(ErrorResponse::new(ErrorCode::InvalidInput)
+                .with_message("Email addresses from this domain are not allowed"));
+        }
+    }
+
     let payload = payload.0.into_update_user();
     match User::update(&state.sea_db, user.id, payload).await {
         Ok(Some(user)) => {
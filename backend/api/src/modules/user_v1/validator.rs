@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
 
 use crate::db::sea_models::user::{
-    AdminCreateUser, AdminUpdateUser, AdminUserQuery, UpdateUser, UserRole,
+    AdminCreateUser, AdminUpdateUser, AdminUserQuery, AuthorProfileFields, SocialLink, UpdateUser,
+    UserRole,
 };
 use crate::utils::SortParam;
 
@@ -14,6 +15,102 @@ use crate::utils::SortParam;
 const PASSWORD_MIN: u64 = 12;
 const PASSWORD_MAX: u64 = 256;
 
+const SOCIAL_LINKS_MAX: usize = 10;
+
+fn is_http_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+fn validate_website_url(url: &str) -> Result<(), ValidationError> {
+    let url = url.trim();
+    if url.is_empty() || is_http_url(url) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("website_url")
+            .with_message("website_url must be an absolute http(s) URL".into()))
+    }
+}
+
+fn validate_social_links(links: &[SocialLink]) -> Result<(), ValidationError> {
+    if links.len() > SOCIAL_LINKS_MAX {
+        return Err(ValidationError::new("social_links")
+            .with_message(format!("At most {SOCIAL_LINKS_MAX} social links").into()));
+    }
+    for link in links {
+        let label = link.label.trim();
+        if label.is_empty() || label.chars().count() > 40 {
+            return Err(ValidationError::new("social_links")
+                .with_message("Each link needs a label of at most 40 characters".into()));
+        }
+        if link.url.len() > 2048 || !is_http_url(link.url.trim()) {
+            return Err(ValidationError::new("social_links")
+                .with_message("Each link must be an absolute http(s) URL".into()));
+        }
+    }
+    Ok(())
+}
+
+/// Lowercase letters, digits and single hyphens, with at least one letter so
+/// a slug never collides with the numeric-id fallback of author pages.
+fn validate_profile_slug(slug: &str) -> Result<(), ValidationError> {
+    let slug = slug.trim();
+    if slug.is_empty() {
+        return Ok(());
+    }
+    let well_formed = (3..=64).contains(&slug.len())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && !slug.contains("--")
+        && slug.chars().any(|c| c.is_ascii_lowercase());
+    if well_formed {
+        Ok(())
+    } else {
+        Err(ValidationError::new("profile_slug").with_message(
+            "profile_slug must be 3-64 lowercase letters, digits or hyphens and contain a letter"
+                .into(),
+        ))
+    }
+}
+
+/// Public author profile fields, shared by the self-service and admin update
+/// payloads. Omitted fields are left alone; blank strings clear them.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate)]
+pub struct V1AuthorProfilePayload {
+    #[validate(length(max = 2000))]
+    pub bio: Option<String>,
+    #[validate(length(max = 2048), custom(function = "validate_website_url"))]
+    pub website_url: Option<String>,
+    #[validate(custom(function = "validate_social_links"))]
+    pub social_links: Option<Vec<SocialLink>>,
+    #[validate(custom(function = "validate_profile_slug"))]
+    pub profile_slug: Option<String>,
+}
+
+impl V1AuthorProfilePayload {
+    pub fn into_fields(self) -> AuthorProfileFields {
+        let clean = |value: Option<String>| {
+            value.map(|v| Some(v.trim().to_string()).filter(|v| !v.is_empty()))
+        };
+        AuthorProfileFields {
+            bio: clean(self.bio),
+            website_url: clean(self.website_url),
+            social_links: self.social_links.map(|links| {
+                links
+                    .into_iter()
+                    .map(|link| SocialLink {
+                        label: link.label.trim().to_string(),
+                        url: link.url.trim().to_string(),
+                    })
+                    .collect()
+            }),
+            profile_slug: clean(self.profile_slug),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct V1UpdateProfilePayload {
     #[validate(length(min = 1))]
@@ -22,6 +119,11 @@ pub struct V1UpdateProfilePayload {
     pub email: Option<String>,
     #[validate(length(min = PASSWORD_MIN, max = PASSWORD_MAX))]
     pub password: Option<String>,
+    /// Must be the user's own upload.
+    pub avatar_id: Option<i32>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub profile: V1AuthorProfilePayload,
 }
 
 impl V1UpdateProfilePayload {
@@ -29,6 +131,8 @@ impl V1UpdateProfilePayload {
         UpdateUser {
             name: self.name,
             email: self.email,
+            avatar_id: self.avatar_id,
+            profile: self.profile.into_fields(),
             updated_at: chrono::Utc::now().fixed_offset(),
         }
     }
@@ -86,6 +190,9 @@ pub struct V1AdminUpdateUserPayload {
     pub is_verified: Option<bool>,
     #[validate(custom(function = "validate_role"))]
     pub role: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub profile: V1AuthorProfilePayload,
 }
 
 impl V1AdminUpdateUserPayload {
@@ -97,6 +204,7 @@ impl V1AdminUpdateUserPayload {
             password: self.password,
             is_verified: self.is_verified,
             role: self.role.and_then(|r| UserRole::from_str(&r).ok()),
+            profile: self.profile.into_fields(),
             updated_at: chrono::Utc::now().fixed_offset(),
         }
     }
@@ -139,3 +247,30 @@ impl V1AdminUserQueryParams {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_slug_rejects_numeric_and_malformed_handles() {
+        assert!(validate_profile_slug("jane-doe-2").is_ok());
+        assert!(validate_profile_slug("").is_ok());
+        assert!(validate_profile_slug("12345").is_err());
+        assert!(validate_profile_slug("Jane").is_err());
+        assert!(validate_profile_slug("-jane").is_err());
+        assert!(validate_profile_slug("jane--doe").is_err());
+    }
+
+    #[test]
+    fn social_links_must_be_http_urls() {
+        let link = |url: &str| SocialLink {
+            label: "Site".into(),
+            url: url.into(),
+        };
+        assert!(validate_social_links(&[link("https://example.com")]).is_ok());
+        assert!(validate_social_links(&[link("javascript:alert(1)")]).is_err());
+        let too_many = vec![link("https://example.com"); SOCIAL_LINKS_MAX + 1];
+        assert!(validate_social_links(&too_many).is_err());
+    }
+}
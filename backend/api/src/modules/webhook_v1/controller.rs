@@ -0,0 +1,262 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use axum_macros::debug_handler;
+use sea_orm::EntityTrait;
+use serde_json::json;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    db::sea_models::{
+        webhook_delivery::Entity as WebhookDelivery,
+        webhook_endpoint::{self, Entity as WebhookEndpoint},
+    },
+    error::{ErrorCode, ErrorResponse},
+    extractors::ValidatedJson,
+    services::{auth::AuthSession, webhooks},
+    AppState,
+};
+
+use super::validator::{
+    V1CreateWebhookEndpointPayload, V1UpdateWebhookEndpointPayload, V1WebhookDeliveryQueryParams,
+    V1WebhookEndpointQueryParams,
+};
+
+fn endpoint_not_found() -> ErrorResponse {
+    ErrorResponse::new(ErrorCode::RecordNotFound).with_message("Webhook endpoint does not exist")
+}
+
+/// The endpoint with its signing secret, which is otherwise never returned.
+fn with_secret(endpoint: &webhook_endpoint::Model) -> serde_json::Value {
+    let mut body = json!(endpoint);
+    body["secret"] = json!(endpoint.secret);
+    body
+}
+
+#[allow(clippy::result_large_err)]
+async fn check_target(url: &str) -> Result<(), ErrorResponse> {
+    webhooks::check_target(url)
+        .await
+        .map_err(|message| ErrorResponse::new(ErrorCode::InvalidInput).with_message(&message))
+}
+
+/// Register a webhook endpoint. The response carries its signing secret.
+#[debug_handler]
+#[instrument(skip(state, auth, payload), fields(endpoint_id))]
+pub async fn create(
+    State(state): State<AppState>,
+    auth: AuthSession,
+    payload: ValidatedJson<V1CreateWebhookEndpointPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let user = auth.user.unwrap();
+    let new_endpoint = payload
+        .0
+        .into_new_endpoint(user.id, webhooks::generate_secret());
+    check_target(&new_endpoint.url).await?;
+
+    match WebhookEndpoint::create(&state.sea_db, new_endpoint).await {
+        Ok(endpoint) => {
+            tracing::Span::current().record("endpoint_id", endpoint.id);
+            info!(endpoint_id = endpoint.id, "Webhook endpoint created");
+            Ok((StatusCode::CREATED, Json(with_secret(&endpoint))))
+        }
+        Err(err) => {
+            error!("Failed to create webhook endpoint: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Update a webhook endpoint's URL, description, events or active flag
+#[debug_handler]
+#[instrument(skip(state, _auth, payload))]
+pub async fn update(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(endpoint_id): Path<i32>,
+    payload: ValidatedJson<V1UpdateWebhookEndpointPayload>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let update = payload.0.into_update_endpoint();
+    if let Some(url) = update.url.as_deref() {
+        check_target(url).await?;
+    }
+
+    match WebhookEndpoint::update(&state.sea_db, endpoint_id, update).await {
+        Ok(Some(endpoint)) => {
+            info!(endpoint_id, "Webhook endpoint updated");
+            Ok((StatusCode::OK, Json(json!(endpoint))))
+        }
+        Ok(None) => {
+            warn!(endpoint_id, "Webhook endpoint not found for update");
+            Err(endpoint_not_found())
+        }
+        Err(err) => {
+            error!(endpoint_id, "Failed to update webhook endpoint: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Delete a webhook endpoint and its delivery log
+#[debug_handler]
+#[instrument(skip(state, _auth))]
+pub async fn delete(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(endpoint_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match WebhookEndpoint::delete(&state.sea_db, endpoint_id).await {
+        Ok(0) => {
+            warn!(endpoint_id, "Webhook endpoint not found for delete");
+            Err(endpoint_not_found())
+        }
+        Ok(_) => {
+            info!(endpoint_id, "Webhook endpoint deleted");
+            Ok((
+                StatusCode::OK,
+                Json(json!({ "message": "Webhook endpoint deleted successfully" })),
+            ))
+        }
+        Err(err) => {
+            error!(endpoint_id, "Failed to delete webhook endpoint: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Replace an endpoint's signing secret. Deliveries sent from now on use
+/// the new secret, retries included.
+#[debug_handler]
+#[instrument(skip(state, _auth))]
+pub async fn rotate_secret(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(endpoint_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    match WebhookEndpoint::rotate_secret(&state.sea_db, endpoint_id, webhooks::generate_secret())
+        .await
+    {
+        Ok(Some(endpoint)) => {
+            info!(endpoint_id, "Webhook endpoint secret rotated");
+            Ok((StatusCode::OK, Json(with_secret(&endpoint))))
+        }
+        Ok(None) => Err(endpoint_not_found()),
+        Err(err) => {
+            error!(endpoint_id, "Failed to rotate webhook secret: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Send a signed test ping to an endpoint and report how it answered
+#[debug_handler]
+#[instrument(skip(state, _auth))]
+pub async fn test(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(endpoint_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let endpoint = WebhookEndpoint::find_by_id(endpoint_id)
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(endpoint_not_found)?;
+
+    let result = webhooks::send_test(&endpoint).await;
+    info!(
+        endpoint_id,
+        success = result.success,
+        response_status = result.response_status,
+        "Webhook test sent"
+    );
+    Ok((StatusCode::OK, Json(json!(result))))
+}
+
+/// List webhook endpoints with paging
+#[debug_handler]
+#[instrument(skip(state, payload))]
+pub async fn find_with_query(
+    State(state): State<AppState>,
+    payload: ValidatedJson<V1WebhookEndpointQueryParams>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let query = payload.0.into_query();
+    let page = query.page.unwrap_or(1);
+
+    match WebhookEndpoint::find_with_query(&state.sea_db, query).await {
+        Ok((endpoints, total)) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "data": endpoints,
+                "total": total,
+                "per_page": WebhookEndpoint::PER_PAGE,
+                "page": page,
+            })),
+        )),
+        Err(err) => {
+            error!("Failed to query webhook endpoints: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// The delivery log, newest first, filtered by endpoint, event or status
+#[debug_handler]
+#[instrument(skip(state, payload))]
+pub async fn deliveries(
+    State(state): State<AppState>,
+    payload: ValidatedJson<V1WebhookDeliveryQueryParams>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let query = payload.0.into_query();
+    let page = query.page.unwrap_or(1);
+
+    match WebhookDelivery::find_with_query(&state.sea_db, query).await {
+        Ok((deliveries, total)) => Ok((
+            StatusCode::OK,
+            Json(json!({
+                "data": deliveries,
+                "total": total,
+                "per_page": WebhookDelivery::PER_PAGE,
+                "page": page,
+            })),
+        )),
+        Err(err) => {
+            error!("Failed to query webhook deliveries: {}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Send a logged delivery again. The copy keeps the original message id and
+/// body and goes out on the worker's next pass.
+#[debug_handler]
+#[instrument(skip(state, _auth))]
+pub async fn redeliver(
+    State(state): State<AppState>,
+    _auth: AuthSession,
+    Path(delivery_id): Path<i32>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let delivery = WebhookDelivery::find_by_id(delivery_id)
+        .one(&state.sea_db)
+        .await?
+        .ok_or_else(|| {
+            ErrorResponse::new(ErrorCode::RecordNotFound)
+                .with_message("Webhook delivery does not exist")
+        })?;
+
+    match webhooks::redeliver(&state.sea_db, &delivery).await {
+        Ok(copy) => {
+            info!(
+                delivery_id,
+                redelivery_id = copy.id,
+                "Webhook redelivery queued"
+            );
+            Ok((StatusCode::ACCEPTED, Json(json!(copy))))
+        }
+        Err(err) => {
+            error!(delivery_id, "Failed to queue webhook redelivery: {}", err);
+            Err(err)
+        }
+    }
+}
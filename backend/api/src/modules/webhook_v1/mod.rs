@@ -0,0 +1,27 @@
+pub mod controller;
+pub mod validator;
+
+use axum::{middleware, routing::post, Router};
+
+use crate::{middlewares::auth_guard, AppState};
+
+pub fn routes() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route("/create", post(controller::create))
+        .route("/update/{endpoint_id}", post(controller::update))
+        .route("/delete/{endpoint_id}", post(controller::delete))
+        .route(
+            "/rotate_secret/{endpoint_id}",
+            post(controller::rotate_secret),
+        )
+        .route("/test/{endpoint_id}", post(controller::test))
+        .route("/list/query", post(controller::find_with_query))
+        .route("/deliveries/query", post(controller::deliveries))
+        .route(
+            "/deliveries/redeliver/{delivery_id}",
+            post(controller::redeliver),
+        )
+        .route_layer(middleware::from_fn(
+            auth_guard::verified_with_role::<{ auth_guard::ROLE_ADMIN }>,
+        ))
+}
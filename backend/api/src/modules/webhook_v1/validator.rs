@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::db::sea_models::{
+    webhook_delivery::{WebhookDeliveryQuery, WebhookDeliveryStatus},
+    webhook_endpoint::{
+        NewWebhookEndpoint, UpdateWebhookEndpoint, WebhookEndpointQuery, WebhookEvent,
+    },
+};
+
+fn validate_endpoint_url(url: &str) -> Result<(), ValidationError> {
+    let url = url.trim();
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(ValidationError::new("url").with_message("url must be an absolute http(s) URL".into()))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1CreateWebhookEndpointPayload {
+    #[validate(
+        length(min = 1, max = 2048),
+        custom(function = "validate_endpoint_url")
+    )]
+    pub url: String,
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    /// Event types to receive, e.g. `post.published`.
+    #[validate(length(min = 1, max = 16))]
+    pub events: Vec<WebhookEvent>,
+    pub is_active: Option<bool>,
+}
+
+impl V1CreateWebhookEndpointPayload {
+    pub fn into_new_endpoint(self, created_by: i32, secret: String) -> NewWebhookEndpoint {
+        NewWebhookEndpoint {
+            url: self.url.trim().to_string(),
+            description: self.description,
+            secret,
+            events: dedupe(self.events),
+            is_active: self.is_active.unwrap_or(true),
+            created_by: Some(created_by),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate)]
+pub struct V1UpdateWebhookEndpointPayload {
+    #[validate(
+        length(min = 1, max = 2048),
+        custom(function = "validate_endpoint_url")
+    )]
+    pub url: Option<String>,
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    #[validate(length(min = 1, max = 16))]
+    pub events: Option<Vec<WebhookEvent>>,
+    pub is_active: Option<bool>,
+}
+
+impl V1UpdateWebhookEndpointPayload {
+    pub fn into_update_endpoint(self) -> UpdateWebhookEndpoint {
+        UpdateWebhookEndpoint {
+            url: self.url.map(|url| url.trim().to_string()),
+            description: self.description,
+            events: self.events.map(dedupe),
+            is_active: self.is_active,
+            updated_at: chrono::Utc::now().fixed_offset(),
+        }
+    }
+}
+
+fn dedupe(events: Vec<WebhookEvent>) -> Vec<WebhookEvent> {
+    WebhookEvent::ALL
+        .into_iter()
+        .filter(|event| events.contains(event))
+        .collect()
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1WebhookEndpointQueryParams {
+    pub page: Option<u64>,
+}
+
+impl V1WebhookEndpointQueryParams {
+    pub fn into_query(self) -> WebhookEndpointQuery {
+        WebhookEndpointQuery { page: self.page }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Validate)]
+pub struct V1WebhookDeliveryQueryParams {
+    pub page: Option<u64>,
+    pub endpoint_id: Option<i32>,
+    pub event: Option<WebhookEvent>,
+    pub status: Option<WebhookDeliveryStatus>,
+}
+
+impl V1WebhookDeliveryQueryParams {
+    pub fn into_query(self) -> WebhookDeliveryQuery {
+        WebhookDeliveryQuery {
+            page: self.page,
+            endpoint_id: self.endpoint_id,
+            event: self.event,
+            status: self.status,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use axum::{
-    extract::State,
+    extract::{DefaultBodyLimit, Path, State},
     http::{header, StatusCode},
     middleware,
-    routing::{get, post},
+    response::IntoResponse,
+    routing::{get, post, put},
     Json, Router,
 };
 use tower_http::{
@@ -13,7 +14,8 @@ use tracing::Level;
 
 use crate::middlewares::{http_metrics, rate_limit, request_id_middleware, security_headers};
 use crate::modules::{
-    auth_v1, category_v1, csrf_v1, feed_v1, media_v1, post_v1, search_v1, tag_v1, user_v1,
+    auth_v1, author_v1, blocklist_v1, category_v1, csrf_v1, feed_v1, media_v1, notification_v1,
+    post_v1, redirect_v1, search_v1, seo_v1, tag_v1, user_v1, webhook_v1,
 };
 use fred::interfaces::ClientLike;
 
@@ -44,7 +46,7 @@ use crate::modules::seed_v1;
 #[cfg(feature = "billing")]
 use crate::modules::billing_v1;
 
-use crate::utils::sanitize::xml_escape;
+use crate::services::sitemap::{self, Sitemap};
 
 use super::AppState;
 
@@ -53,6 +55,7 @@ pub fn router(state: AppState) -> Router<AppState> {
         .route("/healthz", get(health_check))
         .route("/robots.txt", get(robots_txt))
         .route("/sitemap.xml", get(sitemap_xml))
+        .route("/sitemaps/{file}", get(child_sitemap_xml))
         // Per-session CSRF token issuer. Lives inside the main router so the
         // SessionManagerLayer and csrf_guard both apply (it's exempted from the
         // latter via the exact-match exempt list). The handler bootstraps a new
@@ -110,7 +113,36 @@ pub fn router(state: AppState) -> Router<AppState> {
             "/media/v1",
             media_v1::routes().layer(rate_limit::RateLimitLayer::new(state.clone(), 30, 60)),
         )
+        // Files and presigned part uploads for the local storage driver;
+        // both 404 under S3.
+        .route(
+            "/media/_parts/{upload_id}/{part_number}",
+            put(media_v1::controller::put_part).layer(DefaultBodyLimit::max(
+                crate::config::body_limits::MEDIA_UPLOAD_PART,
+            )),
+        )
+        .route("/media/{*path}", get(media_v1::controller::serve_file))
         .nest("/feed/v1", feed_v1::routes())
+        .nest("/author/v1", author_v1::routes())
+        .nest("/blocklist/v1", blocklist_v1::routes())
+        .nest("/webhook/v1", webhook_v1::routes())
+        // Unsubscribe links are public, so the nest gets a per-IP cap.
+        .nest(
+            "/notification/v1",
+            notification_v1::routes().layer(rate_limit::RateLimitLayer::new(state.clone(), 60, 60)),
+        )
+        // The consumer site resolves every unknown URL here, crawler probes
+        // included, so the public lookup shares a per-IP cap.
+        .nest(
+            "/redirect/v1",
+            redirect_v1::routes().layer(rate_limit::RateLimitLayer::new(state.clone(), 120, 60)),
+        )
+        // Social cards are rendered on a cache miss; crawlers fetch them once
+        // per share, so a modest per-IP cap keeps rendering bounded.
+        .nest(
+            "/seo/v1",
+            seo_v1::routes().layer(rate_limit::RateLimitLayer::new(state.clone(), 60, 60)),
+        )
         // DOS-SEARCH-1: search runs a triple leading-wildcard ILIKE (full table
         // scan) per request and was previously un-rate-limited. 30/min/IP bounds
         // an anonymous caller cheaply minting a CSRF token then replaying it.
@@ -129,7 +161,13 @@ pub fn router(state: AppState) -> Router<AppState> {
 
     #[cfg(feature = "analytics")]
     {
-        router = router.nest("/analytics/v1", analytics_v1::routes());
+        // `/collect` is public and fires on every consumer page view; 200/min
+        // per IP covers fast navigation without letting one client flood the
+        // events table.
+        router = router.nest(
+            "/analytics/v1",
+            analytics_v1::routes().layer(rate_limit::RateLimitLayer::new(state.clone(), 200, 60)),
+        );
     }
 
     #[cfg(feature = "admin-routes")]
@@ -219,17 +257,21 @@ async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<serde_
 async fn robots_txt() -> (
     StatusCode,
     [(axum::http::HeaderName, &'static str); 1],
-    &'static str,
+    String,
 ) {
     (
         StatusCode::OK,
         [(header::CONTENT_TYPE, "text/plain")],
-        "User-agent: *\nAllow: /\nDisallow: /admin/\nDisallow: /api/\nDisallow: /auth/\n\nSitemap: https://ruxlog.com/sitemap.xml\n",
+        format!(
+            "User-agent: *\nAllow: /\nDisallow: /admin/\nDisallow: /api/\nDisallow: /auth/\n\nSitemap: {}/sitemap.xml\n",
+            sitemap::base_url()
+        ),
     )
 }
 
-async fn sitemap_xml(
-    State(state): State<AppState>,
+async fn serve_sitemap(
+    state: &AppState,
+    sitemap: Sitemap,
 ) -> Result<
     (
         StatusCode,
@@ -238,40 +280,27 @@ async fn sitemap_xml(
     ),
     StatusCode,
 > {
-    use crate::db::sea_models::post;
-
-    let posts = post::Entity::sitemap(&state.sea_db)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let raw_base_url =
-        std::env::var("CONSUMER_SITE_URL").unwrap_or_else(|_| "https://ruxlog.com".to_string());
-    // SITEMAP-XML-1: escape both the operator base URL and every post slug
-    // before interpolating into XML. Slugs are author-controlled and only
-    // length-validated, so unescaped interpolation is a stored XML-injection
-    // vector. `feed_v1` already escapes its RSS output the same way.
-    let base_url = xml_escape(&raw_base_url);
-
-    let mut urls = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
-    urls.push_str(&format!(
-        "  <url><loc>{}/</loc><changefreq>daily</changefreq><priority>1.0</priority></url>\n",
-        base_url
-    ));
-
-    for p in &posts {
-        let lastmod = p.updated_at.to_rfc3339();
-        let slug = xml_escape(&p.slug);
-        urls.push_str(&format!(
-            "  <url><loc>{}/posts/{}</loc><lastmod>{}</lastmod><changefreq>weekly</changefreq><priority>0.8</priority></url>\n",
-            base_url, slug, lastmod
-        ));
+    match sitemap::render(state, sitemap).await {
+        Ok(Some(xml)) => Ok((
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "application/xml")],
+            xml,
+        )),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
+}
 
-    urls.push_str("</urlset>");
+async fn sitemap_xml(State(state): State<AppState>) -> impl IntoResponse {
+    serve_sitemap(&state, Sitemap::Index).await
+}
 
-    Ok((
-        StatusCode::OK,
-        [(header::CONTENT_TYPE, "application/xml")],
-        urls,
-    ))
+async fn child_sitemap_xml(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+) -> impl IntoResponse {
+    match Sitemap::from_file_name(&file) {
+        Some(sitemap) => serve_sitemap(&state, sitemap).await,
+        None => Err(StatusCode::NOT_FOUND),
+    }
 }
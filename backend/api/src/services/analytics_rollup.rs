@@ -0,0 +1,513 @@
+//! Daily analytics rollups and raw `post_views` retention.
+//!
+//! A background task folds complete UTC days of `post_views`,
+//! `post_comments` and `users` into the `analytics_daily_*` tables and moves
+//! a watermark (`analytics_rollup_state.rolled_up_through`) forward. Reports
+//! read rolled-up days from those tables and only scan raw rows after the
+//! watermark, which is normally just today. Once a day is rolled up, raw
+//! views older than the retention window can be pruned or archived.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Duration as ChronoDuration, FixedOffset, NaiveDate, Utc};
+use sea_orm::{
+    sea_query::Value, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, FromQueryResult,
+    Statement, TransactionTrait,
+};
+use serde::Serialize;
+use tracing::{error, info, instrument, warn};
+
+use crate::error::ErrorResponse;
+use crate::state::AppState;
+
+const ROLLUP_NAME: &str = "daily";
+
+/// Distinct-viewer key for `post_views`: the user when signed in, otherwise
+/// the IP address. Matches `VIEW_DAYS.raw_cols`.
+const VIEWER_KEY: &str = "COALESCE(user_id::TEXT, CONCAT('ip:', COALESCE(ip_address, '')))";
+
+/// Runtime settings for the rollup job. Raw views are kept forever unless
+/// `raw_retention_days` is set.
+#[derive(Debug, Clone)]
+pub struct AnalyticsRollupConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    /// Upper bound on days folded per pass, so backfilling a long history
+    /// runs as a series of short transactions.
+    pub max_days_per_pass: u64,
+    pub raw_retention_days: u64,
+    /// Move pruned rows to `post_views_archive` instead of deleting them.
+    pub archive: bool,
+    pub prune_batch: u64,
+}
+
+impl Default for AnalyticsRollupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 60 * 60,
+            max_days_per_pass: 31,
+            raw_retention_days: 0,
+            archive: true,
+            prune_batch: 5000,
+        }
+    }
+}
+
+/// Parameters for a single rollup pass.
+#[derive(Debug, Clone, Copy)]
+pub struct AnalyticsRollupOptions {
+    /// Recompute from this day instead of continuing after the watermark.
+    /// Never earlier than the oldest raw view still kept.
+    pub rebuild_from: Option<NaiveDate>,
+    pub max_days: u64,
+    pub raw_retention_days: u64,
+    pub archive: bool,
+    pub prune_batch: u64,
+}
+
+impl From<&AnalyticsRollupConfig> for AnalyticsRollupOptions {
+    fn from(config: &AnalyticsRollupConfig) -> Self {
+        Self {
+            rebuild_from: None,
+            max_days: config.max_days_per_pass,
+            raw_retention_days: config.raw_retention_days,
+            archive: config.archive,
+            prune_batch: config.prune_batch,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AnalyticsRollupReport {
+    pub rolled_up_from: Option<NaiveDate>,
+    /// The watermark after this pass.
+    pub rolled_up_through: Option<NaiveDate>,
+    pub days: u64,
+    /// Set when `rebuild_from` reached into already-pruned days.
+    pub rebuild_clamped_to: Option<NaiveDate>,
+    pub pruned_views: u64,
+    pub archived: bool,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct RollupStateRow {
+    rolled_up_through: Option<NaiveDate>,
+    raw_pruned_before: Option<NaiveDate>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct EarliestDayRow {
+    day: Option<NaiveDate>,
+}
+
+/// Start the rollup job as a background tokio task.
+pub fn start_analytics_rollup(state: AppState, config: AnalyticsRollupConfig) {
+    if !config.enabled {
+        info!("Analytics rollups disabled");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        raw_retention_days = config.raw_retention_days,
+        archive = config.archive,
+        "Analytics rollups started"
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(60)));
+        loop {
+            interval.tick().await;
+            match run_once(&state, AnalyticsRollupOptions::from(&config)).await {
+                Ok(report) => {
+                    if report.days > 0 || report.pruned_views > 0 {
+                        info!(
+                            from = ?report.rolled_up_from,
+                            through = ?report.rolled_up_through,
+                            days = report.days,
+                            pruned_views = report.pruned_views,
+                            "Analytics rollup pass finished"
+                        );
+                    }
+                }
+                Err(err) => error!(error = %err, "Analytics rollup pass failed"),
+            }
+        }
+    });
+}
+
+/// The last UTC day folded into the rollup tables, if any. Reports read
+/// rollups through this day and raw rows after it.
+pub async fn rolled_up_through(db: &DatabaseConnection) -> Result<Option<NaiveDate>, DbErr> {
+    Ok(load_state(db).await?.and_then(|row| row.rolled_up_through))
+}
+
+/// The first UTC day whose raw `post_views` are still kept, if pruning has
+/// run. Earlier views survive only as UTC-day rollups.
+pub async fn raw_pruned_before(db: &DatabaseConnection) -> Result<Option<NaiveDate>, DbErr> {
+    Ok(load_state(db).await?.and_then(|row| row.raw_pruned_before))
+}
+
+/// Whether a raw-view read starting at `date_from` stays inside retained
+/// history.
+pub fn raw_views_cover(date_from: DateTime<FixedOffset>, pruned_before: Option<NaiveDate>) -> bool {
+    pruned_before.is_none_or(|before| date_from.with_timezone(&Utc).date_naive() >= before)
+}
+
+async fn load_state<C: ConnectionTrait>(db: &C) -> Result<Option<RollupStateRow>, DbErr> {
+    RollupStateRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        "SELECT rolled_up_through, raw_pruned_before FROM analytics_rollup_state WHERE name = $1",
+        vec![ROLLUP_NAME.into()],
+    ))
+    .one(db)
+    .await
+}
+
+/// Fold the next batch of complete days into the rollup tables, then apply
+/// the retention policy to raw views.
+#[instrument(skip(state))]
+pub async fn run_once(
+    state: &AppState,
+    options: AnalyticsRollupOptions,
+) -> Result<AnalyticsRollupReport, ErrorResponse> {
+    let db = &state.sea_db;
+    let today = Utc::now().date_naive();
+    let yesterday = today - ChronoDuration::days(1);
+    let mut report = AnalyticsRollupReport {
+        archived: options.archive,
+        ..Default::default()
+    };
+
+    let saved = load_state(db).await?;
+    let watermark = saved.as_ref().and_then(|row| row.rolled_up_through);
+    let pruned_before = saved.as_ref().and_then(|row| row.raw_pruned_before);
+
+    let from = match options.rebuild_from {
+        // Days whose raw views are gone can only be kept as they are.
+        Some(day) => match pruned_before {
+            Some(floor) if day < floor => {
+                warn!(requested = %day, floor = %floor, "Rollup rebuild clamped to retained raw views");
+                report.rebuild_clamped_to = Some(floor);
+                Some(floor)
+            }
+            _ => Some(day),
+        },
+        None => match watermark {
+            Some(day) => Some(day + ChronoDuration::days(1)),
+            None => earliest_day(db).await?,
+        },
+    };
+
+    if let Some(from) = from.filter(|from| *from <= yesterday) {
+        let span = options.max_days.max(1) as i64 - 1;
+        let through = (from + ChronoDuration::days(span)).min(yesterday);
+        roll_up(db, from, through).await?;
+        report.rolled_up_from = Some(from);
+        report.days = (through - from).num_days() as u64 + 1;
+        report.rolled_up_through = Some(through);
+    } else {
+        report.rolled_up_through = watermark;
+    }
+
+    if options.raw_retention_days > 0 {
+        if let Some(through) = report.rolled_up_through {
+            // Only days that are already rolled up may lose their raw rows.
+            let cutoff = (today - ChronoDuration::days(options.raw_retention_days as i64))
+                .min(through + ChronoDuration::days(1));
+            report.pruned_views =
+                prune_views(db, cutoff, options.archive, options.prune_batch).await?;
+        }
+    }
+
+    Ok(report)
+}
+
+async fn earliest_day(db: &DatabaseConnection) -> Result<Option<NaiveDate>, DbErr> {
+    let row = EarliestDayRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Postgres,
+        r#"
+        SELECT (LEAST(
+            (SELECT MIN(created_at) FROM post_views),
+            (SELECT MIN(created_at) FROM post_comments),
+            (SELECT MIN(created_at) FROM users)
+        ) AT TIME ZONE 'UTC')::DATE AS day
+        "#,
+    ))
+    .one(db)
+    .await?;
+    Ok(row.and_then(|row| row.day))
+}
+
+/// Replace the rollups for `[from, through]` and move the watermark to
+/// `through`, atomically.
+async fn roll_up(
+    db: &DatabaseConnection,
+    from: NaiveDate,
+    through: NaiveDate,
+) -> Result<(), DbErr> {
+    let txn = db.begin().await?;
+    let range = || -> Vec<Value> { vec![from.into(), through.into()] };
+
+    for table in [
+        "analytics_daily_post_views",
+        "analytics_daily_comments",
+        "analytics_daily_signups",
+    ] {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!("DELETE FROM {table} WHERE day >= $1 AND day <= $2"),
+            range(),
+        ))
+        .await?;
+    }
+
+    // `$1`/`$2` are UTC days; the raw scans cover `[$1 00:00, $2 + 1 00:00)`.
+    let window = "created_at >= $1::TIMESTAMP AT TIME ZONE 'UTC' \
+                  AND created_at < ($2 + 1)::TIMESTAMP AT TIME ZONE 'UTC'";
+    let inserts = [
+        format!(
+            r#"
+            INSERT INTO analytics_daily_post_views (day, post_id, views, unique_visitors)
+            SELECT
+                (created_at AT TIME ZONE 'UTC')::DATE,
+                post_id,
+                COUNT(*),
+                COUNT(DISTINCT {VIEWER_KEY})
+            FROM post_views
+            WHERE {window}
+            GROUP BY 1, 2
+            "#
+        ),
+        format!(
+            r#"
+            INSERT INTO analytics_daily_comments (day, post_id, comments)
+            SELECT (created_at AT TIME ZONE 'UTC')::DATE, post_id, COUNT(*)
+            FROM post_comments
+            WHERE {window}
+            GROUP BY 1, 2
+            "#
+        ),
+        format!(
+            r#"
+            INSERT INTO analytics_daily_signups (day, new_users)
+            SELECT (created_at AT TIME ZONE 'UTC')::DATE, COUNT(*)
+            FROM users
+            WHERE {window}
+            GROUP BY 1
+            "#
+        ),
+    ];
+    for sql in inserts {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            sql,
+            range(),
+        ))
+        .await?;
+    }
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        r#"
+        INSERT INTO analytics_rollup_state (name, rolled_up_through, updated_at)
+        VALUES ($1, $2, NOW())
+        ON CONFLICT (name) DO UPDATE
+        SET rolled_up_through = EXCLUDED.rolled_up_through, updated_at = NOW()
+        "#,
+        vec![ROLLUP_NAME.into(), through.into()],
+    ))
+    .await?;
+
+    txn.commit().await
+}
+
+/// Delete (or archive) raw views created before `cutoff` in batches, and
+/// record the new retention floor.
+async fn prune_views(
+    db: &DatabaseConnection,
+    cutoff: NaiveDate,
+    archive: bool,
+    batch: u64,
+) -> Result<u64, DbErr> {
+    let batch = batch.max(1);
+    let select = "SELECT id FROM post_views \
+                  WHERE created_at < $1::TIMESTAMP AT TIME ZONE 'UTC' \
+                  ORDER BY id LIMIT $2";
+    let sql = if archive {
+        format!(
+            r#"
+            WITH moved AS (
+                DELETE FROM post_views WHERE id IN ({select})
+                RETURNING id, post_id, user_id, ip_address, user_agent, created_at
+            )
+            INSERT INTO post_views_archive (id, post_id, user_id, ip_address, user_agent, created_at)
+            SELECT id, post_id, user_id, ip_address, user_agent, created_at FROM moved
+            ON CONFLICT (id) DO NOTHING
+            "#
+        )
+    } else {
+        format!("DELETE FROM post_views WHERE id IN ({select})")
+    };
+
+    let mut pruned = 0;
+    loop {
+        let result = db
+            .execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                sql.as_str(),
+                vec![cutoff.into(), Value::BigInt(Some(batch as i64))],
+            ))
+            .await?;
+        pruned += result.rows_affected();
+        if result.rows_affected() < batch {
+            break;
+        }
+    }
+
+    db.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Postgres,
+        r#"
+        UPDATE analytics_rollup_state
+        SET raw_pruned_before = GREATEST(COALESCE(raw_pruned_before, $2), $2)
+        WHERE name = $1
+        "#,
+        vec![ROLLUP_NAME.into(), cutoff.into()],
+    ))
+    .await?;
+
+    Ok(pruned)
+}
+
+/// `COALESCE(through, -infinity)` for a nullable watermark expression.
+fn watermark(through: &str) -> String {
+    format!("COALESCE({through}::DATE, '-infinity'::DATE)")
+}
+
+/// A rolled-up table and the raw table it is built from, exposed to reports
+/// as one `{name}` CTE keyed by `day`.
+struct DaysSource {
+    name: &'static str,
+    rollup: &'static str,
+    rollup_cols: &'static str,
+    raw: &'static str,
+    /// Aggregates over the raw rows, in the order of `rollup_cols` after `day`.
+    raw_cols: &'static str,
+    group_by: &'static str,
+}
+
+const VIEW_DAYS: DaysSource = DaysSource {
+    name: "view_days",
+    rollup: "analytics_daily_post_views",
+    rollup_cols: "day, post_id, views, unique_visitors",
+    raw: "post_views",
+    raw_cols: "post_id, COUNT(*)::BIGINT, COUNT(DISTINCT COALESCE(user_id::TEXT, CONCAT('ip:', COALESCE(ip_address, ''))))::BIGINT",
+    group_by: "1, 2",
+};
+
+const COMMENT_DAYS: DaysSource = DaysSource {
+    name: "comment_days",
+    rollup: "analytics_daily_comments",
+    rollup_cols: "day, post_id, comments",
+    raw: "post_comments",
+    raw_cols: "post_id, COUNT(*)::BIGINT",
+    group_by: "1, 2",
+};
+
+const SIGNUP_DAYS: DaysSource = DaysSource {
+    name: "signup_days",
+    rollup: "analytics_daily_signups",
+    rollup_cols: "day, new_users",
+    raw: "users",
+    raw_cols: "COUNT(*)::BIGINT",
+    group_by: "1",
+};
+
+/// Rolled-up rows for the UTC days of `[from, to]` through the watermark,
+/// raw rows grouped by day after it.
+fn days_cte(source: &DaysSource, from: &str, to: &str, through: &str) -> String {
+    let DaysSource {
+        name,
+        rollup,
+        rollup_cols,
+        raw,
+        raw_cols,
+        group_by,
+    } = source;
+    let through = watermark(through);
+    format!(
+        r#"
+        {name} AS (
+            SELECT {rollup_cols}
+            FROM {rollup}
+            WHERE day >= ({from} AT TIME ZONE 'UTC')::DATE
+              AND day <= LEAST(({to} AT TIME ZONE 'UTC')::DATE, {through})
+            UNION ALL
+            SELECT (created_at AT TIME ZONE 'UTC')::DATE AS day, {raw_cols}
+            FROM {raw}
+            WHERE created_at >= GREATEST({from}, ({through} + 1)::TIMESTAMP AT TIME ZONE 'UTC')
+              AND created_at <= {to}
+            GROUP BY {group_by}
+        )
+        "#
+    )
+}
+
+/// `view_days(day, post_id, views, unique_visitors)` over `[from, to]`
+/// (timestamptz expressions), using rollups through `through` (a nullable
+/// date expression, see [`rolled_up_through`]).
+pub fn post_view_days_cte(from: &str, to: &str, through: &str) -> String {
+    days_cte(&VIEW_DAYS, from, to, through)
+}
+
+/// `comment_days(day, post_id, comments)`; see [`post_view_days_cte`].
+pub fn comment_days_cte(from: &str, to: &str, through: &str) -> String {
+    days_cte(&COMMENT_DAYS, from, to, through)
+}
+
+/// `signup_days(day, new_users)`; see [`post_view_days_cte`].
+pub fn signup_days_cte(from: &str, to: &str, through: &str) -> String {
+    days_cte(&SIGNUP_DAYS, from, to, through)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_follow_config_without_rebuild() {
+        let config = AnalyticsRollupConfig {
+            raw_retention_days: 90,
+            archive: false,
+            ..Default::default()
+        };
+        let options = AnalyticsRollupOptions::from(&config);
+        assert!(options.rebuild_from.is_none());
+        assert_eq!(options.max_days, config.max_days_per_pass);
+        assert_eq!(options.raw_retention_days, 90);
+        assert!(!options.archive);
+    }
+
+    #[test]
+    fn days_cte_splits_rollup_and_raw_at_the_watermark() {
+        let sql = post_view_days_cte("$1", "$2", "$9");
+        assert!(sql.contains("view_days AS ("));
+        assert!(sql.contains("FROM analytics_daily_post_views"));
+        assert!(sql.contains("FROM post_views"));
+        assert_eq!(
+            sql.matches("COALESCE($9::DATE, '-infinity'::DATE)").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn raw_views_cover_compares_in_utc() {
+        let before = NaiveDate::from_ymd_opt(2026, 3, 10);
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+        assert!(raw_views_cover(at("2026-01-01T00:00:00Z"), None));
+        assert!(raw_views_cover(at("2026-03-10T00:00:00Z"), before));
+        // Local midnight on the 10th east of UTC is still the 9th in UTC.
+        assert!(!raw_views_cover(at("2026-03-10T00:00:00+05:00"), before));
+        assert!(raw_views_cover(at("2026-03-10T00:00:00-05:00"), before));
+    }
+}
@@ -10,12 +10,12 @@ use std::net::IpAddr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use maxminddb::Reader as MaxMindReader;
 use serde::Deserialize;
 
 use super::provider::{
     BillingError, BillingProvider, CheckoutSession, ParsedWebhook, SubscriptionInfo, WebhookEvent,
 };
+use crate::services::geoip::{GeoInfo, GeoIp};
 
 // ── Config types ──────────────────────────────────────────────────────────
 
@@ -75,42 +75,19 @@ impl GeoRulesConfig {
 
 // ── Geo lookup ────────────────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
-struct GeoInfo {
-    country_code: Option<String>,
-    continent_code: Option<String>,
-}
-
 /// Resolves an IP to a provider name using MaxMind + routing rules.
 pub struct GeoRouter {
-    reader: Option<MaxMindReader<Vec<u8>>>,
+    geoip: Arc<GeoIp>,
     rules: Vec<RoutingRule>,
     default_provider: String,
 }
 
 impl GeoRouter {
-    pub fn new(config: GeoRulesConfig) -> Self {
-        let reader = match std::env::var("GEOLITE2_DB_PATH") {
-            Ok(path) if !path.is_empty() => match MaxMindReader::open_readfile(&path) {
-                Ok(r) => {
-                    tracing::info!(path = %path, "GeoLite2 database loaded");
-                    Some(r)
-                }
-                Err(e) => {
-                    tracing::warn!(path = %path, error = %e, "Failed to load GeoLite2 database, geo-routing disabled");
-                    None
-                }
-            },
-            _ => {
-                tracing::info!(
-                    "GEOLITE2_DB_PATH not set, geo-routing disabled (using default provider)"
-                );
-                None
-            }
-        };
-
+    /// `geoip` is the process-wide GeoLite2 reader; without a database every
+    /// checkout falls through to the default provider.
+    pub fn new(config: GeoRulesConfig, geoip: Arc<GeoIp>) -> Self {
         Self {
-            reader,
+            geoip,
             rules: config.rules,
             default_provider: config.default_provider,
         }
@@ -145,21 +122,7 @@ impl GeoRouter {
     }
 
     fn lookup_geo(&self, ip: IpAddr) -> Option<GeoInfo> {
-        let reader = self.reader.as_ref()?;
-        // maxminddb 0.27 split lookup into `lookup()` (returns a LookupResult
-        // handle) + `decode::<T>()` (materialises the typed record). We resolve
-        // both errors (corrupt/truncated DB, decode failure) and missing-data
-        // (None) to a plain `None` — geo routing always falls back gracefully.
-        let result = reader.lookup(ip).ok()?;
-        let geoip: maxminddb::geoip2::Country<'_> = result.decode().ok()??;
-
-        let country_code = geoip.country.iso_code.map(String::from);
-        let continent_code = geoip.continent.code.map(String::from);
-
-        Some(GeoInfo {
-            country_code,
-            continent_code,
-        })
+        self.geoip.lookup(ip)
     }
 
     fn rule_matches(&self, rule: &RoutingRule, geo: &Option<GeoInfo>) -> bool {
@@ -546,9 +509,8 @@ mod tests {
 
     impl GeoRouter {
         fn new_for_test(rules: Vec<RoutingRule>, default_provider: String) -> Self {
-            let reader: Option<MaxMindReader<Vec<u8>>> = None;
             Self {
-                reader,
+                geoip: Arc::new(GeoIp::disabled()),
                 rules,
                 default_provider,
             }
@@ -0,0 +1,270 @@
+//! Admin-managed blocklists, checked when comments are posted or edited and
+//! when accounts are registered or renamed.
+//!
+//! Entries live in `blocklist_entries` (see the `blocklist_v1` module) and
+//! come in four kinds:
+//! - `word`: a word or phrase, matched case-insensitively on word boundaries
+//! - `regex`: a case-insensitive regular expression
+//! - `domain`: a domain, matched against hosts mentioned in comments
+//!   (`spam.example` also blocks `www.spam.example` and `a.spam.example`)
+//! - `email_domain`: the domain part of a registering email, with the same
+//!   subdomain rule
+//!
+//! Words and regexes apply to comment content and user names. The list is
+//! loaded from the database for every check; it is small and only consulted
+//! on writes.
+
+use std::sync::LazyLock;
+
+use regex::{Regex, RegexBuilder};
+use sea_orm::DbConn;
+use tracing::warn;
+
+use crate::db::sea_models::blocklist_entry::{self, BlocklistKind};
+use crate::error::DbResult;
+
+/// Compiled size cap for admin-supplied regexes, so one entry cannot blow
+/// up memory or matching time.
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Host names mentioned in free text, with or without a scheme.
+static HOST_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b((?:[a-z0-9](?:[a-z0-9-]*[a-z0-9])?\.)+[a-z]{2,})\b").unwrap()
+});
+
+/// Compile a `regex` entry the way it will be matched.
+pub fn compile_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+/// Reduce a `domain` / `email_domain` entry to a bare lowercase host:
+/// `https://www.Spam.example/path` becomes `spam.example`. Returns `None`
+/// when nothing host-like is left.
+pub fn normalize_domain(input: &str) -> Option<String> {
+    let mut host = input.trim().to_lowercase();
+    if let Some((_, rest)) = host.split_once("://") {
+        host = rest.to_string();
+    }
+    if let Some((_, rest)) = host.rsplit_once('@') {
+        host = rest.to_string();
+    }
+    let host = host
+        .split(['/', '?', '#', ':'])
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("www.")
+        .trim_matches('.')
+        .to_string();
+
+    let valid = host.contains('.')
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+    valid.then_some(host)
+}
+
+fn host_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// The entry that caused a check to fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlocklistMatch {
+    pub kind: BlocklistKind,
+    pub pattern: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Blocklist {
+    words: Vec<(String, Regex)>,
+    regexes: Vec<(String, Regex)>,
+    domains: Vec<String>,
+    email_domains: Vec<String>,
+}
+
+impl Blocklist {
+    /// Compile entries into matchers. Entries that no longer compile (or
+    /// normalize) are skipped with a warning rather than failing every check.
+    pub fn compile(entries: &[blocklist_entry::Model]) -> Self {
+        let mut list = Self::default();
+        for entry in entries {
+            match entry.kind {
+                BlocklistKind::Word => {
+                    let source = format!(r"\b{}\b", regex::escape(entry.pattern.trim()));
+                    match compile_regex(&source) {
+                        Ok(re) => list.words.push((entry.pattern.clone(), re)),
+                        Err(err) => warn!(entry_id = entry.id, "Skipping blocklist word: {}", err),
+                    }
+                }
+                BlocklistKind::Regex => match compile_regex(&entry.pattern) {
+                    Ok(re) => list.regexes.push((entry.pattern.clone(), re)),
+                    Err(err) => warn!(entry_id = entry.id, "Skipping blocklist regex: {}", err),
+                },
+                BlocklistKind::Domain => match normalize_domain(&entry.pattern) {
+                    Some(domain) => list.domains.push(domain),
+                    None => warn!(entry_id = entry.id, "Skipping invalid blocklist domain"),
+                },
+                BlocklistKind::EmailDomain => match normalize_domain(&entry.pattern) {
+                    Some(domain) => list.email_domains.push(domain),
+                    None => warn!(
+                        entry_id = entry.id,
+                        "Skipping invalid blocklist email domain"
+                    ),
+                },
+            }
+        }
+        list
+    }
+
+    pub async fn load(conn: &DbConn) -> DbResult<Self> {
+        let entries = blocklist_entry::Entity::find_all(conn).await?;
+        Ok(Self::compile(&entries))
+    }
+
+    fn check_patterns(&self, text: &str) -> Option<BlocklistMatch> {
+        let hit = |kind: BlocklistKind, list: &[(String, Regex)]| {
+            list.iter()
+                .find(|(_, re)| re.is_match(text))
+                .map(|(pattern, _)| BlocklistMatch {
+                    kind,
+                    pattern: pattern.clone(),
+                })
+        };
+        hit(BlocklistKind::Word, &self.words).or_else(|| hit(BlocklistKind::Regex, &self.regexes))
+    }
+
+    /// Words, regexes and blocked domains in comment content.
+    pub fn check_comment(&self, content: &str) -> Option<BlocklistMatch> {
+        if let Some(found) = self.check_patterns(content) {
+            return Some(found);
+        }
+        HOST_RE.captures_iter(content).find_map(|caps| {
+            let host = caps[1].to_lowercase();
+            let host = host.trim_start_matches("www.");
+            self.domains
+                .iter()
+                .find(|domain| host_matches(host, domain))
+                .map(|domain| BlocklistMatch {
+                    kind: BlocklistKind::Domain,
+                    pattern: domain.clone(),
+                })
+        })
+    }
+
+    /// Words and regexes in a display name.
+    pub fn check_name(&self, name: &str) -> Option<BlocklistMatch> {
+        self.check_patterns(name)
+    }
+
+    /// The domain part of an email address.
+    pub fn check_email(&self, email: &str) -> Option<BlocklistMatch> {
+        let (_, domain) = email.trim().rsplit_once('@')?;
+        let domain = domain.to_lowercase();
+        self.email_domains
+            .iter()
+            .find(|blocked| host_matches(&domain, blocked))
+            .map(|blocked| BlocklistMatch {
+                kind: BlocklistKind::EmailDomain,
+                pattern: blocked.clone(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: BlocklistKind, pattern: &str) -> blocklist_entry::Model {
+        let now = chrono::Utc::now().fixed_offset();
+        blocklist_entry::Model {
+            id: 1,
+            kind,
+            pattern: pattern.to_string(),
+            note: None,
+            created_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn list() -> Blocklist {
+        Blocklist::compile(&[
+            entry(BlocklistKind::Word, "casino"),
+            entry(BlocklistKind::Regex, r"buy\s+followers"),
+            entry(BlocklistKind::Domain, "spam.example"),
+            entry(BlocklistKind::EmailDomain, "mailinator.com"),
+        ])
+    }
+
+    #[test]
+    fn words_match_whole_words_case_insensitively() {
+        let list = list();
+        assert!(list.check_comment("Visit my CASINO tonight").is_some());
+        assert!(list.check_comment("occasional casinos").is_none());
+        assert_eq!(
+            list.check_name("Casino King").map(|m| m.kind),
+            Some(BlocklistKind::Word)
+        );
+    }
+
+    #[test]
+    fn regexes_apply_to_comments_and_names() {
+        let list = list();
+        assert_eq!(
+            list.check_comment("Buy   followers here").map(|m| m.kind),
+            Some(BlocklistKind::Regex)
+        );
+        assert!(list.check_name("buy followers").is_some());
+        assert!(list.check_name("Jane").is_none());
+    }
+
+    #[test]
+    fn domains_match_hosts_and_subdomains_in_comments() {
+        let list = list();
+        assert!(list
+            .check_comment("see https://spam.example/offer")
+            .is_some());
+        assert!(list.check_comment("see www.Spam.Example").is_some());
+        assert!(list
+            .check_comment("see http://deals.spam.example")
+            .is_some());
+        assert!(list.check_comment("see notspam.example").is_none());
+        // Domains only apply to comment content, not names.
+        assert!(list.check_name("spam.example").is_none());
+    }
+
+    #[test]
+    fn email_domains_match_domain_and_subdomains() {
+        let list = list();
+        assert!(list.check_email("a@mailinator.com").is_some());
+        assert!(list.check_email("a@eu.Mailinator.com").is_some());
+        assert!(list.check_email("a@notmailinator.com").is_none());
+        assert!(list.check_email("mailinator.com").is_none());
+    }
+
+    #[test]
+    fn normalize_domain_strips_scheme_www_and_path() {
+        assert_eq!(
+            normalize_domain("https://www.Spam.example/path?q=1"),
+            Some("spam.example".to_string())
+        );
+        assert_eq!(
+            normalize_domain("@mailinator.com"),
+            Some("mailinator.com".to_string())
+        );
+        assert_eq!(normalize_domain("localhost"), None);
+        assert_eq!(normalize_domain("bad domain.com"), None);
+    }
+
+    #[test]
+    fn invalid_regex_entries_are_skipped() {
+        let list = Blocklist::compile(&[entry(BlocklistKind::Regex, "(unclosed")]);
+        assert!(list.check_comment("(unclosed").is_none());
+    }
+}
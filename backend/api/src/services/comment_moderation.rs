@@ -0,0 +1,559 @@
+//! Comment moderation: decides whether a comment is published, held for
+//! review or rejected.
+//!
+//! `COMMENT_MODERATION_MODE` picks the baseline policy:
+//! - `auto` (default): publish unless the spam scorer objects
+//! - `first_comment`: hold a commenter's comments until one has been approved
+//! - `hold`: hold every comment for review
+//!
+//! Every comment is also run through the [`SpamScorer`]. A score at or above
+//! `COMMENT_SPAM_HOLD_SCORE` (default 5) holds the comment; one at or above
+//! `COMMENT_SPAM_REJECT_SCORE` (default 10) rejects it outright. Moderators
+//! and admins bypass both. The built-in checks read `COMMENT_SPAM_MAX_LINKS`
+//! (default 2), `COMMENT_SPAM_BLOCKLIST` (comma-separated terms) and
+//! `COMMENT_NEW_ACCOUNT_HOURS` (default 24).
+//!
+//! A comment that collects `COMMENT_FLAG_HIDE_THRESHOLD` flags (default 5,
+//! 0 disables) is hidden until a moderator reviews it.
+
+use chrono::Duration;
+use sea_orm::{
+    sea_query::{Alias, Expr, Func},
+    ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter,
+};
+
+use crate::db::sea_models::{post_comment, user};
+use crate::error::DbResult;
+use crate::middlewares::auth_guard::ROLE_MODERATOR;
+
+pub use ruxlog_types::enums::CommentStatus;
+
+/// How long an identical comment counts as a duplicate.
+const DUPLICATE_WINDOW_HOURS: i64 = 24;
+/// Short replies ("Thanks!") are legitimately repeated, so only comments at
+/// least this long are checked for duplicates.
+const DUPLICATE_MIN_CHARS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModerationMode {
+    #[default]
+    AutoPublish,
+    FirstComment,
+    AlwaysHold,
+}
+
+impl ModerationMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "auto" | "auto_publish" => Some(Self::AutoPublish),
+            "first_comment" => Some(Self::FirstComment),
+            "hold" | "always_hold" => Some(Self::AlwaysHold),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentModerationConfig {
+    pub mode: ModerationMode,
+    pub hold_score: i32,
+    pub reject_score: i32,
+    /// Flags needed to auto-hide a comment; 0 disables auto-hiding.
+    pub flag_hide_threshold: i32,
+    pub max_links: usize,
+    /// Lowercased terms that mark a comment as spam.
+    pub blocked_terms: Vec<String>,
+    pub new_account_hours: i64,
+}
+
+impl Default for CommentModerationConfig {
+    fn default() -> Self {
+        Self {
+            mode: ModerationMode::AutoPublish,
+            hold_score: 5,
+            reject_score: 10,
+            flag_hide_threshold: 5,
+            max_links: 2,
+            blocked_terms: Vec::new(),
+            new_account_hours: 24,
+        }
+    }
+}
+
+impl CommentModerationConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let mode = match std::env::var("COMMENT_MODERATION_MODE") {
+            Ok(value) if !value.trim().is_empty() => {
+                ModerationMode::parse(&value).unwrap_or_else(|| {
+                    tracing::warn!(value, "Unknown COMMENT_MODERATION_MODE, using auto");
+                    ModerationMode::AutoPublish
+                })
+            }
+            _ => defaults.mode,
+        };
+
+        Self {
+            mode,
+            hold_score: env_number("COMMENT_SPAM_HOLD_SCORE", defaults.hold_score),
+            reject_score: env_number("COMMENT_SPAM_REJECT_SCORE", defaults.reject_score),
+            flag_hide_threshold: env_number(
+                "COMMENT_FLAG_HIDE_THRESHOLD",
+                defaults.flag_hide_threshold,
+            ),
+            max_links: env_number("COMMENT_SPAM_MAX_LINKS", defaults.max_links),
+            blocked_terms: parse_terms(
+                &std::env::var("COMMENT_SPAM_BLOCKLIST").unwrap_or_default(),
+            ),
+            new_account_hours: env_number("COMMENT_NEW_ACCOUNT_HOURS", defaults.new_account_hours),
+        }
+    }
+}
+
+fn env_number<T: std::str::FromStr>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!(key, value, "Ignoring malformed comment moderation setting");
+            default
+        }),
+        _ => default,
+    }
+}
+
+fn parse_terms(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// What a spam check gets to look at. The async context (account age,
+/// history, duplicates) is gathered once by [`CommentModerator`] so the checks
+/// themselves stay pure.
+#[derive(Debug, Clone)]
+pub struct SpamInput<'a> {
+    pub content: &'a str,
+    pub account_age: Duration,
+    /// Comments by the same author that a moderator has approved.
+    pub approved_comments: u64,
+    /// Identical comments posted recently, by anyone.
+    pub duplicates: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpamSignal {
+    pub score: i32,
+    pub reason: String,
+}
+
+/// One heuristic of the spam scorer. Implement this to add a check and
+/// register it with [`SpamScorer::with_check`].
+pub trait SpamCheck: Send + Sync {
+    fn check(&self, input: &SpamInput<'_>) -> Option<SpamSignal>;
+}
+
+/// Scores 3 per link beyond the allowed count.
+pub struct LinkCountCheck {
+    pub max_links: usize,
+}
+
+impl SpamCheck for LinkCountCheck {
+    fn check(&self, input: &SpamInput<'_>) -> Option<SpamSignal> {
+        let lower = input.content.to_lowercase();
+        let links = lower.matches("http://").count()
+            + lower.matches("https://").count()
+            + lower.matches("www.").count();
+        (links > self.max_links).then(|| SpamSignal {
+            score: 3 * (links - self.max_links) as i32,
+            reason: format!("{} links", links),
+        })
+    }
+}
+
+/// Scores 5 per blocklisted term the comment contains.
+pub struct BlockedTermsCheck {
+    pub terms: Vec<String>,
+}
+
+impl SpamCheck for BlockedTermsCheck {
+    fn check(&self, input: &SpamInput<'_>) -> Option<SpamSignal> {
+        let lower = input.content.to_lowercase();
+        let hits = self
+            .terms
+            .iter()
+            .filter(|term| lower.contains(term.as_str()))
+            .count();
+        (hits > 0).then(|| SpamSignal {
+            score: 5 * hits as i32,
+            reason: format!("{} blocked terms", hits),
+        })
+    }
+}
+
+/// Scores 2 for a young account that has never had a comment approved, plus
+/// 2 more when that first comment carries a link.
+pub struct NewAccountCheck {
+    pub min_age: Duration,
+}
+
+impl SpamCheck for NewAccountCheck {
+    fn check(&self, input: &SpamInput<'_>) -> Option<SpamSignal> {
+        if input.account_age >= self.min_age || input.approved_comments > 0 {
+            return None;
+        }
+        let lower = input.content.to_lowercase();
+        let has_link = lower.contains("http://") || lower.contains("https://");
+        Some(SpamSignal {
+            score: if has_link { 4 } else { 2 },
+            reason: "new account".to_string(),
+        })
+    }
+}
+
+/// Scores 5 when the same text was posted within the duplicate window.
+pub struct DuplicateContentCheck;
+
+impl SpamCheck for DuplicateContentCheck {
+    fn check(&self, input: &SpamInput<'_>) -> Option<SpamSignal> {
+        (input.duplicates > 0).then(|| SpamSignal {
+            score: 5,
+            reason: "duplicate content".to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpamVerdict {
+    pub score: i32,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct SpamScorer {
+    checks: Vec<Box<dyn SpamCheck>>,
+}
+
+impl SpamScorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in checks, tuned by `config`.
+    pub fn from_config(config: &CommentModerationConfig) -> Self {
+        Self::new()
+            .with_check(LinkCountCheck {
+                max_links: config.max_links,
+            })
+            .with_check(BlockedTermsCheck {
+                terms: config.blocked_terms.clone(),
+            })
+            .with_check(NewAccountCheck {
+                min_age: Duration::hours(config.new_account_hours),
+            })
+            .with_check(DuplicateContentCheck)
+    }
+
+    pub fn with_check(mut self, check: impl SpamCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    pub fn score(&self, input: &SpamInput<'_>) -> SpamVerdict {
+        let mut verdict = SpamVerdict::default();
+        for signal in self.checks.iter().filter_map(|c| c.check(input)) {
+            verdict.score += signal.score;
+            verdict.reasons.push(signal.reason);
+        }
+        verdict
+    }
+}
+
+/// Outcome of moderating a new or edited comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assessment {
+    pub status: CommentStatus,
+    pub spam_score: i32,
+    pub spam_reasons: Vec<String>,
+}
+
+impl Assessment {
+    fn staff() -> Self {
+        Self {
+            status: CommentStatus::Approved,
+            spam_score: 0,
+            spam_reasons: Vec::new(),
+        }
+    }
+}
+
+pub struct CommentModerator {
+    pub config: CommentModerationConfig,
+    scorer: SpamScorer,
+}
+
+impl CommentModerator {
+    pub fn new(config: CommentModerationConfig, scorer: SpamScorer) -> Self {
+        Self { config, scorer }
+    }
+
+    pub fn from_env() -> Self {
+        let config = CommentModerationConfig::from_env();
+        tracing::info!(
+            mode = ?config.mode,
+            hold_score = config.hold_score,
+            reject_score = config.reject_score,
+            flag_hide_threshold = config.flag_hide_threshold,
+            blocked_terms = config.blocked_terms.len(),
+            "Comment moderation loaded"
+        );
+        let scorer = SpamScorer::from_config(&config);
+        Self::new(config, scorer)
+    }
+
+    /// Status for a brand-new comment.
+    pub fn decide(&self, verdict: &SpamVerdict, approved_comments: u64) -> CommentStatus {
+        if verdict.score >= self.config.reject_score {
+            return CommentStatus::Rejected;
+        }
+        if verdict.score >= self.config.hold_score {
+            return CommentStatus::Pending;
+        }
+        match self.config.mode {
+            ModerationMode::AutoPublish => CommentStatus::Approved,
+            ModerationMode::FirstComment if approved_comments > 0 => CommentStatus::Approved,
+            ModerationMode::FirstComment | ModerationMode::AlwaysHold => CommentStatus::Pending,
+        }
+    }
+
+    /// Status after an edit. An approved comment is judged again as if new,
+    /// so a held mode sends the edited text back to the queue; anything
+    /// else keeps its status. `approved_comments` excludes the edited one.
+    pub fn decide_edit(
+        &self,
+        verdict: &SpamVerdict,
+        current: CommentStatus,
+        approved_comments: u64,
+    ) -> CommentStatus {
+        if current != CommentStatus::Approved {
+            return current;
+        }
+        self.decide(verdict, approved_comments)
+    }
+
+    pub async fn assess_new(
+        &self,
+        conn: &DbConn,
+        author: &user::Model,
+        content: &str,
+    ) -> DbResult<Assessment> {
+        if author.role.to_i32() >= ROLE_MODERATOR {
+            return Ok(Assessment::staff());
+        }
+        let (verdict, approved) = self.score(conn, author, content, None).await?;
+        Ok(Assessment {
+            status: self.decide(&verdict, approved),
+            spam_score: verdict.score,
+            spam_reasons: verdict.reasons,
+        })
+    }
+
+    pub async fn assess_edit(
+        &self,
+        conn: &DbConn,
+        author: &user::Model,
+        comment: &post_comment::Model,
+        content: &str,
+    ) -> DbResult<Assessment> {
+        if author.role.to_i32() >= ROLE_MODERATOR {
+            return Ok(Assessment {
+                status: comment.status,
+                ..Assessment::staff()
+            });
+        }
+        let (verdict, approved) = self.score(conn, author, content, Some(comment.id)).await?;
+        Ok(Assessment {
+            status: self.decide_edit(&verdict, comment.status, approved),
+            spam_score: verdict.score,
+            spam_reasons: verdict.reasons,
+        })
+    }
+
+    async fn score(
+        &self,
+        conn: &DbConn,
+        author: &user::Model,
+        content: &str,
+        exclude_comment: Option<i32>,
+    ) -> DbResult<(SpamVerdict, u64)> {
+        let now = chrono::Utc::now().fixed_offset();
+
+        let mut approved_query = post_comment::Entity::find()
+            .filter(post_comment::Column::UserId.eq(author.id))
+            .filter(post_comment::Column::Status.eq(CommentStatus::Approved));
+        if let Some(id) = exclude_comment {
+            approved_query = approved_query.filter(post_comment::Column::Id.ne(id));
+        }
+        let approved_comments = approved_query.count(conn).await?;
+
+        let normalized = content.trim().to_lowercase();
+        let duplicates = if normalized.chars().count() >= DUPLICATE_MIN_CHARS {
+            let mut query = post_comment::Entity::find()
+                .filter(
+                    Expr::expr(Func::lower(
+                        Func::cust(Alias::new("BTRIM"))
+                            .arg(Expr::col(post_comment::Column::Content)),
+                    ))
+                    .eq(normalized),
+                )
+                .filter(
+                    post_comment::Column::CreatedAt
+                        .gt(now - Duration::hours(DUPLICATE_WINDOW_HOURS)),
+                );
+            if let Some(id) = exclude_comment {
+                query = query.filter(post_comment::Column::Id.ne(id));
+            }
+            query.count(conn).await?
+        } else {
+            0
+        };
+
+        let input = SpamInput {
+            content,
+            account_age: now - author.created_at,
+            approved_comments,
+            duplicates,
+        };
+        Ok((self.scorer.score(&input), approved_comments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(content: &str) -> SpamInput<'_> {
+        SpamInput {
+            content,
+            account_age: Duration::days(30),
+            approved_comments: 3,
+            duplicates: 0,
+        }
+    }
+
+    fn moderator(mode: ModerationMode) -> CommentModerator {
+        let config = CommentModerationConfig {
+            mode,
+            blocked_terms: parse_terms(" Casino , ,viagra"),
+            ..Default::default()
+        };
+        let scorer = SpamScorer::from_config(&config);
+        CommentModerator::new(config, scorer)
+    }
+
+    #[test]
+    fn clean_comment_from_established_account_scores_zero() {
+        let m = moderator(ModerationMode::AutoPublish);
+        let verdict = m.scorer.score(&input("Great write-up, thanks."));
+        assert_eq!(verdict, SpamVerdict::default());
+        assert_eq!(m.decide(&verdict, 3), CommentStatus::Approved);
+    }
+
+    #[test]
+    fn signals_add_up_and_map_to_hold_and_reject() {
+        let m = moderator(ModerationMode::AutoPublish);
+        let links = "see https://a.io https://b.io https://c.io";
+        let verdict = m.scorer.score(&input(links));
+        assert_eq!(verdict.score, 3);
+        assert_eq!(m.decide(&verdict, 3), CommentStatus::Approved);
+
+        let verdict = m.scorer.score(&input("Best CASINO bonus, cheap viagra"));
+        assert_eq!(verdict.score, 10);
+        assert_eq!(m.decide(&verdict, 3), CommentStatus::Rejected);
+
+        let mut dup = input("the exact same comment again and again");
+        dup.duplicates = 1;
+        let verdict = m.scorer.score(&dup);
+        assert_eq!(verdict.reasons, vec!["duplicate content".to_string()]);
+        assert_eq!(m.decide(&verdict, 3), CommentStatus::Pending);
+    }
+
+    #[test]
+    fn new_account_check_only_applies_before_first_approval() {
+        let m = moderator(ModerationMode::AutoPublish);
+        let mut fresh = input("hello https://example.com");
+        fresh.account_age = Duration::hours(1);
+        fresh.approved_comments = 0;
+        assert_eq!(m.scorer.score(&fresh).score, 4);
+
+        fresh.approved_comments = 1;
+        assert_eq!(m.scorer.score(&fresh).score, 0);
+    }
+
+    #[test]
+    fn modes_decide_clean_comments() {
+        let clean = SpamVerdict::default();
+        let first = moderator(ModerationMode::FirstComment);
+        assert_eq!(first.decide(&clean, 0), CommentStatus::Pending);
+        assert_eq!(first.decide(&clean, 1), CommentStatus::Approved);
+        let hold = moderator(ModerationMode::AlwaysHold);
+        assert_eq!(hold.decide(&clean, 5), CommentStatus::Pending);
+    }
+
+    #[test]
+    fn edits_only_pull_approved_comments_back() {
+        let m = moderator(ModerationMode::AutoPublish);
+        let clean = SpamVerdict::default();
+        let spammy = SpamVerdict {
+            score: 6,
+            reasons: vec!["3 links".into()],
+        };
+        assert_eq!(
+            m.decide_edit(&clean, CommentStatus::Approved, 0),
+            CommentStatus::Approved
+        );
+        assert_eq!(
+            m.decide_edit(&spammy, CommentStatus::Approved, 3),
+            CommentStatus::Pending
+        );
+        assert_eq!(
+            m.decide_edit(&clean, CommentStatus::Rejected, 3),
+            CommentStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn held_modes_requeue_edited_comments() {
+        let clean = SpamVerdict::default();
+        let hold = moderator(ModerationMode::AlwaysHold);
+        assert_eq!(
+            hold.decide_edit(&clean, CommentStatus::Approved, 5),
+            CommentStatus::Pending
+        );
+        let first = moderator(ModerationMode::FirstComment);
+        assert_eq!(
+            first.decide_edit(&clean, CommentStatus::Approved, 0),
+            CommentStatus::Pending
+        );
+        assert_eq!(
+            first.decide_edit(&clean, CommentStatus::Approved, 1),
+            CommentStatus::Approved
+        );
+    }
+
+    #[test]
+    fn mode_parsing_accepts_documented_values() {
+        assert_eq!(
+            ModerationMode::parse("auto"),
+            Some(ModerationMode::AutoPublish)
+        );
+        assert_eq!(
+            ModerationMode::parse(" First_Comment "),
+            Some(ModerationMode::FirstComment)
+        );
+        assert_eq!(
+            ModerationMode::parse("hold"),
+            Some(ModerationMode::AlwaysHold)
+        );
+        assert_eq!(ModerationMode::parse("sometimes"), None);
+    }
+}
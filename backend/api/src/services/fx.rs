@@ -0,0 +1,150 @@
+//! Static exchange rates for revenue reporting.
+//!
+//! Plans, payments and post purchases each carry their own currency. Revenue
+//! analytics converts every amount into `REVENUE_REPORTING_CURRENCY` (default
+//! `usd`) using the fixed rates in `REVENUE_FX_RATES`, e.g.
+//! `eur=1.08,jpy=0.0067`: one unit of the listed currency (a euro, a yen) is
+//! worth `rate` units of the reporting currency. Amounts are stored in minor
+//! units, whose size differs per currency (cents, but whole yen), so
+//! [`ExchangeRates::minor_unit_rates`] rescales each rate by the two
+//! currencies' exponents before it is applied to stored amounts. Amounts in
+//! a currency with no rate are left out of converted totals and reported as
+//! unconverted.
+
+use std::collections::BTreeMap;
+
+pub const DEFAULT_REPORTING_CURRENCY: &str = "usd";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRates {
+    reporting_currency: String,
+    rates: BTreeMap<String, f64>,
+}
+
+impl ExchangeRates {
+    pub fn from_env() -> Self {
+        let reporting = std::env::var("REVENUE_REPORTING_CURRENCY").unwrap_or_default();
+        let rates = std::env::var("REVENUE_FX_RATES").unwrap_or_default();
+        let fx = Self::new(&reporting, &rates);
+        tracing::info!(
+            currency = %fx.reporting_currency,
+            rates = fx.rates.len() - 1,
+            "Revenue exchange rates loaded"
+        );
+        fx
+    }
+
+    /// Build from the raw `REVENUE_REPORTING_CURRENCY` / `REVENUE_FX_RATES`
+    /// values. Malformed or non-positive entries are skipped with a warning.
+    pub fn new(reporting_currency: &str, rates: &str) -> Self {
+        let reporting_currency = match reporting_currency.trim() {
+            "" => DEFAULT_REPORTING_CURRENCY.to_string(),
+            code => code.to_ascii_lowercase(),
+        };
+
+        let mut parsed = BTreeMap::new();
+        for entry in rates.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let rate = entry.split_once('=').and_then(|(code, rate)| {
+                let code = code.trim().to_ascii_lowercase();
+                let rate = rate.trim().parse::<f64>().ok()?;
+                (!code.is_empty() && rate.is_finite() && rate > 0.0).then_some((code, rate))
+            });
+            match rate {
+                Some((code, rate)) => {
+                    parsed.insert(code, rate);
+                }
+                None => tracing::warn!(entry, "Ignoring malformed REVENUE_FX_RATES entry"),
+            }
+        }
+        // The reporting currency always converts 1:1, whatever was configured.
+        parsed.insert(reporting_currency.clone(), 1.0);
+
+        Self {
+            reporting_currency,
+            rates: parsed,
+        }
+    }
+
+    pub fn reporting_currency(&self) -> &str {
+        &self.reporting_currency
+    }
+
+    /// Lowercase currency codes and their configured per-unit rates,
+    /// reporting currency included.
+    pub fn rates(&self) -> &BTreeMap<String, f64> {
+        &self.rates
+    }
+
+    /// Rates that convert a minor-unit amount of each currency straight into
+    /// minor units of the reporting currency.
+    pub fn minor_unit_rates(&self) -> BTreeMap<String, f64> {
+        let reporting = minor_unit_exponent(&self.reporting_currency);
+        self.rates
+            .iter()
+            .map(|(code, rate)| {
+                let shift = reporting - minor_unit_exponent(code);
+                (code.clone(), rate * 10f64.powi(shift))
+            })
+            .collect()
+    }
+}
+
+/// Decimal places of a currency's minor unit, following Stripe's list of
+/// zero- and three-decimal currencies.
+fn minor_unit_exponent(code: &str) -> i32 {
+    match code {
+        "bif" | "clp" | "djf" | "gnf" | "jpy" | "kmf" | "krw" | "mga" | "pyg" | "rwf" | "ugx"
+        | "vnd" | "vuv" | "xaf" | "xof" | "xpf" => 0,
+        "bhd" | "jod" | "kwd" | "omr" | "tnd" => 3,
+        _ => 2,
+    }
+}
+
+impl Default for ExchangeRates {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPORTING_CURRENCY, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rates_and_normalizes_codes() {
+        let fx = ExchangeRates::new("EUR", "USD=0.92, gbp = 1.17");
+        assert_eq!(fx.reporting_currency(), "eur");
+        assert_eq!(fx.rates().get("usd"), Some(&0.92));
+        assert_eq!(fx.rates().get("gbp"), Some(&1.17));
+        assert_eq!(fx.rates().get("eur"), Some(&1.0));
+    }
+
+    #[test]
+    fn skips_malformed_entries() {
+        let fx = ExchangeRates::new("", "eur=abc,gbp,=1.2,jpy=-1,inr=0.012");
+        assert_eq!(fx.reporting_currency(), DEFAULT_REPORTING_CURRENCY);
+        assert_eq!(fx.rates().len(), 2);
+        assert_eq!(fx.rates().get("inr"), Some(&0.012));
+    }
+
+    #[test]
+    fn minor_unit_rates_account_for_zero_decimal_currencies() {
+        let fx = ExchangeRates::new("usd", "eur=1.08,jpy=0.0067,kwd=3.25");
+        let minor = fx.minor_unit_rates();
+        // 1 cent of EUR -> 1.08 US cents; 1 yen -> 0.67 US cents.
+        assert!((minor["eur"] - 1.08).abs() < 1e-9);
+        assert!((minor["jpy"] - 0.67).abs() < 1e-9);
+        assert!((minor["kwd"] - 0.325).abs() < 1e-9);
+        assert_eq!(minor["usd"], 1.0);
+
+        let fx = ExchangeRates::new("jpy", "usd=150");
+        // 1 US cent -> 1.5 yen.
+        assert!((fx.minor_unit_rates()["usd"] - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reporting_currency_is_always_one() {
+        let fx = ExchangeRates::new("usd", "usd=2.0");
+        assert_eq!(fx.rates().get("usd"), Some(&1.0));
+    }
+}
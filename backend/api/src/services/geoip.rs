@@ -0,0 +1,67 @@
+//! Country lookup from the MaxMind GeoLite2 database.
+//!
+//! Loaded once at startup from `GEOLITE2_DB_PATH` and shared through
+//! `AppState`: billing geo-routing and traffic analytics read the same
+//! reader. Without a database every lookup returns `None`.
+
+use std::net::IpAddr;
+
+use maxminddb::Reader as MaxMindReader;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeoInfo {
+    /// ISO 3166-1 alpha-2 country code.
+    pub country_code: Option<String>,
+    /// Two-letter continent code (AF, AS, EU, NA, OC, SA, AN).
+    pub continent_code: Option<String>,
+}
+
+pub struct GeoIp {
+    reader: Option<MaxMindReader<Vec<u8>>>,
+}
+
+impl GeoIp {
+    pub fn from_env() -> Self {
+        let reader = match std::env::var("GEOLITE2_DB_PATH") {
+            Ok(path) if !path.is_empty() => match MaxMindReader::open_readfile(&path) {
+                Ok(r) => {
+                    tracing::info!(path = %path, "GeoLite2 database loaded");
+                    Some(r)
+                }
+                Err(e) => {
+                    tracing::warn!(path = %path, error = %e, "Failed to load GeoLite2 database, geo lookups disabled");
+                    None
+                }
+            },
+            _ => {
+                tracing::info!("GEOLITE2_DB_PATH not set, geo lookups disabled");
+                None
+            }
+        };
+        Self { reader }
+    }
+
+    /// A reader-less instance; every lookup misses.
+    pub fn disabled() -> Self {
+        Self { reader: None }
+    }
+
+    pub fn lookup(&self, ip: IpAddr) -> Option<GeoInfo> {
+        let reader = self.reader.as_ref()?;
+        // maxminddb 0.27 split lookup into `lookup()` (returns a LookupResult
+        // handle) + `decode::<T>()` (materialises the typed record). We resolve
+        // both errors (corrupt/truncated DB, decode failure) and missing-data
+        // (None) to a plain `None` — callers always fall back gracefully.
+        let result = reader.lookup(ip).ok()?;
+        let geoip: maxminddb::geoip2::Country<'_> = result.decode().ok()??;
+
+        Some(GeoInfo {
+            country_code: geoip.country.iso_code.map(String::from),
+            continent_code: geoip.continent.code.map(String::from),
+        })
+    }
+
+    pub fn country_code(&self, ip: IpAddr) -> Option<String> {
+        self.lookup(ip)?.country_code
+    }
+}
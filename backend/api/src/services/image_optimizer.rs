@@ -24,6 +24,9 @@ pub struct OptimizationRequest<'a> {
     pub reference: Option<MediaReference>,
     pub original_mime: Option<&'a str>,
     pub original_extension: Option<&'a str>,
+    /// Rebuilding variants for an already-stored original: skip the
+    /// "already optimized" heuristic and never re-encode the original.
+    pub regenerate: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -42,6 +45,46 @@ pub enum VariantLabel {
     Lqip,
 }
 
+impl VariantLabel {
+    /// Value stored in `media_variants.variant_type`.
+    pub fn variant_type(&self) -> String {
+        match self {
+            VariantLabel::Width(width) => format!("{}w", width),
+            VariantLabel::Lqip => "lqip".to_string(),
+            VariantLabel::Original => "original".to_string(),
+        }
+    }
+}
+
+/// Storage key for `variant` of the object at `object_key`:
+/// `{base}@{480w|lqip}.{ext}`, or `{base}@{480w|lqip}-{version}.{ext}` for a
+/// regenerated set, so a replaced variant is never served from a stale cache.
+/// `None` for the original itself.
+pub fn variant_object_key(
+    object_key: &str,
+    variant: &OptimizedImage,
+    version: Option<&str>,
+) -> Option<String> {
+    let mut suffix = match variant.label {
+        VariantLabel::Width(width) => format!("@{}w", width),
+        VariantLabel::Lqip => "@lqip".to_string(),
+        VariantLabel::Original => return None,
+    };
+    if let Some(version) = version {
+        suffix = format!("{}-{}", suffix, version);
+    }
+    let base = object_key
+        .rsplit_once('.')
+        .map(|(prefix, _)| prefix)
+        .unwrap_or(object_key);
+    let extension = if variant.extension.is_empty() {
+        String::new()
+    } else {
+        format!(".{}", variant.extension)
+    };
+    Some(format!("{}{}{}", base, suffix, extension))
+}
+
 #[derive(Debug, Clone)]
 pub struct OptimizedImage {
     pub bytes: Bytes,
@@ -58,6 +101,9 @@ pub struct OptimizationResult {
     pub replaced_original: bool,
     pub original: OptimizedImage,
     pub variants: Vec<OptimizedImage>,
+    /// Compact blurred placeholder for the original, rendered before the
+    /// real image loads.
+    pub blurhash: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +156,15 @@ pub enum OptimizationError {
     ValidationFailed(String),
 }
 
+/// rav1e speed preset (0 = slowest/best, 10 = fastest). Uploads are encoded
+/// inline, so favour throughput over the last few percent of compression.
+const AVIF_SPEED: u8 = 8;
+
+/// Blurhash grid and the thumbnail it is computed from; the hash only carries
+/// `BLURHASH_COMPONENTS` worth of detail, so a tiny source is enough.
+const BLURHASH_COMPONENTS: (u32, u32) = (4, 3);
+const BLURHASH_SOURCE_WIDTH: u32 = 32;
+
 const LOSSY_BPP_THRESHOLD: f32 = 1.5;
 const LOSSLESS_BPP_THRESHOLD: f32 = 3.0;
 
@@ -183,7 +238,7 @@ pub fn optimize(
         return Ok(OptimizationOutcome::Skipped(SkipReason::ExceedsPixelBudget));
     }
 
-    if should_skip_for_quality(&probed) {
+    if !request.regenerate && should_skip_for_quality(&probed) {
         debug!(bpp = probed.bytes_per_pixel, "Already optimized");
         tracing::Span::current().record("outcome", "skipped_optimized");
         metrics
@@ -233,9 +288,10 @@ pub fn optimize(
             quality: None,
         },
         variants: Vec::new(),
+        blurhash: compute_blurhash(&decoded),
     };
 
-    if !config.keep_original {
+    if !config.keep_original && !request.regenerate {
         if let Some(replacement) =
             strategy.reencode_original(&decoded, &probed, &characteristics, request.bytes.len())?
         {
@@ -244,6 +300,8 @@ pub fn optimize(
         }
     }
 
+    let focal_point = request.metadata.focal_point();
+
     for spec in plan {
         let max_allowed = match spec.kind {
             ResizeKind::ExactSquare => characteristics.min_dimension,
@@ -254,7 +312,7 @@ pub fn optimize(
             continue;
         }
 
-        if let Some(variant) = encode_variant(&decoded, &spec)? {
+        if let Some(variant) = encode_variant(&decoded, &spec, focal_point)? {
             result.variants.push(variant);
         }
     }
@@ -413,6 +471,7 @@ enum TargetFormat {
     WebpLossless,
     Jpeg,
     Png,
+    Avif,
 }
 
 #[derive(Clone, Copy)]
@@ -483,6 +542,7 @@ impl Strategy {
                             });
                         }
                     }
+                    push_avif_variants(&mut variants, config);
                 }
 
                 variants
@@ -506,6 +566,7 @@ impl Strategy {
                         });
                     }
                 }
+                push_avif_variants(&mut variants, config);
 
                 if probed.width > 48 {
                     variants.push(VariantSpec {
@@ -574,9 +635,56 @@ impl Strategy {
     }
 }
 
+/// Mirror every width variant planned so far as AVIF, so `<picture>` can
+/// offer an AVIF `srcset` with the same candidates as the fallback format.
+fn push_avif_variants(variants: &mut Vec<VariantSpec>, config: &OptimizerConfig) {
+    if !config.avif_enabled {
+        return;
+    }
+    let avif: Vec<VariantSpec> = variants
+        .iter()
+        .filter(|spec| matches!(spec.label, VariantLabel::Width(_)))
+        .map(|spec| VariantSpec {
+            format: TargetFormat::Avif,
+            quality: config.avif_quality,
+            ..spec.clone()
+        })
+        .collect();
+    variants.extend(avif);
+}
+
+/// Blurhash of `image`, computed from a small thumbnail. `None` if the image
+/// is degenerate or the encoder rejects it.
+pub(crate) fn compute_blurhash(image: &DynamicImage) -> Option<String> {
+    if image.width() == 0 || image.height() == 0 {
+        return None;
+    }
+    let thumb = if image.width() > BLURHASH_SOURCE_WIDTH {
+        image.thumbnail(BLURHASH_SOURCE_WIDTH, BLURHASH_SOURCE_WIDTH * 4)
+    } else {
+        image.clone()
+    };
+    let rgba = thumb.to_rgba8();
+    let (x, y) = BLURHASH_COMPONENTS;
+    blurhash::encode(x, y, rgba.width(), rgba.height(), rgba.as_raw()).ok()
+}
+
+/// Blurhash for an upload the optimizer skipped (already well compressed),
+/// still bounded by the configured pixel budget.
+pub fn blurhash_for_bytes(config: &OptimizerConfig, bytes: &[u8]) -> Option<String> {
+    let size = imagesize::blob_size(bytes).ok()?;
+    let pixels = (size.width as u64).saturating_mul(size.height as u64);
+    if pixels == 0 || pixels > config.max_pixels {
+        return None;
+    }
+    let decoded = image::load_from_memory(bytes).ok()?;
+    compute_blurhash(&decoded)
+}
+
 fn encode_variant(
     source: &DynamicImage,
     spec: &VariantSpec,
+    focal_point: Option<(f64, f64)>,
 ) -> Result<Option<OptimizedImage>, OptimizationError> {
     if spec.width == 0 {
         return Ok(None);
@@ -585,8 +693,9 @@ fn encode_variant(
     let prepared = match spec.kind {
         ResizeKind::ExactSquare => {
             let min_side = source.width().min(source.height());
-            let x = (source.width() - min_side) / 2;
-            let y = (source.height() - min_side) / 2;
+            let (focal_x, focal_y) = focal_point.unwrap_or((0.5, 0.5));
+            let x = focal_crop_origin(source.width(), min_side, focal_x);
+            let y = focal_crop_origin(source.height(), min_side, focal_y);
             source.crop_imm(x, y, min_side, min_side).resize_exact(
                 spec.width,
                 spec.width,
@@ -614,7 +723,7 @@ fn encode_variant(
     };
 
     let quality_opt = match spec.format {
-        TargetFormat::Jpeg => Some(spec.quality),
+        TargetFormat::Jpeg | TargetFormat::Avif => Some(spec.quality),
         _ => None,
     };
 
@@ -641,6 +750,7 @@ fn validate_encoded_output(
     buffer: &[u8],
     expected_width: u32,
     expected_height: u32,
+    redecode: bool,
 ) -> Result<(), OptimizationError> {
     if buffer.is_empty() {
         return Err(OptimizationError::ValidationFailed(
@@ -661,6 +771,10 @@ fn validate_encoded_output(
         )));
     }
 
+    if !redecode {
+        return Ok(());
+    }
+
     // Full re-decode: confirms the bytes are a complete, well-formed image and
     // not a truncated/partial stream that happened to carry a valid header.
     let redecoded = image::load_from_memory(buffer).map_err(|err| {
@@ -718,16 +832,60 @@ fn encode_to_format(
                 .map_err(|err| OptimizationError::EncodeFailed(err.to_string()))?;
             (buffer, "image/png", "png")
         }
+        TargetFormat::Avif => {
+            let mut buffer = Vec::new();
+            let encoder =
+                codecs::avif::AvifEncoder::new_with_speed_quality(&mut buffer, AVIF_SPEED, quality);
+            if image.color().has_alpha() {
+                let rgba = image.to_rgba8();
+                encoder.write_image(
+                    rgba.as_raw(),
+                    rgba.width(),
+                    rgba.height(),
+                    ExtendedColorType::Rgba8,
+                )
+            } else {
+                let rgb = image.to_rgb8();
+                encoder.write_image(
+                    rgb.as_raw(),
+                    rgb.width(),
+                    rgb.height(),
+                    ExtendedColorType::Rgb8,
+                )
+            }
+            .map_err(|err| OptimizationError::EncodeFailed(err.to_string()))?;
+            (buffer, "image/avif", "avif")
+        }
     };
 
     // CRYP-GAP-017: validate the freshly re-encoded output (header +
     // dimensions + full re-decode) before returning it for storage. A failed
     // validation drops the bytes — they never reach a stored OptimizedImage.
-    validate_encoded_output(&buffer, image.width(), image.height())?;
+    // The AVIF decoder (dav1d) is not compiled in, so AVIF output gets the
+    // header + dimension check only.
+    let redecode = !matches!(format, TargetFormat::Avif);
+    validate_encoded_output(&buffer, image.width(), image.height(), redecode)?;
 
     Ok((buffer, mime, extension))
 }
 
+/// Offset along an axis of `length` pixels for a crop window of `window`
+/// pixels centred on the normalized `focal` coordinate, clamped so the window
+/// stays inside the image.
+fn focal_crop_origin(length: u32, window: u32, focal: f64) -> u32 {
+    let slack = length.saturating_sub(window);
+    if slack == 0 {
+        return 0;
+    }
+    let focal = if focal.is_finite() {
+        focal.clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let origin = focal * length as f64 - window as f64 / 2.0;
+    origin.round().clamp(0.0, slack as f64) as u32
+}
+
 fn significant_reduction(original: usize, candidate: usize, threshold: f32) -> bool {
     if candidate >= original {
         return false;
@@ -741,6 +899,28 @@ fn significant_reduction(original: usize, candidate: usize, threshold: f32) -> b
 mod tests {
     use super::*;
 
+    // ── focal_crop_origin ──
+
+    #[test]
+    fn test_focal_crop_origin_centered_matches_legacy_crop() {
+        assert_eq!(focal_crop_origin(1000, 600, 0.5), 200);
+        assert_eq!(focal_crop_origin(600, 600, 0.5), 0);
+    }
+
+    #[test]
+    fn test_focal_crop_origin_follows_focal_point() {
+        // Centred on x = 800 the window would start at 500; clamped to 400.
+        assert_eq!(focal_crop_origin(1000, 600, 0.8), 400);
+        assert_eq!(focal_crop_origin(2000, 600, 0.25), 200);
+    }
+
+    #[test]
+    fn test_focal_crop_origin_clamps_to_edges() {
+        assert_eq!(focal_crop_origin(1000, 600, 0.0), 0);
+        assert_eq!(focal_crop_origin(1000, 600, 1.0), 400);
+        assert_eq!(focal_crop_origin(1000, 600, f64::NAN), 200);
+    }
+
     // ── normalize_extension ──
 
     #[test]
@@ -897,7 +1077,7 @@ mod tests {
 
     #[test]
     fn test_validate_rejects_empty_buffer() {
-        let err = validate_encoded_output(&[], 10, 10).unwrap_err();
+        let err = validate_encoded_output(&[], 10, 10, true).unwrap_err();
         assert!(matches!(err, OptimizationError::ValidationFailed(_)));
     }
 
@@ -905,7 +1085,7 @@ mod tests {
     fn test_validate_rejects_garbage_bytes() {
         // Random bytes are not a valid image header.
         let garbage = b"\x00\x01\x02\x03not an image";
-        let err = validate_encoded_output(garbage, 8, 8).unwrap_err();
+        let err = validate_encoded_output(garbage, 8, 8, true).unwrap_err();
         assert!(matches!(err, OptimizationError::ValidationFailed(_)));
     }
 
@@ -915,7 +1095,7 @@ mod tests {
         // different — the validator must reject it.
         let img = DynamicImage::ImageRgb8(image::RgbImage::new(16, 16));
         let (buffer, _, _) = encode_to_format(&img, TargetFormat::Png, 100).unwrap();
-        let err = validate_encoded_output(&buffer, 32, 32).unwrap_err();
+        let err = validate_encoded_output(&buffer, 32, 32, true).unwrap_err();
         assert!(matches!(err, OptimizationError::ValidationFailed(_)));
     }
 
@@ -923,14 +1103,132 @@ mod tests {
     fn test_validate_accepts_roundtrip_png() {
         let img = DynamicImage::ImageRgb8(image::RgbImage::new(24, 16));
         let (buffer, _, _) = encode_to_format(&img, TargetFormat::Png, 100).unwrap();
-        validate_encoded_output(&buffer, 24, 16).expect("valid re-encoded PNG must pass");
+        validate_encoded_output(&buffer, 24, 16, true).expect("valid re-encoded PNG must pass");
     }
 
     #[test]
     fn test_validate_accepts_roundtrip_jpeg() {
         let img = DynamicImage::ImageRgb8(image::RgbImage::new(40, 30));
         let (buffer, _, _) = encode_to_format(&img, TargetFormat::Jpeg, 80).unwrap();
-        validate_encoded_output(&buffer, 40, 30).expect("valid re-encoded JPEG must pass");
+        validate_encoded_output(&buffer, 40, 30, true).expect("valid re-encoded JPEG must pass");
+    }
+
+    #[test]
+    fn test_avif_encode_passes_header_validation() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(48, 32, |x, y| {
+            image::Rgb([(x * 5) as u8, (y * 7) as u8, 128])
+        }));
+        let (buffer, mime, ext) = encode_to_format(&img, TargetFormat::Avif, 60).unwrap();
+        assert_eq!((mime, ext), ("image/avif", "avif"));
+        assert!(!buffer.is_empty());
+    }
+
+    // ── AVIF planning / blurhash ──
+
+    fn test_config(avif_enabled: bool) -> OptimizerConfig {
+        OptimizerConfig {
+            enabled: true,
+            max_pixels: 12_000_000,
+            keep_original: true,
+            default_webp_quality: 80,
+            avif_enabled,
+            avif_quality: 55,
+            defer_uploads: false,
+        }
+    }
+
+    #[test]
+    fn test_variant_object_key_and_type() {
+        let variant = |label, extension: &str| OptimizedImage {
+            bytes: Bytes::new(),
+            mime_type: "image/webp".into(),
+            extension: extension.into(),
+            width: 480,
+            height: 240,
+            label,
+            quality: Some(80),
+        };
+
+        let width = variant(VariantLabel::Width(480), "webp");
+        assert_eq!(
+            variant_object_key("media/2026/07/abc.jpg", &width, None).as_deref(),
+            Some("media/2026/07/abc@480w.webp")
+        );
+        assert_eq!(
+            variant_object_key("media/2026/07/abc.jpg", &width, Some("1a2b3c4d")).as_deref(),
+            Some("media/2026/07/abc@480w-1a2b3c4d.webp")
+        );
+        assert_eq!(width.label.variant_type(), "480w");
+
+        let lqip = variant(VariantLabel::Lqip, "");
+        assert_eq!(
+            variant_object_key("media/noext", &lqip, None).as_deref(),
+            Some("media/noext@lqip")
+        );
+        assert_eq!(
+            variant_object_key("media/a.png", &variant(VariantLabel::Original, "png"), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_push_avif_variants_mirrors_width_variants_only() {
+        let mut variants = vec![
+            VariantSpec {
+                width: 480,
+                format: TargetFormat::Jpeg,
+                quality: 80,
+                kind: ResizeKind::FitWidth,
+                label: VariantLabel::Width(480),
+            },
+            VariantSpec {
+                width: 24,
+                format: TargetFormat::Jpeg,
+                quality: 40,
+                kind: ResizeKind::FitWidth,
+                label: VariantLabel::Lqip,
+            },
+        ];
+        push_avif_variants(&mut variants, &test_config(true));
+        assert_eq!(variants.len(), 3);
+        let avif = &variants[2];
+        assert!(matches!(avif.format, TargetFormat::Avif));
+        assert_eq!(avif.width, 480);
+        assert_eq!(avif.quality, 55);
+
+        let mut disabled = variants[..1].to_vec();
+        push_avif_variants(&mut disabled, &test_config(false));
+        assert_eq!(disabled.len(), 1);
+    }
+
+    #[test]
+    fn test_compute_blurhash_is_stable_and_decodable() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(120, 80, |x, _| {
+            if x < 60 {
+                image::Rgb([220, 40, 40])
+            } else {
+                image::Rgb([40, 40, 220])
+            }
+        }));
+        let hash = compute_blurhash(&img).expect("blurhash");
+        // 4x3 components: 1 size + 1 max-AC + 4 DC + 2 per AC component.
+        assert_eq!(hash.len(), 6 + 2 * (4 * 3 - 1));
+        assert_eq!(compute_blurhash(&img), Some(hash.clone()));
+        assert!(blurhash::decode(&hash, 8, 8, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_blurhash_for_bytes_respects_pixel_budget() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::new(64, 64));
+        let (png, _, _) = encode_to_format(&img, TargetFormat::Png, 100).unwrap();
+        assert!(blurhash_for_bytes(&test_config(true), &png).is_some());
+
+        let tiny_budget = OptimizerConfig {
+            max_pixels: 100,
+            ..test_config(true)
+        };
+        assert!(blurhash_for_bytes(&tiny_budget, &png).is_none());
+        assert!(blurhash_for_bytes(&test_config(true), b"nope").is_none());
     }
 
     // ── significant_reduction ──
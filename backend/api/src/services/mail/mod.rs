@@ -1,3 +1,4 @@
+use lettre::message::header::{HeaderName, HeaderValue};
 use lettre::{AsyncSmtpTransport, AsyncTransport};
 use std::time::Instant;
 use tracing::{error, info, instrument};
@@ -10,13 +11,24 @@ pub mod templates;
 
 const DOMAIN: &str = "domain.tld";
 
-#[instrument(skip(mailer, body), fields(recipient_domain, result))]
 async fn send_email(
     mailer: &AsyncSmtpTransport<lettre::Tokio1Executor>,
     email_to: &str,
     email_from: &str,
     subject: &str,
     body: String,
+) -> Result<(), String> {
+    send_email_with_headers(mailer, email_to, email_from, subject, body, Vec::new()).await
+}
+
+#[instrument(skip(mailer, body, headers), fields(recipient_domain, result))]
+async fn send_email_with_headers(
+    mailer: &AsyncSmtpTransport<lettre::Tokio1Executor>,
+    email_to: &str,
+    email_from: &str,
+    subject: &str,
+    body: String,
+    headers: Vec<HeaderValue>,
 ) -> Result<(), String> {
     let metrics = telemetry::mail_metrics();
     let start = Instant::now();
@@ -41,16 +53,18 @@ async fn send_email(
         "Invalid sender email address"
     })?;
 
-    let email = lettre::Message::builder()
+    let mut builder = lettre::Message::builder()
         .from(email_from_parsed)
         .to(email_to_parsed)
         .subject(subject)
-        .header(lettre::message::header::ContentType::TEXT_HTML)
-        .body(body)
-        .map_err(|e| {
-            error!(error = %e, "Failed to build email message");
-            e.to_string()
-        })?;
+        .header(lettre::message::header::ContentType::TEXT_HTML);
+    for header in headers {
+        builder = builder.raw_header(header);
+    }
+    let email = builder.body(body).map_err(|e| {
+        error!(error = %e, "Failed to build email message");
+        e.to_string()
+    })?;
 
     match mailer.send(email).await {
         Ok(_) => {
@@ -101,3 +115,31 @@ pub async fn send_forgot_password_email(
 
     send_email(mailer, email, &no_reply, subject, body).await
 }
+
+/// A notification email (single or digest) with RFC 8058 one-click
+/// unsubscribe headers pointing at `unsubscribe_url`.
+#[instrument(
+    skip(mailer, body, unsubscribe_url),
+    fields(email_type = "notification")
+)]
+pub async fn send_notification_email(
+    mailer: &AsyncSmtpTransport<lettre::Tokio1Executor>,
+    email: &str,
+    subject: &str,
+    body: String,
+    unsubscribe_url: &str,
+) -> Result<(), String> {
+    let no_reply = format!("Notifications <no-reply@{}>", DOMAIN);
+    let headers = vec![
+        HeaderValue::new(
+            HeaderName::new_from_ascii_str("List-Unsubscribe"),
+            format!("<{}>", unsubscribe_url),
+        ),
+        HeaderValue::new(
+            HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+            "List-Unsubscribe=One-Click".to_string(),
+        ),
+    ];
+
+    send_email_with_headers(mailer, email, &no_reply, subject, body, headers).await
+}
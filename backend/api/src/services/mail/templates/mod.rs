@@ -60,6 +60,21 @@ fn tera_instance() -> Result<Tera, TemplateError> {
         source: e,
     })?;
 
+    tera.add_raw_template("notification", include_str!("notification.html"))
+        .map_err(|e| TemplateError::Render {
+            name: "notification".into(),
+            source: e,
+        })?;
+
+    tera.add_raw_template(
+        "notification_digest",
+        include_str!("notification_digest.html"),
+    )
+    .map_err(|e| TemplateError::Render {
+        name: "notification_digest".into(),
+        source: e,
+    })?;
+
     Ok(tera)
 }
 
@@ -73,6 +88,8 @@ fn tera_instance() -> Result<Tera, TemplateError> {
 /// - `"newsletter_confirmation"` — variables: `app_name`, `confirm_url`
 /// - `"payment_receipt"` — variables: `app_name`, `user_name`, `amount`, `currency`, `plan_name`, `invoice_url`
 /// - `"subscription_confirmation"` — variables: `app_name`, `user_name`, `plan_name`, `amount`, `next_billing_date`
+/// - `"notification"` — variables: `app_name`, `subject`, `body`, `link`, `unsubscribe_url`, `unsubscribe_all_url`
+/// - `"notification_digest"` — variables: `app_name`, `user_name`, `items` (`subject`, `body`, `link`), `unsubscribe_url`
 ///
 /// All templates also support an optional `primary_color` variable (defaults to `"#3b82f6"`).
 pub fn render(template_name: &str, context: &tera::Context) -> Result<String, TemplateError> {
@@ -165,6 +182,43 @@ mod tests {
         assert!(html.contains("2026-06-12"));
     }
 
+    #[test]
+    fn render_notification_escapes_user_content() {
+        let mut ctx = tera::Context::new();
+        ctx.insert("app_name", "TestApp");
+        ctx.insert("subject", "Frank commented on \"Hello\"");
+        ctx.insert("body", "<script>alert(1)</script>");
+        ctx.insert("link", "https://example.com/posts/hello#comment-7");
+        ctx.insert(
+            "unsubscribe_url",
+            "https://api.example.com/u/t?kind=comment_on_post",
+        );
+        ctx.insert("unsubscribe_all_url", "https://api.example.com/u/t");
+        let html = render("notification", &ctx).unwrap();
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("Turn off emails like this"));
+    }
+
+    #[test]
+    fn render_notification_digest_lists_items() {
+        let mut ctx = tera::Context::new();
+        ctx.insert("app_name", "TestApp");
+        ctx.insert("user_name", "Grace");
+        ctx.insert(
+            "items",
+            &serde_json::json!([
+                { "subject": "First", "body": "one", "link": null },
+                { "subject": "Second", "body": "two", "link": "https://example.com/p" }
+            ]),
+        );
+        ctx.insert("unsubscribe_url", "https://api.example.com/u/t");
+        let html = render("notification_digest", &ctx).unwrap();
+        assert!(html.contains("Grace"));
+        assert!(html.contains("First"));
+        assert!(html.contains("Second"));
+    }
+
     #[test]
     fn unknown_template_returns_error() {
         let ctx = tera::Context::new();
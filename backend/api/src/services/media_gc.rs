@@ -0,0 +1,408 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::json;
+use tracing::{error, info, instrument, warn};
+
+use crate::db::sea_models::{
+    media::{self, Entity as Media},
+    media_upload::{self, Entity as MediaUpload, MediaUploadStatus},
+    media_variant::Entity as MediaVariant,
+};
+use crate::error::{ErrorCode, ErrorResponse};
+use crate::services::storage::StoredObject;
+use crate::state::AppState;
+
+/// Every upload lives under this prefix (see `media_v1::controller::build_object_key`).
+const MEDIA_PREFIX: &str = "media/";
+
+/// Runtime settings for the background media garbage collector.
+///
+/// The collector is off by default and, when on, only reports what it would
+/// remove unless `delete` is set — a misconfigured bucket or a reference
+/// source we don't know about should never silently wipe user content.
+#[derive(Debug, Clone)]
+pub struct MediaGcConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub min_age_hours: u64,
+    pub delete: bool,
+    pub batch_limit: u64,
+}
+
+impl Default for MediaGcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: 60 * 60 * 24,
+            min_age_hours: 72,
+            delete: false,
+            batch_limit: 500,
+        }
+    }
+}
+
+/// Parameters for a single collection pass.
+#[derive(Debug, Clone, Copy)]
+pub struct MediaGcOptions {
+    pub dry_run: bool,
+    pub min_age_hours: u64,
+    pub limit: u64,
+}
+
+impl From<&MediaGcConfig> for MediaGcOptions {
+    fn from(config: &MediaGcConfig) -> Self {
+        Self {
+            dry_run: !config.delete,
+            min_age_hours: config.min_age_hours,
+            limit: config.batch_limit,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct MediaGcReport {
+    pub dry_run: bool,
+    pub cutoff: Option<DateTime<Utc>>,
+    pub scanned_objects: usize,
+    pub orphaned_keys: Vec<String>,
+    pub unreferenced_media_ids: Vec<i32>,
+    pub expired_upload_ids: Vec<i32>,
+    pub deleted_objects: usize,
+    pub deleted_media: usize,
+    pub aborted_uploads: usize,
+    pub failures: Vec<String>,
+}
+
+/// Runtime settings for the expired direct-upload sweep. Unlike the GC it is
+/// on by default: an abandoned multipart upload keeps its parts, and their
+/// storage cost, until it is aborted.
+#[derive(Debug, Clone)]
+pub struct UploadSweepConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub batch_limit: u64,
+}
+
+impl Default for UploadSweepConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 60 * 60,
+            batch_limit: 500,
+        }
+    }
+}
+
+/// Start the expired direct-upload sweep as a background tokio task.
+pub fn start_upload_sweep(state: AppState, config: UploadSweepConfig) {
+    if !config.enabled {
+        info!("Expired upload sweep disabled");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        batch_limit = config.batch_limit,
+        "Expired upload sweep started"
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(60)));
+        loop {
+            interval.tick().await;
+            match sweep_expired_uploads(&state, config.batch_limit).await {
+                Ok((0, failures)) if failures.is_empty() => {}
+                Ok((aborted, failures)) => info!(
+                    aborted,
+                    failures = failures.len(),
+                    "Expired upload sweep finished"
+                ),
+                Err(err) => error!(error = %err, "Expired upload sweep failed"),
+            }
+        }
+    });
+}
+
+/// Abort up to `limit` direct-upload sessions past their expiry. Returns how
+/// many were aborted and what failed.
+#[instrument(skip(state))]
+pub async fn sweep_expired_uploads(
+    state: &AppState,
+    limit: u64,
+) -> Result<(usize, Vec<String>), ErrorResponse> {
+    let expired =
+        MediaUpload::find_expired_pending(&state.sea_db, Utc::now().fixed_offset(), limit).await?;
+    let mut aborted = 0;
+    let mut failures = Vec::new();
+    for upload in expired {
+        match abort_expired_upload(state, upload).await {
+            Ok(()) => aborted += 1,
+            Err(failure) => failures.push(failure),
+        }
+    }
+    Ok((aborted, failures))
+}
+
+/// Abort one expired session: its multipart upload, any staged object, then
+/// the row. The staging object only exists if the upload was completed but
+/// the row never got updated; deleting a missing key is a no-op.
+async fn abort_expired_upload(state: &AppState, upload: media_upload::Model) -> Result<(), String> {
+    let upload_id = upload.id;
+    let aborted = state
+        .storage
+        .abort_multipart(&upload.object_key, &upload.provider_upload_id)
+        .await;
+    let staged = state.storage.delete(&upload.object_key).await;
+    aborted
+        .and(staged)
+        .map_err(|err| format!("upload {}: {}", upload_id, err))?;
+
+    MediaUpload::finish(&state.sea_db, upload, MediaUploadStatus::Aborted, None)
+        .await
+        .map(|_| ())
+        .map_err(|err| format!("upload {}: {}", upload_id, err.message))
+}
+
+/// Start the media garbage collector as a background tokio task.
+pub fn start_media_gc(state: AppState, config: MediaGcConfig) {
+    if !config.enabled {
+        info!("Media GC disabled");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        min_age_hours = config.min_age_hours,
+        delete = config.delete,
+        "Media GC started"
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(60)));
+        // The first tick fires immediately; skip it so a restart loop doesn't
+        // hammer the bucket listing.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match run_once(&state, MediaGcOptions::from(&config)).await {
+                Ok(report) => info!(
+                    dry_run = report.dry_run,
+                    scanned = report.scanned_objects,
+                    orphaned = report.orphaned_keys.len(),
+                    unreferenced = report.unreferenced_media_ids.len(),
+                    expired_uploads = report.expired_upload_ids.len(),
+                    deleted_objects = report.deleted_objects,
+                    deleted_media = report.deleted_media,
+                    aborted_uploads = report.aborted_uploads,
+                    failures = report.failures.len(),
+                    "Media GC pass finished"
+                ),
+                Err(err) => error!(error = %err, "Media GC pass failed"),
+            }
+        }
+    });
+}
+
+/// Run one collection pass: find storage objects with no matching media /
+/// variant row, and media rows nothing references, both older than the
+/// cutoff, plus direct-upload sessions past their expiry. When `dry_run` is
+/// false they are removed (sessions are aborted).
+#[instrument(skip(state))]
+pub async fn run_once(
+    state: &AppState,
+    options: MediaGcOptions,
+) -> Result<MediaGcReport, ErrorResponse> {
+    let cutoff = Utc::now() - chrono::Duration::hours(options.min_age_hours as i64);
+    let mut report = MediaGcReport {
+        dry_run: options.dry_run,
+        cutoff: Some(cutoff),
+        ..Default::default()
+    };
+
+    // Snapshot known keys before listing so that an upload landing mid-pass is
+    // protected by the age cutoff rather than racing the listing.
+    let known_keys = Media::all_object_keys(&state.sea_db).await?;
+    let listed = state.storage.list(MEDIA_PREFIX).await?;
+    report.scanned_objects = listed.len();
+
+    let mut orphaned = find_orphaned_keys(&listed, &known_keys, cutoff);
+    orphaned.truncate(options.limit as usize);
+    report.orphaned_keys = orphaned;
+
+    let unreferenced =
+        Media::find_unreferenced(&state.sea_db, cutoff.fixed_offset(), options.limit).await?;
+    report.unreferenced_media_ids = unreferenced.iter().map(|m| m.id).collect();
+
+    // Expiry is a hard deadline set at init, so the age cutoff doesn't apply.
+    let expired_uploads =
+        MediaUpload::find_expired_pending(&state.sea_db, Utc::now().fixed_offset(), options.limit)
+            .await?;
+    report.expired_upload_ids = expired_uploads.iter().map(|u| u.id).collect();
+
+    if options.dry_run {
+        return Ok(report);
+    }
+
+    for upload in expired_uploads {
+        match abort_expired_upload(state, upload).await {
+            Ok(()) => report.aborted_uploads += 1,
+            Err(failure) => report.failures.push(failure),
+        }
+    }
+
+    for key in &report.orphaned_keys {
+        match state.storage.delete(key).await {
+            Ok(()) => report.deleted_objects += 1,
+            Err(err) => report.failures.push(format!("{key}: {err}")),
+        }
+    }
+
+    for media in unreferenced {
+        // Re-check right before removal; the candidate list can be stale by
+        // the time we get here.
+        if !Media::find_references(&state.sea_db, media.id)
+            .await?
+            .is_empty()
+        {
+            continue;
+        }
+
+        match delete_stored_objects(state, &media).await {
+            Ok(removed) => report.deleted_objects += removed,
+            Err(err) => {
+                report
+                    .failures
+                    .push(format!("media {}: {}", media.id, err.message));
+                continue;
+            }
+        }
+
+        match Media::delete_by_id(&state.sea_db, media.id).await {
+            Ok(_) => report.deleted_media += 1,
+            Err(err) => report
+                .failures
+                .push(format!("media {}: {}", media.id, err.message)),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Remove a media item's variant objects and original object from storage.
+///
+/// Variant failures are logged and skipped (a stray variant is picked up by
+/// the GC later); failing to remove the original is an error so callers keep
+/// the row and can retry. So is media recorded in a bucket other than the
+/// configured one: its keys would name someone else's objects here. Returns
+/// the number of objects removed.
+#[instrument(skip(state, media), fields(media_id = media.id))]
+pub async fn delete_stored_objects(
+    state: &AppState,
+    media: &media::Model,
+) -> Result<usize, ErrorResponse> {
+    if let Some(bucket) = media.bucket.as_deref() {
+        if bucket != state.storage.bucket() {
+            return Err(ErrorResponse::new(ErrorCode::FileDeletionError)
+                .with_message("Media is stored in a bucket this server does not manage")
+                .with_context(json!({
+                    "media_bucket": bucket,
+                    "configured_bucket": state.storage.bucket(),
+                })));
+        }
+    }
+
+    let mut removed = 0;
+
+    let variants = MediaVariant::find_by_media_id(&state.sea_db, media.id).await?;
+    for variant in variants {
+        match state.storage.delete(&variant.object_key).await {
+            Ok(()) => removed += 1,
+            Err(err) => warn!(
+                media_id = media.id,
+                variant_id = variant.id,
+                key = %variant.object_key,
+                error = %err,
+                "Failed to delete media variant object"
+            ),
+        }
+    }
+
+    state
+        .storage
+        .delete(&media.object_key)
+        .await
+        .map_err(|err| {
+            ErrorResponse::new(ErrorCode::FileDeletionError)
+                .with_message("Failed to delete media from storage")
+                .with_details(err.to_string())
+        })?;
+
+    Ok(removed + 1)
+}
+
+/// Keys present in storage but unknown to the database and older than
+/// `cutoff`. Objects without a modification time are kept.
+pub fn find_orphaned_keys(
+    listed: &[StoredObject],
+    known_keys: &HashSet<String>,
+    cutoff: DateTime<Utc>,
+) -> Vec<String> {
+    listed
+        .iter()
+        .filter(|object| !known_keys.contains(&object.key))
+        .filter(|object| matches!(object.last_modified, Some(ts) if ts < cutoff))
+        .map(|object| object.key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(key: &str, age_hours: Option<i64>, now: DateTime<Utc>) -> StoredObject {
+        StoredObject {
+            key: key.to_string(),
+            last_modified: age_hours.map(|h| now - chrono::Duration::hours(h)),
+        }
+    }
+
+    #[test]
+    fn orphaned_keys_skip_known_recent_and_undated_objects() {
+        let now = Utc::now();
+        let cutoff = now - chrono::Duration::hours(72);
+        let listed = vec![
+            object("media/2025/01/known.png", Some(500), now),
+            object("media/2025/01/known@640w.webp", Some(500), now),
+            object("media/2025/01/stray.png", Some(500), now),
+            object("media/2025/01/fresh.png", Some(1), now),
+            object("media/2025/01/undated.png", None, now),
+        ];
+        let known: HashSet<String> = [
+            "media/2025/01/known.png".to_string(),
+            "media/2025/01/known@640w.webp".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            find_orphaned_keys(&listed, &known, cutoff),
+            vec!["media/2025/01/stray.png".to_string()]
+        );
+    }
+
+    #[test]
+    fn config_defaults_are_report_only() {
+        let config = MediaGcConfig::default();
+        assert!(!config.enabled);
+        let options = MediaGcOptions::from(&config);
+        assert!(options.dry_run);
+        assert_eq!(options.min_age_hours, 72);
+    }
+
+    #[test]
+    fn expired_upload_sweep_runs_without_the_gc() {
+        assert!(UploadSweepConfig::default().enabled);
+    }
+}
@@ -0,0 +1,910 @@
+//! Pure-Rust probing of non-image uploads.
+//!
+//! Reads just enough of each container to fill in what the media library
+//! shows and players need up front: duration for MP4/WebM/MP3/OGG, display
+//! size for video, page count for PDF, plus a poster where one can be had
+//! without a video decoder (embedded MP3 cover art, a rendered first PDF page
+//! with the `pdf-preview` feature). Nothing here decodes audio or video
+//! frames; video posters are supplied by the client instead.
+//!
+//! Every parser is defensive: malformed input yields `None` fields, never a
+//! panic or an error, since the file has already passed the allowlist.
+
+/// Facts read from an upload's container.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MediaProbe {
+    pub duration_ms: Option<i64>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub page_count: Option<i32>,
+    pub poster: Option<Poster>,
+}
+
+/// Still image standing in for a non-image upload in listings and players.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Poster {
+    pub bytes: Vec<u8>,
+    pub mime_type: String,
+    pub extension: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub blurhash: Option<String>,
+}
+
+impl Poster {
+    /// Wrap encoded image bytes, accepting only JPEG/PNG/WebP that parse.
+    pub fn from_image_bytes(bytes: Vec<u8>) -> Option<Self> {
+        let (mime_type, extension) = match imagesize::image_type(&bytes).ok()? {
+            imagesize::ImageType::Jpeg => ("image/jpeg", "jpg"),
+            imagesize::ImageType::Png => ("image/png", "png"),
+            imagesize::ImageType::Webp => ("image/webp", "webp"),
+            _ => return None,
+        };
+        let size = imagesize::blob_size(&bytes).ok()?;
+        Some(Self {
+            bytes,
+            mime_type: mime_type.to_string(),
+            extension: extension.to_string(),
+            width: i32::try_from(size.width).ok(),
+            height: i32::try_from(size.height).ok(),
+            blurhash: None,
+        })
+    }
+}
+
+/// Whether `bytes` start like the container `mime` claims. Only checked for
+/// video, audio and PDF, which are stored verbatim; images go through the
+/// optimizer's decoder instead.
+pub fn matches_signature(mime: &str, bytes: &[u8]) -> bool {
+    match mime {
+        "video/mp4" => bytes.len() >= 12 && &bytes[4..8] == b"ftyp",
+        "video/webm" => bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]),
+        "audio/mpeg" => bytes.starts_with(b"ID3") || mp3_frame_at(bytes, 0).is_some(),
+        "audio/ogg" => bytes.starts_with(b"OggS"),
+        // The spec tolerates leading junk before the header, readers look for
+        // it in the first KiB.
+        "application/pdf" => find(&bytes[..bytes.len().min(1024)], b"%PDF-").is_some(),
+        _ => false,
+    }
+}
+
+/// Probe `bytes` of type `mime`. CPU-bound (PDF rendering especially): call
+/// from a blocking thread.
+pub fn probe(mime: &str, bytes: &[u8]) -> MediaProbe {
+    match mime {
+        "video/mp4" => probe_mp4(bytes),
+        "video/webm" => probe_webm(bytes),
+        "audio/mpeg" => probe_mp3(bytes),
+        "audio/ogg" => probe_ogg(bytes),
+        "application/pdf" => probe_pdf(bytes),
+        _ => MediaProbe::default(),
+    }
+}
+
+/// Bytes read from each end of an object for [`probe_sparse`].
+pub const SPARSE_WINDOW: u64 = 8 * 1024 * 1024;
+
+/// [`probe`] for an object too large to hold in memory, from its first and
+/// last bytes (`head`, `tail`, up to [`SPARSE_WINDOW`] each) and its length.
+/// Containers keep what we read near either end: the MP4 `moov` box before or
+/// after the media data, WebM headers, MP3 tags and frame headers, the first
+/// and last OGG pages. PDFs need the whole file and probe to nothing.
+pub fn probe_sparse(mime: &str, head: &[u8], tail: &[u8], len: u64) -> MediaProbe {
+    match mime {
+        "video/mp4" => mp4_sparse_moov(head, tail, len)
+            .map(probe_mp4)
+            .unwrap_or_default(),
+        "video/webm" => probe_webm(head),
+        "audio/mpeg" => probe_mp3_parts(head, tail, len),
+        "audio/ogg" => probe_ogg_parts(head, tail),
+        _ => MediaProbe::default(),
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn be_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn be_u64(bytes: &[u8], at: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(bytes.get(at..at + 8)?.try_into().ok()?))
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn le_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn le_i64(bytes: &[u8], at: usize) -> Option<i64> {
+    Some(i64::from_le_bytes(bytes.get(at..at + 8)?.try_into().ok()?))
+}
+
+/// `units / per_second` seconds in milliseconds; `None` for a zero rate.
+fn to_millis(units: u64, per_second: u64) -> Option<i64> {
+    if per_second == 0 {
+        return None;
+    }
+    i64::try_from(u128::from(units) * 1000 / u128::from(per_second)).ok()
+}
+
+// ── MP4 (ISO base media file format) ────────────────────────────────────────
+
+/// Iterate the boxes in `bytes` as `(type, body)`.
+fn mp4_boxes(bytes: &[u8]) -> impl Iterator<Item = ([u8; 4], &[u8])> {
+    let mut offset = 0usize;
+    std::iter::from_fn(move || {
+        let size32 = be_u32(bytes, offset)?;
+        let kind: [u8; 4] = bytes.get(offset + 4..offset + 8)?.try_into().ok()?;
+        let (header, size) = match size32 {
+            // Box runs to the end of its parent.
+            0 => (8, bytes.len() - offset),
+            1 => (16, usize::try_from(be_u64(bytes, offset + 8)?).ok()?),
+            n => (8, n as usize),
+        };
+        if size < header {
+            return None;
+        }
+        let end = offset.checked_add(size)?.min(bytes.len());
+        let body = bytes.get(offset + header..end)?;
+        offset = end;
+        Some((kind, body))
+    })
+}
+
+fn mp4_child<'a>(parent: &'a [u8], kind: &[u8; 4]) -> Option<&'a [u8]> {
+    mp4_boxes(parent)
+        .find(|(k, _)| k == kind)
+        .map(|(_, body)| body)
+}
+
+/// The top-level `moov` box (header included) of an MP4 of `len` bytes, if it
+/// lies wholly in `head` (its first bytes) or `tail` (its last bytes). Box
+/// headers are followed from the start through whichever window holds them.
+fn mp4_sparse_moov<'a>(head: &'a [u8], tail: &'a [u8], len: u64) -> Option<&'a [u8]> {
+    let tail_start = len.checked_sub(tail.len() as u64)?;
+    let window = |start: u64, end: u64| -> Option<&'a [u8]> {
+        if end <= head.len() as u64 {
+            head.get(usize::try_from(start).ok()?..usize::try_from(end).ok()?)
+        } else if start >= tail_start && end <= len {
+            let start = usize::try_from(start - tail_start).ok()?;
+            let end = usize::try_from(end - tail_start).ok()?;
+            tail.get(start..end)
+        } else {
+            None
+        }
+    };
+
+    let mut offset = 0u64;
+    while offset.checked_add(8)? <= len {
+        let header = window(offset, offset + 8)?;
+        let size = match be_u32(header, 0)? {
+            // Box runs to the end of the file.
+            0 => len - offset,
+            1 => be_u64(window(offset, offset + 16)?, 8)?,
+            n => u64::from(n),
+        };
+        if size < 8 {
+            return None;
+        }
+        let end = offset.checked_add(size)?.min(len);
+        if &header[4..8] == b"moov" {
+            return window(offset, end);
+        }
+        offset = end;
+    }
+    None
+}
+
+fn probe_mp4(bytes: &[u8]) -> MediaProbe {
+    let mut probe = MediaProbe::default();
+    let Some(moov) = mp4_child(bytes, b"moov") else {
+        return probe;
+    };
+
+    if let Some(mvhd) = mp4_child(moov, b"mvhd") {
+        // Full box: version(1) flags(3), then creation/modification times,
+        // timescale and duration, 32- or 64-bit depending on the version.
+        let (timescale, duration) = if mvhd.first() == Some(&1) {
+            (be_u32(mvhd, 20), be_u64(mvhd, 24))
+        } else {
+            (be_u32(mvhd, 12), be_u32(mvhd, 16).map(u64::from))
+        };
+        if let (Some(timescale), Some(duration)) = (timescale, duration) {
+            probe.duration_ms = to_millis(duration, u64::from(timescale));
+        }
+    }
+
+    // Display size comes from the first track header with one; audio tracks
+    // carry zero.
+    for (kind, trak) in mp4_boxes(moov) {
+        if &kind != b"trak" {
+            continue;
+        }
+        let Some(tkhd) = mp4_child(trak, b"tkhd") else {
+            continue;
+        };
+        let dims_at = if tkhd.first() == Some(&1) { 88 } else { 76 };
+        // 16.16 fixed point.
+        let width = be_u32(tkhd, dims_at).map(|w| w >> 16).unwrap_or(0);
+        let height = be_u32(tkhd, dims_at + 4).map(|h| h >> 16).unwrap_or(0);
+        if width > 0 && height > 0 {
+            probe.width = i32::try_from(width).ok();
+            probe.height = i32::try_from(height).ok();
+            break;
+        }
+    }
+    probe
+}
+
+// ── WebM (Matroska / EBML) ─────────────────────────────────────────────────
+
+const EBML_SEGMENT: u32 = 0x1853_8067;
+const EBML_INFO: u32 = 0x1549_A966;
+const EBML_TIMECODE_SCALE: u32 = 0x2A_D7B1;
+const EBML_DURATION: u32 = 0x4489;
+const EBML_TRACKS: u32 = 0x1654_AE6B;
+const EBML_TRACK_ENTRY: u32 = 0xAE;
+const EBML_VIDEO: u32 = 0xE0;
+const EBML_PIXEL_WIDTH: u32 = 0xB0;
+const EBML_PIXEL_HEIGHT: u32 = 0xBA;
+const EBML_CLUSTER: u32 = 0x1F43_B675;
+
+/// Element id (marker bits kept, as ids are written in the spec) and its
+/// encoded length.
+fn ebml_id(bytes: &[u8], at: usize) -> Option<(u32, usize)> {
+    let first = *bytes.get(at)?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 4 {
+        return None;
+    }
+    let mut id = 0u32;
+    for i in 0..len {
+        id = (id << 8) | u32::from(*bytes.get(at + i)?);
+    }
+    Some((id, len))
+}
+
+/// Data size (marker removed) and its encoded length. `None` size means
+/// "unknown", used by live recordings for the segment and clusters.
+fn ebml_size(bytes: &[u8], at: usize) -> Option<(Option<u64>, usize)> {
+    let first = *bytes.get(at)?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 {
+        return None;
+    }
+    let mut value = u64::from(first) & (0xFF >> len);
+    let mut all_ones = value == (0xFF >> len);
+    for i in 1..len {
+        let byte = *bytes.get(at + i)?;
+        all_ones &= byte == 0xFF;
+        value = (value << 8) | u64::from(byte);
+    }
+    Some((if all_ones { None } else { Some(value) }, len))
+}
+
+/// Iterate the elements in `bytes` as `(id, body)`. An element of unknown
+/// size extends to the end of `bytes`.
+fn ebml_elements(bytes: &[u8]) -> impl Iterator<Item = (u32, &[u8])> {
+    let mut offset = 0usize;
+    std::iter::from_fn(move || {
+        let (id, id_len) = ebml_id(bytes, offset)?;
+        let (size, size_len) = ebml_size(bytes, offset + id_len)?;
+        let start = offset + id_len + size_len;
+        let end = match size {
+            Some(size) => start
+                .checked_add(usize::try_from(size).ok()?)?
+                .min(bytes.len()),
+            None => bytes.len(),
+        };
+        let body = bytes.get(start..end)?;
+        offset = end;
+        Some((id, body))
+    })
+}
+
+fn ebml_uint(body: &[u8]) -> Option<u64> {
+    if body.is_empty() || body.len() > 8 {
+        return None;
+    }
+    Some(body.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+fn ebml_float(body: &[u8]) -> Option<f64> {
+    match body.len() {
+        4 => Some(f64::from(f32::from_be_bytes(body.try_into().ok()?))),
+        8 => Some(f64::from_be_bytes(body.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+fn probe_webm(bytes: &[u8]) -> MediaProbe {
+    let mut probe = MediaProbe::default();
+    let Some((_, segment)) = ebml_elements(bytes).find(|(id, _)| *id == EBML_SEGMENT) else {
+        return probe;
+    };
+
+    for (id, body) in ebml_elements(segment) {
+        match id {
+            EBML_INFO => {
+                let mut scale = 1_000_000u64;
+                let mut duration = None;
+                for (id, value) in ebml_elements(body) {
+                    match id {
+                        EBML_TIMECODE_SCALE => scale = ebml_uint(value).unwrap_or(scale),
+                        EBML_DURATION => duration = ebml_float(value),
+                        _ => {}
+                    }
+                }
+                // Duration is in timecode-scale units (nanoseconds each by
+                // default). MediaRecorder output often omits it.
+                probe.duration_ms = duration
+                    .filter(|d| d.is_finite() && *d > 0.0)
+                    .map(|d| (d * scale as f64 / 1_000_000.0).round() as i64);
+            }
+            EBML_TRACKS => {
+                let video = ebml_elements(body)
+                    .filter(|(id, _)| *id == EBML_TRACK_ENTRY)
+                    .find_map(|(_, entry)| {
+                        ebml_elements(entry)
+                            .find(|(id, _)| *id == EBML_VIDEO)
+                            .map(|(_, video)| video)
+                    });
+                if let Some(video) = video {
+                    for (id, value) in ebml_elements(video) {
+                        match id {
+                            EBML_PIXEL_WIDTH => {
+                                probe.width = ebml_uint(value).and_then(|v| i32::try_from(v).ok())
+                            }
+                            EBML_PIXEL_HEIGHT => {
+                                probe.height = ebml_uint(value).and_then(|v| i32::try_from(v).ok())
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            // Headers precede the media data; clusters may have unknown sizes
+            // and can't be skipped reliably anyway.
+            EBML_CLUSTER => break,
+            _ => {}
+        }
+    }
+    probe
+}
+
+// ── MP3 (MPEG audio layer III) ─────────────────────────────────────────────
+
+const MP3_BITRATES_V1: [u32; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+const MP3_BITRATES_V2: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mp3Frame {
+    mpeg1: bool,
+    mono: bool,
+    bitrate_kbps: u32,
+    sample_rate: u32,
+}
+
+impl Mp3Frame {
+    fn samples_per_frame(&self) -> u64 {
+        if self.mpeg1 {
+            1152
+        } else {
+            576
+        }
+    }
+
+    /// Offset of a Xing/Info tag from the frame start (after the side info).
+    fn xing_offset(&self) -> usize {
+        4 + match (self.mpeg1, self.mono) {
+            (true, false) => 32,
+            (true, true) | (false, false) => 17,
+            (false, true) => 9,
+        }
+    }
+}
+
+/// Parse a layer III frame header at `at`.
+fn mp3_frame_at(bytes: &[u8], at: usize) -> Option<Mp3Frame> {
+    let header = bytes.get(at..at + 4)?;
+    if header[0] != 0xFF || header[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+    let version = (header[1] >> 3) & 0b11;
+    let layer = (header[1] >> 1) & 0b11;
+    if version == 0b01 || layer != 0b01 {
+        return None;
+    }
+    let bitrate_index = usize::from(header[2] >> 4);
+    let rate_index = usize::from((header[2] >> 2) & 0b11);
+    if bitrate_index == 0 || bitrate_index == 15 || rate_index == 3 {
+        return None;
+    }
+    let mpeg1 = version == 0b11;
+    let bitrate_kbps = if mpeg1 {
+        MP3_BITRATES_V1[bitrate_index]
+    } else {
+        MP3_BITRATES_V2[bitrate_index]
+    };
+    let base_rate = [44_100, 48_000, 32_000][rate_index];
+    let sample_rate = match version {
+        0b11 => base_rate,
+        0b10 => base_rate / 2,
+        _ => base_rate / 4,
+    };
+    Some(Mp3Frame {
+        mpeg1,
+        mono: header[3] >> 6 == 0b11,
+        bitrate_kbps,
+        sample_rate,
+    })
+}
+
+/// Total length of the ID3v2 tag at the start of `bytes`, if any.
+fn id3v2_len(bytes: &[u8]) -> usize {
+    if !bytes.starts_with(b"ID3") || bytes.len() < 10 {
+        return 0;
+    }
+    let footer = if bytes[5] & 0x10 != 0 { 10 } else { 0 };
+    10 + syncsafe(&bytes[6..10]) as usize + footer
+}
+
+fn syncsafe(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0u32, |acc, b| (acc << 7) | u32::from(b & 0x7F))
+}
+
+fn probe_mp3(bytes: &[u8]) -> MediaProbe {
+    probe_mp3_parts(bytes, bytes, bytes.len() as u64)
+}
+
+/// MP3 from its first bytes (`head`: ID3v2 tag and frame headers), last bytes
+/// (`tail`: ID3v1 tag) and length.
+fn probe_mp3_parts(head: &[u8], tail: &[u8], len: u64) -> MediaProbe {
+    let bytes = head;
+    let tag_len = id3v2_len(bytes);
+    let mut probe = MediaProbe {
+        poster: id3_cover_art(bytes).and_then(Poster::from_image_bytes),
+        ..Default::default()
+    };
+
+    // Padding may sit between the tag and the first frame.
+    let search_end = bytes.len().min(tag_len + 64 * 1024);
+    let first = (tag_len..search_end).find_map(|at| Some((at, mp3_frame_at(bytes, at)?)));
+    let Some((start, frame)) = first else {
+        return probe;
+    };
+
+    // VBR files carry a frame count in a Xing/Info (or Fraunhofer VBRI) tag
+    // inside the first frame.
+    let xing = start + frame.xing_offset();
+    let frames = match bytes.get(xing..xing + 4) {
+        Some(b"Xing") | Some(b"Info") => be_u32(bytes, xing + 4)
+            .filter(|flags| flags & 1 != 0)
+            .and_then(|_| be_u32(bytes, xing + 8)),
+        _ => match bytes.get(start + 36..start + 40) {
+            Some(b"VBRI") => be_u32(bytes, start + 36 + 14),
+            _ => None,
+        },
+    };
+
+    probe.duration_ms = match frames {
+        Some(frames) => to_millis(
+            u64::from(frames) * frame.samples_per_frame(),
+            u64::from(frame.sample_rate),
+        ),
+        None => {
+            // Constant bitrate: the audio byte count gives the length.
+            let id3v1 = if tail.len() >= 128 && &tail[tail.len() - 128..][..3] == b"TAG" {
+                128
+            } else {
+                0
+            };
+            let audio_bytes = len.saturating_sub(start as u64).saturating_sub(id3v1);
+            to_millis(audio_bytes * 8, u64::from(frame.bitrate_kbps) * 1000)
+        }
+    };
+    probe
+}
+
+/// Picture of the first `APIC` frame in an ID3v2.3/2.4 tag, preferring the
+/// front cover.
+fn id3_cover_art(bytes: &[u8]) -> Option<Vec<u8>> {
+    let tag_len = id3v2_len(bytes);
+    if tag_len == 0 {
+        return None;
+    }
+    let major = bytes[3];
+    if !(3..=4).contains(&major) || bytes[5] & 0x80 != 0 {
+        // ID3v2.2 uses three-letter frames; unsynchronised tags would need
+        // undoing first. Neither is worth it for cover art.
+        return None;
+    }
+    let tag = bytes.get(10..tag_len.min(bytes.len()))?;
+    let mut offset = 0usize;
+    let mut fallback = None;
+    while offset + 10 <= tag.len() {
+        let id = &tag[offset..offset + 4];
+        if id[0] == 0 {
+            break; // padding
+        }
+        let size = match major {
+            4 => syncsafe(&tag[offset + 4..offset + 8]),
+            _ => be_u32(tag, offset + 4)?,
+        } as usize;
+        let body = tag.get(offset + 10..offset + 10 + size)?;
+        offset += 10 + size;
+        if id != b"APIC" {
+            continue;
+        }
+
+        // encoding(1) mime(latin1, NUL) type(1) description(NUL in the given
+        // encoding) data
+        let encoding = *body.first()?;
+        let mime_end = 1 + body.get(1..)?.iter().position(|b| *b == 0)?;
+        let picture_type = *body.get(mime_end + 1)?;
+        let description = body.get(mime_end + 2..)?;
+        let data_start = if matches!(encoding, 1 | 2) {
+            description
+                .chunks_exact(2)
+                .position(|pair| pair == [0, 0])
+                .map(|i| i * 2 + 2)?
+        } else {
+            description.iter().position(|b| *b == 0)? + 1
+        };
+        let picture = description.get(data_start..)?.to_vec();
+        if picture_type == 3 {
+            return Some(picture);
+        }
+        fallback.get_or_insert(picture);
+    }
+    fallback
+}
+
+// ── Ogg (Vorbis / Opus) ────────────────────────────────────────────────────
+
+struct OggPage<'a> {
+    granule: i64,
+    serial: u32,
+    body: &'a [u8],
+}
+
+fn ogg_page_at(bytes: &[u8], at: usize) -> Option<OggPage<'_>> {
+    if bytes.get(at..at + 4)? != b"OggS" {
+        return None;
+    }
+    let segments = usize::from(*bytes.get(at + 26)?);
+    let table = bytes.get(at + 27..at + 27 + segments)?;
+    let body_len: usize = table.iter().map(|s| usize::from(*s)).sum();
+    let start = at + 27 + segments;
+    Some(OggPage {
+        granule: le_i64(bytes, at + 6)?,
+        serial: le_u32(bytes, at + 14)?,
+        body: bytes.get(start..(start + body_len).min(bytes.len()))?,
+    })
+}
+
+fn probe_ogg(bytes: &[u8]) -> MediaProbe {
+    probe_ogg_parts(bytes, bytes)
+}
+
+/// OGG from its first page (in `head`) and last pages (in `tail`).
+fn probe_ogg_parts(head: &[u8], tail: &[u8]) -> MediaProbe {
+    let mut probe = MediaProbe::default();
+    let Some(first) = ogg_page_at(head, 0) else {
+        return probe;
+    };
+
+    // The first packet identifies the codec. Opus granules always count 48 kHz
+    // samples and include the encoder pre-skip.
+    let (rate, pre_skip) = if first.body.starts_with(b"\x01vorbis") {
+        match le_u32(first.body, 12) {
+            Some(rate) => (u64::from(rate), 0),
+            None => return probe,
+        }
+    } else if first.body.starts_with(b"OpusHead") {
+        (48_000, u64::from(le_u16(first.body, 10).unwrap_or(0)))
+    } else {
+        return probe;
+    };
+
+    // The last page of the stream holds its final granule position.
+    let tail_start = tail.len().saturating_sub(64 * 1024);
+    let mut at = tail.len().saturating_sub(4);
+    while at > tail_start {
+        at -= 1;
+        let Some(page) = ogg_page_at(tail, at) else {
+            continue;
+        };
+        if page.serial == first.serial && page.granule > 0 {
+            let samples = (page.granule as u64).saturating_sub(pre_skip);
+            probe.duration_ms = to_millis(samples, rate);
+            break;
+        }
+    }
+    probe
+}
+
+// ── PDF ────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "pdf-preview")]
+fn probe_pdf(bytes: &[u8]) -> MediaProbe {
+    let preview = crate::services::pdf_preview::preview(bytes);
+    MediaProbe {
+        page_count: preview.page_count,
+        poster: preview.poster,
+        ..Default::default()
+    }
+}
+
+/// Without a PDF parser there's nothing reliable to read: page objects are
+/// usually packed into compressed object streams.
+#[cfg(not(feature = "pdf-preview"))]
+fn probe_pdf(_bytes: &[u8]) -> MediaProbe {
+    MediaProbe::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut out = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(body);
+        out
+    }
+
+    fn sample_mp4() -> Vec<u8> {
+        // mvhd v0: version/flags, times, timescale 1000, duration 12_345.
+        let mut mvhd = vec![0u8; 12];
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&12_345u32.to_be_bytes());
+        mvhd.extend_from_slice(&[0u8; 80]);
+
+        let audio_tkhd = vec![0u8; 84];
+        let mut video_tkhd = vec![0u8; 76];
+        video_tkhd.extend_from_slice(&(1280u32 << 16).to_be_bytes());
+        video_tkhd.extend_from_slice(&(720u32 << 16).to_be_bytes());
+
+        let moov = [
+            mp4_box(b"mvhd", &mvhd),
+            mp4_box(b"trak", &mp4_box(b"tkhd", &audio_tkhd)),
+            mp4_box(b"trak", &mp4_box(b"tkhd", &video_tkhd)),
+        ]
+        .concat();
+        [
+            mp4_box(b"ftyp", b"isom\0\0\0\0isom"),
+            mp4_box(b"mdat", &[0u8; 32]),
+            mp4_box(b"moov", &moov),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn mp4_reads_duration_and_video_track_size() {
+        let bytes = sample_mp4();
+        assert!(matches_signature("video/mp4", &bytes));
+
+        let probe = probe("video/mp4", &bytes);
+        assert_eq!(probe.duration_ms, Some(12_345));
+        assert_eq!((probe.width, probe.height), (Some(1280), Some(720)));
+    }
+
+    fn ebml(id: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut out = id.to_vec();
+        out.push(0x80 | body.len() as u8);
+        out.extend_from_slice(body);
+        out
+    }
+
+    #[test]
+    fn webm_reads_scaled_duration_and_pixel_size() {
+        let info = [
+            ebml(&[0x2A, 0xD7, 0xB1], &[0x0F, 0x42, 0x40]),
+            ebml(&[0x44, 0x89], &2_500.0f64.to_be_bytes()),
+        ]
+        .concat();
+        let video = [ebml(&[0xB0], &[0x02, 0x80]), ebml(&[0xBA], &[0x01, 0xE0])].concat();
+        let tracks = ebml(&[0xAE], &ebml(&[0xE0], &video));
+        let segment_body = [
+            ebml(&[0x15, 0x49, 0xA9, 0x66], &info),
+            ebml(&[0x16, 0x54, 0xAE, 0x6B], &tracks),
+        ]
+        .concat();
+        // Unknown-size segment, as MediaRecorder writes it.
+        let mut bytes = ebml(&[0x1A, 0x45, 0xDF, 0xA3], b"\x42\x82\x84webm");
+        bytes.extend_from_slice(&[0x18, 0x53, 0x80, 0x67, 0xFF]);
+        bytes.extend_from_slice(&segment_body);
+
+        assert!(matches_signature("video/webm", &bytes));
+        let probe = probe("video/webm", &bytes);
+        assert_eq!(probe.duration_ms, Some(2_500));
+        assert_eq!((probe.width, probe.height), (Some(640), Some(480)));
+    }
+
+    fn mp3_header() -> [u8; 4] {
+        // MPEG-1 layer III, 128 kbps, 44.1 kHz, stereo.
+        [0xFF, 0xFB, 0x90, 0x00]
+    }
+
+    #[test]
+    fn mp3_cbr_duration_from_byte_count() {
+        // 16_000 bytes at 128 kbps is one second.
+        let mut bytes = mp3_header().to_vec();
+        bytes.resize(16_000, 0);
+        assert!(matches_signature("audio/mpeg", &bytes));
+        assert_eq!(probe("audio/mpeg", &bytes).duration_ms, Some(1_000));
+    }
+
+    #[test]
+    fn mp3_vbr_duration_from_xing_frames() {
+        let mut frame = mp3_header().to_vec();
+        frame.resize(36, 0);
+        frame.extend_from_slice(b"Xing");
+        frame.extend_from_slice(&1u32.to_be_bytes());
+        frame.extend_from_slice(&100u32.to_be_bytes());
+        frame.resize(417, 0);
+
+        // 100 frames of 1152 samples at 44.1 kHz.
+        assert_eq!(probe("audio/mpeg", &frame).duration_ms, Some(2_612));
+    }
+
+    #[test]
+    fn mp3_extracts_front_cover_from_id3() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png.extend_from_slice(&13u32.to_be_bytes());
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&300u32.to_be_bytes());
+        png.extend_from_slice(&200u32.to_be_bytes());
+        png.extend_from_slice(&[8, 6, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut apic = vec![0u8];
+        apic.extend_from_slice(b"image/png\0");
+        apic.push(3);
+        apic.extend_from_slice(b"cover\0");
+        apic.extend_from_slice(&png);
+
+        let mut frame = b"APIC".to_vec();
+        frame.extend_from_slice(&(apic.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+        frame.extend_from_slice(&apic);
+
+        let mut bytes = b"ID3\x03\x00\x00".to_vec();
+        let len = frame.len() as u32;
+        bytes.extend_from_slice(&[
+            (len >> 21 & 0x7F) as u8,
+            (len >> 14 & 0x7F) as u8,
+            (len >> 7 & 0x7F) as u8,
+            (len & 0x7F) as u8,
+        ]);
+        bytes.extend_from_slice(&frame);
+        bytes.extend_from_slice(&mp3_header());
+        bytes.resize(bytes.len() + 4_000, 0);
+
+        let probe = probe("audio/mpeg", &bytes);
+        let poster = probe.poster.expect("cover art");
+        assert_eq!(poster.mime_type, "image/png");
+        assert_eq!((poster.width, poster.height), (Some(300), Some(200)));
+        assert!(probe.duration_ms.is_some());
+    }
+
+    fn ogg_page(granule: i64, serial: u32, body: &[u8]) -> Vec<u8> {
+        let mut out = b"OggS\0\0".to_vec();
+        out.extend_from_slice(&granule.to_le_bytes());
+        out.extend_from_slice(&serial.to_le_bytes());
+        out.extend_from_slice(&[0u8; 8]);
+        out.push(1);
+        out.push(body.len() as u8);
+        out.extend_from_slice(body);
+        out
+    }
+
+    #[test]
+    fn ogg_vorbis_duration_from_last_granule() {
+        let mut ident = b"\x01vorbis".to_vec();
+        ident.extend_from_slice(&0u32.to_le_bytes());
+        ident.push(2);
+        ident.extend_from_slice(&44_100u32.to_le_bytes());
+        ident.resize(30, 0);
+
+        let bytes = [
+            ogg_page(0, 7, &ident),
+            ogg_page(44_100 * 3, 9, &[0u8; 4]),
+            ogg_page(44_100 * 2, 7, &[0u8; 4]),
+        ]
+        .concat();
+        assert!(matches_signature("audio/ogg", &bytes));
+        assert_eq!(probe("audio/ogg", &bytes).duration_ms, Some(2_000));
+    }
+
+    #[test]
+    fn ogg_opus_subtracts_pre_skip() {
+        let mut head = b"OpusHead\x01\x02".to_vec();
+        head.extend_from_slice(&312u16.to_le_bytes());
+        head.extend_from_slice(&48_000u32.to_le_bytes());
+        head.resize(19, 0);
+
+        let bytes = [ogg_page(0, 1, &head), ogg_page(48_000 + 312, 1, &[0u8; 4])].concat();
+        assert_eq!(probe("audio/ogg", &bytes).duration_ms, Some(1_000));
+    }
+
+    #[test]
+    fn signatures_reject_mislabeled_content() {
+        let html = b"<!doctype html><script>alert(1)</script>";
+        for mime in [
+            "video/mp4",
+            "video/webm",
+            "audio/mpeg",
+            "audio/ogg",
+            "application/pdf",
+        ] {
+            assert!(!matches_signature(mime, html), "{mime}");
+        }
+        assert!(matches_signature(
+            "application/pdf",
+            b"\xEF\xBB\xBF%PDF-1.7\n"
+        ));
+        assert!(!matches_signature("image/png", b"\x89PNG"));
+    }
+
+    #[test]
+    fn sparse_mp4_finds_moov_at_either_end() {
+        let bytes = sample_mp4();
+        let len = bytes.len() as u64;
+        // moov trails the media data: it is only in the tail window.
+        let probe = probe_sparse("video/mp4", &bytes[..40], &bytes[50..], len);
+        assert_eq!(probe.duration_ms, Some(12_345));
+        assert_eq!((probe.width, probe.height), (Some(1280), Some(720)));
+        // Neither window holds all of it.
+        assert_eq!(
+            probe_sparse("video/mp4", &bytes[..40], &bytes[bytes.len() - 20..], len),
+            MediaProbe::default()
+        );
+
+        // Fast-start layout: moov right after ftyp, in the head window.
+        let moov = mp4_child(&bytes, b"moov").unwrap();
+        let faststart = [
+            mp4_box(b"ftyp", b"isom\0\0\0\0isom"),
+            mp4_box(b"moov", moov),
+            mp4_box(b"mdat", &[0u8; 4096]),
+        ]
+        .concat();
+        let head = &faststart[..faststart.len() - 4000];
+        let tail = &faststart[faststart.len() - 16..];
+        let probe = probe_sparse("video/mp4", head, tail, faststart.len() as u64);
+        assert_eq!(probe.duration_ms, Some(12_345));
+    }
+
+    #[test]
+    fn sparse_mp3_counts_bytes_between_the_windows() {
+        let mut bytes = mp3_header().to_vec();
+        bytes.resize(16_000, 0);
+        let whole = probe("audio/mpeg", &bytes);
+        let sparse = probe_sparse("audio/mpeg", &bytes[..1024], &bytes[15_000..], 16_000);
+        assert_eq!(sparse.duration_ms, whole.duration_ms);
+        assert!(sparse.duration_ms.is_some());
+    }
+
+    #[test]
+    fn truncated_input_probes_to_nothing() {
+        for mime in ["video/mp4", "video/webm", "audio/mpeg", "audio/ogg"] {
+            assert_eq!(probe(mime, &[0xFF, 0xFB]), MediaProbe::default(), "{mime}");
+            assert_eq!(probe(mime, &[]), MediaProbe::default(), "{mime}");
+        }
+        let mut mp4 = sample_mp4();
+        mp4.truncate(mp4.len() - 40);
+        assert_eq!(probe("video/mp4", &mp4).width, None);
+    }
+}
@@ -0,0 +1,380 @@
+#![cfg(feature = "image-optimization")]
+
+//! Background (re)optimization of stored images.
+//!
+//! Jobs live in `media_optimization_jobs`. Admins enqueue a batch through
+//! `/media/v1/optimize/run` (e.g. after changing `OptimizerConfig`), and
+//! uploads enqueue themselves when `OPTIMIZER_DEFER_UPLOADS` is on. The worker
+//! regenerates variants from the stored original; the original object itself
+//! is never rewritten.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use lazy_static::lazy_static;
+use tokio::sync::{Notify, Semaphore};
+use tracing::{error, info, instrument, warn};
+
+use crate::db::sea_models::{
+    media::{self, Entity as Media},
+    media_optimization_job::{Entity as MediaOptimizationJob, JobResult, MediaJobStatus, Model},
+    media_variant::{Entity as MediaVariant, NewMediaVariant},
+};
+use crate::modules::media_v1::validator::MediaUploadMetadata;
+use crate::services::image_optimizer::{self, OptimizationOutcome, SkipReason};
+use crate::state::AppState;
+
+lazy_static! {
+    static ref QUEUE_NOTIFY: Notify = Notify::new();
+}
+
+/// Runtime settings for the optimization worker.
+#[derive(Debug, Clone)]
+pub struct MediaReoptimizerConfig {
+    pub enabled: bool,
+    /// Jobs processed at once. Each one decodes and re-encodes on a blocking
+    /// thread, so this is the CPU bound for background image work.
+    pub concurrency: usize,
+    /// Fallback poll when nobody calls [`wake`].
+    pub poll_interval_secs: u64,
+    /// A job that errors is retried until it has been claimed this many times.
+    pub max_attempts: i32,
+    /// Delay before the first retry of a failed job; doubles per attempt.
+    pub retry_base_secs: u64,
+    /// How long a job may stay `running` before it is presumed abandoned by
+    /// a dead worker and requeued. Must comfortably exceed the slowest job.
+    pub lease_secs: u64,
+}
+
+impl Default for MediaReoptimizerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            concurrency: 2,
+            poll_interval_secs: 30,
+            max_attempts: 3,
+            retry_base_secs: 60,
+            lease_secs: 15 * 60,
+        }
+    }
+}
+
+/// Nudge the worker after enqueueing so it doesn't wait for the next poll.
+pub fn wake() {
+    QUEUE_NOTIFY.notify_one();
+}
+
+/// Start the optimization worker as a background tokio task.
+pub fn start_media_reoptimizer(state: AppState, config: MediaReoptimizerConfig) {
+    if !config.enabled {
+        info!("Media re-optimization worker disabled");
+        return;
+    }
+
+    info!(
+        concurrency = config.concurrency,
+        poll_interval_secs = config.poll_interval_secs,
+        "Media re-optimization worker started"
+    );
+    tokio::spawn(run(state, config));
+}
+
+async fn run(state: AppState, config: MediaReoptimizerConfig) {
+    let permits = Arc::new(Semaphore::new(config.concurrency.max(1)));
+    let poll = Duration::from_secs(config.poll_interval_secs.max(1));
+    let mut last_sweep: Option<Instant> = None;
+
+    loop {
+        // Other replicas may be mid-job, so only rows past their lease are
+        // taken back; check once per poll interval.
+        if last_sweep.is_none_or(|at| at.elapsed() >= poll) {
+            last_sweep = Some(Instant::now());
+            requeue_stale(&state, &config).await;
+        }
+
+        // Only claim as many jobs as there are free workers, so queued rows
+        // stay `pending` (and visible as such) until a worker picks them up.
+        let available = permits.available_permits();
+        let claimed = if available == 0 {
+            Vec::new()
+        } else {
+            match MediaOptimizationJob::claim_pending(&state.sea_db, available as u64).await {
+                Ok(jobs) => jobs,
+                Err(err) => {
+                    error!(error = %err, "Failed to claim media optimization jobs");
+                    Vec::new()
+                }
+            }
+        };
+
+        let idle = claimed.is_empty();
+        for job in claimed {
+            let Ok(permit) = permits.clone().acquire_owned().await else {
+                return;
+            };
+            let state = state.clone();
+            let config = config.clone();
+            tokio::spawn(async move {
+                process_job(&state, job, &config).await;
+                drop(permit);
+                // A slot just freed up; look for more work right away.
+                wake();
+            });
+        }
+
+        if idle {
+            tokio::select! {
+                _ = QUEUE_NOTIFY.notified() => {}
+                _ = tokio::time::sleep(poll) => {}
+            }
+        }
+    }
+}
+
+async fn requeue_stale(state: &AppState, config: &MediaReoptimizerConfig) {
+    let lease = chrono::Duration::seconds(config.lease_secs.max(1) as i64);
+    let stale_before = (Utc::now() - lease).fixed_offset();
+    match MediaOptimizationJob::requeue_stale(&state.sea_db, stale_before, config.max_attempts)
+        .await
+    {
+        Ok(0) => {}
+        Ok(count) => info!(count, "Recovered abandoned media optimization jobs"),
+        Err(err) => error!(error = %err, "Failed to recover abandoned optimization jobs"),
+    }
+}
+
+/// Backoff before retry number `attempts`: doubles each time, capped at an
+/// hour.
+fn retry_delay(base_secs: u64, attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 20) as u32;
+    let secs = base_secs.max(1).saturating_mul(1u64 << exponent);
+    Duration::from_secs(secs.min(60 * 60))
+}
+
+#[instrument(skip(state, job, config), fields(job_id = job.id, media_id = job.media_id))]
+async fn process_job(state: &AppState, job: Model, config: &MediaReoptimizerConfig) {
+    let result = match reoptimize_media(state, job.media_id).await {
+        Ok(result) => result,
+        Err(message) if job.attempts < config.max_attempts => {
+            let delay = retry_delay(config.retry_base_secs, job.attempts);
+            warn!(
+                attempts = job.attempts,
+                retry_in_secs = delay.as_secs(),
+                error = %message,
+                "Media optimization failed; will retry"
+            );
+            JobResult {
+                status: MediaJobStatus::Pending,
+                message: Some(message),
+                variant_count: None,
+                next_attempt_at: Some((Utc::now() + delay).fixed_offset()),
+            }
+        }
+        Err(message) => {
+            error!(attempts = job.attempts, error = %message, "Media optimization failed");
+            JobResult {
+                status: MediaJobStatus::Failed,
+                message: Some(message),
+                variant_count: None,
+                next_attempt_at: None,
+            }
+        }
+    };
+
+    if let Err(err) = MediaOptimizationJob::finish(&state.sea_db, job, result).await {
+        error!(error = %err, "Failed to record media optimization result");
+    }
+}
+
+/// Regenerate the variants of one media item from its stored original.
+async fn reoptimize_media(state: &AppState, media_id: i32) -> Result<JobResult, String> {
+    let Some(media) = Media::find_by_id(&state.sea_db, media_id)
+        .await
+        .map_err(|err| err.message)?
+    else {
+        return Ok(skipped("media no longer exists"));
+    };
+    if !media.mime_type.starts_with("image/") {
+        return Ok(skipped("not an image"));
+    }
+
+    let blob = state
+        .storage
+        .get(&media.object_key)
+        .await
+        .map_err(|err| format!("failed to read original: {err}"))?
+        .ok_or_else(|| "original object is missing from storage".to_string())?;
+
+    let metadata = MediaUploadMetadata {
+        reference_type: media.reference_type,
+        width: media.width,
+        height: media.height,
+        focal_x: media.focal_x,
+        focal_y: media.focal_y,
+        ..Default::default()
+    };
+    let config = state.optimizer.clone();
+    let mime = media.mime_type.clone();
+    let extension = media.extension.clone();
+    let bytes = blob.bytes;
+    let outcome = tokio::task::spawn_blocking(move || {
+        image_optimizer::optimize(
+            &config,
+            image_optimizer::OptimizationRequest {
+                bytes: &bytes,
+                metadata: &metadata,
+                reference: metadata.reference_type,
+                original_mime: Some(&mime),
+                original_extension: extension.as_deref(),
+                regenerate: true,
+            },
+        )
+    })
+    .await
+    .map_err(|err| format!("optimizer task failed: {err}"))?
+    .map_err(|err| err.to_string())?;
+
+    let result = match outcome {
+        OptimizationOutcome::Optimized(result) => result,
+        OptimizationOutcome::Skipped(reason) => return Ok(skipped(skip_message(&reason))),
+    };
+
+    // Variants are served as immutable, so the new set goes to fresh keys and
+    // the old objects are deleted once the rows point at the new ones.
+    let version = uuid::Uuid::new_v4().simple().to_string();
+    let version = &version[..8];
+    let mut records = Vec::with_capacity(result.variants.len());
+    for variant in &result.variants {
+        let Some(key) =
+            image_optimizer::variant_object_key(&media.object_key, variant, Some(version))
+        else {
+            continue;
+        };
+        state
+            .storage
+            .put(&key, variant.bytes.clone(), &variant.mime_type)
+            .await
+            .map_err(|err| format!("failed to store variant {key}: {err}"))?;
+        records.push(NewMediaVariant {
+            media_id: media.id,
+            object_key: key,
+            mime_type: variant.mime_type.clone(),
+            width: i32::try_from(variant.width).ok(),
+            height: i32::try_from(variant.height).ok(),
+            size: variant.bytes.len() as i64,
+            extension: (!variant.extension.is_empty()).then(|| variant.extension.clone()),
+            quality: variant.quality.map(i32::from),
+            variant_type: variant.label.variant_type(),
+        });
+    }
+
+    let kept: HashSet<String> = records.iter().map(|r| r.object_key.clone()).collect();
+    let variant_count = records.len() as i32;
+    let previous = MediaVariant::replace_for_media(&state.sea_db, media.id, records)
+        .await
+        .map_err(|err| err.message)?;
+    for stale in stale_variant_keys(&previous, &kept) {
+        // A leftover object is harmless and media GC picks it up later.
+        if let Err(err) = state.storage.delete(&stale).await {
+            warn!(key = %stale, error = %err, "Failed to delete replaced media variant");
+        }
+    }
+
+    let width = i32::try_from(result.original.width).ok();
+    let height = i32::try_from(result.original.height).ok();
+    media::Entity::mark_optimized(&state.sea_db, media, width, height, result.blurhash)
+        .await
+        .map_err(|err| err.message)?;
+
+    Ok(JobResult {
+        status: MediaJobStatus::Completed,
+        message: None,
+        variant_count: Some(variant_count),
+        next_attempt_at: None,
+    })
+}
+
+fn skipped(reason: &str) -> JobResult {
+    JobResult {
+        status: MediaJobStatus::Skipped,
+        message: Some(reason.to_string()),
+        variant_count: None,
+        next_attempt_at: None,
+    }
+}
+
+fn skip_message(reason: &SkipReason) -> &'static str {
+    match reason {
+        SkipReason::Disabled => "optimizer disabled",
+        SkipReason::UnsupportedFormat => "unsupported format",
+        SkipReason::ExceedsPixelBudget => "exceeds pixel budget",
+        SkipReason::AlreadyOptimized => "already optimized",
+        SkipReason::DecodeFailed => "decode failed",
+    }
+}
+
+/// Object keys of previous variants that the new set doesn't overwrite.
+fn stale_variant_keys(
+    previous: &[crate::db::sea_models::media_variant::Model],
+    kept: &HashSet<String>,
+) -> Vec<String> {
+    previous
+        .iter()
+        .filter(|variant| !kept.contains(&variant.object_key))
+        .map(|variant| variant.object_key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(key: &str) -> crate::db::sea_models::media_variant::Model {
+        let now = Utc::now().fixed_offset();
+        crate::db::sea_models::media_variant::Model {
+            id: 1,
+            media_id: 1,
+            object_key: key.to_string(),
+            mime_type: "image/webp".into(),
+            width: None,
+            height: None,
+            size: 1,
+            extension: None,
+            quality: None,
+            variant_type: "480w".into(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn stale_keys_exclude_overwritten_variants() {
+        let previous = vec![variant("m/a@480w.webp"), variant("m/a@1920w.webp")];
+        let kept: HashSet<String> = ["m/a@480w.webp".to_string(), "m/a@480w.avif".to_string()]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            stale_variant_keys(&previous, &kept),
+            vec!["m/a@1920w.webp".to_string()]
+        );
+    }
+
+    #[test]
+    fn config_defaults_bound_concurrency() {
+        let config = MediaReoptimizerConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.concurrency, 2);
+        assert!(config.max_attempts > 0);
+        assert!(config.lease_secs >= 60);
+    }
+
+    #[test]
+    fn retry_delay_doubles_and_caps_at_an_hour() {
+        assert_eq!(retry_delay(60, 1), Duration::from_secs(60));
+        assert_eq!(retry_delay(60, 3), Duration::from_secs(240));
+        assert_eq!(retry_delay(60, 40), Duration::from_secs(60 * 60));
+        assert_eq!(retry_delay(0, 1), Duration::from_secs(1));
+    }
+}
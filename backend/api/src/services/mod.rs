@@ -1,14 +1,40 @@
 // Always enabled
 pub mod abuse_limiter;
 pub mod auth;
+pub mod blocklist;
+pub mod comment_moderation;
+pub mod fx;
+pub mod geoip;
 pub mod mail;
+pub mod media_gc;
+pub mod media_probe;
+pub mod notification_hub;
+pub mod notifications;
 pub mod paywall;
+pub mod redirects;
 pub mod redis;
+pub mod seo;
+pub mod sitemap;
+pub mod storage;
+pub mod traffic;
+pub mod webhooks;
 
 // Feature-gated
+#[cfg(feature = "analytics")]
+pub mod analytics_rollup;
+
 #[cfg(feature = "image-optimization")]
 pub mod image_optimizer;
 
+#[cfg(feature = "image-optimization")]
+pub mod media_reoptimizer;
+
+#[cfg(feature = "pdf-preview")]
+pub mod pdf_preview;
+
+#[cfg(feature = "og-image")]
+pub mod og_image;
+
 #[cfg(feature = "admin-acl")]
 pub mod acl_service;
 
@@ -0,0 +1,181 @@
+//! Real-time fan-out for in-app notifications.
+//!
+//! A stored notification is published once on the Redis channel
+//! [`CHANNEL`]. Every replica runs a relay ([`start_notification_relay`])
+//! that subscribes to the channel and hands each message to its local
+//! [`NotificationHub`]; the SSE streams open on that replica pick out their
+//! user's notifications. A client therefore hears about a notification no
+//! matter which replica created it or which one it is connected to.
+
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tower_sessions_redis_store::fred::prelude::{
+    Client, ClientLike, EventInterface, Pool as RedisPool, PubsubInterface,
+};
+use tracing::{error, info, warn};
+
+use crate::db::sea_models::notification;
+
+/// Redis pub/sub channel carrying every new in-app notification.
+pub const CHANNEL: &str = "notifications:realtime";
+
+/// Notifications buffered per subscriber before a slow stream starts
+/// skipping; the client catches up from the list endpoint.
+const HUB_CAPACITY: usize = 256;
+
+/// Process-local broadcast of notifications received from Redis.
+pub struct NotificationHub {
+    sender: broadcast::Sender<notification::Model>,
+}
+
+impl Default for NotificationHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(HUB_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<notification::Model> {
+        self.sender.subscribe()
+    }
+
+    /// Hand a notification to this replica's open streams. No open streams
+    /// is not an error.
+    pub fn send(&self, notification: notification::Model) {
+        let _ = self.sender.send(notification);
+    }
+}
+
+/// Publish a stored notification to every replica. If Redis is unavailable
+/// the notification still reaches streams on this replica.
+pub async fn publish(redis_pool: &RedisPool, hub: &NotificationHub, item: &notification::Model) {
+    let payload = match serde_json::to_string(item) {
+        Ok(payload) => payload,
+        Err(err) => {
+            error!(
+                notification_id = item.id,
+                "Failed to encode notification: {}", err
+            );
+            return;
+        }
+    };
+    if let Err(err) = redis_pool
+        .next()
+        .publish::<(), _, _>(CHANNEL, payload)
+        .await
+    {
+        warn!(
+            notification_id = item.id,
+            error = ?err,
+            "Failed to publish notification; delivering locally only"
+        );
+        hub.send(item.clone());
+    }
+}
+
+/// Start the relay as a background tokio task. It holds its own Redis
+/// connection, since a subscribed connection cannot run other commands, and
+/// re-subscribes whenever that connection reconnects. Connecting and
+/// subscribing are retried with backoff, so a Redis outage at boot only
+/// delays cross-replica delivery.
+pub fn start_notification_relay(redis_pool: RedisPool, hub: std::sync::Arc<NotificationHub>) {
+    tokio::spawn(async move {
+        let client = connect(&redis_pool).await;
+        let mut reconnects = client.reconnect_rx();
+        let mut messages = client.message_rx();
+        subscribe(&client).await;
+        info!(channel = CHANNEL, "Notification relay started");
+
+        loop {
+            tokio::select! {
+                reconnect = reconnects.recv() => match reconnect {
+                    Ok(_) => subscribe(&client).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                message = messages.recv() => match message {
+                    Ok(message) => {
+                        let Some(payload) = message.value.as_str() else {
+                            continue;
+                        };
+                        match serde_json::from_str::<notification::Model>(&payload) {
+                            Ok(item) => hub.send(item),
+                            Err(err) => warn!("Ignoring malformed notification message: {}", err),
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Notification relay lagged behind Redis");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            }
+        }
+        warn!("Notification relay stopped");
+    });
+}
+
+/// A fresh, initialized connection for the relay; retries until Redis
+/// answers.
+async fn connect(redis_pool: &RedisPool) -> Client {
+    let mut attempt = 0;
+    loop {
+        let client = redis_pool.next().clone_new();
+        match client.init().await {
+            Ok(_) => return client,
+            Err(err) => {
+                attempt += 1;
+                let delay = retry_delay(attempt);
+                error!(
+                    error = ?err,
+                    attempt,
+                    retry_in_secs = delay.as_secs(),
+                    "Notification relay failed to connect to Redis"
+                );
+                let _ = client.quit().await;
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Subscribe to [`CHANNEL`], retrying until it succeeds.
+async fn subscribe(client: &Client) {
+    let mut attempt = 0;
+    while let Err(err) = client.subscribe(CHANNEL).await {
+        attempt += 1;
+        let delay = retry_delay(attempt);
+        error!(
+            error = ?err,
+            attempt,
+            retry_in_secs = delay.as_secs(),
+            "Notification relay failed to subscribe"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// One second after the first failure, doubling up to a minute.
+fn retry_delay(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(6);
+    Duration::from_secs((1u64 << exponent).min(60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_and_caps_at_a_minute() {
+        assert_eq!(retry_delay(1), Duration::from_secs(1));
+        assert_eq!(retry_delay(2), Duration::from_secs(2));
+        assert_eq!(retry_delay(6), Duration::from_secs(32));
+        assert_eq!(retry_delay(7), Duration::from_secs(60));
+        assert_eq!(retry_delay(u32::MAX), Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,633 @@
+//! Notification dispatcher and email digest worker.
+//!
+//! Handlers report what happened with [`dispatch`]; the dispatcher works out
+//! who should hear about it in a background task, so the request never waits
+//! on recipient lookups or SMTP:
+//! - a published comment notifies the parent comment's author (reply), users
+//!   whose `@profile_slug` it mentions, and the post's authors, each at most
+//!   once and never the commenter
+//! - registrations, payments, cancellations and newsletter signups notify
+//!   admins
+//! - events with a webhook type are also handed to [`webhooks`]
+//!
+//! Each recipient's `notification_preferences` decide whether the
+//! notification lands in their in-app notification center (pushed live
+//! through [`notification_hub`]) and whether they get an email right away,
+//! an entry in their daily digest, or nothing. Every email carries a
+//! one-click unsubscribe link (RFC 8058).
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use regex::Regex;
+use sea_orm::{ColumnTrait, DbConn, EntityTrait, QueryFilter};
+use serde::Serialize;
+use tracing::{error, info, instrument, warn};
+
+use crate::db::sea_models::{
+    notification::{self, NewNotification},
+    notification_digest_item::{self, NewDigestItem},
+    notification_preference::{self, EmailDelivery, NotificationKind},
+    post, post_author, post_comment, user, user_ban,
+};
+use crate::error::DbResult;
+use crate::services::{abuse_limiter, mail, notification_hub, sitemap, webhooks};
+use crate::state::AppState;
+
+/// Longest comment excerpt quoted in an email.
+const EXCERPT_CHARS: usize = 280;
+
+/// Most users one comment can notify by mention; further `@handles` are
+/// ignored so a single comment cannot fan out to the whole user base.
+const MAX_MENTIONS: usize = 10;
+
+/// `@profile_slug` mentions in comment text.
+static MENTION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^\w@])@([a-z0-9](?:[a-z0-9-]*[a-z0-9])?)\b").unwrap());
+
+/// Something that happened which someone may want to hear about.
+#[derive(Debug, Clone)]
+pub enum SiteEvent {
+    /// A post went live. Only webhooks are told about it.
+    PostPublished {
+        post_id: i32,
+    },
+    /// A comment became visible, on creation or when a moderator approved it.
+    CommentPublished {
+        comment_id: i32,
+    },
+    UserRegistered {
+        user_id: i32,
+    },
+    PaymentReceived {
+        user_id: i32,
+        amount_cents: i32,
+        currency: String,
+    },
+    SubscriptionCanceled {
+        user_id: i32,
+    },
+    NewsletterSubscribed {
+        email: String,
+    },
+}
+
+/// One notification for one recipient.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Notification {
+    pub user_id: i32,
+    pub kind: NotificationKind,
+    pub subject: String,
+    pub body: String,
+    pub link: Option<String>,
+}
+
+/// Deliver `event` in the background. Failures are logged, never surfaced
+/// to the request that triggered it.
+pub fn dispatch(state: &AppState, event: SiteEvent) {
+    webhooks::dispatch(state, &event);
+    let state = state.clone();
+    tokio::spawn(async move {
+        if let Err(err) = deliver(&state, event.clone()).await {
+            error!(event = ?event, error = ?err, "Notification dispatch failed");
+        }
+    });
+}
+
+#[instrument(skip(state))]
+async fn deliver(state: &AppState, event: SiteEvent) -> DbResult<()> {
+    let notifications = resolve(&state.sea_db, &event).await?;
+    if notifications.is_empty() {
+        return Ok(());
+    }
+
+    let user_ids: Vec<i32> = notifications
+        .iter()
+        .map(|n| n.user_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let preferences =
+        notification_preference::Entity::find_or_create_many(&state.sea_db, &user_ids).await?;
+    let users: HashMap<i32, user::Model> = user::Entity::find()
+        .filter(user::Column::Id.is_in(user_ids.iter().copied()))
+        .all(&state.sea_db)
+        .await?
+        .into_iter()
+        .map(|u| (u.id, u))
+        .collect();
+
+    for notification in notifications {
+        let (Some(preference), Some(recipient)) = (
+            preferences.get(&notification.user_id),
+            users.get(&notification.user_id),
+        ) else {
+            continue;
+        };
+        if preference.wants_in_app(notification.kind) {
+            notify_in_app(state, &notification).await?;
+        }
+        if !preference.wants_email(notification.kind) {
+            continue;
+        }
+
+        match preference.email_delivery {
+            EmailDelivery::Immediate => {
+                if let Err(err) = send_immediate(state, recipient, preference, &notification).await
+                {
+                    warn!(
+                        user_id = recipient.id,
+                        kind = ?notification.kind,
+                        "Failed to send notification email: {}",
+                        err
+                    );
+                }
+            }
+            EmailDelivery::Digest => {
+                notification_digest_item::Entity::enqueue(
+                    &state.sea_db,
+                    NewDigestItem {
+                        user_id: notification.user_id,
+                        kind: notification.kind,
+                        subject: notification.subject,
+                        body: notification.body,
+                        link: notification.link,
+                    },
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Store `notification` in the recipient's notification center and push it
+/// to their open streams.
+async fn notify_in_app(state: &AppState, notification: &Notification) -> DbResult<()> {
+    let item = notification::Entity::create(
+        &state.sea_db,
+        NewNotification {
+            user_id: notification.user_id,
+            kind: notification.kind,
+            subject: notification.subject.clone(),
+            body: notification.body.clone(),
+            link: notification.link.clone(),
+        },
+    )
+    .await?;
+    notification_hub::publish(&state.redis_pool, &state.notification_hub, &item).await;
+    Ok(())
+}
+
+async fn resolve(conn: &DbConn, event: &SiteEvent) -> DbResult<Vec<Notification>> {
+    match event {
+        SiteEvent::PostPublished { .. } => Ok(Vec::new()),
+        SiteEvent::CommentPublished { comment_id } => {
+            comment_notifications(conn, *comment_id).await
+        }
+        SiteEvent::UserRegistered { user_id } => {
+            let Some(new_user) = user::Entity::find_by_id(*user_id).one(conn).await? else {
+                return Ok(Vec::new());
+            };
+            admin_notifications(
+                conn,
+                NotificationKind::NewUser,
+                format!("New user: {}", new_user.name),
+                format!("{} <{}> just signed up.", new_user.name, new_user.email),
+            )
+            .await
+        }
+        SiteEvent::PaymentReceived {
+            user_id,
+            amount_cents,
+            currency,
+        } => {
+            let payer = user_name(conn, *user_id).await?;
+            let amount = format_amount(*amount_cents, currency);
+            admin_notifications(
+                conn,
+                NotificationKind::PaymentReceived,
+                format!("Payment received: {}", amount),
+                format!("{} paid {}.", payer, amount),
+            )
+            .await
+        }
+        SiteEvent::SubscriptionCanceled { user_id } => {
+            let subscriber = user_name(conn, *user_id).await?;
+            admin_notifications(
+                conn,
+                NotificationKind::SubscriptionCanceled,
+                format!("Subscription canceled: {}", subscriber),
+                format!("{} canceled their subscription.", subscriber),
+            )
+            .await
+        }
+        SiteEvent::NewsletterSubscribed { email } => {
+            admin_notifications(
+                conn,
+                NotificationKind::NewsletterSubscriber,
+                "New newsletter subscriber".to_string(),
+                format!("{} subscribed to the newsletter.", email),
+            )
+            .await
+        }
+    }
+}
+
+async fn user_name(conn: &DbConn, user_id: i32) -> DbResult<String> {
+    Ok(user::Entity::find_by_id(user_id)
+        .one(conn)
+        .await?
+        .map(|u| u.name)
+        .unwrap_or_else(|| "Someone".to_string()))
+}
+
+async fn admin_notifications(
+    conn: &DbConn,
+    kind: NotificationKind,
+    subject: String,
+    body: String,
+) -> DbResult<Vec<Notification>> {
+    let admins = user::Entity::find()
+        .filter(user::Column::Role.is_in([user::UserRole::Admin, user::UserRole::SuperAdmin]))
+        .all(conn)
+        .await?;
+    Ok(admins
+        .into_iter()
+        .map(|admin| Notification {
+            user_id: admin.id,
+            kind,
+            subject: subject.clone(),
+            body: body.clone(),
+            link: None,
+        })
+        .collect())
+}
+
+async fn comment_notifications(conn: &DbConn, comment_id: i32) -> DbResult<Vec<Notification>> {
+    let Some(comment) = post_comment::Entity::find_by_id(comment_id)
+        .one(conn)
+        .await?
+    else {
+        return Ok(Vec::new());
+    };
+    // Only comments readers can see; a shadow-banned author's comments are
+    // visible to nobody else, so they must not leak out through email.
+    if !comment.is_published() || user_ban::Entity::is_shadow_banned(conn, comment.user_id).await? {
+        return Ok(Vec::new());
+    }
+    let Some(post) = post::Entity::find_by_id(comment.post_id).one(conn).await? else {
+        return Ok(Vec::new());
+    };
+    let commenter = user_name(conn, comment.user_id).await?;
+
+    // Most specific first: `dedupe_targets` keeps each user's first entry.
+    let mut targets = Vec::new();
+    if let Some(parent_id) = comment.parent_id {
+        if let Some(parent) = post_comment::Entity::find_by_id(parent_id)
+            .one(conn)
+            .await?
+        {
+            targets.push((parent.user_id, NotificationKind::CommentReply));
+        }
+    }
+    let slugs = mentioned_slugs(&comment.content);
+    if !slugs.is_empty() {
+        let mentioned = user::Entity::find()
+            .filter(user::Column::ProfileSlug.is_in(slugs))
+            .all(conn)
+            .await?;
+        targets.extend(
+            mentioned
+                .into_iter()
+                .map(|u| (u.id, NotificationKind::CommentMention)),
+        );
+    }
+    targets.push((post.author_id, NotificationKind::CommentOnPost));
+    let co_authors = post_author::Entity::find()
+        .filter(post_author::Column::PostId.eq(post.id))
+        .all(conn)
+        .await?;
+    targets.extend(
+        co_authors
+            .into_iter()
+            .map(|a| (a.user_id, NotificationKind::CommentOnPost)),
+    );
+
+    let link = format!(
+        "{}/posts/{}#comment-{}",
+        sitemap::base_url(),
+        post.slug,
+        comment.id
+    );
+    let body = excerpt(&comment.content, EXCERPT_CHARS);
+    Ok(dedupe_targets(comment.user_id, targets)
+        .into_iter()
+        .map(|(user_id, kind)| {
+            let subject = match kind {
+                NotificationKind::CommentReply => {
+                    format!(
+                        "{} replied to your comment on \"{}\"",
+                        commenter, post.title
+                    )
+                }
+                NotificationKind::CommentMention => {
+                    format!("{} mentioned you on \"{}\"", commenter, post.title)
+                }
+                _ => format!("{} commented on \"{}\"", commenter, post.title),
+            };
+            Notification {
+                user_id,
+                kind,
+                subject,
+                body: body.clone(),
+                link: Some(link.clone()),
+            }
+        })
+        .collect())
+}
+
+/// Lowercased `@profile_slug` mentions, deduplicated, in order of appearance,
+/// capped at [`MAX_MENTIONS`].
+pub fn mentioned_slugs(content: &str) -> Vec<String> {
+    let lowered = content.to_lowercase();
+    let mut seen = HashSet::new();
+    MENTION_RE
+        .captures_iter(&lowered)
+        .map(|caps| caps[1].to_string())
+        .filter(|slug| seen.insert(slug.clone()))
+        .take(MAX_MENTIONS)
+        .collect()
+}
+
+/// Keep each recipient's first (most specific) notification and drop the
+/// actor, who never needs telling about their own comment.
+pub fn dedupe_targets(
+    actor_id: i32,
+    targets: Vec<(i32, NotificationKind)>,
+) -> Vec<(i32, NotificationKind)> {
+    let mut seen = HashSet::new();
+    targets
+        .into_iter()
+        .filter(|(user_id, _)| *user_id != actor_id && seen.insert(*user_id))
+        .collect()
+}
+
+fn excerpt(content: &str, max_chars: usize) -> String {
+    let trimmed = content.trim();
+    if trimmed.chars().count() <= max_chars {
+        return trimmed.to_string();
+    }
+    let cut: String = trimmed.chars().take(max_chars).collect();
+    format!("{}…", cut.trim_end())
+}
+
+fn format_amount(amount_cents: i32, currency: &str) -> String {
+    format!(
+        "{:.2} {}",
+        amount_cents as f64 / 100.0,
+        currency.to_uppercase()
+    )
+}
+
+/// One-click unsubscribe link for a preferences row. With `kind`, the link
+/// only turns that kind off.
+pub fn unsubscribe_url(token: &str, kind: Option<NotificationKind>) -> String {
+    let base = std::env::var("SITE_URL").unwrap_or_else(|_| "http://localhost:8888".to_string());
+    let mut url = format!(
+        "{}/notification/v1/unsubscribe/{}",
+        base.trim_end_matches('/'),
+        token
+    );
+    if let Some(kind) = kind {
+        if let Ok(serde_json::Value::String(kind)) = serde_json::to_value(kind) {
+            url.push_str("?kind=");
+            url.push_str(&kind);
+        }
+    }
+    url
+}
+
+async fn send_immediate(
+    state: &AppState,
+    recipient: &user::Model,
+    preference: &notification_preference::Model,
+    notification: &Notification,
+) -> Result<(), String> {
+    let unsubscribe_url = unsubscribe_url(&preference.unsubscribe_token, Some(notification.kind));
+
+    let mut ctx = tera::Context::new();
+    ctx.insert("subject", &notification.subject);
+    ctx.insert("body", &notification.body);
+    ctx.insert("link", &notification.link);
+    ctx.insert("unsubscribe_url", &unsubscribe_url);
+    ctx.insert(
+        "unsubscribe_all_url",
+        &self::unsubscribe_url(&preference.unsubscribe_token, None),
+    );
+    let html = mail::templates::render("notification", &ctx).map_err(|e| e.to_string())?;
+
+    mail::send_notification_email(
+        &state.mailer,
+        &recipient.email,
+        &notification.subject,
+        html,
+        &unsubscribe_url,
+    )
+    .await
+}
+
+/// Runtime settings for the digest worker. Every `interval_secs` it emails
+/// each digest user whose oldest queued notification is at least
+/// `min_age_hours` old, so nobody gets more than about one digest a day.
+#[derive(Debug, Clone)]
+pub struct NotificationDigestConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub min_age_hours: u64,
+    pub batch_limit: u64,
+}
+
+impl Default for NotificationDigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 60 * 60,
+            min_age_hours: 24,
+            batch_limit: 200,
+        }
+    }
+}
+
+/// Start the digest worker as a background tokio task.
+pub fn start_notification_digest(state: AppState, config: NotificationDigestConfig) {
+    if !config.enabled {
+        info!("Notification digest disabled");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        min_age_hours = config.min_age_hours,
+        "Notification digest started"
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(60)));
+        loop {
+            interval.tick().await;
+            match send_digests(&state, &config).await {
+                Ok(0) => {}
+                Ok(sent) => info!(sent, "Notification digests sent"),
+                Err(err) => error!(error = ?err, "Notification digest pass failed"),
+            }
+        }
+    });
+}
+
+/// Send every digest that is due. Returns how many emails went out.
+#[instrument(skip(state))]
+pub async fn send_digests(state: &AppState, config: &NotificationDigestConfig) -> DbResult<usize> {
+    let cutoff =
+        chrono::Utc::now().fixed_offset() - chrono::Duration::hours(config.min_age_hours as i64);
+    let user_ids = notification_digest_item::Entity::pending_user_ids(
+        &state.sea_db,
+        cutoff,
+        config.batch_limit,
+    )
+    .await?;
+
+    let mut sent = 0;
+    for user_id in user_ids {
+        // Replicas run the same pass; whoever claims a user sends their digest.
+        let claim = format!("notification:digest:{}", user_id);
+        if !abuse_limiter::dedup_nx(&state.redis_pool, &claim, 300).await? {
+            continue;
+        }
+        match send_digest(state, user_id).await {
+            Ok(true) => sent += 1,
+            Ok(false) => {}
+            Err(err) => warn!(user_id, error = ?err, "Failed to send notification digest"),
+        }
+    }
+    Ok(sent)
+}
+
+/// Email one user's queued notifications. Items the user no longer wants
+/// are dropped; nothing is marked sent if the email fails.
+async fn send_digest(state: &AppState, user_id: i32) -> DbResult<bool> {
+    let items = notification_digest_item::Entity::pending_for_user(&state.sea_db, user_id).await?;
+    let item_ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+    let preference =
+        notification_preference::Entity::find_or_create(&state.sea_db, user_id).await?;
+    let recipient = user::Entity::find_by_id(user_id).one(&state.sea_db).await?;
+
+    let wanted: Vec<_> = items
+        .into_iter()
+        .filter(|item| preference.wants_email(item.kind))
+        .collect();
+    let Some(recipient) = recipient.filter(|_| !wanted.is_empty()) else {
+        notification_digest_item::Entity::mark_sent(&state.sea_db, &item_ids).await?;
+        return Ok(false);
+    };
+
+    let unsubscribe_url = unsubscribe_url(&preference.unsubscribe_token, None);
+    let mut ctx = tera::Context::new();
+    ctx.insert("user_name", &recipient.name);
+    ctx.insert(
+        "items",
+        &wanted
+            .iter()
+            .map(|item| {
+                serde_json::json!({
+                    "subject": item.subject,
+                    "body": item.body,
+                    "link": item.link,
+                })
+            })
+            .collect::<Vec<_>>(),
+    );
+    ctx.insert("unsubscribe_url", &unsubscribe_url);
+    let subject = match wanted.len() {
+        1 => "1 new notification".to_string(),
+        n => format!("{} new notifications", n),
+    };
+
+    let result = match mail::templates::render("notification_digest", &ctx) {
+        Ok(html) => {
+            mail::send_notification_email(
+                &state.mailer,
+                &recipient.email,
+                &subject,
+                html,
+                &unsubscribe_url,
+            )
+            .await
+        }
+        Err(err) => Err(err.to_string()),
+    };
+    if let Err(err) = result {
+        warn!(user_id, "Notification digest email failed: {}", err);
+        return Ok(false);
+    }
+
+    notification_digest_item::Entity::mark_sent(&state.sea_db, &item_ids).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mentions_are_lowercased_slugs_without_emails() {
+        assert_eq!(
+            mentioned_slugs("Thanks @Jane-Doe and @bob! cc @jane-doe, mail me at a@b.com"),
+            vec!["jane-doe".to_string(), "bob".to_string()]
+        );
+        assert!(mentioned_slugs("no mentions here").is_empty());
+    }
+
+    #[test]
+    fn mentions_are_capped_per_comment() {
+        let content = (0..MAX_MENTIONS + 5)
+            .map(|n| format!("@user{n}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let slugs = mentioned_slugs(&content);
+        assert_eq!(slugs.len(), MAX_MENTIONS);
+        assert_eq!(slugs[0], "user0");
+    }
+
+    #[test]
+    fn dedupe_keeps_first_kind_and_skips_actor() {
+        let targets = vec![
+            (2, NotificationKind::CommentReply),
+            (3, NotificationKind::CommentMention),
+            (2, NotificationKind::CommentOnPost),
+            (1, NotificationKind::CommentOnPost),
+            (4, NotificationKind::CommentOnPost),
+        ];
+        assert_eq!(
+            dedupe_targets(1, targets),
+            vec![
+                (2, NotificationKind::CommentReply),
+                (3, NotificationKind::CommentMention),
+                (4, NotificationKind::CommentOnPost),
+            ]
+        );
+    }
+
+    #[test]
+    fn excerpt_truncates_on_char_boundaries() {
+        assert_eq!(excerpt("  short  ", 10), "short");
+        assert_eq!(excerpt("héllo wörld", 5), "héllo…");
+    }
+
+    #[test]
+    fn unsubscribe_url_scopes_to_kind() {
+        let url = unsubscribe_url("abc", Some(NotificationKind::CommentReply));
+        assert!(url.ends_with("/notification/v1/unsubscribe/abc?kind=comment_reply"));
+        assert!(unsubscribe_url("abc", None).ends_with("/notification/v1/unsubscribe/abc"));
+    }
+}
@@ -0,0 +1,316 @@
+//! Branded Open Graph cards: a 1200×630 PNG with the title, a label and a
+//! byline over the content's accent colour, served for posts, categories and
+//! tags that have no custom social image.
+
+use std::sync::OnceLock;
+
+use ab_glyph::{point, Font, FontVec, PxScale, ScaleFont};
+use image::{codecs::png::PngEncoder, ExtendedColorType, ImageEncoder, Rgb, RgbImage};
+use tracing::error;
+
+use crate::utils::color::{normalize_hex, parse_hex_to_rgb, DEFAULT_BG_COLOR};
+
+pub const WIDTH: u32 = 1200;
+pub const HEIGHT: u32 = 630;
+
+const DEFAULT_FONT_PATH: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans-Bold.ttf";
+const INK: [u8; 3] = [0x0b, 0x10, 0x20];
+const WHITE: [u8; 3] = [0xff, 0xff, 0xff];
+
+const MARGIN_X: f32 = 88.0;
+const TEXT_WIDTH: f32 = WIDTH as f32 - 2.0 * MARGIN_X;
+
+/// What goes on a card.
+#[derive(Debug, Clone)]
+pub struct OgCard {
+    pub title: String,
+    /// Small caps line above the title, e.g. the category name.
+    pub label: Option<String>,
+    /// Line under the title, e.g. the author.
+    pub byline: Option<String>,
+    /// `#rrggbb` accent; the default category blue when unparsable.
+    pub color: String,
+    pub site_name: String,
+}
+
+/// The card font, read once from `OG_IMAGE_FONT` (a TTF/OTF path) or the
+/// DejaVu Sans Bold that Debian images ship. `None` if it can't be loaded.
+fn font() -> Option<&'static FontVec> {
+    static FONT: OnceLock<Option<FontVec>> = OnceLock::new();
+    FONT.get_or_init(|| {
+        let path = std::env::var("OG_IMAGE_FONT").unwrap_or_else(|_| DEFAULT_FONT_PATH.into());
+        let loaded = std::fs::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| FontVec::try_from_vec(bytes).map_err(|err| err.to_string()));
+        match loaded {
+            Ok(font) => Some(font),
+            Err(err) => {
+                error!(path, error = %err, "Failed to load Open Graph card font");
+                None
+            }
+        }
+    })
+    .as_ref()
+}
+
+fn mix(a: [u8; 3], b: [u8; 3], t: f32) -> [u8; 3] {
+    let t = t.clamp(0.0, 1.0);
+    let lerp = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t).round() as u8;
+    [lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])]
+}
+
+/// Greedy word wrap against `measure`. Lines past `max_lines` are dropped and
+/// the last kept line gets an ellipsis; a word wider than a whole line is
+/// cut to fit.
+pub fn wrap_words<F>(text: &str, max_width: f32, max_lines: usize, measure: F) -> Vec<String>
+where
+    F: Fn(&str) -> f32,
+{
+    let fit = |word: &str| -> String {
+        let mut out = String::new();
+        for c in word.chars() {
+            out.push(c);
+            if measure(&out) > max_width {
+                out.pop();
+                break;
+            }
+        }
+        out
+    };
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut truncated = false;
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if measure(&candidate) <= max_width {
+            current = candidate;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if lines.len() == max_lines {
+            truncated = true;
+            break;
+        }
+        current = fit(word);
+    }
+    if !truncated && !current.is_empty() {
+        if lines.len() == max_lines {
+            truncated = true;
+        } else {
+            lines.push(current);
+        }
+    }
+
+    if truncated {
+        if let Some(last) = lines.last_mut() {
+            while !last.is_empty() && measure(&format!("{last}…")) > max_width {
+                last.pop();
+            }
+            *last = format!("{}…", last.trim_end());
+        }
+    }
+    lines
+}
+
+fn text_width(font: &FontVec, scale: PxScale, text: &str) -> f32 {
+    let scaled = font.as_scaled(scale);
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(prev) = previous {
+            width += scaled.kern(prev, id);
+        }
+        width += scaled.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+struct TextStyle {
+    size: f32,
+    color: [u8; 3],
+    opacity: f32,
+}
+
+impl TextStyle {
+    fn scale(&self) -> PxScale {
+        PxScale::from(self.size)
+    }
+}
+
+/// Draw `text` with its baseline at `y`, alpha-blended over the canvas.
+fn draw_text(canvas: &mut RgbImage, font: &FontVec, style: &TextStyle, x: f32, y: f32, text: &str) {
+    let scale = style.scale();
+    let scaled = font.as_scaled(scale);
+    let mut caret = x;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(prev) = previous {
+            caret += scaled.kern(prev, id);
+        }
+        let glyph = id.with_scale_and_position(scale, point(caret, y));
+        caret += scaled.h_advance(id);
+        previous = Some(id);
+
+        let Some(outlined) = font.outline_glyph(glyph) else {
+            continue;
+        };
+        let bounds = outlined.px_bounds();
+        outlined.draw(|gx, gy, coverage| {
+            let px = bounds.min.x as i32 + gx as i32;
+            let py = bounds.min.y as i32 + gy as i32;
+            if px < 0 || py < 0 || px >= WIDTH as i32 || py >= HEIGHT as i32 {
+                return;
+            }
+            let pixel = canvas.get_pixel_mut(px as u32, py as u32);
+            pixel.0 = mix(pixel.0, style.color, coverage * style.opacity);
+        });
+    }
+}
+
+/// Render a card to PNG bytes. `None` when the font is unavailable or
+/// encoding fails; callers answer with an error rather than a blank card.
+pub fn render(card: &OgCard) -> Option<Vec<u8>> {
+    let font = font()?;
+    let (r, g, b) = normalize_hex(&card.color)
+        .and_then(|hex| parse_hex_to_rgb(&hex))
+        .or_else(|| parse_hex_to_rgb(DEFAULT_BG_COLOR))?;
+    let accent = [r, g, b];
+
+    // Accent-tinted diagonal gradient into near-black, with a solid accent
+    // bar down the left edge.
+    let top = mix(accent, INK, 0.35);
+    let bottom = mix(accent, INK, 0.82);
+    let mut canvas = RgbImage::from_fn(WIDTH, HEIGHT, |x, y| {
+        if x < 18 {
+            return Rgb(accent);
+        }
+        let t = 0.35 * x as f32 / WIDTH as f32 + 0.65 * y as f32 / HEIGHT as f32;
+        Rgb(mix(top, bottom, t))
+    });
+
+    let mut y = 132.0;
+    if let Some(label) = card.label.as_deref().filter(|l| !l.trim().is_empty()) {
+        let style = TextStyle {
+            size: 34.0,
+            color: WHITE,
+            opacity: 0.75,
+        };
+        let label = label.trim().to_uppercase();
+        let lines = wrap_words(&label, TEXT_WIDTH, 1, |s| {
+            text_width(font, style.scale(), s)
+        });
+        if let Some(line) = lines.first() {
+            draw_text(&mut canvas, font, &style, MARGIN_X, y, line);
+        }
+        y += 40.0;
+    }
+
+    // Shrink long titles before resorting to an ellipsis.
+    let mut title = (0.0, Vec::new());
+    for size in [76.0, 64.0, 54.0] {
+        let lines = wrap_words(&card.title, TEXT_WIDTH, 3, |s| {
+            text_width(font, PxScale::from(size), s)
+        });
+        let fits = !lines.last().is_some_and(|l| l.ends_with('…'));
+        title = (size, lines);
+        if fits {
+            break;
+        }
+    }
+    let (size, lines) = title;
+    let style = TextStyle {
+        size,
+        color: WHITE,
+        opacity: 1.0,
+    };
+    for line in &lines {
+        y += size * 1.18;
+        draw_text(&mut canvas, font, &style, MARGIN_X, y, line);
+    }
+
+    if let Some(byline) = card.byline.as_deref().filter(|b| !b.trim().is_empty()) {
+        let style = TextStyle {
+            size: 30.0,
+            color: WHITE,
+            opacity: 0.85,
+        };
+        let lines = wrap_words(byline.trim(), TEXT_WIDTH, 1, |s| {
+            text_width(font, style.scale(), s)
+        });
+        if let Some(line) = lines.first() {
+            draw_text(
+                &mut canvas,
+                font,
+                &style,
+                MARGIN_X,
+                HEIGHT as f32 - 128.0,
+                line,
+            );
+        }
+    }
+    let style = TextStyle {
+        size: 30.0,
+        color: mix(accent, WHITE, 0.55),
+        opacity: 1.0,
+    };
+    draw_text(
+        &mut canvas,
+        font,
+        &style,
+        MARGIN_X,
+        HEIGHT as f32 - 72.0,
+        &card.site_name,
+    );
+
+    let mut png = Vec::new();
+    match PngEncoder::new(&mut png).write_image(
+        canvas.as_raw(),
+        WIDTH,
+        HEIGHT,
+        ExtendedColorType::Rgb8,
+    ) {
+        Ok(()) => Some(png),
+        Err(err) => {
+            error!(error = %err, "Failed to encode Open Graph card");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One unit per character keeps the wrapping tests independent of fonts.
+    fn chars(s: &str) -> f32 {
+        s.chars().count() as f32
+    }
+
+    #[test]
+    fn wraps_on_word_boundaries() {
+        let lines = wrap_words("the quick brown fox jumps", 10.0, 4, chars);
+        assert_eq!(lines, vec!["the quick", "brown fox", "jumps"]);
+    }
+
+    #[test]
+    fn truncates_past_max_lines_with_an_ellipsis() {
+        let lines = wrap_words("the quick brown fox jumps over", 10.0, 2, chars);
+        assert_eq!(lines, vec!["the quick", "brown fox…"]);
+    }
+
+    #[test]
+    fn cuts_words_wider_than_a_line() {
+        let lines = wrap_words("abcdefghijklmno", 5.0, 3, chars);
+        assert_eq!(lines, vec!["abcde"]);
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "pdf-preview")]
+
+//! Page count and first-page thumbnail for PDF uploads, rendered with the
+//! pure-Rust `hayro` rasterizer and encoded through the `image` crate.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use hayro::hayro_interpret::InterpreterSettings;
+use hayro::hayro_syntax::Pdf;
+use hayro::vello_cpu::color::palette::css::WHITE;
+use hayro::{render, PixmapSettings, RenderCache, RenderSettings};
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, RgbaImage};
+use tracing::warn;
+
+use crate::services::image_optimizer::compute_blurhash;
+use crate::services::media_probe::Poster;
+
+/// Rendered thumbnail width in pixels.
+const POSTER_WIDTH: f32 = 640.0;
+/// Tall pages (receipts, infographics) are cropped by scale rather than
+/// rendered into an enormous bitmap.
+const POSTER_MAX_HEIGHT: f32 = POSTER_WIDTH * 4.0;
+const POSTER_QUALITY: u8 = 80;
+
+#[derive(Debug, Default)]
+pub struct PdfPreview {
+    pub page_count: Option<i32>,
+    pub poster: Option<Poster>,
+}
+
+/// Parse `bytes` and render the first page. Encrypted or malformed files give
+/// an empty preview; the upload itself is still accepted.
+pub fn preview(bytes: &[u8]) -> PdfPreview {
+    // The parser and interpreter handle arbitrary uploads; a panic deep in
+    // either must not take the blocking worker down with it.
+    match catch_unwind(AssertUnwindSafe(|| render_preview(bytes))) {
+        Ok(preview) => preview,
+        Err(_) => {
+            warn!("PDF preview panicked; storing the document without one");
+            PdfPreview::default()
+        }
+    }
+}
+
+fn render_preview(bytes: &[u8]) -> PdfPreview {
+    let pdf = match Pdf::new(bytes.to_vec()) {
+        Ok(pdf) => pdf,
+        Err(err) => {
+            warn!(error = ?err, "Could not parse PDF for preview");
+            return PdfPreview::default();
+        }
+    };
+    let pages = pdf.pages();
+    let page_count = i32::try_from(pages.len()).ok();
+
+    let poster = pages.first().and_then(|page| {
+        let (width, height) = page.render_dimensions();
+        if !(width.is_finite() && height.is_finite() && width > 0.0 && height > 0.0) {
+            return None;
+        }
+        let scale = (POSTER_WIDTH / width).min(POSTER_MAX_HEIGHT / height);
+        let pixmap = render(
+            page,
+            &RenderCache::new(),
+            &InterpreterSettings::default(),
+            &RenderSettings::default(),
+            &PixmapSettings {
+                x_scale: scale,
+                y_scale: scale,
+                bg_color: WHITE,
+            },
+        );
+        // Rendered onto an opaque background, so premultiplied RGBA is plain
+        // RGBA.
+        let rgba = RgbaImage::from_raw(
+            u32::from(pixmap.width()),
+            u32::from(pixmap.height()),
+            pixmap.data_as_u8_slice().to_vec(),
+        )?;
+        encode_poster(DynamicImage::ImageRgba8(rgba))
+    });
+
+    PdfPreview { page_count, poster }
+}
+
+fn encode_poster(image: DynamicImage) -> Option<Poster> {
+    if image.width() == 0 || image.height() == 0 {
+        return None;
+    }
+    let mut bytes = Vec::new();
+    JpegEncoder::new_with_quality(&mut bytes, POSTER_QUALITY)
+        .encode_image(&image.to_rgb8())
+        .ok()?;
+    Some(Poster {
+        bytes,
+        mime_type: "image/jpeg".to_string(),
+        extension: "jpg".to_string(),
+        width: i32::try_from(image.width()).ok(),
+        height: i32::try_from(image.height()).ok(),
+        blurhash: compute_blurhash(&image),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal letter-size PDF with `pages` pages, each a filled square.
+    fn sample_pdf(pages: usize) -> Vec<u8> {
+        let content = b"0 0 1 rg 100 100 200 200 re f";
+        let kids = (0..pages)
+            .map(|i| format!("{} 0 R", 3 + i * 2))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!("<< /Type /Pages /Kids [{kids}] /Count {pages} >>"),
+        ];
+        for i in 0..pages {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents {} 0 R >>",
+                4 + i * 2
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}\nendstream",
+                content.len(),
+                String::from_utf8_lossy(content)
+            ));
+        }
+
+        let mut out = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+        }
+        let xref = out.len();
+        out.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+        );
+        for offset in offsets {
+            out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .as_bytes(),
+        );
+        out
+    }
+
+    #[test]
+    fn renders_first_page_and_counts_pages() {
+        let preview = preview(&sample_pdf(3));
+
+        assert_eq!(preview.page_count, Some(3));
+        let poster = preview.poster.expect("first page rendered");
+        assert_eq!(poster.mime_type, "image/jpeg");
+        assert_eq!(poster.width, Some(640));
+        assert_eq!(poster.height, Some(828));
+        assert!(poster.blurhash.is_some());
+        assert_eq!(
+            Poster::from_image_bytes(poster.bytes).map(|p| (p.width, p.height)),
+            Some((Some(640), Some(828)))
+        );
+    }
+
+    #[test]
+    fn garbage_yields_empty_preview() {
+        let preview = preview(b"%PDF-1.7\nnot really a pdf");
+        assert!(preview.poster.is_none());
+    }
+}
@@ -0,0 +1,30 @@
+//! Automatic redirect bookkeeping for content mutations. Each call runs
+//! after the content change has committed, so failures are logged rather
+//! than surfaced to the caller.
+
+use sea_orm::DatabaseConnection;
+use tracing::error;
+
+use crate::db::sea_models::redirect::Entity as Redirect;
+
+/// Content moved from `old_path` to `new_path` (a slug change). No-op when
+/// the path is unchanged.
+pub async fn record_move(db: &DatabaseConnection, old_path: &str, new_path: &str) {
+    if let Err(err) = Redirect::record_move(db, old_path, new_path).await {
+        error!(error = ?err, old_path, new_path, "Failed to record redirect");
+    }
+}
+
+/// The content at `path` was deleted; answer 410 Gone from now on.
+pub async fn record_gone(db: &DatabaseConnection, path: &str) {
+    if let Err(err) = Redirect::record_gone(db, path).await {
+        error!(error = ?err, path, "Failed to record gone path");
+    }
+}
+
+/// New content now lives at `path`; drop any rule that would shadow it.
+pub async fn release(db: &DatabaseConnection, path: &str) {
+    if let Err(err) = Redirect::release(db, path).await {
+        error!(error = ?err, path, "Failed to release redirect path");
+    }
+}
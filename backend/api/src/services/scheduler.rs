@@ -4,6 +4,7 @@ use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
 use tracing::{error, info, instrument, warn};
 
 use crate::db::sea_models::post::{ActiveModel, Column, Entity, PostStatus};
+use crate::services::notifications::{self, SiteEvent};
 use crate::state::AppState;
 
 /// Interval between scheduler ticks in seconds.
@@ -89,9 +90,11 @@ async fn publish_due_posts(state: &AppState) -> Result<(), sea_orm::DbErr> {
             );
         } else {
             info!(post_id, "Scheduled post published");
+            notifications::dispatch(state, SiteEvent::PostPublished { post_id });
         }
     }
 
+    crate::services::sitemap::invalidate(&state.redis_pool).await;
     info!(count, "Scheduled post publisher tick completed");
     Ok(())
 }
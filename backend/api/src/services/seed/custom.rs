@@ -359,6 +359,9 @@ where
             view_count: 0,
             likes_count: 0,
             tag_ids,
+            locale: crate::utils::locale::default_locale(),
+            translation_of: None,
+            co_author_ids: Vec::new(),
         };
 
         let _ = post::Entity::create(db, public_url, new_post).await;
@@ -422,8 +425,12 @@ where
         let new_comment = post_comment::NewComment {
             post_id: post.id,
             user_id: user.id,
+            parent_id: None,
             content,
             likes_count: Some(0),
+            status: post_comment::CommentStatus::Approved,
+            spam_score: 0,
+            spam_reasons: Vec::new(),
         };
         let _ = post_comment::Entity::create(db, new_comment).await;
 
@@ -1024,6 +1031,15 @@ where
             content_hash: None,
             is_optimized: false,
             optimized_at: None,
+            alt_text: None,
+            caption: None,
+            credit: None,
+            license: None,
+            focal_x: None,
+            focal_y: None,
+            blurhash: None,
+            duration_ms: None,
+            page_count: None,
             created_at: now,
             updated_at: now,
         };
@@ -1042,6 +1058,15 @@ where
             content_hash: Set(media_record.content_hash),
             is_optimized: Set(media_record.is_optimized),
             optimized_at: Set(media_record.optimized_at),
+            alt_text: Set(media_record.alt_text),
+            caption: Set(media_record.caption),
+            credit: Set(media_record.credit),
+            license: Set(media_record.license),
+            focal_x: Set(media_record.focal_x),
+            focal_y: Set(media_record.focal_y),
+            blurhash: Set(media_record.blurhash),
+            duration_ms: Set(media_record.duration_ms),
+            page_count: Set(media_record.page_count),
             created_at: Set(media_record.created_at),
             updated_at: Set(media_record.updated_at),
         };
@@ -0,0 +1,77 @@
+//! Per-item SEO overrides as the post, category and tag controllers use
+//! them: saved alongside the item's own payload and served with its reads.
+
+use sea_orm::DatabaseConnection;
+use serde_json::Value;
+
+use crate::{
+    db::sea_models::{
+        media,
+        seo_override::{self, SeoEntityType, SeoFields},
+    },
+    error::{ErrorCode, ErrorResponse},
+    services::sitemap,
+    AppState,
+};
+
+/// Reject overrides naming a custom social image that doesn't exist, before
+/// the item itself is written.
+pub async fn check(
+    db: &DatabaseConnection,
+    fields: Option<&SeoFields>,
+) -> Result<(), ErrorResponse> {
+    let Some(og_image_id) = fields.and_then(|f| f.og_image_id) else {
+        return Ok(());
+    };
+    match media::Entity::find_by_id(db, og_image_id).await? {
+        Some(_) => Ok(()),
+        None => Err(ErrorResponse::new(ErrorCode::InvalidInput)
+            .with_message("seo.og_image_id does not refer to an existing media item")),
+    }
+}
+
+/// Replace an item's overrides when the payload carried them; `None` leaves
+/// the stored set untouched. Flipping `noindex` changes which items the
+/// sitemaps list, so the cached ones are dropped.
+pub async fn save(
+    state: &AppState,
+    entity_type: SeoEntityType,
+    entity_id: i32,
+    fields: Option<SeoFields>,
+) -> Result<(), ErrorResponse> {
+    let Some(fields) = fields else {
+        return Ok(());
+    };
+    let was_noindex = seo_override::Entity::find_for(&state.sea_db, entity_type, entity_id)
+        .await?
+        .is_some_and(|row| row.noindex);
+    let noindex = fields.noindex;
+    seo_override::Entity::set(&state.sea_db, entity_type, entity_id, fields).await?;
+    if noindex != was_noindex {
+        sitemap::invalidate(&state.redis_pool).await;
+    }
+    Ok(())
+}
+
+/// Add the item's overrides to a serialized read as `seo` (null when none).
+pub async fn attach(
+    state: &AppState,
+    entity_type: SeoEntityType,
+    entity_id: i32,
+    mut body: Value,
+) -> Result<Value, ErrorResponse> {
+    let meta = seo_override::Entity::find_meta(
+        &state.sea_db,
+        &state.object_storage.public_url,
+        entity_type,
+        entity_id,
+    )
+    .await?;
+    if let Value::Object(map) = &mut body {
+        map.insert(
+            "seo".to_string(),
+            serde_json::to_value(meta).unwrap_or(Value::Null),
+        );
+    }
+    Ok(body)
+}
@@ -0,0 +1,527 @@
+//! XML sitemaps for the consumer site. `/sitemap.xml` is an index pointing
+//! at child sitemaps for the home page, posts (paged), categories, tags,
+//! series and authors. Only content anonymous readers can open is listed:
+//! published, non-paywalled posts, and the groupings that contain them.
+//!
+//! Posts with published translations carry `xhtml:link` hreflang
+//! alternates for every public version, with the default locale's version
+//! as `x-default`.
+//!
+//! Rendered documents are cached in Redis under a generation number that
+//! [`invalidate`] bumps on every content change, so stale entries are never
+//! read again and simply expire.
+
+use std::collections::HashMap;
+
+use sea_orm::prelude::Uuid;
+use sea_orm::{DatabaseBackend, DatabaseConnection, DbErr, FromQueryResult, Statement, Value};
+use tower_sessions_redis_store::fred::interfaces::KeysInterface;
+use tower_sessions_redis_store::fred::prelude::Pool as RedisPool;
+use tracing::warn;
+
+use crate::db::sea_models::media;
+use crate::utils::locale::{default_locale, localized_path};
+use crate::utils::sanitize::xml_escape;
+use crate::AppState;
+
+/// Most URLs the sitemap protocol allows in one file.
+pub const POSTS_PER_SITEMAP: i64 = 50_000;
+
+const CACHE_TTL_SECS: i64 = 3600;
+const GENERATION_KEY: &str = "sitemap:generation";
+
+/// Posts anonymous readers can open: published (so never draft or archived)
+/// and without a paid or subscriber-only `post_access` rule. Posts marked
+/// noindex in their SEO overrides are left out too.
+const PUBLIC_POST: &str = "p.status = 'published' AND NOT EXISTS (\
+     SELECT 1 FROM post_access pa WHERE pa.post_id = p.id AND pa.access_type::text <> 'free') \
+     AND NOT EXISTS (SELECT 1 FROM seo_overrides so \
+     WHERE so.entity_type = 'post' AND so.entity_id = p.id AND so.noindex)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sitemap {
+    Index,
+    Pages,
+    /// One-based page of posts, [`POSTS_PER_SITEMAP`] per page.
+    Posts(u32),
+    Categories,
+    Tags,
+    Series,
+    Authors,
+}
+
+impl Sitemap {
+    /// Child sitemap from its file name under `/sitemaps/`.
+    pub fn from_file_name(file: &str) -> Option<Self> {
+        let stem = file.strip_suffix(".xml")?;
+        match stem {
+            "pages" => Some(Self::Pages),
+            "categories" => Some(Self::Categories),
+            "tags" => Some(Self::Tags),
+            "series" => Some(Self::Series),
+            "authors" => Some(Self::Authors),
+            _ => stem
+                .strip_prefix("posts-")?
+                .parse::<u32>()
+                .ok()
+                .filter(|page| *page >= 1)
+                .map(Self::Posts),
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        match self {
+            Self::Index => "sitemap.xml".to_string(),
+            Self::Pages => "pages.xml".to_string(),
+            Self::Posts(page) => format!("posts-{page}.xml"),
+            Self::Categories => "categories.xml".to_string(),
+            Self::Tags => "tags.xml".to_string(),
+            Self::Series => "series.xml".to_string(),
+            Self::Authors => "authors.xml".to_string(),
+        }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            Self::Index => "/sitemap.xml".to_string(),
+            child => format!("/sitemaps/{}", child.file_name()),
+        }
+    }
+}
+
+/// Public origin of the consumer site, without a trailing slash.
+pub fn base_url() -> String {
+    std::env::var("CONSUMER_SITE_URL")
+        .unwrap_or_else(|_| "https://ruxlog.com".to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// One `<url>` entry. `loc`, `images` and alternate URLs are absolute and
+/// unescaped; `alternates` pairs an hreflang value with its URL.
+struct UrlEntry {
+    loc: String,
+    lastmod: Option<String>,
+    images: Vec<String>,
+    alternates: Vec<(String, String)>,
+}
+
+fn render_urlset(entries: &[UrlEntry]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" xmlns:image=\"http://www.google.com/schemas/sitemap-image/1.1\" xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n",
+    );
+    for entry in entries {
+        xml.push_str(&format!("  <url><loc>{}</loc>", xml_escape(&entry.loc)));
+        if let Some(lastmod) = &entry.lastmod {
+            xml.push_str(&format!("<lastmod>{lastmod}</lastmod>"));
+        }
+        for image in &entry.images {
+            xml.push_str(&format!(
+                "<image:image><image:loc>{}</image:loc></image:image>",
+                xml_escape(image)
+            ));
+        }
+        for (hreflang, href) in &entry.alternates {
+            xml.push_str(&format!(
+                "<xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+                xml_escape(hreflang),
+                xml_escape(href)
+            ));
+        }
+        xml.push_str("</url>\n");
+    }
+    xml.push_str("</urlset>");
+    xml
+}
+
+fn render_index(base_url: &str, children: &[Sitemap]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for child in children {
+        xml.push_str(&format!(
+            "  <sitemap><loc>{}</loc></sitemap>\n",
+            xml_escape(&format!("{base_url}{}", child.path()))
+        ));
+    }
+    xml.push_str("</sitemapindex>");
+    xml
+}
+
+#[derive(FromQueryResult)]
+struct CountRow {
+    total: i64,
+}
+
+#[derive(FromQueryResult)]
+struct PostRow {
+    slug: String,
+    locale: String,
+    translation_group_id: Option<Uuid>,
+    updated_at: sea_orm::prelude::DateTimeWithTimeZone,
+    object_key: Option<String>,
+    bucket: Option<String>,
+}
+
+#[derive(FromQueryResult)]
+struct TranslationRow {
+    translation_group_id: Uuid,
+    locale: String,
+    slug: String,
+}
+
+/// A category, tag, series or author page, dated by its newest public post.
+#[derive(FromQueryResult)]
+struct GroupRow {
+    key: String,
+    lastmod: sea_orm::prelude::DateTimeWithTimeZone,
+}
+
+fn statement(sql: String, values: Vec<Value>) -> Statement {
+    Statement::from_sql_and_values(DatabaseBackend::Postgres, sql, values)
+}
+
+async fn post_pages(db: &DatabaseConnection) -> Result<u32, DbErr> {
+    let row = CountRow::find_by_statement(statement(
+        format!("SELECT COUNT(*) AS total FROM posts p WHERE {PUBLIC_POST}"),
+        vec![],
+    ))
+    .one(db)
+    .await?;
+    let total = row.map(|r| r.total).unwrap_or(0).max(0) as u64;
+    Ok(total.div_ceil(POSTS_PER_SITEMAP as u64).max(1) as u32)
+}
+
+async fn post_entries(state: &AppState, page: u32) -> Result<Vec<UrlEntry>, DbErr> {
+    let base = base_url();
+    let rows = PostRow::find_by_statement(statement(
+        format!(
+            "SELECT p.slug, p.locale, p.translation_group_id, p.updated_at, m.object_key, m.bucket \
+             FROM posts p LEFT JOIN media m ON m.id = p.featured_image_id \
+             WHERE {PUBLIC_POST} ORDER BY p.id LIMIT $1 OFFSET $2"
+        ),
+        vec![
+            Value::BigInt(Some(POSTS_PER_SITEMAP)),
+            Value::BigInt(Some((i64::from(page) - 1) * POSTS_PER_SITEMAP)),
+        ],
+    ))
+    .all(&state.sea_db)
+    .await?;
+    let groups = translation_groups(&state.sea_db, &rows).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| UrlEntry {
+            loc: post_url(&base, &row.locale, &row.slug),
+            alternates: row
+                .translation_group_id
+                .and_then(|group| groups.get(&group))
+                .map(|versions| hreflang_alternates(&base, versions))
+                .unwrap_or_default(),
+            lastmod: Some(row.updated_at.to_rfc3339()),
+            images: row
+                .object_key
+                .map(|key| {
+                    media::url::build_public_file_url(
+                        &state.object_storage.public_url,
+                        row.bucket.as_deref(),
+                        &key,
+                    )
+                })
+                .into_iter()
+                .collect(),
+        })
+        .collect())
+}
+
+/// Canonical consumer URL of a post.
+pub fn post_url(base: &str, locale: &str, slug: &str) -> String {
+    format!(
+        "{base}{}",
+        localized_path(locale, &format!("/posts/{slug}"))
+    )
+}
+
+/// Public versions of every translation group on the page, keyed by group.
+async fn translation_groups(
+    db: &DatabaseConnection,
+    rows: &[PostRow],
+) -> Result<HashMap<Uuid, Vec<TranslationRow>>, DbErr> {
+    let mut ids: Vec<Uuid> = rows
+        .iter()
+        .filter_map(|row| row.translation_group_id)
+        .collect();
+    ids.sort();
+    ids.dedup();
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let placeholders: Vec<String> = (1..=ids.len()).map(|n| format!("${n}")).collect();
+    let versions = TranslationRow::find_by_statement(statement(
+        format!(
+            "SELECT p.translation_group_id, p.locale, p.slug FROM posts p \
+             WHERE {PUBLIC_POST} AND p.translation_group_id IN ({}) ORDER BY p.locale",
+            placeholders.join(",")
+        ),
+        ids.into_iter().map(Value::from).collect(),
+    ))
+    .all(db)
+    .await?;
+
+    let mut groups: HashMap<Uuid, Vec<TranslationRow>> = HashMap::new();
+    for version in versions {
+        groups
+            .entry(version.translation_group_id)
+            .or_default()
+            .push(version);
+    }
+    Ok(groups)
+}
+
+/// hreflang links for a group with more than one public version, plus
+/// `x-default` pointing at the default locale's version when there is one.
+fn hreflang_alternates(base: &str, versions: &[TranslationRow]) -> Vec<(String, String)> {
+    if versions.len() < 2 {
+        return Vec::new();
+    }
+    let mut alternates: Vec<(String, String)> = versions
+        .iter()
+        .map(|v| (v.locale.clone(), post_url(base, &v.locale, &v.slug)))
+        .collect();
+    let default = default_locale();
+    if let Some(v) = versions.iter().find(|v| v.locale == default) {
+        alternates.push(("x-default".to_string(), post_url(base, &v.locale, &v.slug)));
+    }
+    alternates
+}
+
+async fn group_entries(
+    db: &DatabaseConnection,
+    sql: &str,
+    path: &str,
+) -> Result<Vec<UrlEntry>, DbErr> {
+    let base = base_url();
+    let rows = GroupRow::find_by_statement(statement(sql.to_string(), vec![]))
+        .all(db)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| UrlEntry {
+            loc: format!("{base}/{path}/{}", row.key),
+            lastmod: Some(row.lastmod.to_rfc3339()),
+            images: Vec::new(),
+            alternates: Vec::new(),
+        })
+        .collect())
+}
+
+fn group_sql(select: &str, joins: &str, filter: &str) -> String {
+    format!(
+        "SELECT {select} AS key, MAX(p.updated_at) AS lastmod FROM {joins} \
+         WHERE {PUBLIC_POST}{filter} GROUP BY 1 ORDER BY 1"
+    )
+}
+
+/// Render a sitemap straight from the database. `None` for a posts page past
+/// the last one.
+async fn build(state: &AppState, sitemap: Sitemap) -> Result<Option<String>, DbErr> {
+    let db = &state.sea_db;
+    let entries = match sitemap {
+        Sitemap::Index => {
+            let mut children = vec![Sitemap::Pages];
+            children.extend((1..=post_pages(db).await?).map(Sitemap::Posts));
+            children.extend([
+                Sitemap::Categories,
+                Sitemap::Tags,
+                Sitemap::Series,
+                Sitemap::Authors,
+            ]);
+            return Ok(Some(render_index(&base_url(), &children)));
+        }
+        Sitemap::Pages => vec![UrlEntry {
+            loc: format!("{}/", base_url()),
+            lastmod: None,
+            images: Vec::new(),
+            alternates: Vec::new(),
+        }],
+        Sitemap::Posts(page) => {
+            if page > 1 && page > post_pages(db).await? {
+                return Ok(None);
+            }
+            post_entries(state, page).await?
+        }
+        Sitemap::Categories => {
+            let sql = group_sql(
+                "c.slug",
+                "categories c JOIN posts p ON p.category_id = c.id",
+                " AND c.is_active AND NOT EXISTS (SELECT 1 FROM seo_overrides so \
+                 WHERE so.entity_type = 'category' AND so.entity_id = c.id AND so.noindex)",
+            );
+            group_entries(db, &sql, "categories").await?
+        }
+        Sitemap::Tags => {
+            let sql = group_sql(
+                "t.slug",
+                "tags t JOIN posts p ON t.id = ANY(p.tag_ids)",
+                " AND t.is_active AND NOT EXISTS (SELECT 1 FROM seo_overrides so \
+                 WHERE so.entity_type = 'tag' AND so.entity_id = t.id AND so.noindex)",
+            );
+            group_entries(db, &sql, "tags").await?
+        }
+        Sitemap::Series => {
+            let sql = group_sql(
+                "s.slug",
+                "post_series s JOIN post_series_posts sp ON sp.series_id = s.id \
+                 JOIN posts p ON p.id = sp.post_id",
+                "",
+            );
+            group_entries(db, &sql, "series").await?
+        }
+        Sitemap::Authors => {
+            let sql = group_sql(
+                "COALESCE(u.profile_slug, u.id::text)",
+                "users u JOIN posts p ON p.author_id = u.id \
+                 OR p.id IN (SELECT pa.post_id FROM post_authors pa WHERE pa.user_id = u.id)",
+                "",
+            );
+            group_entries(db, &sql, "authors").await?
+        }
+    };
+    Ok(Some(render_urlset(&entries)))
+}
+
+async fn generation(redis: &RedisPool) -> Option<i64> {
+    match redis.get::<Option<i64>, _>(GENERATION_KEY).await {
+        Ok(generation) => Some(generation.unwrap_or(0)),
+        Err(err) => {
+            warn!(error = %err, "Sitemap cache unavailable; rendering uncached");
+            None
+        }
+    }
+}
+
+/// The sitemap document, from the Redis cache when present. Redis errors
+/// fall back to rendering from the database.
+pub async fn render(state: &AppState, sitemap: Sitemap) -> Result<Option<String>, DbErr> {
+    let Some(generation) = generation(&state.redis_pool).await else {
+        return build(state, sitemap).await;
+    };
+    let key = format!("sitemap:{generation}:{}", sitemap.file_name());
+
+    if let Ok(Some(cached)) = state.redis_pool.get::<Option<String>, _>(&key).await {
+        return Ok(Some(cached));
+    }
+
+    let document = build(state, sitemap).await?;
+    if let Some(xml) = &document {
+        if let Err(err) = state
+            .redis_pool
+            .set::<(), _, _>(
+                &key,
+                xml.as_str(),
+                Some(tower_sessions_redis_store::fred::types::Expiration::EX(
+                    CACHE_TTL_SECS,
+                )),
+                None,
+                false,
+            )
+            .await
+        {
+            warn!(error = %err, %key, "Failed to cache sitemap");
+        }
+    }
+    Ok(document)
+}
+
+/// Drop every cached sitemap. Call after anything that changes which posts,
+/// categories, tags, series or authors are public. Best-effort: a Redis
+/// error only delays the update until the cache TTL runs out.
+pub async fn invalidate(redis: &RedisPool) {
+    if let Err(err) = redis.incr::<i64, _>(GENERATION_KEY).await {
+        warn!(error = %err, "Failed to invalidate sitemap cache");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_file_names_round_trip() {
+        for sitemap in [
+            Sitemap::Pages,
+            Sitemap::Posts(1),
+            Sitemap::Posts(12),
+            Sitemap::Categories,
+            Sitemap::Tags,
+            Sitemap::Series,
+            Sitemap::Authors,
+        ] {
+            assert_eq!(Sitemap::from_file_name(&sitemap.file_name()), Some(sitemap));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_zero_pages() {
+        assert_eq!(Sitemap::from_file_name("posts-0.xml"), None);
+        assert_eq!(Sitemap::from_file_name("posts-x.xml"), None);
+        assert_eq!(Sitemap::from_file_name("posts.xml"), None);
+        assert_eq!(Sitemap::from_file_name("sitemap.xml"), None);
+        assert_eq!(Sitemap::from_file_name("tags"), None);
+    }
+
+    #[test]
+    fn index_lists_children_under_the_base_url() {
+        let xml = render_index(
+            "https://example.com",
+            &[Sitemap::Posts(1), Sitemap::Posts(2), Sitemap::Tags],
+        );
+        assert!(xml.contains("<sitemapindex"));
+        assert!(xml.contains("<loc>https://example.com/sitemaps/posts-2.xml</loc>"));
+        assert!(xml.contains("<loc>https://example.com/sitemaps/tags.xml</loc>"));
+    }
+
+    #[test]
+    fn urlset_escapes_locations_and_lists_images() {
+        let xml = render_urlset(&[UrlEntry {
+            loc: "https://example.com/posts/a&b".to_string(),
+            lastmod: Some("2026-01-02T03:04:05+00:00".to_string()),
+            images: vec!["https://cdn.example.com/x.png?w=1&h=2".to_string()],
+            alternates: Vec::new(),
+        }]);
+        assert!(xml.contains("xmlns:image=\"http://www.google.com/schemas/sitemap-image/1.1\""));
+        assert!(xml.contains("<loc>https://example.com/posts/a&amp;b</loc>"));
+        assert!(xml.contains("<lastmod>2026-01-02T03:04:05+00:00</lastmod>"));
+        assert!(xml.contains(
+            "<image:image><image:loc>https://cdn.example.com/x.png?w=1&amp;h=2</image:loc></image:image>"
+        ));
+    }
+
+    #[test]
+    fn urlset_lists_hreflang_alternates_for_translated_posts() {
+        let versions = ["zu", &default_locale()]
+            .iter()
+            .map(|locale| TranslationRow {
+                translation_group_id: Uuid::nil(),
+                locale: locale.to_string(),
+                slug: format!("hello-{locale}"),
+            })
+            .collect::<Vec<_>>();
+        let alternates = hreflang_alternates("https://example.com", &versions);
+        assert_eq!(alternates.len(), 3);
+        assert_eq!(alternates[2].0, "x-default");
+        assert!(hreflang_alternates("https://example.com", &versions[..1]).is_empty());
+
+        let xml = render_urlset(&[UrlEntry {
+            loc: "https://example.com/zu/posts/hello-zu".to_string(),
+            lastmod: None,
+            images: Vec::new(),
+            alternates,
+        }]);
+        assert!(xml.contains("xmlns:xhtml=\"http://www.w3.org/1999/xhtml\""));
+        assert!(xml.contains(
+            "<xhtml:link rel=\"alternate\" hreflang=\"zu\" href=\"https://example.com/zu/posts/hello-zu\"/>"
+        ));
+    }
+}
@@ -0,0 +1,749 @@
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+use super::{
+    content_type_for_key, validate_key, ObjectStore, ObjectStream, StorageBackend, StorageError,
+    StorageResult, StoredBlob, StoredObject, UploadedPart,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// `exp` / `sig` query parameters produced by [`LocalStore::signed_url`].
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct SignedQuery {
+    pub exp: Option<i64>,
+    pub sig: Option<String>,
+}
+
+/// Filesystem driver. Objects live at `{root}/{bucket}/{key}` and are served
+/// by the `/media/{bucket}/{key}` route.
+///
+/// Multipart uploads are emulated: each part is written by the
+/// `/media/_parts/{upload_id}/{part}` route into `{root}/.multipart/{upload_id}/`
+/// and concatenated into the final object on completion. Part URLs are always
+/// signed.
+///
+/// Objects are served like a public bucket, so the URLs built from
+/// `public_url` in API responses just work. A request that does carry
+/// `exp`/`sig` (from `signed_url`) is only served if they verify.
+pub struct LocalStore {
+    root: PathBuf,
+    bucket: String,
+    public_url: String,
+    signing_key: Vec<u8>,
+}
+
+impl std::fmt::Debug for LocalStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalStore")
+            .field("root", &self.root)
+            .field("bucket", &self.bucket)
+            .field("public_url", &self.public_url)
+            .field("signing_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl LocalStore {
+    pub fn new(
+        root: impl Into<PathBuf>,
+        bucket: impl Into<String>,
+        public_url: impl Into<String>,
+        signing_key: Vec<u8>,
+    ) -> Self {
+        Self {
+            root: root.into(),
+            bucket: bucket.into(),
+            public_url: public_url.into(),
+            signing_key,
+        }
+    }
+
+    fn path_for(&self, key: &str) -> StorageResult<PathBuf> {
+        validate_key(key)?;
+        Ok(self.root.join(&self.bucket).join(key))
+    }
+
+    fn mac_for(&self, key: &str, exp: i64) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.signing_key).expect("HMAC accepts any key length");
+        mac.update(self.bucket.as_bytes());
+        mac.update(b"/");
+        mac.update(key.as_bytes());
+        mac.update(b"\n");
+        mac.update(exp.to_string().as_bytes());
+        mac
+    }
+
+    fn parts_dir(&self, upload_id: &str) -> StorageResult<PathBuf> {
+        // Upload ids are uuids we issued; anything else could be a path.
+        uuid::Uuid::try_parse(upload_id)
+            .map_err(|_| StorageError::InvalidKey(upload_id.to_string()))?;
+        Ok(self.root.join(".multipart").join(upload_id))
+    }
+
+    fn part_mac_for(&self, upload_id: &str, part_number: i32, exp: i64) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.signing_key).expect("HMAC accepts any key length");
+        mac.update(b"part\n");
+        mac.update(upload_id.as_bytes());
+        mac.update(b"\n");
+        mac.update(part_number.to_string().as_bytes());
+        mac.update(b"\n");
+        mac.update(exp.to_string().as_bytes());
+        mac
+    }
+
+    /// Whether a part upload request carries a valid, unexpired signature
+    /// from `presign_part`.
+    pub fn authorize_part(
+        &self,
+        upload_id: &str,
+        part_number: i32,
+        query: &SignedQuery,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let (Some(exp), Some(sig)) = (query.exp, query.sig.as_deref()) else {
+            return false;
+        };
+        if exp < now.timestamp() {
+            return false;
+        }
+        let Ok(provided) = hex::decode(sig) else {
+            return false;
+        };
+        self.part_mac_for(upload_id, part_number, exp)
+            .verify_slice(&provided)
+            .is_ok()
+    }
+
+    /// Store one part of an emulated multipart upload and return its ETag.
+    /// Fails with `NotFound` when the upload was never created or was aborted.
+    pub async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: i32,
+        body: Bytes,
+    ) -> StorageResult<String> {
+        let dir = self.parts_dir(upload_id)?;
+        if !(1..=MAX_PART_NUMBER).contains(&part_number) {
+            return Err(StorageError::InvalidKey(format!("part {part_number}")));
+        }
+        if !tokio::fs::try_exists(&dir).await? {
+            return Err(StorageError::NotFound(upload_id.to_string()));
+        }
+
+        let path = dir.join(part_number.to_string());
+        let tmp = dir.join(format!(".tmp-{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, &body).await?;
+        if let Err(err) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(err.into());
+        }
+        Ok(etag_for(&tokio::fs::metadata(&path).await?))
+    }
+
+    /// Whether a `/media` request for `bucket`/`key` may be served.
+    pub fn authorize(
+        &self,
+        bucket: &str,
+        key: &str,
+        query: &SignedQuery,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if bucket != self.bucket || validate_key(key).is_err() {
+            return false;
+        }
+
+        match (query.exp, query.sig.as_deref()) {
+            (Some(exp), Some(sig)) => {
+                if exp < now.timestamp() {
+                    return false;
+                }
+                let Ok(provided) = hex::decode(sig) else {
+                    return false;
+                };
+                self.mac_for(key, exp).verify_slice(&provided).is_ok()
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// S3 caps multipart uploads at 10,000 parts; mirror that.
+const MAX_PART_NUMBER: i32 = 10_000;
+
+/// Chunk size of [`ObjectStore::get_stream`] reads.
+const STREAM_CHUNK: usize = 256 * 1024;
+
+/// Part ETag from file metadata (size and mtime), so listing parts never
+/// reads their contents.
+fn etag_for(meta: &std::fs::Metadata) -> String {
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|at| at.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_nanos())
+        .unwrap_or_default();
+    format!("\"{:x}-{:x}\"", meta.len(), modified)
+}
+
+fn collect_files(
+    dir: &Path,
+    out: &mut Vec<(PathBuf, Option<DateTime<Utc>>)>,
+) -> std::io::Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), out)?;
+        } else if file_type.is_file() {
+            let modified = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .map(DateTime::<Utc>::from);
+            out.push((entry.path(), modified));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ObjectStore for LocalStore {
+    fn backend(&self) -> StorageBackend {
+        StorageBackend::Local
+    }
+
+    fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    async fn put(&self, key: &str, body: Bytes, _content_type: &str) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // Write to a sibling temp file and rename so a reader never sees a
+        // half-written object.
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, &body).await?;
+        if let Err(err) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> StorageResult<Option<StoredBlob>> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(StoredBlob {
+                bytes: Bytes::from(bytes),
+                content_type: content_type_for_key(key).to_string(),
+            })),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn get_stream(&self, key: &str) -> StorageResult<Option<ObjectStream>> {
+        let path = self.path_for(key)?;
+        let file = match tokio::fs::File::open(&path).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let stream = futures_util::stream::try_unfold(file, |mut file| async move {
+            let mut buf = vec![0; STREAM_CHUNK];
+            let read = file.read(&mut buf).await.map_err(StorageError::from)?;
+            if read == 0 {
+                return Ok(None);
+            }
+            buf.truncate(read);
+            Ok(Some((Bytes::from(buf), file)))
+        });
+        Ok(Some(Box::pin(stream)))
+    }
+
+    async fn get_range(&self, key: &str, range: Range<u64>) -> StorageResult<Option<Bytes>> {
+        let path = self.path_for(key)?;
+        let mut file = match tokio::fs::File::open(&path).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        file.seek(std::io::SeekFrom::Start(range.start)).await?;
+        let mut buf = Vec::new();
+        file.take(range.end.saturating_sub(range.start))
+            .read_to_end(&mut buf)
+            .await?;
+        Ok(Some(Bytes::from(buf)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _content_type: &str) -> StorageResult<()> {
+        let source = self.path_for(from)?;
+        let path = self.path_for(to)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        if let Err(err) = tokio::fs::copy(&source, &tmp).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(if err.kind() == std::io::ErrorKind::NotFound {
+                StorageError::NotFound(from.to_string())
+            } else {
+                err.into()
+            });
+        }
+        if let Err(err) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        let base = self.root.join(&self.bucket);
+        let prefix = prefix.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut files = Vec::new();
+            collect_files(&base, &mut files)?;
+
+            let mut objects: Vec<StoredObject> = files
+                .into_iter()
+                .filter_map(|(path, last_modified)| {
+                    let relative = path.strip_prefix(&base).ok()?;
+                    let key = relative
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+                    key.starts_with(&prefix)
+                        .then_some(StoredObject { key, last_modified })
+                })
+                .collect();
+            objects.sort_by(|a, b| a.key.cmp(&b.key));
+            Ok(objects)
+        })
+        .await
+        .map_err(|err| StorageError::Backend(err.to_string()))?
+    }
+
+    async fn signed_url(&self, key: &str, ttl: Duration) -> StorageResult<String> {
+        validate_key(key)?;
+        let exp = Utc::now().timestamp() + ttl.as_secs() as i64;
+        Ok(format!(
+            "{}/{}/{}?exp={}&sig={}",
+            self.public_url.trim_end_matches('/'),
+            self.bucket,
+            key,
+            exp,
+            hex::encode(self.mac_for(key, exp).finalize().into_bytes())
+        ))
+    }
+
+    async fn create_multipart(&self, key: &str, _content_type: &str) -> StorageResult<String> {
+        validate_key(key)?;
+        let upload_id = uuid::Uuid::new_v4().to_string();
+        tokio::fs::create_dir_all(self.parts_dir(&upload_id)?).await?;
+        Ok(upload_id)
+    }
+
+    async fn presign_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl: Duration,
+    ) -> StorageResult<String> {
+        validate_key(key)?;
+        self.parts_dir(upload_id)?;
+        let exp = Utc::now().timestamp() + ttl.as_secs() as i64;
+        Ok(format!(
+            "{}/_parts/{}/{}?exp={}&sig={}",
+            self.public_url.trim_end_matches('/'),
+            upload_id,
+            part_number,
+            exp,
+            hex::encode(
+                self.part_mac_for(upload_id, part_number, exp)
+                    .finalize()
+                    .into_bytes()
+            )
+        ))
+    }
+
+    async fn list_parts(&self, _key: &str, upload_id: &str) -> StorageResult<Vec<UploadedPart>> {
+        let dir = self.parts_dir(upload_id)?;
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::NotFound(upload_id.to_string()))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut parts = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let Some(part_number) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<i32>().ok())
+            else {
+                continue;
+            };
+            let meta = entry.metadata().await?;
+            parts.push(UploadedPart {
+                part_number,
+                etag: etag_for(&meta),
+                size: meta.len() as i64,
+            });
+        }
+
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+
+    async fn complete_multipart(
+        &self,
+        key: &str,
+        upload_id: &str,
+        parts: &[UploadedPart],
+    ) -> StorageResult<()> {
+        let dir = self.parts_dir(upload_id)?;
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // Append the parts to a sibling temp file, then rename it into place,
+        // so nothing larger than a copy buffer is held in memory.
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        let assembled: StorageResult<()> = async {
+            let mut out = tokio::fs::File::create(&tmp).await?;
+            for part in parts {
+                let mut input =
+                    match tokio::fs::File::open(dir.join(part.part_number.to_string())).await {
+                        Ok(input) => input,
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                            return Err(StorageError::NotFound(format!(
+                                "{upload_id} part {}",
+                                part.part_number
+                            )))
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+                tokio::io::copy(&mut input, &mut out).await?;
+            }
+            out.flush().await?;
+            tokio::fs::rename(&tmp, &path).await?;
+            Ok(())
+        }
+        .await;
+        if let Err(err) = assembled {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(err);
+        }
+
+        self.abort_multipart(key, upload_id).await
+    }
+
+    async fn abort_multipart(&self, _key: &str, upload_id: &str) -> StorageResult<()> {
+        match tokio::fs::remove_dir_all(self.parts_dir(upload_id)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn as_local(&self) -> Option<&LocalStore> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> (LocalStore, PathBuf) {
+        let root =
+            std::env::temp_dir().join(format!("ruxlog-local-store-{}", uuid::Uuid::new_v4()));
+        let store = LocalStore::new(
+            &root,
+            "local",
+            "http://localhost:8888/media",
+            b"test-signing-key".to_vec(),
+        );
+        (store, root)
+    }
+
+    fn query_from(url: &str) -> SignedQuery {
+        let query = url.split_once('?').unwrap().1;
+        let mut parsed = SignedQuery::default();
+        for pair in query.split('&') {
+            match pair.split_once('=').unwrap() {
+                ("exp", v) => parsed.exp = v.parse().ok(),
+                ("sig", v) => parsed.sig = Some(v.to_string()),
+                _ => {}
+            }
+        }
+        parsed
+    }
+
+    #[tokio::test]
+    async fn put_get_list_delete_roundtrip() {
+        let (store, root) = temp_store();
+
+        store
+            .put(
+                "media/2025/01/a.png",
+                Bytes::from_static(b"png"),
+                "image/png",
+            )
+            .await
+            .unwrap();
+        store
+            .put(
+                "media/2025/01/a@640w.webp",
+                Bytes::from_static(b"webp"),
+                "image/webp",
+            )
+            .await
+            .unwrap();
+        store
+            .put("seed/b.png", Bytes::from_static(b"seed"), "image/png")
+            .await
+            .unwrap();
+
+        let blob = store.get("media/2025/01/a.png").await.unwrap().unwrap();
+        assert_eq!(&blob.bytes[..], b"png");
+        assert_eq!(blob.content_type, "image/png");
+
+        let keys: Vec<String> = store
+            .list("media/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|o| o.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec!["media/2025/01/a.png", "media/2025/01/a@640w.webp"]
+        );
+
+        store.delete("media/2025/01/a.png").await.unwrap();
+        store.delete("media/2025/01/a.png").await.unwrap();
+        assert!(store.get("media/2025/01/a.png").await.unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn rejects_traversal_keys() {
+        let (store, _root) = temp_store();
+        assert!(matches!(
+            store.put("../escape.png", Bytes::new(), "image/png").await,
+            Err(StorageError::InvalidKey(_))
+        ));
+        assert!(store.get("media/../../etc/passwd").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn signed_urls_verify_and_expire() {
+        let (store, _root) = temp_store();
+        let url = store
+            .signed_url("media/a.png", Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(url.starts_with("http://localhost:8888/media/local/media/a.png?exp="));
+
+        let query = query_from(&url);
+        let now = Utc::now();
+        assert!(store.authorize("local", "media/a.png", &query, now));
+        assert!(!store.authorize("local", "media/b.png", &query, now));
+        assert!(!store.authorize("other", "media/a.png", &query, now));
+        assert!(!store.authorize(
+            "local",
+            "media/a.png",
+            &query,
+            now + chrono::Duration::seconds(120)
+        ));
+    }
+
+    #[tokio::test]
+    async fn multipart_upload_assembles_parts_in_order() {
+        let (store, root) = temp_store();
+        let key = "uploads/2025/01/big.png";
+
+        let upload_id = store.create_multipart(key, "image/png").await.unwrap();
+        store
+            .write_part(&upload_id, 2, Bytes::from_static(b"world"))
+            .await
+            .unwrap();
+        store
+            .write_part(&upload_id, 1, Bytes::from_static(b"hello "))
+            .await
+            .unwrap();
+
+        let parts = store.list_parts(key, &upload_id).await.unwrap();
+        assert_eq!(
+            parts.iter().map(|p| p.part_number).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(parts[0].size, 6);
+
+        store
+            .complete_multipart(key, &upload_id, &parts)
+            .await
+            .unwrap();
+        let blob = store.get(key).await.unwrap().unwrap();
+        assert_eq!(&blob.bytes[..], b"hello world");
+
+        // The session is gone once completed.
+        assert!(matches!(
+            store.write_part(&upload_id, 3, Bytes::new()).await,
+            Err(StorageError::NotFound(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn streams_ranges_and_copies_objects() {
+        use futures_util::TryStreamExt;
+
+        let (store, root) = temp_store();
+        let body = vec![7u8; STREAM_CHUNK + 10];
+        store
+            .put("uploads/a.mp4", Bytes::from(body.clone()), "video/mp4")
+            .await
+            .unwrap();
+
+        let chunks: Vec<Bytes> = store
+            .get_stream("uploads/a.mp4")
+            .await
+            .unwrap()
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.concat(), body);
+        assert!(store
+            .get_stream("uploads/missing.mp4")
+            .await
+            .unwrap()
+            .is_none());
+
+        let range = store
+            .get_range("uploads/a.mp4", 4..9)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&range[..], &body[4..9]);
+        let past_end = body.len() as u64 - 3..body.len() as u64 + 100;
+        let tail = store
+            .get_range("uploads/a.mp4", past_end)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tail.len(), 3);
+
+        store
+            .copy("uploads/a.mp4", "media/2025/01/a.mp4", "video/mp4")
+            .await
+            .unwrap();
+        let copied = store.get("media/2025/01/a.mp4").await.unwrap().unwrap();
+        assert_eq!(&copied.bytes[..], &body[..]);
+        assert!(matches!(
+            store
+                .copy("uploads/missing.mp4", "media/b.mp4", "video/mp4")
+                .await,
+            Err(StorageError::NotFound(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn part_urls_are_signed_per_part() {
+        let (store, root) = temp_store();
+        let upload_id = store
+            .create_multipart("uploads/a.png", "image/png")
+            .await
+            .unwrap();
+        let url = store
+            .presign_part("uploads/a.png", &upload_id, 3, Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(url.starts_with(&format!(
+            "http://localhost:8888/media/_parts/{upload_id}/3?exp="
+        )));
+
+        let query = query_from(&url);
+        let now = Utc::now();
+        assert!(store.authorize_part(&upload_id, 3, &query, now));
+        assert!(!store.authorize_part(&upload_id, 4, &query, now));
+        // Part URLs are never served unsigned, unlike objects.
+        assert!(!store.authorize_part(&upload_id, 3, &SignedQuery::default(), now));
+        assert!(store
+            .write_part("../../etc", 1, Bytes::new())
+            .await
+            .is_err());
+
+        store
+            .abort_multipart("uploads/a.png", &upload_id)
+            .await
+            .unwrap();
+        store
+            .abort_multipart("uploads/a.png", &upload_id)
+            .await
+            .unwrap();
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn unsigned_object_requests_are_public_but_bad_signatures_are_not() {
+        let (public, _) = temp_store();
+        assert!(public.authorize("local", "media/a.png", &SignedQuery::default(), Utc::now()));
+
+        let tampered = SignedQuery {
+            exp: Some(Utc::now().timestamp() + 60),
+            sig: Some("00".repeat(32)),
+        };
+        assert!(!public.authorize("local", "media/a.png", &tampered, Utc::now()));
+    }
+}
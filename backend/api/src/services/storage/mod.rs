@@ -0,0 +1,223 @@
+//! Object storage abstraction.
+//!
+//! Media originals and optimizer variants are written through [`ObjectStore`]
+//! rather than a concrete `aws_sdk_s3::Client`, so the backend (and its tests)
+//! can run against the local filesystem without an S3/R2 endpoint. The driver
+//! is picked once at startup from `STORAGE_BACKEND` and held in
+//! `AppState::storage`.
+
+mod local;
+mod s3;
+
+use std::ops::Range;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+
+pub use crate::error::StorageError;
+pub use local::{LocalStore, SignedQuery};
+pub use s3::S3Store;
+
+pub type StorageResult<T> = Result<T, StorageError>;
+
+/// An object's contents read in chunks, for objects too large to buffer.
+pub type ObjectStream = BoxStream<'static, StorageResult<Bytes>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageBackend {
+    S3,
+    Local,
+}
+
+impl StorageBackend {
+    pub fn from_env_value(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "s3" | "r2" | "garage" => Some(Self::S3),
+            "local" | "fs" | "filesystem" => Some(Self::Local),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::S3 => "s3",
+            Self::Local => "local",
+        }
+    }
+}
+
+/// A listed object; `last_modified` is `None` when the backend didn't report one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    pub key: String,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// Object contents plus the content type it should be served with.
+#[derive(Debug, Clone)]
+pub struct StoredBlob {
+    pub bytes: Bytes,
+    pub content_type: String,
+}
+
+/// A part already received for a multipart upload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedPart {
+    pub part_number: i32,
+    pub etag: String,
+    pub size: i64,
+}
+
+#[async_trait]
+pub trait ObjectStore: Send + Sync + std::fmt::Debug {
+    fn backend(&self) -> StorageBackend;
+
+    /// Bucket name recorded on `media.bucket` for objects written by this store.
+    fn bucket(&self) -> &str;
+
+    async fn put(&self, key: &str, body: Bytes, content_type: &str) -> StorageResult<()>;
+
+    async fn get(&self, key: &str) -> StorageResult<Option<StoredBlob>>;
+
+    /// Like [`get`](Self::get), without holding the whole object in memory.
+    async fn get_stream(&self, key: &str) -> StorageResult<Option<ObjectStream>>;
+
+    /// Bytes `range` of an object, cut short at its end.
+    async fn get_range(&self, key: &str, range: Range<u64>) -> StorageResult<Option<Bytes>>;
+
+    /// Copy `from` to `to` within the store; the bytes never pass through
+    /// the API.
+    async fn copy(&self, from: &str, to: &str, content_type: &str) -> StorageResult<()>;
+
+    /// Remove an object. Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> StorageResult<()>;
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>>;
+
+    /// Time-limited URL that grants read access to `key` even when the
+    /// store is not publicly readable.
+    async fn signed_url(&self, key: &str, ttl: Duration) -> StorageResult<String>;
+
+    /// Start a multipart upload to `key`, returning the backend's upload id.
+    async fn create_multipart(&self, key: &str, content_type: &str) -> StorageResult<String>;
+
+    /// Time-limited URL the client `PUT`s one part's bytes to.
+    async fn presign_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl: Duration,
+    ) -> StorageResult<String>;
+
+    /// Parts received so far, ordered by part number.
+    async fn list_parts(&self, key: &str, upload_id: &str) -> StorageResult<Vec<UploadedPart>>;
+
+    /// Assemble `parts` into the object at `key`.
+    async fn complete_multipart(
+        &self,
+        key: &str,
+        upload_id: &str,
+        parts: &[UploadedPart],
+    ) -> StorageResult<()>;
+
+    /// Discard an unfinished upload. Aborting an unknown upload is not an error.
+    async fn abort_multipart(&self, key: &str, upload_id: &str) -> StorageResult<()>;
+
+    /// Downcast hook for the `/media` file route, which only exists for the
+    /// local driver.
+    fn as_local(&self) -> Option<&LocalStore> {
+        None
+    }
+}
+
+/// Reject keys that could escape a storage root or confuse URL building.
+pub fn validate_key(key: &str) -> StorageResult<()> {
+    let invalid = key.is_empty()
+        || key.starts_with('/')
+        || key.contains('\\')
+        || key.contains('\0')
+        || key
+            .split('/')
+            .any(|segment| segment.is_empty() || segment == "." || segment == "..");
+
+    if invalid {
+        Err(StorageError::InvalidKey(key.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Best-effort content type from a key's extension, used where the backend
+/// doesn't keep one alongside the object.
+pub fn content_type_for_key(key: &str) -> &'static str {
+    let ext = key
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "tif" | "tiff" => "image/tiff",
+        "pdf" => "application/pdf",
+        "mp4" | "m4v" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        "ogg" | "oga" | "opus" => "audio/ogg",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_key_accepts_media_keys() {
+        assert!(validate_key("media/2025/01/abc.png").is_ok());
+        assert!(validate_key("media/2025/01/abc@640w.webp").is_ok());
+    }
+
+    #[test]
+    fn validate_key_rejects_traversal_and_absolute_paths() {
+        for key in [
+            "",
+            "/etc/passwd",
+            "media/../../etc/passwd",
+            "media/./a.png",
+            "media//a.png",
+            "media\\a.png",
+            "media/a\0.png",
+        ] {
+            assert!(validate_key(key).is_err(), "{key:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn content_type_is_inferred_from_extension() {
+        assert_eq!(content_type_for_key("a/b.PNG"), "image/png");
+        assert_eq!(content_type_for_key("a/b.jpeg"), "image/jpeg");
+        assert_eq!(content_type_for_key("a/b"), "application/octet-stream");
+    }
+
+    #[test]
+    fn backend_parses_aliases() {
+        assert_eq!(
+            StorageBackend::from_env_value("R2"),
+            Some(StorageBackend::S3)
+        );
+        assert_eq!(
+            StorageBackend::from_env_value(" local "),
+            Some(StorageBackend::Local)
+        );
+        assert_eq!(StorageBackend::from_env_value("ftp"), None);
+    }
+}
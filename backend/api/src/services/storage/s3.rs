@@ -0,0 +1,380 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use aws_sdk_s3::{
+    presigning::PresigningConfig,
+    primitives::ByteStream,
+    types::{CompletedMultipartUpload, CompletedPart, MetadataDirective},
+};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+
+use super::{
+    content_type_for_key, validate_key, ObjectStore, ObjectStream, StorageBackend, StorageError,
+    StorageResult, StoredBlob, StoredObject, UploadedPart,
+};
+
+/// S3-compatible driver (Cloudflare R2, Garage, AWS S3, ...).
+#[derive(Debug, Clone)]
+pub struct S3Store {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl S3Store {
+    pub fn new(client: aws_sdk_s3::Client, bucket: impl Into<String>) -> Self {
+        Self {
+            client,
+            bucket: bucket.into(),
+        }
+    }
+}
+
+fn backend_err(err: impl std::fmt::Display) -> StorageError {
+    StorageError::Backend(err.to_string())
+}
+
+#[async_trait]
+impl ObjectStore for S3Store {
+    fn backend(&self) -> StorageBackend {
+        StorageBackend::S3
+    }
+
+    fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    async fn put(&self, key: &str, body: Bytes, content_type: &str) -> StorageResult<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .content_type(content_type)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    async fn get(&self, key: &str) -> StorageResult<Option<StoredBlob>> {
+        let output = match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => {
+                if err
+                    .as_service_error()
+                    .map(|e| e.is_no_such_key())
+                    .unwrap_or(false)
+                {
+                    return Ok(None);
+                }
+                return Err(backend_err(err));
+            }
+        };
+
+        let content_type = output
+            .content_type()
+            .map(str::to_string)
+            .unwrap_or_else(|| content_type_for_key(key).to_string());
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(backend_err)?
+            .into_bytes();
+
+        Ok(Some(StoredBlob {
+            bytes,
+            content_type,
+        }))
+    }
+
+    async fn get_stream(&self, key: &str) -> StorageResult<Option<ObjectStream>> {
+        let output = match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => {
+                if err
+                    .as_service_error()
+                    .map(|e| e.is_no_such_key())
+                    .unwrap_or(false)
+                {
+                    return Ok(None);
+                }
+                return Err(backend_err(err));
+            }
+        };
+
+        let stream = futures_util::stream::try_unfold(output.body, |mut body| async move {
+            let chunk = body.try_next().await.map_err(backend_err)?;
+            Ok(chunk.map(|chunk| (chunk, body)))
+        });
+        Ok(Some(Box::pin(stream)))
+    }
+
+    async fn get_range(&self, key: &str, range: Range<u64>) -> StorageResult<Option<Bytes>> {
+        if range.is_empty() {
+            return Ok(Some(Bytes::new()));
+        }
+        let output = match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .range(format!("bytes={}-{}", range.start, range.end - 1))
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => {
+                if err
+                    .as_service_error()
+                    .map(|e| e.is_no_such_key())
+                    .unwrap_or(false)
+                {
+                    return Ok(None);
+                }
+                // A range starting past the end is answered with 416.
+                if err.raw_response().map(|r| r.status().as_u16()) == Some(416) {
+                    return Ok(Some(Bytes::new()));
+                }
+                return Err(backend_err(err));
+            }
+        };
+
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(backend_err)?
+            .into_bytes();
+        Ok(Some(bytes))
+    }
+
+    async fn copy(&self, from: &str, to: &str, content_type: &str) -> StorageResult<()> {
+        // Validated keys are plain path segments, so `bucket/key` needs no
+        // escaping.
+        validate_key(from)?;
+        validate_key(to)?;
+        self.client
+            .copy_object()
+            .bucket(&self.bucket)
+            .key(to)
+            .copy_source(format!("{}/{}", self.bucket, from))
+            .content_type(content_type)
+            .metadata_directive(MetadataDirective::Replace)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        let mut objects = Vec::new();
+        let mut continuation: Option<String> = None;
+
+        loop {
+            let page = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix)
+                .set_continuation_token(continuation.take())
+                .send()
+                .await
+                .map_err(backend_err)?;
+
+            for object in page.contents() {
+                let Some(key) = object.key() else { continue };
+                let last_modified = object
+                    .last_modified()
+                    .and_then(|ts| DateTime::<Utc>::from_timestamp(ts.secs(), ts.subsec_nanos()));
+                objects.push(StoredObject {
+                    key: key.to_string(),
+                    last_modified,
+                });
+            }
+
+            match page.next_continuation_token() {
+                Some(token) if page.is_truncated().unwrap_or(false) => {
+                    continuation = Some(token.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(objects)
+    }
+
+    async fn signed_url(&self, key: &str, ttl: Duration) -> StorageResult<String> {
+        let config = PresigningConfig::expires_in(ttl).map_err(backend_err)?;
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .presigned(config)
+            .await
+            .map_err(backend_err)?;
+
+        Ok(request.uri().to_string())
+    }
+
+    async fn create_multipart(&self, key: &str, content_type: &str) -> StorageResult<String> {
+        let output = self
+            .client
+            .create_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .send()
+            .await
+            .map_err(backend_err)?;
+
+        output
+            .upload_id()
+            .map(str::to_string)
+            .ok_or_else(|| StorageError::Backend("missing multipart upload id".to_string()))
+    }
+
+    async fn presign_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: i32,
+        ttl: Duration,
+    ) -> StorageResult<String> {
+        let config = PresigningConfig::expires_in(ttl).map_err(backend_err)?;
+        let request = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .presigned(config)
+            .await
+            .map_err(backend_err)?;
+
+        Ok(request.uri().to_string())
+    }
+
+    async fn list_parts(&self, key: &str, upload_id: &str) -> StorageResult<Vec<UploadedPart>> {
+        let mut parts = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let page = self
+                .client
+                .list_parts()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .set_part_number_marker(marker.take())
+                .send()
+                .await
+                .map_err(backend_err)?;
+
+            for part in page.parts() {
+                let (Some(part_number), Some(etag)) = (part.part_number(), part.e_tag()) else {
+                    continue;
+                };
+                parts.push(UploadedPart {
+                    part_number,
+                    etag: etag.to_string(),
+                    size: part.size().unwrap_or_default(),
+                });
+            }
+
+            match page.next_part_number_marker() {
+                Some(next) if page.is_truncated().unwrap_or(false) => {
+                    marker = Some(next.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        parts.sort_by_key(|part| part.part_number);
+        Ok(parts)
+    }
+
+    async fn complete_multipart(
+        &self,
+        key: &str,
+        upload_id: &str,
+        parts: &[UploadedPart],
+    ) -> StorageResult<()> {
+        let completed = parts
+            .iter()
+            .map(|part| {
+                CompletedPart::builder()
+                    .part_number(part.part_number)
+                    .e_tag(&part.etag)
+                    .build()
+            })
+            .collect();
+
+        self.client
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(completed))
+                    .build(),
+            )
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    async fn abort_multipart(&self, key: &str, upload_id: &str) -> StorageResult<()> {
+        match self
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err)
+                if err
+                    .as_service_error()
+                    .map(|e| e.is_no_such_upload())
+                    .unwrap_or(false) =>
+            {
+                Ok(())
+            }
+            Err(err) => Err(backend_err(err)),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Privacy-friendly enrichment for first-party traffic events: device class
+//! from the User-Agent, external referrer host, UTM parameters and a daily
+//! visitor hash. The raw IP and User-Agent are only inputs here; neither is
+//! stored.
+
+use chrono::NaiveDate;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::db::sea_models::analytics_event::{DeviceClass, UtmParams};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Longest UTM value kept; anything beyond is cut off.
+pub const MAX_UTM_LEN: usize = 128;
+const MAX_HOST_LEN: usize = 253;
+
+const BOT_MARKERS: &[&str] = &[
+    "bot",
+    "crawler",
+    "spider",
+    "slurp",
+    "headless",
+    "lighthouse",
+    "preview",
+    "curl/",
+    "wget/",
+    "python-requests",
+];
+
+/// Whether the User-Agent belongs to a crawler or script. Such requests are
+/// not recorded at all.
+pub fn is_bot(user_agent: &str) -> bool {
+    let ua = user_agent.to_ascii_lowercase();
+    BOT_MARKERS.iter().any(|marker| ua.contains(marker))
+}
+
+pub fn device_class(user_agent: Option<&str>) -> DeviceClass {
+    let Some(ua) = user_agent.filter(|ua| !ua.trim().is_empty()) else {
+        return DeviceClass::Unknown;
+    };
+    let ua = ua.to_ascii_lowercase();
+
+    if ua.contains("ipad")
+        || ua.contains("tablet")
+        || ua.contains("kindle")
+        || ua.contains("silk/")
+        || (ua.contains("android") && !ua.contains("mobile"))
+    {
+        DeviceClass::Tablet
+    } else if ua.contains("mobi")
+        || ua.contains("iphone")
+        || ua.contains("ipod")
+        || ua.contains("windows phone")
+    {
+        DeviceClass::Mobile
+    } else {
+        DeviceClass::Desktop
+    }
+}
+
+/// Host of an http(s) referrer URL, lowercased and without `www.`. `None` for
+/// other schemes, malformed URLs and self-referrals (same host as `own_host`).
+pub fn referrer_host(referrer: &str, own_host: Option<&str>) -> Option<String> {
+    let rest = referrer
+        .trim()
+        .strip_prefix("https://")
+        .or_else(|| referrer.trim().strip_prefix("http://"))?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_port = authority.rsplit('@').next()?;
+    let host = host_port.split(':').next()?.to_ascii_lowercase();
+    let host = normalize_host(&host)?;
+
+    if own_host.and_then(normalize_host).as_deref() == Some(host.as_str()) {
+        return None;
+    }
+    Some(host)
+}
+
+fn normalize_host(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let valid = !host.is_empty()
+        && host.len() <= MAX_HOST_LEN
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    valid.then(|| host.to_string())
+}
+
+/// `utm_*` parameters from a query string (with or without the leading `?`).
+/// Source and medium are lowercased so `Google` and `google` group together.
+pub fn parse_utm(query: &str) -> UtmParams {
+    let mut utm = UtmParams::default();
+    for pair in query.trim_start_matches('?').split('&') {
+        let Some((key, value)) = pair.split_once('=') else {
+            continue;
+        };
+        let value = urlencoding::decode(&value.replace('+', " "))
+            .map(|v| v.into_owned())
+            .unwrap_or_default();
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let value: String = value.chars().take(MAX_UTM_LEN).collect();
+        match key {
+            "utm_source" => utm.source = Some(value.to_lowercase()),
+            "utm_medium" => utm.medium = Some(value.to_lowercase()),
+            "utm_campaign" => utm.campaign = Some(value),
+            "utm_term" => utm.term = Some(value),
+            "utm_content" => utm.content = Some(value),
+            _ => {}
+        }
+    }
+    utm
+}
+
+/// Visitor id for `day`: `HMAC-SHA256(secret, day | ip | user agent)`, hex,
+/// truncated to 128 bits. The date is part of the input, so the same visitor
+/// gets an unrelated id the next day, and without the server secret the id
+/// cannot be traced back to an IP.
+pub fn visitor_id(secret: &[u8], day: NaiveDate, ip: &str, user_agent: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(b"analytics-visitor\0");
+    mac.update(day.to_string().as_bytes());
+    mac.update(b"\0");
+    mac.update(ip.as_bytes());
+    mac.update(b"\0");
+    mac.update(user_agent.as_bytes());
+    hex::encode(&mac.finalize().into_bytes()[..16])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IPHONE: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1";
+    const IPAD: &str = "Mozilla/5.0 (iPad; CPU OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1";
+    const ANDROID_TABLET: &str = "Mozilla/5.0 (Linux; Android 13; SM-X700) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36";
+    const ANDROID_PHONE: &str = "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Mobile Safari/537.36";
+    const DESKTOP: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36";
+
+    #[test]
+    fn device_class_from_common_user_agents() {
+        assert_eq!(device_class(Some(IPHONE)), DeviceClass::Mobile);
+        assert_eq!(device_class(Some(ANDROID_PHONE)), DeviceClass::Mobile);
+        assert_eq!(device_class(Some(IPAD)), DeviceClass::Tablet);
+        assert_eq!(device_class(Some(ANDROID_TABLET)), DeviceClass::Tablet);
+        assert_eq!(device_class(Some(DESKTOP)), DeviceClass::Desktop);
+        assert_eq!(device_class(Some("  ")), DeviceClass::Unknown);
+        assert_eq!(device_class(None), DeviceClass::Unknown);
+    }
+
+    #[test]
+    fn bots_are_detected() {
+        assert!(is_bot(
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"
+        ));
+        assert!(is_bot("curl/8.4.0"));
+        assert!(is_bot("Mozilla/5.0 HeadlessChrome/120.0"));
+        assert!(!is_bot(DESKTOP));
+        assert!(!is_bot(IPHONE));
+    }
+
+    #[test]
+    fn referrer_host_is_normalized() {
+        assert_eq!(
+            referrer_host("https://www.Google.com/search?q=x", None).as_deref(),
+            Some("google.com")
+        );
+        assert_eq!(
+            referrer_host("http://user@news.ycombinator.com:8080/item", None).as_deref(),
+            Some("news.ycombinator.com")
+        );
+        assert_eq!(referrer_host("https://t.co", None).as_deref(), Some("t.co"));
+    }
+
+    #[test]
+    fn referrer_host_drops_self_referrals_and_other_schemes() {
+        assert_eq!(
+            referrer_host("https://www.ruxlog.com/blog", Some("ruxlog.com")),
+            None
+        );
+        assert_eq!(referrer_host("android-app://com.slack", None), None);
+        assert_eq!(referrer_host("", None), None);
+        assert_eq!(referrer_host("https://exa mple.com/", None), None);
+    }
+
+    #[test]
+    fn utm_parameters_are_decoded_and_trimmed() {
+        let utm = parse_utm(
+            "?utm_source=Newsletter&utm_medium=EMAIL&utm_campaign=Spring+Sale%202026&utm_term=&ref=x",
+        );
+        assert_eq!(utm.source.as_deref(), Some("newsletter"));
+        assert_eq!(utm.medium.as_deref(), Some("email"));
+        assert_eq!(utm.campaign.as_deref(), Some("Spring Sale 2026"));
+        assert_eq!(utm.term, None);
+        assert_eq!(utm.content, None);
+
+        let long = format!("utm_campaign={}", "a".repeat(500));
+        assert_eq!(
+            parse_utm(&long).campaign.map(|c| c.len()),
+            Some(MAX_UTM_LEN)
+        );
+        assert_eq!(parse_utm(""), UtmParams::default());
+    }
+
+    #[test]
+    fn visitor_id_rotates_daily_and_depends_on_the_secret() {
+        let day = NaiveDate::from_ymd_opt(2026, 7, 20).unwrap();
+        let next = day.succ_opt().unwrap();
+        let id = visitor_id(b"secret", day, "203.0.113.7", DESKTOP);
+
+        assert_eq!(id.len(), 32);
+        assert_eq!(id, visitor_id(b"secret", day, "203.0.113.7", DESKTOP));
+        assert_ne!(id, visitor_id(b"secret", next, "203.0.113.7", DESKTOP));
+        assert_ne!(id, visitor_id(b"other", day, "203.0.113.7", DESKTOP));
+        assert_ne!(id, visitor_id(b"secret", day, "203.0.113.8", DESKTOP));
+    }
+}
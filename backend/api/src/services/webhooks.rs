@@ -0,0 +1,701 @@
+//! Outgoing webhooks.
+//!
+//! Admins register endpoints and pick the events each one receives (see
+//! [`WebhookEvent`]). When one of those events happens,
+//! [`notifications::dispatch`](crate::services::notifications::dispatch)
+//! hands it to [`dispatch`], which builds the payload once and queues a
+//! delivery per subscribed endpoint. The delivery worker
+//! ([`start_webhook_delivery`]) POSTs due deliveries and retries failures
+//! with exponential backoff until `max_attempts`; every attempt is recorded
+//! in `webhook_deliveries`, and an admin can redeliver any of them.
+//!
+//! Deliveries are signed the way Standard Webhooks receivers expect, which
+//! is also how `services::billing::webhook_util::verify_standard_webhooks`
+//! checks inbound Polar events:
+//! - `webhook-id`: the message id, stable across retries and redeliveries
+//! - `webhook-timestamp`: unix seconds of this attempt
+//! - `webhook-signature`: `v1,<base64 HMAC-SHA256>` over
+//!   `"{webhook-id}.{webhook-timestamp}.{body}"`, keyed with the endpoint's
+//!   `whsec_<base64>` secret
+//!
+//! Endpoints must resolve to public addresses unless
+//! `WEBHOOK_ALLOW_PRIVATE_TARGETS=true`, and redirects are never followed,
+//! so a webhook cannot be pointed at internal services. The delivery client
+//! resolves hosts itself and only ever connects to the public addresses it
+//! found, so a DNS answer that changes between the check and the request
+//! (rebinding) cannot reach a private address either.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sea_orm::{DbConn, EntityTrait};
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tracing::{error, info, instrument, warn};
+
+use crate::db::sea_models::{
+    post, post_comment, redirect, user, user_ban,
+    webhook_delivery::{self, NewWebhookDelivery, WebhookAttempt, WebhookDeliveryStatus},
+    webhook_endpoint::{self, WebhookEvent},
+};
+use crate::error::DbResult;
+use crate::services::{abuse_limiter, notifications::SiteEvent, sitemap};
+use crate::state::AppState;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Longest response body kept in the delivery log.
+const RESPONSE_BODY_CHARS: usize = 1024;
+
+/// How long a replica holds a delivery it is sending; longer than the
+/// request timeout so two replicas never send the same attempt.
+const CLAIM_TTL_SECS: usize = 60;
+
+/// Shared client for deliveries: short timeouts, no redirects and
+/// [`PublicResolver`], so an endpoint cannot bounce a delivery, or rebind its
+/// DNS, to an address that was not checked.
+static CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+    reqwest::Client::builder()
+        .dns_resolver(Arc::new(PublicResolver))
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(15))
+        .redirect(reqwest::redirect::Policy::none())
+        .user_agent(concat!("Ruxlog-Webhooks/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .expect("building webhook reqwest::Client must not fail")
+});
+
+/// A fresh `whsec_<base64>` signing secret for an endpoint.
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rng().fill(&mut bytes);
+    format!(
+        "whsec_{}",
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    )
+}
+
+/// A fresh `webhook-id`.
+pub fn new_message_id() -> String {
+    format!("msg_{}", uuid::Uuid::new_v4().simple())
+}
+
+/// The raw HMAC key behind a `whsec_<base64>` secret.
+fn signing_key(secret: &str) -> Vec<u8> {
+    let trimmed = secret.strip_prefix("whsec_").unwrap_or(secret);
+    base64::engine::general_purpose::STANDARD
+        .decode(trimmed)
+        .unwrap_or_else(|_| trimmed.as_bytes().to_vec())
+}
+
+/// The `webhook-signature` header value for one attempt.
+pub fn sign(secret: &str, message_id: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(&signing_key(secret)).expect("HMAC accepts any key length");
+    mac.update(message_id.as_bytes());
+    mac.update(b".");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!(
+        "v1,{}",
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Wait before the attempt after `attempts` failures: `base_secs` doubled
+/// per failure, capped at a day.
+pub fn retry_delay(base_secs: u64, attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 20) as u32;
+    let secs = base_secs.max(1).saturating_mul(1u64 << exponent);
+    Duration::from_secs(secs.min(24 * 60 * 60))
+}
+
+/// Queue `event` for every subscribed endpoint in the background. Events
+/// without a webhook type are ignored.
+pub fn dispatch(state: &AppState, event: &SiteEvent) {
+    let Some(webhook_event) = webhook_event(event) else {
+        return;
+    };
+    let state = state.clone();
+    let event = event.clone();
+    tokio::spawn(async move {
+        if let Err(err) = enqueue(&state, webhook_event, &event).await {
+            error!(event = ?event, error = ?err, "Webhook dispatch failed");
+        }
+    });
+}
+
+fn webhook_event(event: &SiteEvent) -> Option<WebhookEvent> {
+    match event {
+        SiteEvent::PostPublished { .. } => Some(WebhookEvent::PostPublished),
+        SiteEvent::CommentPublished { .. } => Some(WebhookEvent::CommentCreated),
+        SiteEvent::UserRegistered { .. } => Some(WebhookEvent::UserRegistered),
+        SiteEvent::PaymentReceived { .. } => Some(WebhookEvent::PaymentSucceeded),
+        SiteEvent::SubscriptionCanceled { .. } => Some(WebhookEvent::SubscriptionCanceled),
+        SiteEvent::NewsletterSubscribed { .. } => None,
+    }
+}
+
+#[instrument(skip(state))]
+async fn enqueue(state: &AppState, webhook_event: WebhookEvent, event: &SiteEvent) -> DbResult<()> {
+    let endpoints = webhook_endpoint::Entity::find_subscribed(&state.sea_db, webhook_event).await?;
+    if endpoints.is_empty() {
+        return Ok(());
+    }
+    let Some(data) = event_data(&state.sea_db, event).await? else {
+        return Ok(());
+    };
+
+    for endpoint in endpoints {
+        let message_id = new_message_id();
+        let payload = json!({
+            "id": message_id,
+            "type": webhook_event.as_str(),
+            "created_at": chrono::Utc::now().to_rfc3339(),
+            "data": data,
+        });
+        webhook_delivery::Entity::create(
+            &state.sea_db,
+            NewWebhookDelivery {
+                endpoint_id: endpoint.id,
+                message_id,
+                event: webhook_event,
+                payload,
+            },
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// The `data` object for an event, or `None` when there is nothing that
+/// may be shared (a deleted row, or a comment readers cannot see).
+async fn event_data(conn: &DbConn, event: &SiteEvent) -> DbResult<Option<Value>> {
+    Ok(match event {
+        SiteEvent::PostPublished { post_id } => post::Entity::find_by_id(*post_id)
+            .one(conn)
+            .await?
+            .filter(|p| p.status == post::PostStatus::Published)
+            .map(|p| {
+                let url = format!(
+                    "{}{}",
+                    sitemap::base_url(),
+                    redirect::post_path(&p.locale, &p.slug)
+                );
+                json!({
+                    "id": p.id,
+                    "title": p.title,
+                    "slug": p.slug,
+                    "locale": p.locale,
+                    "excerpt": p.excerpt,
+                    "author_id": p.author_id,
+                    "published_at": p.published_at,
+                    "url": url,
+                })
+            }),
+        SiteEvent::CommentPublished { comment_id } => {
+            let Some(comment) = post_comment::Entity::find_by_id(*comment_id)
+                .one(conn)
+                .await?
+            else {
+                return Ok(None);
+            };
+            // Same rule as notifications: only comments readers can see.
+            if !comment.is_published()
+                || user_ban::Entity::is_shadow_banned(conn, comment.user_id).await?
+            {
+                return Ok(None);
+            }
+            let Some(post) = post::Entity::find_by_id(comment.post_id).one(conn).await? else {
+                return Ok(None);
+            };
+            let author_name = user::Entity::find_by_id(comment.user_id)
+                .one(conn)
+                .await?
+                .map(|u| u.name);
+            Some(json!({
+                "id": comment.id,
+                "post_id": post.id,
+                "post_slug": post.slug,
+                "parent_id": comment.parent_id,
+                "user_id": comment.user_id,
+                "author_name": author_name,
+                "content": comment.content,
+                "created_at": comment.created_at,
+                "url": format!(
+                    "{}{}#comment-{}",
+                    sitemap::base_url(),
+                    redirect::post_path(&post.locale, &post.slug),
+                    comment.id
+                ),
+            }))
+        }
+        SiteEvent::UserRegistered { user_id } => user::Entity::find_by_id(*user_id)
+            .one(conn)
+            .await?
+            .map(|u| {
+                json!({
+                    "id": u.id,
+                    "name": u.name,
+                    "email": u.email,
+                    "role": u.role,
+                    "created_at": u.created_at,
+                })
+            }),
+        SiteEvent::PaymentReceived {
+            user_id,
+            amount_cents,
+            currency,
+        } => Some(json!({
+            "user_id": user_id,
+            "amount_cents": amount_cents,
+            "currency": currency.to_uppercase(),
+        })),
+        SiteEvent::SubscriptionCanceled { user_id } => Some(json!({ "user_id": user_id })),
+        SiteEvent::NewsletterSubscribed { .. } => None,
+    })
+}
+
+/// Queue another delivery of a logged one, with the same message id and
+/// body, for the worker's next pass.
+pub async fn redeliver(
+    conn: &DbConn,
+    delivery: &webhook_delivery::Model,
+) -> DbResult<webhook_delivery::Model> {
+    webhook_delivery::Entity::create(
+        conn,
+        NewWebhookDelivery {
+            endpoint_id: delivery.endpoint_id,
+            message_id: delivery.message_id.clone(),
+            event: delivery.event,
+            payload: delivery.payload.clone(),
+        },
+    )
+    .await
+}
+
+/// Whether endpoints may resolve to loopback or private addresses. Only for
+/// local development.
+fn allow_private_targets() -> bool {
+    std::env::var("WEBHOOK_ALLOW_PRIVATE_TARGETS")
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Check that `url` is an absolute http(s) URL whose host resolves only to
+/// public addresses.
+pub async fn check_target(url: &str) -> Result<(), String> {
+    let parsed = reqwest::Url::parse(url).map_err(|_| "URL is not valid".to_string())?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("URL must use http or https".to_string());
+    }
+    let Some(host) = parsed.host_str() else {
+        return Err("URL has no host".to_string());
+    };
+    if allow_private_targets() {
+        return Ok(());
+    }
+    let port = parsed.port_or_known_default().unwrap_or(443);
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs: Vec<_> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| format!("Could not resolve {}", host))?
+        .collect();
+    if addrs.is_empty() {
+        return Err(format!("Could not resolve {}", host));
+    }
+    if addrs.iter().any(|addr| !is_public_ip(addr.ip())) {
+        return Err("URL must not point at a private or loopback address".to_string());
+    }
+    Ok(())
+}
+
+/// Resolver for the delivery client that drops every non-public address from
+/// the answer (unless private targets are allowed), so the connection goes to
+/// an address that passed [`is_public_ip`] in the same lookup.
+struct PublicResolver;
+
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let host = name.as_str();
+            let allow_private = allow_private_targets();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, 0))
+                .await?
+                .filter(|addr| allow_private || is_public_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_ipv4(v4),
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public_ipv4(v4);
+            }
+            let segments = v6.segments();
+            let first = segments[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || (first & 0xfe00) == 0xfc00 // unique local
+                || (first & 0xffc0) == 0xfe80 // link-local
+                || first == 0x2002 // 6to4, wraps any IPv4 address
+                || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]) // NAT64
+        }
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0
+        || (a == 100 && (64..128).contains(&b)) // carrier-grade NAT
+        || (a == 198 && (b == 18 || b == 19))) // benchmarking
+}
+
+/// Runtime settings for the delivery worker. Every `interval_secs` it sends
+/// up to `batch_limit` due deliveries. A failed attempt is retried after
+/// `retry_base_secs`, doubling each time, until `max_attempts` attempts have
+/// failed.
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    pub batch_limit: u64,
+    pub max_attempts: u64,
+    pub retry_base_secs: u64,
+}
+
+impl Default for WebhookDeliveryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 10,
+            batch_limit: 50,
+            max_attempts: 8,
+            retry_base_secs: 60,
+        }
+    }
+}
+
+/// Start the delivery worker as a background tokio task.
+pub fn start_webhook_delivery(state: AppState, config: WebhookDeliveryConfig) {
+    if !config.enabled {
+        info!("Webhook delivery disabled");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        max_attempts = config.max_attempts,
+        "Webhook delivery started"
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_secs.max(1)));
+        loop {
+            interval.tick().await;
+            match deliver_due(&state, &config).await {
+                Ok(0) => {}
+                Ok(sent) => info!(sent, "Webhook deliveries attempted"),
+                Err(err) => error!(error = ?err, "Webhook delivery pass failed"),
+            }
+        }
+    });
+}
+
+/// Attempt every delivery that is due. Returns how many were attempted.
+#[instrument(skip(state))]
+pub async fn deliver_due(state: &AppState, config: &WebhookDeliveryConfig) -> DbResult<usize> {
+    let due = webhook_delivery::Entity::find_due(
+        &state.sea_db,
+        chrono::Utc::now().fixed_offset(),
+        config.batch_limit,
+    )
+    .await?;
+
+    let mut attempted = 0;
+    for delivery in due {
+        // Replicas run the same pass; whoever claims a delivery sends it.
+        let claim = format!("webhook:delivery:{}:{}", delivery.id, delivery.attempts);
+        if !abuse_limiter::dedup_nx(&state.redis_pool, &claim, CLAIM_TTL_SECS).await? {
+            continue;
+        }
+        let Some(endpoint) = webhook_endpoint::Entity::find_by_id(delivery.endpoint_id)
+            .one(&state.sea_db)
+            .await?
+        else {
+            continue;
+        };
+        let delivery_id = delivery.id;
+        let attempt = attempt(&endpoint, &delivery, config).await;
+        if attempt.status != WebhookDeliveryStatus::Succeeded {
+            warn!(
+                delivery_id,
+                endpoint_id = endpoint.id,
+                status = ?attempt.status,
+                response_status = attempt.response_status,
+                error = attempt.error.as_deref(),
+                "Webhook delivery attempt failed"
+            );
+        }
+        webhook_delivery::Entity::record_attempt(&state.sea_db, delivery, attempt).await?;
+        attempted += 1;
+    }
+    Ok(attempted)
+}
+
+/// Send one delivery and work out what happens next. A paused endpoint is
+/// not contacted; its deliveries wait and retry like any other failure.
+async fn attempt(
+    endpoint: &webhook_endpoint::Model,
+    delivery: &webhook_delivery::Model,
+    config: &WebhookDeliveryConfig,
+) -> WebhookAttempt {
+    let outcome = if endpoint.is_active {
+        send(endpoint, &delivery.message_id, &delivery.payload).await
+    } else {
+        Err((None, None, "Endpoint is paused".to_string()))
+    };
+
+    match outcome {
+        Ok((status, body)) => WebhookAttempt {
+            status: WebhookDeliveryStatus::Succeeded,
+            response_status: Some(status),
+            response_body: body,
+            error: None,
+            next_attempt_at: None,
+        },
+        Err((response_status, response_body, error)) => {
+            let attempts = delivery.attempts + 1;
+            let (status, next_attempt_at) = if attempts as u64 >= config.max_attempts {
+                (WebhookDeliveryStatus::Failed, None)
+            } else {
+                let delay = retry_delay(config.retry_base_secs, attempts);
+                (
+                    WebhookDeliveryStatus::Pending,
+                    Some(
+                        chrono::Utc::now().fixed_offset()
+                            + chrono::Duration::seconds(delay.as_secs() as i64),
+                    ),
+                )
+            };
+            WebhookAttempt {
+                status,
+                response_status,
+                response_body,
+                error: Some(error),
+                next_attempt_at,
+            }
+        }
+    }
+}
+
+/// Outcome of a test ping, reported back to the admin and not logged.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookTestResult {
+    pub success: bool,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Send a signed `webhook.test` ping to an endpoint right away, paused or
+/// not, so an admin can check the URL and their signature verification.
+pub async fn send_test(endpoint: &webhook_endpoint::Model) -> WebhookTestResult {
+    let message_id = new_message_id();
+    let payload = json!({
+        "id": message_id,
+        "type": "webhook.test",
+        "created_at": chrono::Utc::now().to_rfc3339(),
+        "data": { "endpoint_id": endpoint.id },
+    });
+    match send(endpoint, &message_id, &payload).await {
+        Ok((status, body)) => WebhookTestResult {
+            success: true,
+            response_status: Some(status),
+            response_body: body,
+            error: None,
+        },
+        Err((response_status, response_body, error)) => WebhookTestResult {
+            success: false,
+            response_status,
+            response_body,
+            error: Some(error),
+        },
+    }
+}
+
+type SendError = (Option<i32>, Option<String>, String);
+
+/// POST the signed payload. `Ok` carries the 2xx status and response body.
+async fn send(
+    endpoint: &webhook_endpoint::Model,
+    message_id: &str,
+    payload: &Value,
+) -> Result<(i32, Option<String>), SendError> {
+    check_target(&endpoint.url)
+        .await
+        .map_err(|err| (None, None, err))?;
+
+    let body = serde_json::to_vec(payload)
+        .map_err(|err| (None, None, format!("Failed to encode payload: {}", err)))?;
+    let timestamp = chrono::Utc::now().timestamp();
+    let signature = sign(&endpoint.secret, message_id, timestamp, &body);
+
+    let response = CLIENT
+        .post(&endpoint.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("webhook-id", message_id)
+        .header("webhook-timestamp", timestamp.to_string())
+        .header("webhook-signature", signature)
+        .body(body)
+        .send()
+        .await
+        .map_err(|err| (None, None, format!("Request failed: {}", err)))?;
+
+    let status = response.status();
+    let text = response.text().await.ok().map(|text| truncate(&text));
+    if status.is_success() {
+        Ok((status.as_u16() as i32, text))
+    } else {
+        Err((
+            Some(status.as_u16() as i32),
+            text,
+            format!("Endpoint responded with {}", status),
+        ))
+    }
+}
+
+fn truncate(text: &str) -> String {
+    text.chars().take(RESPONSE_BODY_CHARS).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_secret_round_trips_to_a_32_byte_key() {
+        let secret = generate_secret();
+        assert!(secret.starts_with("whsec_"));
+        assert_eq!(signing_key(&secret).len(), 32);
+        assert_ne!(secret, generate_secret());
+    }
+
+    #[test]
+    fn signature_covers_id_timestamp_and_body() {
+        let secret = generate_secret();
+        let signature = sign(&secret, "msg_1", 1_700_000_000, b"{}");
+        assert!(signature.starts_with("v1,"));
+        assert_eq!(signature, sign(&secret, "msg_1", 1_700_000_000, b"{}"));
+        assert_ne!(signature, sign(&secret, "msg_2", 1_700_000_000, b"{}"));
+        assert_ne!(signature, sign(&secret, "msg_1", 1_700_000_001, b"{}"));
+        assert_ne!(signature, sign(&secret, "msg_1", 1_700_000_000, b"[]"));
+    }
+
+    #[cfg(feature = "billing")]
+    #[test]
+    fn signature_verifies_like_an_inbound_standard_webhook() {
+        use crate::services::billing::webhook_util::verify_standard_webhooks;
+        use axum::http::HeaderMap;
+
+        let secret = generate_secret();
+        let now = 1_700_000_000i64;
+        let body = br#"{"type":"post.published"}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert("webhook-id", "msg_1".parse().unwrap());
+        headers.insert("webhook-timestamp", now.to_string().parse().unwrap());
+        headers.insert(
+            "webhook-signature",
+            sign(&secret, "msg_1", now, body).parse().unwrap(),
+        );
+
+        assert!(verify_standard_webhooks(&headers, &secret, body, now));
+        assert!(!verify_standard_webhooks(
+            &headers,
+            &generate_secret(),
+            body,
+            now
+        ));
+    }
+
+    #[test]
+    fn retry_delay_doubles_and_caps_at_a_day() {
+        assert_eq!(retry_delay(60, 1), Duration::from_secs(60));
+        assert_eq!(retry_delay(60, 2), Duration::from_secs(120));
+        assert_eq!(retry_delay(60, 4), Duration::from_secs(480));
+        assert_eq!(retry_delay(60, 30), Duration::from_secs(24 * 60 * 60));
+    }
+
+    #[test]
+    fn private_and_special_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "2002:7f00:1::",
+            "2002:a9fe:a9fe::1",
+            "64:ff9b::7f00:1",
+            "64:ff9b::a9fe:a9fe",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip} should be blocked");
+        }
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip} should be allowed");
+        }
+    }
+
+    #[tokio::test]
+    async fn delivery_resolver_drops_loopback_answers() {
+        use reqwest::dns::Resolve;
+        use std::str::FromStr;
+
+        let name = reqwest::dns::Name::from_str("localhost").unwrap();
+        assert!(PublicResolver.resolve(name).await.is_err());
+    }
+
+    #[test]
+    fn only_webhook_event_types_are_forwarded() {
+        assert_eq!(
+            webhook_event(&SiteEvent::PostPublished { post_id: 1 }),
+            Some(WebhookEvent::PostPublished)
+        );
+        assert_eq!(
+            webhook_event(&SiteEvent::CommentPublished { comment_id: 1 }),
+            Some(WebhookEvent::CommentCreated)
+        );
+        assert_eq!(
+            webhook_event(&SiteEvent::NewsletterSubscribed {
+                email: "a@example.com".to_string()
+            }),
+            None
+        );
+    }
+}
@@ -5,6 +5,11 @@ use sea_orm::DatabaseConnection;
 use tower_sessions_redis_store::fred::prelude::Pool as RedisPool;
 
 use crate::services::auth::AuthBackend;
+use crate::services::comment_moderation::CommentModerator;
+use crate::services::fx::ExchangeRates;
+use crate::services::geoip::GeoIp;
+use crate::services::notification_hub::NotificationHub;
+use crate::services::storage::ObjectStore;
 
 #[cfg(feature = "billing")]
 use crate::services::billing::BillingRouter;
@@ -40,7 +45,8 @@ pub fn build_http_client() -> reqwest::Client {
 // literal "<redacted>" while still printing the non-secret fields.
 #[derive(Clone)]
 pub struct ObjectStorageConfig {
-    // S3-compatible storage (Cloudflare R2, Garage, AWS S3, etc.)
+    // S3-compatible storage (Cloudflare R2, Garage, AWS S3, etc.). With the
+    // local driver only `bucket` and `public_url` are meaningful.
     pub region: String,
     pub account_id: String,
     pub bucket: String,
@@ -71,6 +77,12 @@ pub struct OptimizerConfig {
     pub max_pixels: u64,
     pub keep_original: bool,
     pub default_webp_quality: u8,
+    /// Also emit AVIF copies of every width variant.
+    pub avif_enabled: bool,
+    pub avif_quality: u8,
+    /// Store uploads as-is and generate variants on the background
+    /// optimization queue instead of inside the upload request.
+    pub defer_uploads: bool,
 }
 
 #[derive(Clone)]
@@ -79,7 +91,9 @@ pub struct AppState {
     pub redis_pool: RedisPool,
     pub mailer: lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
     pub object_storage: ObjectStorageConfig,
-    pub s3_client: aws_sdk_s3::Client,
+    /// Media object store (S3-compatible or local filesystem), selected by
+    /// `STORAGE_BACKEND` at startup. All object reads/writes go through this.
+    pub storage: std::sync::Arc<dyn ObjectStore>,
     /// Server secret (the `COOKIE_KEY` bytes) used to derive keyed hashes for
     /// short-lived verification/reset codes (see `utils::code_hash`). Held here
     /// rather than re-reading env so the key is fixed for the process lifetime.
@@ -101,6 +115,18 @@ pub struct AppState {
     /// providers and the Google userinfo/JWKS fetch so no handler thread can
     /// be pinned by a hanging upstream.
     pub http_client: reqwest::Client,
+    /// GeoLite2 reader loaded once from `GEOLITE2_DB_PATH`, shared by billing
+    /// geo-routing and traffic analytics.
+    pub geoip: std::sync::Arc<GeoIp>,
+    /// Fixed rates from `REVENUE_FX_RATES` used to report revenue in a single
+    /// currency.
+    pub exchange_rates: std::sync::Arc<ExchangeRates>,
+    /// Comment moderation mode, spam scorer and flag threshold, loaded from
+    /// the `COMMENT_*` env vars.
+    pub comment_moderator: std::sync::Arc<CommentModerator>,
+    /// This replica's share of the in-app notification stream, fed from
+    /// Redis pub/sub by `notification_hub::start_notification_relay`.
+    pub notification_hub: std::sync::Arc<NotificationHub>,
     #[cfg(feature = "billing")]
     pub billing_router: std::sync::Arc<BillingRouter>,
 }
@@ -0,0 +1,93 @@
+use validator::ValidationError;
+
+/// Locale for content created before translations existed, and the fallback
+/// when `DEFAULT_LOCALE` is unset or unusable.
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// Canonical form of a BCP 47 tag limited to what content needs: a 2-3
+/// letter language with an optional 2 letter region or 4 letter script
+/// (`en`, `pt-BR`, `zh-Hant`). Underscores are accepted as separators.
+pub fn normalize_locale(code: &str) -> Option<String> {
+    let code = code.trim().replace('_', "-");
+    let mut parts = code.split('-');
+    let language = parts.next()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut normalized = language.to_ascii_lowercase();
+    match (parts.next(), parts.next()) {
+        (None, _) => {}
+        (Some(region), None)
+            if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            normalized.push('-');
+            normalized.push_str(&region.to_ascii_uppercase());
+        }
+        (Some(script), None)
+            if script.len() == 4 && script.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            normalized.push('-');
+            normalized.push_str(&script[..1].to_ascii_uppercase());
+            normalized.push_str(&script[1..].to_ascii_lowercase());
+        }
+        _ => return None,
+    }
+    Some(normalized)
+}
+
+/// The site's primary locale from `DEFAULT_LOCALE`. Its content is served
+/// without a locale prefix.
+pub fn default_locale() -> String {
+    std::env::var("DEFAULT_LOCALE")
+        .ok()
+        .and_then(|code| normalize_locale(&code))
+        .unwrap_or_else(|| FALLBACK_LOCALE.to_string())
+}
+
+/// Consumer path for content in `locale`: unprefixed for the default locale,
+/// `/{locale}{path}` otherwise.
+pub fn localized_path(locale: &str, path: &str) -> String {
+    if locale == default_locale() {
+        path.to_string()
+    } else {
+        format!("/{locale}{path}")
+    }
+}
+
+pub fn validate_locale(code: &str) -> Result<(), ValidationError> {
+    match normalize_locale(code) {
+        Some(_) => Ok(()),
+        None => Err(ValidationError::new("locale")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_language_region_and_script() {
+        assert_eq!(normalize_locale("EN").as_deref(), Some("en"));
+        assert_eq!(normalize_locale("pt_br").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize_locale(" zh-hant ").as_deref(), Some("zh-Hant"));
+        assert_eq!(normalize_locale("fil").as_deref(), Some("fil"));
+    }
+
+    #[test]
+    fn rejects_malformed_tags() {
+        for code in ["", "e", "english", "en-", "en-US-x", "e1", "en-1A", "../en"] {
+            assert_eq!(normalize_locale(code), None, "{code}");
+        }
+    }
+
+    #[test]
+    fn prefixes_only_non_default_locales() {
+        let default = default_locale();
+        assert_eq!(localized_path(&default, "/posts/a"), "/posts/a");
+        let other = if default == "de" { "fr" } else { "de" };
+        assert_eq!(
+            localized_path(other, "/posts/a"),
+            format!("/{other}/posts/a")
+        );
+    }
+}
@@ -31,5 +31,9 @@ run "Comment Rate"               "$DIR/analytics_comment_rate.sh"
 run "Newsletter Growth"          "$DIR/analytics_newsletter_growth.sh"
 run "Media Upload Trends"        "$DIR/analytics_media_upload_trends.sh"
 run "Dashboard Summary"          "$DIR/analytics_dashboard_summary.sh"
+run "Traffic Breakdowns"         "$DIR/analytics_traffic.sh"
+run "Post Engagement"            "$DIR/analytics_post_engagement.sh"
+run "Revenue"                    "$DIR/analytics_revenue.sh"
+run "Rollups"                    "$DIR/analytics_rollups.sh"
 
 echo -e "\nSaved to $OUT" | tee -a "$OUT"
@@ -6,3 +6,7 @@ payload='{"filters":{"period":"30d"}}'
 
 curl_json "/analytics/v1/dashboard/summary" "$payload" | jq .
 
+
+tz_payload='{"timezone":"Asia/Kolkata","filters":{"period":"7d"}}'
+
+curl_json "/analytics/v1/dashboard/summary" "$tz_payload" | jq .
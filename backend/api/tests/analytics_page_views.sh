@@ -10,3 +10,12 @@ payload='{
 
 curl_json "/analytics/v1/engagement/page-views" "$payload" | jq .
 
+# Same week bucketed by Los Angeles days (bypasses the UTC rollups).
+tz_payload='{
+  "date_from":"2024-03-01",
+  "date_to":"2024-03-07",
+  "timezone":"America/Los_Angeles",
+  "filters":{"group_by":"day"}
+}'
+
+curl_json "/analytics/v1/engagement/page-views" "$tz_payload" | jq .
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+set -euo pipefail
+source "$(dirname "$0")/_analytics_common.sh"
+
+post='{
+  "date_from":"2024-03-01",
+  "date_to":"2024-03-31",
+  "filters":{"post_id":42,"group_by":"week"}
+}'
+curl_json "/analytics/v1/engagement/post" "$post" | jq .
+
+author='{
+  "per_page":10,
+  "sort_by":"completion_rate",
+  "filters":{"author_id":1}
+}'
+curl_json "/analytics/v1/engagement/author" "$author" | jq .
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+set -euo pipefail
+source "$(dirname "$0")/_analytics_common.sh"
+
+# Amounts are reported in REVENUE_REPORTING_CURRENCY; see meta.filters_applied.
+trend='{
+  "per_page":12,
+  "sort_order":"asc",
+  "filters":{"group_by":"month"}
+}'
+
+for trend_endpoint in mrr subscribers trials; do
+  curl_json "/analytics/v1/revenue/${trend_endpoint}" "$trend" | jq .
+done
+
+breakdown='{
+  "per_page":10,
+  "sort_by":"revenue"
+}'
+
+for breakdown_endpoint in posts providers; do
+  curl_json "/analytics/v1/revenue/${breakdown_endpoint}" "$breakdown" | jq .
+done
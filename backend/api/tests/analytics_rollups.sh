@@ -0,0 +1,10 @@
+#!/usr/bin/env bash
+set -euo pipefail
+source "$(dirname "$0")/_analytics_common.sh"
+
+# Continue after the watermark, like the background job.
+curl_json "/analytics/v1/rollups/run" '{}' | jq .
+
+# Recompute the last week (clamped to raw views still kept).
+rebuild_from="$(date -u -d '7 days ago' +%F 2>/dev/null || date -u -v-7d +%F)"
+curl_json "/analytics/v1/rollups/run" "{\"rebuild_from\":\"${rebuild_from}\",\"max_days\":7}" | jq .
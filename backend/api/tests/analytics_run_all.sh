@@ -29,6 +29,10 @@ run "Comment Rate"               "$(dirname "$0")/analytics_comment_rate.sh"
 run "Newsletter Growth"          "$(dirname "$0")/analytics_newsletter_growth.sh"
 run "Media Upload Trends"        "$(dirname "$0")/analytics_media_upload_trends.sh"
 run "Dashboard Summary"          "$(dirname "$0")/analytics_dashboard_summary.sh"
+run "Traffic Breakdowns"         "$(dirname "$0")/analytics_traffic.sh"
+run "Post Engagement"            "$(dirname "$0")/analytics_post_engagement.sh"
+run "Revenue"                    "$(dirname "$0")/analytics_revenue.sh"
+run "Rollups"                    "$(dirname "$0")/analytics_rollups.sh"
 
 echo "\nPassed: $pass  Failed: $fail" >&2
 
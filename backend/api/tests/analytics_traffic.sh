@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+set -euo pipefail
+source "$(dirname "$0")/_analytics_common.sh"
+
+# Record one pageview as a consumer browser would, then read the breakdowns.
+collect='{
+  "path":"/blog/hello-world",
+  "referrer":"https://news.ycombinator.com/item?id=1",
+  "query":"?utm_source=Newsletter&utm_medium=email&utm_campaign=launch"
+}'
+# curl's own User-Agent is dropped as a bot, so pose as a browser.
+curl -sS -X POST "${BASE}/analytics/v1/collect" \
+  -b "$COOKIES_FILE" -c "$COOKIES_FILE" \
+  -H "Content-Type: application/json" \
+  -H "csrf-token: ${CSRF_TOKEN}" \
+  -H "Origin: ${BASE}" \
+  -A "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36" \
+  -d "$collect" \
+  -o /dev/null -w "collect: HTTP %{http_code}\n" >&2
+
+payload='{
+  "per_page":10,
+  "sort_by":"visitors",
+  "filters":{"path":"/blog/hello-world"}
+}'
+
+for breakdown in referrers campaigns countries devices; do
+  curl_json "/analytics/v1/traffic/${breakdown}" "$payload" | jq .
+done
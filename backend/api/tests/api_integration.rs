@@ -240,6 +240,59 @@ async fn atom_feed_returns_ok() {
     );
 }
 
+#[tokio::test]
+async fn json_feed_returns_json_feed_document() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/feed/v1/json").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["version"], "https://jsonfeed.org/version/1.1");
+    assert!(body["items"].is_array());
+}
+
+#[tokio::test]
+async fn feed_honours_if_none_match() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/feed/v1/rss").await;
+    let etag = resp
+        .headers()
+        .get("etag")
+        .expect("feed should send an ETag")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let resp = client
+        .get(format!("{BASE_URL}/feed/v1/rss"))
+        .header("If-None-Match", etag)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn scoped_feed_for_unknown_category_is_not_found() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/feed/v1/category/__no_such_category__/rss").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn locale_feed_declares_its_language() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/feed/v1/json?locale=pt_br").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["language"], "pt-BR");
+
+    let resp = get_api(&client, "/feed/v1/rss?locale=not-a-locale").await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
 // --- Static Routes ---
 
 #[tokio::test]
@@ -256,6 +309,62 @@ async fn sitemap_xml_returns_ok() {
     skip_if_no_server!(client);
     let resp = get_api(&client, "/sitemap.xml").await;
     assert_eq!(resp.status(), StatusCode::OK);
+    let body = resp.text().await.unwrap();
+    assert!(body.contains("<sitemapindex"));
+    assert!(body.contains("/sitemaps/posts-1.xml</loc>"));
+}
+
+#[tokio::test]
+async fn child_sitemaps_resolve_by_file_name() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/sitemaps/posts-1.xml").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.text().await.unwrap().contains("<urlset"));
+
+    let resp = get_api(&client, "/sitemaps/posts-999999.xml").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = get_api(&client, "/sitemaps/unknown.xml").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// --- Redirects ---
+
+/// Paths without a redirect rule resolve to 404; malformed paths too.
+#[tokio::test]
+async fn resolve_redirect_unknown_path_returns_not_found() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/redirect/v1/resolve?path=/posts/no-such-post-xyz").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = get_api(&client, "/redirect/v1/resolve?path=not-a-path").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// --- SEO ---
+
+/// Cards are only rendered for known item types and existing slugs.
+#[tokio::test]
+async fn og_card_unknown_item_returns_not_found() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/seo/v1/og/post/no-such-post-xyz.png").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = get_api(&client, "/seo/v1/og/page/about.png").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// --- Authors ---
+
+/// Unknown handles, and users without published posts, have no author page.
+#[tokio::test]
+async fn author_page_unknown_slug_returns_not_found() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/author/v1/no-such-author-xyz").await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = get_api(&client, "/author/v1/no-such-author-xyz?locale=not-a-locale").await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 }
 
 // --- CSRF Protection ---
@@ -406,6 +515,192 @@ async fn tag_create_requires_admin_auth() {
     );
 }
 
+#[tokio::test]
+async fn comment_approve_requires_admin_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let token = require_csrf(&client).await;
+    for action in ["approve", "reject"] {
+        let resp = post_api(
+            &client,
+            &format!("/post/comment/v1/admin/{}/1", action),
+            json!({}),
+            &token,
+        )
+        .await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "comment {} without auth should return 401",
+            action
+        );
+    }
+}
+
+#[tokio::test]
+async fn comment_shadow_ban_requires_admin_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let token = require_csrf(&client).await;
+    for path in ["shadow_ban/1", "shadow_ban/lift/1"] {
+        let resp = post_api(
+            &client,
+            &format!("/post/comment/v1/admin/{}", path),
+            json!({}),
+            &token,
+        )
+        .await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} without auth should return 401",
+            path
+        );
+    }
+}
+
+#[tokio::test]
+async fn comment_like_and_reaction_require_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let token = require_csrf(&client).await;
+    for path in ["like/1", "unlike/1", "like/status/batch"] {
+        let resp = post_api(
+            &client,
+            &format!("/post/comment/v1/{}", path),
+            json!({ "comment_ids": [1] }),
+            &token,
+        )
+        .await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} without auth should return 401",
+            path
+        );
+    }
+    let resp = post_api(
+        &client,
+        "/post/comment/v1/react/1",
+        json!({ "reaction": "love" }),
+        &token,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+// --- Blocklist ---
+
+#[tokio::test]
+async fn blocklist_routes_require_admin_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let token = require_csrf(&client).await;
+    let resp = post_api(&client, "/blocklist/v1/list/query", json!({}), &token).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    let resp = post_api(
+        &client,
+        "/blocklist/v1/create",
+        json!({ "kind": "word", "pattern": "casino" }),
+        &token,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+// --- Notifications ---
+
+#[tokio::test]
+async fn notification_preferences_require_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let resp = get_api(&client, "/notification/v1/preferences").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    let token = require_csrf(&client).await;
+    let resp = post_api(
+        &client,
+        "/notification/v1/preferences/update",
+        json!({ "email_delivery": "digest" }),
+        &token,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn notification_inbox_requires_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    for path in ["/notification/v1/unread_count", "/notification/v1/stream"] {
+        let resp = get_api(&client, path).await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} without auth should return 401",
+            path
+        );
+    }
+    let token = require_csrf(&client).await;
+    for (path, body) in [
+        ("/notification/v1/list/query", json!({ "page": 1 })),
+        ("/notification/v1/mark_read", json!({ "ids": [1] })),
+        ("/notification/v1/mark_all_read", json!({})),
+    ] {
+        let resp = post_api(&client, path, body, &token).await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} without auth should return 401",
+            path
+        );
+    }
+}
+
+#[tokio::test]
+async fn notification_unsubscribe_with_unknown_token_returns_not_found() {
+    let client = client();
+    skip_if_no_server!(client);
+    let path = "/notification/v1/unsubscribe/not-a-real-token";
+    let resp = get_api(&client, path).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    // One-click unsubscribe is posted by mail clients, so it needs no CSRF token.
+    let resp = client
+        .post(format!("{BASE_URL}{path}"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body("List-Unsubscribe=One-Click")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// --- Webhooks ---
+
+#[tokio::test]
+async fn webhook_admin_endpoints_require_auth() {
+    let client = client();
+    skip_if_no_server!(client);
+    let token = require_csrf(&client).await;
+    for (path, body) in [
+        ("/webhook/v1/list/query", json!({ "page": 1 })),
+        ("/webhook/v1/deliveries/query", json!({ "page": 1 })),
+        (
+            "/webhook/v1/create",
+            json!({ "url": "https://example.com/hook", "events": ["post.published"] }),
+        ),
+        ("/webhook/v1/deliveries/redeliver/1", json!({})),
+        ("/webhook/v1/test/1", json!({})),
+    ] {
+        let resp = post_api(&client, path, body, &token).await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} without auth should return 401",
+            path
+        );
+    }
+}
+
 // --- Search ---
 
 #[tokio::test]
@@ -1676,7 +1676,10 @@ mod billing_mock_tests {
                 default_provider: default.to_string(),
                 rules: vec![],
             };
-            let geo = GeoRouter::new(config);
+            let geo = GeoRouter::new(
+                config,
+                std::sync::Arc::new(ruxlog::services::geoip::GeoIp::disabled()),
+            );
             BillingRouter::new(providers, geo)
         }
 
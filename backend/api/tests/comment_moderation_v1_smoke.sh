@@ -8,14 +8,28 @@
 #     - POST /post/comment/v1/delete/{comment_id}
 #     - POST /post/comment/v1/flag/{comment_id}
 #     - POST /post/comment/v1/{post_id}                 (public list by post)
+#     - POST /post/comment/v1/like/{comment_id}
+#     - POST /post/comment/v1/unlike/{comment_id}
+#     - POST /post/comment/v1/like/status/batch
+#     - POST /post/comment/v1/react/{comment_id}
+#     - POST /post/comment/v1/unreact/{comment_id}
 #   Admin moderation routes:
 #     - POST /post/comment/v1/admin/list
+#     - POST /post/comment/v1/admin/approve/{comment_id}
+#     - POST /post/comment/v1/admin/reject/{comment_id}
+#     - POST /post/comment/v1/admin/shadow_ban/{user_id}
+#     - POST /post/comment/v1/admin/shadow_ban/lift/{user_id}
 #     - POST /post/comment/v1/admin/hide/{comment_id}
 #     - POST /post/comment/v1/admin/unhide/{comment_id}
 #     - POST /post/comment/v1/admin/delete/{comment_id}
 #     - POST /post/comment/v1/admin/flags/clear/{comment_id}
 #     - POST /post/comment/v1/admin/flags/list
 #     - POST /post/comment/v1/admin/flags/summary/{comment_id}
+#   Blocklist routes:
+#     - POST /blocklist/v1/create
+#     - POST /blocklist/v1/update/{entry_id}
+#     - POST /blocklist/v1/list/query
+#     - POST /blocklist/v1/delete/{entry_id}
 #
 # Assumptions:
 #   - Server running at BASE_URL
@@ -406,6 +420,73 @@ echo "==> Unhide comment1"
 post_json "/post/comment/v1/admin/unhide/$comment1_id" "{}" 200
 echo
 
+# -----------------------------
+# Moderation queue
+# -----------------------------
+echo "==> Reject comment1 (drops it from the public list)"
+post_json "/post/comment/v1/admin/reject/$comment1_id" "{}" 200
+
+echo "==> List with status=rejected (comment1 should appear)"
+post_json "/post/comment/v1/admin/list" "$(jq -nc '{page:1, status:"rejected"}')" 200
+
+echo "==> List with status=pending (the moderation queue)"
+post_json "/post/comment/v1/admin/list" "$(jq -nc '{page:1, status:"pending"}')" 200
+
+echo "==> Approve comment1"
+post_json "/post/comment/v1/admin/approve/$comment1_id" "{}" 200
+echo
+
+# -----------------------------
+# Likes, reactions & sorting
+# -----------------------------
+echo "==> Like comment1 twice (second is a no-op)"
+post_json "/post/comment/v1/like/$comment1_id" "{}" 200
+post_json "/post/comment/v1/like/$comment1_id" "{}" 200
+
+echo "==> React to comment1"
+post_json "/post/comment/v1/react/$comment1_id" "$(jq -nc '{reaction:"celebrate"}')" 200
+
+echo "==> Batch like status (comment1 liked, comment2 not)"
+post_json "/post/comment/v1/like/status/batch" "$(jq -nc --argjson a "$comment1_id" --argjson b "$comment2_id" '{comment_ids:[$a,$b]}')" 200
+
+echo "==> Public list sorted by top"
+post_json "/post/comment/v1/$post_id" "$(jq -nc '{sort:"top"}')" 200
+
+echo "==> Remove reaction and like"
+post_json "/post/comment/v1/unreact/$comment1_id" "$(jq -nc '{reaction:"celebrate"}')" 200
+post_json "/post/comment/v1/unlike/$comment1_id" "{}" 200
+echo
+
+# -----------------------------
+# Shadow bans & blocklists
+# -----------------------------
+echo "==> List comments by shadow-banned authors"
+post_json "/post/comment/v1/admin/list" "$(jq -nc '{page:1, shadow_banned:true}')" 200
+
+echo "==> Shadow-ban a missing user (expect 404)"
+post_json "/post/comment/v1/admin/shadow_ban/999999" "$(jq -nc '{reason:"smoke"}')" 404
+
+echo "==> Lift a shadow ban that does not exist (expect 404)"
+post_json "/post/comment/v1/admin/shadow_ban/lift/999999" "{}" 404
+
+echo "==> Reject an invalid blocklist regex (expect 400)"
+post_json "/blocklist/v1/create" "$(jq -nc '{kind:"regex", pattern:"(unclosed"}')" 400
+
+echo "==> Create a blocklist domain entry"
+bl_file="$(post_json "/blocklist/v1/create" "$(jq -nc --arg p "https://www.smoke-$RANDOM.example/x" '{kind:"domain", pattern:$p, note:"smoke"}')" 201)"
+blocklist_id="$(jq -r '.id' "$bl_file")"
+echo "Blocklist entry id=$blocklist_id pattern=$(jq -r '.pattern' "$bl_file")"
+
+echo "==> Update the blocklist entry note"
+post_json "/blocklist/v1/update/$blocklist_id" "$(jq -nc '{note:"smoke (updated)"}')" 200
+
+echo "==> List domain blocklist entries"
+post_json "/blocklist/v1/list/query" "$(jq -nc '{page:1, kind:"domain"}')" 200
+
+echo "==> Delete the blocklist entry"
+post_json "/blocklist/v1/delete/$blocklist_id" "{}" 200
+echo
+
 # -----------------------------
 # Admin flags list / summary
 # -----------------------------
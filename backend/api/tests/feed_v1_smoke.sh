@@ -0,0 +1,85 @@
+#!/usr/bin/env bash
+# ruxlog-backend/tests/feed_v1_smoke.sh
+#
+# Smoke tests for the public feeds under /feed/v1
+# - Global RSS/Atom/JSON Feed documents and their content types
+# - Conditional GETs: ETag / If-None-Match and Last-Modified / If-Modified-Since
+# - Scoped feeds 404 for unknown scopes and formats
+#
+# Usage:
+#   bash tests/feed_v1_smoke.sh
+#   CATEGORY_SLUG=rust bash tests/feed_v1_smoke.sh   # also exercise a scoped feed
+set -euo pipefail
+
+BASE_URL="${BASE_URL:-http://localhost:8888}"
+CATEGORY_SLUG="${CATEGORY_SLUG:-}"
+TMP_DIR="$(mktemp -d)"
+
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+require_cmd() {
+  command -v "$1" >/dev/null 2>&1 || { echo "Missing required command: $1"; exit 1; }
+}
+
+fail() {
+  echo "FAIL: $*"
+  exit 1
+}
+
+# status_of PATH [extra curl args...] -> prints the HTTP status, headers in $TMP_DIR/headers
+status_of() {
+  local path="$1"
+  shift
+  curl -sS -o "$TMP_DIR/body" -D "$TMP_DIR/headers" -w '%{http_code}' "$@" "$BASE_URL$path"
+}
+
+header_value() {
+  grep -i "^$1:" "$TMP_DIR/headers" | head -n1 | cut -d' ' -f2- | tr -d '\r'
+}
+
+require_cmd curl
+require_cmd jq
+
+echo "== content types"
+for format in rss atom json; do
+  code="$(status_of "/feed/v1/$format")"
+  [[ "$code" == "200" ]] || fail "/feed/v1/$format returned $code"
+  echo "  $format: $(header_value content-type)"
+done
+
+echo "== JSON Feed 1.1 shape"
+status_of "/feed/v1/json?full=true&limit=5" >/dev/null
+jq -e '.version == "https://jsonfeed.org/version/1.1" and (.items | type == "array")' \
+  "$TMP_DIR/body" >/dev/null || fail "not a JSON Feed 1.1 document"
+jq -r '.items[] | "  \(.id) html=\(has("content_html")) image=\(has("image"))"' "$TMP_DIR/body"
+
+echo "== If-None-Match"
+status_of "/feed/v1/rss" >/dev/null
+etag="$(header_value etag)"
+[[ -n "$etag" ]] || fail "missing ETag"
+code="$(status_of "/feed/v1/rss" -H "If-None-Match: $etag")"
+[[ "$code" == "304" ]] || fail "expected 304 for matching ETag, got $code"
+echo "  $etag -> 304"
+
+echo "== If-Modified-Since"
+last_modified="$(header_value last-modified)"
+if [[ -n "$last_modified" ]]; then
+  code="$(status_of "/feed/v1/atom" -H "If-Modified-Since: $last_modified")"
+  [[ "$code" == "304" ]] || fail "expected 304 for If-Modified-Since, got $code"
+  echo "  $last_modified -> 304"
+else
+  echo "  no published posts; skipped"
+fi
+
+echo "== scoped feeds"
+code="$(status_of "/feed/v1/category/__missing__/rss")"
+[[ "$code" == "404" ]] || fail "unknown category returned $code"
+code="$(status_of "/feed/v1/author/1/xml")"
+[[ "$code" == "404" ]] || fail "unknown format returned $code"
+if [[ -n "$CATEGORY_SLUG" ]]; then
+  code="$(status_of "/feed/v1/category/$CATEGORY_SLUG/json")"
+  [[ "$code" == "200" ]] || fail "category $CATEGORY_SLUG returned $code"
+  jq -r '"  \(.title): \(.items | length) items"' "$TMP_DIR/body"
+fi
+
+echo "OK"
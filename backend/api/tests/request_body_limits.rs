@@ -1,8 +1,8 @@
 use axum::{
     body::{Body, Bytes},
-    extract::Multipart,
+    extract::{DefaultBodyLimit, Multipart},
     http::{Request, StatusCode},
-    routing::post,
+    routing::{post, put},
     Router,
 };
 mod size_config {
@@ -69,6 +69,15 @@ fn media_router() -> Router {
         .layer(RequestBodyLimitLayer::new(body_limits::MEDIA))
 }
 
+/// Mirrors the `/media/_parts/...` route, which raises axum's own 2 MiB
+/// extractor limit to the part size.
+fn part_router() -> Router {
+    Router::new().route(
+        "/part",
+        put(accept_bytes).layer(DefaultBodyLimit::max(body_limits::MEDIA_UPLOAD_PART)),
+    )
+}
+
 #[tokio::test]
 async fn default_payload_under_limit_is_accepted() {
     let app = default_router();
@@ -200,3 +209,50 @@ async fn media_payload_over_limit_is_rejected() {
 
     assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
 }
+
+#[tokio::test]
+async fn upload_part_at_part_size_is_accepted_and_larger_rejected() {
+    for (size, expected) in [
+        (body_limits::MEDIA_UPLOAD_PART, StatusCode::OK),
+        (
+            body_limits::MEDIA_UPLOAD_PART + 1,
+            StatusCode::PAYLOAD_TOO_LARGE,
+        ),
+    ] {
+        let response = part_router()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/part")
+                    .body(Body::from(vec![0u8; size]))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), expected, "part of {size} bytes");
+    }
+}
+
+#[test]
+fn direct_upload_limits_fit_s3_multipart_rules() {
+    // S3 rejects non-final parts under 5 MiB, and direct uploads only exist
+    // for files the regular media limit can't take.
+    const {
+        assert!(body_limits::MEDIA_UPLOAD_PART >= 5 * 1024 * 1024);
+        assert!(body_limits::MEDIA_IMAGE > body_limits::MEDIA);
+        assert!(body_limits::MEDIA_VIDEO > body_limits::MEDIA);
+        assert!(body_limits::MEDIA_AUDIO > body_limits::MEDIA);
+        assert!(body_limits::MEDIA_DOCUMENT > body_limits::MEDIA);
+    }
+}
+
+#[test]
+fn buffered_direct_uploads_stay_below_the_largest_kind() {
+    // Finished direct uploads above MEDIA_BUFFERED are streamed rather than
+    // read into memory; the largest kinds must always take that path.
+    const {
+        assert!(body_limits::MEDIA_BUFFERED >= body_limits::MEDIA);
+        assert!(body_limits::MEDIA_BUFFERED < body_limits::MEDIA_VIDEO);
+    }
+}
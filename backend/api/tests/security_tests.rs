@@ -314,6 +314,10 @@ fn user_json_never_leaks_secret_fields() {
         email: "test@example.com".into(),
         password: Some("dummy-argon2-hash".into()),
         avatar_id: None,
+        bio: None,
+        website_url: None,
+        social_links: serde_json::json!([]),
+        profile_slug: None,
         is_verified: true,
         role: UserRole::User,
         two_fa_enabled: true,
@@ -0,0 +1,229 @@
+use serde_json::Value;
+
+use super::inline::escape_html;
+use super::{
+    blocks, callout, checklist_items, header_level, image, link, list_items, str_field, table_rows,
+};
+
+/// Render EditorJS content to HTML, cleaned through ammonia's default
+/// allowlist. Inline-HTML fields pass through the sanitizer; text fields
+/// are escaped first.
+pub fn to_html(content: &Value) -> String {
+    ammonia::clean(&render(content, false))
+}
+
+/// [`to_html`] for AMP pages: image blocks become responsive `<amp-img>`
+/// elements and any other `<img>` is dropped, since AMP forbids it. The
+/// sanitizer already strips scripts, styles and iframes.
+pub fn to_amp_html(content: &Value) -> String {
+    ammonia::Builder::default()
+        .rm_tags(["img"])
+        .add_tags(["amp-img"])
+        .add_tag_attributes("amp-img", ["src", "alt", "width", "height", "layout"])
+        .clean(&render(content, true))
+        .to_string()
+}
+
+/// Image blocks without stored dimensions render at 16:9 on AMP.
+fn amp_dimensions(data: &Value) -> (u64, u64) {
+    let file = data.get("file").unwrap_or(&Value::Null);
+    let dim = |key: &str| file.get(key).and_then(|v| v.as_u64()).filter(|v| *v > 0);
+    dim("width").zip(dim("height")).unwrap_or((16, 9))
+}
+
+fn render(content: &Value, amp: bool) -> String {
+    let mut html = String::new();
+    for (typ, data) in blocks(content) {
+        match typ {
+            "paragraph" => html.push_str(&format!("<p>{}</p>", str_field(data, "text"))),
+            "header" => {
+                let level = header_level(data);
+                html.push_str(&format!(
+                    "<h{level}>{}</h{level}>",
+                    escape_html(str_field(data, "text"))
+                ));
+            }
+            "list" => {
+                let tag = if str_field(data, "style") == "ordered" {
+                    "ol"
+                } else {
+                    "ul"
+                };
+                html.push_str(&format!("<{tag}>"));
+                for item in list_items(data) {
+                    html.push_str(&format!("<li>{item}</li>"));
+                }
+                html.push_str(&format!("</{tag}>"));
+            }
+            "checklist" => {
+                html.push_str("<ul>");
+                for (checked, text) in checklist_items(data) {
+                    html.push_str(&format!(
+                        "<li>{} {}</li>",
+                        if checked { "☑" } else { "☐" },
+                        escape_html(text)
+                    ));
+                }
+                html.push_str("</ul>");
+            }
+            "quote" => {
+                html.push_str(&format!(
+                    "<blockquote><p>{}</p>",
+                    escape_html(str_field(data, "text"))
+                ));
+                let caption = str_field(data, "caption");
+                if !caption.is_empty() {
+                    html.push_str(&format!("<cite>{}</cite>", escape_html(caption)));
+                }
+                html.push_str("</blockquote>");
+            }
+            "code" => html.push_str(&format!(
+                "<pre><code>{}</code></pre>",
+                escape_html(str_field(data, "code"))
+            )),
+            "delimiter" => html.push_str("<hr>"),
+            "image" => {
+                let Some((url, alt, caption)) = image(data) else {
+                    continue;
+                };
+                if amp {
+                    let (width, height) = amp_dimensions(data);
+                    html.push_str(&format!(
+                        r#"<figure><amp-img src="{}" alt="{}" width="{width}" height="{height}" layout="responsive"></amp-img>"#,
+                        escape_html(url),
+                        escape_html(alt)
+                    ));
+                } else {
+                    html.push_str(&format!(
+                        r#"<figure><img src="{}" alt="{}">"#,
+                        escape_html(url),
+                        escape_html(alt)
+                    ));
+                }
+                if !caption.is_empty() {
+                    html.push_str(&format!(
+                        "<figcaption>{}</figcaption>",
+                        escape_html(caption)
+                    ));
+                }
+                html.push_str("</figure>");
+            }
+            "table" => {
+                html.push_str("<table>");
+                for row in table_rows(data) {
+                    html.push_str("<tr>");
+                    for cell in row {
+                        html.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    html.push_str("</tr>");
+                }
+                html.push_str("</table>");
+            }
+            "alert" | "warning" => {
+                let (title, message) = callout(typ, data);
+                html.push_str("<aside>");
+                if !title.is_empty() {
+                    html.push_str(&format!("<strong>{}</strong> ", escape_html(title)));
+                }
+                html.push_str(&format!("{}</aside>", escape_html(message)));
+            }
+            "embed" | "linktool" | "attaches" | "button" => {
+                if let Some((href, label)) = link(typ, data) {
+                    html.push_str(&format!(
+                        r#"<p><a href="{}">{}</a></p>"#,
+                        escape_html(&href),
+                        escape_html(&label)
+                    ));
+                }
+            }
+            "raw" => html.push_str(str_field(data, "html")),
+            _ => {}
+        }
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn escapes_text_blocks_and_sanitizes_html_blocks() {
+        let content = json!({
+            "blocks": [
+                { "type": "header", "data": { "text": "A <b>title</b>", "level": 2 } },
+                { "type": "paragraph", "data": { "text": "Hi <b>there</b><script>alert(1)</script>" } },
+                { "type": "list", "data": { "style": "ordered", "items": ["one", "<a href=\"javascript:x\">two</a>"] } },
+                { "type": "code", "data": { "code": "a < b" } },
+                { "type": "raw", "data": { "html": "<img src=x onerror=alert(1)>" } },
+                { "type": "delimiter", "data": {} }
+            ]
+        });
+        let html = to_html(&content);
+        assert!(html.contains("<h2>A &lt;b&gt;title&lt;/b&gt;</h2>"));
+        assert!(html.contains("<p>Hi <b>there</b></p>"));
+        assert!(!html.contains("script"));
+        assert!(html.contains("<ol><li>one</li>"));
+        assert!(!html.contains("javascript"));
+        assert!(html.contains("<pre><code>a &lt; b</code></pre>"));
+        assert!(!html.contains("onerror"));
+        assert!(html.contains("<hr>"));
+    }
+
+    #[test]
+    fn renders_images_with_captions() {
+        let content = json!({
+            "blocks": [
+                { "type": "image", "data": {
+                    "file": { "url": "https://cdn.example.com/p.png", "alt": "A plot" },
+                    "caption": "Figure 1"
+                } }
+            ]
+        });
+        let html = to_html(&content);
+        assert!(html.contains(r#"<img src="https://cdn.example.com/p.png" alt="A plot">"#));
+        assert!(html.contains("<figcaption>Figure 1</figcaption>"));
+    }
+
+    #[test]
+    fn renders_link_blocks_and_nested_list_items() {
+        let content = json!({
+            "blocks": [
+                { "type": "attaches", "data": { "file": { "url": "https://cdn.example.com/r.pdf", "name": "r.pdf" } } },
+                { "type": "list", "data": { "style": "unordered", "items": [{ "content": "nested", "items": [] }] } }
+            ]
+        });
+        let html = to_html(&content);
+        assert!(html.contains(r#"href="https://cdn.example.com/r.pdf""#));
+        assert!(html.contains(">r.pdf</a>"));
+        assert!(html.contains("<ul><li>nested</li></ul>"));
+    }
+
+    #[test]
+    fn amp_output_swaps_images_for_amp_img() {
+        let content = json!({
+            "blocks": [
+                { "type": "image", "data": {
+                    "file": { "url": "https://cdn.example.com/p.png", "width": 1200, "height": 800 }
+                } },
+                { "type": "image", "data": { "file": { "url": "https://cdn.example.com/q.png" } } },
+                { "type": "paragraph", "data": { "text": "x <img src=\"https://cdn.example.com/i.png\">" } },
+                { "type": "raw", "data": { "html": "<iframe src=\"https://x\"></iframe><amp-img src=\"javascript:x\"></amp-img>" } }
+            ]
+        });
+        let html = to_amp_html(&content);
+        assert!(html.contains(
+            r#"<amp-img src="https://cdn.example.com/p.png" alt="" width="1200" height="800" layout="responsive"></amp-img>"#
+        ));
+        assert!(html.contains(r#"width="16" height="9""#));
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("iframe"));
+        assert!(!html.contains("javascript"));
+    }
+
+    #[test]
+    fn non_editorjs_value_is_empty() {
+        assert_eq!(to_html(&json!({ "not": "blocks" })), "");
+    }
+}
@@ -0,0 +1,270 @@
+//! The inline HTML EditorJS stores in paragraphs and list items: a flat run
+//! of text and `<b>`/`<i>`/`<a>`/`<code>`/`<br>`-style tags, converted to
+//! Markdown or plain text without a full HTML parser.
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Backslash-escape the characters that start inline Markdown constructs.
+pub fn escape_markdown(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        if matches!(
+            ch,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|'
+        ) {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Decode the character references EditorJS emits (named basics and
+/// numeric). Unknown references are kept verbatim.
+pub fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let ch = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => name
+                    .strip_prefix("#x")
+                    .or_else(|| name.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| name.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, end))
+        });
+        match decoded {
+            Some((ch, end)) => {
+                out.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: String, attrs: &'a str },
+    Close(String),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut out = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            out.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            out.push(Token::Text(&rest[..start]));
+        }
+        let Some(len) = rest[start..].find('>') else {
+            // A stray `<` with no closing bracket is text.
+            out.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name_len = tag
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(tag.len());
+        let name = tag[..name_len].to_ascii_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+        if closing {
+            out.push(Token::Close(name));
+        } else {
+            out.push(Token::Open {
+                name,
+                attrs: &tag[name_len..],
+            });
+        }
+    }
+    out
+}
+
+/// Value of `name="…"` / `name='…'` in a tag's attribute text.
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    let lower = attrs.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find(name) {
+        let at = from + pos;
+        from = at + name.len();
+        let boundary = at == 0 || lower.as_bytes()[at - 1].is_ascii_whitespace();
+        let after = lower[from..].trim_start();
+        if !boundary || !after.starts_with('=') {
+            continue;
+        }
+        let value = attrs[attrs.len() - after.len() + 1..].trim_start();
+        let quote = value.chars().next()?;
+        let value = if quote == '"' || quote == '\'' {
+            let inner = &value[1..];
+            &inner[..inner.find(quote).unwrap_or(inner.len())]
+        } else {
+            &value[..value.find(char::is_whitespace).unwrap_or(value.len())]
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+pub fn to_text(html: &str) -> String {
+    let mut out = String::new();
+    for token in tokens(html) {
+        match token {
+            Token::Text(text) => out.push_str(&decode_entities(text)),
+            Token::Open { name, .. } if name == "br" => out.push('\n'),
+            _ => {}
+        }
+    }
+    out
+}
+
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<Option<String>> = Vec::new();
+    let mut in_code = false;
+    for token in tokens(html) {
+        match token {
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                if in_code {
+                    out.push_str(&text);
+                } else {
+                    out.push_str(&escape_markdown(&text));
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "b" | "strong" => out.push_str("**"),
+                "i" | "em" => out.push('_'),
+                "code" => {
+                    in_code = true;
+                    out.push('`');
+                }
+                "br" => out.push_str("\\\n"),
+                "a" => {
+                    let href = attr(attrs, "href").filter(|h| is_safe_url(h));
+                    if href.is_some() {
+                        out.push('[');
+                    }
+                    links.push(href);
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "b" | "strong" => out.push_str("**"),
+                "i" | "em" => out.push('_'),
+                "code" => {
+                    in_code = false;
+                    out.push('`');
+                }
+                "a" => {
+                    if let Some(Some(href)) = links.pop() {
+                        out.push_str(&format!("]({})", markdown_url(&href)));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    out
+}
+
+/// Link targets allowed into Markdown output. HTML output relies on ammonia;
+/// Markdown has no sanitizer downstream, so script-capable schemes are
+/// dropped here and the link text kept.
+pub fn is_safe_url(url: &str) -> bool {
+    let lower = url.trim().to_ascii_lowercase();
+    match lower.find(':') {
+        Some(colon) if !lower[..colon].contains('/') => {
+            matches!(&lower[..colon], "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+/// Wrap a URL in `<…>` when it would otherwise break a Markdown link.
+pub fn markdown_url(url: &str) -> String {
+    if url.contains([' ', '(', ')']) {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_named_and_numeric_entities() {
+        assert_eq!(
+            decode_entities("a &amp; b &lt;c&gt; &#39;d&#x27; &copy;"),
+            "a & b <c> 'd' &copy;"
+        );
+        assert_eq!(decode_entities("AT&T"), "AT&T");
+    }
+
+    #[test]
+    fn text_strips_tags_and_keeps_breaks() {
+        assert_eq!(
+            to_text("Hi <b>there</b><br>next &amp; <a href=\"/x\">last</a>"),
+            "Hi there\nnext & last"
+        );
+        assert_eq!(to_text("1 < 2"), "1 < 2");
+    }
+
+    #[test]
+    fn markdown_converts_inline_formatting() {
+        assert_eq!(
+            to_markdown(
+                "<b>bold</b>, <i>it</i>, <code>a*b</code> and <a href=\"https://x.dev/a b\">link</a>"
+            ),
+            "**bold**, _it_, `a*b` and [link](<https://x.dev/a b>)"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_text_and_drops_unsafe_links() {
+        assert_eq!(to_markdown("2 * 3 = [six]"), "2 \\* 3 = \\[six\\]");
+        assert_eq!(
+            to_markdown("<a href=\"javascript:alert(1)\">click</a>"),
+            "click"
+        );
+        assert_eq!(
+            to_markdown("<a href='/relative'>rel</a>"),
+            "[rel](/relative)"
+        );
+    }
+}
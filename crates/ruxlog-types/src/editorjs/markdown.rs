@@ -0,0 +1,199 @@
+use serde_json::Value;
+
+use super::inline::{self, escape_html, escape_markdown, is_safe_url, markdown_url};
+use super::{
+    blocks, callout, checklist_items, header_level, image, link, list_items, str_field, table_rows,
+};
+
+/// Fence for a code block: one backtick longer than the longest run inside.
+fn fence(code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat((longest + 1).max(3))
+}
+
+fn quote_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render EditorJS content to CommonMark. Tables have no CommonMark syntax
+/// and are emitted as HTML blocks; `raw` blocks are sanitized with ammonia
+/// and passed through the same way.
+pub fn to_markdown(content: &Value) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for (typ, data) in blocks(content) {
+        let part = match typ {
+            "paragraph" => inline::to_markdown(str_field(data, "text")),
+            "header" => format!(
+                "{} {}",
+                "#".repeat(header_level(data)),
+                escape_markdown(str_field(data, "text"))
+            ),
+            "list" => {
+                let ordered = str_field(data, "style") == "ordered";
+                list_items(data)
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let marker = if ordered {
+                            format!("{}.", i + 1)
+                        } else {
+                            "-".to_string()
+                        };
+                        format!("{marker} {}", inline::to_markdown(item))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            "checklist" => checklist_items(data)
+                .map(|(checked, text)| {
+                    format!(
+                        "- [{}] {}",
+                        if checked { "x" } else { " " },
+                        escape_markdown(text)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "quote" => {
+                let mut quote = quote_lines(&escape_markdown(str_field(data, "text")));
+                let caption = str_field(data, "caption");
+                if !caption.is_empty() {
+                    quote.push_str(&format!("\n>\n> — {}", escape_markdown(caption)));
+                }
+                quote
+            }
+            "code" => {
+                let code = str_field(data, "code");
+                let fence = fence(code);
+                format!("{fence}\n{code}\n{fence}")
+            }
+            "delimiter" => "---".to_string(),
+            "image" => {
+                let Some((url, alt, caption)) = image(data) else {
+                    continue;
+                };
+                if !is_safe_url(url) {
+                    continue;
+                }
+                let mut image = format!("![{}]({})", escape_markdown(alt), markdown_url(url));
+                if !caption.is_empty() {
+                    image.push_str(&format!("\n_{}_", escape_markdown(caption)));
+                }
+                image
+            }
+            "table" => {
+                let rows: String = table_rows(data)
+                    .map(|row| {
+                        let cells: String = row
+                            .iter()
+                            .map(|cell| format!("<td>{}</td>", escape_html(cell)))
+                            .collect();
+                        format!("<tr>{cells}</tr>")
+                    })
+                    .collect();
+                format!("<table>{rows}</table>")
+            }
+            "alert" | "warning" => {
+                let (title, message) = callout(typ, data);
+                let text = if title.is_empty() {
+                    escape_markdown(message)
+                } else {
+                    format!(
+                        "**{}** {}",
+                        escape_markdown(title),
+                        escape_markdown(message)
+                    )
+                };
+                quote_lines(&text)
+            }
+            "embed" | "linktool" | "attaches" | "button" => match link(typ, data) {
+                Some((href, label)) if is_safe_url(&href) => {
+                    format!("[{}]({})", escape_markdown(&label), markdown_url(&href))
+                }
+                _ => continue,
+            },
+            "raw" => ammonia::clean(str_field(data, "html")),
+            _ => continue,
+        };
+        if !part.trim().is_empty() {
+            parts.push(part);
+        }
+    }
+
+    let mut out = parts.join("\n\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_common_blocks() {
+        let content = json!({
+            "blocks": [
+                { "type": "header", "data": { "text": "Intro_1", "level": 3 } },
+                { "type": "paragraph", "data": { "text": "Hello <b>world</b>" } },
+                { "type": "list", "data": { "style": "ordered", "items": ["one", "<i>two</i>"] } },
+                { "type": "checklist", "data": { "items": [
+                    { "text": "done", "checked": true },
+                    { "text": "todo", "checked": false }
+                ] } },
+                { "type": "quote", "data": { "text": "Be brief", "caption": "Someone" } },
+                { "type": "delimiter", "data": {} }
+            ]
+        });
+        assert_eq!(
+            to_markdown(&content),
+            "### Intro\\_1\n\n\
+             Hello **world**\n\n\
+             1. one\n2. _two_\n\n\
+             - [x] done\n- [ ] todo\n\n\
+             > Be brief\n>\n> — Someone\n\n\
+             ---\n"
+        );
+    }
+
+    #[test]
+    fn code_fence_outgrows_backticks_in_the_code() {
+        let content = json!({
+            "blocks": [{ "type": "code", "data": { "code": "let s = \"````\";" } }]
+        });
+        assert_eq!(to_markdown(&content), "`````\nlet s = \"````\";\n`````\n");
+    }
+
+    #[test]
+    fn tables_and_raw_html_become_sanitized_html_blocks() {
+        let content = json!({
+            "blocks": [
+                { "type": "table", "data": { "content": [["a", "<b>"]] } },
+                { "type": "raw", "data": { "html": "<em>ok</em><script>x()</script>" } }
+            ]
+        });
+        assert_eq!(
+            to_markdown(&content),
+            "<table><tr><td>a</td><td>&lt;b&gt;</td></tr></table>\n\n<em>ok</em>\n"
+        );
+    }
+
+    #[test]
+    fn images_and_links_skip_unsafe_urls() {
+        let content = json!({
+            "blocks": [
+                { "type": "image", "data": { "file": { "url": "https://cdn.example.com/p.png" }, "caption": "Plot" } },
+                { "type": "button", "data": { "text": "Go", "link": "javascript:alert(1)" } },
+                { "type": "linktool", "data": { "link": "https://rust-lang.org", "meta": { "title": "Rust" } } }
+            ]
+        });
+        assert_eq!(
+            to_markdown(&content),
+            "![Plot](https://cdn.example.com/p.png)\n_Plot_\n\n[Rust](https://rust-lang.org)\n"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Server-side rendering of stored EditorJS content to sanitized HTML (plain
+//! or AMP), CommonMark and plain text.
+//!
+//! Works on the raw `serde_json::Value` the backend stores, so callers don't
+//! need the frontend block types. Block fields follow the frontend
+//! renderers: `paragraph.text`, `list.items[]` and `raw.html` hold inline
+//! HTML; everything else (headers, quotes, code, tables, captions) is
+//! literal text. Unknown block types are skipped.
+
+mod html;
+mod inline;
+mod markdown;
+mod text;
+
+use serde_json::Value;
+
+pub use html::{to_amp_html, to_html};
+pub use inline::{escape_html, escape_markdown};
+pub use markdown::to_markdown;
+pub use text::{summary, to_plain_text};
+
+/// `(type, data)` for every block, in document order.
+fn blocks(content: &Value) -> impl Iterator<Item = (&str, &Value)> {
+    content
+        .get("blocks")
+        .and_then(|b| b.as_array())
+        .into_iter()
+        .flatten()
+        .map(|block| {
+            let typ = block.get("type").and_then(|t| t.as_str()).unwrap_or("");
+            (typ, block.get("data").unwrap_or(&Value::Null))
+        })
+}
+
+fn str_field<'a>(data: &'a Value, key: &str) -> &'a str {
+    data.get(key).and_then(|v| v.as_str()).unwrap_or("")
+}
+
+/// List items are plain strings, or `{ content }` objects from the nested
+/// list tool.
+fn list_items(data: &Value) -> impl Iterator<Item = &str> {
+    data.get("items")
+        .and_then(|i| i.as_array())
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            item.as_str()
+                .or_else(|| item.get("content").and_then(|c| c.as_str()))
+                .unwrap_or("")
+        })
+}
+
+fn checklist_items(data: &Value) -> impl Iterator<Item = (bool, &str)> {
+    data.get("items")
+        .and_then(|i| i.as_array())
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            let checked = item
+                .get("checked")
+                .and_then(|c| c.as_bool())
+                .unwrap_or(false);
+            (checked, str_field(item, "text"))
+        })
+}
+
+fn table_rows(data: &Value) -> impl Iterator<Item = Vec<&str>> {
+    data.get("content")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .map(|row| {
+            row.as_array()
+                .into_iter()
+                .flatten()
+                .map(|cell| cell.as_str().unwrap_or(""))
+                .collect()
+        })
+}
+
+fn header_level(data: &Value) -> usize {
+    data.get("level")
+        .and_then(|l| l.as_u64())
+        .unwrap_or(2)
+        .clamp(1, 6) as usize
+}
+
+/// `(url, alt, caption)` of an image block, or `None` without a URL.
+fn image(data: &Value) -> Option<(&str, &str, &str)> {
+    let file = data.get("file")?;
+    let url = str_field(file, "url");
+    if url.is_empty() {
+        return None;
+    }
+    let caption = str_field(data, "caption");
+    let alt = file.get("alt").and_then(|a| a.as_str()).unwrap_or(caption);
+    Some((url, alt, caption))
+}
+
+/// `(href, label)` for blocks that boil down to a link: embeds, link
+/// previews, attachments and buttons. The label falls back to the URL.
+fn link(typ: &str, data: &Value) -> Option<(String, String)> {
+    let (href, label) = match typ {
+        "embed" => (str_field(data, "source"), str_field(data, "caption")),
+        "linktool" => (
+            str_field(data, "link"),
+            data.get("meta")
+                .map(|m| str_field(m, "title"))
+                .unwrap_or(""),
+        ),
+        "attaches" => {
+            let file = data.get("file").unwrap_or(&Value::Null);
+            let title = match str_field(data, "title") {
+                "" => str_field(file, "name"),
+                title => title,
+            };
+            (str_field(file, "url"), title)
+        }
+        "button" => (str_field(data, "link"), str_field(data, "text")),
+        _ => return None,
+    };
+    if href.is_empty() {
+        return None;
+    }
+    let label = if label.is_empty() { href } else { label };
+    Some((href.to_string(), label.to_string()))
+}
+
+/// `(title, message)` of an alert or warning; alerts have no title.
+fn callout<'a>(typ: &str, data: &'a Value) -> (&'a str, &'a str) {
+    match typ {
+        "warning" => (str_field(data, "title"), str_field(data, "message")),
+        _ => ("", str_field(data, "message")),
+    }
+}
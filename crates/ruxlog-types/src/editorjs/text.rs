@@ -0,0 +1,162 @@
+use serde_json::Value;
+
+use super::inline;
+use super::{blocks, callout, checklist_items, image, link, list_items, str_field, table_rows};
+
+/// Render EditorJS content to plain text, one paragraph per block: for
+/// email text parts, search indexing and anywhere markup isn't wanted.
+pub fn to_plain_text(content: &Value) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for (typ, data) in blocks(content) {
+        let part = match typ {
+            "paragraph" => inline::to_text(str_field(data, "text")),
+            "header" => str_field(data, "text").to_string(),
+            "code" => str_field(data, "code").to_string(),
+            "list" => {
+                let ordered = str_field(data, "style") == "ordered";
+                list_items(data)
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let marker = if ordered {
+                            format!("{}.", i + 1)
+                        } else {
+                            "-".to_string()
+                        };
+                        format!("{marker} {}", inline::to_text(item))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            "checklist" => checklist_items(data)
+                .map(|(checked, text)| format!("[{}] {text}", if checked { "x" } else { " " }))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "quote" => {
+                let caption = str_field(data, "caption");
+                if caption.is_empty() {
+                    str_field(data, "text").to_string()
+                } else {
+                    format!("{}\n— {caption}", str_field(data, "text"))
+                }
+            }
+            "delimiter" => "---".to_string(),
+            "image" => image(data)
+                .map(|(_, _, caption)| caption.to_string())
+                .unwrap_or_default(),
+            "table" => table_rows(data)
+                .map(|row| row.join("\t"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "alert" | "warning" => match callout(typ, data) {
+                ("", message) => message.to_string(),
+                (title, message) => format!("{title}: {message}"),
+            },
+            "embed" | "linktool" | "attaches" | "button" => match link(typ, data) {
+                Some((href, label)) if href == label => href,
+                Some((href, label)) => format!("{label} ({href})"),
+                None => continue,
+            },
+            "raw" => inline::to_text(&ammonia::clean(str_field(data, "html"))),
+            _ => continue,
+        };
+        let part = part.trim();
+        if !part.is_empty() {
+            parts.push(part.to_string());
+        }
+    }
+    parts.join("\n\n")
+}
+
+/// The words of a block for a one-line digest: list-like blocks join their
+/// items with commas, and captions, links and markup are left out.
+fn digest_text(typ: &str, data: &Value) -> String {
+    match typ {
+        "paragraph" => inline::to_text(str_field(data, "text")),
+        "header" | "quote" => str_field(data, "text").to_string(),
+        "code" => str_field(data, "code").to_string(),
+        "alert" | "warning" => callout(typ, data).1.to_string(),
+        "list" => list_items(data)
+            .map(inline::to_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        "checklist" => checklist_items(data)
+            .map(|(_, text)| text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => String::new(),
+    }
+}
+
+/// Short single-line summary of the text blocks, at most `max_len`
+/// characters. Empty when the content has no text.
+pub fn summary(content: &Value, max_len: usize) -> String {
+    let mut out = String::new();
+    for (typ, data) in blocks(content) {
+        let text = digest_text(typ, data);
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&text);
+        if out.len() >= max_len {
+            break;
+        }
+    }
+    out.chars().take(max_len).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn plain_text_strips_markup_and_separates_blocks() {
+        let content = json!({
+            "blocks": [
+                { "type": "header", "data": { "text": "Title", "level": 1 } },
+                { "type": "paragraph", "data": { "text": "Fish &amp; <b>chips</b>" } },
+                { "type": "list", "data": { "style": "unordered", "items": ["a", "<i>b</i>"] } },
+                { "type": "image", "data": { "file": { "url": "https://x/y.png" }, "caption": "" } },
+                { "type": "warning", "data": { "title": "Careful", "message": "Hot" } },
+                { "type": "linktool", "data": { "link": "https://rust-lang.org", "meta": { "title": "Rust" } } }
+            ]
+        });
+        assert_eq!(
+            to_plain_text(&content),
+            "Title\n\nFish & chips\n\n- a\n- b\n\nCareful: Hot\n\nRust (https://rust-lang.org)"
+        );
+    }
+
+    #[test]
+    fn plain_text_of_raw_html_drops_scripts() {
+        let content = json!({
+            "blocks": [{ "type": "raw", "data": { "html": "<p>Shown</p><script>hidden()</script>" } }]
+        });
+        assert_eq!(to_plain_text(&content), "Shown");
+    }
+
+    #[test]
+    fn summary_joins_text_blocks_on_one_line() {
+        let content = json!({
+            "blocks": [
+                { "type": "header", "data": { "text": "Title" } },
+                { "type": "paragraph", "data": { "text": "Line<br>break <b>bold</b>" } },
+                { "type": "image", "data": { "file": { "url": "https://x/y.png" }, "caption": "Skipped" } },
+                { "type": "list", "data": { "items": ["one", "two"] } }
+            ]
+        });
+        assert_eq!(summary(&content, 100), "Title Line break bold one, two");
+        assert_eq!(summary(&content, 9), "Title Lin");
+    }
+
+    #[test]
+    fn summary_of_content_without_text_is_empty() {
+        let content = json!({ "blocks": [{ "type": "delimiter", "data": {} }] });
+        assert_eq!(summary(&content, 100), "");
+        assert_eq!(summary(&json!({ "not": "blocks" }), 100), "");
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Kind of a first-party analytics event sent by the consumer site.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalyticsEventKind {
+    #[default]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "pageview"))]
+    Pageview,
+    /// Heartbeat while a post is open: active reading time since the last
+    /// heartbeat and the deepest scroll position reached.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "engagement"))]
+    Engagement,
+}
+
+impl AnalyticsEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalyticsEventKind::Pageview => "pageview",
+            AnalyticsEventKind::Engagement => "engagement",
+        }
+    }
+}
+
+impl fmt::Display for AnalyticsEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Coarse device family derived from the User-Agent at ingest.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviceClass {
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "desktop"))]
+    Desktop,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "mobile"))]
+    Mobile,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "tablet"))]
+    Tablet,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "unknown"))]
+    Unknown,
+}
+
+impl DeviceClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceClass::Desktop => "desktop",
+            DeviceClass::Mobile => "mobile",
+            DeviceClass::Tablet => "tablet",
+            DeviceClass::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for DeviceClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// What a blocklist entry's pattern is matched against.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlocklistKind {
+    /// A word or phrase, matched case-insensitively on word boundaries in
+    /// comments and user names.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "word"))]
+    Word,
+    /// A regular expression checked against comments and user names.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "regex"))]
+    Regex,
+    /// A domain (and its subdomains) that comments may not link to.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "domain"))]
+    Domain,
+    /// An email domain (and its subdomains) that cannot register.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "email_domain"))]
+    EmailDomain,
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// Moderation state of a post comment. Only `Approved` comments are shown on
+/// the public site.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStatus {
+    /// Held in the moderation queue until an admin approves or rejects it.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "pending"))]
+    Pending,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "approved"))]
+    #[default]
+    Approved,
+    /// Rejected by a moderator or by the spam scorer.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "rejected"))]
+    Rejected,
+}
+
+/// Emoji reaction on a comment. Likes are tracked separately (see
+/// `post_comments.likes_count`); these are the extra reactions.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentReaction {
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "love"))]
+    Love,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "laugh"))]
+    Laugh,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "wow"))]
+    Wow,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "sad"))]
+    Sad,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "celebrate"))]
+    Celebrate,
+}
+
+impl CommentReaction {
+    pub const ALL: [CommentReaction; 5] = [
+        CommentReaction::Love,
+        CommentReaction::Laugh,
+        CommentReaction::Wow,
+        CommentReaction::Sad,
+        CommentReaction::Celebrate,
+    ];
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            CommentReaction::Love => "\u{2764}\u{fe0f}",
+            CommentReaction::Laugh => "\u{1f602}",
+            CommentReaction::Wow => "\u{1f62e}",
+            CommentReaction::Sad => "\u{1f622}",
+            CommentReaction::Celebrate => "\u{1f389}",
+        }
+    }
+}
@@ -100,3 +100,148 @@ impl EntityType {
         }
     }
 }
+
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(
+    feature = "backend",
+    sea_orm(
+        rs_type = "String",
+        db_type = "Enum",
+        enum_name = "media_upload_status"
+    )
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaUploadStatus {
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "pending"))]
+    Pending,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "completed"))]
+    Completed,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "aborted"))]
+    Aborted,
+}
+
+impl MediaUploadStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaUploadStatus::Pending => "pending",
+            MediaUploadStatus::Completed => "completed",
+            MediaUploadStatus::Aborted => "aborted",
+        }
+    }
+}
+
+impl fmt::Display for MediaUploadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(
+    feature = "backend",
+    sea_orm(rs_type = "String", db_type = "Enum", enum_name = "media_job_status")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaJobStatus {
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "pending"))]
+    Pending,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "running"))]
+    Running,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "completed"))]
+    Completed,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "skipped"))]
+    Skipped,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "failed"))]
+    Failed,
+}
+
+impl MediaJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaJobStatus::Pending => "pending",
+            MediaJobStatus::Running => "running",
+            MediaJobStatus::Completed => "completed",
+            MediaJobStatus::Skipped => "skipped",
+            MediaJobStatus::Failed => "failed",
+        }
+    }
+
+    /// Whether the job is still waiting for or holding a worker.
+    pub fn is_active(&self) -> bool {
+        matches!(self, MediaJobStatus::Pending | MediaJobStatus::Running)
+    }
+}
+
+impl fmt::Display for MediaJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Broad family of an upload, derived from its MIME type. Decides which
+/// pipeline (image optimizer, media probe) and which size limit applies, and
+/// which player a client renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaKind {
+    Image,
+    Video,
+    Audio,
+    Document,
+}
+
+impl MediaKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaKind::Image => "image",
+            MediaKind::Video => "video",
+            MediaKind::Audio => "audio",
+            MediaKind::Document => "document",
+        }
+    }
+
+    /// Kind of `mime` (case-insensitive, parameters ignored). Only PDF counts
+    /// as a document.
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        let mime = mime.split(';').next().unwrap_or("").trim();
+        let (top, sub) = mime.split_once('/')?;
+        match top.to_ascii_lowercase().as_str() {
+            "image" => Some(MediaKind::Image),
+            "video" => Some(MediaKind::Video),
+            "audio" => Some(MediaKind::Audio),
+            "application" if sub.eq_ignore_ascii_case("pdf") => Some(MediaKind::Document),
+            _ => None,
+        }
+    }
+
+    /// Kind of a file extension (leading dot optional), for clients that only
+    /// have a file name.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext
+            .trim()
+            .trim_start_matches('.')
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "jpg" | "jpeg" | "png" | "webp" | "gif" | "tiff" | "avif" => Some(MediaKind::Image),
+            "mp4" | "m4v" | "webm" => Some(MediaKind::Video),
+            "mp3" | "ogg" | "oga" | "opus" => Some(MediaKind::Audio),
+            "pdf" => Some(MediaKind::Document),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MediaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,11 +1,25 @@
+pub mod analytics;
 pub mod billing;
+pub mod blocklist;
+pub mod comment;
 pub mod media;
 pub mod newsletter;
+pub mod notification;
 pub mod post;
+pub mod redirect;
+pub mod seo;
 pub mod user;
+pub mod webhook;
 
+pub use analytics::*;
 pub use billing::*;
+pub use blocklist::*;
+pub use comment::*;
 pub use media::*;
 pub use newsletter::*;
+pub use notification::*;
 pub use post::*;
+pub use redirect::*;
+pub use seo::*;
 pub use user::*;
+pub use webhook::*;
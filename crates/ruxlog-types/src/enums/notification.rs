@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Something a user can be notified about. The comment kinds go to the
+/// people involved in a conversation; the rest go to admins.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A new comment on a post the user wrote or co-wrote.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "comment_on_post"))]
+    CommentOnPost,
+    /// A reply to one of the user's comments.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "comment_reply"))]
+    CommentReply,
+    /// A comment mentioning the user's `@profile_slug`.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "comment_mention"))]
+    CommentMention,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "new_user"))]
+    NewUser,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "payment_received"))]
+    PaymentReceived,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "subscription_canceled"))]
+    SubscriptionCanceled,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "newsletter_subscriber"))]
+    NewsletterSubscriber,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "contact_form"))]
+    ContactForm,
+}
+
+impl NotificationKind {
+    /// Kinds only sent to admins.
+    pub fn is_admin_only(&self) -> bool {
+        !matches!(
+            self,
+            NotificationKind::CommentOnPost
+                | NotificationKind::CommentReply
+                | NotificationKind::CommentMention
+        )
+    }
+}
+
+/// When notification emails go out.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailDelivery {
+    /// One email per notification, sent right away.
+    #[default]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "immediate"))]
+    Immediate,
+    /// Notifications are collected and sent together once a day.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "digest"))]
+    Digest,
+}
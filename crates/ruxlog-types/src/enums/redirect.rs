@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// How a redirect's `source_path` is compared with a requested path.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedirectMatchType {
+    /// The whole path must equal `source_path`.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "exact"))]
+    Exact,
+    /// Any path under `source_path`; the remainder is appended to the target.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "prefix"))]
+    Prefix,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedirectStatus {
+    /// 301 Moved Permanently.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "permanent"))]
+    Permanent,
+    /// 302 Found.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "temporary"))]
+    Temporary,
+    /// 410 Gone: the content was removed and has no replacement.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "gone"))]
+    Gone,
+}
+
+impl RedirectStatus {
+    pub fn status_code(&self) -> u16 {
+        match self {
+            RedirectStatus::Permanent => 301,
+            RedirectStatus::Temporary => 302,
+            RedirectStatus::Gone => 410,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Content that can carry per-item SEO overrides.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeoEntityType {
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "post"))]
+    Post,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "category"))]
+    Category,
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "tag"))]
+    Tag,
+}
+
+impl SeoEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeoEntityType::Post => "post",
+            SeoEntityType::Category => "category",
+            SeoEntityType::Tag => "tag",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "post" => Ok(SeoEntityType::Post),
+            "category" => Ok(SeoEntityType::Category),
+            "tag" => Ok(SeoEntityType::Tag),
+            _ => Err(format!("Invalid SEO entity type: {}", value)),
+        }
+    }
+}
@@ -82,3 +82,22 @@ impl From<UserRole> for i32 {
 }
 
 // `Default` is derived on `UserRole` above (`#[default] User`).
+
+/// Whether a user ban blocks the account outright or only hides it.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UserBanKind {
+    /// The user cannot log in.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "full"))]
+    #[default]
+    Full,
+    /// The user can still log in and comment, but their comments are only
+    /// shown to themselves.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "shadow"))]
+    Shadow,
+}
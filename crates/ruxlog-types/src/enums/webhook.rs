@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// A site event that outgoing webhook endpoints can subscribe to. The
+/// serialized name is the `type` of the delivered payload.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookEvent {
+    /// A post went live, on creation, update or from the scheduler.
+    #[serde(rename = "post.published")]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "post.published"))]
+    PostPublished,
+    /// A comment became visible, on creation or when a moderator approved it.
+    #[serde(rename = "comment.created")]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "comment.created"))]
+    CommentCreated,
+    #[serde(rename = "user.registered")]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "user.registered"))]
+    UserRegistered,
+    #[serde(rename = "payment.succeeded")]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "payment.succeeded"))]
+    PaymentSucceeded,
+    #[serde(rename = "subscription.canceled")]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "subscription.canceled"))]
+    SubscriptionCanceled,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 5] = [
+        WebhookEvent::PostPublished,
+        WebhookEvent::CommentCreated,
+        WebhookEvent::UserRegistered,
+        WebhookEvent::PaymentSucceeded,
+        WebhookEvent::SubscriptionCanceled,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::PostPublished => "post.published",
+            WebhookEvent::CommentCreated => "comment.created",
+            WebhookEvent::UserRegistered => "user.registered",
+            WebhookEvent::PaymentSucceeded => "payment.succeeded",
+            WebhookEvent::SubscriptionCanceled => "subscription.canceled",
+        }
+    }
+}
+
+/// Where a webhook delivery stands.
+#[cfg_attr(
+    feature = "backend",
+    derive(sea_orm::DeriveActiveEnum, strum::EnumIter)
+)]
+#[cfg_attr(feature = "backend", sea_orm(rs_type = "String", db_type = "Text"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first attempt or a retry.
+    #[default]
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "pending"))]
+    Pending,
+    /// The endpoint answered with a 2xx status.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "succeeded"))]
+    Succeeded,
+    /// Every attempt failed; only a manual redelivery sends it again.
+    #[cfg_attr(feature = "backend", sea_orm(string_value = "failed"))]
+    Failed,
+}
@@ -4,5 +4,7 @@ pub mod pagination;
 pub mod query;
 pub mod types;
 
+#[cfg(feature = "editorjs")]
+pub mod editorjs;
 #[cfg(feature = "slug")]
 pub mod slug;
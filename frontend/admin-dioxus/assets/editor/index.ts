@@ -219,11 +219,12 @@ const mountEditor = async (): Promise<void> => {
                 const response = await window.editorjs_upload_file(file);
                 console.log('[EditorJS] Attachment upload response:', response);
 
-                // Attaches tool expects additional metadata
+                // Attaches tool reads name/size from `file` and the
+                // block title from the top level
                 return {
                   ...response,
+                  file: { name: file.name, size: file.size, ...response.file },
                   title: file.name,
-                  size: file.size,
                 };
               } catch (err) {
                 console.error('[EditorJS] Attachment upload failed:', err);
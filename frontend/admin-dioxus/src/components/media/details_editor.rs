@@ -0,0 +1,197 @@
+//! MediaDetailsEditor - Edit alt text, caption, credit/license and the focal
+//! point of a single media item. Embedded in the MediaPickerDialog.
+
+use dioxus::prelude::*;
+use hmziq_dioxus_free_icons::{icons::ld_icons::LdChevronLeft, Icon};
+use oxui::shadcn::button::{Button, ButtonVariant};
+use ruxlog_shared::store::{
+    media::{Media, MediaEditPayload},
+    use_media,
+};
+
+const FIELD_CLASS: &str = "w-full rounded-md border border-border/70 bg-transparent px-3 py-2 text-sm text-foreground placeholder:text-muted-foreground transition-colors duration-200 focus:border-ring focus:ring-2 focus:ring-ring/40";
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MediaDetailsEditorProps {
+    pub media: Media,
+    /// Return to the media list without saving
+    pub on_back: EventHandler<()>,
+    /// Called with the updated media after a successful save
+    pub on_saved: EventHandler<Media>,
+}
+
+#[component]
+pub fn MediaDetailsEditor(props: MediaDetailsEditorProps) -> Element {
+    let media_state = use_media();
+    let media = props.media.clone();
+    let media_id = media.id;
+
+    let mut alt_text = use_signal(|| media.alt_text.clone().unwrap_or_default());
+    let mut caption = use_signal(|| media.caption.clone().unwrap_or_default());
+    let mut credit = use_signal(|| media.credit.clone().unwrap_or_default());
+    let mut license = use_signal(|| media.license.clone().unwrap_or_default());
+    let had_focal_point = media.focal_x.is_some() && media.focal_y.is_some();
+    let mut has_focal_point = use_signal(|| had_focal_point);
+    let mut focal_x = use_signal(|| (media.focal_x.unwrap_or(0.5) * 100.0).round());
+    let mut focal_y = use_signal(|| (media.focal_y.unwrap_or(0.5) * 100.0).round());
+
+    let edit_frame = media_state.edit.read().get(&media_id).cloned();
+    let is_saving = edit_frame.as_ref().map(|f| f.is_loading()).unwrap_or(false);
+    let error = edit_frame
+        .as_ref()
+        .filter(|f| f.is_failed())
+        .and_then(|f| f.error_message());
+
+    let handle_save = move |_| {
+        let payload = MediaEditPayload {
+            alt_text: Some(alt_text()),
+            caption: Some(caption()),
+            credit: Some(credit()),
+            license: Some(license()),
+            focal_x: has_focal_point().then(|| focal_x() / 100.0),
+            focal_y: has_focal_point().then(|| focal_y() / 100.0),
+            clear_focal_point: had_focal_point && !has_focal_point(),
+        };
+        spawn(async move {
+            if let Some(updated) = media_state.edit(media_id, payload).await {
+                props.on_saved.call(updated);
+            }
+        });
+    };
+
+    let marker_style = format!("left: {}%; top: {}%;", focal_x(), focal_y());
+
+    rsx! {
+        div { class: "space-y-4",
+            button {
+                class: "flex items-center gap-1 text-sm text-base-content/60 hover:text-primary",
+                onclick: move |_| props.on_back.call(()),
+                Icon { icon: LdChevronLeft, width: 16, height: 16 }
+                "Back to media"
+            }
+
+            div { class: "grid gap-6 md:grid-cols-2",
+                div { class: "space-y-2",
+                    div { class: "relative inline-block max-w-full",
+                        img {
+                            src: "{media.file_url}",
+                            alt: "{alt_text}",
+                            class: "block max-h-72 max-w-full rounded",
+                        }
+                        if has_focal_point() {
+                            span {
+                                class: "absolute h-4 w-4 -translate-x-1/2 -translate-y-1/2 rounded-full border-2 border-white bg-primary shadow pointer-events-none",
+                                style: "{marker_style}",
+                            }
+                        }
+                    }
+
+                    label { class: "flex items-center gap-2 text-sm",
+                        input {
+                            r#type: "checkbox",
+                            checked: has_focal_point(),
+                            onchange: move |e| has_focal_point.set(e.checked()),
+                        }
+                        "Set focal point"
+                    }
+                    if has_focal_point() {
+                        div { class: "grid grid-cols-2 gap-3 text-sm",
+                            label { class: "space-y-1",
+                                span { "Horizontal ({focal_x}%)" }
+                                input {
+                                    r#type: "range",
+                                    class: "w-full",
+                                    min: 0,
+                                    max: 100,
+                                    value: "{focal_x}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<f64>() {
+                                            focal_x.set(val);
+                                        }
+                                    },
+                                }
+                            }
+                            label { class: "space-y-1",
+                                span { "Vertical ({focal_y}%)" }
+                                input {
+                                    r#type: "range",
+                                    class: "w-full",
+                                    min: 0,
+                                    max: 100,
+                                    value: "{focal_y}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<f64>() {
+                                            focal_y.set(val);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        p { class: "text-xs text-base-content/60",
+                            "Cropped variants keep this point in frame."
+                        }
+                    }
+                }
+
+                div { class: "space-y-3",
+                    label { class: "block space-y-1 text-sm",
+                        span { class: "font-medium", "Alt text" }
+                        textarea {
+                            class: "{FIELD_CLASS} h-20 resize-none",
+                            placeholder: "Describe the image for screen readers",
+                            maxlength: 500,
+                            value: alt_text(),
+                            oninput: move |e| alt_text.set(e.value()),
+                        }
+                    }
+                    label { class: "block space-y-1 text-sm",
+                        span { class: "font-medium", "Caption" }
+                        textarea {
+                            class: "{FIELD_CLASS} h-20 resize-none",
+                            maxlength: 2000,
+                            value: caption(),
+                            oninput: move |e| caption.set(e.value()),
+                        }
+                    }
+                    label { class: "block space-y-1 text-sm",
+                        span { class: "font-medium", "Credit" }
+                        input {
+                            class: FIELD_CLASS,
+                            placeholder: "Photographer or source",
+                            maxlength: 255,
+                            value: credit(),
+                            oninput: move |e| credit.set(e.value()),
+                        }
+                    }
+                    label { class: "block space-y-1 text-sm",
+                        span { class: "font-medium", "License" }
+                        input {
+                            class: FIELD_CLASS,
+                            placeholder: "e.g. CC BY 4.0",
+                            maxlength: 255,
+                            value: license(),
+                            oninput: move |e| license.set(e.value()),
+                        }
+                    }
+
+                    if let Some(message) = error {
+                        div { class: "alert alert-error text-sm", "{message}" }
+                    }
+
+                    div { class: "flex justify-end gap-2",
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            onclick: move |_| props.on_back.call(()),
+                            "Cancel"
+                        }
+                        Button {
+                            disabled: is_saving,
+                            onclick: handle_save,
+                            if is_saving { "Saving..." } else { "Save details" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod details_editor;
 pub mod picker_dialog;
 pub mod preview_item;
 pub mod upload_item;
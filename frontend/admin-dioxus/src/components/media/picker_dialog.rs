@@ -1,13 +1,16 @@
 //! MediaPickerDialog - A modal dialog for browsing and selecting media files
 //! or uploading new ones. Used by the RichTextEditor and other components.
+//! Each row can also be opened in the details editor to set alt text,
+//! caption, credit and focal point before picking it.
 
+use super::details_editor::MediaDetailsEditor;
 use super::upload_zone::MediaUploadZone;
 use crate::utils::dates::format_short_date_dt;
 use crate::utils::file_helpers::{format_file_size, is_image};
 use dioxus::prelude::*;
 use dioxus_time::sleep;
 use hmziq_dioxus_free_icons::{
-    icons::ld_icons::{LdChevronLeft, LdChevronRight, LdUpload, LdX},
+    icons::ld_icons::{LdChevronLeft, LdChevronRight, LdPencil, LdUpload, LdX},
     Icon,
 };
 use oxui::custom::portal::AppPortal;
@@ -40,6 +43,7 @@ pub fn MediaPickerDialog(props: MediaPickerDialogProps) -> Element {
     let mut filters = use_signal(MediaListQuery::new);
     let mut selected_ids = use_signal(Vec::<i32>::new);
     let mut upload_blob_urls = use_signal(Vec::<String>::new);
+    let mut editing = use_signal(|| None::<Media>);
 
     // Load media list when dialog opens
     use_effect({
@@ -184,7 +188,14 @@ pub fn MediaPickerDialog(props: MediaPickerDialogProps) -> Element {
                     div { class: "flex-1 overflow-y-auto p-4",
                         if current_tab() == "browse" {
                             // Browse tab
-                            if list_loading {
+                            if let Some(media) = editing() {
+                                MediaDetailsEditor {
+                                    key: "{media.id}",
+                                    media,
+                                    on_back: move |_| editing.set(None),
+                                    on_saved: move |_| editing.set(None),
+                                }
+                            } else if list_loading {
                                 div { class: "flex items-center justify-center py-12",
                                     span { class: "loading loading-spinner loading-lg" }
                                 }
@@ -209,12 +220,15 @@ pub fn MediaPickerDialog(props: MediaPickerDialogProps) -> Element {
                                                 th { "Type" }
                                                 th { "Size" }
                                                 th { "Uploaded" }
+                                                th { class: "w-12" }
                                             }
                                         }
                                         tbody {
                                             for media in items_vec.iter() {
                                                 {
                                                     let media_clone = media.clone();
+                                                    let media_for_edit = media.clone();
+                                                    let media_alt = media.alt_text.clone().unwrap_or_else(|| media.object_key.clone());
                                                     let media_id = media.id;
                                                     let media_mime = media.mime_type.clone();
                                                     let media_file_url = media.file_url.clone();
@@ -245,7 +259,7 @@ pub fn MediaPickerDialog(props: MediaPickerDialogProps) -> Element {
                                                                 if is_image(&media_mime) {
                                                                     img {
                                                                         src: "{media_file_url}",
-                                                                        alt: "{media_obj_key}",
+                                                                        alt: "{media_alt}",
                                                                         class: "w-12 h-12 object-cover rounded"
                                                                     }
                                                                 } else {
@@ -266,6 +280,17 @@ pub fn MediaPickerDialog(props: MediaPickerDialogProps) -> Element {
                                                             td { class: "py-2 px-3 text-sm text-base-content/60",
                                                                 "{format_short_date_dt(&media_created)}"
                                                             }
+                                                            td { class: "w-12 py-2 px-3",
+                                                                button {
+                                                                    class: "btn btn-ghost btn-xs btn-circle",
+                                                                    title: "Edit details",
+                                                                    onclick: move |e| {
+                                                                        e.stop_propagation();
+                                                                        editing.set(Some(media_for_edit.clone()));
+                                                                    },
+                                                                    Icon { icon: LdPencil, width: 14, height: 14 }
+                                                                }
+                                                            }
                                                         }
                                                     }
                                                 }
@@ -2,7 +2,9 @@ use crate::utils::file_helpers::validate_file_type;
 use dioxus::{logger::tracing, prelude::*};
 use hmziq_dioxus_free_icons::{icons::ld_icons::LdUpload, Icon};
 use oxui::shadcn::button::{Button, ButtonVariant};
-use ruxlog_shared::store::{media::MediaReference, media::MediaUploadPayload, use_media};
+use ruxlog_shared::store::{
+    media::MediaReference, media::MediaUploadPayload, media::DIRECT_UPLOAD_THRESHOLD, use_media,
+};
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 use wasm_bindgen::JsCast;
@@ -276,9 +278,15 @@ async fn process_files_async(
                 height: None,
             };
 
-            // Initiate upload (returns blob URL immediately)
+            // Initiate upload (returns blob URL immediately). Large files go
+            // straight to storage in resumable parts instead of one request.
             tracing::debug!("[process_files_async] Initiating upload for: {}", &filename);
-            match media_state.upload(payload).await {
+            let upload = if file_size > DIRECT_UPLOAD_THRESHOLD {
+                media_state.upload_resumable(payload).await
+            } else {
+                media_state.upload(payload).await
+            };
+            match upload {
                 Ok(blob_url) => {
                     tracing::debug!(
                         "[process_files_async] Upload initiated successfully, blob URL: {}",
@@ -7,6 +7,8 @@ pub mod registration_trend_chart;
 
 pub mod media_upload_trends_chart;
 pub mod page_views_chart;
+pub mod post_engagement_card;
+pub mod revenue_summary_cards;
 pub mod verification_rates_chart;
 
 // Filter components
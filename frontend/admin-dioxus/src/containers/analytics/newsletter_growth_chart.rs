@@ -4,8 +4,8 @@ use oxstore::StateFrameStatus;
 use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
 use oxui::components::loading_overlay::LoadingOverlay;
 use ruxlog_shared::store::{
-    use_analytics, AnalyticsEnvelope, AnalyticsEnvelopeResponse, AnalyticsInterval,
-    NewsletterGrowthFilters, NewsletterGrowthPoint, NewsletterGrowthRequest,
+    browser_timezone, use_analytics, AnalyticsEnvelope, AnalyticsEnvelopeResponse,
+    AnalyticsInterval, NewsletterGrowthFilters, NewsletterGrowthPoint, NewsletterGrowthRequest,
 };
 
 /// Props for `NewsletterGrowthChart`.
@@ -57,6 +57,7 @@ pub fn NewsletterGrowthChart(props: NewsletterGrowthChartProps) -> Element {
                     per_page: None,
                     sort_by: None,
                     sort_order: None,
+                    timezone: browser_timezone(),
                 },
                 filters: NewsletterGrowthFilters {
                     group_by: match interval {
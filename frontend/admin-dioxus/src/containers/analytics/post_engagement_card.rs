@@ -0,0 +1,136 @@
+use dioxus::prelude::*;
+
+use oxstore::{StateFrame, StateFrameStatus};
+use ruxlog_shared::store::analytics::{
+    AnalyticsEnvelopeResponse, PostEngagementData, PostEngagementRequest, PostTrafficPoint,
+};
+
+/// Reading engagement for a single post: headline metrics plus pageviews over
+/// time. Fed by `use_analytics().post_engagement`.
+#[derive(Props, PartialEq, Clone)]
+pub struct PostEngagementCardProps {
+    pub frame: StateFrame<AnalyticsEnvelopeResponse<PostEngagementData>, PostEngagementRequest>,
+    #[props(default = "Engagement".to_string())]
+    pub title: String,
+}
+
+#[component]
+pub fn PostEngagementCard(props: PostEngagementCardProps) -> Element {
+    let status = props.frame.status;
+    let body = match (status, props.frame.data.as_ref()) {
+        (StateFrameStatus::Init | StateFrameStatus::Loading, _) => rsx! {
+            div { class: "grid grid-cols-2 md:grid-cols-3 gap-3",
+                for i in 0..6 {
+                    div { key: "{i}", class: "h-16 rounded-md bg-muted/50 animate-pulse" }
+                }
+            }
+        },
+        (StateFrameStatus::Failed, _) => rsx! {
+            p { class: "text-sm text-destructive",
+                {props.frame.error_message().unwrap_or_else(|| "Unable to load engagement data.".to_string())}
+            }
+        },
+        (StateFrameStatus::Success, Some(envelope)) => {
+            let summary = &envelope.data.summary;
+            let stats = [
+                ("Views", summary.views.to_string()),
+                ("Visitors", summary.visitors.to_string()),
+                (
+                    "Avg. read time",
+                    format_read_time(summary.avg_read_time_secs),
+                ),
+                (
+                    "Completion",
+                    format!("{:.0}%", summary.completion_rate * 100.0),
+                ),
+                ("Likes / view", format!("{:.2}", summary.likes_per_view)),
+                (
+                    "Comments / view",
+                    format!("{:.2}", summary.comments_per_view),
+                ),
+            ];
+            let traffic = envelope.data.traffic.clone();
+
+            rsx! {
+                div { class: "grid grid-cols-2 md:grid-cols-3 gap-3",
+                    for (label, value) in stats {
+                        div { key: "{label}", class: "rounded-md border border-border p-3",
+                            div { class: "text-xs text-muted-foreground", "{label}" }
+                            div { class: "text-lg font-semibold text-foreground", "{value}" }
+                        }
+                    }
+                }
+                TrafficBars { points: traffic }
+            }
+        }
+        _ => rsx! {
+            p { class: "text-sm text-muted-foreground", "No engagement data yet." }
+        },
+    };
+
+    rsx! {
+        div { class: "rounded-lg border border-border flex flex-col gap-4 p-5",
+            div { class: "flex flex-col gap-0.5",
+                h3 { class: "text-sm font-semibold text-foreground", "{props.title}" }
+                span { class: "text-xs text-muted-foreground",
+                    "Reading time, completion and interactions from first-party events"
+                }
+            }
+            {body}
+        }
+    }
+}
+
+/// Pageviews per bucket as horizontal bars, scaled to the busiest bucket.
+#[component]
+fn TrafficBars(points: Vec<PostTrafficPoint>) -> Element {
+    if points.is_empty() {
+        return rsx! {
+            p { class: "text-xs text-muted-foreground", "No pageviews in this period." }
+        };
+    }
+    let max = points.iter().map(|p| p.pageviews).max().unwrap_or(1).max(1);
+
+    rsx! {
+        div { class: "flex flex-col gap-1.5",
+            for point in points {
+                {
+                    let width = (point.pageviews as f64 / max as f64 * 100.0).round();
+                    rsx! {
+                        div { key: "{point.bucket}", class: "flex items-center gap-3 text-xs",
+                            span { class: "w-24 shrink-0 text-muted-foreground tabular-nums", "{point.bucket}" }
+                            div { class: "flex-1 h-2 rounded bg-muted",
+                                div { class: "h-2 rounded bg-primary", style: "width: {width}%;" }
+                            }
+                            span { class: "w-20 shrink-0 text-right tabular-nums",
+                                "{point.pageviews} / {point.visitors}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_read_time(secs: f64) -> String {
+    let secs = secs.max(0.0).round() as u64;
+    if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_time_formats_minutes_and_seconds() {
+        assert_eq!(format_read_time(0.0), "0s");
+        assert_eq!(format_read_time(42.4), "42s");
+        assert_eq!(format_read_time(125.0), "2m 5s");
+        assert_eq!(format_read_time(-3.0), "0s");
+    }
+}
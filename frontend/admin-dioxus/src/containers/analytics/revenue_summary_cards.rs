@@ -0,0 +1,129 @@
+use dioxus::prelude::*;
+
+use oxstore::{StateFrame, StateFrameStatus};
+use ruxlog_shared::store::analytics::{
+    AnalyticsEnvelopeResponse, DashboardSummaryData, DashboardSummaryRequest,
+    DashboardSummaryRevenue,
+};
+
+/// Revenue headline for the billing home, read from the `revenue` section of
+/// `use_analytics().dashboard_summary`.
+#[derive(Props, PartialEq, Clone)]
+pub struct RevenueSummaryCardsProps {
+    pub frame: StateFrame<AnalyticsEnvelopeResponse<DashboardSummaryData>, DashboardSummaryRequest>,
+}
+
+#[component]
+pub fn RevenueSummaryCards(props: RevenueSummaryCardsProps) -> Element {
+    let status = props.frame.status;
+    let revenue = props
+        .frame
+        .data
+        .as_ref()
+        .map(|envelope| envelope.data.revenue.clone());
+
+    rsx! {
+        section { class: "w-full space-y-3",
+            match (status, revenue) {
+                (_, Some(revenue)) => rsx! {
+                    RevenueCardsGrid { revenue }
+                },
+                (StateFrameStatus::Init | StateFrameStatus::Loading, None) => rsx! {
+                    div { class: "grid grid-cols-2 lg:grid-cols-4 gap-3",
+                        for i in 0..4 {
+                            div { key: "{i}", class: "h-24 rounded-lg border border-border bg-muted/50 animate-pulse" }
+                        }
+                    }
+                },
+                (StateFrameStatus::Failed, None) => rsx! {
+                    p { class: "text-sm text-destructive",
+                        {props.frame.error_message().unwrap_or_else(|| "Unable to load revenue summary.".to_string())}
+                    }
+                },
+                _ => rsx! {},
+            }
+        }
+    }
+}
+
+#[component]
+fn RevenueCardsGrid(revenue: DashboardSummaryRevenue) -> Element {
+    let currency = revenue.currency.to_uppercase();
+    let ltv = revenue
+        .ltv_cents
+        .map(|cents| format_money(cents, &currency))
+        .unwrap_or_else(|| "—".to_string());
+    let cards = [
+        (
+            "MRR",
+            format_money(revenue.mrr_cents, &currency),
+            format!("ARR {}", format_money(revenue.arr_cents, &currency)),
+        ),
+        (
+            "Paying subscribers",
+            revenue.paying_subscriptions.to_string(),
+            format!("{} in trial", revenue.trialing),
+        ),
+        (
+            "Churn",
+            format!("{:.1}%", revenue.churn_rate * 100.0),
+            format!(
+                "+{} new / -{} churned",
+                revenue.new_subscriptions, revenue.churned
+            ),
+        ),
+        (
+            "Revenue this period",
+            format_money(revenue.revenue_in_period_cents, &currency),
+            format!(
+                "ARPU {} · LTV {ltv}",
+                format_money(revenue.arpu_cents, &currency)
+            ),
+        ),
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-2 lg:grid-cols-4 gap-3",
+            for (label, value, detail) in cards {
+                div { key: "{label}", class: "flex flex-col gap-2 rounded-lg border border-border p-4",
+                    span { class: "text-xs font-medium text-muted-foreground", "{label}" }
+                    span { class: "text-2xl font-semibold tracking-tight text-foreground", "{value}" }
+                    span { class: "text-xs text-muted-foreground", "{detail}" }
+                }
+            }
+        }
+        if revenue.unconverted_payments > 0 {
+            p { class: "text-xs text-muted-foreground",
+                "{revenue.unconverted_payments} payments are in a currency without an exchange rate and are not included."
+            }
+        }
+    }
+}
+
+/// Minor units as a major-unit amount with the currency code, e.g. `USD 12.50`.
+/// `minor` units of `currency`; zero- and three-decimal currencies keep
+/// their own number of decimals.
+fn format_money(minor: i64, currency: &str) -> String {
+    let decimals = match currency.to_ascii_lowercase().as_str() {
+        "bif" | "clp" | "djf" | "gnf" | "jpy" | "kmf" | "krw" | "mga" | "pyg" | "rwf" | "ugx"
+        | "vnd" | "vuv" | "xaf" | "xof" | "xpf" => 0,
+        "bhd" | "jod" | "kwd" | "omr" | "tnd" => 3,
+        _ => 2,
+    };
+    let major = minor as f64 / 10f64.powi(decimals);
+    format!("{currency} {major:.prec$}", prec = decimals as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_formats_minor_units() {
+        assert_eq!(format_money(1250, "USD"), "USD 12.50");
+        assert_eq!(format_money(0, "EUR"), "EUR 0.00");
+        assert_eq!(format_money(-99, "USD"), "USD -0.99");
+        assert_eq!(format_money(1250, "JPY"), "JPY 1250");
+        assert_eq!(format_money(1250, "KWD"), "KWD 1.250");
+    }
+}
@@ -7,7 +7,7 @@ use crate::components::editor_js_host::EditorJsHost;
 use crate::components::image_editor::ImageEditorModal;
 use crate::components::media::{upload_item::MediaUploadItem, upload_zone::MediaUploadZone};
 use crate::components::post_success_dialog::PostSuccessDialog;
-use crate::hooks::use_previous;
+use crate::hooks::{use_previous, OxForm};
 use crate::router::Route;
 use oxui::components::confirm_dialog::ConfirmDialog;
 use oxui::components::form::input::AppInput;
@@ -41,6 +41,21 @@ fn cleanup_legacy_cache(storage: &web_sys::Storage) {
     let _ = storage.remove_item("blog_form_draft_content");
 }
 
+/// Store a finished upload's blob URL on the image field that started it
+fn set_uploaded_blob(mut form: Signal<OxForm<BlogForm>>, field_name: &str, blob_url: String) {
+    let mut form_mut = form.write();
+    match field_name {
+        "og_image" => {
+            form_mut.data.og_image_blob_url = Some(blob_url);
+            form_mut.data.og_image_media_id = None;
+        }
+        _ => {
+            form_mut.data.featured_image_blob_url = Some(blob_url);
+            form_mut.data.featured_image_media_id = None;
+        }
+    }
+}
+
 #[component]
 pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
     let posts = use_post();
@@ -98,7 +113,23 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                         is_published: post.status == PostStatus::Published,
                         category_id: Some(post.category.id),
                         tag_ids: post.tags.iter().map(|t| t.id).collect(),
+                        locale: post.locale.clone().unwrap_or_default(),
+                        co_authors: post
+                            .co_authors
+                            .iter()
+                            .map(|a| a.id.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        ..BlogForm::new()
                     };
+                    if let Some(seo) = &post.seo {
+                        form.meta_title = seo.meta_title.clone().unwrap_or_default();
+                        form.meta_description = seo.meta_description.clone().unwrap_or_default();
+                        form.canonical_url = seo.canonical_url.clone().unwrap_or_default();
+                        form.noindex = seo.noindex;
+                        form.og_image_blob_url = seo.og_image.as_ref().map(|m| m.file_url.clone());
+                        form.og_image_media_id = seo.og_image.as_ref().map(|m| m.id);
+                    }
 
                     // Check for draft content specific to this post
                     if let Some(window) = web_sys::window() {
@@ -219,6 +250,20 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                 }
             }
         }
+
+        // Same for the custom social (OG) image
+        if let Some(og_blob) = &form_data.og_image_blob_url {
+            if form_data.og_image_media_id.is_none() {
+                if let Some(media) = media_state.get_uploaded_media(og_blob) {
+                    tracing::debug!(
+                        "[BlogForm] OG image upload complete, media ID: {}",
+                        media.id
+                    );
+                    let mut form_mut = form.write();
+                    form_mut.data.og_image_media_id = Some(media.id);
+                }
+            }
+        }
     });
 
     // Handle file selection from upload zone
@@ -252,7 +297,7 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
         let file = pending_file();
         let field = pending_field();
 
-        if let (Some(f), Some(_field_name)) = (file, field) {
+        if let (Some(f), Some(field_name)) = (file, field) {
             tracing::debug!("[BlogForm] Skipping edit, uploading directly: {}", f.name());
 
             // Upload the file
@@ -267,8 +312,7 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                 match media_state.upload(payload).await {
                     Ok(blob_url) => {
                         tracing::debug!("[BlogForm] Upload successful: {}", &blob_url);
-                        let mut form_mut = form.write();
-                        form_mut.data.featured_image_blob_url = Some(blob_url);
+                        set_uploaded_blob(form, &field_name, blob_url);
                     }
                     Err(e) => {
                         tracing::error!("[BlogForm] Upload failed: {}", e);
@@ -282,7 +326,7 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
     let handle_editor_save = move |edited_file: web_sys::File| {
         let field = pending_field();
 
-        if let Some(_field_name) = field {
+        if let Some(field_name) = field {
             tracing::debug!(
                 "[BlogForm] Editor saved, uploading edited file: {}",
                 edited_file.name()
@@ -300,8 +344,7 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                 match media_state.upload(payload).await {
                     Ok(blob_url) => {
                         tracing::debug!("[BlogForm] Edited upload successful: {}", &blob_url);
-                        let mut form_mut = form.write();
-                        form_mut.data.featured_image_blob_url = Some(blob_url);
+                        set_uploaded_blob(form, &field_name, blob_url);
                     }
                     Err(e) => {
                         tracing::error!("[BlogForm] Edited upload failed: {}", e);
@@ -363,6 +406,27 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
         }
     }));
 
+    let credited_authors = post_id
+        .and_then(|id| {
+            posts.view.read().get(&id).and_then(|frame| {
+                frame
+                    .data
+                    .as_ref()
+                    .map(|post| post.authors().cloned().collect::<Vec<_>>())
+            })
+        })
+        .unwrap_or_default();
+
+    let existing_translations = post_id
+        .and_then(|id| {
+            posts
+                .view
+                .read()
+                .get(&id)
+                .and_then(|frame| frame.data.as_ref().map(|post| post.translations.clone()))
+        })
+        .unwrap_or_default();
+
     rsx! {
         div {
             h1 { class: "sr-only",
@@ -711,6 +775,154 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                     }
                         }
 
+                    // Authors card
+                    div { class: "rounded-xl border border-border/70 bg-transparent",
+                        div { class: "px-6 pt-6",
+                            h2 { class: "text-lg font-semibold", "Authors" }
+                            p { class: "text-sm text-muted-foreground", "Credit co-authors and guest contributors after the post owner." }
+                        }
+                        div { class: "px-6 py-6 space-y-6",
+                            AppInput {
+                                name: "co_authors",
+                                form,
+                                label: "Co-authors (user IDs)",
+                                placeholder: "Comma separated, in credit order, e.g. 12, 7",
+                            }
+                            if !credited_authors.is_empty() {
+                                div { class: "space-y-1",
+                                    label { class: "block text-sm font-medium text-foreground", "Credited" }
+                                    for (position, author) in credited_authors.iter().enumerate() {
+                                        p { key: "{author.id}", class: "text-xs text-muted-foreground",
+                                            "{position + 1}. {author.name} · #{author.id}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Language card
+                    div { class: "rounded-xl border border-border/70 bg-transparent",
+                        div { class: "px-6 pt-6",
+                            h2 { class: "text-lg font-semibold", "Language" }
+                            p { class: "text-sm text-muted-foreground", "Link localized versions of the same post." }
+                        }
+                        div { class: "px-6 py-6 space-y-6",
+                            AppInput {
+                                name: "locale",
+                                form,
+                                label: "Locale",
+                                placeholder: "Site default, e.g. en or pt-BR",
+                            }
+                            AppInput {
+                                name: "translation_of",
+                                form,
+                                label: "Translation of (post ID)",
+                                r#type: "number",
+                                placeholder: "Leave empty for an original post",
+                            }
+                            if !existing_translations.is_empty() {
+                                div { class: "space-y-1",
+                                    label { class: "block text-sm font-medium text-foreground", "Translations" }
+                                    for translation in existing_translations.iter() {
+                                        p { key: "{translation.id}", class: "text-xs text-muted-foreground",
+                                            "{translation.locale} · #{translation.id} · {translation.title}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // SEO card
+                    div { class: "rounded-xl border border-border/70 bg-transparent",
+                        div { class: "px-6 pt-6",
+                            h2 { class: "text-lg font-semibold", "SEO" }
+                            p { class: "text-sm text-muted-foreground", "Override how search engines and social sites see this post." }
+                        }
+                        div { class: "px-6 py-6 space-y-6",
+                            AppInput {
+                                name: "meta_title",
+                                form,
+                                label: "Meta title",
+                                placeholder: "Defaults to the post title",
+                            }
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-foreground", "Meta description" }
+                                textarea {
+                                    class: "w-full h-24 resize-none rounded-md border border-border/70 bg-transparent px-4 py-3 text-sm text-foreground placeholder:text-muted-foreground transition-colors duration-200 focus:border-ring focus:ring-2 focus:ring-ring/40",
+                                    placeholder: "Defaults to the excerpt",
+                                    rows: "3",
+                                    value: form.read().data.meta_description.clone(),
+                                    oninput: move |event| {
+                                        form.write().update_field("meta_description", event.value());
+                                    },
+                                }
+                            }
+                            AppInput {
+                                name: "canonical_url",
+                                form,
+                                label: "Canonical URL",
+                                r#type: "url",
+                                placeholder: "https://example.com/original-post",
+                            }
+                            div { class: "flex items-center justify-between",
+                                div { class: "space-y-0.5",
+                                    label { class: "block text-sm font-medium text-foreground", "Hide from search engines" }
+                                    p { class: "text-xs text-muted-foreground", "Adds noindex and leaves the post out of sitemaps." }
+                                }
+                                Checkbox {
+                                    checked: form.read().data.noindex,
+                                    onchange: move |checked: bool| {
+                                        form.write().data.noindex = checked;
+                                    },
+                                }
+                            }
+
+                            div { class: "h-px bg-border/60" }
+
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-foreground", "Social image" }
+                                p { class: "text-xs text-muted-foreground", "Without one, a card with the title and category colors is generated." }
+                                {
+                                    let og_blob_url = form.read().data.og_image_blob_url.clone();
+                                    match og_blob_url {
+                                        Some(blob) => {
+                                            let (filename, file_size) = match media_state.get_file_info(&blob) {
+                                                Some(info) => (info.filename, info.size),
+                                                None => ("Social Image".to_string(), 0),
+                                            };
+                                            rsx! {
+                                                MediaUploadItem {
+                                                    blob_url: blob.clone(),
+                                                    filename,
+                                                    file_size,
+                                                    on_remove: move |_url: String| {
+                                                        let mut form_mut = form.write();
+                                                        form_mut.data.og_image_blob_url = None;
+                                                        form_mut.data.og_image_media_id = None;
+                                                    },
+                                                    on_edit: Some(EventHandler::new(handle_edit_uploaded("og_image".to_string()))),
+                                                }
+                                            }
+                                        }
+                                        None => rsx! {
+                                            MediaUploadZone {
+                                                on_upload: move |_blob_urls: Vec<String>| {},
+                                                on_file_selected: Some(EventHandler::new(handle_file_selected("og_image".to_string()))),
+                                                reference_type: Some(MediaReference::Post),
+                                                max_files: 1,
+                                                allowed_types: vec!["image/".to_string()],
+                                                title: "Upload social image".to_string(),
+                                                description: "1200×630 works best".to_string(),
+                                                multiple: false,
+                                            }
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
@@ -774,6 +986,10 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                                         category_id: form_data.data.category_id,
                                         tag_ids: Some(form_data.data.tag_ids.clone()),
                                         published_at: None,
+                                        locale: form_data.data.locale_value(),
+                                        translation_of: form_data.data.translation_of_id(),
+                                        co_author_ids: Some(form_data.data.co_author_ids()),
+                                        seo: Some(form_data.data.seo_payload()),
                                     };
 
                                     spawn(async move {
@@ -795,6 +1011,10 @@ pub fn BlogFormContainer(post_id: Option<i32>) -> Element {
                                         category_id: form_data.data.category_id.unwrap(),
                                         tag_ids: form_data.data.tag_ids.clone(),
                                         published_at: None,
+                                        locale: form_data.data.locale_value(),
+                                        translation_of: form_data.data.translation_of_id(),
+                                        co_author_ids: form_data.data.co_author_ids(),
+                                        seo: Some(form_data.data.seo_payload()),
                                     };
 
                                     spawn(async move {
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use ruxlog_shared::store::posts::SeoPayload;
 use std::collections::HashMap;
 use validator::{Validate, ValidationError};
 
@@ -27,6 +28,21 @@ pub struct BlogForm {
     pub category_id: Option<i32>,
 
     pub tag_ids: Vec<i32>,
+
+    // SEO overrides; blank fields fall back to the post's own title/excerpt
+    pub meta_title: String,
+    pub meta_description: String,
+    pub canonical_url: String,
+    pub noindex: bool,
+    pub og_image_blob_url: Option<String>,
+    pub og_image_media_id: Option<i32>,
+
+    // Language; a blank locale uses the site default
+    pub locale: String,
+    pub translation_of: String,
+
+    // Co-author user IDs, comma separated, in credit order
+    pub co_authors: String,
 }
 
 fn validate_slug(slug: &str) -> Result<(), ValidationError> {
@@ -51,6 +67,15 @@ impl BlogForm {
             is_published: false,
             category_id: None,
             tag_ids: vec![],
+            meta_title: String::new(),
+            meta_description: String::new(),
+            canonical_url: String::new(),
+            noindex: false,
+            og_image_blob_url: None,
+            og_image_media_id: None,
+            locale: String::new(),
+            translation_of: String::new(),
+            co_authors: String::new(),
         }
     }
 
@@ -58,7 +83,40 @@ impl BlogForm {
     #[allow(dead_code)]
     pub fn is_uploading(&self) -> bool {
         // If we have a blob URL but no media ID yet, upload is in progress
-        self.featured_image_blob_url.is_some() && self.featured_image_media_id.is_none()
+        (self.featured_image_blob_url.is_some() && self.featured_image_media_id.is_none())
+            || (self.og_image_blob_url.is_some() && self.og_image_media_id.is_none())
+    }
+
+    pub fn seo_payload(&self) -> SeoPayload {
+        let non_empty = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        SeoPayload {
+            meta_title: non_empty(&self.meta_title),
+            meta_description: non_empty(&self.meta_description),
+            canonical_url: non_empty(&self.canonical_url),
+            noindex: self.noindex,
+            og_image_id: self.og_image_media_id,
+        }
+    }
+
+    pub fn locale_value(&self) -> Option<String> {
+        let locale = self.locale.trim();
+        (!locale.is_empty()).then(|| locale.to_string())
+    }
+
+    /// ID of the post this one translates, if one was entered
+    pub fn translation_of_id(&self) -> Option<i32> {
+        self.translation_of.trim().parse().ok()
+    }
+
+    /// Co-author user IDs in the order entered; unparsable entries are skipped
+    pub fn co_author_ids(&self) -> Vec<i32> {
+        self.co_authors
+            .split(',')
+            .filter_map(|id| id.trim().parse().ok())
+            .collect()
     }
 
     pub fn sanitize_slug(text: &str) -> String {
@@ -94,7 +152,16 @@ impl OxFormModel for BlogForm {
         if let Some(category_id) = self.category_id {
             map.insert("category_id".to_string(), category_id.to_string());
         }
-        // Featured image and tag IDs are handled separately
+        map.insert("meta_title".to_string(), self.meta_title.clone());
+        map.insert(
+            "meta_description".to_string(),
+            self.meta_description.clone(),
+        );
+        map.insert("canonical_url".to_string(), self.canonical_url.clone());
+        map.insert("locale".to_string(), self.locale.clone());
+        map.insert("translation_of".to_string(), self.translation_of.clone());
+        map.insert("co_authors".to_string(), self.co_authors.clone());
+        // Featured image, OG image and tag IDs are handled separately
         map
     }
 
@@ -106,6 +173,12 @@ impl OxFormModel for BlogForm {
             "excerpt" => self.excerpt = value.to_string(),
             "is_published" => self.is_published = value.parse().unwrap_or(false),
             "category_id" => self.category_id = value.parse().ok(),
+            "meta_title" => self.meta_title = value.to_string(),
+            "meta_description" => self.meta_description = value.to_string(),
+            "canonical_url" => self.canonical_url = value.to_string(),
+            "locale" => self.locale = value.to_string(),
+            "translation_of" => self.translation_of = value.to_string(),
+            "co_authors" => self.co_authors = value.to_string(),
             _ => {}
         }
     }
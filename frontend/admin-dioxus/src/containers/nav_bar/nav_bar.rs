@@ -5,6 +5,7 @@ use hmziq_dioxus_free_icons::Icon;
 use crate::components::sidebar::Sidebar;
 use crate::config::DarkMode;
 use crate::{router::Route, utils::persist};
+use ruxlog_shared::components::notification_bell::NotificationBell;
 use ruxlog_shared::components::user_avatar::UserAvatar;
 use ruxlog_shared::use_auth;
 
@@ -94,7 +95,7 @@ pub fn NavBarContainer() -> Element {
                 }
 
                 div { class: "flex items-center space-x-4",
-                    button { class: "rounded-full p-1 text-muted-foreground transition-colors duration-200 hover:bg-muted/50 hover:text-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring/50 focus-visible:ring-offset-2 focus-visible:ring-offset-background",
+                    NotificationBell { button_class: "rounded-full p-1 text-muted-foreground transition-colors duration-200 hover:bg-muted/50 hover:text-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring/50 focus-visible:ring-offset-2 focus-visible:ring-offset-background",
                         div { class: "w-4 h-4",
                             Icon { icon: LdBell }
                         }
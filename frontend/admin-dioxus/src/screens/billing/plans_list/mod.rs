@@ -10,6 +10,46 @@ use oxui::shadcn::dropdown_menu::{
 };
 use ruxlog_shared::store::use_billing;
 
+#[cfg(feature = "analytics")]
+use crate::containers::analytics::revenue_summary_cards::RevenueSummaryCards;
+#[cfg(feature = "analytics")]
+use ruxlog_shared::store::analytics::{
+    browser_timezone, use_analytics, AnalyticsEnvelope, DashboardSummaryFilters,
+    DashboardSummaryRequest,
+};
+
+/// MRR, churn and period revenue above the plans table.
+#[cfg(feature = "analytics")]
+#[component]
+fn BillingRevenueOverview() -> Element {
+    let analytics = use_analytics();
+
+    use_effect(move || {
+        spawn(async move {
+            let request = DashboardSummaryRequest {
+                envelope: Some(AnalyticsEnvelope {
+                    timezone: browser_timezone(),
+                    ..Default::default()
+                }),
+                filters: DashboardSummaryFilters {
+                    period: "30d".to_string(),
+                },
+            };
+            analytics.fetch_dashboard_summary(request).await;
+        });
+    });
+
+    rsx! {
+        RevenueSummaryCards { frame: analytics.dashboard_summary.read().clone() }
+    }
+}
+
+#[cfg(not(feature = "analytics"))]
+#[component]
+fn BillingRevenueOverview() -> Element {
+    rsx! {}
+}
+
 #[component]
 pub fn BillingPlansListScreen() -> Element {
     let nav = use_navigator();
@@ -39,6 +79,8 @@ pub fn BillingPlansListScreen() -> Element {
                 }
             }
 
+            BillingRevenueOverview {}
+
             if is_failed {
                 div { class: "text-center py-8",
                     p { class: "text-destructive mb-4", "Failed to load plans" }
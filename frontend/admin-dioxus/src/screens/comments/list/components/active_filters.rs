@@ -36,6 +36,7 @@ pub fn ActiveFilters(active_filter_count: usize, filters: Signal<CommentListQuer
     let selected_user_id = *ctx.selected_user_id.read();
     let selected_post_id = *ctx.selected_post_id.read();
     let selected_flag_filter = ctx.selected_flag_filter.read().clone();
+    let selected_status = *ctx.selected_status.read();
     let hidden_filter = filters.read().hidden_filter.clone();
 
     rsx! {
@@ -120,6 +121,29 @@ pub fn ActiveFilters(active_filter_count: usize, filters: Signal<CommentListQuer
                 }
             }
 
+            if let Some(status) = selected_status {
+                {
+                    let mut ctx_clone = ctx.clone();
+                    rsx! {
+                        Badge {
+                            variant: BadgeVariant::Outline,
+                            class: "gap-1.5",
+                            "Moderation: {status.label()}"
+                            button {
+                                class: "ml-1 hover:bg-zinc-200 dark:hover:bg-zinc-700 rounded-full",
+                                onclick: {
+                                    let mut filters = filters;
+                                    move |_| {
+                                        ctx_clone.set_status_filter(&mut filters, None);
+                                    }
+                                },
+                                Icon { icon: LdX {}, class: "w-3 h-3" }
+                            }
+                        }
+                    }
+                }
+            }
+
             if let Some(hidden) =
                 hidden_filter.filter(|h| matches!(h, HiddenFilter::Hidden | HiddenFilter::All))
             {
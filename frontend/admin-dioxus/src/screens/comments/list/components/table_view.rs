@@ -13,7 +13,7 @@ use oxui::shadcn::checkbox::Checkbox;
 use oxui::shadcn::dropdown_menu::{
     DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuSeparator, DropdownMenuTrigger,
 };
-use ruxlog_shared::store::{use_comments, Comment};
+use ruxlog_shared::store::{use_comments, Comment, CommentStatus};
 
 use hmziq_dioxus_free_icons::{icons::ld_icons::LdEllipsis, Icon};
 
@@ -73,8 +73,20 @@ pub fn TableView(
                     let comment_id = comment.id;
                     let is_selected = ctx.selected_ids.read().contains(&comment_id);
                     let is_hidden = comment.hidden;
+                    let status = comment.status;
+                    let author_id = comment.user_id;
+                    let author_shadow_banned = comment.author_shadow_banned;
+                    let spam_note = comment
+                        .spam_score
+                        .filter(|score| *score > 0)
+                        .map(|score| {
+                            format!("Spam score {}: {}", score, comment.spam_reasons.join(", "))
+                        });
                     let mut ctx_clone = ctx.clone();
                     let on_refresh_hide = on_refresh;
+                    let on_refresh_approve = on_refresh;
+                    let on_refresh_reject = on_refresh;
+                    let on_refresh_shadow_ban = on_refresh;
                     let on_refresh_delete = on_refresh;
                     let is_moderating = comments_state
                         .moderation
@@ -112,21 +124,38 @@ pub fn TableView(
                                     } else {
                                         span { class: "text-xs text-muted-foreground", "User #{comment.user_id}" }
                                     }
+                                    if author_shadow_banned {
+                                        Badge { variant: BadgeVariant::Secondary, class: "bg-purple-100 text-purple-800 border-purple-200 dark:bg-purple-900/20 dark:text-purple-400", "Shadow-banned" }
+                                    }
                                 }
                             }
                             td { class: "py-2 px-3 max-w-md",
                                 p { class: "text-sm text-zinc-700 dark:text-zinc-300 truncate", "{comment.content}" }
+                                if let Some(note) = spam_note {
+                                    p { class: "text-xs text-amber-700 dark:text-amber-400 truncate", "{note}" }
+                                }
                             }
                             td { class: "py-2 px-3",
-                                {if is_hidden {
-                                    rsx! {
-                                        Badge { variant: BadgeVariant::Secondary, class: "bg-red-100 text-red-800 border-red-200 dark:bg-red-900/20 dark:text-red-400", "Hidden" }
-                                    }
-                                } else {
-                                    rsx! {
-                                        Badge { class: "bg-green-100 text-green-800 border-green-200 dark:bg-green-900/20 dark:text-green-400", "Visible" }
-                                    }
-                                }}
+                                div { class: "flex flex-wrap gap-1",
+                                    {match status {
+                                        CommentStatus::Pending => rsx! {
+                                            Badge { class: "bg-amber-100 text-amber-800 border-amber-200 dark:bg-amber-900/20 dark:text-amber-400", "Pending" }
+                                        },
+                                        CommentStatus::Rejected => rsx! {
+                                            Badge { variant: BadgeVariant::Secondary, class: "bg-zinc-100 text-zinc-700 border-zinc-200 dark:bg-zinc-800 dark:text-zinc-300", "Rejected" }
+                                        },
+                                        CommentStatus::Approved => rsx! {},
+                                    }}
+                                    {if is_hidden {
+                                        rsx! {
+                                            Badge { variant: BadgeVariant::Secondary, class: "bg-red-100 text-red-800 border-red-200 dark:bg-red-900/20 dark:text-red-400", "Hidden" }
+                                        }
+                                    } else {
+                                        rsx! {
+                                            Badge { class: "bg-green-100 text-green-800 border-green-200 dark:bg-green-900/20 dark:text-green-400", "Visible" }
+                                        }
+                                    }}
+                                }
                             }
                             td { class: "py-2 px-3 text-xs text-muted-foreground whitespace-nowrap",
                                 {format_short_date_dt(&comment.created_at)}
@@ -148,6 +177,28 @@ pub fn TableView(
                                             },
                                             "View"
                                         }
+                                        if status != CommentStatus::Approved {
+                                            DropdownMenuItem {
+                                                onclick: move |_| {
+                                                    spawn(async move {
+                                                        comments_state.approve(comment_id).await;
+                                                        on_refresh_approve.call(());
+                                                    });
+                                                },
+                                                "Approve"
+                                            }
+                                        }
+                                        if status != CommentStatus::Rejected {
+                                            DropdownMenuItem {
+                                                onclick: move |_| {
+                                                    spawn(async move {
+                                                        comments_state.reject(comment_id).await;
+                                                        on_refresh_reject.call(());
+                                                    });
+                                                },
+                                                "Reject"
+                                            }
+                                        }
                                         DropdownMenuItem {
                                             onclick: {
                                                 move |_| {
@@ -163,6 +214,19 @@ pub fn TableView(
                                             },
                                             if is_hidden { "Unhide" } else { "Hide" }
                                         }
+                                        DropdownMenuItem {
+                                            onclick: move |_| {
+                                                spawn(async move {
+                                                    if author_shadow_banned {
+                                                        comments_state.lift_author_shadow_ban(comment_id, author_id).await;
+                                                    } else {
+                                                        comments_state.shadow_ban_author(comment_id, author_id).await;
+                                                    }
+                                                    on_refresh_shadow_ban.call(());
+                                                });
+                                            },
+                                            if author_shadow_banned { "Lift shadow ban" } else { "Shadow-ban author" }
+                                        }
                                         DropdownMenuSeparator {}
                                         DropdownMenuItem {
                                             class: "text-red-600 dark:text-red-400",
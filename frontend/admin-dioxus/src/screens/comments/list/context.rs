@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use oxstore::ListQuery;
-use ruxlog_shared::store::{CommentListQuery, FlagFilter, HiddenFilter};
+use ruxlog_shared::store::{CommentListQuery, CommentStatus, FlagFilter, HiddenFilter};
 
 #[derive(Clone)]
 pub struct CommentListContext {
@@ -8,6 +8,7 @@ pub struct CommentListContext {
     pub selected_user_id: Signal<Option<i32>>,
     pub selected_post_id: Signal<Option<i32>>,
     pub selected_flag_filter: Signal<FlagFilter>,
+    pub selected_status: Signal<Option<CommentStatus>>,
 }
 
 impl CommentListContext {
@@ -17,6 +18,7 @@ impl CommentListContext {
             selected_user_id: use_signal(|| None),
             selected_post_id: use_signal(|| None),
             selected_flag_filter: use_signal(|| FlagFilter::All),
+            selected_status: use_signal(|| None),
         }
     }
 
@@ -27,6 +29,7 @@ impl CommentListContext {
         q.user_id = *self.selected_user_id.peek();
         q.post_id = *self.selected_post_id.peek();
         q.flag_filter = Some(self.selected_flag_filter.peek().clone());
+        q.status = *self.selected_status.peek();
 
         filters.set(q);
     }
@@ -37,12 +40,14 @@ impl CommentListContext {
         q.user_id = None;
         q.post_id = None;
         q.flag_filter = Some(FlagFilter::All);
+        q.status = None;
         q.hidden_filter = None;
         q.set_search(None);
         filters.set(q);
         self.selected_user_id.set(None);
         self.selected_post_id.set(None);
         self.selected_flag_filter.set(FlagFilter::All);
+        self.selected_status.set(None);
     }
 
     pub fn clear_user_filter(&mut self, filters: &mut Signal<CommentListQuery>) {
@@ -82,6 +87,15 @@ impl CommentListContext {
         self.apply_filters(filters);
     }
 
+    pub fn set_status_filter(
+        &mut self,
+        filters: &mut Signal<CommentListQuery>,
+        status: Option<CommentStatus>,
+    ) {
+        self.selected_status.set(status);
+        self.apply_filters(filters);
+    }
+
     pub fn active_filter_count(&self, filters: &Signal<CommentListQuery>) -> usize {
         let q = filters.read();
         let mut count = 0;
@@ -94,6 +108,9 @@ impl CommentListContext {
         if q.flag_filter.is_some() && q.flag_filter != Some(FlagFilter::All) {
             count += 1;
         }
+        if q.status.is_some() {
+            count += 1;
+        }
         if matches!(
             q.hidden_filter,
             Some(HiddenFilter::Hidden) | Some(HiddenFilter::All)
@@ -14,7 +14,8 @@ use oxstore::{ListQuery, ListStore, Order};
 use oxui::shadcn::checkbox::Checkbox;
 use oxui::shadcn::combobox::{Combobox, ComboboxItem};
 use ruxlog_shared::store::{
-    use_comments, use_post, use_user, Comment, CommentListQuery, FlagFilter, HiddenFilter,
+    use_comments, use_post, use_user, Comment, CommentListQuery, CommentStatus, FlagFilter,
+    HiddenFilter,
 };
 
 #[component]
@@ -117,7 +118,7 @@ pub fn CommentsListScreen() -> Element {
     let below_toolbar_content = rsx! {
         div { class: "space-y-3",
             // Filter row
-            div { class: "grid grid-cols-1 md:grid-cols-3 gap-3",
+            div { class: "grid grid-cols-1 md:grid-cols-4 gap-3",
                 div { class: "flex flex-col gap-1.5 md:max-w-xs",
                     label { class: "text-sm font-medium text-zinc-700 dark:text-zinc-300", "Filter by User" }
                     Combobox {
@@ -181,6 +182,38 @@ pub fn CommentsListScreen() -> Element {
                         })),
                     }
                 }
+                div { class: "flex flex-col gap-1.5 md:max-w-xs",
+                    label { class: "text-sm font-medium text-zinc-700 dark:text-zinc-300", "Filter by Moderation" }
+                    Combobox {
+                        items: vec![
+                            ComboboxItem { value: "all".to_string(), label: "All".to_string() },
+                            ComboboxItem { value: "pending".to_string(), label: "Pending".to_string() },
+                            ComboboxItem { value: "approved".to_string(), label: "Approved".to_string() },
+                            ComboboxItem { value: "rejected".to_string(), label: "Rejected".to_string() },
+                        ],
+                        placeholder: "Select moderation status...".to_string(),
+                        value: Some(match *ctx.selected_status.read() {
+                            Some(CommentStatus::Pending) => "pending".to_string(),
+                            Some(CommentStatus::Approved) => "approved".to_string(),
+                            Some(CommentStatus::Rejected) => "rejected".to_string(),
+                            None => "all".to_string(),
+                        }),
+                        width: "w-full md:w-64".to_string(),
+                        onvaluechange: Some(EventHandler::new({
+                            let mut ctx = ctx.clone();
+                            let mut filters = filters;
+                            move |val: Option<String>| {
+                                let status = match val.as_deref() {
+                                    Some("pending") => Some(CommentStatus::Pending),
+                                    Some("approved") => Some(CommentStatus::Approved),
+                                    Some("rejected") => Some(CommentStatus::Rejected),
+                                    _ => None,
+                                };
+                                ctx.set_status_filter(&mut filters, status);
+                            }
+                        })),
+                    }
+                }
             }
             if has_data {
                 {
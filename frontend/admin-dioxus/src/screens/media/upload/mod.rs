@@ -55,9 +55,11 @@ pub fn MediaUploadScreen() -> Element {
                         allowed_types: vec![
                             "image/".to_string(),
                             "video/".to_string(),
+                            "audio/".to_string(),
+                            "application/pdf".to_string(),
                         ],
                         title: "Upload Media Files".to_string(),
-                        description: "Drag and drop files here, or click to select. Supports images and videos.".to_string(),
+                        description: "Drag and drop files here, or click to select. Supports images, video, audio and PDFs.".to_string(),
                         multiple: true,
                     }
                 }
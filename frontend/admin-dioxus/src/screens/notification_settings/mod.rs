@@ -4,24 +4,219 @@ use crate::containers::page_header::PageHeader;
 use oxui::shadcn::button::{Button, ButtonVariant};
 use oxui::shadcn::card::Card;
 use oxui::shadcn::checkbox::Checkbox;
+use ruxlog_shared::store::{
+    use_notifications, use_webhooks, CreateWebhookEndpointPayload, EmailDelivery,
+    UpdateNotificationPreferencesPayload, UpdateWebhookEndpointPayload, WebhookDelivery,
+    WebhookDeliveryListQuery, WebhookDeliveryStatus, WebhookEndpoint, WebhookEvent,
+};
+
+fn event_label(event: WebhookEvent) -> &'static str {
+    match event {
+        WebhookEvent::PostPublished => "Post published",
+        WebhookEvent::CommentCreated => "Comment created",
+        WebhookEvent::UserRegistered => "User registered",
+        WebhookEvent::PaymentSucceeded => "Payment succeeded",
+        WebhookEvent::SubscriptionCanceled => "Subscription canceled",
+    }
+}
 
 #[component]
 pub fn NotificationSettingsScreen() -> Element {
+    let notifications = use_notifications();
+
     // Toggle states for each email notification event
     let mut new_comment = use_signal(|| true);
+    let mut comment_reply = use_signal(|| true);
+    let mut comment_mention = use_signal(|| true);
     let mut new_user = use_signal(|| true);
     let mut payment_received = use_signal(|| true);
     let mut subscription_cancelled = use_signal(|| true);
     let mut newsletter_subscriber = use_signal(|| false);
     let mut contact_form = use_signal(|| true);
+    let mut email_delivery = use_signal(EmailDelivery::default);
 
     // Channel toggles
     let mut channel_email = use_signal(|| true);
-    let mut channel_in_app = use_signal(|| false);
+    let mut channel_in_app = use_signal(|| true);
     let mut channel_webhook = use_signal(|| false);
 
-    // Webhook URL
+    // Webhook endpoint being edited; `None` while adding a new one
+    let webhooks = use_webhooks();
+    let mut selected_endpoint = use_signal(|| None::<i32>);
     let mut webhook_url = use_signal(String::new);
+    let mut webhook_events = use_signal(|| WebhookEvent::ALL.to_vec());
+    let mut revealed_secret = use_signal(|| None::<String>);
+    let mut webhooks_loaded = use_signal(|| false);
+
+    let mut loaded = use_signal(|| false);
+
+    use_effect(move || {
+        spawn(async move {
+            notifications.fetch_preferences().await;
+        });
+        spawn(async move {
+            webhooks.list().await;
+        });
+    });
+
+    let mut select_endpoint = move |endpoint: Option<WebhookEndpoint>| {
+        revealed_secret.set(None);
+        *webhooks.test.write() = Default::default();
+        match endpoint {
+            Some(endpoint) => {
+                selected_endpoint.set(Some(endpoint.id));
+                webhook_url.set(endpoint.url.clone());
+                webhook_events.set(endpoint.events.clone());
+                channel_webhook.set(endpoint.is_active);
+                spawn(async move {
+                    webhooks
+                        .list_deliveries(WebhookDeliveryListQuery::for_endpoint(endpoint.id))
+                        .await;
+                });
+            }
+            None => {
+                selected_endpoint.set(None);
+                webhook_url.set(String::new());
+                webhook_events.set(WebhookEvent::ALL.to_vec());
+                *webhooks.deliveries.write() = Default::default();
+            }
+        }
+    };
+
+    // Start with the first registered endpoint, if any
+    let endpoints = webhooks
+        .endpoints
+        .read()
+        .data
+        .as_ref()
+        .map(|list| list.data.clone());
+    if let Some(endpoints) = &endpoints {
+        if !webhooks_loaded() {
+            webhooks_loaded.set(true);
+            select_endpoint(endpoints.first().cloned());
+        }
+    }
+    let endpoints = endpoints.unwrap_or_default();
+
+    // Populate the form once the saved preferences arrive
+    let preferences_frame = notifications.preferences.read();
+    if let Some(preferences) = &preferences_frame.data {
+        if !loaded() {
+            new_comment.set(preferences.comment_on_post);
+            comment_reply.set(preferences.comment_reply);
+            comment_mention.set(preferences.comment_mention);
+            new_user.set(preferences.new_user);
+            payment_received.set(preferences.payment_received);
+            subscription_cancelled.set(preferences.subscription_canceled);
+            newsletter_subscriber.set(preferences.newsletter_subscriber);
+            contact_form.set(preferences.contact_form);
+            email_delivery.set(preferences.email_delivery);
+            channel_email.set(preferences.email_enabled);
+            channel_in_app.set(preferences.in_app_enabled);
+            loaded.set(true);
+        }
+    }
+    let load_error = preferences_frame
+        .is_failed()
+        .then(|| preferences_frame.error_message())
+        .flatten();
+    drop(preferences_frame);
+
+    let update_frame = notifications.update_preferences.read();
+    let saving = update_frame.is_loading();
+    let saved = update_frame.is_success();
+    let save_error = update_frame
+        .is_failed()
+        .then(|| update_frame.error_message())
+        .flatten();
+    drop(update_frame);
+
+    let webhook_saving = webhooks.save.read().is_loading();
+    let webhook_error = [
+        webhooks.endpoints.read().error_message(),
+        webhooks.save.read().error_message(),
+        webhooks.remove.read().error_message(),
+        webhooks.redeliver.read().error_message(),
+    ]
+    .into_iter()
+    .flatten()
+    .next();
+    let test_frame = webhooks.test.read();
+    let testing = test_frame.is_loading();
+    let test_result = test_frame.data.clone();
+    drop(test_frame);
+    let deliveries = webhooks
+        .deliveries
+        .read()
+        .data
+        .as_ref()
+        .map(|list| list.data.clone())
+        .unwrap_or_default();
+
+    let on_save_webhook = move |_| {
+        let url = webhook_url().trim().to_string();
+        let events = webhook_events();
+        spawn(async move {
+            match selected_endpoint() {
+                Some(id) => {
+                    webhooks
+                        .update(
+                            id,
+                            UpdateWebhookEndpointPayload {
+                                url: Some(url),
+                                events: Some(events),
+                                is_active: Some(channel_webhook()),
+                                ..Default::default()
+                            },
+                        )
+                        .await;
+                }
+                None => {
+                    let payload = CreateWebhookEndpointPayload {
+                        url,
+                        description: None,
+                        events,
+                        is_active: channel_webhook(),
+                    };
+                    if let Some(endpoint) = webhooks.create(payload).await {
+                        let secret = endpoint.secret.clone();
+                        select_endpoint(Some(endpoint));
+                        revealed_secret.set(secret);
+                    }
+                }
+            }
+        });
+    };
+
+    let on_save = move |_| {
+        let payload = UpdateNotificationPreferencesPayload {
+            comment_on_post: Some(new_comment()),
+            comment_reply: Some(comment_reply()),
+            comment_mention: Some(comment_mention()),
+            new_user: Some(new_user()),
+            payment_received: Some(payment_received()),
+            subscription_canceled: Some(subscription_cancelled()),
+            newsletter_subscriber: Some(newsletter_subscriber()),
+            contact_form: Some(contact_form()),
+            email_enabled: Some(channel_email()),
+            in_app_enabled: Some(channel_in_app()),
+            email_delivery: Some(email_delivery()),
+            ..Default::default()
+        };
+        spawn(async move {
+            notifications.update_preferences(payload).await;
+        });
+        // The webhook channel pauses or resumes the selected endpoint
+        if let Some(id) = selected_endpoint() {
+            let payload = UpdateWebhookEndpointPayload {
+                is_active: Some(channel_webhook()),
+                ..Default::default()
+            };
+            spawn(async move {
+                webhooks.update(id, payload).await;
+            });
+        }
+    };
 
     rsx! {
         div { class: "min-h-screen bg-transparent text-foreground",
@@ -32,6 +227,12 @@ pub fn NotificationSettingsScreen() -> Element {
 
             div { class: "container mx-auto px-4 my-8 space-y-8",
 
+                if let Some(message) = load_error.or(save_error) {
+                    div { class: "rounded-lg border border-red-200 bg-red-50 dark:bg-red-900/10 dark:border-red-800 p-4",
+                        p { class: "text-sm text-red-800 dark:text-red-400", "{message}" }
+                    }
+                }
+
                 // -- Email Notification Preferences --
                 div { class: "space-y-4",
                     div {
@@ -56,6 +257,34 @@ pub fn NotificationSettingsScreen() -> Element {
                             }
                         }
 
+                        // Reply to a comment
+                        div { class: "flex items-center justify-between py-4",
+                            div { class: "space-y-0.5 pr-4",
+                                p { class: "text-sm font-medium", "Reply to your comment" }
+                                p { class: "text-xs text-muted-foreground",
+                                    "Get notified when someone replies to one of your comments."
+                                }
+                            }
+                            Checkbox {
+                                checked: *comment_reply.read(),
+                                onchange: move |checked| comment_reply.set(checked),
+                            }
+                        }
+
+                        // Mention in a comment
+                        div { class: "flex items-center justify-between py-4",
+                            div { class: "space-y-0.5 pr-4",
+                                p { class: "text-sm font-medium", "Mentioned in a comment" }
+                                p { class: "text-xs text-muted-foreground",
+                                    "Get notified when a comment mentions your @profile handle."
+                                }
+                            }
+                            Checkbox {
+                                checked: *comment_mention.read(),
+                                onchange: move |checked| comment_mention.set(checked),
+                            }
+                        }
+
                         // New user registration
                         div { class: "flex items-center justify-between py-4",
                             div { class: "space-y-0.5 pr-4",
@@ -128,6 +357,38 @@ pub fn NotificationSettingsScreen() -> Element {
                     }
                 }
 
+                // -- Email Delivery --
+                div { class: "space-y-4",
+                    div {
+                        h2 { class: "text-lg font-semibold", "Email Delivery" }
+                        p { class: "text-sm text-muted-foreground",
+                            "Send each notification right away, or bundle them into one email a day."
+                        }
+                    }
+
+                    Card { class: "p-6 space-y-4",
+                        div { class: "grid gap-2 max-w-xs",
+                            label { class: "text-sm font-medium", "Frequency" }
+                            select {
+                                class: "w-full rounded-lg border border-border bg-background px-3 py-2 text-sm",
+                                value: match email_delivery() {
+                                    EmailDelivery::Immediate => "immediate",
+                                    EmailDelivery::Digest => "digest",
+                                },
+                                onchange: move |e| {
+                                    email_delivery.set(if e.value() == "digest" {
+                                        EmailDelivery::Digest
+                                    } else {
+                                        EmailDelivery::Immediate
+                                    });
+                                },
+                                option { value: "immediate", "Immediately" }
+                                option { value: "digest", "Daily digest" }
+                            }
+                        }
+                    }
+                }
+
                 // -- Notification Channels --
                 div { class: "space-y-4",
                     div {
@@ -155,19 +416,13 @@ pub fn NotificationSettingsScreen() -> Element {
                         // In-app channel
                         div { class: "flex items-center justify-between py-4",
                             div { class: "space-y-0.5 pr-4",
-                                div { class: "flex items-center gap-2",
-                                    p { class: "text-sm font-medium", "In-App" }
-                                    span { class: "text-[10px] px-1.5 py-0.5 rounded-full bg-muted text-muted-foreground font-medium",
-                                        "Coming Soon"
-                                    }
-                                }
+                                p { class: "text-sm font-medium", "In-App" }
                                 p { class: "text-xs text-muted-foreground",
                                     "Receive notifications within the admin panel."
                                 }
                             }
                             Checkbox {
                                 checked: *channel_in_app.read(),
-                                disabled: true,
                                 onchange: move |checked| channel_in_app.set(checked),
                             }
                         }
@@ -177,23 +432,65 @@ pub fn NotificationSettingsScreen() -> Element {
                             div { class: "space-y-0.5 pr-4",
                                 p { class: "text-sm font-medium", "Webhook" }
                                 p { class: "text-xs text-muted-foreground",
-                                    "Send notifications to an external endpoint via HTTP POST."
+                                    "Send site events to an external endpoint via signed HTTP POST."
                                 }
                             }
                             Checkbox {
                                 checked: *channel_webhook.read(),
+                                disabled: selected_endpoint().is_none(),
                                 onchange: move |checked| channel_webhook.set(checked),
                             }
                         }
                     }
                 }
 
-                // -- Webhook URL Configuration --
+                div { class: "flex items-center gap-3",
+                    Button {
+                        variant: ButtonVariant::Default,
+                        disabled: !loaded() || saving,
+                        onclick: on_save,
+                        if saving { "Saving..." } else { "Save Preferences" }
+                    }
+                    if saved {
+                        span { class: "text-sm text-muted-foreground", "Preferences saved." }
+                    }
+                }
+
+                // -- Webhook Configuration --
                 div { class: "space-y-4",
                     div {
                         h2 { class: "text-lg font-semibold", "Webhook Configuration" }
                         p { class: "text-sm text-muted-foreground",
-                            "Configure the endpoint URL for webhook notifications."
+                            "Site events are POSTed as JSON and signed with HMAC-SHA256 using the Standard Webhooks headers (webhook-id, webhook-timestamp, webhook-signature). Failed deliveries are retried with exponential backoff."
+                        }
+                    }
+
+                    if let Some(message) = webhook_error {
+                        div { class: "rounded-lg border border-red-200 bg-red-50 dark:bg-red-900/10 dark:border-red-800 p-4",
+                            p { class: "text-sm text-red-800 dark:text-red-400", "{message}" }
+                        }
+                    }
+
+                    if !endpoints.is_empty() {
+                        Card { class: "p-2 divide-y divide-border",
+                            for endpoint in endpoints.clone() {
+                                button {
+                                    key: "{endpoint.id}",
+                                    class: if selected_endpoint() == Some(endpoint.id) { "flex w-full items-center justify-between gap-3 rounded-md bg-muted/60 px-4 py-3 text-left" } else { "flex w-full items-center justify-between gap-3 rounded-md px-4 py-3 text-left hover:bg-muted/40" },
+                                    onclick: {
+                                        let endpoint = endpoint.clone();
+                                        move |_| select_endpoint(Some(endpoint.clone()))
+                                    },
+                                    span { class: "truncate text-sm font-medium", "{endpoint.url}" }
+                                    span { class: "shrink-0 text-xs text-muted-foreground",
+                                        if endpoint.is_active {
+                                            "{endpoint.events.len()} events"
+                                        } else {
+                                            "Paused"
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
 
@@ -203,25 +500,150 @@ pub fn NotificationSettingsScreen() -> Element {
                             input {
                                 r#type: "url",
                                 class: "w-full rounded-lg border border-border bg-background px-3 py-2 text-sm",
-                                placeholder: "https://example.com/webhooks/notifications",
+                                placeholder: "https://example.com/webhooks/ruxlog",
                                 value: "{webhook_url}",
                                 oninput: move |e| webhook_url.set(e.value()),
                             }
                             p { class: "text-xs text-muted-foreground",
-                                "Notifications will be sent as POST requests with a JSON payload to this URL."
+                                "The URL must be reachable from the internet; private and loopback addresses are refused."
+                            }
+                        }
+
+                        div { class: "grid gap-2",
+                            p { class: "text-sm font-medium", "Events" }
+                            for event in WebhookEvent::ALL {
+                                label { class: "flex items-center gap-3 text-sm",
+                                    Checkbox {
+                                        checked: webhook_events.read().contains(&event),
+                                        onchange: move |checked| {
+                                            let mut events = webhook_events.write();
+                                            events.retain(|e| *e != event);
+                                            if checked {
+                                                events.push(event);
+                                            }
+                                        },
+                                    }
+                                    span { "{event_label(event)}" }
+                                    code { class: "text-xs text-muted-foreground", "{event.as_str()}" }
+                                }
+                            }
+                        }
+
+                        if let Some(secret) = revealed_secret() {
+                            div { class: "rounded-lg border border-amber-200 bg-amber-50 dark:bg-amber-900/10 dark:border-amber-800 p-4 space-y-1",
+                                p { class: "text-sm font-medium", "Signing secret" }
+                                code { class: "block break-all text-xs", "{secret}" }
+                                p { class: "text-xs text-muted-foreground",
+                                    "Copy it now; it will not be shown again. Verify each delivery's webhook-signature with it."
+                                }
                             }
                         }
 
-                        div { class: "flex items-center gap-3",
+                        if let Some(result) = test_result {
+                            if result.success {
+                                p { class: "text-sm text-green-700 dark:text-green-400",
+                                    "Test delivered (HTTP {result.response_status.unwrap_or_default()})."
+                                }
+                            } else {
+                                p { class: "text-sm text-red-700 dark:text-red-400",
+                                    "Test failed: {result.error.clone().unwrap_or_default()}"
+                                }
+                            }
+                        }
+
+                        div { class: "flex flex-wrap items-center gap-3",
                             Button {
                                 variant: ButtonVariant::Outline,
-                                disabled: webhook_url.read().is_empty(),
-                                "Test Webhook"
+                                disabled: selected_endpoint().is_none() || testing,
+                                onclick: move |_| {
+                                    if let Some(id) = selected_endpoint() {
+                                        spawn(async move {
+                                            webhooks.test(id).await;
+                                        });
+                                    }
+                                },
+                                if testing { "Testing..." } else { "Test Webhook" }
                             }
                             Button {
                                 variant: ButtonVariant::Default,
-                                disabled: webhook_url.read().is_empty(),
-                                "Save Configuration"
+                                disabled: webhook_url.read().trim().is_empty()
+                                    || webhook_events.read().is_empty() || webhook_saving,
+                                onclick: on_save_webhook,
+                                if webhook_saving { "Saving..." } else { "Save Configuration" }
+                            }
+                            if let Some(id) = selected_endpoint() {
+                                Button {
+                                    variant: ButtonVariant::Outline,
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            if let Some(endpoint) = webhooks.rotate_secret(id).await {
+                                                revealed_secret.set(endpoint.secret);
+                                            }
+                                        });
+                                    },
+                                    "Rotate Secret"
+                                }
+                                Button {
+                                    variant: ButtonVariant::Outline,
+                                    onclick: move |_| select_endpoint(None),
+                                    "Add Another Endpoint"
+                                }
+                                Button {
+                                    variant: ButtonVariant::Destructive,
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            webhooks.remove(id).await;
+                                            webhooks_loaded.set(false);
+                                        });
+                                    },
+                                    "Delete Endpoint"
+                                }
+                            }
+                        }
+                    }
+
+                    if selected_endpoint().is_some() {
+                        div { class: "space-y-2",
+                            div { class: "flex items-center justify-between",
+                                h3 { class: "text-sm font-semibold", "Recent Deliveries" }
+                                Button {
+                                    variant: ButtonVariant::Ghost,
+                                    onclick: move |_| {
+                                        if let Some(id) = selected_endpoint() {
+                                            spawn(async move {
+                                                webhooks
+                                                    .list_deliveries(WebhookDeliveryListQuery::for_endpoint(id))
+                                                    .await;
+                                            });
+                                        }
+                                    },
+                                    "Refresh"
+                                }
+                            }
+                            Card { class: "overflow-x-auto",
+                                if deliveries.is_empty() {
+                                    p { class: "p-6 text-center text-sm text-muted-foreground",
+                                        "No deliveries yet."
+                                    }
+                                } else {
+                                    table { class: "w-full text-sm",
+                                        thead {
+                                            tr { class: "border-b border-border text-left text-xs text-muted-foreground",
+                                                th { class: "px-4 py-2 font-medium", "Event" }
+                                                th { class: "px-4 py-2 font-medium", "Status" }
+                                                th { class: "px-4 py-2 font-medium", "Attempts" }
+                                                th { class: "px-4 py-2 font-medium", "Response" }
+                                                th { class: "px-4 py-2 font-medium", "Created" }
+                                                th { class: "px-4 py-2" }
+                                            }
+                                        }
+                                        tbody {
+                                            for delivery in deliveries {
+                                                DeliveryRow { key: "{delivery.id}", delivery }
+                                            }
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -230,3 +652,51 @@ pub fn NotificationSettingsScreen() -> Element {
         }
     }
 }
+
+#[component]
+fn DeliveryRow(delivery: WebhookDelivery) -> Element {
+    let webhooks = use_webhooks();
+    let id = delivery.id;
+    let (status_label, status_class) = match delivery.status {
+        WebhookDeliveryStatus::Succeeded => ("Succeeded", "text-green-700 dark:text-green-400"),
+        WebhookDeliveryStatus::Pending => ("Pending", "text-amber-700 dark:text-amber-400"),
+        WebhookDeliveryStatus::Failed => ("Failed", "text-red-700 dark:text-red-400"),
+    };
+    let error = delivery.error.clone().unwrap_or_default();
+    let response = match delivery.response_status {
+        Some(status) => format!("HTTP {status}"),
+        None if !error.is_empty() => error.clone(),
+        None => "-".to_string(),
+    };
+    let created_at = delivery.created_at.format("%b %d, %H:%M").to_string();
+
+    rsx! {
+        tr { class: "border-b border-border last:border-0",
+            td { class: "px-4 py-2 font-mono text-xs", "{delivery.event.as_str()}" }
+            td { class: "px-4 py-2",
+                span { class: status_class, "{status_label}" }
+            }
+            td { class: "px-4 py-2", "{delivery.attempts}" }
+            td {
+                class: "px-4 py-2 max-w-xs truncate text-xs text-muted-foreground",
+                title: "{error}",
+                "{response}"
+            }
+            td { class: "px-4 py-2 text-xs text-muted-foreground", "{created_at}" }
+            td { class: "px-4 py-2 text-right",
+                // Pending rows are still being retried by the worker
+                if delivery.status != WebhookDeliveryStatus::Pending {
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| {
+                            spawn(async move {
+                                webhooks.redeliver(id).await;
+                            });
+                        },
+                        "Redeliver"
+                    }
+                }
+            }
+        }
+    }
+}
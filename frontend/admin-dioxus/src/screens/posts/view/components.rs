@@ -9,6 +9,14 @@ use ruxlog_shared::store::{use_post, EditorJsBlock, PostContent};
 #[cfg(debug_assertions)]
 use std::{cell::Cell, rc::Rc};
 
+#[cfg(feature = "analytics")]
+use crate::containers::analytics::post_engagement_card::PostEngagementCard;
+#[cfg(feature = "analytics")]
+use ruxlog_shared::store::analytics::{
+    use_analytics, use_analytics_filters, AnalyticsInterval, PostEngagementFilters,
+    PostEngagementRequest,
+};
+
 // M-9 (defense-in-depth XSS): the server already strips dangerous markup from
 // post content with ammonia on write, but every `dangerous_inner_html` sink is
 // an XSS hole if ANY unsanitized string ever reaches the client (a buggy
@@ -429,6 +437,41 @@ fn render_editorjs_content(content: &PostContent) -> Element {
 // Post View Screen
 // ============================================================================
 
+/// Reading engagement and traffic for the post, over the dashboard's current
+/// date range.
+#[cfg(feature = "analytics")]
+#[component]
+fn PostEngagementSection(post_id: i32) -> Element {
+    let analytics = use_analytics();
+    let filters = use_analytics_filters();
+
+    use_effect(use_reactive!(|post_id| {
+        spawn(async move {
+            let request = PostEngagementRequest {
+                envelope: filters.build_envelope(),
+                filters: PostEngagementFilters {
+                    post_id,
+                    group_by: AnalyticsInterval::Day,
+                },
+            };
+            analytics.fetch_post_engagement(request).await;
+        });
+    }));
+
+    rsx! {
+        div { class: "mt-10",
+            PostEngagementCard { frame: analytics.post_engagement.read().clone() }
+        }
+    }
+}
+
+#[cfg(not(feature = "analytics"))]
+#[component]
+fn PostEngagementSection(post_id: i32) -> Element {
+    let _ = post_id;
+    rsx! {}
+}
+
 #[component]
 pub fn PostsViewScreen(id: i32) -> Element {
     let posts = use_post();
@@ -577,6 +620,8 @@ pub fn PostsViewScreen(id: i32) -> Element {
                             span { "Status: {post.status}" }
                         }
                     }
+
+                    PostEngagementSection { post_id: id }
                 }
             }
         }
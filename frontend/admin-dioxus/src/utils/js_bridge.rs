@@ -6,7 +6,9 @@ use web_sys::File;
 /// Exposes window.editorjs_upload_file() to JavaScript
 #[wasm_bindgen]
 pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
-    use ruxlog_shared::store::{use_media, MediaReference, MediaUploadPayload, UploadStatus};
+    use ruxlog_shared::store::{
+        media::DIRECT_UPLOAD_THRESHOLD, use_media, MediaReference, MediaUploadPayload, UploadStatus,
+    };
     use serde::Serialize;
 
     tracing::debug!(
@@ -17,6 +19,10 @@ pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
     // Get media store reference (this is fine, it's just a static reference)
     let media_store = use_media();
 
+    // Videos and PDFs go through the chunked direct upload; give them two
+    // extra seconds per MiB on top of the base timeout.
+    let max_wait_secs = 30 + (file.size() / (1024.0 * 1024.0)).ceil() as u32 * 2;
+
     // Create upload payload
     let payload = MediaUploadPayload {
         file,
@@ -25,23 +31,28 @@ pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
         height: None,
     };
 
-    // Upload via media store
-    match media_store.upload(payload).await {
+    // Upload via media store; large attachments use the resumable flow
+    let upload = if payload.file.size() > DIRECT_UPLOAD_THRESHOLD {
+        media_store.upload_resumable(payload).await
+    } else {
+        media_store.upload(payload).await
+    };
+    match upload {
         Ok(blob_url) => {
             tracing::debug!(
                 "[editorjs_upload_file] Upload initiated, blob URL: {}",
                 &blob_url
             );
 
-            // Poll for upload completion
-            let max_wait = 30; // 30 seconds timeout
-            let mut elapsed = 0;
+            // Poll for upload completion, twice a second
+            let max_polls = max_wait_secs * 2;
+            let mut polls = 0;
 
             loop {
-                if elapsed >= max_wait {
-                    let err_msg = "Upload timeout after 30 seconds";
+                if polls >= max_polls {
+                    let err_msg = format!("Upload timeout after {} seconds", max_wait_secs);
                     tracing::error!("[editorjs_upload_file] {}", err_msg);
-                    return Err(JsValue::from_str(err_msg));
+                    return Err(JsValue::from_str(&err_msg));
                 }
 
                 if media_store.is_upload_complete(&blob_url) {
@@ -60,10 +71,22 @@ pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
                                 file: EditorJsFile,
                             }
 
+                            // The attaches tool and the consumer renderer
+                            // pick a player from mime_type / extension.
                             #[derive(Serialize)]
                             struct EditorJsFile {
                                 url: String,
                                 media_id: i32,
+                                size: i64,
+                                mime_type: String,
+                                #[serde(skip_serializing_if = "Option::is_none")]
+                                extension: Option<String>,
+                                #[serde(skip_serializing_if = "Option::is_none")]
+                                duration_ms: Option<i64>,
+                                #[serde(skip_serializing_if = "Option::is_none")]
+                                page_count: Option<i32>,
+                                #[serde(skip_serializing_if = "Option::is_none")]
+                                poster: Option<String>,
                             }
 
                             let response = EditorJsUploadResponse {
@@ -71,6 +94,12 @@ pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
                                 file: EditorJsFile {
                                     url: media.file_url,
                                     media_id: media.id,
+                                    size: media.size,
+                                    mime_type: media.mime_type,
+                                    extension: media.extension,
+                                    duration_ms: media.duration_ms,
+                                    page_count: media.page_count,
+                                    poster: media.poster_url,
                                 },
                             };
 
@@ -99,7 +128,7 @@ pub async fn editorjs_upload_file(file: File) -> Result<JsValue, JsValue> {
 
                 // Wait 500ms before checking again
                 dioxus_time::sleep(std::time::Duration::from_millis(500)).await;
-                elapsed += 1;
+                polls += 1;
             }
         }
         Err(err_msg) => {
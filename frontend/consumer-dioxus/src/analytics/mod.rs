@@ -2,6 +2,8 @@
 //!
 //! This module provides Firebase Analytics tracking capabilities for the application.
 //! Only available on wasm32 target and when the `analytics` feature is enabled.
+//! First-party pageviews sent to the blog API live in [`pageview`] and are
+//! always on.
 //!
 //! # Features
 //! - Page view tracking
@@ -38,6 +40,8 @@
 //! }
 //! ```
 
+pub mod pageview;
+
 #[cfg(feature = "analytics")]
 pub mod bindings;
 
@@ -0,0 +1,97 @@
+//! First-party pageview and reading engagement collection
+//!
+//! Posts each page view, and reading heartbeats on post pages, to the blog
+//! API (`/analytics/v1/collect`), which derives the referrer host, UTM
+//! campaign, device class, country and a daily visitor hash server-side.
+//! Nothing is stored in the browser, and this works without the Firebase
+//! `analytics` feature.
+
+#[cfg(target_arch = "wasm32")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `document.referrer` and the landing query string describe how the visitor
+/// arrived; after client-side navigation they are stale, so only the first
+/// pageview of a page load carries them.
+#[cfg(target_arch = "wasm32")]
+static LANDED: AtomicBool = AtomicBool::new(false);
+
+/// Record a pageview for the current location. Pass `post_id` on post pages so
+/// per-post traffic breakdowns can filter on it.
+pub fn track_pageview(post_id: Option<i32>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let location = window.location();
+        let path = location.pathname().unwrap_or_else(|_| "/".to_string());
+
+        let landing = !LANDED.swap(true, Ordering::Relaxed);
+        let (referrer, query) = if landing {
+            (
+                window
+                    .document()
+                    .map(|document| document.referrer())
+                    .filter(|referrer| !referrer.is_empty()),
+                location.search().ok().filter(|query| !query.is_empty()),
+            )
+        } else {
+            (None, None)
+        };
+
+        let payload = serde_json::json!({
+            "path": path,
+            "post_id": post_id,
+            "referrer": referrer,
+            "query": query,
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = oxcore::http::post("/analytics/v1/collect", &payload)
+                .send()
+                .await
+            {
+                tracing::debug!("Pageview not recorded: {e}");
+            }
+        });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = post_id;
+    }
+}
+
+/// Record a reading heartbeat on a post page: `engaged_ms` of active reading
+/// since the previous heartbeat and the deepest `scroll_depth` (percent)
+/// reached so far.
+pub fn track_engagement(post_id: i32, scroll_depth: u8, engaged_ms: u32) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let path = window
+            .location()
+            .pathname()
+            .unwrap_or_else(|_| "/".to_string());
+
+        let payload = serde_json::json!({
+            "kind": "engagement",
+            "path": path,
+            "post_id": post_id,
+            "scroll_depth": scroll_depth.min(100),
+            "engaged_ms": engaged_ms,
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = oxcore::http::post("/analytics/v1/collect", &payload)
+                .send()
+                .await
+            {
+                tracing::debug!("Engagement heartbeat not recorded: {e}");
+            }
+        });
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (post_id, scroll_depth, engaged_ms);
+    }
+}
@@ -1,10 +1,12 @@
 use dioxus::prelude::*;
 use hmziq_dioxus_free_icons::icons::ld_icons::{
-    LdCornerDownRight, LdFlag, LdLoader, LdMessageCircle, LdSend,
+    LdCornerDownRight, LdFlag, LdHeart, LdLoader, LdMessageCircle, LdSend,
 };
 use hmziq_dioxus_free_icons::Icon;
 use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
-use ruxlog_shared::store::comments::{Comment, CommentCreatePayload};
+use ruxlog_shared::store::comments::{
+    Comment, CommentCreatePayload, CommentLikeStatus, CommentReaction, CommentSort,
+};
 use ruxlog_shared::store::use_auth;
 use ruxlog_shared::store::use_comments;
 
@@ -31,8 +33,36 @@ pub fn CommentsSection(props: CommentsSectionProps) -> Element {
         });
     });
 
+    // Once the list is in, load the viewer's likes and reactions for it. The
+    // memo keeps like/reaction count updates from refetching the batch.
+    let comment_ids = use_memo(move || {
+        comments_store
+            .list
+            .read()
+            .data
+            .as_ref()
+            .map(|list| list.data.iter().map(|c| c.id).collect::<Vec<i32>>())
+            .unwrap_or_default()
+    });
+    use_effect(move || {
+        let comments = comments_store;
+        let logged_in = auth_store.user.read().is_some();
+        let ids = comment_ids();
+        if logged_in && !ids.is_empty() {
+            spawn(async move {
+                comments.fetch_like_status(ids).await;
+            });
+        }
+    });
+
     let comments_frame = comments_store.list.read();
+    let current_sort = *comments_store.sort.read();
     let add_frame = comments_store.add.read();
+    // A held comment is not in the public list, so tell the author where it went.
+    let awaiting_moderation = add_frame
+        .data
+        .as_ref()
+        .is_some_and(|c| c.post_id == post_id && !c.is_published());
     let current_user = auth_store.user.read();
     let is_logged_in = current_user.is_some();
 
@@ -66,6 +96,21 @@ pub fn CommentsSection(props: CommentsSectionProps) -> Element {
                 if let Some(data) = comments_frame.data.clone() {
                     span { "({data.data.len()})" }
                 }
+                div { class: "ml-auto flex items-center gap-1 text-sm",
+                    for sort in [CommentSort::Oldest, CommentSort::Newest, CommentSort::Top] {
+                        button {
+                            key: "{sort.label()}",
+                            class: if sort == current_sort { "px-2 py-1 rounded-md bg-muted font-medium" } else { "px-2 py-1 rounded-md hover:bg-muted/60" },
+                            onclick: move |_| {
+                                let comments = comments_store;
+                                spawn(async move {
+                                    comments.set_sort(post_id, sort).await;
+                                });
+                            },
+                            "{sort.label()}"
+                        }
+                    }
+                }
             }
 
             // Comment form
@@ -114,6 +159,12 @@ pub fn CommentsSection(props: CommentsSectionProps) -> Element {
                                 }
                             }
 
+                            if awaiting_moderation {
+                                p { class: "mt-2 text-sm text-muted-foreground",
+                                    "Thanks! Your comment will appear once a moderator approves it."
+                                }
+                            }
+
                             div { class: "flex justify-end mt-3",
                                 button {
                                     class: "flex items-center gap-2 px-4 py-2 rounded-lg bg-primary hover:bg-primary/90 transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
@@ -189,8 +240,36 @@ fn CommentItem(props: CommentItemProps) -> Element {
     let author_name = comment.author_name();
     let author_initial = author_name.chars().next().unwrap_or('A').to_uppercase();
 
+    let comments_store = use_comments();
+    let status = comments_store
+        .like_status
+        .read()
+        .get(&comment_id)
+        .and_then(|frame| frame.data.clone())
+        .unwrap_or(CommentLikeStatus {
+            comment_id,
+            is_liked: false,
+            likes_count: comment.likes_count,
+            reactions: Vec::new(),
+        });
+    let busy = comments_store
+        .like_actions
+        .read()
+        .get(&comment_id)
+        .is_some_and(|frame| frame.is_loading());
+    let is_liked = status.is_liked;
+    let reaction_count = |reaction: CommentReaction| {
+        comment
+            .reactions
+            .iter()
+            .find(|r| r.reaction == reaction)
+            .map(|r| r.count)
+            .unwrap_or(0)
+    };
+
     rsx! {
-        div { class: "flex gap-3",
+        // Notification emails link straight to the comment.
+        div { id: "comment-{comment_id}", class: "flex gap-3 scroll-mt-24",
             // Avatar
             div { class: "flex-shrink-0",
                 div { class: "w-10 h-10 rounded-full bg-muted flex items-center justify-center font-semibold",
@@ -202,6 +281,13 @@ fn CommentItem(props: CommentItemProps) -> Element {
             div { class: "flex-1 min-w-0",
                 div { class: "flex items-center gap-2 mb-1",
                     span { class: "font-semibold", "{author_name}" }
+                    for badge in comment.badges.iter() {
+                        span {
+                            key: "{badge.label()}",
+                            class: "px-1.5 py-0.5 rounded text-[10px] font-medium uppercase tracking-wide bg-primary/10 text-primary",
+                            "{badge.label()}"
+                        }
+                    }
                     span { class: "text-xs", "{created_at}" }
                 }
 
@@ -209,9 +295,56 @@ fn CommentItem(props: CommentItemProps) -> Element {
                     "{comment.content}"
                 }
 
+                // Reactions
+                div { class: "flex flex-wrap items-center gap-1.5 mt-2",
+                    for reaction in CommentReaction::ALL {
+                        if reaction_count(reaction) > 0 || props.is_logged_in {
+                            button {
+                                key: "{reaction.emoji()}",
+                                class: if status.reactions.contains(&reaction) { "flex items-center gap-1 px-2 py-0.5 rounded-full border border-primary bg-primary/10 text-xs" } else { "flex items-center gap-1 px-2 py-0.5 rounded-full border border-border text-xs" },
+                                disabled: !props.is_logged_in || busy,
+                                onclick: {
+                                    let reacted = status.reactions.contains(&reaction);
+                                    move |_| {
+                                        let comments = comments_store;
+                                        spawn(async move {
+                                            if reacted {
+                                                comments.unreact(comment_id, reaction).await;
+                                            } else {
+                                                comments.react(comment_id, reaction).await;
+                                            }
+                                        });
+                                    }
+                                },
+                                span { "{reaction.emoji()}" }
+                                if reaction_count(reaction) > 0 {
+                                    span { "{reaction_count(reaction)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Actions
                 div { class: "flex items-center gap-4 mt-2",
                     if props.is_logged_in {
+                        button {
+                            class: if is_liked { "flex items-center gap-1.5 text-xs text-primary" } else { "flex items-center gap-1.5 text-xs" },
+                            disabled: busy,
+                            onclick: move |_| {
+                                let comments = comments_store;
+                                spawn(async move {
+                                    if is_liked {
+                                        comments.unlike(comment_id).await;
+                                    } else {
+                                        comments.like(comment_id).await;
+                                    }
+                                });
+                            },
+                            Icon { icon: LdHeart, class: "w-3.5 h-3.5" }
+                            "{comment.likes_count}"
+                        }
+
                         button {
                             class: "flex items-center gap-1.5 text-xs",
                             onclick: move |_| props.on_reply.call(comment_id),
@@ -224,6 +357,11 @@ fn CommentItem(props: CommentItemProps) -> Element {
                             Icon { icon: LdFlag, class: "w-3.5 h-3.5" }
                             "Report"
                         }
+                    } else if comment.likes_count > 0 {
+                        span { class: "flex items-center gap-1.5 text-xs",
+                            Icon { icon: LdHeart, class: "w-3.5 h-3.5" }
+                            "{comment.likes_count}"
+                        }
                     }
                 }
             }
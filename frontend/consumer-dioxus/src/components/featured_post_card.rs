@@ -1,4 +1,5 @@
 use super::post_card::{estimate_reading_time, format_date};
+use super::ResponsivePicture;
 use dioxus::prelude::*;
 use hmziq_dioxus_free_icons::icons::ld_icons::LdArrowRight;
 use hmziq_dioxus_free_icons::Icon;
@@ -28,10 +29,13 @@ pub fn FeaturedPostCard(props: FeaturedPostCardProps) -> Element {
             // Media section
             div { class: "relative aspect-[21/9] overflow-hidden bg-muted",
                 if let Some(img) = &post.featured_image {
-                    img {
-                        src: "{img.file_url}",
-                        alt: "{post.title}",
+                    ResponsivePicture {
+                        src: img.file_url.clone(),
+                        alt: img.alt_text.clone().unwrap_or_else(|| post.title.clone()),
+                        responsive: img.responsive.clone(),
+                        sizes: "(max-width: 1280px) 100vw, 1280px".to_string(),
                         class: "w-full h-full object-cover transition-transform duration-500 group-hover:scale-105",
+                        style: "object-position: {img.object_position()};",
                     }
                 } else {
                     // Fallback
@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::router::Route;
+use crate::server_fns::resolve_redirect;
+
+/// Shown when a post or category lookup comes back empty. The API is asked
+/// whether `path` moved or was removed first: moved content is replaced in
+/// history with its new address, removed content says so instead of
+/// "not found".
+#[component]
+pub fn MissingContent(path: String, title: String, message: String) -> Element {
+    let nav = use_navigator();
+
+    let resolved = use_server_future(move || {
+        let path = path.clone();
+        async move { resolve_redirect(path).await }
+    })?;
+
+    let redirect = resolved().and_then(Result::ok).flatten();
+    let location = redirect.as_ref().and_then(|r| r.location.clone());
+    let is_gone = redirect.as_ref().is_some_and(|r| r.status_code == 410);
+
+    use_effect(use_reactive!(|location| {
+        if let Some(location) = location {
+            nav.replace(NavigationTarget::<Route>::from(location.as_str()));
+        }
+    }));
+
+    if location.is_some() {
+        return rsx! {
+            div { class: "min-h-screen flex items-center justify-center",
+                div { class: "animate-pulse text-muted-foreground", "Redirecting..." }
+            }
+        };
+    }
+
+    let (title, message) = if is_gone {
+        (
+            "This page has been removed".to_string(),
+            "The content that used to live here is no longer available.".to_string(),
+        )
+    } else {
+        (title, message)
+    };
+
+    rsx! {
+        div { class: "min-h-screen flex items-center justify-center",
+            div { class: "text-center max-w-md",
+                h1 { class: "text-2xl font-bold mb-2", "{title}" }
+                if !message.is_empty() {
+                    p { class: "text-muted-foreground mb-4", "{message}" }
+                }
+                button {
+                    class: "text-primary hover:underline",
+                    onclick: move |_| { nav.push(Route::HomeScreen {}); },
+                    "Back to home"
+                }
+            }
+        }
+    }
+}
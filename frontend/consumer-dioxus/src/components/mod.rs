@@ -4,12 +4,14 @@ pub mod category_card;
 pub mod cookie_consent;
 pub mod engagement;
 pub mod featured_post_card;
+pub mod missing_content;
 pub mod mouse_tracking_card;
 pub mod paywall;
 pub mod post_card;
 pub mod posts_skeleton;
 pub mod reading_progress;
 pub mod related_posts;
+pub mod responsive_picture;
 pub mod series_navigation;
 pub mod share_box;
 pub mod table_of_contents;
@@ -24,12 +26,14 @@ pub use category_card::CategoryCard;
 pub use cookie_consent::CookieConsent;
 pub use engagement::{ActionBar, EngagementBar, LikeButton, ShareButton};
 pub use featured_post_card::FeaturedPostCard;
+pub use missing_content::MissingContent;
 pub use mouse_tracking_card::MouseTrackingCard;
 pub use paywall::PaywallOverlay;
 pub use post_card::{estimate_reading_time, format_date, get_gradient_for_tag, PostCard};
 pub use posts_skeleton::{PostCardSkeleton, PostsEmptyState, PostsLoadingSkeleton};
 pub use reading_progress::ReadingProgressBar;
 pub use related_posts::RelatedPosts;
+pub use responsive_picture::ResponsivePicture;
 pub use series_navigation::SeriesNavigation;
 pub use share_box::ShareBox;
 pub use table_of_contents::TableOfContents;
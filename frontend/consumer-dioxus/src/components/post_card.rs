@@ -1,3 +1,4 @@
+use super::ResponsivePicture;
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use hmziq_dioxus_free_icons::icons::ld_icons::LdLock;
@@ -83,10 +84,13 @@ pub fn PostCard(props: PostCardProps) -> Element {
             // Media
             div { class: "relative aspect-[16/9] overflow-hidden bg-muted",
                 if let Some(img) = &post.featured_image {
-                    img {
-                        src: "{img.file_url}",
-                        alt: "{post.title}",
+                    ResponsivePicture {
+                        src: img.file_url.clone(),
+                        alt: img.alt_text.clone().unwrap_or_else(|| post.title.clone()),
+                        responsive: img.responsive.clone(),
+                        sizes: "(max-width: 768px) 100vw, (max-width: 1280px) 50vw, 400px".to_string(),
                         class: "w-full h-full object-cover transition-transform duration-500 group-hover:scale-105",
+                        style: "object-position: {img.object_position()};",
                     }
                 } else {
                     // Fallback
@@ -1,13 +1,60 @@
 use dioxus::prelude::*;
 
+/// Interval between reading heartbeats.
+#[cfg(target_arch = "wasm32")]
+const HEARTBEAT_MS: u32 = 15_000;
+/// A reader who has not scrolled for this long is treated as idle and stops
+/// accruing reading time.
+#[cfg(target_arch = "wasm32")]
+const IDLE_AFTER_MS: f64 = 60_000.0;
+
+/// Current scroll position as a percentage of the scrollable height, or
+/// `None` when the page does not scroll.
+#[cfg(target_arch = "wasm32")]
+fn scroll_percent() -> Option<u8> {
+    let window = web_sys::window()?;
+    let body = window.document()?.body()?;
+    let scroll_height = body.scroll_height() as f64;
+    let client_height = window
+        .inner_height()
+        .ok()
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+    let scrollable = scroll_height - client_height;
+    if scrollable <= 0.0 {
+        return None;
+    }
+    let scroll_top = window.scroll_y().unwrap_or(0.0);
+    Some(((scroll_top / scrollable) * 100.0).clamp(0.0, 100.0) as u8)
+}
+
 /// A reading progress bar that shows scroll position on post pages.
 /// Renders a thin bar at the top of the viewport that fills as the user scrolls.
+///
+/// With a `post_id`, it also sends a first-party reading heartbeat every 15
+/// seconds while the tab is visible and the reader is active, carrying the
+/// deepest scroll position reached.
 #[component]
-pub fn ReadingProgressBar() -> Element {
+pub fn ReadingProgressBar(#[props(default)] post_id: Option<i32>) -> Element {
     // `mut` is required on wasm32 (mutated via `.set()` in the scroll closure
     // below); allowed on non-wasm where it is unused.
     #[allow(unused_mut)]
     let mut progress = use_signal(|| 0u8);
+    // Deepest position reached and the time of the last scroll, read by the
+    // heartbeat.
+    let mut max_depth = use_signal(|| 0u8);
+    #[cfg(target_arch = "wasm32")]
+    let mut last_active = use_signal(|| 0f64);
+    let mut current_post = use_signal(|| post_id);
+
+    // The bar can stay mounted while navigating between posts; restart the
+    // depth tracking when the post changes.
+    use_effect(use_reactive!(|post_id| {
+        if *current_post.peek() != post_id {
+            current_post.set(post_id);
+            max_depth.set(0);
+        }
+    }));
 
     #[cfg(target_arch = "wasm32")]
     use_drop(move || {
@@ -34,22 +81,11 @@ pub fn ReadingProgressBar() -> Element {
             // The closure mutates `progress` via `Signal::set` (which takes
             // `&mut self`), so it is `FnMut`, not `Fn`.
             let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move || {
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        if let Some(body) = document.body() {
-                            let scroll_height = body.scroll_height() as f64;
-                            let client_height = window
-                                .inner_height()
-                                .ok()
-                                .and_then(|v| v.as_f64())
-                                .unwrap_or(0.0);
-                            let scrollable = scroll_height - client_height;
-                            if scrollable > 0.0 {
-                                let scroll_top = window.scroll_y().unwrap_or(0.0);
-                                let pct = ((scroll_top / scrollable) * 100.0) as u8;
-                                progress.set(pct);
-                            }
-                        }
+                last_active.set(js_sys::Date::now());
+                if let Some(pct) = scroll_percent() {
+                    progress.set(pct);
+                    if pct > *max_depth.peek() {
+                        max_depth.set(pct);
                     }
                 }
             }) as Box<dyn FnMut()>);
@@ -62,6 +98,27 @@ pub fn ReadingProgressBar() -> Element {
             );
             let _ = closure.forget();
         });
+
+        // The task is cancelled when the bar unmounts.
+        use_future(move || async move {
+            last_active.set(js_sys::Date::now());
+            loop {
+                gloo_timers::future::TimeoutFuture::new(HEARTBEAT_MS).await;
+                let Some(post_id) = *current_post.peek() else {
+                    continue;
+                };
+                let hidden = web_sys::window()
+                    .and_then(|window| window.document())
+                    .map(|document| document.hidden())
+                    .unwrap_or(true);
+                if hidden || js_sys::Date::now() - *last_active.peek() > IDLE_AFTER_MS {
+                    continue;
+                }
+                // A page that does not scroll is read in full once it is seen.
+                let depth = scroll_percent().unwrap_or(100).max(*max_depth.peek());
+                crate::analytics::pageview::track_engagement(post_id, depth, HEARTBEAT_MS);
+            }
+        });
     }
 
     let width = format!("{}%", progress());
@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use ruxlog_shared::store::media::ResponsiveImage;
+
+/// `<picture>` with AVIF/WebP sources and a blurhash-coloured / LQIP
+/// background that shows until the image has loaded. Falls back to a plain
+/// `<img src>` when the payload carries no responsive data.
+#[component]
+pub fn ResponsivePicture(
+    src: String,
+    alt: String,
+    #[props(default)] responsive: Option<ResponsiveImage>,
+    /// Overrides the server's `sizes` for layouts narrower than the article column.
+    #[props(default)]
+    sizes: Option<String>,
+    #[props(default)] class: String,
+    #[props(default)] style: String,
+    /// Above-the-fold images skip lazy loading.
+    #[props(default)]
+    eager: bool,
+) -> Element {
+    let loading = if eager { "eager" } else { "lazy" };
+
+    let Some(image) = responsive else {
+        return rsx! {
+            img {
+                src: "{src}",
+                alt: "{alt}",
+                class: "{class}",
+                style: "{style}",
+                loading: "{loading}",
+                decoding: "async",
+            }
+        };
+    };
+
+    let sizes = sizes.unwrap_or_else(|| image.sizes.clone());
+    let mut placeholder = String::new();
+    if let Some(color) = image.placeholder_color() {
+        placeholder.push_str(&format!("background-color: {color};"));
+    }
+    if let Some(lqip) = &image.placeholder {
+        placeholder.push_str(&format!(
+            "background-image: url('{lqip}'); background-size: cover; background-position: center;"
+        ));
+    }
+
+    rsx! {
+        picture {
+            for source in image.sources.iter() {
+                source {
+                    r#type: "{source.mime_type}",
+                    srcset: "{source.srcset}",
+                    sizes: "{sizes}",
+                }
+            }
+            img {
+                src: "{image.src}",
+                srcset: image.srcset.clone(),
+                sizes: image.srcset.as_ref().map(|_| sizes.clone()),
+                width: image.width.map(|w| w.to_string()),
+                height: image.height.map(|h| h.to_string()),
+                alt: "{alt}",
+                class: "{class}",
+                style: "{placeholder} {style}",
+                loading: "{loading}",
+                decoding: "async",
+            }
+        }
+    }
+}
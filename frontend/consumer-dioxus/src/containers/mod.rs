@@ -1,3 +1,4 @@
+use crate::analytics::pageview;
 use crate::components::CookieConsent;
 use crate::config::{DarkMode, BRAND};
 use crate::router::Route;
@@ -11,7 +12,9 @@ use hmziq_dioxus_free_icons::icons::{
 use hmziq_dioxus_free_icons::Icon;
 
 #[cfg(feature = "consumer-auth")]
-use hmziq_dioxus_free_icons::icons::ld_icons::LdLogIn;
+use hmziq_dioxus_free_icons::icons::ld_icons::{LdBell, LdLogIn};
+#[cfg(feature = "consumer-auth")]
+use ruxlog_shared::components::NotificationBell;
 #[cfg(feature = "consumer-auth")]
 use ruxlog_shared::use_auth;
 
@@ -25,6 +28,18 @@ pub fn NavBarContainer() -> Element {
     #[cfg(feature = "consumer-auth")]
     let user = auth_store.user.read();
 
+    // First-party pageview on every route change. Post pages record their own
+    // once the post id is loaded.
+    let route: Route = use_route();
+    use_effect(use_reactive!(|route| {
+        if !matches!(
+            route,
+            Route::PostViewScreen { .. } | Route::LocalizedPostViewScreen { .. }
+        ) {
+            pageview::track_pageview(None);
+        }
+    }));
+
     let mut dark_theme = use_context_provider(|| Signal::new(DarkMode(true)));
     let mut has_js_support = use_signal(|| false);
 
@@ -81,6 +96,24 @@ pub fn NavBarContainer() -> Element {
         }
     };
 
+    // Notification bell for signed-in users (only with consumer-auth feature)
+    let notifications_ui: Option<Element> = {
+        #[cfg(feature = "consumer-auth")]
+        {
+            user.is_some().then(|| {
+                rsx! {
+                    NotificationBell { button_class: "icon-button",
+                        Icon { icon: LdBell, class: "w-5 h-5" }
+                    }
+                }
+            })
+        }
+        #[cfg(not(feature = "consumer-auth"))]
+        {
+            None
+        }
+    };
+
     // Prepare auth UI element (conditionally compiled)
     let auth_ui: Option<Element> = {
         #[cfg(feature = "consumer-auth")]
@@ -182,6 +215,9 @@ pub fn NavBarContainer() -> Element {
                             // Theme toggle (only available on WASM/webview, not native renderer)
                             { theme_toggle_ui }
 
+                            // Notifications (only with consumer-auth feature)
+                            { notifications_ui }
+
                             // User menu - use Dioxus Link for client-side navigation (only with consumer-auth feature)
                             { auth_ui }
                         }
@@ -9,6 +9,18 @@ pub const CONSUMER_SITE_URL: &str = match std::option_env!("CONSUMER_SITE_URL")
     None => "http://localhost:1108", // Matches CONSUMER_PORT
 };
 
+// Locale served without a prefix; must match the API's DEFAULT_LOCALE
+pub const DEFAULT_LOCALE: &str = match std::option_env!("DEFAULT_LOCALE") {
+    Some(locale) => locale,
+    None => "en",
+};
+
+// Other content locales, comma separated, served under /{locale}
+pub const CONTENT_LOCALES: &str = match std::option_env!("CONTENT_LOCALES") {
+    Some(locales) => locales,
+    None => "",
+};
+
 // Firebase Analytics Configuration
 #[cfg(feature = "analytics")]
 pub const FIREBASE_API_KEY: &str = match std::option_env!("FIREBASE_API_KEY") {
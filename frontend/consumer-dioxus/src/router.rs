@@ -1,8 +1,9 @@
 use crate::containers::NavBarContainer;
 use crate::screens::{
-    AboutScreen, AdvertiseScreen, BillingScreen, CategoriesScreen, CategoryDetailScreen,
-    ContactScreen, HomeScreen, PostViewScreen, PricingScreen, PrivacyPolicyScreen, SearchScreen,
-    TagDetailScreen, TagsScreen, TermsScreen,
+    AboutScreen, AdvertiseScreen, AuthorScreen, BillingScreen, CategoriesScreen,
+    CategoryDetailScreen, ContactScreen, HomeScreen, LocalizedHomeScreen, LocalizedPostViewScreen,
+    PostViewScreen, PricingScreen, PrivacyPolicyScreen, SearchScreen, SeriesScreen, TagDetailScreen,
+    TagsScreen, TermsScreen,
 };
 use dioxus::prelude::*;
 
@@ -34,6 +35,12 @@ pub enum Route {
     #[route("/categories/:slug")]
     CategoryDetailScreen { slug: String },
 
+    #[route("/authors/:slug")]
+    AuthorScreen { slug: String },
+
+    #[route("/series/:slug")]
+    SeriesScreen { slug: String },
+
     #[cfg(feature = "consumer-auth")]
     #[route("/login")]
     LoginScreen {},
@@ -73,4 +80,11 @@ pub enum Route {
 
     #[route("/billing")]
     BillingScreen {},
+
+    // Content in non-default locales. Static segments above win over `:locale`.
+    #[route("/:locale")]
+    LocalizedHomeScreen { locale: String },
+
+    #[route("/:locale/posts/:slug")]
+    LocalizedPostViewScreen { locale: String, slug: String },
 }
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::components::{PostCard, PostsLoadingSkeleton};
+use crate::router::Route;
+use crate::seo::{
+    breadcrumb_schema, person_schema, SeoHead, SeoImage, SeoMetadataBuilder, StructuredData,
+};
+use crate::server_fns::fetch_author;
+use crate::utils::locale::post_route;
+use dioxus::prelude::*;
+use oxstore::AppError;
+use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
+
+#[component]
+pub fn AuthorScreen(slug: String) -> Element {
+    let nav = use_navigator();
+
+    let author_result = use_server_future(move || {
+        let slug = slug.clone();
+        async move { fetch_author(slug).await }
+    })?;
+
+    // Listed posts can be in any locale; open each at its own localized URL.
+    let post_locales = use_memo(move || match author_result() {
+        Some(Ok(Some(page))) => page
+            .data
+            .iter()
+            .map(|p| (p.slug.clone(), p.locale.clone()))
+            .collect::<HashMap<_, _>>(),
+        _ => HashMap::new(),
+    });
+    let on_post_click = move |post_slug: String| {
+        let locale = post_locales.read().get(&post_slug).cloned().flatten();
+        nav.push(post_route(locale.as_deref(), post_slug));
+    };
+
+    let page = match author_result() {
+        Some(Ok(page)) => page,
+        Some(Err(e)) => {
+            return rsx! {
+                div { class: "min-h-screen flex items-center justify-center",
+                    div { class: "max-w-md w-full",
+                        ErrorDetails {
+                            error: Some(AppError::Other { message: e.to_string() }),
+                            variant: ErrorDetailsVariant::Collapsed,
+                        }
+                    }
+                }
+            };
+        }
+        None => return rsx! { PostsLoadingSkeleton {} },
+    };
+
+    let Some(page) = page else {
+        return rsx! {
+            div { class: "min-h-screen flex items-center justify-center",
+                div { class: "text-center",
+                    h1 { class: "text-2xl font-bold mb-4", "Author not found" }
+                    button {
+                        class: "text-primary hover:underline",
+                        onclick: move |_| { nav.push(Route::HomeScreen {}); },
+                        "Back to home"
+                    }
+                }
+            }
+        };
+    };
+
+    let author = page.author.clone();
+    let path = format!("/authors/{}", author.slug);
+    let description = author
+        .bio
+        .clone()
+        .unwrap_or_else(|| format!("Posts by {}", author.name));
+    let image = author.avatar.as_ref().map(|img| SeoImage {
+        url: img.file_url.clone(),
+        alt: author.name.clone(),
+        width: img.width.and_then(|w| u32::try_from(w).ok()),
+        height: img.height.and_then(|h| u32::try_from(h).ok()),
+    });
+
+    rsx! {
+        SeoHead {
+            metadata: SeoMetadataBuilder::new()
+                .title(&author.name)
+                .description(&description)
+                .canonical(&path)
+                .image_struct(image)
+                .build()
+        }
+        StructuredData { json_ld: person_schema(&author) }
+        StructuredData {
+            json_ld: breadcrumb_schema(vec![("Home", "/"), (&author.name, &path)])
+        }
+
+        div { class: "min-h-screen",
+            div { class: "container mx-auto px-4 py-8 md:py-12 lg:py-16 max-w-6xl",
+                header { class: "flex flex-col sm:flex-row gap-6 items-start mb-10",
+                    if let Some(avatar) = &author.avatar {
+                        img {
+                            class: "w-20 h-20 rounded-full object-cover",
+                            src: "{avatar.file_url}",
+                            alt: "{author.name}",
+                        }
+                    }
+                    div { class: "space-y-3",
+                        h1 { class: "text-3xl font-bold", "{author.name}" }
+                        if let Some(bio) = &author.bio {
+                            p { class: "text-muted-foreground max-w-2xl whitespace-pre-line", "{bio}" }
+                        }
+                        div { class: "flex flex-wrap gap-3 text-sm",
+                            if let Some(website) = &author.website_url {
+                                a {
+                                    class: "text-primary hover:underline",
+                                    href: "{website}",
+                                    rel: "me noopener",
+                                    target: "_blank",
+                                    "Website"
+                                }
+                            }
+                            for link in author.social_links.iter() {
+                                a {
+                                    key: "{link.url}",
+                                    class: "text-primary hover:underline",
+                                    href: "{link.url}",
+                                    rel: "me noopener",
+                                    target: "_blank",
+                                    "{link.label}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h2 { class: "text-xl font-semibold mb-6", "Posts ({page.total})" }
+                if page.data.is_empty() {
+                    div { class: "flex items-center justify-center py-20",
+                        div { "No posts found" }
+                    }
+                } else {
+                    div { class: "grid md:grid-cols-2 lg:grid-cols-3 gap-6",
+                        for post in page.data.iter() {
+                            PostCard {
+                                key: "{post.id}",
+                                post: post.clone(),
+                                on_click: on_post_click,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
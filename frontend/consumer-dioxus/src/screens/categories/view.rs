@@ -1,6 +1,6 @@
-use crate::components::{PostCard, PostsLoadingSkeleton};
+use crate::components::{MissingContent, PostCard, PostsLoadingSkeleton};
 use crate::router::Route;
-use crate::seo::{breadcrumb_schema, SeoHead, SeoMetadataBuilder, StructuredData};
+use crate::seo::{breadcrumb_schema, og_card_url, SeoHead, SeoMetadataBuilder, StructuredData};
 use crate::server_fns::{fetch_category_by_slug, fetch_posts_by_category};
 use dioxus::prelude::*;
 use oxstore::AppError;
@@ -9,6 +9,7 @@ use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
 #[component]
 pub fn CategoryDetailScreen(slug: String) -> Element {
     let nav = use_navigator();
+    let path = format!("/categories/{slug}");
 
     let category_result = use_server_future(move || {
         let slug = slug.clone();
@@ -37,15 +38,10 @@ pub fn CategoryDetailScreen(slug: String) -> Element {
 
     let Some(category) = category else {
         return rsx! {
-            div { class: "min-h-screen flex items-center justify-center",
-                div { class: "text-center",
-                    h1 { class: "text-2xl font-bold mb-4", "Category not found" }
-                    button {
-                        class: "text-primary hover:underline",
-                        onclick: move |_| { nav.push(Route::HomeScreen {}); },
-                        "Back to home"
-                    }
-                }
+            MissingContent {
+                path,
+                title: "Category not found".to_string(),
+                message: String::new(),
             }
         };
     };
@@ -69,6 +65,8 @@ pub fn CategoryDetailScreen(slug: String) -> Element {
                 .title(&cat_name)
                 .description(&format!("Browse all posts in the {} category", cat_name))
                 .canonical(&format!("/categories/{}", cat_slug))
+                .image_with_dimensions(&og_card_url("category", &cat_slug), &cat_name, 1200, 630)
+                .overrides(category.seo.as_ref())
                 .build()
         }
         StructuredData {
@@ -1,26 +1,74 @@
 use crate::components::{
-    BannerPlaceholder, FeaturedPostCard, PostCard, PostsEmptyState, PostsLoadingSkeleton,
+    BannerPlaceholder, FeaturedPostCard, MissingContent, PostCard, PostsEmptyState,
+    PostsLoadingSkeleton,
 };
-use crate::router::Route;
-use crate::seo::{use_static_seo, website_schema, SeoHead, StructuredData};
+use crate::env::DEFAULT_LOCALE;
+use crate::seo::{canonical_url, use_static_seo, website_schema, SeoHead, StructuredData};
 use crate::server_fns::fetch_posts;
+use crate::utils::locale::{
+    is_prefixed_locale, localized_path, og_locale, post_route, prefixed_locales,
+};
 use dioxus::prelude::*;
 use oxstore::AppError;
 use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
 
 #[component]
 pub fn HomeScreen() -> Element {
+    rsx! { LocaleHome { locale: DEFAULT_LOCALE.to_string() } }
+}
+
+/// Home page for a non-default locale, e.g. `/de`
+#[component]
+pub fn LocalizedHomeScreen(locale: String) -> Element {
+    if !is_prefixed_locale(&locale) {
+        return rsx! {
+            MissingContent {
+                path: format!("/{locale}"),
+                title: "Page not found".to_string(),
+                message: String::new(),
+            }
+        };
+    }
+    rsx! { LocaleHome { locale } }
+}
+
+#[component]
+fn LocaleHome(locale: String) -> Element {
     let nav = use_navigator();
 
-    // Generate SEO metadata for homepage
-    let seo_metadata = use_static_seo("home");
+    // Generate SEO metadata for homepage, pointing at every locale's home
+    let mut seo_metadata = use_static_seo("home");
+    seo_metadata.canonical_url = Some(canonical_url(&localized_path(&locale, "/")));
+    if locale != DEFAULT_LOCALE {
+        seo_metadata.locale = og_locale(&locale);
+    }
+    let locales: Vec<&str> = std::iter::once(DEFAULT_LOCALE)
+        .chain(prefixed_locales())
+        .collect();
+    if locales.len() > 1 {
+        seo_metadata.alternates = locales
+            .iter()
+            .map(|l| (l.to_string(), canonical_url(&localized_path(l, "/"))))
+            .chain(std::iter::once((
+                "x-default".to_string(),
+                canonical_url("/"),
+            )))
+            .collect();
+    }
 
-    let posts_result = use_server_future(fetch_posts)?;
+    let posts_result = use_server_future({
+        let locale = locale.clone();
+        move || {
+            let locale = locale.clone();
+            async move { fetch_posts(Some(locale)).await }
+        }
+    })?;
 
     let posts_state = posts_result();
 
+    let post_locale = use_signal(|| locale.clone());
     let on_post_click = move |post_slug: String| {
-        nav.push(Route::PostViewScreen { slug: post_slug });
+        nav.push(post_route(Some(&post_locale.read()), post_slug));
     };
 
     rsx! {
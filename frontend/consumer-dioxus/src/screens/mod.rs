@@ -1,5 +1,6 @@
 mod about;
 mod advertise;
+mod authors;
 mod billing;
 mod categories;
 mod contact;
@@ -8,6 +9,7 @@ mod posts;
 mod pricing;
 mod privacy_policy;
 mod search;
+mod series;
 mod tags;
 mod terms;
 
@@ -19,6 +21,7 @@ mod profile;
 
 pub use about::*;
 pub use advertise::*;
+pub use authors::*;
 pub use billing::*;
 pub use categories::*;
 pub use contact::*;
@@ -27,6 +30,7 @@ pub use posts::*;
 pub use pricing::*;
 pub use privacy_policy::*;
 pub use search::*;
+pub use series::*;
 pub use tags::*;
 pub use terms::*;
 
@@ -1,18 +1,22 @@
+use crate::analytics::pageview;
 use crate::components::{
-    estimate_reading_time, format_date, ActionBar, BannerPlaceholder, PaywallOverlay,
-    ReadingProgressBar, RelatedPosts, SeriesNavigation, TableOfContents,
+    estimate_reading_time, format_date, ActionBar, BannerPlaceholder, MissingContent,
+    PaywallOverlay, ReadingProgressBar, RelatedPosts, ResponsivePicture, SeriesNavigation,
+    TableOfContents,
 };
+use crate::env::DEFAULT_LOCALE;
 use crate::seo::{
-    article_schema, breadcrumb_schema, ArticleMetadata, SeoHead, SeoImage, SeoMetadataBuilder,
-    StructuredData,
+    article_schema, breadcrumb_schema, og_card_url, ArticleMetadata, SeoHead, SeoImage,
+    SeoMetadataBuilder, StructuredData,
 };
 use crate::server_fns::fetch_post_by_slug;
 use crate::utils::editorjs::render_editorjs_content;
+use crate::utils::locale::{is_prefixed_locale, localized_path, og_locale, post_route};
 use dioxus::prelude::*;
 use hmziq_dioxus_free_icons::icons::ld_icons::{LdArrowLeft, LdCalendar, LdClock};
 use hmziq_dioxus_free_icons::Icon;
 use oxui::shadcn::button::{Button, ButtonVariant};
-use ruxlog_shared::store::Post;
+use ruxlog_shared::store::{Post, PostStatus};
 
 #[cfg(feature = "engagement")]
 use crate::components::EngagementBar;
@@ -25,45 +29,130 @@ use crate::components::CommentsSection;
 #[cfg(feature = "analytics")]
 use crate::analytics::{tracker, use_page_timer, use_scroll_depth};
 
+fn post_locale(post: &Post) -> &str {
+    post.locale.as_deref().unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Path of a post under its own locale's prefix
+fn post_path(post: &Post) -> String {
+    localized_path(post_locale(post), &format!("/posts/{}", post.slug))
+}
+
+/// hreflang versions of a post: itself, its published translations and
+/// `x-default` for the default locale's version
+fn post_alternates(post: &Post) -> Vec<(String, String)> {
+    let mut versions = vec![(post_locale(post).to_string(), post_path(post))];
+    versions.extend(
+        post.translations
+            .iter()
+            .filter(|t| t.status == PostStatus::Published)
+            .map(|t| {
+                (
+                    t.locale.clone(),
+                    localized_path(&t.locale, &format!("/posts/{}", t.slug)),
+                )
+            }),
+    );
+    if versions.len() < 2 {
+        return Vec::new();
+    }
+    if let Some((_, path)) = versions.iter().find(|(l, _)| l == DEFAULT_LOCALE) {
+        let path = path.clone();
+        versions.push(("x-default".to_string(), path));
+    }
+    versions
+}
+
 /// Generate SEO metadata for a post
 fn generate_post_seo(post: &Post) -> crate::seo::SeoMetadata {
-    let image = post.featured_image.as_ref().map(|img| SeoImage {
-        url: img.file_url.clone(),
-        alt: post.title.clone(),
-        width: None,
-        height: None,
-    });
+    let image = post
+        .featured_image
+        .as_ref()
+        .map(|img| SeoImage {
+            url: img.file_url.clone(),
+            alt: img.alt_text.clone().unwrap_or_else(|| post.title.clone()),
+            width: None,
+            height: None,
+        })
+        .unwrap_or_else(|| SeoImage {
+            url: og_card_url("post", &post.slug),
+            alt: post.title.clone(),
+            width: Some(1200),
+            height: Some(630),
+        });
 
     let article = ArticleMetadata {
         published_time: post.published_at.unwrap_or(post.created_at),
         modified_time: post.updated_at,
-        author: post.author.name.clone(),
+        author: post
+            .authors()
+            .map(|a| a.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
         section: Some(post.category.name.clone()),
         tags: post.tags.iter().map(|t| t.name.clone()).collect(),
     };
 
-    SeoMetadataBuilder::new()
+    let mut builder = SeoMetadataBuilder::new()
         .title(&post.title)
         .description(
             post.excerpt
                 .as_deref()
                 .unwrap_or("Read this post on Hmziq.rs Blog"),
         )
-        .canonical(&format!("/posts/{}", post.slug))
-        .image_struct(image)
+        .canonical(&post_path(post))
+        .image_struct(Some(image))
         .article(article)
-        .build()
+        .alternates(post_alternates(post));
+    if post_locale(post) != DEFAULT_LOCALE {
+        builder = builder.locale(&og_locale(post_locale(post)));
+    }
+    builder.overrides(post.seo.as_ref()).build()
 }
 #[component]
 pub fn PostViewScreen(slug: String) -> Element {
+    rsx! { PostView { slug, locale: None } }
+}
+
+/// A post under its locale prefix, e.g. `/de/posts/hallo-welt`
+#[component]
+pub fn LocalizedPostViewScreen(locale: String, slug: String) -> Element {
+    if !is_prefixed_locale(&locale) {
+        return rsx! {
+            MissingContent {
+                path: format!("/{locale}/posts/{slug}"),
+                title: "Post not found".to_string(),
+                message: "The post you're looking for doesn't exist.".to_string(),
+            }
+        };
+    }
+    rsx! { PostView { slug, locale: Some(locale) } }
+}
+
+#[component]
+fn PostView(slug: String, locale: Option<String>) -> Element {
     let nav = use_navigator();
+    let path = match &locale {
+        Some(locale) => format!("/{locale}/posts/{slug}"),
+        None => format!("/posts/{slug}"),
+    };
 
     let post_result = use_server_future(move || {
         let slug = slug.clone();
         async move { fetch_post_by_slug(slug).await }
     })?;
 
-    let post_state = post_result();
+    // A locale prefix must match the post's own locale
+    let post_state = match post_result() {
+        Some(Ok(Some(post)))
+            if locale
+                .as_deref()
+                .is_some_and(|locale| locale != post_locale(&post)) =>
+        {
+            Some(Ok(None))
+        }
+        state => state,
+    };
 
     // Check post access (paywall)
     let mut access_type = use_signal(String::new);
@@ -99,6 +188,18 @@ pub fn PostViewScreen(slug: String) -> Element {
     #[cfg(feature = "engagement")]
     let auth = use_auth();
 
+    // First-party pageview, once the post id is known. Other routes are
+    // recorded by the layout.
+    let loaded_post_id = match &post_state {
+        Some(Ok(Some(post))) => Some(post.id),
+        _ => None,
+    };
+    use_effect(use_reactive!(|loaded_post_id| {
+        if let Some(post_id) = loaded_post_id {
+            pageview::track_pageview(Some(post_id));
+        }
+    }));
+
     // Analytics: Track page view and time spent
     #[cfg(feature = "analytics")]
     {
@@ -252,7 +353,13 @@ pub fn PostViewScreen(slug: String) -> Element {
             #[cfg(not(feature = "engagement"))]
             let engagement_bar: Option<Element> = None;
 
-            let post_url = crate::seo::canonical_url(&format!("/posts/{}", post.slug));
+            let post_url = crate::seo::canonical_url(&post_path(&post));
+            let translations: Vec<_> = post
+                .translations
+                .iter()
+                .filter(|t| t.status == PostStatus::Published)
+                .cloned()
+                .collect();
 
             rsx! {
                 // Inject SEO tags
@@ -262,7 +369,7 @@ pub fn PostViewScreen(slug: String) -> Element {
                 StructuredData {
                     json_ld: breadcrumb_schema(vec![
                         ("Home", "/"),
-                        (&post.title, &format!("/posts/{}", post.slug))
+                        (&post.title, &post_path(&post))
                     ])
                 }
 
@@ -270,7 +377,7 @@ pub fn PostViewScreen(slug: String) -> Element {
                 StructuredData { json_ld: article_schema(&post) }
 
                 div { class: "min-h-screen",
-                    ReadingProgressBar {}
+                    ReadingProgressBar { post_id: post.id }
                     BannerPlaceholder {}
 
                     div { class: "container mx-auto px-4 py-6 max-w-6xl",
@@ -307,19 +414,58 @@ pub fn PostViewScreen(slug: String) -> Element {
                                     Icon { icon: LdClock, class: "w-4 h-4" }
                                     span { "{reading_time} min read" }
                                 }
-                                div { class: "flex items-center gap-2",
-                                    span { "By {post.author.name}" }
+                                div { class: "flex items-center gap-1",
+                                    span { "By" }
+                                    for (index, author) in post.authors().cloned().enumerate() {
+                                        if index > 0 {
+                                            span { "," }
+                                        }
+                                        Link {
+                                            key: "{author.id}",
+                                            to: crate::router::Route::AuthorScreen { slug: author.page_slug() },
+                                            class: "hover:underline",
+                                            "{author.name}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Language versions
+                            if !translations.is_empty() {
+                                div { class: "flex flex-wrap items-center gap-2 mt-3 text-sm text-muted-foreground",
+                                    span { "Also available in:" }
+                                    for translation in translations {
+                                        Link {
+                                            key: "{translation.id}",
+                                            to: post_route(Some(&translation.locale), translation.slug.clone()),
+                                            class: "tag-chip",
+                                            lang: translation.locale.clone(),
+                                            "{translation.title} ({translation.locale})"
+                                        }
+                                    }
                                 }
                             }
                         }
 
                         // Featured image
                         if let Some(img) = &post.featured_image {
-                            div { class: "mb-8",
-                                img {
-                                    src: "{img.file_url}",
-                                    alt: "{post.title}",
+                            figure { class: "mb-8",
+                                ResponsivePicture {
+                                    src: img.file_url.clone(),
+                                    alt: img.alt_text.clone().unwrap_or_else(|| post.title.clone()),
+                                    responsive: img.responsive.clone(),
                                     class: "w-full rounded-2xl border border-border/40 shadow-sm",
+                                    eager: true,
+                                }
+                                if img.caption.is_some() || img.attribution().is_some() {
+                                    figcaption { class: "mt-3 text-sm text-center text-muted-foreground",
+                                        if let Some(caption) = &img.caption {
+                                            span { class: "italic", "{caption}" }
+                                        }
+                                        if let Some(attribution) = img.attribution() {
+                                            span { class: "block mt-1 text-xs", "Credit: {attribution}" }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -377,18 +523,12 @@ pub fn PostViewScreen(slug: String) -> Element {
             }
         }
         Some(Ok(None)) => {
-            // Post not found
+            // Post not found: it may have moved or been removed
             rsx! {
-                div { class: "min-h-screen flex items-center justify-center",
-                    div { class: "text-center max-w-md",
-                        h1 { class: "text-2xl font-bold mb-2", "Post not found" }
-                        p { class: "text-muted-foreground mb-4", "The post you're looking for doesn't exist." }
-                        button {
-                            class: "text-primary hover:underline",
-                            onclick: move |_| { nav.push(crate::router::Route::HomeScreen {}); },
-                            "Back to home"
-                        }
-                    }
+                MissingContent {
+                    path: path.clone(),
+                    title: "Post not found".to_string(),
+                    message: "The post you're looking for doesn't exist.".to_string(),
                 }
             }
         }
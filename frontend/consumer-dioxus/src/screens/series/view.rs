@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::components::{PostCard, PostsLoadingSkeleton};
+use crate::router::Route;
+use crate::seo::{breadcrumb_schema, SeoHead, SeoMetadataBuilder, StructuredData};
+use crate::server_fns::fetch_series;
+use crate::utils::locale::post_route;
+use dioxus::prelude::*;
+use oxstore::AppError;
+use oxui::components::error::{ErrorDetails, ErrorDetailsVariant};
+
+#[component]
+pub fn SeriesScreen(slug: String) -> Element {
+    let nav = use_navigator();
+
+    let series_result = use_server_future(move || {
+        let slug = slug.clone();
+        async move { fetch_series(slug).await }
+    })?;
+
+    // Parts can be in any locale; open each at its own localized URL.
+    let post_locales = use_memo(move || match series_result() {
+        Some(Ok(Some(page))) => page
+            .data
+            .iter()
+            .map(|p| (p.slug.clone(), p.locale.clone()))
+            .collect::<HashMap<_, _>>(),
+        _ => HashMap::new(),
+    });
+    let on_post_click = move |post_slug: String| {
+        let locale = post_locales.read().get(&post_slug).cloned().flatten();
+        nav.push(post_route(locale.as_deref(), post_slug));
+    };
+
+    let page = match series_result() {
+        Some(Ok(page)) => page,
+        Some(Err(e)) => {
+            return rsx! {
+                div { class: "min-h-screen flex items-center justify-center",
+                    div { class: "max-w-md w-full",
+                        ErrorDetails {
+                            error: Some(AppError::Other { message: e.to_string() }),
+                            variant: ErrorDetailsVariant::Collapsed,
+                        }
+                    }
+                }
+            };
+        }
+        None => return rsx! { PostsLoadingSkeleton {} },
+    };
+
+    let Some(page) = page else {
+        return rsx! {
+            div { class: "min-h-screen flex items-center justify-center",
+                div { class: "text-center",
+                    h1 { class: "text-2xl font-bold mb-4", "Series not found" }
+                    button {
+                        class: "text-primary hover:underline",
+                        onclick: move |_| { nav.push(Route::HomeScreen {}); },
+                        "Back to home"
+                    }
+                }
+            }
+        };
+    };
+
+    let series = page.series.clone();
+    let path = format!("/series/{}", series.slug);
+    let description = series
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("All parts of the {} series", series.name));
+
+    rsx! {
+        SeoHead {
+            metadata: SeoMetadataBuilder::new()
+                .title(&series.name)
+                .description(&description)
+                .canonical(&path)
+                .build()
+        }
+        StructuredData {
+            json_ld: breadcrumb_schema(vec![("Home", "/"), (&series.name, &path)])
+        }
+
+        div { class: "min-h-screen",
+            div { class: "container mx-auto px-4 py-8 md:py-12 lg:py-16 max-w-6xl",
+                header { class: "space-y-3 mb-10",
+                    h1 { class: "text-3xl font-bold", "{series.name}" }
+                    if let Some(text) = &series.description {
+                        p { class: "text-muted-foreground max-w-2xl whitespace-pre-line", "{text}" }
+                    }
+                }
+
+                h2 { class: "text-xl font-semibold mb-6", "Parts ({page.data.len()})" }
+                div { class: "grid md:grid-cols-2 lg:grid-cols-3 gap-6",
+                    for post in page.data.iter() {
+                        PostCard {
+                            key: "{post.id}",
+                            post: post.clone(),
+                            on_click: on_post_click,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::components::{PostCard, PostsLoadingSkeleton};
 use crate::router::Route;
-use crate::seo::{breadcrumb_schema, SeoHead, SeoMetadataBuilder, StructuredData};
+use crate::seo::{breadcrumb_schema, og_card_url, SeoHead, SeoMetadataBuilder, StructuredData};
 use crate::server_fns::{fetch_posts_by_tag, fetch_tag_by_slug};
 use dioxus::prelude::*;
 use oxstore::AppError;
@@ -69,6 +69,8 @@ pub fn TagDetailScreen(slug: String) -> Element {
                 .title(&tag_name)
                 .description(&format!("Browse all posts tagged with {}", tag_name))
                 .canonical(&format!("/tags/{}", tag_slug))
+                .image_with_dimensions(&og_card_url("tag", &tag_slug), &tag_name, 1200, 630)
+                .overrides(tag.seo.as_ref())
                 .build()
         }
         StructuredData {
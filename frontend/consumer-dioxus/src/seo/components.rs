@@ -39,7 +39,12 @@ pub fn SeoHead(metadata: SeoMetadata) -> Element {
         // Canonical URL
         document::Link { rel: "canonical", href: canonical.clone() }
 
-        // RSS/Atom feed links
+        // Language versions
+        for (hreflang, href) in metadata.alternates.iter() {
+            document::Link { rel: "alternate", hreflang: hreflang.clone(), href: href.clone() }
+        }
+
+        // RSS/Atom/JSON feed links
         document::Link {
             rel: "alternate",
             r#type: "application/rss+xml",
@@ -52,6 +57,12 @@ pub fn SeoHead(metadata: SeoMetadata) -> Element {
             title: "{SEO_CONFIG.site_name} Atom Feed",
             href: format!("{}/feed/v1/atom", SEO_CONFIG.consumer_url)
         }
+        document::Link {
+            rel: "alternate",
+            r#type: "application/feed+json",
+            title: "{SEO_CONFIG.site_name} JSON Feed",
+            href: format!("{}/feed/v1/json", SEO_CONFIG.consumer_url)
+        }
 
         // Open Graph tags
         document::Meta { property: "og:type", content: metadata.og_type() }
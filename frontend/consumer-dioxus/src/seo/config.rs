@@ -50,6 +50,11 @@ pub fn canonical_url(path: &str) -> String {
     format!("{}{}", SEO_CONFIG.consumer_url, path)
 }
 
+/// Generated Open Graph card for a post, category or tag, served by the API
+pub fn og_card_url(kind: &str, slug: &str) -> String {
+    format!("{}/seo/v1/og/{}/{}.png", SEO_CONFIG.site_url, kind, slug)
+}
+
 /// Truncate description to SEO-optimal length (150-160 characters)
 pub fn truncate_description(text: &str, max_len: usize) -> String {
     if text.len() <= max_len {
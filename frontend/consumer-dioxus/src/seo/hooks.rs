@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 
-use super::config::{truncate_description, SEO_CONFIG};
+use super::config::{og_card_url, truncate_description, SEO_CONFIG};
 use super::metadata::{ArticleMetadata, SeoImage, SeoMetadata, SeoMetadataBuilder};
 
 use ruxlog_shared::store::{use_categories, use_post, use_tag};
@@ -70,13 +70,17 @@ fn build_post_seo(post: &ruxlog_shared::Post) -> SeoMetadata {
     // Truncate description to SEO-optimal length
     let description = truncate_description(&description, 160);
 
-    // Build SEO image from featured image
-    let image = post.featured_image.as_ref().map(|img| SeoImage {
-        url: img.file_url.clone(),
-        alt: post.title.clone(),
-        width: img.width.map(|w| w as u32),
-        height: img.height.map(|h| h as u32),
-    });
+    // Build SEO image from featured image, else the generated card
+    let image = post
+        .featured_image
+        .as_ref()
+        .map(|img| SeoImage {
+            url: img.file_url.clone(),
+            alt: img.alt_text.clone().unwrap_or_else(|| post.title.clone()),
+            width: img.width.map(|w| w as u32),
+            height: img.height.map(|h| h as u32),
+        })
+        .unwrap_or_else(|| generated_card("post", &post.slug, &post.title));
 
     // Build article metadata
     let article = ArticleMetadata {
@@ -90,12 +94,23 @@ fn build_post_seo(post: &ruxlog_shared::Post) -> SeoMetadata {
     SeoMetadataBuilder::new()
         .title(&post.title)
         .description(&description)
-        .image_struct(image)
+        .image_struct(Some(image))
         .article(article)
         .canonical(&format!("/posts/{}", post.slug))
+        .overrides(post.seo.as_ref())
         .build()
 }
 
+/// The API-rendered Open Graph card for items without an image of their own
+fn generated_card(kind: &str, slug: &str, alt: &str) -> SeoImage {
+    SeoImage {
+        url: og_card_url(kind, slug),
+        alt: alt.to_string(),
+        width: Some(1200),
+        height: Some(630),
+    }
+}
+
 /// Extract text from EditorJS content for description
 fn extract_text_from_editorjs(content: &ruxlog_shared::PostContent) -> Option<String> {
     use ruxlog_shared::EditorJsBlock;
@@ -162,13 +177,15 @@ fn build_category_seo(category: &ruxlog_shared::Category) -> SeoMetadata {
             alt: format!("{} category", category.name),
             width: img.width.map(|w| w as u32),
             height: img.height.map(|h| h as u32),
-        });
+        })
+        .unwrap_or_else(|| generated_card("category", &category.slug, &category.name));
 
     SeoMetadataBuilder::new()
         .title(&format!("{} Category", category.name))
         .description(&description)
-        .image_struct(image)
+        .image_struct(Some(image))
         .canonical(&format!("/categories/{}", category.slug))
+        .overrides(category.seo.as_ref())
         .build()
 }
 
@@ -199,7 +216,9 @@ fn build_tag_seo(tag: &ruxlog_shared::Tag) -> SeoMetadata {
     SeoMetadataBuilder::new()
         .title(&format!("{} Tag", tag.name))
         .description(&description)
+        .image_struct(Some(generated_card("tag", &tag.slug, &tag.name)))
         .canonical(&format!("/tags/{}", tag.slug))
+        .overrides(tag.seo.as_ref())
         .build()
 }
 
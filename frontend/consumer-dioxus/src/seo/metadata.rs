@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use ruxlog_shared::store::SeoOverrides;
 
 /// Comprehensive SEO metadata for a page
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +12,8 @@ pub struct SeoMetadata {
     pub robots: RobotsDirective,
     pub locale: String,
     pub site_name: String,
+    /// hreflang value and absolute URL of each language version
+    pub alternates: Vec<(String, String)>,
 }
 
 impl SeoMetadata {
@@ -70,6 +73,7 @@ pub struct SeoMetadataBuilder {
     robots: RobotsDirective,
     locale: String,
     site_name: String,
+    alternates: Vec<(String, String)>,
 }
 
 impl SeoMetadataBuilder {
@@ -85,6 +89,7 @@ impl SeoMetadataBuilder {
             robots: RobotsDirective::IndexFollow,
             locale: SEO_CONFIG.locale.to_string(),
             site_name: SEO_CONFIG.site_name.to_string(),
+            alternates: Vec::new(),
         }
     }
 
@@ -144,11 +149,53 @@ impl SeoMetadataBuilder {
         self
     }
 
+    /// Apply per-item overrides set in the admin on top of the derived values
+    pub fn overrides(mut self, seo: Option<&SeoOverrides>) -> Self {
+        let Some(seo) = seo else {
+            return self;
+        };
+        if let Some(title) = seo.meta_title.as_deref().filter(|t| !t.is_empty()) {
+            self.title = title.to_string();
+        }
+        if let Some(description) = seo.meta_description.as_deref().filter(|d| !d.is_empty()) {
+            self.description = Some(description.to_string());
+        }
+        if let Some(url) = seo.canonical_url.as_deref().filter(|u| !u.is_empty()) {
+            self.canonical_url = Some(url.to_string());
+        }
+        if let Some(image) = &seo.og_image {
+            let alt = self.title.clone();
+            self.image = Some(SeoImage {
+                url: image.file_url.clone(),
+                alt,
+                width: image.width.map(|w| w as u32),
+                height: image.height.map(|h| h as u32),
+            });
+        }
+        if seo.noindex {
+            self.robots = RobotsDirective::NoIndexFollow;
+        }
+        self
+    }
+
     pub fn locale(mut self, locale: &str) -> Self {
         self.locale = locale.to_string();
         self
     }
 
+    /// Language versions as (hreflang, path) pairs, including this page.
+    /// Ignored unless there is more than one.
+    pub fn alternates(mut self, versions: Vec<(String, String)>) -> Self {
+        use crate::seo::config;
+        if versions.len() > 1 {
+            self.alternates = versions
+                .into_iter()
+                .map(|(hreflang, path)| (hreflang, config::canonical_url(&path)))
+                .collect();
+        }
+        self
+    }
+
     pub fn build(self) -> SeoMetadata {
         SeoMetadata {
             title: self.title,
@@ -159,6 +206,7 @@ impl SeoMetadataBuilder {
             robots: self.robots,
             locale: self.locale,
             site_name: self.site_name,
+            alternates: self.alternates,
         }
     }
 }
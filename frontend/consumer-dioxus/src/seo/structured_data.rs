@@ -2,6 +2,7 @@ use dioxus::prelude::*;
 use serde_json::{json, Value};
 
 use super::config::SEO_CONFIG;
+use ruxlog_shared::store::PublicAuthorProfile;
 use ruxlog_shared::Post;
 
 /// Serialize JSON-LD for safe embedding inside a
@@ -32,11 +33,14 @@ pub fn article_schema(post: &Post) -> String {
         "image": post.featured_image.as_ref().map(|img| &img.file_url),
         "datePublished": post.published_at.unwrap_or(post.created_at).to_rfc3339(),
         "dateModified": post.updated_at.to_rfc3339(),
-        "author": {
-            "@type": "Person",
-            "name": &post.author.name,
-            "url": SEO_CONFIG.consumer_url
-        },
+        "author": post
+            .authors()
+            .map(|author| json!({
+                "@type": "Person",
+                "name": &author.name,
+                "url": author_url(&author.page_slug())
+            }))
+            .collect::<Vec<_>>(),
         "publisher": {
             "@type": "Organization",
             "name": SEO_CONFIG.site_name,
@@ -56,6 +60,34 @@ pub fn article_schema(post: &Post) -> String {
     to_safe_json_ld(&schema)
 }
 
+fn author_url(slug: &str) -> String {
+    format!("{}/authors/{}", SEO_CONFIG.consumer_url, slug)
+}
+
+/// Generate Person schema for author pages
+pub fn person_schema(author: &PublicAuthorProfile) -> String {
+    let mut same_as: Vec<&str> = author
+        .social_links
+        .iter()
+        .map(|link| link.url.as_str())
+        .collect();
+    if let Some(website) = author.website_url.as_deref() {
+        same_as.insert(0, website);
+    }
+
+    let schema = json!({
+        "@context": "https://schema.org",
+        "@type": "Person",
+        "name": &author.name,
+        "url": author_url(&author.slug),
+        "description": author.bio,
+        "image": author.avatar.as_ref().map(|img| &img.file_url),
+        "sameAs": same_as
+    });
+
+    to_safe_json_ld(&schema)
+}
+
 /// Generate BreadcrumbList schema
 pub fn breadcrumb_schema(items: Vec<(&str, &str)>) -> String {
     let list_items: Vec<_> = items
@@ -135,6 +167,31 @@ mod tests {
         assert_eq!(value["itemListElement"].as_array().unwrap().len(), 3);
     }
 
+    #[test]
+    fn test_person_schema_links_profile_and_social_urls() {
+        let author: PublicAuthorProfile = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "name": "Ada </script>",
+            "slug": "ada",
+            "bio": "Writes about compilers",
+            "website_url": "https://ada.example",
+            "social_links": [{ "label": "GitHub", "url": "https://github.com/ada" }],
+            "created_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        let schema = person_schema(&author);
+        assert!(!schema.contains("</script>"));
+
+        let value: Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(value["@type"], "Person");
+        assert_eq!(
+            value["url"],
+            format!("{}/authors/ada", SEO_CONFIG.consumer_url)
+        );
+        assert_eq!(value["sameAs"][0], "https://ada.example");
+        assert_eq!(value["sameAs"][1], "https://github.com/ada");
+    }
+
     #[test]
     fn test_website_schema_valid_json() {
         let schema = website_schema();
@@ -7,11 +7,26 @@ mod api_backed {
     use oxstore::PaginatedList;
     #[allow(unused_imports)]
     use ruxlog_shared::store::PostListQuery;
-    use ruxlog_shared::store::{Category, Post, Tag};
+    use ruxlog_shared::store::{AuthorPage, Category, Post, SeriesPage, Tag};
+    use serde::{Deserialize, Serialize};
+
+    /// Answer from the API's redirect lookup. `location` is `None` when the
+    /// path is gone (410) rather than moved.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct ResolvedRedirect {
+        pub status_code: u16,
+        pub location: Option<String>,
+    }
 
     #[server]
-    pub async fn fetch_posts() -> Result<PaginatedList<Post>, ServerFnError> {
-        let response = oxcore::http::post("/post/v1/list/published", &serde_json::json!({}))
+    pub async fn fetch_posts(locale: Option<String>) -> Result<PaginatedList<Post>, ServerFnError> {
+        let query = PostListQuery {
+            page: Some(1),
+            locale,
+            ..Default::default()
+        };
+
+        let response = oxcore::http::post("/post/v1/list/published", &query)
             .send()
             .await
             .map_err(|e| ServerFnError::new(e.to_string()))?;
@@ -198,6 +213,87 @@ mod api_backed {
         }
     }
 
+    #[server]
+    pub async fn fetch_author(slug: String) -> Result<Option<AuthorPage>, ServerFnError> {
+        let response = oxcore::http::get(&format!("/author/v1/{}", urlencoding::encode(&slug)))
+            .send()
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        if (200..300).contains(&response.status()) {
+            response
+                .json::<AuthorPage>()
+                .await
+                .map(Some)
+                .map_err(|e| ServerFnError::new(e.to_string()))
+        } else {
+            Err(ServerFnError::new(format!(
+                "API error: {}",
+                response.status()
+            )))
+        }
+    }
+
+    #[server]
+    pub async fn fetch_series(slug: String) -> Result<Option<SeriesPage>, ServerFnError> {
+        let response = oxcore::http::get(&format!(
+            "/post/v1/series/view/{}",
+            urlencoding::encode(&slug)
+        ))
+        .send()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        if (200..300).contains(&response.status()) {
+            response
+                .json::<SeriesPage>()
+                .await
+                .map(Some)
+                .map_err(|e| ServerFnError::new(e.to_string()))
+        } else {
+            Err(ServerFnError::new(format!(
+                "API error: {}",
+                response.status()
+            )))
+        }
+    }
+
+    #[server]
+    pub async fn resolve_redirect(path: String) -> Result<Option<ResolvedRedirect>, ServerFnError> {
+        let response = oxcore::http::get(&format!(
+            "/redirect/v1/resolve?path={}",
+            urlencoding::encode(&path)
+        ))
+        .send()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        if (200..300).contains(&response.status()) {
+            response
+                .json::<ResolvedRedirect>()
+                .await
+                .map(Some)
+                .map_err(|e| ServerFnError::new(e.to_string()))
+        } else {
+            Err(ServerFnError::new(format!(
+                "API error: {}",
+                response.status()
+            )))
+        }
+    }
+
     #[server(endpoint = "static_routes", output = server_fn::codec::Json)]
     pub async fn static_routes() -> Result<Vec<String>, ServerFnError> {
         let mut routes = Route::static_routes()
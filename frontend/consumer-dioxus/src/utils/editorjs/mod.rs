@@ -1,5 +1,10 @@
+use crate::components::ResponsivePicture;
 use dioxus::prelude::*;
-use ruxlog_shared::store::{EditorJsBlock, PostContent};
+use hmziq_dioxus_free_icons::icons::ld_icons::{LdDownload, LdFileText, LdPaperclip};
+use hmziq_dioxus_free_icons::Icon;
+use ruxlog_shared::store::{
+    format_attribution, format_duration, AttachesFile, EditorJsBlock, MediaKind, PostContent,
+};
 
 // M-9 (defense-in-depth XSS): the server already strips dangerous markup from
 // post content with ammonia on write, but every `dangerous_inner_html` sink is
@@ -126,17 +131,35 @@ fn render_list_block(block: &EditorJsBlock) -> Element {
 fn render_image_block(block: &EditorJsBlock) -> Element {
     if let EditorJsBlock::Image { data, .. } = block {
         let url = data.file.url.clone();
-        let caption = &data.caption;
+        // Prefer the library alt text; fall back to the caption so the image
+        // is never announced as just a file name.
+        let alt = data
+            .file
+            .alt
+            .as_deref()
+            .or(data.caption.as_deref())
+            .unwrap_or("");
+        let attribution =
+            format_attribution(data.file.credit.as_deref(), data.file.license.as_deref());
+        let caption = data.caption.clone().filter(|c| !c.trim().is_empty());
 
         rsx! {
             figure { class: "my-8",
-                img {
-                    src: "{url}",
-                    alt: caption.as_deref().unwrap_or(""),
-                    class: "w-full h-auto rounded-lg shadow-md"
+                ResponsivePicture {
+                    src: url,
+                    alt: alt.to_string(),
+                    responsive: data.file.responsive.clone(),
+                    class: "w-full h-auto rounded-lg shadow-md",
                 }
-                if let Some(ref caption) = data.caption {
-                    figcaption { class: "mt-3 text-sm text-center italic", "{caption}" }
+                if caption.is_some() || attribution.is_some() {
+                    figcaption { class: "mt-3 text-sm text-center",
+                        if let Some(caption) = caption {
+                            span { class: "italic", "{caption}" }
+                        }
+                        if let Some(attribution) = attribution {
+                            span { class: "block mt-1 text-xs text-muted-foreground", "Credit: {attribution}" }
+                        }
+                    }
                 }
             }
         }
@@ -145,6 +168,172 @@ fn render_image_block(block: &EditorJsBlock) -> Element {
     }
 }
 
+/// Attachments are uploaded through the editor's attaches tool; video, audio
+/// and PDF files get an inline player or preview instead of a download card.
+fn render_attaches_block(block: &EditorJsBlock) -> Element {
+    if let EditorJsBlock::Attaches { data, .. } = block {
+        let file = &data.file;
+        let title = data
+            .title
+            .clone()
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or_else(|| file.name.clone());
+
+        match file.kind() {
+            Some(MediaKind::Video) => render_video_attachment(file, title),
+            Some(MediaKind::Audio) => render_audio_attachment(file, title),
+            Some(MediaKind::Document) => render_pdf_attachment(file, title),
+            _ => render_download_attachment(file, title),
+        }
+    } else {
+        rsx! {}
+    }
+}
+
+fn render_video_attachment(file: &AttachesFile, title: String) -> Element {
+    let url = file.url.clone();
+    let poster = file.poster.clone().unwrap_or_default();
+    let mime_type = file.mime_type.clone().unwrap_or_default();
+
+    rsx! {
+        figure { class: "my-8",
+            video {
+                class: "w-full h-auto rounded-lg shadow-md bg-black",
+                controls: true,
+                preload: "metadata",
+                playsinline: true,
+                poster: "{poster}",
+                source { src: "{url}", r#type: "{mime_type}" }
+                a { href: "{url}", "Download {title}" }
+            }
+            if !title.is_empty() {
+                figcaption { class: "mt-3 text-sm text-center italic", "{title}" }
+            }
+        }
+    }
+}
+
+fn render_audio_attachment(file: &AttachesFile, title: String) -> Element {
+    let url = file.url.clone();
+    let cover = file.poster.clone();
+    let mime_type = file.mime_type.clone().unwrap_or_default();
+    let duration = file.duration_ms.map(format_duration);
+
+    rsx! {
+        figure { class: "my-8 flex items-center gap-4 rounded-lg border bg-muted/30 p-4",
+            if let Some(cover) = cover {
+                img {
+                    class: "w-20 h-20 shrink-0 rounded object-cover",
+                    src: "{cover}",
+                    alt: "",
+                    loading: "lazy",
+                }
+            }
+            div { class: "flex-1 min-w-0",
+                figcaption { class: "mb-2 flex items-baseline justify-between gap-2 text-sm",
+                    span { class: "font-medium truncate", "{title}" }
+                    if let Some(duration) = duration {
+                        span { class: "text-xs text-muted-foreground tabular-nums", "{duration}" }
+                    }
+                }
+                audio {
+                    class: "w-full",
+                    controls: true,
+                    preload: "metadata",
+                    source { src: "{url}", r#type: "{mime_type}" }
+                    a { href: "{url}", "Download {title}" }
+                }
+            }
+        }
+    }
+}
+
+fn render_pdf_attachment(file: &AttachesFile, title: String) -> Element {
+    let url = file.url.clone();
+    let thumbnail = file.poster.clone();
+    let details = attachment_details(file);
+
+    rsx! {
+        figure { class: "my-8 overflow-hidden rounded-lg border bg-muted/30",
+            a {
+                class: "flex items-center gap-4 p-4 hover:bg-muted/50 transition-colors",
+                href: "{url}",
+                target: "_blank",
+                rel: "noopener",
+                if let Some(thumbnail) = thumbnail {
+                    img {
+                        class: "w-24 shrink-0 rounded border bg-white shadow-sm",
+                        src: "{thumbnail}",
+                        alt: "First page of {title}",
+                        loading: "lazy",
+                    }
+                } else {
+                    Icon { icon: LdFileText, class: "w-10 h-10 shrink-0 text-muted-foreground" }
+                }
+                div { class: "flex-1 min-w-0",
+                    figcaption { class: "font-medium truncate", "{title}" }
+                    if !details.is_empty() {
+                        p { class: "mt-1 text-xs text-muted-foreground", "{details}" }
+                    }
+                }
+                Icon { icon: LdDownload, class: "w-5 h-5 shrink-0 text-muted-foreground" }
+            }
+        }
+    }
+}
+
+fn render_download_attachment(file: &AttachesFile, title: String) -> Element {
+    let url = file.url.clone();
+    let details = attachment_details(file);
+
+    rsx! {
+        a {
+            class: "my-6 flex items-center gap-3 rounded-lg border p-4 hover:bg-muted/50 transition-colors",
+            href: "{url}",
+            target: "_blank",
+            rel: "noopener",
+            Icon { icon: LdPaperclip, class: "w-5 h-5 shrink-0 text-muted-foreground" }
+            span { class: "flex-1 min-w-0 truncate font-medium", "{title}" }
+            if !details.is_empty() {
+                span { class: "text-xs text-muted-foreground", "{details}" }
+            }
+            Icon { icon: LdDownload, class: "w-5 h-5 shrink-0 text-muted-foreground" }
+        }
+    }
+}
+
+/// "PDF · 12 pages · 1.4 MB", skipping whatever is unknown.
+fn attachment_details(file: &AttachesFile) -> String {
+    let mut parts = Vec::new();
+    if !file.extension.is_empty() {
+        parts.push(file.extension.to_uppercase());
+    }
+    match file.page_count {
+        Some(1) => parts.push("1 page".to_string()),
+        Some(pages) if pages > 1 => parts.push(format!("{} pages", pages)),
+        _ => {}
+    }
+    if file.size > 0 {
+        parts.push(format_file_size(file.size));
+    }
+    parts.join(" · ")
+}
+
+fn format_file_size(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
 fn render_delimiter_block(_block: &EditorJsBlock) -> Element {
     rsx! {
         div { class: "my-8 flex items-center justify-center",
@@ -180,6 +369,7 @@ pub fn render_editorjs_content(content: &PostContent) -> Element {
                     EditorJsBlock::List { .. } => render_list_block(block),
                     EditorJsBlock::Delimiter { .. } => render_delimiter_block(block),
                     EditorJsBlock::Image { .. } => render_image_block(block),
+                    EditorJsBlock::Attaches { .. } => render_attaches_block(block),
                     EditorJsBlock::Code { .. } => render_code_block(block),
                     EditorJsBlock::Quote { .. } => render_quote_block(block),
                     EditorJsBlock::Raw { .. } => render_raw_block(block),
@@ -193,7 +383,64 @@ pub fn render_editorjs_content(content: &PostContent) -> Element {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ruxlog_shared::store::{ListBlock, ParagraphBlock, RawBlock};
+    use ruxlog_shared::store::{AttachesBlock, ListBlock, ParagraphBlock, RawBlock};
+
+    fn attachment(extension: &str, mime_type: Option<&str>) -> AttachesFile {
+        AttachesFile {
+            url: format!("https://cdn.test/a.{extension}"),
+            size: 1536,
+            name: format!("a.{extension}"),
+            extension: extension.to_string(),
+            media_id: Some(1),
+            mime_type: mime_type.map(str::to_string),
+            duration_ms: None,
+            page_count: None,
+            poster: None,
+        }
+    }
+
+    #[test]
+    fn attachment_kind_prefers_mime_and_falls_back_to_extension() {
+        assert_eq!(
+            attachment("bin", Some("video/webm")).kind(),
+            Some(MediaKind::Video)
+        );
+        assert_eq!(attachment("mp3", None).kind(), Some(MediaKind::Audio));
+        assert_eq!(attachment("zip", None).kind(), None);
+    }
+
+    #[test]
+    fn attachment_details_lists_known_facts() {
+        let mut pdf = attachment("pdf", Some("application/pdf"));
+        pdf.page_count = Some(12);
+        assert_eq!(attachment_details(&pdf), "PDF · 12 pages · 1.5 KB");
+
+        let mut bare = attachment("", None);
+        bare.size = 0;
+        assert_eq!(attachment_details(&bare), "");
+    }
+
+    #[test]
+    fn attaches_render_path_does_not_panic() {
+        let blocks = [
+            attachment("mp4", Some("video/mp4")),
+            attachment("ogg", Some("audio/ogg")),
+            attachment("pdf", Some("application/pdf")),
+            attachment("zip", None),
+        ]
+        .into_iter()
+        .map(|file| EditorJsBlock::Attaches {
+            id: None,
+            data: AttachesBlock { file, title: None },
+        })
+        .collect();
+
+        let _ = render_editorjs_content(&PostContent {
+            time: 0,
+            version: "test".to_string(),
+            blocks,
+        });
+    }
 
     #[test]
     fn raw_block_render_path_does_not_panic() {
@@ -0,0 +1,61 @@
+use crate::env::{CONTENT_LOCALES, DEFAULT_LOCALE};
+use crate::router::Route;
+
+/// Locales served under a `/{locale}` prefix, from `CONTENT_LOCALES`
+pub fn prefixed_locales() -> impl Iterator<Item = &'static str> {
+    CONTENT_LOCALES
+        .split(',')
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty() && *locale != DEFAULT_LOCALE)
+}
+
+/// Whether a route's leading segment names a configured content locale
+pub fn is_prefixed_locale(locale: &str) -> bool {
+    prefixed_locales().any(|supported| supported == locale)
+}
+
+/// Path for content in `locale`; the default locale keeps the bare path
+pub fn localized_path(locale: &str, path: &str) -> String {
+    if locale == DEFAULT_LOCALE {
+        path.to_string()
+    } else {
+        format!("/{locale}{path}")
+    }
+}
+
+/// Route to a post in its own locale
+pub fn post_route(locale: Option<&str>, slug: String) -> Route {
+    match locale {
+        Some(locale) if locale != DEFAULT_LOCALE => Route::LocalizedPostViewScreen {
+            locale: locale.to_string(),
+            slug,
+        },
+        _ => Route::PostViewScreen { slug },
+    }
+}
+
+/// Open Graph locale (`pt_BR`) from a BCP 47 tag (`pt-BR`)
+pub fn og_locale(locale: &str) -> String {
+    locale.replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_locale_keeps_bare_paths() {
+        assert_eq!(localized_path(DEFAULT_LOCALE, "/posts/a"), "/posts/a");
+        assert_eq!(localized_path("zu", "/posts/a"), "/zu/posts/a");
+    }
+
+    #[test]
+    fn default_locale_is_never_prefixed() {
+        assert!(!is_prefixed_locale(DEFAULT_LOCALE));
+    }
+
+    #[test]
+    fn og_locale_uses_underscores() {
+        assert_eq!(og_locale("pt-BR"), "pt_BR");
+    }
+}
@@ -1,2 +1,3 @@
 pub mod editorjs;
+pub mod locale;
 pub mod persist;
@@ -40,6 +40,12 @@ pub(crate) fn get_base_url() -> String {
     BASE_URL.get().cloned().unwrap_or_default()
 }
 
+/// Absolute URL for `endpoint`, for callers that talk to the backend without
+/// the request helpers (e.g. an `EventSource`).
+pub fn url(endpoint: &str) -> String {
+    format!("{}{}", get_base_url(), endpoint)
+}
+
 /// Current CSRF token (attached to every mutating request). Returns an empty
 /// string until the first successful `/csrf/v1/generate` round-trip completes.
 pub(crate) fn get_csrf_token() -> String {
@@ -2,7 +2,7 @@ use super::config::{get_base_url, get_csrf_token};
 use gloo_net::http::{Request as GlooRequest, RequestBuilder as GlooRequestBuilder};
 use serde::de::Error as _;
 use serde::{de::DeserializeOwned, Serialize};
-use web_sys::{FormData, RequestCredentials};
+use web_sys::{Blob, FormData, RequestCredentials};
 
 pub struct Request(GlooRequest);
 pub struct RequestBuilder(GlooRequestBuilder);
@@ -134,6 +134,19 @@ pub fn post_multipart(endpoint: &str, form_data: &FormData) -> Result<Request, S
     Ok(Request(req))
 }
 
+/// `PUT` a blob to an absolute, presigned URL (e.g. one part of a direct
+/// upload). The base URL, CSRF header and credentials are deliberately left
+/// off: the URL's signature is the authorization, and object stores reject
+/// credentialed cross-origin requests.
+pub fn put_blob(url: &str, blob: &Blob) -> Result<Request, String> {
+    let req = GlooRequest::put(url)
+        .credentials(RequestCredentials::Omit)
+        .body(blob)
+        .map_err(|e| format!("Failed to create upload request: {:?}", e))?;
+
+    Ok(Request(req))
+}
+
 #[derive(serde::Deserialize)]
 struct CsrfTokenResponse {
     token: String,
@@ -1,7 +1,9 @@
 pub mod auth_guard;
+pub mod notification_bell;
 pub mod tag;
 pub mod user_avatar;
 
 pub use auth_guard::*;
+pub use notification_bell::*;
 pub use tag::*;
 pub use user_avatar::*;
@@ -0,0 +1,245 @@
+//! Notification bell with an unread badge and a dropdown of recent
+//! notifications.
+//!
+//! The bell keeps the notification store up to date while it is mounted:
+//! it loads the unread count, then listens on the backend's
+//! `/notification/v1/stream` Server-Sent Events endpoint and adds each
+//! pushed notification to the store. The dropdown loads the first page of
+//! the inbox the first time it opens.
+
+use crate::store::notifications::{use_notifications, Notification, NotificationListQuery};
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+
+/// Messages from the event stream, handed to a coroutine so store updates
+/// run inside the Dioxus runtime rather than in the browser callback.
+enum StreamMessage {
+    Notification(Notification),
+    /// The server skipped notifications; reload instead of guessing.
+    Resync,
+}
+
+#[component]
+pub fn NotificationBell(
+    /// The bell icon.
+    children: Element,
+    #[props(default)] button_class: String,
+) -> Element {
+    let notifications = use_notifications();
+    let mut open = use_signal(|| false);
+
+    use_effect(move || {
+        spawn(async move {
+            notifications.fetch_unread_count().await;
+        });
+    });
+
+    let incoming = use_coroutine(move |mut rx: UnboundedReceiver<StreamMessage>| async move {
+        while let Some(message) = rx.next().await {
+            match message {
+                StreamMessage::Notification(notification) => notifications.receive(notification),
+                StreamMessage::Resync => {
+                    notifications.fetch_unread_count().await;
+                    if notifications.inbox.peek().data.is_some() {
+                        notifications.list(NotificationListQuery::new()).await;
+                    }
+                }
+            }
+        }
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let live = use_hook(move || std::rc::Rc::new(live::LiveStream::connect(incoming)));
+        use_drop(move || drop(live));
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = incoming;
+
+    let unread = notifications.unread_count();
+    let badge = if unread > 9 {
+        "9+".to_string()
+    } else {
+        unread.to_string()
+    };
+
+    let toggle = move |_| {
+        let opening = !open();
+        open.set(opening);
+        if opening && notifications.inbox.peek().data.is_none() {
+            spawn(async move {
+                notifications.list(NotificationListQuery::new()).await;
+            });
+        }
+    };
+
+    let inbox = notifications.inbox.read();
+    let items = inbox
+        .data
+        .as_ref()
+        .map(|list| list.data.clone())
+        .unwrap_or_default();
+    let loading = inbox.is_loading() && inbox.data.is_none();
+    let failed = inbox.is_failed();
+    drop(inbox);
+
+    rsx! {
+        div { class: "relative",
+            button {
+                class: "relative {button_class}",
+                aria_label: "Notifications",
+                onclick: toggle,
+                {children}
+                if unread > 0 {
+                    span { class: "absolute -top-1 -right-1 min-w-4 h-4 px-1 rounded-full bg-red-500 text-white text-[10px] font-semibold leading-4 text-center",
+                        "{badge}"
+                    }
+                }
+            }
+
+            if open() {
+                // Click anywhere else to close.
+                div {
+                    class: "fixed inset-0 z-40",
+                    onclick: move |_| open.set(false),
+                }
+                div { class: "absolute right-0 z-50 mt-2 w-80 rounded-lg border border-border bg-background shadow-lg",
+                    div { class: "flex items-center justify-between border-b border-border px-4 py-3",
+                        p { class: "text-sm font-semibold", "Notifications" }
+                        button {
+                            class: "text-xs text-muted-foreground hover:text-foreground disabled:opacity-50",
+                            disabled: unread == 0,
+                            onclick: move |_| {
+                                spawn(async move {
+                                    notifications.mark_all_read().await;
+                                });
+                            },
+                            "Mark all read"
+                        }
+                    }
+                    div { class: "max-h-96 overflow-y-auto divide-y divide-border",
+                        if loading {
+                            p { class: "px-4 py-6 text-center text-sm text-muted-foreground", "Loading..." }
+                        } else if failed && items.is_empty() {
+                            p { class: "px-4 py-6 text-center text-sm text-muted-foreground",
+                                "Couldn't load notifications."
+                            }
+                        } else if items.is_empty() {
+                            p { class: "px-4 py-6 text-center text-sm text-muted-foreground",
+                                "You're all caught up."
+                            }
+                        } else {
+                            for notification in items {
+                                NotificationRow { key: "{notification.id}", notification }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NotificationRow(notification: Notification) -> Element {
+    let notifications = use_notifications();
+    let id = notification.id;
+    let unread = !notification.is_read();
+    let created_at = notification.created_at.format("%b %d, %H:%M").to_string();
+    let href = notification.link.clone().unwrap_or_else(|| "#".to_string());
+
+    rsx! {
+        a {
+            class: "flex gap-3 px-4 py-3 hover:bg-muted/50",
+            href: "{href}",
+            onclick: move |_| {
+                if unread {
+                    spawn(async move {
+                        notifications.mark_read(vec![id]).await;
+                    });
+                }
+            },
+            span {
+                class: if unread { "mt-1.5 h-2 w-2 shrink-0 rounded-full bg-primary" } else { "mt-1.5 h-2 w-2 shrink-0" },
+            }
+            div { class: "min-w-0 space-y-0.5",
+                p {
+                    class: if unread { "text-sm font-medium" } else { "text-sm text-muted-foreground" },
+                    "{notification.subject}"
+                }
+                p { class: "text-xs text-muted-foreground line-clamp-2", "{notification.body}" }
+                p { class: "text-[10px] text-muted-foreground", "{created_at}" }
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod live {
+    use super::StreamMessage;
+    use crate::store::notifications::Notification;
+    use dioxus::prelude::Coroutine;
+    use wasm_bindgen::{closure::Closure, JsCast};
+    use web_sys::{EventSource, EventSourceInit, MessageEvent};
+
+    /// An open `EventSource`, closed when dropped. The browser reconnects it
+    /// whenever the server ends a stream.
+    pub struct LiveStream {
+        source: Option<EventSource>,
+        _on_notification: Option<Closure<dyn FnMut(MessageEvent)>>,
+        _on_resync: Option<Closure<dyn FnMut(MessageEvent)>>,
+    }
+
+    impl LiveStream {
+        pub fn connect(incoming: Coroutine<StreamMessage>) -> Self {
+            let init = EventSourceInit::new();
+            init.set_with_credentials(true);
+            let Ok(source) = EventSource::new_with_event_source_init_dict(
+                &oxcore::http::url("/notification/v1/stream"),
+                &init,
+            ) else {
+                return Self {
+                    source: None,
+                    _on_notification: None,
+                    _on_resync: None,
+                };
+            };
+
+            let on_notification = Closure::wrap(Box::new(move |event: MessageEvent| {
+                let Some(data) = event.data().as_string() else {
+                    return;
+                };
+                match serde_json::from_str::<Notification>(&data) {
+                    Ok(notification) => incoming.send(StreamMessage::Notification(notification)),
+                    Err(e) => {
+                        dioxus::logger::tracing::error!("Failed to parse notification: {:?}", e)
+                    }
+                }
+            }) as Box<dyn FnMut(MessageEvent)>);
+            let on_resync = Closure::wrap(Box::new(move |_: MessageEvent| {
+                incoming.send(StreamMessage::Resync);
+            }) as Box<dyn FnMut(MessageEvent)>);
+
+            let _ = source.add_event_listener_with_callback(
+                "notification",
+                on_notification.as_ref().unchecked_ref(),
+            );
+            let _ = source
+                .add_event_listener_with_callback("resync", on_resync.as_ref().unchecked_ref());
+
+            Self {
+                source: Some(source),
+                _on_notification: Some(on_notification),
+                _on_resync: Some(on_resync),
+            }
+        }
+    }
+
+    impl Drop for LiveStream {
+        fn drop(&mut self) {
+            if let Some(source) = &self.source {
+                source.close();
+            }
+        }
+    }
+}
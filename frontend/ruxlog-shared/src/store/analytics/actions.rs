@@ -123,6 +123,167 @@ impl AnalyticsState {
         .await;
     }
 
+    pub async fn fetch_traffic_referrers(&self, request: TrafficRequest) {
+        let req = http::post("/analytics/v1/traffic/referrers", &request);
+
+        state_request_abstraction(
+            &self.traffic_referrers,
+            Some(request),
+            req.send(),
+            "traffic_referrers",
+            |response: &AnalyticsEnvelopeResponse<Vec<ReferrerPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_traffic_campaigns(&self, request: TrafficRequest) {
+        let req = http::post("/analytics/v1/traffic/campaigns", &request);
+
+        state_request_abstraction(
+            &self.traffic_campaigns,
+            Some(request),
+            req.send(),
+            "traffic_campaigns",
+            |response: &AnalyticsEnvelopeResponse<Vec<CampaignPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_traffic_countries(&self, request: TrafficRequest) {
+        let req = http::post("/analytics/v1/traffic/countries", &request);
+
+        state_request_abstraction(
+            &self.traffic_countries,
+            Some(request),
+            req.send(),
+            "traffic_countries",
+            |response: &AnalyticsEnvelopeResponse<Vec<CountryPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_traffic_devices(&self, request: TrafficRequest) {
+        let req = http::post("/analytics/v1/traffic/devices", &request);
+
+        state_request_abstraction(
+            &self.traffic_devices,
+            Some(request),
+            req.send(),
+            "traffic_devices",
+            |response: &AnalyticsEnvelopeResponse<Vec<DevicePoint>>| (Some(response.clone()), None),
+        )
+        .await;
+    }
+
+    pub async fn fetch_post_engagement(&self, request: PostEngagementRequest) {
+        let req = http::post("/analytics/v1/engagement/post", &request);
+
+        state_request_abstraction(
+            &self.post_engagement,
+            Some(request),
+            req.send(),
+            "post_engagement",
+            |response: &AnalyticsEnvelopeResponse<PostEngagementData>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_author_engagement(&self, request: AuthorEngagementRequest) {
+        let req = http::post("/analytics/v1/engagement/author", &request);
+
+        state_request_abstraction(
+            &self.author_engagement,
+            Some(request),
+            req.send(),
+            "author_engagement",
+            |response: &AnalyticsEnvelopeResponse<Vec<PostEngagementPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_revenue_mrr(&self, request: RevenueTrendRequest) {
+        let req = http::post("/analytics/v1/revenue/mrr", &request);
+
+        state_request_abstraction(
+            &self.revenue_mrr,
+            Some(request),
+            req.send(),
+            "revenue_mrr",
+            |response: &AnalyticsEnvelopeResponse<Vec<MrrPoint>>| (Some(response.clone()), None),
+        )
+        .await;
+    }
+
+    pub async fn fetch_revenue_subscribers(&self, request: RevenueTrendRequest) {
+        let req = http::post("/analytics/v1/revenue/subscribers", &request);
+
+        state_request_abstraction(
+            &self.revenue_subscribers,
+            Some(request),
+            req.send(),
+            "revenue_subscribers",
+            |response: &AnalyticsEnvelopeResponse<Vec<SubscriberFlowPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_revenue_trials(&self, request: RevenueTrendRequest) {
+        let req = http::post("/analytics/v1/revenue/trials", &request);
+
+        state_request_abstraction(
+            &self.revenue_trials,
+            Some(request),
+            req.send(),
+            "revenue_trials",
+            |response: &AnalyticsEnvelopeResponse<Vec<TrialConversionPoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_revenue_posts(&self, request: RevenueBreakdownRequest) {
+        let req = http::post("/analytics/v1/revenue/posts", &request);
+
+        state_request_abstraction(
+            &self.revenue_posts,
+            Some(request),
+            req.send(),
+            "revenue_posts",
+            |response: &AnalyticsEnvelopeResponse<Vec<PostRevenuePoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
+    pub async fn fetch_revenue_providers(&self, request: RevenueBreakdownRequest) {
+        let req = http::post("/analytics/v1/revenue/providers", &request);
+
+        state_request_abstraction(
+            &self.revenue_providers,
+            Some(request),
+            req.send(),
+            "revenue_providers",
+            |response: &AnalyticsEnvelopeResponse<Vec<ProviderRevenuePoint>>| {
+                (Some(response.clone()), None)
+            },
+        )
+        .await;
+    }
+
     pub fn reset(&self) {
         *self.registration_trends.write() = StateFrame::new();
         *self.verification_rates.write() = StateFrame::new();
@@ -132,5 +293,16 @@ impl AnalyticsState {
         *self.newsletter_growth.write() = StateFrame::new();
         *self.media_upload.write() = StateFrame::new();
         *self.dashboard_summary.write() = StateFrame::new();
+        *self.traffic_referrers.write() = StateFrame::new();
+        *self.traffic_campaigns.write() = StateFrame::new();
+        *self.traffic_countries.write() = StateFrame::new();
+        *self.traffic_devices.write() = StateFrame::new();
+        *self.post_engagement.write() = StateFrame::new();
+        *self.author_engagement.write() = StateFrame::new();
+        *self.revenue_mrr.write() = StateFrame::new();
+        *self.revenue_subscribers.write() = StateFrame::new();
+        *self.revenue_trials.write() = StateFrame::new();
+        *self.revenue_posts.write() = StateFrame::new();
+        *self.revenue_providers.write() = StateFrame::new();
     }
 }
@@ -1,4 +1,4 @@
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDate, Utc};
 use dioxus::prelude::*;
 use std::sync::OnceLock;
 
@@ -22,14 +22,35 @@ pub struct AnalyticsFilterState {
 
 static ANALYTICS_FILTER_STATE: OnceLock<AnalyticsFilterState> = OnceLock::new();
 
+/// The browser's IANA timezone (e.g. `Europe/Berlin`), sent with analytics
+/// requests so ranges and buckets follow the admin's local days.
+pub fn browser_timezone() -> Option<String> {
+    let options = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new())
+        .resolved_options();
+    js_sys::Reflect::get(&options, &"timeZone".into())
+        .ok()?
+        .as_string()
+}
+
+/// Today's date on the browser's clock, falling back to UTC.
+fn local_today() -> NaiveDate {
+    let now = js_sys::Date::new_0();
+    NaiveDate::from_ymd_opt(
+        now.get_full_year() as i32,
+        now.get_month() + 1,
+        now.get_date(),
+    )
+    .unwrap_or_else(|| Utc::now().date_naive())
+}
+
 fn default_date_from() -> Option<String> {
-    let today = Utc::now().date_naive();
+    let today = local_today();
     let seven_days_ago = today - Duration::days(7);
     Some(seven_days_ago.format("%Y-%m-%d").to_string())
 }
 
 fn default_date_to() -> Option<String> {
-    let today = Utc::now().date_naive();
+    let today = local_today();
     Some(today.format("%Y-%m-%d").to_string())
 }
 
@@ -48,7 +69,7 @@ impl AnalyticsFilterState {
 
     /// Set period preset and update date range accordingly
     pub fn set_period_preset(&self, preset: &str) {
-        let today = Utc::now().date_naive();
+        let today = local_today();
         let days = match preset {
             "7d" => 7,
             "30d" => 30,
@@ -83,6 +104,7 @@ impl AnalyticsFilterState {
             per_page: None,
             sort_by: None,
             sort_order: None,
+            timezone: browser_timezone(),
         }
     }
 
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+pub use ruxlog_types::enums::DeviceClass;
+
 // ========== Shared Types ==========
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -85,6 +87,9 @@ pub struct AnalyticsEnvelope {
     pub sort_by: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<String>,
+    /// IANA timezone the backend resolves dates and buckets in; UTC when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -98,6 +103,8 @@ pub struct AnalyticsMeta {
     pub sorted_by: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters_applied: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -303,6 +310,212 @@ pub struct DashboardSummaryData {
     pub posts: DashboardSummaryPosts,
     pub engagement: DashboardSummaryEngagement,
     pub media: DashboardSummaryMedia,
+    pub revenue: DashboardSummaryRevenue,
+}
+
+/// Amounts are minor units of `currency`, the backend's reporting currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DashboardSummaryRevenue {
+    pub currency: String,
+    pub mrr_cents: i64,
+    pub arr_cents: i64,
+    pub paying_subscriptions: i64,
+    pub trialing: i64,
+    pub new_subscriptions: i64,
+    pub churned: i64,
+    pub churn_rate: f64,
+    pub revenue_in_period_cents: i64,
+    pub refunded_cents: i64,
+    pub arpu_cents: i64,
+    pub ltv_cents: Option<i64>,
+    /// Payments in a currency without a configured exchange rate.
+    pub unconverted_payments: i64,
+}
+
+// ========== Traffic ==========
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TrafficFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Shared by the referrer, campaign, country and device breakdowns.
+/// `envelope.sort_by` is `visitors` (default) or `pageviews`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrafficRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: TrafficFilters,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReferrerPoint {
+    /// `None` is direct traffic.
+    pub referrer_host: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CampaignPoint {
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CountryPoint {
+    pub country_code: Option<String>,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DevicePoint {
+    pub device_class: DeviceClass,
+    pub visitors: i64,
+    pub pageviews: i64,
+}
+
+// ========== Post Engagement ==========
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostEngagementFilters {
+    pub post_id: i32,
+    pub group_by: AnalyticsInterval,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostEngagementRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: PostEngagementFilters,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuthorEngagementFilters {
+    pub author_id: i32,
+}
+
+/// `envelope.sort_by`: `views` (default), `visitors`, `avg_read_time`,
+/// `completion_rate`, `likes_per_view` or `comments_per_view`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuthorEngagementRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: AuthorEngagementFilters,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostEngagementPoint {
+    pub post_id: i32,
+    pub title: String,
+    pub views: i64,
+    pub visitors: i64,
+    pub avg_read_time_secs: f64,
+    /// 0-1 share of visitors who reached the end of the post.
+    pub completion_rate: f64,
+    pub likes: i64,
+    pub comments: i64,
+    pub likes_per_view: f64,
+    pub comments_per_view: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostTrafficPoint {
+    pub bucket: String,
+    pub pageviews: i64,
+    pub visitors: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostEngagementData {
+    pub summary: PostEngagementPoint,
+    pub traffic: Vec<PostTrafficPoint>,
+}
+
+// ========== Revenue ==========
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RevenueTrendFilters {
+    pub group_by: AnalyticsInterval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+}
+
+/// Shared by the MRR, subscriber flow and trial conversion trends.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RevenueTrendRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: RevenueTrendFilters,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RevenueBreakdownFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+}
+
+/// Shared by the per-post and per-provider breakdowns. `envelope.sort_by` is
+/// `revenue` (default) or one of the breakdown's count columns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RevenueBreakdownRequest {
+    #[serde(flatten)]
+    pub envelope: AnalyticsEnvelope,
+    pub filters: RevenueBreakdownFilters,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MrrPoint {
+    pub bucket: String,
+    pub mrr_cents: i64,
+    pub arr_cents: i64,
+    pub paying_subscriptions: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SubscriberFlowPoint {
+    pub bucket: String,
+    pub new_subscriptions: i64,
+    pub churned: i64,
+    pub net_change: i64,
+    pub active_at_start: i64,
+    pub churn_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrialConversionPoint {
+    pub bucket: String,
+    pub trials_started: i64,
+    pub converted: i64,
+    pub in_trial: i64,
+    pub conversion_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostRevenuePoint {
+    pub post_id: i32,
+    pub title: String,
+    pub purchases: i64,
+    pub refunded: i64,
+    pub revenue_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProviderRevenuePoint {
+    pub provider: String,
+    pub payments: i64,
+    pub failed_payments: i64,
+    pub revenue_cents: i64,
+    pub refunded_cents: i64,
+    pub post_purchases: i64,
+    pub active_subscriptions: i64,
 }
 
 // ========== Analytics State ==========
@@ -334,6 +547,34 @@ pub struct AnalyticsState {
     pub dashboard_summary: GlobalSignal<
         StateFrame<AnalyticsEnvelopeResponse<DashboardSummaryData>, DashboardSummaryRequest>,
     >,
+    pub traffic_referrers:
+        GlobalSignal<StateFrame<AnalyticsEnvelopeResponse<Vec<ReferrerPoint>>, TrafficRequest>>,
+    pub traffic_campaigns:
+        GlobalSignal<StateFrame<AnalyticsEnvelopeResponse<Vec<CampaignPoint>>, TrafficRequest>>,
+    pub traffic_countries:
+        GlobalSignal<StateFrame<AnalyticsEnvelopeResponse<Vec<CountryPoint>>, TrafficRequest>>,
+    pub traffic_devices:
+        GlobalSignal<StateFrame<AnalyticsEnvelopeResponse<Vec<DevicePoint>>, TrafficRequest>>,
+    pub post_engagement: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<PostEngagementData>, PostEngagementRequest>,
+    >,
+    pub author_engagement: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<Vec<PostEngagementPoint>>, AuthorEngagementRequest>,
+    >,
+    pub revenue_mrr:
+        GlobalSignal<StateFrame<AnalyticsEnvelopeResponse<Vec<MrrPoint>>, RevenueTrendRequest>>,
+    pub revenue_subscribers: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<Vec<SubscriberFlowPoint>>, RevenueTrendRequest>,
+    >,
+    pub revenue_trials: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<Vec<TrialConversionPoint>>, RevenueTrendRequest>,
+    >,
+    pub revenue_posts: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<Vec<PostRevenuePoint>>, RevenueBreakdownRequest>,
+    >,
+    pub revenue_providers: GlobalSignal<
+        StateFrame<AnalyticsEnvelopeResponse<Vec<ProviderRevenuePoint>>, RevenueBreakdownRequest>,
+    >,
 }
 
 impl AnalyticsState {
@@ -347,6 +588,17 @@ impl AnalyticsState {
             newsletter_growth: GlobalSignal::new(|| StateFrame::new()),
             media_upload: GlobalSignal::new(|| StateFrame::new()),
             dashboard_summary: GlobalSignal::new(|| StateFrame::new()),
+            traffic_referrers: GlobalSignal::new(|| StateFrame::new()),
+            traffic_campaigns: GlobalSignal::new(|| StateFrame::new()),
+            traffic_countries: GlobalSignal::new(|| StateFrame::new()),
+            traffic_devices: GlobalSignal::new(|| StateFrame::new()),
+            post_engagement: GlobalSignal::new(|| StateFrame::new()),
+            author_engagement: GlobalSignal::new(|| StateFrame::new()),
+            revenue_mrr: GlobalSignal::new(|| StateFrame::new()),
+            revenue_subscribers: GlobalSignal::new(|| StateFrame::new()),
+            revenue_trials: GlobalSignal::new(|| StateFrame::new()),
+            revenue_posts: GlobalSignal::new(|| StateFrame::new()),
+            revenue_providers: GlobalSignal::new(|| StateFrame::new()),
         }
     }
 }
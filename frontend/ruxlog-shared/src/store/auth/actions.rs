@@ -3,8 +3,8 @@ use super::{
     TwoFactorVerifyPayload, UserRole, UserSession,
 };
 use crate::store::{
-    use_categories, use_comments, use_email_verification, use_media, use_password_reset, use_post,
-    use_tag,
+    use_categories, use_comments, use_email_verification, use_media, use_notifications,
+    use_password_reset, use_post, use_tag,
 };
 
 #[cfg(feature = "analytics-store")]
@@ -19,6 +19,9 @@ use crate::store::use_newsletter;
 #[cfg(feature = "users-store")]
 use crate::store::use_user;
 
+#[cfg(feature = "webhooks-store")]
+use crate::store::use_webhooks;
+
 #[cfg(feature = "image-editor")]
 use crate::store::use_image_editor;
 use dioxus::{logger::tracing, prelude::*};
@@ -118,6 +121,7 @@ impl AuthState {
         use_comments().reset();
         use_email_verification().reset();
         use_password_reset().reset();
+        use_notifications().reset();
 
         // Feature-gated stores
         #[cfg(feature = "image-editor")]
@@ -134,6 +138,9 @@ impl AuthState {
 
         #[cfg(feature = "users-store")]
         use_user().reset();
+
+        #[cfg(feature = "webhooks-store")]
+        use_webhooks().reset();
     }
 
     pub async fn init(&self) {
@@ -1,4 +1,4 @@
-use crate::store::media::Media;
+use crate::store::{media::Media, posts::SeoOverrides};
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use oxstore::{ListQuery, ListStore, PaginatedList, SortParam, StateFrame};
@@ -21,6 +21,8 @@ pub struct Category {
     pub description: Option<String>,
     pub logo_id: Option<i32>,
     pub parent_id: Option<i32>,
+    #[serde(default)]
+    pub seo: Option<SeoOverrides>,
 }
 
 impl Default for Category {
@@ -40,6 +42,7 @@ impl Default for Category {
             description: None,
             logo_id: None,
             parent_id: None,
+            seo: None,
         }
     }
 }
@@ -1,8 +1,10 @@
 use super::{
-    Comment, CommentCreatePayload, CommentFlagPayload, CommentFlagSummary, CommentListQuery,
-    CommentState, CommentUpdatePayload,
+    Comment, CommentCreatePayload, CommentFlagPayload, CommentFlagSummary,
+    CommentLikeActionResponse, CommentLikeStatus, CommentLikeStatusBatchRequest,
+    CommentLikeStatusBatchResponse, CommentListQuery, CommentReaction, CommentReactionPayload,
+    CommentReactionResponse, CommentSort, CommentState, CommentUpdatePayload,
 };
-use dioxus::prelude::ReadableExt;
+use dioxus::prelude::{ReadableExt, WritableExt};
 use oxcore::http;
 use oxstore::{
     edit_state_abstraction, list_state_abstraction, remove_state_abstraction,
@@ -125,13 +127,15 @@ impl CommentState {
         }
     }
 
-    /// List comments for a post (consumer endpoint - returns plain array)
+    /// List comments for a post (consumer endpoint - returns plain array),
+    /// in the current `sort` order
     pub async fn list(&self, post_id: i32) {
+        let sort = *self.sort.read();
         let _ = simple_list_state_abstraction(
             &self.list,
             http::post(
                 &format!("/post/comment/v1/{}", post_id),
-                &serde_json::json!({}),
+                &serde_json::json!({ "sort": sort }),
             )
             .send(),
             "comments",
@@ -139,6 +143,231 @@ impl CommentState {
         .await;
     }
 
+    /// Change the consumer list order and reload it
+    pub async fn set_sort(&self, post_id: i32, sort: CommentSort) {
+        *self.sort.write() = sort;
+        self.list(post_id).await;
+    }
+
+    /// Load the viewer's likes and reactions for the given comments
+    pub async fn fetch_like_status(&self, comment_ids: Vec<i32>) {
+        if comment_ids.is_empty() {
+            return;
+        }
+        let result = http::post(
+            "/post/comment/v1/like/status/batch",
+            &CommentLikeStatusBatchRequest { comment_ids },
+        )
+        .send()
+        .await;
+
+        if let Ok(response) = result {
+            if (200..300).contains(&response.status()) {
+                match response.json::<CommentLikeStatusBatchResponse>().await {
+                    Ok(data) => {
+                        let mut map = self.like_status.write();
+                        for status in data.statuses {
+                            map.entry(status.comment_id)
+                                .or_insert_with(StateFrame::new)
+                                .set_success(Some(status));
+                        }
+                    }
+                    Err(e) => {
+                        dioxus::logger::tracing::error!(
+                            "Failed to parse comment like status: {:?}",
+                            e
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Like a comment
+    pub async fn like(&self, comment_id: i32) {
+        self.run_like_action(comment_id, format!("/post/comment/v1/like/{}", comment_id))
+            .await;
+    }
+
+    /// Remove a like from a comment
+    pub async fn unlike(&self, comment_id: i32) {
+        self.run_like_action(
+            comment_id,
+            format!("/post/comment/v1/unlike/{}", comment_id),
+        )
+        .await;
+    }
+
+    /// Add an emoji reaction to a comment
+    pub async fn react(&self, comment_id: i32, reaction: CommentReaction) {
+        self.run_reaction_action(
+            comment_id,
+            format!("/post/comment/v1/react/{}", comment_id),
+            reaction,
+        )
+        .await;
+    }
+
+    /// Remove an emoji reaction from a comment
+    pub async fn unreact(&self, comment_id: i32, reaction: CommentReaction) {
+        self.run_reaction_action(
+            comment_id,
+            format!("/post/comment/v1/unreact/{}", comment_id),
+            reaction,
+        )
+        .await;
+    }
+
+    async fn run_like_action(&self, comment_id: i32, url: String) {
+        self.like_actions
+            .write()
+            .entry(comment_id)
+            .or_insert_with(StateFrame::new)
+            .set_loading();
+
+        let result = http::post(&url, &()).send().await;
+        match result {
+            Ok(response) if (200..300).contains(&response.status()) => {
+                match response.json::<CommentLikeActionResponse>().await {
+                    Ok(data) => {
+                        self.update_like_status(comment_id, |status| {
+                            status.is_liked = data.is_liked;
+                            status.likes_count = data.likes_count;
+                        });
+                        self.set_list_likes_count(comment_id, data.likes_count);
+                        self.like_actions
+                            .write()
+                            .entry(comment_id)
+                            .or_insert_with(StateFrame::new)
+                            .set_success(None);
+                    }
+                    Err(e) => {
+                        self.like_actions
+                            .write()
+                            .entry(comment_id)
+                            .or_insert_with(StateFrame::new)
+                            .set_decode_error("comment_like", format!("{}", e), None);
+                    }
+                }
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                self.like_actions
+                    .write()
+                    .entry(comment_id)
+                    .or_insert_with(StateFrame::new)
+                    .set_api_error(status, body);
+            }
+            Err(e) => {
+                let (kind, msg) = oxstore::error::classify_transport_error(&e);
+                self.like_actions
+                    .write()
+                    .entry(comment_id)
+                    .or_insert_with(StateFrame::new)
+                    .set_transport_error(kind, Some(msg));
+            }
+        }
+    }
+
+    async fn run_reaction_action(&self, comment_id: i32, url: String, reaction: CommentReaction) {
+        self.like_actions
+            .write()
+            .entry(comment_id)
+            .or_insert_with(StateFrame::new)
+            .set_loading();
+
+        let result = http::post(&url, &CommentReactionPayload { reaction })
+            .send()
+            .await;
+        match result {
+            Ok(response) if (200..300).contains(&response.status()) => {
+                match response.json::<CommentReactionResponse>().await {
+                    Ok(data) => {
+                        self.update_like_status(comment_id, |status| {
+                            status.reactions.retain(|r| *r != data.reaction);
+                            if data.reacted {
+                                status.reactions.push(data.reaction);
+                                status.reactions.sort();
+                            }
+                        });
+                        self.set_list_reaction_count(comment_id, data.reaction, data.count);
+                        self.like_actions
+                            .write()
+                            .entry(comment_id)
+                            .or_insert_with(StateFrame::new)
+                            .set_success(None);
+                    }
+                    Err(e) => {
+                        self.like_actions
+                            .write()
+                            .entry(comment_id)
+                            .or_insert_with(StateFrame::new)
+                            .set_decode_error("comment_reaction", format!("{}", e), None);
+                    }
+                }
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                self.like_actions
+                    .write()
+                    .entry(comment_id)
+                    .or_insert_with(StateFrame::new)
+                    .set_api_error(status, body);
+            }
+            Err(e) => {
+                let (kind, msg) = oxstore::error::classify_transport_error(&e);
+                self.like_actions
+                    .write()
+                    .entry(comment_id)
+                    .or_insert_with(StateFrame::new)
+                    .set_transport_error(kind, Some(msg));
+            }
+        }
+    }
+
+    fn update_like_status(&self, comment_id: i32, apply: impl FnOnce(&mut CommentLikeStatus)) {
+        let mut map = self.like_status.write();
+        let frame = map.entry(comment_id).or_insert_with(StateFrame::new);
+        let mut status = frame.data.clone().unwrap_or(CommentLikeStatus {
+            comment_id,
+            is_liked: false,
+            likes_count: 0,
+            reactions: Vec::new(),
+        });
+        apply(&mut status);
+        frame.set_success(Some(status));
+    }
+
+    fn set_list_likes_count(&self, comment_id: i32, likes_count: i32) {
+        let mut frame = self.list.write();
+        if let Some(comment) = frame
+            .data
+            .as_mut()
+            .and_then(|list| list.data.iter_mut().find(|c| c.id == comment_id))
+        {
+            comment.likes_count = likes_count;
+        }
+    }
+
+    fn set_list_reaction_count(&self, comment_id: i32, reaction: CommentReaction, count: i64) {
+        let mut frame = self.list.write();
+        if let Some(comment) = frame
+            .data
+            .as_mut()
+            .and_then(|list| list.data.iter_mut().find(|c| c.id == comment_id))
+        {
+            comment.reactions.retain(|r| r.reaction != reaction);
+            if count > 0 {
+                comment
+                    .reactions
+                    .push(super::ReactionCount { reaction, count });
+                comment.reactions.sort_by_key(|r| r.reaction);
+            }
+        }
+    }
+
     /// Admin list with filters
     pub async fn admin_list(&self, query: CommentListQuery) {
         let _ = list_state_abstraction(
@@ -149,6 +378,44 @@ impl CommentState {
         .await;
     }
 
+    /// Approve a pending or rejected comment (admin)
+    pub async fn approve(&self, comment_id: i32) {
+        self.run_moderation_action(
+            comment_id,
+            format!("/post/comment/v1/admin/approve/{}", comment_id),
+        )
+        .await;
+    }
+
+    /// Reject a comment so it stays off the public site (admin)
+    pub async fn reject(&self, comment_id: i32) {
+        self.run_moderation_action(
+            comment_id,
+            format!("/post/comment/v1/admin/reject/{}", comment_id),
+        )
+        .await;
+    }
+
+    /// Shadow-ban a comment's author so their comments are only visible to
+    /// themselves (admin). Tracked under the comment the action came from.
+    pub async fn shadow_ban_author(&self, comment_id: i32, user_id: i32) {
+        self.run_moderation_request(
+            comment_id,
+            format!("/post/comment/v1/admin/shadow_ban/{}", user_id),
+            &serde_json::json!({}),
+        )
+        .await;
+    }
+
+    /// Lift a comment author's shadow ban (admin)
+    pub async fn lift_author_shadow_ban(&self, comment_id: i32, user_id: i32) {
+        self.run_moderation_action(
+            comment_id,
+            format!("/post/comment/v1/admin/shadow_ban/lift/{}", user_id),
+        )
+        .await;
+    }
+
     /// Hide a comment (admin)
     pub async fn hide(&self, comment_id: i32) {
         self.run_moderation_action(
@@ -214,6 +481,15 @@ impl CommentState {
     }
 
     async fn run_moderation_action(&self, comment_id: i32, url: String) {
+        self.run_moderation_request(comment_id, url, &()).await;
+    }
+
+    async fn run_moderation_request<B: serde::Serialize>(
+        &self,
+        comment_id: i32,
+        url: String,
+        body: &B,
+    ) {
         {
             let mut map = self.moderation.write();
             map.entry(comment_id)
@@ -221,7 +497,7 @@ impl CommentState {
                 .set_loading();
         }
 
-        let result = http::post(&url, &()).send().await;
+        let result = http::post(&url, body).send().await;
         let mut map = self.moderation.write();
 
         match result {
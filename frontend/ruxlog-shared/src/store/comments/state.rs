@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+pub use ruxlog_types::enums::CommentReaction;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CommentAuthor {
     pub id: i32,
@@ -28,6 +30,71 @@ pub enum FlagFilter {
     NotFlagged,
 }
 
+/// Moderation state of a comment. Only approved comments are public.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStatus {
+    Pending,
+    #[default]
+    Approved,
+    Rejected,
+}
+
+impl CommentStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommentStatus::Pending => "Pending",
+            CommentStatus::Approved => "Approved",
+            CommentStatus::Rejected => "Rejected",
+        }
+    }
+}
+
+/// Order of the public comment list.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentSort {
+    #[default]
+    Oldest,
+    Newest,
+    Top,
+}
+
+impl CommentSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommentSort::Oldest => "Oldest",
+            CommentSort::Newest => "Newest",
+            CommentSort::Top => "Top",
+        }
+    }
+}
+
+/// Role badge shown next to a commenter's name.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentBadge {
+    PostAuthor,
+    Moderator,
+    Admin,
+}
+
+impl CommentBadge {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommentBadge::PostAuthor => "Author",
+            CommentBadge::Moderator => "Moderator",
+            CommentBadge::Admin => "Admin",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReactionCount {
+    pub reaction: CommentReaction,
+    pub count: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Comment {
     pub id: i32,
@@ -45,12 +112,27 @@ pub struct Comment {
     pub likes_count: i32,
     #[serde(default)]
     pub flags_count: i32,
+    #[serde(default)]
+    pub status: CommentStatus,
+    /// Spam scorer output, only present on the admin list.
+    #[serde(default)]
+    pub spam_score: Option<i32>,
+    #[serde(default)]
+    pub spam_reasons: Vec<String>,
+    /// Whether the author is shadow-banned, only present on the admin list.
+    #[serde(default)]
+    pub author_shadow_banned: bool,
     /// User name from consumer endpoint (flat field)
     #[serde(default)]
     pub user_name: Option<String>,
     /// Author object from admin endpoint (nested object)
     #[serde(default)]
     pub author: Option<CommentAuthor>,
+    #[serde(default)]
+    pub badges: Vec<CommentBadge>,
+    /// Emoji reaction totals, only present on the consumer endpoint.
+    #[serde(default)]
+    pub reactions: Vec<ReactionCount>,
 }
 
 impl Comment {
@@ -63,6 +145,51 @@ impl Comment {
             .or_else(|| self.user_name.clone())
             .unwrap_or_else(|| "Anonymous".to_string())
     }
+
+    pub fn is_published(&self) -> bool {
+        self.status == CommentStatus::Approved && !self.hidden
+    }
+}
+
+/// The viewer's like and reactions on one comment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentLikeStatus {
+    pub comment_id: i32,
+    pub is_liked: bool,
+    pub likes_count: i32,
+    #[serde(default)]
+    pub reactions: Vec<CommentReaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentLikeActionResponse {
+    pub comment_id: i32,
+    pub is_liked: bool,
+    pub likes_count: i32,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentLikeStatusBatchRequest {
+    pub comment_ids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentLikeStatusBatchResponse {
+    pub statuses: Vec<CommentLikeStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentReactionPayload {
+    pub reaction: CommentReaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentReactionResponse {
+    pub comment_id: i32,
+    pub reaction: CommentReaction,
+    pub reacted: bool,
+    pub count: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -104,6 +231,8 @@ pub struct CommentListQuery {
     pub user_id: Option<i32>,
     pub page: u64,
     pub limit: Option<u64>,
+    pub status: Option<CommentStatus>,
+    pub shadow_banned: Option<bool>,
     pub hidden_filter: Option<HiddenFilter>,
     pub flag_filter: Option<FlagFilter>,
     pub search: Option<String>,
@@ -159,6 +288,11 @@ pub struct CommentState {
     pub flag_actions: GlobalSignal<HashMap<i32, StateFrame<(), CommentFlagPayload>>>,
     pub moderation: GlobalSignal<HashMap<i32, StateFrame>>,
     pub summaries: GlobalSignal<HashMap<i32, StateFrame<CommentFlagSummary>>>,
+    /// Sort used by the consumer `list` call.
+    pub sort: GlobalSignal<CommentSort>,
+    /// Viewer's like/reaction status per comment id.
+    pub like_status: GlobalSignal<HashMap<i32, StateFrame<CommentLikeStatus>>>,
+    pub like_actions: GlobalSignal<HashMap<i32, StateFrame>>,
 }
 
 impl ListStore<Comment, CommentListQuery> for CommentState {
@@ -187,6 +321,9 @@ impl CommentState {
             flag_actions: GlobalSignal::new(|| HashMap::new()),
             moderation: GlobalSignal::new(|| HashMap::new()),
             summaries: GlobalSignal::new(|| HashMap::new()),
+            sort: GlobalSignal::new(CommentSort::default),
+            like_status: GlobalSignal::new(|| HashMap::new()),
+            like_actions: GlobalSignal::new(|| HashMap::new()),
         }
     }
 
@@ -200,6 +337,9 @@ impl CommentState {
         *self.flag_actions.write() = HashMap::new();
         *self.moderation.write() = HashMap::new();
         *self.summaries.write() = HashMap::new();
+        *self.sort.write() = CommentSort::default();
+        *self.like_status.write() = HashMap::new();
+        *self.like_actions.write() = HashMap::new();
     }
 }
 
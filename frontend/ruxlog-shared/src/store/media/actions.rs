@@ -1,16 +1,22 @@
 use super::{
-    Media, MediaListQuery, MediaState, MediaUploadPayload, MediaUsageDetails,
+    Media, MediaEditPayload, MediaListQuery, MediaState, MediaUploadPayload, MediaUsageDetails,
     MediaUsageDetailsRequest, MediaUsageDetailsResponse, UploadStatus,
 };
+#[cfg(target_arch = "wasm32")]
+use super::{
+    MediaUploadInitRequest, MediaUploadInitResponse, MediaUploadPartsRequest,
+    MediaUploadPartsResponse, MediaUploadSession, MediaUploadStatusResponse,
+};
 use oxcore::http;
 
 use oxstore::{
-    list_state_abstraction, remove_state_abstraction, view_state_abstraction, StateFrame,
+    edit_state_abstraction, list_state_abstraction, remove_state_abstraction,
+    view_state_abstraction, StateFrame,
 };
 use std::collections::HashMap;
 
 #[cfg(target_arch = "wasm32")]
-use web_sys::{Blob, FormData, Url};
+use web_sys::{Blob, File, FormData, Url};
 
 impl MediaState {
     #[cfg(target_arch = "wasm32")]
@@ -18,54 +24,8 @@ impl MediaState {
     pub async fn upload(&self, payload: MediaUploadPayload) -> Result<String, String> {
         dioxus::logger::tracing::debug!("[MediaState::upload] Starting upload");
 
-        // 1. Create blob URL immediately for instant preview
-        let blob: &Blob = payload.file.as_ref();
-        dioxus::logger::tracing::debug!("[MediaState::upload] Creating blob URL for file");
-
-        let blob_url = Url::create_object_url_with_blob(blob).map_err(|e| {
-            let err_msg = format!("Failed to create blob URL: {:?}", e);
-            dioxus::logger::tracing::error!("[MediaState::upload] {}", &err_msg);
-            err_msg
-        })?;
-
-        // Extract file info
-        let filename = payload.file.name();
-        let size = payload.file.size() as i64;
-        let mime_type = payload.file.type_();
-
-        dioxus::logger::tracing::debug!(
-            "[MediaState::upload] Blob URL created: {} | File: {} | Size: {} | Type: {}",
-            &blob_url,
-            &filename,
-            size,
-            &mime_type
-        );
-
-        // 2. Initialize tracking state
-        dioxus::logger::tracing::debug!("[MediaState::upload] Initializing tracking state");
-        {
-            let mut status_map = self.upload_status.write();
-            status_map.insert(blob_url.clone(), UploadStatus::Uploading);
-        }
-        {
-            let mut progress_map = self.upload_progress.write();
-            progress_map.insert(blob_url.clone(), 0.0);
-        }
-        {
-            let mut blob_map = self.blob_to_media.write();
-            blob_map.insert(blob_url.clone(), None);
-        }
-        {
-            let mut file_info_map = self.blob_file_info.write();
-            file_info_map.insert(
-                blob_url.clone(),
-                super::FileInfo {
-                    filename: filename.clone(),
-                    size,
-                },
-            );
-        }
-        dioxus::logger::tracing::debug!("[MediaState::upload] Tracking state initialized");
+        // 1-2. Blob URL for instant preview + tracking state
+        let blob_url = self.start_tracking(&payload.file)?;
 
         // 3. Prepare multipart form data
         dioxus::logger::tracing::debug!("[MediaState::upload] Preparing form data");
@@ -125,7 +85,7 @@ impl MediaState {
         let blob_url_clone = blob_url.clone();
         dioxus::logger::tracing::debug!(
             "[MediaState::upload] Spawning background upload task for: {}",
-            &filename
+            payload.file.name()
         );
 
         wasm_bindgen_futures::spawn_local(async move {
@@ -252,6 +212,118 @@ impl MediaState {
         Err("File upload is only supported in WASM environment".to_string())
     }
 
+    #[cfg(target_arch = "wasm32")]
+    /// Create the preview blob URL for `file` and register it with the upload
+    /// tracking maps.
+    fn start_tracking(&self, file: &File) -> Result<String, String> {
+        let blob: &Blob = file.as_ref();
+        let blob_url = Url::create_object_url_with_blob(blob).map_err(|e| {
+            let err_msg = format!("Failed to create blob URL: {:?}", e);
+            dioxus::logger::tracing::error!("[MediaState::upload] {}", &err_msg);
+            err_msg
+        })?;
+
+        let filename = file.name();
+        let size = file.size() as i64;
+        dioxus::logger::tracing::debug!(
+            "[MediaState::upload] Blob URL created: {} | File: {} | Size: {} | Type: {}",
+            &blob_url,
+            &filename,
+            size,
+            file.type_()
+        );
+
+        self.upload_status
+            .write()
+            .insert(blob_url.clone(), UploadStatus::Uploading);
+        self.upload_progress.write().insert(blob_url.clone(), 0.0);
+        self.blob_to_media.write().insert(blob_url.clone(), None);
+        self.blob_file_info
+            .write()
+            .insert(blob_url.clone(), super::FileInfo { filename, size });
+
+        Ok(blob_url)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    /// Resumable direct upload for large files: the file is sent straight to
+    /// storage in parts through presigned URLs and the backend only finalizes
+    /// it. Returns the blob URL immediately, like [`MediaState::upload`].
+    ///
+    /// The session id is remembered in localStorage per file, so picking the
+    /// same file again after a dropped connection or a reload only sends the
+    /// parts that are still missing.
+    pub async fn upload_resumable(&self, payload: MediaUploadPayload) -> Result<String, String> {
+        let blob_url = self.start_tracking(&payload.file)?;
+        let blob_url_clone = blob_url.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            use super::use_media;
+            let media_state = use_media();
+
+            let progress_url = blob_url_clone.clone();
+            let result = run_direct_upload(&payload, move |progress| {
+                use_media()
+                    .upload_progress
+                    .write()
+                    .insert(progress_url.clone(), progress);
+            })
+            .await;
+
+            match result {
+                Ok(media) => {
+                    dioxus::logger::tracing::debug!(
+                        "[MediaState::upload_resumable] Upload finished, media ID: {}",
+                        media.id
+                    );
+                    media_state
+                        .upload_status
+                        .write()
+                        .insert(blob_url_clone.clone(), UploadStatus::Success);
+                    media_state
+                        .upload_progress
+                        .write()
+                        .insert(blob_url_clone.clone(), 100.0);
+                    media_state
+                        .blob_to_media
+                        .write()
+                        .insert(blob_url_clone, Some(media));
+                    media_state.list().await;
+                }
+                Err(err_msg) => {
+                    dioxus::logger::tracing::error!("[MediaState::upload_resumable] {}", &err_msg);
+                    media_state
+                        .upload_status
+                        .write()
+                        .insert(blob_url_clone, UploadStatus::Error(err_msg));
+                }
+            }
+        });
+
+        Ok(blob_url)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Native upload: not supported for native target
+    pub async fn upload_resumable(&self, _payload: MediaUploadPayload) -> Result<String, String> {
+        Err("File upload is only supported in WASM environment".to_string())
+    }
+
+    pub async fn edit(&self, id: i32, payload: MediaEditPayload) -> Option<Media> {
+        edit_state_abstraction(
+            &self.edit,
+            id,
+            payload.clone(),
+            http::post(&format!("/media/v1/update/{}", id), &payload).send(),
+            "media",
+            Some(&self.list),
+            Some(&self.view),
+            |media: &Media| media.id,
+            None::<fn(&Media)>,
+        )
+        .await
+    }
+
     pub async fn remove(&self, id: i32) {
         let _ = remove_state_abstraction(
             &self.remove,
@@ -326,6 +398,7 @@ impl MediaState {
 
     pub fn reset(&self) {
         *self.upload.write() = StateFrame::new();
+        *self.edit.write() = HashMap::new();
         *self.remove.write() = HashMap::new();
         *self.list.write() = StateFrame::new();
         *self.view.write() = HashMap::new();
@@ -393,3 +466,169 @@ impl MediaState {
         // No blob URL cleanup needed for native
     }
 }
+
+/// Parts requested per presign round trip; well under the backend's cap.
+#[cfg(target_arch = "wasm32")]
+const PART_BATCH: usize = 10;
+
+#[cfg(target_arch = "wasm32")]
+fn resume_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+/// localStorage key identifying "the same file" across page loads.
+#[cfg(target_arch = "wasm32")]
+fn resume_key(file: &File) -> String {
+    format!(
+        "ruxlog:media-upload:{}:{}:{}",
+        file.name(),
+        file.size() as i64,
+        file.last_modified() as i64
+    )
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn post_json<B: serde::Serialize, T: serde::de::DeserializeOwned>(
+    endpoint: &str,
+    body: &B,
+) -> Result<T, String> {
+    let response = http::post(endpoint, body)
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {:?}", e))?;
+    let status = response.status();
+    if !(200..300).contains(&status) {
+        return Err(format!(
+            "{} failed with status {}: {}",
+            endpoint,
+            status,
+            response.body_text()
+        ));
+    }
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| format!("Failed to parse response: {:?}", e))
+}
+
+/// Resume the remembered session for `file` if the server still has it
+/// pending, returning it with the parts that are still missing.
+#[cfg(target_arch = "wasm32")]
+async fn resume_session(key: &str) -> Option<(MediaUploadSession, Vec<i32>)> {
+    let storage = resume_storage()?;
+    let upload_id = storage.get_item(key).ok().flatten()?;
+
+    let status = post_json::<_, MediaUploadStatusResponse>(
+        &format!("/media/v1/upload/{}/status", upload_id),
+        &serde_json::json!({}),
+    )
+    .await;
+
+    match status {
+        Ok(status) if status.upload.status == "pending" => {
+            Some((status.upload, status.missing_parts))
+        }
+        _ => {
+            let _ = storage.remove_item(key);
+            None
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn run_direct_upload(
+    payload: &MediaUploadPayload,
+    on_progress: impl Fn(f64),
+) -> Result<Media, String> {
+    let file = &payload.file;
+    let key = resume_key(file);
+
+    let (session, missing) = match resume_session(&key).await {
+        Some(resumed) => {
+            dioxus::logger::tracing::debug!(
+                "[run_direct_upload] Resuming upload {} with {} parts missing",
+                resumed.0.id,
+                resumed.1.len()
+            );
+            resumed
+        }
+        None => {
+            let mime_type = file.type_();
+            let init = post_json::<_, MediaUploadInitResponse>(
+                "/media/v1/upload/init",
+                &MediaUploadInitRequest {
+                    filename: file.name(),
+                    mime_type: (!mime_type.is_empty()).then_some(mime_type),
+                    size: file.size() as i64,
+                    reference_type: payload.reference_type,
+                    width: payload.width,
+                    height: payload.height,
+                },
+            )
+            .await?;
+
+            if let Some(media) = init.media.filter(|_| init.duplicate) {
+                return Ok(media);
+            }
+            let session = init
+                .upload
+                .ok_or_else(|| "Upload init returned no session".to_string())?;
+            if let Some(storage) = resume_storage() {
+                let _ = storage.set_item(&key, &session.id.to_string());
+            }
+            let missing = (1..=session.part_count).collect();
+            (session, missing)
+        }
+    };
+
+    let total = session.part_count.max(1) as f64;
+    let mut done = (session.part_count as usize).saturating_sub(missing.len()) as f64;
+    // Leave the last few percent for server-side finalization.
+    on_progress(done / total * 95.0);
+
+    for batch in missing.chunks(PART_BATCH) {
+        let urls = post_json::<_, MediaUploadPartsResponse>(
+            &format!("/media/v1/upload/{}/parts", session.id),
+            &MediaUploadPartsRequest {
+                part_numbers: batch.to_vec(),
+            },
+        )
+        .await?;
+
+        for part in urls.parts {
+            let start = (part.part_number as i64 - 1) * session.part_size;
+            let end = (start + session.part_size).min(session.size);
+            let chunk = file
+                .slice_with_f64_and_f64(start as f64, end as f64)
+                .map_err(|e| format!("Failed to slice file: {:?}", e))?;
+
+            let response = http::put_blob(&part.url, &chunk)?
+                .send()
+                .await
+                .map_err(|e| format!("Part {} failed: {:?}", part.part_number, e))?;
+            if !(200..300).contains(&response.status()) {
+                return Err(format!(
+                    "Part {} failed with status {}",
+                    part.part_number,
+                    response.status()
+                ));
+            }
+
+            done += 1.0;
+            on_progress(done / total * 95.0);
+        }
+    }
+
+    let media = post_json::<_, Media>(
+        &format!("/media/v1/upload/{}/complete", session.id),
+        &serde_json::json!({}),
+    )
+    .await?;
+
+    // On failure the key is kept: `resume_session` drops it if the server
+    // has since closed the session.
+    if let Some(storage) = resume_storage() {
+        let _ = storage.remove_item(&key);
+    }
+    Ok(media)
+}
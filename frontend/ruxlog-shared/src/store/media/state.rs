@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use oxstore::{ListQuery, ListStore, PaginatedList, SortParam, StateFrame};
-pub use ruxlog_types::enums::MediaReference;
+pub use ruxlog_types::enums::{MediaKind, MediaReference};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use web_sys::File;
@@ -35,6 +35,31 @@ pub struct Media {
     #[serde(default)]
     pub optimized_at: Option<DateTime<Utc>>,
     #[serde(default)]
+    pub alt_text: Option<String>,
+    #[serde(default)]
+    pub caption: Option<String>,
+    #[serde(default)]
+    pub credit: Option<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+    #[serde(default)]
+    pub focal_x: Option<f64>,
+    #[serde(default)]
+    pub focal_y: Option<f64>,
+    #[serde(default)]
+    pub blurhash: Option<String>,
+    /// Playback length of video and audio uploads.
+    #[serde(default)]
+    pub duration_ms: Option<i64>,
+    #[serde(default)]
+    pub page_count: Option<i32>,
+    /// Poster frame, cover art or first-page thumbnail of non-image media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
+    /// `<picture>` sources, present on post payloads (featured image).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responsive: Option<ResponsiveImage>,
+    #[serde(default)]
     pub usage_count: i32,
     #[serde(default = "default_datetime")]
     pub created_at: DateTime<Utc>,
@@ -42,6 +67,92 @@ pub struct Media {
     pub updated_at: DateTime<Utc>,
 }
 
+impl Media {
+    /// Attribution line combining credit and license, if either is set.
+    pub fn attribution(&self) -> Option<String> {
+        format_attribution(self.credit.as_deref(), self.license.as_deref())
+    }
+
+    /// CSS `object-position` value for the focal point, centred by default.
+    pub fn object_position(&self) -> String {
+        let x = self.focal_x.unwrap_or(0.5) * 100.0;
+        let y = self.focal_y.unwrap_or(0.5) * 100.0;
+        format!("{:.1}% {:.1}%", x, y)
+    }
+
+    pub fn kind(&self) -> Option<MediaKind> {
+        MediaKind::from_mime(&self.mime_type)
+    }
+}
+
+/// Playback length as `m:ss`, or `h:mm:ss` from an hour up.
+pub fn format_duration(duration_ms: i64) -> String {
+    let total = duration_ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (total / 3600, (total / 60) % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// "Credit (License)", or whichever of the two is present.
+pub fn format_attribution(credit: Option<&str>, license: Option<&str>) -> Option<String> {
+    match (credit, license) {
+        (Some(credit), Some(license)) => Some(format!("{} ({})", credit, license)),
+        (Some(credit), None) => Some(credit.to_string()),
+        (None, Some(license)) => Some(license.to_string()),
+        (None, None) => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResponsiveSource {
+    pub mime_type: String,
+    pub srcset: String,
+}
+
+/// Ready-to-render responsive image: modern-format `<source>`s plus an `<img>`
+/// fallback, with a blurhash / LQIP placeholder.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResponsiveImage {
+    pub media_id: i32,
+    pub src: String,
+    #[serde(default)]
+    pub width: Option<i32>,
+    #[serde(default)]
+    pub height: Option<i32>,
+    #[serde(default)]
+    pub srcset: Option<String>,
+    pub sizes: String,
+    #[serde(default)]
+    pub sources: Vec<ResponsiveSource>,
+    #[serde(default)]
+    pub blurhash: Option<String>,
+    #[serde(default)]
+    pub placeholder: Option<String>,
+}
+
+impl ResponsiveImage {
+    /// Average colour encoded in the blurhash, as a CSS `#rrggbb` value.
+    pub fn placeholder_color(&self) -> Option<String> {
+        self.blurhash.as_deref().and_then(blurhash_average_color)
+    }
+}
+
+/// Decode the DC component (characters 2..6, base83) of a blurhash into a hex
+/// colour. Cheap enough to paint a background before the image loads.
+pub fn blurhash_average_color(hash: &str) -> Option<String> {
+    const DIGITS: &str =
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+    let dc = hash.get(2..6)?;
+    let mut value: u32 = 0;
+    for ch in dc.chars() {
+        value = value * 83 + DIGITS.find(ch)? as u32;
+    }
+    Some(format!("#{:06x}", value & 0xff_ffff))
+}
+
 fn default_datetime() -> DateTime<Utc> {
     Utc::now()
 }
@@ -62,6 +173,17 @@ impl Default for Media {
             content_hash: None,
             is_optimized: false,
             optimized_at: None,
+            alt_text: None,
+            caption: None,
+            credit: None,
+            license: None,
+            focal_x: None,
+            focal_y: None,
+            blurhash: None,
+            duration_ms: None,
+            page_count: None,
+            poster_url: None,
+            responsive: None,
             usage_count: 0,
             created_at: DateTime::<Utc>::from_timestamp(0, 0).unwrap_or_else(|| Utc::now()),
             updated_at: DateTime::<Utc>::from_timestamp(0, 0).unwrap_or_else(|| Utc::now()),
@@ -131,6 +253,20 @@ pub struct MediaUploadPayload {
     pub height: Option<i32>,
 }
 
+/// Descriptive fields sent to `/media/v1/update/{id}`. `None` leaves a field
+/// untouched and an empty string clears it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MediaEditPayload {
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub license: Option<String>,
+    pub focal_x: Option<f64>,
+    pub focal_y: Option<f64>,
+    #[serde(default)]
+    pub clear_focal_point: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileInfo {
     pub filename: String,
@@ -195,6 +331,67 @@ pub struct UserReference {
     pub avatar_id: Option<i32>,
 }
 
+/// Files larger than this skip `/media/v1/create` (whose body limit it
+/// mirrors) and go straight to storage through a resumable direct upload.
+pub const DIRECT_UPLOAD_THRESHOLD: f64 = 2.0 * 1024.0 * 1024.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadInitRequest {
+    pub filename: String,
+    pub mime_type: Option<String>,
+    pub size: i64,
+    pub reference_type: Option<MediaReference>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadSession {
+    pub id: i32,
+    #[serde(default)]
+    pub filename: Option<String>,
+    pub mime_type: String,
+    pub size: i64,
+    pub part_size: i64,
+    pub part_count: i32,
+    pub status: String,
+    #[serde(default)]
+    pub media_id: Option<i32>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadInitResponse {
+    pub duplicate: bool,
+    #[serde(default)]
+    pub media: Option<Media>,
+    #[serde(default)]
+    pub upload: Option<MediaUploadSession>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadPartsRequest {
+    pub part_numbers: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadPartUrl {
+    pub part_number: i32,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadPartsResponse {
+    pub parts: Vec<MediaUploadPartUrl>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediaUploadStatusResponse {
+    pub upload: MediaUploadSession,
+    #[serde(default)]
+    pub missing_parts: Vec<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MediaUsageDetailsRequest {
     pub media_ids: Vec<i32>,
@@ -207,6 +404,7 @@ pub struct MediaUsageDetailsResponse {
 
 pub struct MediaState {
     pub upload: GlobalSignal<StateFrame<(), MediaUploadPayload>>,
+    pub edit: GlobalSignal<HashMap<i32, StateFrame<(), MediaEditPayload>>>,
     pub remove: GlobalSignal<HashMap<i32, StateFrame>>,
     pub list: GlobalSignal<StateFrame<PaginatedList<Media>>>,
     pub view: GlobalSignal<HashMap<i32, StateFrame<Media>>>,
@@ -222,6 +420,7 @@ impl MediaState {
     pub fn new() -> Self {
         Self {
             upload: GlobalSignal::new(|| StateFrame::new()),
+            edit: GlobalSignal::new(|| HashMap::new()),
             remove: GlobalSignal::new(|| HashMap::new()),
             list: GlobalSignal::new(|| StateFrame::new()),
             view: GlobalSignal::new(|| HashMap::new()),
@@ -16,11 +16,14 @@ pub mod likes;
 pub mod media;
 #[cfg(feature = "newsletter-store")]
 pub mod newsletter;
+pub mod notifications;
 pub mod password_reset;
 pub mod posts;
 pub mod tags;
 #[cfg(feature = "users-store")]
 pub mod users;
+#[cfg(feature = "webhooks-store")]
+pub mod webhooks;
 
 #[cfg(feature = "admin-routes-store")]
 pub use acl::*;
@@ -40,8 +43,11 @@ pub use likes::*;
 pub use media::*;
 #[cfg(feature = "newsletter-store")]
 pub use newsletter::*;
+pub use notifications::*;
 pub use password_reset::*;
 pub use posts::*;
 pub use tags::*;
 #[cfg(feature = "users-store")]
 pub use users::*;
+#[cfg(feature = "webhooks-store")]
+pub use webhooks::*;
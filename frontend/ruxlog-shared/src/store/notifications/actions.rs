@@ -0,0 +1,126 @@
+use super::{
+    MarkNotificationsReadPayload, MarkNotificationsReadResponse, Notification,
+    NotificationListQuery, NotificationPreferences, NotificationState, UnreadCount,
+    UpdateNotificationPreferencesPayload,
+};
+use oxcore::http;
+use oxstore::{list_state_abstraction, state_request_abstraction};
+
+impl NotificationState {
+    pub async fn fetch_preferences(&self) {
+        let _ = state_request_abstraction(
+            &self.preferences,
+            None::<()>,
+            http::get("/notification/v1/preferences").send(),
+            "notification_preferences",
+            |preferences: &NotificationPreferences| (Some(preferences.clone()), None),
+        )
+        .await;
+    }
+
+    pub async fn update_preferences(&self, payload: UpdateNotificationPreferencesPayload) {
+        let updated = state_request_abstraction(
+            &self.update_preferences,
+            Some(payload.clone()),
+            http::post("/notification/v1/preferences/update", &payload).send(),
+            "notification_preferences",
+            |preferences: &NotificationPreferences| (Some(preferences.clone()), None),
+        )
+        .await;
+
+        if let Some(preferences) = updated {
+            self.preferences.write().set_success(Some(preferences));
+        }
+    }
+
+    pub async fn list(&self, query: NotificationListQuery) {
+        let _ = list_state_abstraction(
+            &self.inbox,
+            http::post("/notification/v1/list/query", &query).send(),
+            "notifications",
+        )
+        .await;
+    }
+
+    pub async fn fetch_unread_count(&self) {
+        let _ = state_request_abstraction(
+            &self.unread,
+            None::<()>,
+            http::get("/notification/v1/unread_count").send(),
+            "notification_unread_count",
+            |count: &UnreadCount| (Some(count.clone()), None),
+        )
+        .await;
+    }
+
+    pub async fn mark_read(&self, ids: Vec<i32>) {
+        if ids.is_empty() {
+            return;
+        }
+        let payload = MarkNotificationsReadPayload { ids: ids.clone() };
+        let result = state_request_abstraction(
+            &self.mark_read,
+            None::<()>,
+            http::post("/notification/v1/mark_read", &payload).send(),
+            "notification_mark_read",
+            |response: &MarkNotificationsReadResponse| (Some(response.clone()), None),
+        )
+        .await;
+
+        if let Some(response) = result {
+            self.apply_read(
+                |notification| ids.contains(&notification.id),
+                response.unread,
+            );
+        }
+    }
+
+    pub async fn mark_all_read(&self) {
+        let result = state_request_abstraction(
+            &self.mark_read,
+            None::<()>,
+            http::post("/notification/v1/mark_all_read", &()).send(),
+            "notification_mark_read",
+            |response: &MarkNotificationsReadResponse| (Some(response.clone()), None),
+        )
+        .await;
+
+        if let Some(response) = result {
+            self.apply_read(|_| true, response.unread);
+        }
+    }
+
+    /// Add a notification pushed by the stream to the top of the inbox.
+    pub fn receive(&self, notification: Notification) {
+        {
+            let mut inbox = self.inbox.write();
+            if let Some(list) = inbox.data.as_mut() {
+                if list.data.iter().any(|n| n.id == notification.id) {
+                    return;
+                }
+                list.data.insert(0, notification.clone());
+                list.total += 1;
+            }
+        }
+        if !notification.is_read() {
+            let unread = self.unread_count() + 1;
+            self.unread
+                .write()
+                .set_success(Some(UnreadCount { unread }));
+        }
+    }
+
+    fn apply_read(&self, matches: impl Fn(&Notification) -> bool, unread: u64) {
+        let now = chrono::Utc::now();
+        if let Some(list) = self.inbox.write().data.as_mut() {
+            for notification in list.data.iter_mut() {
+                if notification.read_at.is_none() && matches(notification) {
+                    notification.read_at = Some(now);
+                }
+            }
+        }
+        self.unread
+            .write()
+            .set_success(Some(UnreadCount { unread }));
+    }
+}
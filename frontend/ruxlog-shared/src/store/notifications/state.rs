@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use oxstore::{PaginatedList, StateFrame};
+pub use ruxlog_types::enums::{EmailDelivery, NotificationKind};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationPreferences {
+    pub user_id: i32,
+    pub comment_on_post: bool,
+    pub comment_reply: bool,
+    pub comment_mention: bool,
+    pub new_user: bool,
+    pub payment_received: bool,
+    pub subscription_canceled: bool,
+    pub newsletter_subscriber: bool,
+    pub contact_form: bool,
+    pub email_enabled: bool,
+    pub in_app_enabled: bool,
+    pub email_delivery: EmailDelivery,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Partial update; fields left as `None` keep their saved value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateNotificationPreferencesPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_on_post: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_mention: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_user: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_received: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_canceled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newsletter_subscriber: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_form: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_app_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_delivery: Option<EmailDelivery>,
+}
+
+/// An entry in the notification center.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Notification {
+    pub id: i32,
+    pub user_id: i32,
+    pub kind: NotificationKind,
+    pub subject: String,
+    pub body: String,
+    #[serde(default)]
+    pub link: Option<String>,
+    #[serde(default)]
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct NotificationListQuery {
+    pub page: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_only: Option<bool>,
+}
+
+impl NotificationListQuery {
+    pub fn new() -> Self {
+        Self {
+            page: 1,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct UnreadCount {
+    pub unread: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MarkNotificationsReadPayload {
+    pub ids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MarkNotificationsReadResponse {
+    pub updated: u64,
+    pub unread: u64,
+}
+
+pub struct NotificationState {
+    pub preferences: GlobalSignal<StateFrame<NotificationPreferences>>,
+    pub update_preferences:
+        GlobalSignal<StateFrame<NotificationPreferences, UpdateNotificationPreferencesPayload>>,
+    /// Newest notifications first, as shown in the bell dropdown.
+    pub inbox: GlobalSignal<StateFrame<PaginatedList<Notification>>>,
+    pub unread: GlobalSignal<StateFrame<UnreadCount>>,
+    pub mark_read: GlobalSignal<StateFrame<MarkNotificationsReadResponse>>,
+}
+
+impl NotificationState {
+    pub fn new() -> Self {
+        Self {
+            preferences: GlobalSignal::new(|| StateFrame::new()),
+            update_preferences: GlobalSignal::new(|| StateFrame::new()),
+            inbox: GlobalSignal::new(|| StateFrame::new()),
+            unread: GlobalSignal::new(|| StateFrame::new()),
+            mark_read: GlobalSignal::new(|| StateFrame::new()),
+        }
+    }
+
+    pub fn reset(&self) {
+        *self.preferences.write() = StateFrame::new();
+        *self.update_preferences.write() = StateFrame::new();
+        *self.inbox.write() = StateFrame::new();
+        *self.unread.write() = StateFrame::new();
+        *self.mark_read.write() = StateFrame::new();
+    }
+
+    /// Unread notifications, for the bell badge.
+    pub fn unread_count(&self) -> u64 {
+        self.unread
+            .read()
+            .data
+            .as_ref()
+            .map(|count| count.unread)
+            .unwrap_or(0)
+    }
+}
+
+static NOTIFICATION_STATE: OnceLock<NotificationState> = OnceLock::new();
+
+pub fn use_notifications() -> &'static NotificationState {
+    NOTIFICATION_STATE.get_or_init(NotificationState::new)
+}
@@ -1,4 +1,4 @@
-use crate::store::media::Media;
+use crate::store::media::{Media, MediaKind, ResponsiveImage};
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use oxstore::{ListQuery, PaginatedList, SortParam, StateFrame};
@@ -14,8 +14,18 @@ use std::collections::HashMap;
 pub struct PostAuthor {
     pub id: i32,
     pub name: String,
-    pub email: String,
     pub avatar: Option<Media>,
+    #[serde(default)]
+    pub profile_slug: Option<String>,
+}
+
+impl PostAuthor {
+    /// Handle of the author's public page.
+    pub fn page_slug(&self) -> String {
+        self.profile_slug
+            .clone()
+            .unwrap_or_else(|| self.id.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -223,6 +233,19 @@ pub struct ImageFile {
     pub title: Option<String>,
     #[serde(default)]
     pub media_id: Option<i32>,
+    /// Descriptive fields merged in from the media library on read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focal_x: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focal_y: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responsive: Option<ResponsiveImage>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -251,16 +274,42 @@ pub struct LinkToolMeta {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AttachesBlock {
     pub file: AttachesFile,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AttachesFile {
     pub url: String,
+    #[serde(default)]
     pub size: i64,
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub extension: String,
     #[serde(default)]
     pub media_id: Option<i32>,
+    /// Playback and preview details merged in from the media library on read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<i32>,
+    /// Poster frame, cover art or first-page thumbnail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster: Option<String>,
+}
+
+impl AttachesFile {
+    /// What kind of player the attachment needs, from the merged MIME type or,
+    /// for blocks saved before it was merged, the file extension.
+    pub fn kind(&self) -> Option<MediaKind> {
+        self.mime_type
+            .as_deref()
+            .and_then(MediaKind::from_mime)
+            .or_else(|| MediaKind::from_extension(&self.extension))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -298,6 +347,9 @@ pub struct Post {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub author: PostAuthor,
+    /// Credited after `author`, in order.
+    #[serde(default)]
+    pub co_authors: Vec<PostAuthor>,
     pub category: PostCategory,
     #[serde(default)]
     pub tags: Vec<PostTag>,
@@ -305,9 +357,21 @@ pub struct Post {
     pub view_count: i32,
     pub comment_count: i64,
     pub status: PostStatus,
+    #[serde(default)]
+    pub seo: Option<SeoOverrides>,
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Other locales' versions; only filled on single-post reads.
+    #[serde(default)]
+    pub translations: Vec<PostTranslation>,
 }
 
 impl Post {
+    /// Every credited author, owner first.
+    pub fn authors(&self) -> impl Iterator<Item = &PostAuthor> {
+        std::iter::once(&self.author).chain(self.co_authors.iter())
+    }
+
     pub fn is_published(&self) -> bool {
         self.status == PostStatus::Published
     }
@@ -321,6 +385,49 @@ impl Post {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostTranslation {
+    pub id: i32,
+    pub locale: String,
+    pub slug: String,
+    pub title: String,
+    pub status: PostStatus,
+}
+
+// ============================================================================
+// SEO Overrides (posts, categories and tags)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeoOgImage {
+    pub id: i32,
+    pub file_url: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+/// Hand-set SEO metadata; unset fields fall back to what the content implies.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SeoOverrides {
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    #[serde(default)]
+    pub noindex: bool,
+    #[serde(default)]
+    pub og_image: Option<SeoOgImage>,
+}
+
+/// Replaces the stored overrides as a whole; an all-empty payload clears them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SeoPayload {
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
+    pub og_image_id: Option<i32>,
+}
+
 // ============================================================================
 // Post Payloads (matching backend V1 API)
 // ============================================================================
@@ -338,6 +445,16 @@ pub struct PostCreatePayload {
     pub category_id: i32,
     #[serde(default)]
     pub tag_ids: Vec<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Post this one translates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation_of: Option<i32>,
+    /// Users credited after the creator, in order.
+    #[serde(default)]
+    pub co_author_ids: Vec<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seo: Option<SeoPayload>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -358,6 +475,15 @@ pub struct PostEditPayload {
     pub featured_image_id: Option<i32>,
     pub category_id: Option<i32>,
     pub tag_ids: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation_of: Option<i32>,
+    /// Replaces the co-author list when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub co_author_ids: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seo: Option<SeoPayload>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -370,6 +496,7 @@ pub struct PostListQuery {
     pub sorts: Option<Vec<SortParam>>,
     pub tag_ids: Option<Vec<i32>>,
     pub title: Option<String>,
+    pub locale: Option<String>,
     pub created_at_gt: Option<DateTime<Utc>>,
     pub created_at_lt: Option<DateTime<Utc>>,
     pub updated_at_gt: Option<DateTime<Utc>>,
@@ -452,6 +579,13 @@ pub struct Series {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Public series page: the series and its published posts in reading order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeriesPage {
+    pub series: Series,
+    pub data: Vec<Post>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct SeriesCreatePayload {
     pub name: String,
@@ -1,3 +1,4 @@
+use crate::store::posts::SeoOverrides;
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use oxstore::{ListQuery, ListStore, PaginatedList, SortParam, StateFrame};
@@ -15,6 +16,8 @@ pub struct Tag {
     pub color: String,
     pub text_color: String,
     pub is_active: bool,
+    #[serde(default)]
+    pub seo: Option<SeoOverrides>,
 }
 
 impl Default for Tag {
@@ -29,6 +32,7 @@ impl Default for Tag {
             color: "#3b82f6".to_string(),
             text_color: "#ffffff".to_string(),
             is_active: true,
+            seo: None,
         }
     }
 }
@@ -54,12 +54,24 @@ pub struct UsersEditPayload {
     pub role: Option<String>,
 }
 
+/// A labelled link on a public author profile.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SocialLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Omitted fields are left alone; blank strings clear them.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct UpdateProfilePayload {
     pub name: Option<String>,
     pub bio: Option<String>,
     pub avatar_id: Option<Option<i32>>,
-    pub social_links: Option<serde_json::Value>,
+    pub social_links: Option<Vec<SocialLink>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_slug: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -69,7 +81,12 @@ pub struct UserProfile {
     pub email: String,
     pub avatar: Option<Media>,
     pub bio: Option<String>,
-    pub social_links: Option<serde_json::Value>,
+    #[serde(default)]
+    pub social_links: Vec<SocialLink>,
+    #[serde(default)]
+    pub website_url: Option<String>,
+    #[serde(default)]
+    pub profile_slug: Option<String>,
     pub role: UserRole,
     pub is_verified: bool,
     pub created_at: DateTime<Utc>,
@@ -79,6 +96,31 @@ pub struct UserProfile {
     pub tags: Option<HashSet<String>>,
 }
 
+/// What `/author/v1/{slug}` exposes about an author.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PublicAuthorProfile {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub avatar: Option<Media>,
+    pub bio: Option<String>,
+    pub website_url: Option<String>,
+    #[serde(default)]
+    pub social_links: Vec<SocialLink>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An author page: the profile plus one page of their published posts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuthorPage {
+    pub author: PublicAuthorProfile,
+    pub data: Vec<crate::store::Post>,
+    pub total: u64,
+    pub per_page: u64,
+    pub page: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UsersListQuery {
     pub page: Option<u64>,
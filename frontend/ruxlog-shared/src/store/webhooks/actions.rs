@@ -0,0 +1,128 @@
+use super::{
+    CreateWebhookEndpointPayload, UpdateWebhookEndpointPayload, WebhookDelivery,
+    WebhookDeliveryListQuery, WebhookEndpoint, WebhookEndpointListQuery, WebhookState,
+    WebhookTestResult,
+};
+use oxcore::http;
+use oxstore::{list_state_abstraction, state_request_abstraction};
+
+impl WebhookState {
+    pub async fn list(&self) {
+        let _ = list_state_abstraction(
+            &self.endpoints,
+            http::post("/webhook/v1/list/query", &WebhookEndpointListQuery::new()).send(),
+            "webhook_endpoints",
+        )
+        .await;
+    }
+
+    /// Register an endpoint. On success `save` holds it with its secret.
+    pub async fn create(&self, payload: CreateWebhookEndpointPayload) -> Option<WebhookEndpoint> {
+        let created = self
+            .save_request(http::post("/webhook/v1/create", &payload).send())
+            .await;
+        if created.is_some() {
+            self.list().await;
+        }
+        created
+    }
+
+    pub async fn update(
+        &self,
+        id: i32,
+        payload: UpdateWebhookEndpointPayload,
+    ) -> Option<WebhookEndpoint> {
+        let updated = self
+            .save_request(http::post(&format!("/webhook/v1/update/{}", id), &payload).send())
+            .await;
+        if let Some(endpoint) = &updated {
+            self.replace_in_list(endpoint);
+        }
+        updated
+    }
+
+    /// Issue a new signing secret. On success `save` holds it.
+    pub async fn rotate_secret(&self, id: i32) -> Option<WebhookEndpoint> {
+        self.save_request(http::post(&format!("/webhook/v1/rotate_secret/{}", id), &()).send())
+            .await
+    }
+
+    pub async fn remove(&self, id: i32) {
+        let removed = state_request_abstraction(
+            &self.remove,
+            None::<()>,
+            http::post(&format!("/webhook/v1/delete/{}", id), &()).send(),
+            "webhook_endpoint",
+            |_: &serde_json::Value| (None, None),
+        )
+        .await;
+        if removed.is_some() {
+            self.list().await;
+        }
+    }
+
+    pub async fn test(&self, id: i32) {
+        let _ = state_request_abstraction(
+            &self.test,
+            None::<()>,
+            http::post(&format!("/webhook/v1/test/{}", id), &()).send(),
+            "webhook_test",
+            |result: &WebhookTestResult| (Some(result.clone()), None),
+        )
+        .await;
+    }
+
+    pub async fn list_deliveries(&self, query: WebhookDeliveryListQuery) {
+        let _ = list_state_abstraction(
+            &self.deliveries,
+            http::post("/webhook/v1/deliveries/query", &query).send(),
+            "webhook_deliveries",
+        )
+        .await;
+    }
+
+    /// Queue a logged delivery again and show the copy at the top of the log.
+    pub async fn redeliver(&self, delivery_id: i32) {
+        let copy = state_request_abstraction(
+            &self.redeliver,
+            None::<()>,
+            http::post(
+                &format!("/webhook/v1/deliveries/redeliver/{}", delivery_id),
+                &(),
+            )
+            .send(),
+            "webhook_delivery",
+            |delivery: &WebhookDelivery| (Some(delivery.clone()), None),
+        )
+        .await;
+
+        if let Some(copy) = copy {
+            if let Some(list) = self.deliveries.write().data.as_mut() {
+                list.data.insert(0, copy);
+                list.total += 1;
+            }
+        }
+    }
+
+    async fn save_request<F>(&self, request: F) -> Option<WebhookEndpoint>
+    where
+        F: std::future::Future<Output = Result<http::Response, http::Error>>,
+    {
+        state_request_abstraction(
+            &self.save,
+            None::<()>,
+            request,
+            "webhook_endpoint",
+            |endpoint: &WebhookEndpoint| (Some(endpoint.clone()), None),
+        )
+        .await
+    }
+
+    fn replace_in_list(&self, endpoint: &WebhookEndpoint) {
+        if let Some(list) = self.endpoints.write().data.as_mut() {
+            if let Some(existing) = list.data.iter_mut().find(|e| e.id == endpoint.id) {
+                *existing = endpoint.clone();
+            }
+        }
+    }
+}
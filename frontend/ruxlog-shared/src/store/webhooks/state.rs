@@ -0,0 +1,153 @@
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use oxstore::{PaginatedList, StateFrame};
+pub use ruxlog_types::enums::{WebhookDeliveryStatus, WebhookEvent};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// An outgoing webhook receiver.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookEndpoint {
+    pub id: i32,
+    pub url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub events: Vec<WebhookEvent>,
+    pub is_active: bool,
+    #[serde(default)]
+    pub created_by: Option<i32>,
+    /// Only present right after the endpoint is created or its secret rotated.
+    #[serde(default)]
+    pub secret: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One event sent (or still to be sent) to an endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: i32,
+    pub endpoint_id: i32,
+    pub message_id: String,
+    pub event: WebhookEvent,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    #[serde(default)]
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub response_status: Option<i32>,
+    #[serde(default)]
+    pub response_body: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreateWebhookEndpointPayload {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub events: Vec<WebhookEvent>,
+    pub is_active: bool,
+}
+
+/// Partial update; fields left as `None` keep their saved value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateWebhookEndpointPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<WebhookEvent>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WebhookEndpointListQuery {
+    pub page: u64,
+}
+
+impl WebhookEndpointListQuery {
+    pub fn new() -> Self {
+        Self { page: 1 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WebhookDeliveryListQuery {
+    pub page: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<WebhookEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<WebhookDeliveryStatus>,
+}
+
+impl WebhookDeliveryListQuery {
+    pub fn for_endpoint(endpoint_id: i32) -> Self {
+        Self {
+            page: 1,
+            endpoint_id: Some(endpoint_id),
+            ..Default::default()
+        }
+    }
+}
+
+/// How an endpoint answered a test ping.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookTestResult {
+    pub success: bool,
+    #[serde(default)]
+    pub response_status: Option<i32>,
+    #[serde(default)]
+    pub response_body: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+pub struct WebhookState {
+    pub endpoints: GlobalSignal<StateFrame<PaginatedList<WebhookEndpoint>>>,
+    /// The last created, updated or rotated endpoint. Holds the signing
+    /// secret after a create or rotate.
+    pub save: GlobalSignal<StateFrame<WebhookEndpoint>>,
+    pub remove: GlobalSignal<StateFrame>,
+    pub test: GlobalSignal<StateFrame<WebhookTestResult>>,
+    pub deliveries: GlobalSignal<StateFrame<PaginatedList<WebhookDelivery>>>,
+    pub redeliver: GlobalSignal<StateFrame<WebhookDelivery>>,
+}
+
+impl WebhookState {
+    pub fn new() -> Self {
+        Self {
+            endpoints: GlobalSignal::new(|| StateFrame::new()),
+            save: GlobalSignal::new(|| StateFrame::new()),
+            remove: GlobalSignal::new(|| StateFrame::new()),
+            test: GlobalSignal::new(|| StateFrame::new()),
+            deliveries: GlobalSignal::new(|| StateFrame::new()),
+            redeliver: GlobalSignal::new(|| StateFrame::new()),
+        }
+    }
+
+    pub fn reset(&self) {
+        *self.endpoints.write() = StateFrame::new();
+        *self.save.write() = StateFrame::new();
+        *self.remove.write() = StateFrame::new();
+        *self.test.write() = StateFrame::new();
+        *self.deliveries.write() = StateFrame::new();
+        *self.redeliver.write() = StateFrame::new();
+    }
+}
+
+static WEBHOOK_STATE: OnceLock<WebhookState> = OnceLock::new();
+
+pub fn use_webhooks() -> &'static WebhookState {
+    WEBHOOK_STATE.get_or_init(WebhookState::new)
+}